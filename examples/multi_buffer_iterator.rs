@@ -40,6 +40,6 @@ fn char_to_utf8_multi_buffer_iterator() {
 
 fn main() {
     utf8_to_char_multi_buffer_iterator();
-    println!("");
+    println!();
     char_to_utf8_multi_buffer_iterator();
 }
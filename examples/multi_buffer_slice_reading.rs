@@ -15,18 +15,12 @@ fn utf8_to_char_multi_buffer_slice_reading() {
     for indx in 0 .. mybuffers.len() {
         parser.set_is_last_buffer(indx == mybuffers.len() - 1);
         let mut cur_slice = mybuffers[indx];
-        loop {
-            match parser.utf8_to_char(cur_slice) {
-                Result::Ok((slice_pos, char_val)) => {
-                    cur_slice = slice_pos;
-                    println!("{}", char_val);
-                    println!("{}", parser.has_invalid_sequence());
-                }
-                Result::Err(MoreEnum::More(_amt)) => {
-                    // _amt equals to 0 when end of data
-                    break;
-                }
-            }
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, char_val)) = parser.utf8_to_char(cur_slice) {
+            cur_slice = slice_pos;
+            println!("{}", char_val);
+            println!("{}", parser.has_invalid_sequence());
         }
     }
 }
@@ -39,24 +33,18 @@ fn utf32_to_utf8_multi_buffer_slice_reading() {
         parser.set_is_last_buffer(indx == mybuffers.len() - 1);
         let current_array = mybuffers[indx];
         let mut current_slice = & current_array[..];
-        loop {
-            match parser.utf32_to_utf8(current_slice) {
-                Result::Ok((slice_pos, utf8_val)) => {
-                    current_slice = slice_pos;
-                    println!("{:02x}", utf8_val);
-                    println!("{}", parser.has_invalid_sequence());
-                }
-                Result::Err(MoreEnum::More(_amt)) => {
-                    // _amt equals to 0 when end of data
-                    break;
-                }
-            }
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, utf8_val)) = parser.utf32_to_utf8(current_slice) {
+            current_slice = slice_pos;
+            println!("{:02x}", utf8_val);
+            println!("{}", parser.has_invalid_sequence());
         }
     }
 }
 
 fn main() {
     utf8_to_char_multi_buffer_slice_reading();
-    println!("");
+    println!();
     utf32_to_utf8_multi_buffer_slice_reading();
 }
@@ -6,8 +6,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-/// Example program demonstrating converting UTF8 to char while reading a file.
-/// Exercise iterator converter style API.
+// Example program demonstrating converting UTF8 to char while reading a file.
+// Exercise iterator converter style API.
 
 use std::error::Error;
 use std::io;
@@ -52,9 +52,9 @@ read_obj: & mut (impl Read + ?Sized), buf: & mut [u8]) -> io::Result<()> {
                 parser.set_is_last_buffer(num_bytes == 0);
                 let buf_slice = & buf[0 .. num_bytes];
                 let mut utf8_ref_iter = buf_slice.iter();
-                let mut iterator = parser.utf8_ref_to_char_with_iter(& mut utf8_ref_iter);
+                let iterator = parser.utf8_ref_to_char_with_iter(& mut utf8_ref_iter);
                 // Loop over characters.
-                while let Some(char_val) = iterator.next()  {
+                for char_val in iterator {
                     print!("{}", char_val);
                 }
                 // Finished with this buffer.  Check if we should return.
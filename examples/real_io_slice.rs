@@ -12,10 +12,8 @@ use std::io::prelude::*;
 use std::fs::File;
 use utf8conv::*;
 
-/// Example program demonstrating converting UTF8 to char while reading a file.
-/// Exercise slice parsing style API.
-
-
+// Example program demonstrating converting UTF8 to char while reading a file.
+// Exercise slice parsing style API.
 
 /// Read bytes without I/O interrupts.
 /// Returns the number of bytes read or an IO Error.
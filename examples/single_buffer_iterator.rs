@@ -34,6 +34,6 @@ fn char_to_utf8_single_buffer_iterator() {
 
 fn main() {
     utf8_to_char_single_buffer_iterator();
-    println!("");
+    println!();
     char_to_utf8_single_buffer_iterator();
 }
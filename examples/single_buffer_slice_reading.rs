@@ -13,18 +13,12 @@ fn utf8_to_char_single_buffer_slice_reading() {
     let mybuffer = "Wxyz".as_bytes();
     let mut parser = FromUtf8::new();
     let mut cur_slice = mybuffer;
-    loop {
-        match parser.utf8_to_char(cur_slice) {
-            Result::Ok((slice_pos, char_val)) => {
-                cur_slice = slice_pos;
-                println!("{}", char_val);
-                println!("{}", parser.has_invalid_sequence());
-            }
-            Result::Err(MoreEnum::More(_amt)) => {
-                // _amt equals to 0 when end of data
-                break;
-            }
-        }
+    // The loop stops at MoreEnum::More(_amt);
+    // _amt equals to 0 when end of data
+    while let Result::Ok((slice_pos, char_val)) = parser.utf8_to_char(cur_slice) {
+        cur_slice = slice_pos;
+        println!("{}", char_val);
+        println!("{}", parser.has_invalid_sequence());
     }
 }
 
@@ -33,23 +27,17 @@ fn utf32_to_utf8_single_buffer_slice_reading() {
     let mybuffer = [0x7Fu32, 0x80u32, 0x81u32, 0x82u32];
     let mut parser = FromUnicode::new();
     let mut current_slice = & mybuffer[..];
-    loop {
-        match parser.utf32_to_utf8(current_slice) {
-            Result::Ok((slice_pos, utf8_val)) => {
-                current_slice = slice_pos;
-                println!("{:02x}", utf8_val);
-                println!("{}", parser.has_invalid_sequence());
-            }
-            Result::Err(MoreEnum::More(_amt)) => {
-                // _amt equals to 0 when end of data
-                break;
-            }
-        }
+    // The loop stops at MoreEnum::More(_amt);
+    // _amt equals to 0 when end of data
+    while let Result::Ok((slice_pos, utf8_val)) = parser.utf32_to_utf8(current_slice) {
+        current_slice = slice_pos;
+        println!("{:02x}", utf8_val);
+        println!("{}", parser.has_invalid_sequence());
     }
 }
 
 fn main() {
     utf8_to_char_single_buffer_slice_reading();
-    println!("");
+    println!();
     utf32_to_utf8_single_buffer_slice_reading();
 }
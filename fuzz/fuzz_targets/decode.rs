@@ -0,0 +1,62 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Fuzz target: decode arbitrary bytes split into arbitrary buffers.
+//
+// Checks that the decoder does not panic, that the output equals
+// String::from_utf8_lossy(), and that decoding the UTF8 encoding of
+// the output again gives the same chars (re-sanitization is idempotent).
+//
+// Run with: cargo +nightly fuzz run decode
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use utf8conv::*;
+
+// Decode buffers with utf8_to_char().
+fn decode_buffers(buffers: & [&[u8]]) -> (String, bool) {
+    let mut par = FromUtf8::new();
+    let mut output = String::new();
+    for (indx, buf) in buffers.iter().enumerate() {
+        par.set_is_last_buffer(indx == buffers.len() - 1);
+        let mut cur_slice: &[u8] = buf;
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, ch)) = par.utf8_to_char(cur_slice) {
+            cur_slice = slice_pos;
+            output.push(ch);
+        }
+    }
+    (output, par.has_invalid_sequence())
+}
+
+fuzz_target!(|input: (Vec<u16>, Vec<u8>)| {
+    let (splits, bytes) = input;
+    let mut positions: Vec<usize> = splits.iter()
+        .map(|v| (* v as usize) % (bytes.len() + 1))
+        .collect();
+    positions.sort_unstable();
+    let mut buffers: Vec<&[u8]> = Vec::new();
+    let mut start:usize = 0;
+    for pos in positions {
+        buffers.push(& bytes[start .. pos]);
+        start = pos;
+    }
+    buffers.push(& bytes[start ..]);
+
+    let (output, invalid) = decode_buffers(& buffers);
+    let truth = String::from_utf8_lossy(& bytes);
+    assert_eq!(truth, output);
+    // Encountering a replacement character is considered the same as
+    // having an invalid decode.
+    assert_eq!(output.contains(char::REPLACEMENT_CHARACTER), invalid);
+
+    let (again, _invalid) = decode_buffers(& [output.as_bytes()]);
+    assert_eq!(output, again);
+});
@@ -0,0 +1,65 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Fuzz target: encode an arbitrary UTF32 stream split into arbitrary
+// buffers.
+//
+// Checks that the encoder does not panic, that the output is valid
+// UTF8 with invalid codepoints replaced, and that encoding the decoded
+// output again gives the same bytes.
+//
+// Run with: cargo +nightly fuzz run encode
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use utf8conv::*;
+
+// Encode buffers with utf32_to_utf8().
+fn encode_buffers(buffers: & [&[u32]]) -> (Vec<u8>, bool) {
+    let mut par = FromUnicode::new();
+    let mut output = Vec::new();
+    for (indx, buf) in buffers.iter().enumerate() {
+        par.set_is_last_buffer(indx == buffers.len() - 1);
+        let mut cur_slice: &[u32] = buf;
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, b)) = par.utf32_to_utf8(cur_slice) {
+            cur_slice = slice_pos;
+            output.push(b);
+        }
+    }
+    (output, par.has_invalid_sequence())
+}
+
+fuzz_target!(|input: (Vec<u16>, Vec<u32>)| {
+    let (splits, codes) = input;
+    let mut positions: Vec<usize> = splits.iter()
+        .map(|v| (* v as usize) % (codes.len() + 1))
+        .collect();
+    positions.sort_unstable();
+    let mut buffers: Vec<&[u32]> = Vec::new();
+    let mut start:usize = 0;
+    for pos in positions {
+        buffers.push(& codes[start .. pos]);
+        start = pos;
+    }
+    buffers.push(& codes[start ..]);
+
+    let (output, invalid) = encode_buffers(& buffers);
+    let text = std::str::from_utf8(& output).expect("encoder produced invalid UTF8");
+    let truth: String = codes.iter()
+        .map(|v| char::from_u32(* v).unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect();
+    assert_eq!(truth, text);
+    assert_eq!(text.contains(char::REPLACEMENT_CHARACTER), invalid);
+
+    let decoded: Vec<u32> = text.chars().map(|v| v as u32).collect();
+    let (again, _invalid) = encode_buffers(& [& decoded[..]]);
+    assert_eq!(output, again);
+});
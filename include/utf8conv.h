@@ -0,0 +1,61 @@
+/*
+ * Copyright 2022 Thomas Wang and utf8conv contributors
+ *
+ * Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * option. This file may not be copied, modified, or distributed
+ * except according to those terms.
+ */
+
+/*
+ * C interface of utf8conv, built with the "ffi" feature.
+ *
+ * Feed functions convert as much input as fits in the output, return
+ * the number of output units written, and store the number of input
+ * units used in *input_used.  Finish functions signal end of data;
+ * call them until they return less than output_len.  Invalid input is
+ * replaced with U+FFFD and recorded in the has_invalid flag.
+ */
+
+#ifndef UTF8CONV_H
+#define UTF8CONV_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Streaming UTF8 to UTF32 decoder */
+typedef struct utf8conv_decoder utf8conv_decoder;
+
+/* Streaming UTF32 to UTF8 encoder */
+typedef struct utf8conv_encoder utf8conv_encoder;
+
+utf8conv_decoder *utf8conv_decoder_create(void);
+size_t utf8conv_decoder_feed(utf8conv_decoder *dec,
+    const uint8_t *input, size_t input_len, size_t *input_used,
+    uint32_t *output, size_t output_len);
+size_t utf8conv_decoder_finish(utf8conv_decoder *dec,
+    uint32_t *output, size_t output_len);
+int32_t utf8conv_decoder_has_invalid(const utf8conv_decoder *dec);
+void utf8conv_decoder_reset(utf8conv_decoder *dec);
+void utf8conv_decoder_destroy(utf8conv_decoder *dec);
+
+utf8conv_encoder *utf8conv_encoder_create(void);
+size_t utf8conv_encoder_feed(utf8conv_encoder *enc,
+    const uint32_t *input, size_t input_len, size_t *input_used,
+    uint8_t *output, size_t output_len);
+size_t utf8conv_encoder_finish(utf8conv_encoder *enc,
+    uint8_t *output, size_t output_len);
+int32_t utf8conv_encoder_has_invalid(const utf8conv_encoder *enc);
+void utf8conv_encoder_reset(utf8conv_encoder *enc);
+void utf8conv_encoder_destroy(utf8conv_encoder *enc);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* UTF8CONV_H */
@@ -0,0 +1,314 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// utf8conv command line tool, enabled by the "cli" feature.
+// Converts a file between UTF8, UTF16 and UTF32, streaming it through
+// the library Transcoder one buffer at a time.
+
+use std::env;
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::process;
+
+use utf8conv::*;
+
+const USAGE: &str = "\
+usage: utf8conv [OPTIONS] [INPUT [OUTPUT]]
+
+Converts INPUT to OUTPUT; '-' or a missing name means stdin or stdout.
+
+options:
+  --from ENC        input encoding: utf8, utf16le, utf16be, utf32le, utf32be
+                    (default utf8)
+  --to ENC          output encoding, with the same names (default utf8)
+  --bom MODE        keep, strip or add a leading byte order mark (default keep)
+  --newline MODE    keep, or lf to turn CR and CR LF into LF (default keep)
+  --lossy           replace invalid input with U+FFFD (default)
+  --strict          stop with an error at the first invalid input
+  -h, --help        print this help
+";
+
+/// Size of each read from the input
+const READ_SIZE: usize = 4096;
+
+/// Size of the output buffers, in bytes
+const WRITE_SIZE: usize = 4 * READ_SIZE;
+
+/// The intermediate encoding, where every char is one 4 byte unit
+const MID_ENCODING: Encoding = Encoding::Utf32Le;
+
+/// Replacement character in the intermediate encoding
+const MID_REPLACEMENT: [u8; 4] = (char::REPLACEMENT_CHARACTER as u32).to_le_bytes();
+
+/// Look up an encoding by its command line name.
+fn parse_encoding(name: &str) -> Option<Encoding> {
+    match name.to_ascii_lowercase().as_str() {
+        "utf8" | "utf-8" => Option::Some(Encoding::Utf8),
+        "utf16le" | "utf-16le" => Option::Some(Encoding::Utf16Le),
+        "utf16be" | "utf-16be" => Option::Some(Encoding::Utf16Be),
+        "utf32le" | "utf-32le" => Option::Some(Encoding::Utf32Le),
+        "utf32be" | "utf-32be" => Option::Some(Encoding::Utf32Be),
+        _ => Option::None,
+    }
+}
+
+/// Command line name of an encoding
+fn encoding_name(encoding: Encoding) -> &'static str {
+    match encoding {
+        Encoding::Utf8 => "utf8",
+        Encoding::Utf16Le => "utf16le",
+        Encoding::Utf16Be => "utf16be",
+        Encoding::Utf32Le => "utf32le",
+        Encoding::Utf32Be => "utf32be",
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Byte order mark handling
+enum BomMode {
+    Keep,
+    Strip,
+    Add,
+}
+
+#[derive(Debug)]
+/// Parsed command line
+struct Options {
+    from: Encoding,
+    to: Encoding,
+    bom: BomMode,
+    newline_lf: bool,
+    strict: bool,
+    input: Option<String>,
+    output: Option<String>,
+}
+
+/// Parse the command line arguments after the program name.
+/// Returns the options, 'None' when help was requested, or a usage error.
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Option<Options>, String> {
+    let mut opts = Options {
+        from: Encoding::Utf8,
+        to: Encoding::Utf8,
+        bom: BomMode::Keep,
+        newline_lf: false,
+        strict: false,
+        input: Option::None,
+        output: Option::None,
+    };
+    let mut files: Vec<String> = Vec::new();
+    while let Option::Some(arg) = args.next() {
+        // Accept both "--flag value" and "--flag=value".
+        let (flag, inline_value) = match arg.split_once('=') {
+            Option::Some((f, v)) if arg.starts_with("--") => (f.to_string(), Option::Some(v.to_string())),
+            _ => (arg.clone(), Option::None),
+        };
+        let mut value = |name: &str| -> Result<String, String> {
+            inline_value.clone().or_else(|| args.next())
+            .ok_or_else(|| format!("missing value for {}", name))
+        };
+        match flag.as_str() {
+            "-h" | "--help" => {
+                return Result::Ok(Option::None);
+            }
+            "--from" | "--to" => {
+                let name = value(& flag)?;
+                let enc = parse_encoding(& name)
+                .ok_or_else(|| format!("unknown encoding '{}'", name))?;
+                if flag == "--from" {
+                    opts.from = enc;
+                }
+                else {
+                    opts.to = enc;
+                }
+            }
+            "--bom" => {
+                opts.bom = match value(& flag)?.as_str() {
+                    "keep" => BomMode::Keep,
+                    "strip" => BomMode::Strip,
+                    "add" => BomMode::Add,
+                    other => {
+                        return Result::Err(format!("unknown bom mode '{}'", other));
+                    }
+                };
+            }
+            "--newline" => {
+                opts.newline_lf = match value(& flag)?.as_str() {
+                    "keep" => false,
+                    "lf" => true,
+                    other => {
+                        return Result::Err(format!("unknown newline mode '{}'", other));
+                    }
+                };
+            }
+            "--strict" => {
+                opts.strict = true;
+            }
+            "--lossy" => {
+                opts.strict = false;
+            }
+            _ => {
+                if flag.starts_with("--") || (flag.starts_with('-') && flag != "-") {
+                    return Result::Err(format!("unknown option '{}'", arg));
+                }
+                files.push(arg);
+            }
+        }
+    }
+    if files.len() > 2 {
+        return Result::Err("too many file names".to_string());
+    }
+    let mut files = files.into_iter().map(|v| if v == "-" { Option::None } else { Option::Some(v) });
+    opts.input = files.next().flatten();
+    opts.output = files.next().flatten();
+    Result::Ok(Option::Some(opts))
+}
+
+/// Read bytes without I/O interrupts.
+/// Returns the number of bytes read or an IO Error.
+/// # Arguments
+/// * `read_obj` - a mutable Read trait reference
+/// * `buf` - a mutable byte slice
+fn read_bytes_without_interrupt(
+read_obj: & mut (impl Read + ?Sized), buf: & mut [u8]) -> io::Result<usize> {
+    loop {
+        match read_obj.read(buf) {
+            Ok(num_bytes) => { return Ok(num_bytes); }
+            Err(er) => {
+                if er.kind() != io::ErrorKind::Interrupted {
+                    return Err(er);
+                }
+            }
+        }
+    }
+}
+
+/// Convert all of `input` with `transcoder`, and write the output.
+/// # Arguments
+/// * `transcoder` - the converter
+/// * `input` - the input bytes
+/// * `last` - true when `input` ends the stream
+/// * `out_buf` - the output buffer
+/// * `writer` - the destination of the output
+fn transcode_all(transcoder: & mut Transcoder, mut input: &[u8], last: bool,
+out_buf: & mut [u8], writer: & mut impl Write) -> io::Result<()> {
+    loop {
+        let (num_read, num_written) = transcoder.transcode(input, out_buf, last);
+        writer.write_all(& out_buf[0 .. num_written])?;
+        input = & input[num_read ..];
+        if input.is_empty() && (num_written < out_buf.len()) {
+            return Ok(());
+        }
+    }
+}
+
+/// Errors that stop a conversion
+enum RunError {
+    Read(io::Error),
+    Write(io::Error),
+    Invalid(usize),
+}
+
+/// Convert `reader` into `writer`.
+///
+/// The input is decoded into the intermediate encoding first, where the
+/// chars can be counted, and in strict mode checked for replacement
+/// characters.  The library counts an encoded replacement character in
+/// the input as invalid too, so every replacement character is invalid
+/// input.  The chars are then encoded into the output encoding.
+fn convert(reader: & mut impl Read, writer: & mut impl Write, opts: & Options) -> Result<(), RunError> {
+    let mut recode = Recode::from(opts.from).to(MID_ENCODING);
+    if opts.bom != BomMode::Keep {
+        recode = recode.strip_bom();
+    }
+    if opts.newline_lf {
+        recode = recode.normalize_newlines();
+    }
+    let mut decoder = Transcoder::from(recode);
+    let mut encoder = Transcoder::new(MID_ENCODING, opts.to);
+    let mut in_buf = vec![0u8; READ_SIZE];
+    let mut mid_buf = vec![0u8; WRITE_SIZE];
+    let mut out_buf = vec![0u8; WRITE_SIZE];
+    if opts.bom == BomMode::Add {
+        writer.write_all(bom_bytes(opts.to)).map_err(RunError::Write)?;
+    }
+    let mut num_chars: usize = 0;
+    loop {
+        let num_bytes = read_bytes_without_interrupt(reader, & mut in_buf).map_err(RunError::Read)?;
+        // Indicate last buffer if end of file.
+        let last_buffer = num_bytes == 0;
+        let mut input = & in_buf[0 .. num_bytes];
+        loop {
+            let (num_read, num_written) = decoder.transcode(input, & mut mid_buf, last_buffer);
+            input = & input[num_read ..];
+            let mut mid = & mid_buf[0 .. num_written];
+            let mut invalid_at = Option::None;
+            if opts.strict {
+                // Stop right before the first invalid char.
+                if let Option::Some(indx) = mid.chunks_exact(4).position(|v| v == MID_REPLACEMENT) {
+                    mid = & mid[0 .. 4 * indx];
+                    invalid_at = Option::Some(num_chars + indx);
+                }
+            }
+            num_chars += mid.len() / 4;
+            transcode_all(& mut encoder, mid, false, & mut out_buf, writer).map_err(RunError::Write)?;
+            if let Option::Some(indx) = invalid_at {
+                return Result::Err(RunError::Invalid(indx));
+            }
+            if input.is_empty() && (num_written < mid_buf.len()) {
+                break;
+            }
+        }
+        if last_buffer {
+            return transcode_all(& mut encoder, &[], true, & mut out_buf, writer).map_err(RunError::Write);
+        }
+    }
+}
+
+/// Convert the input named in `opts` to its output.
+fn run(opts: & Options) -> Result<(), String> {
+    let mut reader: Box<dyn Read> = match & opts.input {
+        Option::Some(name) => Box::new(File::open(name).map_err(|er| format!("{}: {}", name, er))?),
+        Option::None => Box::new(io::stdin().lock()),
+    };
+    let writer: Box<dyn Write> = match & opts.output {
+        Option::Some(name) => Box::new(File::create(name).map_err(|er| format!("{}: {}", name, er))?),
+        Option::None => Box::new(io::stdout().lock()),
+    };
+    let mut writer = BufWriter::new(writer);
+    let converted = convert(& mut reader, & mut writer, opts);
+    // Write out the chars before an invalid one too.
+    writer.flush().map_err(|er| format!("write error: {}", er))?;
+    match converted {
+        Result::Ok(()) => Result::Ok(()),
+        Result::Err(RunError::Read(er)) => Result::Err(format!("read error: {}", er)),
+        Result::Err(RunError::Write(er)) => Result::Err(format!("write error: {}", er)),
+        Result::Err(RunError::Invalid(indx)) => {
+            Result::Err(format!("invalid {} input at char {}", encoding_name(opts.from), indx))
+        }
+    }
+}
+
+fn main() {
+    match parse_args(env::args().skip(1)) {
+        Result::Ok(Option::None) => {
+            print!("{}", USAGE);
+        }
+        Result::Ok(Option::Some(opts)) => {
+            if let Result::Err(msg) = run(& opts) {
+                eprintln!("utf8conv: {}", msg);
+                process::exit(1);
+            }
+        }
+        Result::Err(msg) => {
+            eprintln!("utf8conv: {}\n\n{}", msg, USAGE);
+            process::exit(2);
+        }
+    }
+}
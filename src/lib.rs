@@ -9,12 +9,17 @@
 //!
 //! Implementation of UTF 8 / UTF32 converters and converting iterators,
 //! including the supporting recognition and translation functions.
+//! UTF16 code units are decoded to char by `FromUtf16`, with the same
+//! streaming interface, and encoded from char by `FromUnicode`.
 //!
 //! Works on a single buffer as well as multiple buffers without needing
 //! heap allocation.
 //!
 //! An invalid Unicode decoding sequence is replaced with an Unicode Replacement codepoint.
 //!
+//! The converters do not panic on any input; slices are accessed with
+//! checked operations only.
+//!
 //! Includes an adapter iterator to filter out Byte Order Mark at the
 //! beginning of a stream, and substituting carriage returns with newlines.
 //!
@@ -24,6 +29,62 @@
 //!
 //! Credits attribution of utf8conv is located in source directory doc/utf8conv-credits.md.
 //!
+//! #### Cargo features
+//!
+//! * `alloc` - `convert_text()`, decoding a complete input into a
+//!   String with byte order mark stripping and newline normalization,
+//!   and `TextDecoder::decode()`.
+//! * `std` - adapters between the converters and `std::io`, such as
+//!   `IoSink`, `Utf16LeReader`, `Utf16LeWriter`, `RecodeReader`,
+//!   `RecodeWriter`, `stdin_chars()` and `print_chars()`, and threaded
+//!   conversion stages connected by channels.
+//! * `safe` - the crate is built with `forbid(unsafe_code)`.  Decoded
+//!   codepoints are converted to char with a checked conversion, and the
+//!   bulk converters into `MaybeUninit` buffers are left out.
+//! * `small` - a smaller and slower decoder for targets with little
+//!   flash, such as AVR or MSP430.  The ASCII fast path, the word sized
+//!   scratch pad refill and the inlining hints of the decoder are left
+//!   out.  The API and the decoded results are the same.
+//! * `test-util` - the `test_util` module with functions that verify
+//!   multi-buffer decoding against a truth string.
+//! * `defmt` - `defmt::Format` for the status enums and the parsers, for
+//!   logging decoder state on embedded targets.
+//! * `cli` - the `utf8conv` command line tool, converting files between
+//!   UTF8, UTF16 and UTF32 with options for byte order marks, newlines,
+//!   and strict or lossy decoding.
+//! * `embedded-io` - `EmbeddedUtf8Reader` and `EmbeddedUtf8Writer`,
+//!   decoding chars from an `embedded_io::Read` source and encoding them
+//!   into an `embedded_io::Write` target, without the standard library.
+//! * `embedded-io-async` - `AsyncUtf8Reader`, awaiting UTF8 bytes from an
+//!   `embedded_io_async::Read` source such as an embassy UART, and
+//!   decoding them incrementally.
+//! * `futures` - `Utf8CharStream`, a `futures_core::Stream` of chars
+//!   decoded from a `futures_io::AsyncRead` source; implies `std`.
+//! * `tokio-codec` - `Utf8Codec`, a `tokio_util` codec decoding UTF8
+//!   byte streams into text with replacement characters, and encoding
+//!   text; implies `std`.
+//! * `gb18030` - `FromGb18030`, a streaming GB18030 decoder with the
+//!   GB18030-2005 mappings held in run tables.
+//! * `ffi` - the `ffi` module with a C interface to the streaming
+//!   decoder and encoder; implies `alloc`.  The module needs unsafe code,
+//!   so it is left out when `safe` is also enabled.
+//! * `windows` - `write_console_chars()` and `print_console_chars()`,
+//!   writing chars to a Windows console as UTF16 with WriteConsoleW, or
+//!   as UTF8 when the handle is redirected; Windows targets only.  The
+//!   functions need unsafe code, so they are left out when `safe` is
+//!   also enabled.
+//!
+//! #### One-liner conversions
+//!
+//! ```rust
+//! use utf8conv::*;
+//!
+//! let text: String = decode_lossy(& [0x41u8, 0xE2u8, 0x82u8, 0xACu8, 0xFFu8]).collect();
+//! assert_eq!("A\u{20AC}\u{FFFD}", text);
+//! let bytes: Vec<u8> = encode(text.chars()).collect();
+//! assert_eq!(text.as_bytes(), & bytes[..]);
+//! ```
+//!
 //! #### Single buffer iterator based parsing
 //!
 //! ```rust
@@ -189,9 +250,14 @@
 //! }
 //! ```
 
+#![cfg_attr(feature = "safe", forbid(unsafe_code))]
+
 #[cfg(doctest)]
 extern crate doc_comment;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 
 pub use crate::utf8conv::REPLACE_UTF32;
 pub use crate::utf8conv::REPLACE_PART1;
@@ -200,21 +266,188 @@ pub use crate::utf8conv::REPLACE_PART3;
 pub use crate::utf8conv::FromUtf8;
 pub use crate::utf8conv::FromUnicode;
 pub use crate::utf8conv::UtfParserCommon;
+pub use crate::utf8conv::Decoder;
+pub use crate::utf8conv::Encoder;
+pub use crate::utf8conv::CharSource;
+pub use crate::utf8conv::IterSource;
 pub use crate::utf8conv::Utf8IterToCharIter;
 pub use crate::utf8conv::Utf32IterToUtf8Iter;
 pub use crate::utf8conv::Utf8RefIterToCharIter;
 pub use crate::utf8conv::CharRefIterToUtf8Iter;
+pub use crate::utf8conv::Utf8IterToUtf16Iter;
+pub use crate::utf8conv::Utf32IterToUtf16Iter;
+pub use crate::utf8conv::CharRefIterToUtf16Iter;
+pub use crate::utf8conv::Utf8IntoCharIter;
+pub use crate::utf8conv::Utf32IntoUtf8Iter;
 pub use crate::utf8conv::Utf8TypeEnum;
 pub use crate::utf8conv::Utf8EndEnum;
 pub use crate::utf8conv::MoreEnum;
+pub use crate::utf8conv::Utf8ErrorKind;
+pub use crate::utf8conv::Utf32ErrorKind;
+pub use crate::utf8conv::Utf32Error;
+pub use crate::utf8conv::TruncatedInputError;
+pub use crate::utf8conv::StrictEncodeError;
+pub use crate::utf8conv::EncodeError;
 pub use crate::utf8conv::classify_utf32;
+pub use crate::utf8conv::utf8_sequence_len;
+pub use crate::utf8conv::utf8_len;
+pub use crate::utf8conv::utf8_len_of_slice;
+pub use crate::utf8conv::validate_utf32_slice;
+pub use crate::utf8conv::validate_char_slice;
+pub use crate::utf8conv::encode_utf32_to_buf;
+pub use crate::utf8conv::encode_char_to_buf;
+pub use crate::utf8conv::decode_lossy;
+pub use crate::utf8conv::decode_nul_terminated;
+pub use crate::utf8conv::decode_utf8_exact;
+pub use crate::utf8conv::char_at;
+pub use crate::utf8conv::encode;
+pub use crate::utf8conv::DisplayUtf8Lossy;
+pub use crate::utf8conv::measure_utf8_to_char;
+pub use crate::utf8conv::convert_utf8_to_char;
+pub use crate::utf8conv::convert_to_utf8;
+pub use crate::utf8conv::max_utf8_len_for_chars;
+pub use crate::utf8conv::max_chars_for_utf8_len;
+pub use crate::utf8conv::max_utf16_len_for_utf8;
+pub use crate::utf8conv::max_utf8_len_for_utf16;
 pub use crate::utf8conv::utf8_decode;
+pub use crate::utf8conv::utf8_decode_with_len;
+pub use crate::utf8conv::find_non_ascii;
 pub use crate::utf8conv::char_ref_iter_to_char_iter;
 pub use crate::utf8conv::utf32_ref_iter_to_utf32_iter;
 pub use crate::utf8conv::utf8_ref_iter_to_utf8_iter;
 pub use crate::utf8conv::char_iter_to_utf32_iter;
+pub use crate::utf8conv::str_to_utf32_iter;
+pub use crate::utf8conv::StrToUtf32Struct;
 pub use crate::utf8conv::filter_bom_and_cr_iter;
+pub use crate::utf8conv::char_iter_to_char_chunk_iter;
+pub use crate::utf8conv::CharToCharChunkStruct;
+pub use crate::utf8conv::char_iter_to_str_iter;
+pub use crate::utf8conv::CharToStrStruct;
+pub use crate::utf8conv::bom::bom_bytes;
+pub use crate::utf8conv::bom::detect_encoding;
+pub use crate::utf8conv::bom::strip_bom;
+pub use crate::utf8conv::bom::write_bom;
+pub use crate::utf8conv::bom::BomSniffer;
+pub use crate::utf8conv::bom::Encoding;
 pub use crate::utf8conv::buf::EightBytes;
+pub use crate::utf8conv::chain::ChainedBuffers;
+pub use crate::utf8conv::chain::RefillBuffers;
+pub use crate::utf8conv::chain::Utf8ChunksIntoCharIter;
+pub use crate::utf8conv::context::FromUtf8WithContext;
+pub use crate::utf8conv::context::Utf8ErrorContext;
+pub use crate::utf8conv::cursor::Utf8Cursor;
+pub use crate::utf8conv::cursor::chars_with_bytes;
+pub use crate::utf8conv::cursor::Utf8CharsWithBytes;
+pub use crate::utf8conv::diag::DecodeError;
+pub use crate::utf8conv::diag::DiagnosticSink;
+pub use crate::utf8conv::diag::FromUtf8WithDiagnostics;
+pub use crate::utf8conv::diag::Utf8Diagnostic;
+pub use crate::utf8conv::display::DisplayChars;
+pub use crate::utf8conv::ebcdic::cp037_to_char;
+pub use crate::utf8conv::ebcdic::FromCp037;
+pub use crate::utf8conv::framer::Utf8Framer;
+pub use crate::utf8conv::html::html_char_ref_iter;
+pub use crate::utf8conv::html::HtmlCharRefStruct;
+pub use crate::utf8conv::index::CharIndexBuilder;
+pub use crate::utf8conv::index::char_index_seek;
+pub use crate::utf8conv::json::json_escape_iter;
+pub use crate::utf8conv::json::JsonEscapeStruct;
+pub use crate::utf8conv::json::json_unescape_iter;
+pub use crate::utf8conv::json::JsonUnescapeStruct;
+pub use crate::utf8conv::latin1::Latin1Encoder;
+pub use crate::utf8conv::latin1::SingleByteCharset;
+pub use crate::utf8conv::latin1::UnencodableError;
+pub use crate::utf8conv::latin1::UnencodablePolicy;
+pub use crate::utf8conv::machine::Utf8ByteMachine;
+pub use crate::utf8conv::machine::MachineStatus;
+pub use crate::utf8conv::offsets::ArrowOffset;
+pub use crate::utf8conv::offsets::ArrowOffsetsBuilder;
+pub use crate::utf8conv::offsets::OffsetsError;
+pub use crate::utf8conv::recode::Recode;
+pub use crate::utf8conv::recode::RecodeIter;
+pub use crate::utf8conv::repair::plan_repairs;
+pub use crate::utf8conv::repair::RepairEdit;
+pub use crate::utf8conv::repair::RepairSummary;
+pub use crate::utf8conv::sanitize::Utf8SanitizeIter;
+pub use crate::utf8conv::sink::CharSink;
+pub use crate::utf8conv::sink::ByteSink;
+pub use crate::utf8conv::sink::FnSink;
+pub use crate::utf8conv::sink::FmtSink;
+pub use crate::utf8conv::sink::EncodeWriter;
+pub use crate::utf8conv::transcoder::BufferedChars;
+pub use crate::utf8conv::transcoder::BufferedTranscoder;
+pub use crate::utf8conv::transcoder::Transcoder;
+pub use crate::utf8conv::utf16::FromUtf16;
+pub use crate::utf8conv::utf16::Utf16IterToCharIter;
+pub use crate::utf8conv::utf16::Utf16RefIterToCharIter;
+pub use crate::utf8conv::utf16::Utf16IterToUtf8Iter;
+pub use crate::utf8conv::valid::utf8_runs;
+pub use crate::utf8conv::valid::Utf8Run;
+pub use crate::utf8conv::valid::Utf8Runs;
+pub use crate::utf8conv::valid::Utf8ValidationError;
+pub use crate::utf8conv::valid::ValidUtf8;
+pub use crate::utf8conv::whatwg::TextDecodeError;
+pub use crate::utf8conv::whatwg::TextDecoder;
+pub use crate::utf8conv::whatwg::TextDecoderOptions;
+pub use crate::utf8conv::xml::is_xml_char;
+pub use crate::utf8conv::xml::xml_safe_iter;
+pub use crate::utf8conv::xml::XmlSafeStruct;
+#[cfg(feature = "alloc")]
+pub use crate::utf8conv::text::convert_text;
+#[cfg(feature = "alloc")]
+pub use crate::utf8conv::text::TextOptions;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::sink::IoSink;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::io::Utf16LeReader;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::io::Utf16LeWriter;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::io::RecodeReader;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::io::RecodeWriter;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::io::Utf8ReadChars;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::io::CharReader;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::io::Utf8Writer;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::io::Utf16Writer;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::io::stdin_chars;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::io::print_chars;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::io::write_chars;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::pipeline::spawn_read_stage;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::pipeline::spawn_decode_stage;
+#[cfg(feature = "std")]
+pub use crate::utf8conv::pipeline::spawn_sanitize_stage;
+#[cfg(feature = "embedded-io")]
+pub use crate::utf8conv::embedded::EmbeddedUtf8Reader;
+#[cfg(feature = "embedded-io")]
+pub use crate::utf8conv::embedded::EmbeddedUtf8Writer;
+#[cfg(feature = "embedded-io-async")]
+pub use crate::utf8conv::embedded_async::AsyncUtf8Reader;
+#[cfg(feature = "futures")]
+pub use crate::utf8conv::async_stream::Utf8CharStream;
+#[cfg(feature = "tokio-codec")]
+pub use crate::utf8conv::codec::Utf8Codec;
+#[cfg(feature = "gb18030")]
+pub use crate::utf8conv::gb18030::gb18030_two_byte_to_char;
+#[cfg(feature = "gb18030")]
+pub use crate::utf8conv::gb18030::FromGb18030;
+#[cfg(all(feature = "windows", windows, not(feature = "safe")))]
+pub use crate::utf8conv::console::print_console_chars;
+#[cfg(all(feature = "windows", windows, not(feature = "safe")))]
+pub use crate::utf8conv::console::write_console_chars;
+#[cfg(feature = "test-util")]
+pub use crate::utf8conv::test_util;
+#[cfg(all(feature = "ffi", not(feature = "safe")))]
+pub use crate::utf8conv::ffi;
 
 
 mod utf8conv;
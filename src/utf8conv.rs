@@ -51,7 +51,8 @@ const SIX_ONES_SHIFTED:u32 = 0b111111000000u32;
 /// 0x3F bit mask
 const SIX_ONES:u32 = 0b111111u32;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[cfg_attr(nightly, warn(rustdoc::missing_doc_code_examples))]
 /// Indication for needing more data when parameter value greater than 0,
 /// or end of data condition when parameter value is 0.
@@ -64,6 +65,7 @@ pub enum MoreEnum {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[cfg_attr(nightly, warn(rustdoc::missing_doc_code_examples))]
 /// Indication for the type of UTF8 decoding when converting
 /// from UTF32 to UTF8
@@ -85,6 +87,7 @@ pub enum Utf8TypeEnum {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[cfg_attr(nightly, warn(rustdoc::missing_doc_code_examples))]
 /// Utf8EndEnum is the result container for the UTF8 to char
 /// finite state machine.
@@ -100,6 +103,259 @@ pub enum Utf8EndEnum {
     TypeUnknown,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// The kind of an invalid UTF8 sequence.
+pub enum Utf8ErrorKind {
+
+    /// a byte that cannot start a sequence: 80 to C1, or F5 to FF
+    InvalidLead,
+
+    /// a sequence interrupted by a byte that cannot continue it,
+    /// including overlong, surrogate, and out of range sequences
+    InvalidContinuation,
+
+    /// a sequence cut short by the end of data
+    Truncated,
+
+    /// an encoded replacement character, which counts as an earlier
+    /// invalid sequence
+    ReplacementCharacter,
+
+    /// an overlong sequence decoded by the overlong tolerant mode
+    Overlong,
+}
+
+/// Display for Utf8ErrorKind
+impl fmt::Display for Utf8ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Utf8ErrorKind::InvalidLead => "invalid UTF8 lead byte",
+            Utf8ErrorKind::InvalidContinuation => "invalid UTF8 continuation byte",
+            Utf8ErrorKind::Truncated => "truncated UTF8 sequence",
+            Utf8ErrorKind::ReplacementCharacter => "replacement character in UTF8 input",
+            Utf8ErrorKind::Overlong => "overlong UTF8 sequence",
+        };
+        f.write_str(msg)
+    }
+}
+
+/// Error for Utf8ErrorKind
+impl core::error::Error for Utf8ErrorKind {}
+
+/// Implementation of Utf8ErrorKind
+impl Utf8ErrorKind {
+
+    /// Write the Display message into `buf` without allocation, and
+    /// return it.  A message too long for the buffer is cut short.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut buf = [0u8; 64];
+    /// assert_eq!("truncated UTF8 sequence", Utf8ErrorKind::Truncated.format_into(& mut buf));
+    /// let mut small = [0u8; 9];
+    /// assert_eq!("truncated", Utf8ErrorKind::Truncated.format_into(& mut small));
+    /// ```
+    /// # Arguments
+    /// * `buf` - the output buffer
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        format_into(self, buf)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// The kind of an invalid UTF32 value.
+pub enum Utf32ErrorKind {
+
+    /// an UTF16 surrogate: D800 to DFFF
+    Surrogate,
+
+    /// a value above 10FFFF
+    OutOfRange,
+
+    /// a replacement character, which counts as an earlier invalid
+    /// sequence
+    ReplacementCharacter,
+}
+
+/// Display for Utf32ErrorKind
+impl fmt::Display for Utf32ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Utf32ErrorKind::Surrogate => "surrogate in UTF32 input",
+            Utf32ErrorKind::OutOfRange => "out of range value in UTF32 input",
+            Utf32ErrorKind::ReplacementCharacter => "replacement character in UTF32 input",
+        };
+        f.write_str(msg)
+    }
+}
+
+/// Error for Utf32ErrorKind
+impl core::error::Error for Utf32ErrorKind {}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// The first invalid value found by validate_utf32_slice()
+pub struct Utf32Error {
+
+    /// index of the invalid value
+    pub index: usize,
+
+    /// the kind of the invalid value
+    pub kind: Utf32ErrorKind,
+}
+
+/// Display for Utf32Error
+impl fmt::Display for Utf32Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at index {}", self.kind, self.index)
+    }
+}
+
+/// Error for Utf32Error
+impl core::error::Error for Utf32Error {}
+
+/// Implementation of Utf32Error
+impl Utf32Error {
+
+    /// Write the Display message into `buf` without allocation, and
+    /// return it.  A message too long for the buffer is cut short.
+    /// # Arguments
+    /// * `buf` - the output buffer
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        format_into(self, buf)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// A sequence cut short by the end of the input of decode_utf8_exact()
+pub struct TruncatedInputError {
+
+    /// byte offset of the incomplete sequence
+    pub offset: usize,
+
+    /// length of the incomplete sequence in bytes
+    pub len: usize,
+}
+
+/// Display for TruncatedInputError
+impl fmt::Display for TruncatedInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "truncated input: {} byte UTF8 sequence at byte {}", self.len, self.offset)
+    }
+}
+
+/// Error for TruncatedInputError
+impl core::error::Error for TruncatedInputError {}
+
+/// Implementation of TruncatedInputError
+impl TruncatedInputError {
+
+    /// Write the Display message into `buf` without allocation, and
+    /// return it.  A message too long for the buffer is cut short.
+    /// # Arguments
+    /// * `buf` - the output buffer
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        format_into(self, buf)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// An invalid value rejected by strict encoding in FromUnicode
+pub struct StrictEncodeError {
+
+    /// number of values encoded before it in the stream
+    pub index: u64,
+
+    /// the value
+    pub value: u32,
+
+    /// the kind of the invalid value
+    pub kind: Utf32ErrorKind,
+}
+
+/// Display for StrictEncodeError
+impl fmt::Display for StrictEncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at index {}, value {:#X}", self.kind, self.index, self.value)
+    }
+}
+
+/// Error for StrictEncodeError
+impl core::error::Error for StrictEncodeError {}
+
+/// Implementation of StrictEncodeError
+impl StrictEncodeError {
+
+    /// Write the Display message into `buf` without allocation, and
+    /// return it.  A message too long for the buffer is cut short.
+    /// # Arguments
+    /// * `buf` - the output buffer
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        format_into(self, buf)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// The outcome of the strict FromUnicode parsers, such as
+/// utf32_to_utf8_strict(), when no output came out
+pub enum EncodeError {
+
+    /// an invalid value, not encoded
+    Invalid(StrictEncodeError),
+
+    /// the MoreEnum condition of the parser: a request for more data,
+    /// or the end of data with More(0)
+    More(MoreEnum),
+}
+
+/// Display for EncodeError
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::Invalid(error) => fmt::Display::fmt(error, f),
+            EncodeError::More(MoreEnum::More(0)) => f.write_str("end of data"),
+            EncodeError::More(MoreEnum::More(_amt)) => f.write_str("more data needed"),
+        }
+    }
+}
+
+/// Error for EncodeError
+impl core::error::Error for EncodeError {}
+
+/// Implementation of EncodeError
+impl EncodeError {
+
+    /// Write the Display message into `buf` without allocation, and
+    /// return it.  A message too long for the buffer is cut short.
+    /// # Arguments
+    /// * `buf` - the output buffer
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        format_into(self, buf)
+    }
+}
+
+#[inline]
+/// Determine the kind of an Utf8EndEnum::BadDecode(len) result from the
+/// first byte of the failed sequence.
+pub(crate) fn bad_decode_kind(lead: u8, len: u32) -> Utf8ErrorKind {
+    if (0x80u8 ..= 0xC1u8).contains(& lead) || (lead >= 0xF5u8) {
+        Utf8ErrorKind::InvalidLead
+    }
+    else if (lead == REPLACE_PART1) && (len == 3) {
+        // EF BF BD is the only 3 byte failure starting with EF.
+        Utf8ErrorKind::ReplacementCharacter
+    }
+    else {
+        Utf8ErrorKind::InvalidContinuation
+    }
+}
+
 
 #[inline]
 /// Classify an UTF32 value into the type of UTF8 it belongs.
@@ -109,7 +365,7 @@ pub enum Utf8EndEnum {
 /// Returning Utf8TypeEnum::Type0 indicates error.
 /// # Arguments
 /// * `code` - the codepoint to be classified
-pub fn classify_utf32(code: u32) -> Utf8TypeEnum {
+pub const fn classify_utf32(code: u32) -> Utf8TypeEnum {
     if code < 0x80u32 {
         Utf8TypeEnum::Type1(code as u8)
     }
@@ -148,104 +404,629 @@ pub fn classify_utf32(code: u32) -> Utf8TypeEnum {
     }
 }
 
+#[inline]
+/// Length of the UTF8 sequence of an UTF32 value: 1 to 4, or 0 when
+/// classify_utf32() returns Utf8TypeEnum::Type0.
+///
+/// Same classification as classify_utf32(), without producing the bytes.
+/// # Arguments
+/// * `code` - the codepoint to be classified
+pub const fn utf8_sequence_len(code: u32) -> u8 {
+    if code < 0x80u32 {
+        1
+    }
+    else if code < 0x800u32 {
+        2
+    }
+    else if (code >= 0xD800u32) && (code < 0xE000u32) {
+        // Illegal UTF16 surrogate range
+        0
+    }
+    else if code < 0x10000u32 {
+        if code == REPLACE_UTF32 {
+            0
+        }
+        else {
+            3
+        }
+    }
+    else if code < 0x110000u32 {
+        4
+    }
+    else {
+        // beyond valid UTF32 range
+        0
+    }
+}
 
-/*
-Technical notes written by Henri Sivonen, selectely quoted
+#[inline]
+/// Number of UTF8 bytes produced when encoding an UTF32 value.
+///
+/// Invalid codepoints and the replacement character count as 3 bytes,
+/// the length of the replacement character.
+/// # Arguments
+/// * `code` - the codepoint to be measured
+pub const fn utf8_len(code: u32) -> usize {
+    match utf8_sequence_len(code) {
+        0 => 3,
+        len => len as usize,
+    }
+}
 
-Unicode 9.0.0 (page 127) says: “An ill-formed subsequence consisting of more
-than one code unit could be treated as a single error or as multiple errors.
-For example, in processing the UTF-8 code unit sequence <F0 80 80 41>,
-the only formal requirement mandated by Unicode conformance for a converter
-is that the <41> be processed and correctly interpreted as <U+0041>.
-The converter could return <U+FFFD, U+0041>, handling <F0 80 80> as a single
-error, or <U+FFFD, U+FFFD, U+FFFD, U+0041>, handling each byte of <F0 80 80>
-as a separate error, or could take other approaches to signalling <F0 80 80>
-as an ill-formed code unit subsequence.” So as far as Unicode is concerned,
-any number from one to the number of bytes in the number of bogus bytes
-(inclusive) is OK. In other words, the precise number is
-implementation-defined as far as Unicode is concerned.
+#[inline]
+/// Number of UTF8 bytes produced when encoding a slice of chars or
+/// UTF32 values, applying the same replacement policy as utf8_len().
+/// # Arguments
+/// * `input` - a char slice or an UTF32 slice
+pub fn utf8_len_of_slice<T: Copy + Into<u32>>(input: &[T]) -> usize {
+    input.iter().map(|v| utf8_len((* v).into())).sum()
+}
 
-> However, for the best compatibility with existing software, implementing
-> the conversion with a finite state machine was the typical approach.
+/// number of values checked at a time by validate_utf32_slice()
+const VALIDATE_BLOCK_SIZE:usize = 16;
 
-Code Points         First Byte   Second Byte  Third Byte  Fourth Byte
-U+0000..U+007F      00..7F
->                   action 0
+#[inline]
+/// Returns the kind of an invalid UTF32 value, or 'None' when the value
+/// is encoded without a replacement character.
+const fn utf32_error_kind(code: u32) -> Option<Utf32ErrorKind> {
+    if code >= 0x110000u32 {
+        Option::Some(Utf32ErrorKind::OutOfRange)
+    }
+    else if (code >= 0xD800u32) && (code < 0xE000u32) {
+        Option::Some(Utf32ErrorKind::Surrogate)
+    }
+    else if code == REPLACE_UTF32 {
+        Option::Some(Utf32ErrorKind::ReplacementCharacter)
+    }
+    else {
+        Option::None
+    }
+}
 
-U+0080..U+07FF      C2..DF       80..bf
->                   action 1     action 9
+/// Check a complete UTF32 slice before encoding it, and return the
+/// index and kind of the first value that the encoders would replace.
+///
+/// Blocks of values are checked without branches, so valid input is
+/// checked quickly.  Replacement characters are reported, because the
+/// encoders count them as invalid sequences.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// assert_eq!(Ok(()), validate_utf32_slice(& [0x41u32, 0x10FFFFu32]));
+/// let error = validate_utf32_slice(& [0x41u32, 0xDC00u32, 0x110000u32]).unwrap_err();
+/// assert_eq!(Utf32Error { index: 1, kind: Utf32ErrorKind::Surrogate }, error);
+/// ```
+/// # Arguments
+/// * `input` - the complete UTF32 input
+pub fn validate_utf32_slice(input: &[u32]) -> Result<(), Utf32Error> {
+    let mut base:usize = 0;
+    for block in input.chunks(VALIDATE_BLOCK_SIZE) {
+        // Non-short-circuit operators keep the block loop free of
+        // branches; the exact index and kind are found below.
+        let any_bad = block.iter().fold(false, |acc, v| {
+            acc | (* v >= 0x110000u32) | ((* v & 0xFFFFF800u32) == 0xD800u32)
+                | (* v == REPLACE_UTF32)
+        });
+        if any_bad {
+            for (indx, v) in block.iter().enumerate() {
+                if let Option::Some(kind) = utf32_error_kind(* v) {
+                    return Result::Err(Utf32Error { index: base + indx, kind });
+                }
+            }
+        }
+        base += block.len();
+    }
+    Result::Ok(())
+}
 
-U+0800..U+0FFF      E0           A0..bf       80..bf
->                   action 2     action 14    action 17
+/// Check a complete char slice before encoding it, and return the index
+/// of the first replacement character.  Chars cannot be surrogates or
+/// out of range, so that is the only kind reported.
+/// # Arguments
+/// * `input` - the complete char input
+pub fn validate_char_slice(input: &[char]) -> Result<(), Utf32Error> {
+    match input.iter().position(|ch| * ch == char::REPLACEMENT_CHARACTER) {
+        Option::Some(index) => Result::Err(Utf32Error {
+            index,
+            kind: Utf32ErrorKind::ReplacementCharacter,
+        }),
+        Option::None => Result::Ok(()),
+    }
+}
 
-U+1000..U+CFFF      E1..EC       80..bf       80..bf
->                   action 3     action 10    action (17)
+/// Encode an UTF32 value into a 4 byte buffer, and return the encoded
+/// part of the buffer as a string slice.
+///
+/// Invalid codepoints are encoded as the replacement character.
+/// # Arguments
+/// * `code` - the codepoint to be encoded
+/// * `buf` - the output buffer
+pub fn encode_utf32_to_buf(code: u32, buf: &mut [u8; 4]) -> &str {
+    let len:usize = match classify_utf32(code) {
+        Utf8TypeEnum::Type1(v1) => {
+            buf[0] = v1;
+            1
+        }
+        Utf8TypeEnum::Type2((v1,v2)) => {
+            buf[0] = v1;
+            buf[1] = v2;
+            2
+        }
+        Utf8TypeEnum::Type3((v1,v2,v3)) | Utf8TypeEnum::Type0((v1,v2,v3)) => {
+            buf[0] = v1;
+            buf[1] = v2;
+            buf[2] = v3;
+            3
+        }
+        Utf8TypeEnum::Type4((v1,v2,v3,v4)) => {
+            * buf = [v1, v2, v3, v4];
+            4
+        }
+    };
+    let buf: &[u8; 4] = buf;
+    // classify_utf32() only produces valid UTF8 sequences.
+    core::str::from_utf8(buf.get(0 .. len).unwrap_or(&[])).unwrap_or("\u{FFFD}")
+}
 
-U+D000..U+D7FF      ED           80..9F       80..bf
->                   action 4     action 15    action (17)
+#[inline]
+/// Encode a char into a 4 byte buffer, and return the encoded part of
+/// the buffer as a string slice.
+///
+/// The replacement character policy of FromUnicode applies; the
+/// output is the same as char::encode_utf8().
+/// # Arguments
+/// * `ch` - the char to be encoded
+/// * `buf` - the output buffer
+pub fn encode_char_to_buf(ch: char, buf: &mut [u8; 4]) -> &str {
+    encode_utf32_to_buf(ch as u32, buf)
+}
 
-U+E000..U+FFFF      EE..EF       80..bf       80..bf
->                   action 5     action 11    action 20 (containing FFFD)
+/// Decode a complete UTF8 byte slice into chars.
+///
+/// Invalid sequences are substituted with replacement characters.  Use
+/// FromUtf8 for multiple buffers, or to check for invalid sequences.
+/// # Arguments
+/// * `input` - the complete UTF8 input
+pub fn decode_lossy(input: &[u8]) -> impl Iterator<Item = char> + Clone + '_ {
+    FromUtf8::new().utf8_into_char_iter(input.iter().copied())
+}
 
-U+10000..U+3FFFF    F0           90..bf       80..bf      80..bf
->                   action 6     action 16    action 21   action 24
+/// Decode a fixed size field, such as a firmware string or a packet
+/// field, up to its first NUL byte, or all of it when it has no NUL.
+///
+/// Returns the char iterator and the byte length of the string.
+/// Invalid sequences are substituted with replacement characters.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let field: [u8; 8] = *b"eth0\0\0\xFF\0";
+/// let (chars, len) = decode_nul_terminated(& field);
+/// assert_eq!(4, len);
+/// assert_eq!("eth0", chars.collect::<String>());
+/// ```
+/// # Arguments
+/// * `field` - the bytes of the field
+pub fn decode_nul_terminated(field: &[u8]) -> (impl Iterator<Item = char> + Clone + '_, usize) {
+    let len = field.iter().position(|v| * v == 0).unwrap_or(field.len());
+    let text = field.get(.. len).unwrap_or(field);
+    (decode_lossy(text), len)
+}
 
-U+40000..U+FFFFF    F1..F3       80..bf       80..bf      80..bf
->                   action 7     action 12    action (21) action (24)
+/// Decode a complete UTF8 input that must end on a char boundary, such
+/// as a length delimited protocol field.
+///
+/// An incomplete sequence at the end of the input is reported as a
+/// TruncatedInputError instead of becoming a replacement character.
+/// Other invalid sequences are substituted with replacement characters,
+/// the same as decode_lossy().
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let chars = decode_utf8_exact(b"a\xFFb").unwrap();
+/// assert_eq!("a\u{FFFD}b", chars.collect::<String>());
+/// let error = decode_utf8_exact(b"ab\xF0\x9F\x98").err().unwrap();
+/// assert_eq!(TruncatedInputError { offset: 2, len: 3 }, error);
+/// ```
+/// # Arguments
+/// * `input` - the complete UTF8 input
+pub fn decode_utf8_exact(input: &[u8])
+-> Result<impl Iterator<Item = char> + Clone + '_, TruncatedInputError> {
+    // Only a sequence starting in the last 3 bytes can be cut short, and
+    // it starts at the last byte that is not a continuation byte.
+    let tail_start = input.len().saturating_sub(3);
+    let lead_pos = input.iter().enumerate().skip(tail_start).rev()
+        .find(|(_indx, v)| (* * v & 0xC0) != 0x80)
+        .map(|(indx, _v)| indx);
+    if let Option::Some(offset) = lead_pos {
+        let tail = input.get(offset ..).unwrap_or(&[]);
+        let mut entries = [Utf8Diagnostic::default(); 1];
+        let mut parser = FromUtf8WithDiagnostics::new(& mut entries[..]);
+        let _ = parser.utf8_to_char(tail);
+        if (parser.num_reported() > 0) && (entries[0].kind == Utf8ErrorKind::Truncated)
+        && (entries[0].len as usize == tail.len()) {
+            return Result::Err(TruncatedInputError { offset, len: tail.len() });
+        }
+    }
+    Result::Ok(decode_lossy(input))
+}
 
-U+100000..U+10FFFF  F4           80..8F       80..bf      80..bf
->                   action 8     action 13    action (21) action (24)
+/// Decode only the char starting at the `n`th lead byte of `input`.
+///
+/// Chars are located by counting the bytes that are not continuation
+/// bytes, so only the target sequence is validated.  For valid UTF8 this
+/// is the same as `chars().nth(n)`; in invalid UTF8 a stray continuation
+/// byte is not counted as a char.  An invalid target sequence gives a
+/// replacement character.  'None' is returned when `input` has fewer
+/// than `n + 1` lead bytes.
+///
+/// Each call scans the prefix; use CharIndexBuilder for repeated access.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let bytes = "a\u{20AC}b".as_bytes();
+/// assert_eq!(Some('\u{20AC}'), char_at(bytes, 1));
+/// assert_eq!(Some('b'), char_at(bytes, 2));
+/// assert_eq!(None, char_at(bytes, 3));
+/// ```
+/// # Arguments
+/// * `input` - the complete UTF8 input
+/// * `n` - the position of the char
+pub fn char_at(input: &[u8], n: usize) -> Option<char> {
+    let (start, _v) = input.iter().enumerate()
+        .filter(|(_indx, v)| (* * v & 0xC0) != 0x80)
+        .nth(n)?;
+    let target = input.get(start ..)?;
+    let target = target.get(.. 4).unwrap_or(target);
+    FromUtf8::new().utf8_to_char(target).ok().map(|(_rest, ch)| ch)
+}
 
-> The action number with parenthesis are duplicated actions.
-> action 0: out = v1
-> action 1: out = v1 & ox1F;
-> action 2 to 5: out = v1 & 0xF;
-> action 6 to 8: out = v1 & 0x7;
-> action 9 to 13: out = (arg << 6)+(v2 & 0x3F)
-> action 14: out = (arg << 6)+(v2 & 0x3F)
-> action 15: out = (arg << 6)+(v2 & 0x3F)
-> action 16: out = (arg << 6)+(v2 & 0x3F)
-> action 17: out = (arg << 6)+(v3 & 0x3F)
-> action 20: out = (arg << 6)+(v3 & 0x3F)
-> action 21: out = (arg << 6)+(v3 & 0x3F)
-> action 24: out = (arg << 6)+(v4 & 0x3F)
->
->
-> If buffer is empty then it could be 'end of data' or need to signal
-> for more data.
->
-> We need to ensure the required number of bytes are available when
-> the first byte is checked.  Otherwise it is TypeUnknown. (partial data)
->
-> Different tituation when at the last buffer - we go in to process the
-> remaining bytes even when we could run out mid-stream.
-> This avoids a quote escaping attack, such as quote - F0 - quote - newline
+/// Encode a complete sequence of chars into UTF8 bytes.
+///
+/// Use FromUnicode for multiple buffers, or for UTF32 input.
+/// # Arguments
+/// * `input` - the chars to be encoded
+pub fn encode<I>(input: I) -> impl Iterator<Item = u8>
+where I: IntoIterator<Item = char>, {
+    FromUnicode::new().utf32_into_utf8_iter(input.into_iter().map(|v| v as u32))
+}
 
-*/
+/// number of chars decoded at a time by DisplayUtf8Lossy
+const DISPLAY_BOX_SIZE:usize = 32;
 
-use core::iter::Iterator;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Display wrapper for a complete UTF8 byte slice.
+///
+/// The bytes are decoded while formatting, with invalid sequences
+/// substituted with replacement characters, so no String is allocated.
+/// Width and precision of the format are not applied.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let bytes = [0x41u8, 0xE2u8, 0x82u8, 0xACu8, 0xFFu8];
+/// assert_eq!("A\u{20AC}\u{FFFD}", format!("{}", DisplayUtf8Lossy(& bytes)));
+/// ```
+pub struct DisplayUtf8Lossy<'a>(pub &'a [u8]);
+
+/// Display for DisplayUtf8Lossy
+impl fmt::Display for DisplayUtf8Lossy<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parser = FromUtf8::new();
+        let mut char_box = ['\0'; DISPLAY_BOX_SIZE];
+        let mut cur_slice = self.0;
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, num_chars)) = parser.utf8_to_char_slice(cur_slice, & mut char_box) {
+            cur_slice = slice_pos;
+            for ch in char_box.iter().take(num_chars) {
+                fmt::Write::write_char(f, * ch)?;
+            }
+        }
+        Result::Ok(())
+    }
+}
 
-use crate::utf8conv::buf::EightBytes;
+/// fmt::Write target over a byte buffer, keeping what fits
+struct SliceWriter<'a> {
 
+    /// the output buffer
+    my_buf: &'a mut [u8],
 
-// Action 9 and 10 are different; action 9 can be an end state, while
-// action 10 cannot.
+    /// number of bytes written
+    my_len: usize,
+}
 
-#[inline]
-/// Finite state machine action 9; expect 80 to bf
-fn byte2_action9(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
-    match mybuf.front() {
-        Option::Some(v) => {
-            let v2 = v as u32;
-            // println!("in action 9 with v2={:#02x}", v2);
-            if (v2 >= 0x80) && (v2 <= 0xbf) {
-                mybuf.pop_front(); // advance
-                Utf8EndEnum::Finish((arg << 6)+(v2 & 0x3F))
-            }
-            else {
-                // println!("not within 0x80 and 0xbf");
-                Utf8EndEnum::BadDecode(1)
-            }
+/// fmt::Write for SliceWriter
+impl fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let room = self.my_buf.len() - self.my_len;
+        let mut fit = s.len().min(room);
+        // Cut at a char boundary, so the output stays valid UTF8.
+        while ! s.is_char_boundary(fit) {
+            fit -= 1;
+        }
+        if let Option::Some(dest) = self.my_buf.get_mut(self.my_len .. self.my_len + fit) {
+            dest.copy_from_slice(s.as_bytes().get(0 .. fit).unwrap_or(&[]));
+            self.my_len += fit;
+        }
+        if fit < s.len() {
+            // Stop formatting; the rest cannot fit.
+            Result::Err(fmt::Error)
+        }
+        else {
+            Result::Ok(())
+        }
+    }
+}
+
+/// Format `value` into `buf` without allocation, and return the
+/// formatted part of the buffer.  A message too long for the buffer is
+/// cut short at a char boundary.
+/// # Arguments
+/// * `value` - the value to be formatted
+/// * `buf` - the output buffer
+pub(crate) fn format_into<'a, T>(value: &T, buf: &'a mut [u8]) -> &'a str
+where T: fmt::Display + ?Sized, {
+    let mut writer = SliceWriter { my_buf: buf, my_len: 0 };
+    // A full buffer ends formatting early; the text so far is kept.
+    let _ = fmt::Write::write_fmt(& mut writer, format_args!("{}", value));
+    let len = writer.my_len;
+    let buf: &'a [u8] = writer.my_buf;
+    // SliceWriter only cuts at char boundaries.
+    core::str::from_utf8(buf.get(0 .. len).unwrap_or(&[])).unwrap_or("")
+}
+
+// Two-phase conversions of complete inputs: a measuring pass computes
+// the exact output length, including replacement characters, and the
+// conversion then fills an output buffer of that length.  Encoding is
+// measured with utf8_len_of_slice().
+
+/// Measure the number of chars decoded from a complete UTF8 input.
+/// # Arguments
+/// * `input` - the complete UTF8 input
+pub fn measure_utf8_to_char(input: &[u8]) -> usize {
+    let mut parser = FromUtf8::new();
+    let mut char_box = ['\0'; 32];
+    let mut total:usize = 0;
+    let mut cur_slice = input;
+    // The loop stops at MoreEnum::More(_amt);
+    // _amt equals to 0 when end of data
+    while let Result::Ok((slice_pos, num_chars)) = parser.utf8_to_char_slice(cur_slice, & mut char_box) {
+        cur_slice = slice_pos;
+        total += num_chars;
+    }
+    total
+}
+
+/// Decode a complete UTF8 input into `output`.  Returns the number of
+/// chars written, or 'None' when `output` is too small for the whole
+/// input.
+/// # Arguments
+/// * `input` - the complete UTF8 input
+/// * `output` - the output buffer, sized with measure_utf8_to_char()
+pub fn convert_utf8_to_char(input: &[u8], output: &mut [char]) -> Option<usize> {
+    let mut parser = FromUtf8::new();
+    match parser.utf8_to_char_slice(input, output) {
+        Result::Ok((slice_pos, num_chars)) => {
+            // The output is full; any char left means it was too small.
+            if parser.utf8_to_char(slice_pos).is_ok() {
+                Option::None
+            }
+            else {
+                Option::Some(num_chars)
+            }
+        }
+        Result::Err(_) => {
+            // No char at all: the input was empty, or the output was
+            // empty with input left.
+            if input.is_empty() { Option::Some(0) } else { Option::None }
+        }
+    }
+}
+
+/// Encode a complete char or UTF32 input into `output`.  Returns the
+/// number of bytes written, or 'None' when `output` is too small for
+/// the whole input.
+/// # Arguments
+/// * `input` - a char slice or an UTF32 slice
+/// * `output` - the output buffer, sized with utf8_len_of_slice()
+pub fn convert_to_utf8<T: Copy + Into<u32>>(input: &[T], output: &mut [u8]) -> Option<usize> {
+    let mut encode_buf = [0u8; 4];
+    let mut pos:usize = 0;
+    for v in input.iter() {
+        let encoded = encode_utf32_to_buf((* v).into(), & mut encode_buf).as_bytes();
+        let end = pos + encoded.len();
+        output.get_mut(pos .. end)?.copy_from_slice(encoded);
+        pos = end;
+    }
+    Option::Some(pos)
+}
+
+// Worst-case output lengths for sizing the buffers of the bulk slice
+// APIs.  The lengths are for a whole input stream; a parser continuing
+// an incomplete sequence from an earlier buffer may produce one more
+// output char, or up to 3 more UTF8 bytes.  None is returned when the
+// length overflows usize.
+
+#[inline]
+/// Maximum number of UTF8 bytes produced from `n` chars or UTF32 values.
+/// # Arguments
+/// * `n` - the number of input chars
+pub const fn max_utf8_len_for_chars(n: usize) -> Option<usize> {
+    n.checked_mul(4)
+}
+
+#[inline]
+/// Maximum number of chars produced from `n` UTF8 bytes.  Each byte
+/// produces at most one char, including replacement characters.
+/// # Arguments
+/// * `n` - the number of input bytes
+pub const fn max_chars_for_utf8_len(n: usize) -> Option<usize> {
+    Option::Some(n)
+}
+
+#[inline]
+/// Maximum number of UTF16 code units produced from `n` UTF8 bytes.
+/// A 4 byte sequence produces a surrogate pair; every other byte
+/// produces at most one code unit.
+/// # Arguments
+/// * `n` - the number of input bytes
+pub const fn max_utf16_len_for_utf8(n: usize) -> Option<usize> {
+    Option::Some(n)
+}
+
+#[inline]
+/// Maximum number of UTF8 bytes produced from `n` UTF16 code units.
+/// A lone surrogate produces a 3 byte replacement character.
+/// # Arguments
+/// * `n` - the number of input code units
+pub const fn max_utf8_len_for_utf16(n: usize) -> Option<usize> {
+    n.checked_mul(3)
+}
+
+/// An output slot of the bulk parsers, either a value or an
+/// uninitialized MaybeUninit holding one.
+trait OutputSlot<T> {
+
+    /// Store `value` in this slot.
+    fn put(&mut self, value: T);
+}
+
+/// OutputSlot for initialized slots
+impl<T> OutputSlot<T> for T {
+    #[inline]
+    fn put(&mut self, value: T) {
+        * self = value;
+    }
+}
+
+/// OutputSlot for uninitialized slots
+impl<T> OutputSlot<T> for MaybeUninit<T> {
+    #[inline]
+    fn put(&mut self, value: T) {
+        self.write(value);
+    }
+}
+
+#[cfg(not(feature = "safe"))]
+#[inline]
+/// Returns the first `len` slots of `slots` as initialized values.
+/// # Arguments
+/// * `slots` - slots where the first `len` were written with put()
+/// * `len` - the number of written slots
+fn assume_init_prefix<T>(slots: &mut [MaybeUninit<T>], len: usize) -> &mut [T] {
+    let prefix = slots.get_mut(.. len).unwrap_or(&mut []);
+    // Unsafe is justified because the first `len` slots were written,
+    // and MaybeUninit<T> has the same layout as T.
+    unsafe { &mut * (prefix as * mut [MaybeUninit<T>] as * mut [T]) }
+}
+
+
+/*
+Technical notes written by Henri Sivonen, selectely quoted
+
+Unicode 9.0.0 (page 127) says: “An ill-formed subsequence consisting of more
+than one code unit could be treated as a single error or as multiple errors.
+For example, in processing the UTF-8 code unit sequence <F0 80 80 41>,
+the only formal requirement mandated by Unicode conformance for a converter
+is that the <41> be processed and correctly interpreted as <U+0041>.
+The converter could return <U+FFFD, U+0041>, handling <F0 80 80> as a single
+error, or <U+FFFD, U+FFFD, U+FFFD, U+0041>, handling each byte of <F0 80 80>
+as a separate error, or could take other approaches to signalling <F0 80 80>
+as an ill-formed code unit subsequence.” So as far as Unicode is concerned,
+any number from one to the number of bytes in the number of bogus bytes
+(inclusive) is OK. In other words, the precise number is
+implementation-defined as far as Unicode is concerned.
+
+> However, for the best compatibility with existing software, implementing
+> the conversion with a finite state machine was the typical approach.
+
+Code Points         First Byte   Second Byte  Third Byte  Fourth Byte
+U+0000..U+007F      00..7F
+>                   action 0
+
+U+0080..U+07FF      C2..DF       80..bf
+>                   action 1     action 9
+
+U+0800..U+0FFF      E0           A0..bf       80..bf
+>                   action 2     action 14    action 17
+
+U+1000..U+CFFF      E1..EC       80..bf       80..bf
+>                   action 3     action 10    action (17)
+
+U+D000..U+D7FF      ED           80..9F       80..bf
+>                   action 4     action 15    action (17)
+
+U+E000..U+FFFF      EE..EF       80..bf       80..bf
+>                   action 5     action 11    action 20 (containing FFFD)
+
+U+10000..U+3FFFF    F0           90..bf       80..bf      80..bf
+>                   action 6     action 16    action 21   action 24
+
+U+40000..U+FFFFF    F1..F3       80..bf       80..bf      80..bf
+>                   action 7     action 12    action (21) action (24)
+
+U+100000..U+10FFFF  F4           80..8F       80..bf      80..bf
+>                   action 8     action 13    action (21) action (24)
+
+> The action number with parenthesis are duplicated actions.
+> action 0: out = v1
+> action 1: out = v1 & ox1F;
+> action 2 to 5: out = v1 & 0xF;
+> action 6 to 8: out = v1 & 0x7;
+> action 9 to 13: out = (arg << 6)+(v2 & 0x3F)
+> action 14: out = (arg << 6)+(v2 & 0x3F)
+> action 15: out = (arg << 6)+(v2 & 0x3F)
+> action 16: out = (arg << 6)+(v2 & 0x3F)
+> action 17: out = (arg << 6)+(v3 & 0x3F)
+> action 20: out = (arg << 6)+(v3 & 0x3F)
+> action 21: out = (arg << 6)+(v3 & 0x3F)
+> action 24: out = (arg << 6)+(v4 & 0x3F)
+>
+>
+> If buffer is empty then it could be 'end of data' or need to signal
+> for more data.
+>
+> We need to ensure the required number of bytes are available when
+> the first byte is checked.  Otherwise it is TypeUnknown. (partial data)
+>
+> Different tituation when at the last buffer - we go in to process the
+> remaining bytes even when we could run out mid-stream.
+> This avoids a quote escaping attack, such as quote - F0 - quote - newline
+
+*/
+
+use core::fmt;
+use core::iter::Iterator;
+use core::mem::MaybeUninit;
+
+use crate::utf8conv::buf::EightBytes;
+use crate::utf8conv::diag::FromUtf8WithDiagnostics;
+use crate::utf8conv::diag::Utf8Diagnostic;
+
+
+// Action 9 and 10 are different; action 9 can be an end state, while
+// action 10 cannot.
+
+#[cfg_attr(not(feature = "small"), inline)]
+/// Finite state machine action 9; expect 80 to bf
+fn byte2_action9(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
+    match mybuf.front() {
+        Option::Some(v) => {
+            let v2 = v as u32;
+            // println!("in action 9 with v2={:#02x}", v2);
+            if (0x80 ..= 0xbf).contains(& v2) {
+                mybuf.pop_front(); // advance
+                Utf8EndEnum::Finish((arg << 6)+(v2 & 0x3F))
+            }
+            else {
+                // println!("not within 0x80 and 0xbf");
+                Utf8EndEnum::BadDecode(1)
+            }
         }
         Option::None => {
             // println!("TypeUnknown");
@@ -263,7 +1044,7 @@ fn byte2_action10(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
         Option::Some(v) => {
             let v2 = v as u32;
             // println!("in action 10 with v2={:#02x}", v2);
-            if (v2 >= 0x80) && (v2 <= 0xbf) {
+            if (0x80 ..= 0xbf).contains(& v2) {
                 mybuf.pop_front(); // advance
                 byte3_action17(mybuf, (arg << 6)+(v2 & 0x3F))
             }
@@ -286,7 +1067,7 @@ fn byte2_action11(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
         Option::Some(v) => {
             let v2 = v as u32;
             // println!("in action 10 with v2={:#02x}", v2);
-            if (v2 >= 0x80) && (v2 <= 0xbf) {
+            if (0x80 ..= 0xbf).contains(& v2) {
                 mybuf.pop_front(); // advance
                 byte3_action20(mybuf, (arg << 6)+(v2 & 0x3F))
             }
@@ -308,7 +1089,7 @@ fn byte2_action12(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
         Option::Some(v) => {
             let v2 = v as u32;
             // println!("in action 12 with v2={:#02x}", v2);
-            if (v2 >= 0x80) && (v2 <= 0xbf) {
+            if (0x80 ..= 0xbf).contains(& v2) {
                 mybuf.pop_front(); // advance
                 byte3_action21(mybuf, (arg << 6)+(v2 & 0x3F))
             }
@@ -330,7 +1111,7 @@ fn byte2_action13(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
         Option::Some(v) => {
             let v2 = v as u32;
             // println!("in action 13 with v2={:#02x}", v2);
-            if (v2 >= 0x80) && (v2 <= 0x8F) {
+            if (0x80 ..= 0x8F).contains(& v2) {
                 mybuf.pop_front(); // advance
                 byte3_action21(mybuf, (arg << 6)+(v2 & 0x3F))
             }
@@ -346,14 +1127,14 @@ fn byte2_action13(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
     }
 }
 
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 /// Finite state machine action 14; expect A0 to bf
 fn byte2_action14(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
     match mybuf.front() {
         Option::Some(v) => {
             let v2 = v as u32;
             // println!("in action 14 with v2={:#02x}", v2);
-            if (v2 >= 0xA0) && (v2 <= 0xbf) {
+            if (0xA0 ..= 0xbf).contains(& v2) {
                 mybuf.pop_front(); // advance
                 byte3_action17(mybuf, (arg << 6)+(v2 & 0x3F))
             }
@@ -375,7 +1156,7 @@ fn byte2_action15(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
         Option::Some(v) => {
             let v2 = v as u32;
             // println!("in action 15 with v2={:#02x}", v2);
-            if (v2 >= 0x80) && (v2 <= 0x9F) {
+            if (0x80 ..= 0x9F).contains(& v2) {
                 mybuf.pop_front(); // advance
                 byte3_action17(mybuf, (arg << 6)+(v2 & 0x3F))
             }
@@ -397,7 +1178,7 @@ fn byte2_action16(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
         Option::Some(v) => {
             let v2 = v as u32;
             // println!("in action 16 with v2={:#02x}", v2);
-            if (v2 >= 0x90) && (v2 <= 0xbf) {
+            if (0x90 ..= 0xbf).contains(& v2) {
                 mybuf.pop_front(); // advance
                 byte3_action21(mybuf, (arg << 6)+(v2 & 0x3F))
             }
@@ -413,14 +1194,14 @@ fn byte2_action16(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
     }
 }
 
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 /// Finite state machine action 17; expect 80 to bf
 fn byte3_action17(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
     match mybuf.front() {
         Option::Some(v) => {
             let v3 = v as u32;
             // println!("in action 17 with v3={:#02x}", v3);
-            if (v3 >= 0x80) && (v3 <= 0xbf) {
+            if (0x80 ..= 0xbf).contains(& v3) {
                 mybuf.pop_front(); // advance
                 Utf8EndEnum::Finish((arg << 6)+(v3 & 0x3F))
             }
@@ -436,7 +1217,7 @@ fn byte3_action17(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
     }
 }
 
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 /// Finite state machine action 20 expect 80 to bf
 /// Codepoint E000 to FFFF
 fn byte3_action20(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
@@ -444,7 +1225,7 @@ fn byte3_action20(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
         Option::Some(v) => {
             let v3 = v as u32;
             // println!("in action 20 with v3={:#02x}", v3);
-            if (v3 >= 0x80) && (v3 <= 0xbf) {
+            if (0x80 ..= 0xbf).contains(& v3) {
                 mybuf.pop_front(); // advance
                 let codepoint = (arg << 6) + (v3 & 0x3F);
                 if codepoint == REPLACE_UTF32 {
@@ -475,14 +1256,14 @@ fn byte3_action20(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
     }
 }
 
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 /// Finite state machine action 21; expect 80 to bf
 fn byte3_action21(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
     match mybuf.front() {
         Option::Some(v) => {
             let v3 = v as u32;
             // println!("in action 21 with v3={:#02x}", v3);
-            if (v3 >= 0x80) && (v3 <= 0xbf) {
+            if (0x80 ..= 0xbf).contains(& v3) {
                 mybuf.pop_front(); // advance
                 byte4_action24(mybuf, (arg << 6)+(v3 & 0x3F))
             }
@@ -498,14 +1279,14 @@ fn byte3_action21(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
     }
 }
 
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 /// Finite state machine action 24; expect 80 to bf
 fn byte4_action24(mybuf: & mut EightBytes, arg: u32) -> Utf8EndEnum {
     match mybuf.front() {
         Option::Some(v) => {
             let v4 = v as u32;
             // println!("in action 24 with v4={:#02x}", v4);
-            if (v4 >= 0x80) && (v4 <= 0xbf) {
+            if (0x80 ..= 0xbf).contains(& v4) {
                 mybuf.pop_front(); // advance
                 Utf8EndEnum::Finish((arg << 6)+(v4 & 0x3F))
             }
@@ -657,6 +1438,96 @@ pub fn utf8_decode(mybuf: & mut EightBytes, last_buffer: bool) -> Utf8EndEnum {
     }
 }
 
+#[inline]
+/// Same as utf8_decode(), and also returns the number of bytes
+/// consumed from 'mybuf' for the result.
+///
+/// Utf8EndEnum::Finish(_) consumes the whole sequence, and
+/// Utf8EndEnum::BadDecode(n) consumes n bytes.  Utf8EndEnum::TypeUnknown
+/// consumes no bytes, except on the last buffer, where the truncated
+/// sequence is consumed.
+///
+/// # Arguments
+///
+/// * `mybuf` - contains the bytes to be decoded
+///
+/// * `last_buffer` - is true when we are working on the last byte buffer.
+pub fn utf8_decode_with_len(mybuf: & mut EightBytes, last_buffer: bool) -> (Utf8EndEnum, u32) {
+    let start_len = mybuf.len();
+    let result = utf8_decode(mybuf, last_buffer);
+    (result, start_len - mybuf.len())
+}
+
+
+#[inline]
+/// Convert a codepoint from Utf8EndEnum::Finish() to a char.
+///
+/// With the "safe" feature the conversion is checked, and an
+/// unexpected value becomes a replacement character.
+#[cfg(not(feature = "safe"))]
+fn finished_code_to_char(code: u32) -> char {
+    // Unsafe is justified because utf8_decode() finite state
+    // machine checks for all cases of invalid decodes.
+    unsafe { char::from_u32_unchecked(code) }
+}
+
+#[inline]
+/// Checked variant of finished_code_to_char() for the "safe" feature
+#[cfg(feature = "safe")]
+fn finished_code_to_char(code: u32) -> char {
+    char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+#[cfg(not(feature = "small"))]
+/// high bit of every byte in a machine word
+const WORD_HIGH_BITS:usize = usize::from_ne_bytes([0x80u8; core::mem::size_of::<usize>()]);
+
+/// Find the position of the first byte that is 0x80 or greater.
+///
+/// Returning 'None' indicates the whole slice is ASCII.
+///
+/// The slice is scanned a block of bytes at a time, so long ASCII
+/// regions are skipped at close to memory bandwidth.  The "small"
+/// feature scans a byte at a time instead.
+/// # Arguments
+/// * `input` - the bytes to be scanned
+pub fn find_non_ascii(input: &[u8]) -> Option<usize> {
+    find_non_ascii_inline(input)
+}
+
+#[cfg(not(feature = "small"))]
+#[inline(always)]
+/// Body of find_non_ascii(), inlined into the ASCII fast path of the
+/// bulk parser
+fn find_non_ascii_inline(input: &[u8]) -> Option<usize> {
+    const BLOCK_LEN:usize = 32;
+    const WORD_LEN:usize = core::mem::size_of::<usize>();
+    let mut pos:usize = 0;
+    for block in input.chunks_exact(BLOCK_LEN) {
+        // A branch free fold over the block lets the compiler use
+        // vector instructions.
+        if block.iter().fold(0u8, |acc, v| acc | * v) >= 0x80 {
+            break;
+        }
+        pos += BLOCK_LEN;
+    }
+    for chunk in input.get(pos ..).unwrap_or(&[]).chunks_exact(WORD_LEN) {
+        let mut word_bytes = [0u8; WORD_LEN];
+        word_bytes.copy_from_slice(chunk);
+        if (usize::from_ne_bytes(word_bytes) & WORD_HIGH_BITS) != 0 {
+            // Locate the byte within this word.
+            break;
+        }
+        pos += WORD_LEN;
+    }
+    input.get(pos ..).unwrap_or(&[]).iter().position(|v| * v >= 0x80).map(|v| v + pos)
+}
+
+#[cfg(feature = "small")]
+/// Byte at a time variant of find_non_ascii_inline() for the "small" feature
+fn find_non_ascii_inline(input: &[u8]) -> Option<usize> {
+    input.iter().position(|v| * v >= 0x80)
+}
 
 /// Most iterators on arrays allocated on the stack returns a reference
 /// in order to save memory.  For our converter use-case this is a
@@ -684,22 +1555,20 @@ pub fn utf8_decode(mybuf: & mut EightBytes, last_buffer: bool) -> Utf8EndEnum {
 /// utf8 -> char
 ///
 /// char reference to char iterator struct
-pub struct CharRefToCharStruct<'b> {
+pub struct CharRefToCharStruct<'b, I: ?Sized = dyn Iterator<Item = &'b char> + 'b> {
 
     /// the source iterator
-    my_borrow_mut_iter: &'b mut dyn Iterator<Item = &'b char>,
+    my_borrow_mut_iter: &'b mut I,
 }
 
 /// an adapter iterator to convert a char reference iterator to char iterator
-impl<'b> Iterator for CharRefToCharStruct<'b> {
+impl<'b, 'c, I> Iterator for CharRefToCharStruct<'b, I>
+where I: Iterator<Item = &'c char> + ?Sized, {
     type Item=char;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        match self.my_borrow_mut_iter.next() {
-            Option::None => { Option::None }
-            Option::Some(v) => { Option::Some(* v) }
-        }
+        self.my_borrow_mut_iter.next().copied()
     }
 
     /// sizing hint for iterator, with a lower bound and optional upperbound
@@ -715,31 +1584,29 @@ impl<'b> Iterator for CharRefToCharStruct<'b> {
 ///
 /// * `input` - a mutable reference to a char reference iterator
 #[inline]
-pub fn char_ref_iter_to_char_iter<'a, I: 'a + Iterator>(input: &'a mut I)
--> CharRefToCharStruct<'a>
-where I: Iterator<Item = &'a char>, {
+pub fn char_ref_iter_to_char_iter<'a, 'c, I>(input: &'a mut I)
+-> CharRefToCharStruct<'a, I>
+where I: Iterator<Item = &'c char> + ?Sized, {
     CharRefToCharStruct {
         my_borrow_mut_iter: input,
     }
 }
 
 /// UTF32 reference to UTF32 iterator struct
-pub struct Utf32RefToUtf32Struct<'b> {
+pub struct Utf32RefToUtf32Struct<'b, I: ?Sized = dyn Iterator<Item = &'b u32> + 'b> {
 
     /// the source iterator
-    my_borrow_mut_iter: &'b mut dyn Iterator<Item = &'b u32>,
+    my_borrow_mut_iter: &'b mut I,
 }
 
 /// an adapter iterator to convert a UTF32 reference iterator to UTF32 iterator
-impl<'b> Iterator for Utf32RefToUtf32Struct<'b> {
+impl<'b, 'c, I> Iterator for Utf32RefToUtf32Struct<'b, I>
+where I: Iterator<Item = &'c u32> + ?Sized, {
     type Item=u32;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        match self.my_borrow_mut_iter.next() {
-            Option::None => { Option::None }
-            Option::Some(v) => { Option::Some(* v) }
-        }
+        self.my_borrow_mut_iter.next().copied()
     }
 
     /// sizing hint for iterator, with a lower bound and optional upperbound
@@ -755,31 +1622,29 @@ impl<'b> Iterator for Utf32RefToUtf32Struct<'b> {
 ///
 /// * `input` - a mutable reference to a UTF32 reference iterator
 #[inline]
-pub fn utf32_ref_iter_to_utf32_iter<'a, I: 'a + Iterator>(input: &'a mut I)
--> Utf32RefToUtf32Struct<'a>
-where I: Iterator<Item = &'a u32>, {
+pub fn utf32_ref_iter_to_utf32_iter<'a, 'c, I>(input: &'a mut I)
+-> Utf32RefToUtf32Struct<'a, I>
+where I: Iterator<Item = &'c u32> + ?Sized, {
     Utf32RefToUtf32Struct {
         my_borrow_mut_iter: input,
     }
 }
 
 /// UTF8 reference to UTF8 iterator struct
-pub struct Utf8RefToUtf8Struct<'b> {
+pub struct Utf8RefToUtf8Struct<'b, I: ?Sized = dyn Iterator<Item = &'b u8> + 'b> {
 
     /// the source iterator
-    my_borrow_mut_iter: &'b mut dyn Iterator<Item = &'b u8>,
+    my_borrow_mut_iter: &'b mut I,
 }
 
 /// an adapter iterator to convert a UTF8 reference iterator to UTF8 iterator
-impl<'b> Iterator for Utf8RefToUtf8Struct<'b> {
+impl<'b, 'c, I> Iterator for Utf8RefToUtf8Struct<'b, I>
+where I: Iterator<Item = &'c u8> + ?Sized, {
     type Item=u8;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        match self.my_borrow_mut_iter.next() {
-            Option::None => { Option::None }
-            Option::Some(v) => { Option::Some(* v) }
-        }
+        self.my_borrow_mut_iter.next().copied()
     }
 
     /// sizing hint for iterator, with a lower bound and optional upperbound
@@ -795,31 +1660,29 @@ impl<'b> Iterator for Utf8RefToUtf8Struct<'b> {
 ///
 /// * `input` - a mutable reference to a UTF8 reference iterator
 #[inline]
-pub fn utf8_ref_iter_to_utf8_iter<'a, I: 'a + Iterator>(input: &'a mut I)
--> Utf8RefToUtf8Struct<'a>
-where I: Iterator<Item = &'a u8>, {
+pub fn utf8_ref_iter_to_utf8_iter<'a, 'c, I>(input: &'a mut I)
+-> Utf8RefToUtf8Struct<'a, I>
+where I: Iterator<Item = &'c u8> + ?Sized, {
     Utf8RefToUtf8Struct {
         my_borrow_mut_iter: input,
     }
 }
 
 /// char to UTF32 iterator struct
-pub struct CharToUtf32Struct<'b> {
+pub struct CharToUtf32Struct<'b, I: ?Sized = dyn Iterator<Item = char> + 'b> {
 
     /// the source iterator
-    my_borrow_mut_iter: &'b mut dyn Iterator<Item = char>,
+    my_borrow_mut_iter: &'b mut I,
 }
 
 /// an adapter iterator to convert a char iterator to UTF32 iterator
-impl<'b> Iterator for CharToUtf32Struct<'b> {
+impl<'b, I> Iterator for CharToUtf32Struct<'b, I>
+where I: Iterator<Item = char> + ?Sized, {
     type Item=u32;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        match self.my_borrow_mut_iter.next() {
-            Option::None => { Option::None }
-            Option::Some(v) => { Option::Some(v as u32) }
-        }
+        self.my_borrow_mut_iter.next().map(|v| v as u32)
     }
 
     /// sizing hint for iterator, with a lower bound and optional upperbound
@@ -835,21 +1698,57 @@ impl<'b> Iterator for CharToUtf32Struct<'b> {
 ///
 /// * `input` - a mutable reference to a char iterator
 #[inline]
-pub fn char_iter_to_utf32_iter<'a, I: 'a + Iterator>(input: &'a mut I)
--> CharToUtf32Struct<'a>
-where I: Iterator<Item = char>, {
+pub fn char_iter_to_utf32_iter<'a, I>(input: &'a mut I)
+-> CharToUtf32Struct<'a, I>
+where I: Iterator<Item = char> + ?Sized, {
     CharToUtf32Struct {
         my_borrow_mut_iter: input,
     }
 }
 
+/// string slice to UTF32 iterator struct
+#[derive(Debug, Clone)]
+pub struct StrToUtf32Struct<'b> {
+
+    /// the source char iterator
+    my_chars: core::str::Chars<'b>,
+}
+
+/// an adapter iterator to convert a string slice to UTF32 iterator
+impl<'b> Iterator for StrToUtf32Struct<'b> {
+    type Item=u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.my_chars.next().map(|v| v as u32)
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.my_chars.size_hint()
+    }
+}
+
+/// Function str_to_utf32_iter() takes a string slice, and return
+/// an UTF32 iterator over its chars.
+///
+/// # Arguments
+///
+/// * `input` - a string slice
+#[inline]
+pub fn str_to_utf32_iter(input: &str) -> StrToUtf32Struct<'_> {
+    StrToUtf32Struct {
+        my_chars: input.chars(),
+    }
+}
+
 /// BomAndCarriageReturnFilterStruct contains states tracking
 /// iterator filtering states of Byte Order Mark, and Carriage Return
 /// characters.
-pub struct BomAndCarriageReturnFilterStruct<'b> {
+pub struct BomAndCarriageReturnFilterStruct<'b, I: ?Sized = dyn Iterator<Item = char> + 'b> {
 
     /// the source iterator
-    my_borrow_mut_iter: &'b mut dyn Iterator<Item = char>,
+    my_borrow_mut_iter: &'b mut I,
 
     /// true at the start of iterator stream
     my_start_stream: bool,
@@ -858,8 +1757,36 @@ pub struct BomAndCarriageReturnFilterStruct<'b> {
     my_prev_cr: bool,
 }
 
+/// Implementation of BomAndCarriageReturnFilterStruct
+impl<'b, I: ?Sized> BomAndCarriageReturnFilterStruct<'b, I> {
+
+    /// Signal that the next char from the source iterator starts a new
+    /// file, for streams that concatenate several files.
+    ///
+    /// A Byte Order Mark right after the boundary is removed, the same as
+    /// at the start of the stream.  A carriage return at the end of the
+    /// previous file does not join with a newline at the start of the
+    /// next file.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut chars = "a\r\u{FEFF}\nb".chars();
+    /// let mut filter_iter = filter_bom_and_cr_iter(& mut chars);
+    /// let first: String = filter_iter.by_ref().take(2).collect();
+    /// filter_iter.start_new_file();
+    /// let second: String = filter_iter.collect();
+    /// assert_eq!(("a\n", "\nb"), (first.as_str(), second.as_str()));
+    /// ```
+    pub fn start_new_file(&mut self) {
+        self.my_start_stream = true;
+        self.my_prev_cr = false;
+    }
+}
+
 /// an adapter iterator to filter BOM and Carriage Return characters
-impl<'b> Iterator for BomAndCarriageReturnFilterStruct<'b> {
+impl<'b, I> Iterator for BomAndCarriageReturnFilterStruct<'b, I>
+where I: Iterator<Item = char> + ?Sized, {
     type Item=char;
 
     #[inline]
@@ -928,9 +1855,9 @@ impl<'b> Iterator for BomAndCarriageReturnFilterStruct<'b> {
 ///
 /// * `input` - a mutable reference to a char iterator
 #[inline]
-pub fn filter_bom_and_cr_iter<'a, I: 'a + Iterator>(input: &'a mut I)
--> BomAndCarriageReturnFilterStruct<'a>
-where I: Iterator<Item = char>, {
+pub fn filter_bom_and_cr_iter<'a, I>(input: &'a mut I)
+-> BomAndCarriageReturnFilterStruct<'a, I>
+where I: Iterator<Item = char> + ?Sized, {
     BomAndCarriageReturnFilterStruct {
         my_borrow_mut_iter: input,
         my_start_stream: true,
@@ -938,55 +1865,379 @@ where I: Iterator<Item = char>, {
     }
 }
 
-/// Common operations for UTF conversion parsers
-pub trait UtfParserCommon {
-
-    /// Reset all parser states to the initial value.
-    /// Last buffer indication is set to true.
-    /// Invalid decodes indication is cleared.
-    fn reset_parser(&mut self);
-
-    /// If argument `b` is true, then any input buffer to be presented will
-    /// be the last buffer.
-    fn set_is_last_buffer(&mut self, b: bool);
-
-    /// Returns the last input buffer flag.
-    fn is_last_buffer(&self) -> bool;
-
-    /// This function signals the occurrence of an invalid conversion sequence.
-    fn signal_invalid_sequence(& mut self);
-
-    /// This function returns true if invalid conversion sequence occurred
-    /// in this parsing stream.
-    fn has_invalid_sequence(&self) -> bool;
+/// char to char array iterator struct
+pub struct CharToCharChunkStruct<'b, const N: usize, I: ?Sized = dyn Iterator<Item = char> + 'b> {
 
-    /// This function resets the invalid sequence state.
-    fn reset_invalid_sequence(& mut self);
+    /// the source iterator
+    my_borrow_mut_iter: &'b mut I,
 }
 
-/// Provides conversion functions from UTF8 to char or UTF32
-#[derive(Debug, Clone, Copy)]
-pub struct FromUtf8 {
+/// an adapter iterator to group a char iterator into arrays of N chars
+///
+/// Each item is an array together with its fill count.  The fill count
+/// is N for every array except possibly the last one; unused tail
+/// elements of the last array are set to '\0'.
+impl<'b, const N: usize, I> Iterator for CharToCharChunkStruct<'b, N, I>
+where I: Iterator<Item = char> + ?Sized, {
+    type Item=([char; N], usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk: [char; N] = ['\0'; N];
+        let mut fill: usize = 0;
+        for slot in chunk.iter_mut() {
+            match self.my_borrow_mut_iter.next() {
+                Option::Some(v) => {
+                    * slot = v;
+                    fill += 1;
+                }
+                Option::None => {
+                    break;
+                }
+            }
+        }
+        if fill == 0 {
+            Option::None
+        }
+        else {
+            Option::Some((chunk, fill))
+        }
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.my_borrow_mut_iter.size_hint();
+        if N == 0 {
+            return (0, Option::Some(0));
+        }
+        (lower.div_ceil(N), upper.map(|v| v.div_ceil(N)))
+    }
+}
+
+/// Function char_iter_to_char_chunk_iter() takes a mutable reference to
+/// a char iterator, and return an iterator of char arrays in its place.
+///
+/// Each output item is a tuple of a `[char; N]` array and the number of
+/// chars filled in it.  Only the last array may be partially filled.
+/// An N of 0 yields no items.
+///
+/// # Arguments
+///
+/// * `input` - a mutable reference to a char iterator
+#[inline]
+pub fn char_iter_to_char_chunk_iter<'a, I, const N: usize>(input: &'a mut I)
+-> CharToCharChunkStruct<'a, N, I>
+where I: Iterator<Item = char> + ?Sized, {
+    CharToCharChunkStruct {
+        my_borrow_mut_iter: input,
+    }
+}
+
+/// char to string slice adapter struct
+pub struct CharToStrStruct<'b, I: ?Sized = dyn Iterator<Item = char> + 'b> {
+
+    /// UTF8 bytes of the current char
+    my_buf: [u8; 4],
+
+    /// the source iterator
+    my_borrow_mut_iter: &'b mut I,
+}
+
+/// Implementation of CharToStrStruct
+impl<'b, I> CharToStrStruct<'b, I>
+where I: Iterator<Item = char> + ?Sized, {
+
+    #[inline]
+    /// Returns the next char as a string slice, or 'None' at the end of
+    /// the source iterator.
+    ///
+    /// The slice borrows a 4 byte buffer inside this adapter, so it is
+    /// valid until the next call.
+    pub fn next_str(&mut self) -> Option<&str> {
+        let ch = self.my_borrow_mut_iter.next()?;
+        Option::Some(encode_char_to_buf(ch, & mut self.my_buf))
+    }
+
+    /// sizing hint for the number of string slices remaining, with a
+    /// lower bound and optional upperbound
+    pub fn size_hint(&self) -> (usize, Option<usize>) {
+        self.my_borrow_mut_iter.size_hint()
+    }
+}
+
+/// Function char_iter_to_str_iter() takes a mutable reference to a char
+/// iterator, and returns an adapter in its place that hands out each
+/// char as a `&str`, for APIs that take string slices.
+///
+/// The adapter cannot be an Iterator, because every slice borrows the
+/// adapter; call next_str() in a loop instead.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut chars = decode_lossy(& [0x61u8, 0xE2u8, 0x82u8, 0xACu8]);
+/// let mut str_iter = char_iter_to_str_iter(& mut chars);
+/// assert_eq!(Some("a"), str_iter.next_str());
+/// assert_eq!(Some("\u{20AC}"), str_iter.next_str());
+/// assert_eq!(None, str_iter.next_str());
+/// ```
+///
+/// # Arguments
+///
+/// * `input` - a mutable reference to a char iterator
+#[inline]
+pub fn char_iter_to_str_iter<'a, I>(input: &'a mut I) -> CharToStrStruct<'a, I>
+where I: Iterator<Item = char> + ?Sized, {
+    CharToStrStruct {
+        my_buf: [0; 4],
+        my_borrow_mut_iter: input,
+    }
+}
+
+/// Common operations for UTF conversion parsers
+pub trait UtfParserCommon {
+
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    fn reset_parser(&mut self);
+
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool);
+
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool;
+
+    /// This function signals the occurrence of an invalid conversion sequence.
+    fn signal_invalid_sequence(& mut self);
+
+    /// This function returns true if invalid conversion sequence occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool;
+
+    /// This function resets the invalid sequence state.
+    fn reset_invalid_sequence(& mut self);
+}
+
+/// A decoder converts a stream of code units into chars.
+///
+/// The trait is object safe, so a decoder selected at run time can be
+/// stored as `Box<dyn Decoder<Unit = u8>>` or `&mut dyn Decoder<Unit = u8>`.
+///
+/// Input is presented one buffer at a time, as with the parser slice
+/// APIs.  `MoreEnum::More(n)` with n greater than 0 asks for the next
+/// buffer; `MoreEnum::More(0)` indicates end of data.
+pub trait Decoder: UtfParserCommon {
+
+    /// The code unit type being decoded
+    type Unit: Copy;
+
+    /// Decode one char from `input`.  Returns the remaining input and
+    /// the char, or an MoreEnum when no char can be produced.
+    fn decode_char<'b>(&mut self, input: &'b [Self::Unit])
+    -> Result<(&'b [Self::Unit], char), MoreEnum>;
+
+    /// Decode chars from `input` into `output`.  Returns the remaining
+    /// input and the number of chars written, or an MoreEnum when not a
+    /// single char could be written.
+    fn decode_to_slice<'b>(&mut self, input: &'b [Self::Unit], output: &mut [char])
+    -> Result<(&'b [Self::Unit], usize), MoreEnum> {
+        let mut my_cursor = input;
+        let mut written: usize = 0;
+        for slot in output.iter_mut() {
+            match self.decode_char(my_cursor) {
+                Result::Ok((slice_pos, ch)) => {
+                    * slot = ch;
+                    written += 1;
+                    my_cursor = slice_pos;
+                }
+                Result::Err(en) => {
+                    if written == 0 {
+                        return Result::Err(en);
+                    }
+                    break;
+                }
+            }
+        }
+        Result::Ok((my_cursor, written))
+    }
+
+    /// Signal end of data, and return the next char left over in
+    /// the decoder.  Returns 'None' once the decoder is drained.
+    ///
+    /// An incomplete sequence at end of data is returned as a
+    /// replacement character.
+    fn finish(&mut self) -> Option<char> {
+        self.set_is_last_buffer(true);
+        self.decode_char(& []).ok().map(|(_, ch)| ch)
+    }
+}
+
+/// An encoder converts a stream of chars into code units.
+///
+/// The trait is object safe, so an encoder selected at run time can be
+/// stored as `Box<dyn Encoder<Unit = u8>>` or `&mut dyn Encoder<Unit = u8>`.
+///
+/// Input is presented one buffer at a time, with the same MoreEnum
+/// conditions as Decoder.
+pub trait Encoder: UtfParserCommon {
+
+    /// The code unit type being produced
+    type Unit: Copy;
+
+    /// Produce one code unit from `input`.  Returns the remaining input
+    /// and the code unit, or an MoreEnum when no code unit can be produced.
+    fn encode_char<'b>(&mut self, input: &'b [char])
+    -> Result<(&'b [char], Self::Unit), MoreEnum>;
+
+    /// Encode chars from `input` into `output`.  Returns the remaining
+    /// input and the number of code units written, or an MoreEnum when
+    /// not a single code unit could be written.
+    fn encode_to_slice<'b>(&mut self, input: &'b [char], output: &mut [Self::Unit])
+    -> Result<(&'b [char], usize), MoreEnum> {
+        let mut my_cursor = input;
+        let mut written: usize = 0;
+        for slot in output.iter_mut() {
+            match self.encode_char(my_cursor) {
+                Result::Ok((slice_pos, unit)) => {
+                    * slot = unit;
+                    written += 1;
+                    my_cursor = slice_pos;
+                }
+                Result::Err(en) => {
+                    if written == 0 {
+                        return Result::Err(en);
+                    }
+                    break;
+                }
+            }
+        }
+        Result::Ok((my_cursor, written))
+    }
+
+    /// Signal end of data, and return the next code unit left over in
+    /// the encoder.  Returns 'None' once the encoder is drained.
+    fn finish(&mut self) -> Option<Self::Unit> {
+        self.set_is_last_buffer(true);
+        self.encode_char(& []).ok().map(|(_, unit)| unit)
+    }
+}
+
+/// A source of chars for the encoders.
+///
+/// Implemented for string slices, char slices, UTF32 slices, and
+/// iterators wrapped in IterSource.  A slice source is advanced past
+/// the chars taken, so the remaining input is left in place.
+pub trait CharSource {
+
+    /// Take the next char as an UTF32 value.  Returns 'None' when the
+    /// source is exhausted.
+    fn next_utf32(&mut self) -> Option<u32>;
+}
+
+/// CharSource for string slices
+impl CharSource for &str {
+    #[inline]
+    fn next_utf32(&mut self) -> Option<u32> {
+        let mut chars = self.chars();
+        let ch = chars.next()?;
+        * self = chars.as_str();
+        Option::Some(ch as u32)
+    }
+}
+
+/// CharSource for char slices
+impl CharSource for &[char] {
+    #[inline]
+    fn next_utf32(&mut self) -> Option<u32> {
+        let (v, rest) = self.split_first()?;
+        * self = rest;
+        Option::Some(* v as u32)
+    }
+}
+
+/// CharSource for UTF32 slices
+impl CharSource for &[u32] {
+    #[inline]
+    fn next_utf32(&mut self) -> Option<u32> {
+        let (v, rest) = self.split_first()?;
+        * self = rest;
+        Option::Some(* v)
+    }
+}
+
+/// CharSource wrapper for an iterator of chars or UTF32 values
+#[derive(Debug, Clone)]
+pub struct IterSource<I>(pub I);
+
+/// CharSource for iterators of chars or UTF32 values
+impl<I> CharSource for IterSource<I>
+where I: Iterator, I::Item: Into<u32>, {
+    #[inline]
+    fn next_utf32(&mut self) -> Option<u32> {
+        self.0.next().map(|v| v.into())
+    }
+}
+
+/// A char mapping function of FromUtf8, compared and hashed by address
+#[derive(Debug, Clone, Copy)]
+struct CharMap(fn(char) -> Option<char>);
+
+/// PartialEq for CharMap
+impl PartialEq for CharMap {
+    fn eq(&self, other: &CharMap) -> bool {
+        (self.0 as usize) == (other.0 as usize)
+    }
+}
+
+/// Eq for CharMap
+impl Eq for CharMap {}
+
+/// Hash for CharMap
+impl core::hash::Hash for CharMap {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        (self.0 as usize).hash(state);
+    }
+}
+
+/// Provides conversion functions from UTF8 to char or UTF32
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FromUtf8 {
     my_buf: EightBytes,
     my_last_buffer: bool,
     my_invalid_sequence: bool,
+    my_overlong_tolerant: bool,
+    my_char_map: Option<CharMap>,
+    my_refill: bool,
+    my_bytes_in: u64,
+    my_chars_out: u64,
+    my_decode_errors: u64,
+    my_encoded_replacements: u64,
+    my_ascii_so_far: bool,
 }
 
-/// Provides conversion functions from char or UTF32 to UTF8
-#[derive(Debug, Clone, Copy)]
+/// Provides conversion functions from char or UTF32 to UTF8 or UTF16.
+/// A stream is encoded to one of the two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct FromUnicode {
     my_buf: EightBytes,
+    my_low_surrogate: Option<u16>,
     my_last_buffer: bool,
     my_invalid_sequence: bool,
+    my_chars_in: u64,
+    my_bytes_encoded: u64,
+    my_strict: bool,
+    my_strict_error: Option<StrictEncodeError>,
 }
 
 /// adapter iterator converting from an UTF8 iterator to a char iterator
 /// (This iterator contains a mutable borrow to the launching
 /// FromUtf8 object while this iterator is alive.)
-pub struct Utf8IterToCharIter<'p> {
+pub struct Utf8IterToCharIter<'p, I: ?Sized = dyn Iterator<Item = u8> + 'p> {
 
     /// the source iterator
-    my_borrow_mut_iter: &'p mut dyn Iterator<Item = u8>,
+    my_borrow_mut_iter: &'p mut I,
 
     /// mutable reference to FromUtf8 object
     my_info: &'p mut FromUtf8,
@@ -995,10 +2246,10 @@ pub struct Utf8IterToCharIter<'p> {
 /// adapter iterator converting from an UTF32 iterator to an UTF8 iterator
 /// (This iterator contains a mutable borrow to the launching
 /// FromUnicode object while this iterator is alive.)
-pub struct Utf32IterToUtf8Iter<'q> {
+pub struct Utf32IterToUtf8Iter<'q, I: ?Sized = dyn Iterator<Item = u32> + 'q> {
 
     /// the source iterator
-    my_borrow_mut_iter: &'q mut dyn Iterator<Item = u32>,
+    my_borrow_mut_iter: &'q mut I,
 
     /// mutable reference to FromUnicode object
     my_info: &'q mut FromUnicode,
@@ -1007,10 +2258,10 @@ pub struct Utf32IterToUtf8Iter<'q> {
 /// adapter iterator converting from an UTF8 reference iterator to char iterator
 /// (This iterator contains a mutable borrow to the launching
 /// FromUtf8 object while this iterator is alive.)
-pub struct Utf8RefIterToCharIter<'r> {
+pub struct Utf8RefIterToCharIter<'r, I: ?Sized = dyn Iterator<Item = &'r u8> + 'r> {
 
     /// the source iterator
-    my_borrow_mut_iter: &'r mut dyn Iterator<Item = &'r u8>,
+    my_borrow_mut_iter: &'r mut I,
 
     /// mutable reference to FromUtf8 object
     my_info: &'r mut FromUtf8,
@@ -1019,17 +2270,82 @@ pub struct Utf8RefIterToCharIter<'r> {
 /// adapter iterator converting from a char reference iterator to an UTF8 iterator
 /// (This iterator contains a mutable borrow to the launching
 /// FromUnicode object while this iterator is alive.)
-pub struct CharRefIterToUtf8Iter<'s> {
+pub struct CharRefIterToUtf8Iter<'s, I: ?Sized = dyn Iterator<Item = &'s char> + 's> {
+
+    /// the source iterator
+    my_borrow_mut_iter: &'s mut I,
+
+    /// mutable reference to FromUnicode object
+    my_info: &'s mut FromUnicode,
+}
+
+/// adapter iterator converting from an UTF8 iterator to an UTF16 iterator
+/// (This iterator contains a mutable borrow to the launching
+/// FromUtf8 object while this iterator is alive.)
+pub struct Utf8IterToUtf16Iter<'p, I: ?Sized = dyn Iterator<Item = u8> + 'p> {
+
+    /// the low surrogate of a surrogate pair, waiting to be returned
+    my_low_surrogate: Option<u16>,
+
+    /// the source iterator
+    my_borrow_mut_iter: &'p mut I,
+
+    /// mutable reference to FromUtf8 object
+    my_info: &'p mut FromUtf8,
+}
+
+/// adapter iterator converting from an UTF32 iterator to an UTF16 iterator
+/// (This iterator contains a mutable borrow to the launching
+/// FromUnicode object while this iterator is alive.)
+pub struct Utf32IterToUtf16Iter<'q, I: ?Sized = dyn Iterator<Item = u32> + 'q> {
+
+    /// the source iterator
+    my_borrow_mut_iter: &'q mut I,
+
+    /// mutable reference to FromUnicode object
+    my_info: &'q mut FromUnicode,
+}
+
+/// adapter iterator converting from a char reference iterator to an UTF16 iterator
+/// (This iterator contains a mutable borrow to the launching
+/// FromUnicode object while this iterator is alive.)
+pub struct CharRefIterToUtf16Iter<'s, I: ?Sized = dyn Iterator<Item = &'s char> + 's> {
 
     /// the source iterator
-    my_borrow_mut_iter: &'s mut dyn Iterator<Item = &'s char>,
+    my_borrow_mut_iter: &'s mut I,
 
     /// mutable reference to FromUnicode object
     my_info: &'s mut FromUnicode,
 }
 
+/// adapter iterator converting from an UTF8 iterator to a char iterator
+/// (This iterator owns its FromUtf8 parser and its source iterator,
+/// and can be cloned when the source iterator can be cloned.)
+#[derive(Clone)]
+pub struct Utf8IntoCharIter<I> {
+
+    /// the source iterator
+    my_iter: I,
+
+    /// the FromUtf8 parser
+    my_info: FromUtf8,
+}
+
+/// adapter iterator converting from an UTF32 iterator to an UTF8 iterator
+/// (This iterator owns its FromUnicode parser and its source iterator,
+/// and can be cloned when the source iterator can be cloned.)
+#[derive(Clone)]
+pub struct Utf32IntoUtf8Iter<I> {
+
+    /// the source iterator
+    my_iter: I,
+
+    /// the FromUnicode parser
+    my_info: FromUnicode,
+}
+
 /// Implementations of common operations for FromUtf8
-impl<'b> UtfParserCommon for FromUtf8 {
+impl UtfParserCommon for FromUtf8 {
 
     #[inline]
     /// If argument `b` is true, then any input buffer to be presented will
@@ -1067,17 +2383,19 @@ impl<'b> UtfParserCommon for FromUtf8 {
     /// Reset all parser states to the initial value.
     /// Last buffer indication is set to true.
     /// Invalid decodes indication is cleared.
+    /// Throughput counters are cleared.
     fn reset_parser(&mut self) {
         // Drain our buffer.
         self.my_buf.clear();
         self.set_is_last_buffer(true);
         self.reset_invalid_sequence();
+        self.reset_counters();
     }
 
 }
 
 /// Implementations of common operations for FromUnicode
-impl<'b> UtfParserCommon for FromUnicode {
+impl UtfParserCommon for FromUnicode {
 
     #[inline]
     /// If argument `b` is true, then any input buffer to be presented will
@@ -1106,22 +2424,59 @@ impl<'b> UtfParserCommon for FromUnicode {
     }
 
     #[inline]
-    /// This function resets the invalid sequence state.
+    /// This function resets the invalid sequence state, and clears the
+    /// strict mode error.
     fn reset_invalid_sequence(&mut self) {
         self.my_invalid_sequence = false;
+        self.my_strict_error = Option::None;
     }
 
     #[inline]
     /// Reset all parser states to the initial value.
     /// Last buffer indication is set to true.
     /// Invalid sequence indication is cleared.
+    /// Throughput counters are cleared.
+    /// Strict mode is kept.
     fn reset_parser(&mut self) {
         // Drain our buffer.
         self.my_buf.clear();
+        self.my_low_surrogate = Option::None;
         self.set_is_last_buffer(true);
         self.reset_invalid_sequence();
+        self.reset_counters();
+    }
+
+}
+
+/// Decoder implementation of FromUtf8
+impl Decoder for FromUtf8 {
+    type Unit = u8;
+
+    #[inline]
+    /// Same as utf8_to_char()
+    fn decode_char<'b>(&mut self, input: &'b [u8])
+    -> Result<(&'b [u8], char), MoreEnum> {
+        self.utf8_to_char(input)
+    }
+
+    #[inline]
+    /// Same as utf8_to_char_slice()
+    fn decode_to_slice<'b>(&mut self, input: &'b [u8], output: &mut [char])
+    -> Result<(&'b [u8], usize), MoreEnum> {
+        self.utf8_to_char_slice(input, output)
     }
+}
+
+/// Encoder implementation of FromUnicode, producing UTF8
+impl Encoder for FromUnicode {
+    type Unit = u8;
 
+    #[inline]
+    /// Same as char_to_utf8()
+    fn encode_char<'b>(&mut self, input: &'b [char])
+    -> Result<(&'b [char], u8), MoreEnum> {
+        self.char_to_utf8(input)
+    }
 }
 
 /// Map a char parsing result to a UTF32 parsing result.
@@ -1142,89 +2497,526 @@ impl FromUtf8 {
             my_buf : EightBytes::new(),
             my_last_buffer : true,
             my_invalid_sequence : false,
+            my_overlong_tolerant : false,
+            my_char_map : Option::None,
+            my_refill : false,
+            my_bytes_in : 0,
+            my_chars_out : 0,
+            my_decode_errors : 0,
+            my_encoded_replacements : 0,
+            my_ascii_so_far : true,
         }
     }
 
-    /// A parser takes in byte slice, and returns a Result object with
-    /// either the remaining input and the output char value, or an MoreEnum
-    /// that requests additional data, or an end of data stream condition.
-    ///
-    /// Invalid UTF8 decodes are indicated by Unicode replacement characters.
-    /// has_invalid_decodes() would return true after this event.
-    /// Encountering a replacement character is considered the same as having
-    /// an invalid decode.
-    pub fn utf8_to_char<'b>(&mut self, input: &'b [u8])
-    -> Result<(&'b [u8], char), MoreEnum> {
-        let mut my_cursor: &[u8] = input;
-        let last_buffer = self.my_last_buffer;
-        // Fill buffer phase.
-        loop {
-            if self.my_buf.is_full() || (my_cursor.len() == 0) {
-                break;
-            }
-            // Push a u8, and advance input position.
-            self.my_buf.push_back(my_cursor[0]);
-            my_cursor = &my_cursor[1..];
-        }
-        if self.my_buf.is_empty() {
-            // Processing for buffer being empty case
-            // Determine if we are at end of data.
-            if last_buffer {
-                // at end of data condition
-                Result::Err(MoreEnum::More(0))
-            }
-            else {
-                // Returning an indication to request a new buffer.
-                Result::Err(MoreEnum::More(4096))
-            }
-        }
-        else {
-            match utf8_decode(& mut self.my_buf, last_buffer) {
-                Utf8EndEnum::BadDecode(_) => {
-                    self.signal_invalid_sequence();
-                    Result::Ok((my_cursor, char::REPLACEMENT_CHARACTER))
-                }
-                Utf8EndEnum::Finish(code) => {
-                    // Unsafe is justified because utf8_decode() finite state
-                    // machine checks for all cases of invalid decodes.
-                    let ch = unsafe { char::from_u32_unchecked(code) };
-                    Result::Ok((my_cursor, ch))
-                }
-                Utf8EndEnum::TypeUnknown => {
-                    // Insufficient data to decode.
-                    if last_buffer {
-                        self.signal_invalid_sequence();
-                        // Buffer should be empty at this point.
-                        Result::Ok((my_cursor, char::REPLACEMENT_CHARACTER))
-                    }
-                    else {
-                        // Return an indication to request a new buffer.
-                        Result::Err(MoreEnum::More(4096))
-                    }
-                }
-            }
-        }
+    #[inline]
+    /// Number of bytes taken from the input since this parser was made
+    /// or reset.  Bytes waiting in the scratch pad for the rest of a
+    /// sequence are included.
+    pub fn num_bytes_in(&self) -> u64 {
+        self.my_bytes_in
     }
 
-    /// A parser takes in byte slice, and returns a Result object with
-    /// either the remaining input and the output u32 value, or an MoreEnum
-    /// that requests additional data, or an end of data stream condition.
+    #[inline]
+    /// Number of chars produced since this parser was made or
+    /// reset.  Replacement characters are included.
+    pub fn num_chars_out(&self) -> u64 {
+        self.my_chars_out
+    }
+
+    #[inline]
+    /// Number of invalid sequences found by the decoder since this
+    /// parser was made or reset: sequences replaced with a replacement
+    /// character, and overlong sequences decoded by the overlong
+    /// tolerant mode.
     ///
-    /// Invalid UTF8 decodes are indicated by Unicode replacement characters.
-    /// has_invalid_decodes() would return true after this event.
-    /// Encountering a replacement character is considered the same as having
-    /// an invalid decode.
-    pub fn utf8_to_utf32<'c>(&mut self, input: &'c [u8])
-    -> Result<(&'c [u8], u32), MoreEnum> {
-        let char_parse_result = self.utf8_to_char(input);
-        parse_mapper_char_to_utf32(char_parse_result)
+    /// Unlike has_invalid_sequence(), replacement characters validly
+    /// encoded in the input are not counted, so this tells whether the
+    /// input itself is corrupt.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUtf8::new();
+    /// let mut char_box = ['\0'; 8];
+    /// parser.utf8_to_char_slice(b"a\xEF\xBF\xBDb", & mut char_box).unwrap();
+    /// assert!(parser.has_invalid_sequence());
+    /// assert_eq!((0, 1), (parser.num_decode_errors(), parser.num_encoded_replacements()));
+    /// parser.utf8_to_char_slice(b"\xFF", & mut char_box).unwrap();
+    /// assert_eq!((1, 1), (parser.num_decode_errors(), parser.num_encoded_replacements()));
+    /// ```
+    pub fn num_decode_errors(&self) -> u64 {
+        self.my_decode_errors
     }
 
-    /// Convert from UTF8 to char with a mutable reference
-    /// to the source UTF8 iterator.
-    pub fn utf8_to_char_with_iter<'d>(&'d mut self, iter: &'d mut dyn Iterator<Item = u8>)
-    -> Utf8IterToCharIter {
-        Utf8IterToCharIter {
+    #[inline]
+    /// Number of replacement characters validly encoded in the input
+    /// since this parser was made or reset.  They count as invalid
+    /// sequences for has_invalid_sequence(), but not for
+    /// num_decode_errors().
+    pub fn num_encoded_replacements(&self) -> u64 {
+        self.my_encoded_replacements
+    }
+
+    #[inline]
+    /// Returns true if every char produced since this parser was made
+    /// or reset has been ASCII, so a consumer can stay on an ASCII only
+    /// path until the first other char appears.  Replacement characters
+    /// for invalid sequences are not ASCII; bytes of a partial sequence
+    /// waiting for the next buffer are not looked at until decoded.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUtf8::new();
+    /// let mut char_box = ['\0'; 8];
+    /// parser.set_is_last_buffer(false);
+    /// parser.utf8_to_char_slice(b"abc\xC3", & mut char_box).unwrap();
+    /// assert!(parser.is_ascii_so_far());
+    /// parser.utf8_to_char_slice(b"\xA9", & mut char_box).unwrap();
+    /// assert!(! parser.is_ascii_so_far());
+    /// ```
+    pub fn is_ascii_so_far(&self) -> bool {
+        self.my_ascii_so_far
+    }
+
+    /// Clear the throughput counters, the counters of decode errors
+    /// and encoded replacement characters, and the ASCII flag of
+    /// is_ascii_so_far().  Unlike reset_parser(), the parser state is
+    /// not changed.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUtf8::new();
+    /// let mut char_box = ['\0'; 8];
+    /// parser.utf8_to_char_slice("a\u{20AC}".as_bytes(), & mut char_box).unwrap();
+    /// assert_eq!((4, 2), (parser.num_bytes_in(), parser.num_chars_out()));
+    /// parser.reset_counters();
+    /// assert_eq!((0, 0), (parser.num_bytes_in(), parser.num_chars_out()));
+    /// ```
+    pub fn reset_counters(&mut self) {
+        self.my_bytes_in = 0;
+        self.my_chars_out = 0;
+        self.my_decode_errors = 0;
+        self.my_encoded_replacements = 0;
+        self.my_ascii_so_far = true;
+    }
+
+    /// Turn the overlong tolerant mode on or off.
+    ///
+    /// In this mode an overlong sequence, such as C0 AF for '/', is
+    /// decoded to the codepoint it spells out instead of replacement
+    /// characters, for forensic analysis of crafted input.  It is still
+    /// an invalid sequence: has_invalid_sequence() returns true after
+    /// it.  Overlong forms of surrogates are replaced as usual.
+    ///
+    /// The mode is kept by reset_parser().
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUtf8::new();
+    /// parser.set_overlong_tolerant(true);
+    /// let bytes = [0x2Eu8, 0x2Eu8, 0xC0u8, 0xAFu8];
+    /// let text: String = parser.utf8_ref_to_char_with_iter(& mut bytes.iter()).collect();
+    /// assert_eq!("../", text);
+    /// assert!(parser.has_invalid_sequence());
+    /// ```
+    /// # Arguments
+    /// * `b` - true to decode overlong sequences
+    pub fn set_overlong_tolerant(&mut self, b: bool) {
+        self.my_overlong_tolerant = b;
+    }
+
+    #[inline]
+    /// Returns true when the overlong tolerant mode is on.
+    pub fn is_overlong_tolerant(&self) -> bool {
+        self.my_overlong_tolerant
+    }
+
+    /// Install a function applied to each decoded char before output,
+    /// or remove it with 'None'.  The function returns the char to
+    /// output, or 'None' to drop the char.
+    ///
+    /// Replacement characters of invalid sequences are also passed to
+    /// the function.  With a function installed, the ASCII fast path of
+    /// utf8_to_char_slice() is not used.
+    ///
+    /// The function is kept by reset_parser().
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUtf8::new();
+    /// parser.set_char_map(Some(|ch| match ch {
+    ///     '\u{A0}' => Some(' '),
+    ///     '\u{200B}' => None,
+    ///     _ => Some(ch),
+    /// }));
+    /// let bytes = "a\u{A0}b\u{200B}c".as_bytes();
+    /// let text: String = parser.utf8_ref_to_char_with_iter(& mut bytes.iter()).collect();
+    /// assert_eq!("a bc", text);
+    /// ```
+    /// # Arguments
+    /// * `map` - the function, or 'None' for no mapping
+    pub fn set_char_map(&mut self, map: Option<fn(char) -> Option<char>>) {
+        self.my_char_map = map.map(CharMap);
+    }
+
+    #[inline]
+    /// Returns the installed char mapping function.
+    pub fn char_map(&self) -> Option<fn(char) -> Option<char>> {
+        self.my_char_map.map(|v| v.0)
+    }
+
+    /// Decode an overlong sequence at the front of the scratch pad.
+    ///
+    /// 'None' is returned when the scratch pad does not start with a
+    /// complete overlong sequence of a valid char, leaving it to
+    /// utf8_decode().  'Some(None)' asks for more data.
+    fn decode_overlong(&mut self, last_buffer: bool) -> Option<Option<char>> {
+        let lead = self.my_buf.front()?;
+        // sequence length, largest overlong second byte, lead bits
+        let (len, second_max, lead_mask) = match lead {
+            0xC0 | 0xC1 => (2usize, 0xBFu8, 0x1Fu8),
+            0xE0 => (3, 0x9F, 0x0F),
+            0xF0 => (4, 0x8F, 0x07),
+            _ => {
+                return Option::None;
+            }
+        };
+        let mut code = (lead & lead_mask) as u32;
+        for indx in 1 .. len {
+            match self.my_buf.peek_at(indx) {
+                Option::Some(v) if ((v & 0xC0) == 0x80) && ((indx > 1) || (v <= second_max)) => {
+                    code = (code << 6) | ((v & 0x3F) as u32);
+                }
+                Option::Some(_) => {
+                    return Option::None;
+                }
+                Option::None => {
+                    if last_buffer {
+                        return Option::None;
+                    }
+                    // Wait for the rest of the sequence.
+                    return Option::Some(Option::None);
+                }
+            }
+        }
+        let ch = char::from_u32(code)?;
+        for _indx in 0 .. len {
+            self.my_buf.pop_front();
+        }
+        self.signal_invalid_sequence();
+        self.my_decode_errors += 1;
+        Option::Some(Option::Some(ch))
+    }
+
+    #[cfg_attr(not(feature = "small"), inline)]
+    /// Move as many bytes as the scratch pad can hold from the front of
+    /// `input` into the scratch pad, and return the remaining input.
+    fn fill_buffer<'b>(&mut self, input: &'b [u8]) -> &'b [u8] {
+        // A single u64 read refills the scratch pad when at least
+        // 8 bytes remain.
+        let consumed = self.my_buf.fill_from_slice(input);
+        self.my_bytes_in += consumed as u64;
+        input.get(consumed ..).unwrap_or(&[])
+    }
+
+    #[cfg_attr(not(feature = "small"), inline)]
+    /// Decode one char from the scratch pad.
+    ///
+    /// 'None' is returned when no char can be produced: the scratch pad
+    /// is empty, or it holds a partial sequence while more buffers are
+    /// expected.
+    ///
+    /// This is the inner routine shared by the slice parsers, the bulk
+    /// parser and the iterator adapters.
+    ///
+    /// When the char map drops a char and the scratch pad may no longer
+    /// hold a complete sequence, 'None' is returned with the refill flag
+    /// set; the caller refills the scratch pad and calls again.
+    fn decode_buffered(&mut self, last_buffer: bool) -> Option<char> {
+        loop {
+            let ch = self.decode_pad(last_buffer)?;
+            let mapped = match self.my_char_map {
+                Option::Some(map) => (map.0)(ch),
+                Option::None => Option::Some(ch),
+            };
+            if let Option::Some(out) = mapped {
+                self.my_chars_out += 1;
+                self.my_ascii_so_far &= out.is_ascii();
+                return mapped;
+            }
+            // The char was dropped; decode the next one when the
+            // scratch pad holds a sequence of any length.
+            if self.my_buf.len() < 4 {
+                self.my_refill = true;
+                return Option::None;
+            }
+        }
+    }
+
+    #[inline]
+    /// Returns and clears the refill flag of decode_buffered().
+    fn take_refill(&mut self) -> bool {
+        core::mem::replace(& mut self.my_refill, false)
+    }
+
+    #[cfg_attr(not(feature = "small"), inline)]
+    /// Body of decode_buffered(), without counting the chars produced
+    fn decode_pad(&mut self, last_buffer: bool) -> Option<char> {
+        if self.my_buf.is_empty() {
+            // This is either the end of data, or the current buffer
+            // has run to the end without left-over data in the
+            // scratch pad.
+            return Option::None;
+        }
+        if self.my_overlong_tolerant {
+            if let Option::Some(result) = self.decode_overlong(last_buffer) {
+                return result;
+            }
+        }
+        let lead = self.my_buf.front().unwrap_or(0);
+        match utf8_decode(& mut self.my_buf, last_buffer) {
+            Utf8EndEnum::BadDecode(len) => {
+                self.signal_invalid_sequence();
+                if bad_decode_kind(lead, len) == Utf8ErrorKind::ReplacementCharacter {
+                    self.my_encoded_replacements += 1;
+                }
+                else {
+                    self.my_decode_errors += 1;
+                }
+                Option::Some(char::REPLACEMENT_CHARACTER)
+            }
+            Utf8EndEnum::Finish(code) => {
+                Option::Some(finished_code_to_char(code))
+            }
+            Utf8EndEnum::TypeUnknown => {
+                // Insufficient data to decode.
+                if last_buffer {
+                    self.signal_invalid_sequence();
+                    self.my_decode_errors += 1;
+                    // Buffer should be empty at this point.
+                    Option::Some(char::REPLACEMENT_CHARACTER)
+                }
+                else {
+                    // Ready for next buffer
+                    Option::None
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(not(feature = "small"), inline)]
+    /// Fill the scratch pad from a UTF8 iterator, and decode the next
+    /// char.  This is the inner routine of the UTF8 iterator adapters.
+    fn decode_from_iter<I>(&mut self, iter: &mut I) -> Option<char>
+    where I: Iterator<Item = u8> + ?Sized, {
+        loop {
+            // Fill buffer phase.
+            while ! self.my_buf.is_full() {
+                match iter.next() {
+                    Option::None => {
+                        break;
+                    }
+                    Option::Some(utf8) => {
+                        // Save it in our scratch pad.
+                        self.my_buf.push_back(utf8);
+                        self.my_bytes_in += 1;
+                    }
+                }
+            }
+            let last_buffer = self.is_last_buffer();
+            let result = self.decode_buffered(last_buffer);
+            if result.is_some() || ! self.take_refill() {
+                return result;
+            }
+        }
+    }
+
+    #[inline]
+    /// Returns the MoreEnum to report when decode_buffered() could not
+    /// produce a char.  A partial sequence in the scratch pad reports
+    /// the exact number of bytes needed to complete it.
+    fn more_indication(&self, last_buffer: bool) -> MoreEnum {
+        if last_buffer {
+            // at end of data condition
+            return MoreEnum::More(0);
+        }
+        let seq_len:u32 = match self.my_buf.front() {
+            Option::Some(0xC0u8 ..= 0xDFu8) => 2,
+            Option::Some(0xE0u8 ..= 0xEFu8) => 3,
+            Option::Some(0xF0u8 ..= 0xF7u8) => 4,
+            _ => 0,
+        };
+        if seq_len > self.my_buf.len() {
+            MoreEnum::More(seq_len - self.my_buf.len())
+        }
+        else {
+            // Returning an indication to request a new buffer.
+            MoreEnum::More(4096)
+        }
+    }
+
+    /// A parser takes in byte slice, and returns a Result object with
+    /// either the remaining input and the output char value, or an MoreEnum
+    /// that requests additional data, or an end of data stream condition.
+    ///
+    /// Invalid UTF8 decodes are indicated by Unicode replacement characters.
+    /// has_invalid_decodes() would return true after this event.
+    /// Encountering a replacement character is considered the same as having
+    /// an invalid decode.
+    ///
+    /// When a partial sequence is held in the scratch pad, MoreEnum::More(n)
+    /// gives the exact number of bytes (1 to 3) needed to complete it, so
+    /// a transport can request a minimal read.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUtf8::new();
+    /// parser.set_is_last_buffer(false);
+    /// assert_eq!(Err(MoreEnum::More(3)), parser.utf8_to_char(b"\xF0"));
+    /// assert_eq!(Err(MoreEnum::More(1)), parser.utf8_to_char(b"\x9F\x98"));
+    /// assert_eq!(Ok((&b""[..], '\u{1F600}')), parser.utf8_to_char(b"\x80"));
+    /// ```
+    pub fn utf8_to_char<'b>(&mut self, input: &'b [u8])
+    -> Result<(&'b [u8], char), MoreEnum> {
+        let last_buffer = self.my_last_buffer;
+        let mut my_cursor = input;
+        loop {
+            // Fill buffer phase.
+            my_cursor = self.fill_buffer(my_cursor);
+            match self.decode_buffered(last_buffer) {
+                Option::Some(ch) => {
+                    return Result::Ok((my_cursor, ch));
+                }
+                Option::None if self.take_refill() => {
+                    // Chars were dropped by the char map; refill.
+                }
+                Option::None => {
+                    return Result::Err(self.more_indication(last_buffer));
+                }
+            }
+        }
+    }
+
+    /// A bulk parser takes in byte slice, and fills the `output` slice
+    /// with decoded char values.  Returns a Result object with either
+    /// the remaining input and the number of chars written, or an MoreEnum
+    /// that requests additional data, or an end of data stream condition.
+    ///
+    /// The MoreEnum conditions are the same as utf8_to_char(); they are
+    /// only reported when not a single char could be written.
+    ///
+    /// Invalid UTF8 decodes are indicated by Unicode replacement characters.
+    /// has_invalid_decodes() would return true after this event.
+    /// Encountering a replacement character is considered the same as having
+    /// an invalid decode.
+    ///
+    /// Runs of ASCII bytes are copied to `output` without going through
+    /// the scratch pad, except with the "small" feature.
+    pub fn utf8_to_char_slice<'b>(&mut self, input: &'b [u8], output: &mut [char])
+    -> Result<(&'b [u8], usize), MoreEnum> {
+        self.utf8_to_char_slots(input, output)
+    }
+
+    #[cfg(not(feature = "safe"))]
+    /// utf8_to_char_slice() writing into an uninitialized `output`, so a
+    /// large output buffer need not be zeroed first.  Returns the
+    /// remaining input and the initialized prefix of `output`.
+    ///
+    /// Not available with the "safe" feature.
+    ///
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUtf8::new();
+    /// let mut char_box = [MaybeUninit::<char>::uninit(); 8];
+    /// let (rest, chars) = parser.utf8_to_char_uninit(b"caf\xC3\xA9", & mut char_box).unwrap();
+    /// assert_eq!((&b""[..], &['c', 'a', 'f', '\u{E9}'][..]), (rest, &* chars));
+    /// ```
+    /// # Arguments
+    /// * `input` - the UTF8 input
+    /// * `output` - the uninitialized output buffer
+    pub fn utf8_to_char_uninit<'b, 'o>(&mut self, input: &'b [u8], output: &'o mut [MaybeUninit<char>])
+    -> Result<(&'b [u8], &'o mut [char]), MoreEnum> {
+        let (rest, written) = self.utf8_to_char_slots(input, output)?;
+        Result::Ok((rest, assume_init_prefix(output, written)))
+    }
+
+    #[cfg_attr(not(feature = "small"), inline(always))]
+    /// Shared body of utf8_to_char_slice() and utf8_to_char_uninit()
+    fn utf8_to_char_slots<'b, T: OutputSlot<char>>(&mut self, input: &'b [u8], output: &mut [T])
+    -> Result<(&'b [u8], usize), MoreEnum> {
+        let last_buffer = self.my_last_buffer;
+        let mut my_cursor: &[u8] = input;
+        let mut written: usize = 0;
+        // Slices are accessed with get() and iterators only, so that no
+        // input can cause a panic.
+        while let Option::Some((slot1, out_rest)) =
+        output.get_mut(written ..).and_then(|v| v.split_first_mut()) {
+            if ! cfg!(feature = "small") && self.my_buf.is_empty() && self.my_char_map.is_none() {
+                // Fast path: a run of ASCII bytes bypasses the scratch pad
+                // entirely.
+                let window = my_cursor.get(.. out_rest.len() + 1).unwrap_or(my_cursor);
+                let run = find_non_ascii_inline(window).unwrap_or(window.len());
+                if run > 0 {
+                    let out_run = core::iter::once(slot1).chain(out_rest.iter_mut());
+                    for (slot, v) in out_run.zip(window.iter().take(run)) {
+                        slot.put(* v as char);
+                    }
+                    written += run;
+                    self.my_bytes_in += run as u64;
+                    self.my_chars_out += run as u64;
+                    my_cursor = my_cursor.get(run ..).unwrap_or(&[]);
+                    continue;
+                }
+            }
+            my_cursor = self.fill_buffer(my_cursor);
+            match self.decode_buffered(last_buffer) {
+                Option::Some(ch) => {
+                    slot1.put(ch);
+                    written += 1;
+                }
+                Option::None if self.take_refill() => {
+                    // Chars were dropped by the char map; refill.
+                }
+                Option::None => {
+                    break;
+                }
+            }
+        }
+        if (written == 0) && ! output.is_empty() {
+            Result::Err(self.more_indication(last_buffer))
+        }
+        else {
+            Result::Ok((my_cursor, written))
+        }
+    }
+
+    /// A parser takes in byte slice, and returns a Result object with
+    /// either the remaining input and the output u32 value, or an MoreEnum
+    /// that requests additional data, or an end of data stream condition.
+    ///
+    /// Invalid UTF8 decodes are indicated by Unicode replacement characters.
+    /// has_invalid_decodes() would return true after this event.
+    /// Encountering a replacement character is considered the same as having
+    /// an invalid decode.
+    pub fn utf8_to_utf32<'c>(&mut self, input: &'c [u8])
+    -> Result<(&'c [u8], u32), MoreEnum> {
+        let char_parse_result = self.utf8_to_char(input);
+        parse_mapper_char_to_utf32(char_parse_result)
+    }
+
+    /// Convert from UTF8 to char with a mutable reference
+    /// to the source UTF8 iterator.
+    pub fn utf8_to_char_with_iter<'d, I>(&'d mut self, iter: &'d mut I)
+    -> Utf8IterToCharIter<'d, I>
+    where I: Iterator<Item = u8> + ?Sized, {
+        Utf8IterToCharIter {
             my_info : self,
             my_borrow_mut_iter: iter,
         }
@@ -1232,14 +3024,52 @@ impl FromUtf8 {
 
     /// Convert from UTF8 reference to char with a mutable reference
     /// to the source UTF8 iterator.
-    pub fn utf8_ref_to_char_with_iter<'d>(&'d mut self, iter: &'d mut dyn Iterator<Item = &'d u8>)
-    -> Utf8RefIterToCharIter {
+    pub fn utf8_ref_to_char_with_iter<'d, 'c, I>(&'d mut self, iter: &'d mut I)
+    -> Utf8RefIterToCharIter<'d, I>
+    where I: Iterator<Item = &'c u8> + ?Sized, {
         Utf8RefIterToCharIter {
             my_info : self,
             my_borrow_mut_iter: iter,
         }
     }
 
+    /// Convert from UTF8 to UTF16 with a mutable reference to the source
+    /// UTF8 iterator, without an intermediate char pipeline.
+    ///
+    /// Invalid UTF8 sequences become replacement characters, as with
+    /// utf8_to_char_with_iter().
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUtf8::new();
+    /// let mut iter = b"a\xF0\x9F\x98\x80\xFF".iter().copied();
+    /// let units: Vec<u16> = parser.utf8_to_utf16_with_iter(& mut iter).collect();
+    /// assert_eq!(vec![0x61, 0xD83D, 0xDE00, 0xFFFD], units);
+    /// assert!(parser.has_invalid_sequence());
+    /// ```
+    pub fn utf8_to_utf16_with_iter<'d, I>(&'d mut self, iter: &'d mut I)
+    -> Utf8IterToUtf16Iter<'d, I>
+    where I: Iterator<Item = u8> + ?Sized, {
+        Utf8IterToUtf16Iter {
+            my_low_surrogate: Option::None,
+            my_borrow_mut_iter: iter,
+            my_info : self,
+        }
+    }
+
+    /// Convert from UTF8 to char with an iterator adapter that takes
+    /// ownership of a copy of this parser and of the source UTF8 iterator.
+    ///
+    /// An UTF8 reference iterator can be adapted with copied().
+    pub fn utf8_into_char_iter<I>(self, iter: I) -> Utf8IntoCharIter<I>
+    where I: Iterator<Item = u8>, {
+        Utf8IntoCharIter {
+            my_iter: iter,
+            my_info: self,
+        }
+    }
+
 }
 
 
@@ -1250,11 +3080,127 @@ impl FromUnicode {
     pub fn new() -> FromUnicode {
         FromUnicode {
             my_buf : EightBytes::new(),
+            my_low_surrogate : Option::None,
             my_last_buffer : true,
             my_invalid_sequence : false,
+            my_chars_in : 0,
+            my_bytes_encoded : 0,
+            my_strict : false,
+            my_strict_error : Option::None,
+        }
+    }
+
+    #[inline]
+    /// Turn strict mode on or off for the iterator adapters and for
+    /// push_char() and push_utf32().  Strict mode is kept by
+    /// reset_parser().
+    ///
+    /// These have no way to return an error, so in strict mode an invalid
+    /// value, including a replacement character, is not encoded.  The
+    /// iterator ends before it, or the push returns false, and
+    /// strict_error() returns it; has_invalid_sequence() returns true.
+    /// Iterator sources have already taken the value.
+    /// reset_invalid_sequence() clears the error, and encoding goes on.
+    ///
+    /// The slice and CharSource parsers are not affected; their strict
+    /// forms, such as utf32_to_utf8_strict(), return the error instead.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUnicode::new();
+    /// parser.set_strict(true);
+    /// let input = [0x41u32, 0xD800u32, 0x42u32];
+    /// let mut iter = input.iter().copied();
+    /// assert_eq!(1, parser.utf32_to_utf8_with_iter(& mut iter).count());
+    /// let error = parser.strict_error().unwrap();
+    /// assert_eq!((1, 0xD800, Utf32ErrorKind::Surrogate), (error.index, error.value, error.kind));
+    /// ```
+    /// # Arguments
+    /// * `strict` - true to stop at invalid values
+    pub fn set_strict(&mut self, strict: bool) {
+        self.my_strict = strict;
+    }
+
+    #[inline]
+    /// Returns true in strict mode.
+    pub fn is_strict(&self) -> bool {
+        self.my_strict
+    }
+
+    #[inline]
+    /// Returns the value that stopped the encoder in strict mode.
+    pub fn strict_error(&self) -> Option<StrictEncodeError> {
+        self.my_strict_error
+    }
+
+    #[inline]
+    /// Returns true if `code` may be encoded.  In strict mode an invalid
+    /// value is recorded as the strict error, and false is returned.
+    fn check_strict(&mut self, code: u32) -> bool {
+        if ! self.my_strict {
+            return true;
+        }
+        match self.strict_check(code) {
+            Result::Ok(()) => true,
+            Result::Err(error) => {
+                self.my_strict_error = Option::Some(error);
+                false
+            }
+        }
+    }
+
+    #[inline]
+    /// Returns the StrictEncodeError of `code` if it is invalid, with
+    /// the invalid sequence indication set.
+    fn strict_check(&mut self, code: u32) -> Result<(), StrictEncodeError> {
+        match utf32_error_kind(code) {
+            Option::Some(kind) => {
+                self.signal_invalid_sequence();
+                Result::Err(StrictEncodeError {
+                    index: self.my_chars_in,
+                    value: code,
+                    kind,
+                })
+            }
+            Option::None => Result::Ok(()),
         }
     }
 
+    #[inline]
+    /// The MoreEnum condition after the source is drained
+    fn more_indication(&self) -> MoreEnum {
+        if self.is_last_buffer() {
+            // at end of data condition
+            MoreEnum::More(0)
+        }
+        else {
+            // Returning an indication to request a new buffer.
+            MoreEnum::More(1024)
+        }
+    }
+
+    #[inline]
+    /// Number of codepoints taken from the input since this parser was
+    /// made or reset.  Invalid codepoints are included.
+    pub fn num_chars_in(&self) -> u64 {
+        self.my_chars_in
+    }
+
+    #[inline]
+    /// Number of UTF8 bytes produced since this parser was made or
+    /// reset.  Bytes still waiting in the scratch pad are not included.
+    pub fn num_bytes_out(&self) -> u64 {
+        self.my_bytes_encoded - self.my_buf.len() as u64
+    }
+
+    /// Clear the throughput counters.  Unlike reset_parser(), the
+    /// parser state is not changed.
+    pub fn reset_counters(&mut self) {
+        self.my_chars_in = 0;
+        self.my_bytes_encoded = self.my_buf.len() as u64;
+    }
+
     /// A parser takes in char slice, and returns a Result object with
     /// either the remaining input and the output byte value, or an MoreEnum
     /// that requests additional data, or an end of data stream condition.
@@ -1265,58 +3211,8 @@ impl FromUnicode {
     /// an invalid decode.
     pub fn char_to_utf8<'b>(&mut self, input: &'b [char])
     -> Result<(&'b [char], u8), MoreEnum> {
-        // Check if we can pull an u8 from our ring buffer
-        match self.my_buf.pop_front() {
-            Some(v1) => {
-                return Result::Ok((input, v1));
-            }
-            None => {}
-        }
-        let mut my_cursor: &[char] = input;
-        // Processing for input being empty case
-        if my_cursor.len() == 0 {
-            // Determine if we are at end of data.
-            if self.is_last_buffer() {
-                // at end of data condition
-                return Result::Err(MoreEnum::More(0));
-            }
-            else {
-                // Returning an indication to request a new buffer.
-                return Result::Err(MoreEnum::More(1024));
-            }
-        }
-        // Grab one UTF32 from input
-        let cur_u32 = my_cursor[0] as u32;
-        my_cursor = &my_cursor[1..];
-        // Try to determine the type of UTF32 encoding.
-        match classify_utf32(cur_u32) {
-            Utf8TypeEnum::Type1(v1) => {
-                Result::Ok((my_cursor, v1))
-            }
-            Utf8TypeEnum::Type2((v1,v2)) => {
-                self.my_buf.push_back(v2);
-                Result::Ok((my_cursor, v1))
-            }
-            Utf8TypeEnum::Type3((v1,v2,v3)) => {
-                self.my_buf.push_back(v2);
-                self.my_buf.push_back(v3);
-                Result::Ok((my_cursor, v1))
-            }
-            Utf8TypeEnum::Type4((v1,v2,v3,v4)) => {
-                self.my_buf.push_back(v2);
-                self.my_buf.push_back(v3);
-                self.my_buf.push_back(v4);
-                Result::Ok((my_cursor, v1))
-            }
-            _ => {
-                // Invalid UTF32 codepoint
-                // Emit replacement byte sequence.
-                self.signal_invalid_sequence();
-                self.my_buf.push_back(REPLACE_PART2);
-                self.my_buf.push_back(REPLACE_PART3);
-                Result::Ok((my_cursor, REPLACE_PART1))
-            }
-        }
+        let mut my_cursor = input;
+        self.source_to_utf8(& mut my_cursor).map(|v1| (my_cursor, v1))
     }
 
     /// A parser takes in UTF32 slice, and returns a Result object with
@@ -1329,84 +3225,953 @@ impl FromUnicode {
     /// an invalid decode.
     pub fn utf32_to_utf8<'c>(&mut self, input: &'c [u32])
     -> Result<(&'c [u32], u8), MoreEnum> {
-        // Check if we can pull an u8 from our ring buffer
-        match self.my_buf.pop_front() {
-            Some(v1) => {
-                return Result::Ok((input, v1));
+        let mut my_cursor = input;
+        self.source_to_utf8(& mut my_cursor).map(|v1| (my_cursor, v1))
+    }
+
+    /// A parser takes in a CharSource, and returns a Result object with
+    /// either the output byte value, or an MoreEnum that requests
+    /// additional data, or an end of data stream condition.  The source
+    /// is advanced past the chars consumed.
+    ///
+    /// Invalid UTF32 decodes are indicated by Unicode replacement characters.
+    /// has_invalid_decodes() would return true after this event.
+    /// Encountering a replacement character is considered the same as having
+    /// an invalid decode.
+    pub fn source_to_utf8<S: CharSource + ?Sized>(&mut self, src: &mut S)
+    -> Result<u8, MoreEnum> {
+        // Check if we can pull an u8 from our ring buffer
+        if let Option::Some(v1) = self.my_buf.pop_front() {
+            return Result::Ok(v1);
+        }
+        // Grab one UTF32 from input
+        match src.next_utf32() {
+            Option::Some(cur_u32) => {
+                Result::Ok(self.encode_utf32(cur_u32))
+            }
+            Option::None => {
+                // Processing for input being empty case
+                // Determine if we are at end of data.
+                Result::Err(self.more_indication())
             }
-            None => {}
         }
-        let mut my_cursor: &[u32] = input;
-        // Processing for input being empty case
-        if my_cursor.len() == 0 {
-            // Determine if we are at end of data.
-            if self.is_last_buffer() {
-                // at end of data condition
-                return Result::Err(MoreEnum::More(0));
+    }
+
+    /// A bulk parser takes in a CharSource, and fills the `output` slice
+    /// with UTF8 bytes.  Returns a Result object with either the number
+    /// of bytes written, or an MoreEnum that requests additional data,
+    /// or an end of data stream condition.
+    ///
+    /// The MoreEnum conditions are the same as source_to_utf8(); they are
+    /// only reported when not a single byte could be written.
+    pub fn source_to_utf8_slice<S: CharSource + ?Sized>(&mut self, src: &mut S, output: &mut [u8])
+    -> Result<usize, MoreEnum> {
+        self.source_to_utf8_slots(src, output)
+    }
+
+    #[cfg(not(feature = "safe"))]
+    /// source_to_utf8_slice() writing into an uninitialized `output`, so
+    /// a large output buffer need not be zeroed first.  Returns the
+    /// initialized prefix of `output`.
+    ///
+    /// Not available with the "safe" feature.
+    ///
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUnicode::new();
+    /// let mut byte_box = [MaybeUninit::<u8>::uninit(); 16];
+    /// let bytes = parser.source_to_utf8_uninit(& mut "caf\u{E9}", & mut byte_box).unwrap();
+    /// assert_eq!(b"caf\xC3\xA9", &* bytes);
+    /// ```
+    /// # Arguments
+    /// * `src` - the char or UTF32 source
+    /// * `output` - the uninitialized output buffer
+    pub fn source_to_utf8_uninit<'o, S: CharSource + ?Sized>(&mut self, src: &mut S,
+    output: &'o mut [MaybeUninit<u8>]) -> Result<&'o mut [u8], MoreEnum> {
+        let written = self.source_to_utf8_slots(src, output)?;
+        Result::Ok(assume_init_prefix(output, written))
+    }
+
+    /// Shared body of source_to_utf8_slice() and source_to_utf8_uninit()
+    fn source_to_utf8_slots<S, T>(&mut self, src: &mut S, output: &mut [T]) -> Result<usize, MoreEnum>
+    where S: CharSource + ?Sized, T: OutputSlot<u8>, {
+        let mut written:usize = 0;
+        for slot in output.iter_mut() {
+            match self.source_to_utf8(src) {
+                Result::Ok(v) => {
+                    slot.put(v);
+                    written += 1;
+                }
+                Result::Err(en) => {
+                    if written == 0 {
+                        return Result::Err(en);
+                    }
+                    break;
+                }
             }
-            else {
-                // Returning an indication to request a new buffer.
-                return Result::Err(MoreEnum::More(1024));
+        }
+        Result::Ok(written)
+    }
+
+    #[inline]
+    /// Encode an UTF32 value, keeping the trailing bytes in the scratch
+    /// pad, and return the first byte.
+    fn encode_utf32(&mut self, cur_u32: u32) -> u8 {
+        let before = self.my_buf.len();
+        let first = self.encode_utf32_to_pad(cur_u32);
+        // The first byte is produced here; the rest go to the scratch pad.
+        self.my_chars_in += 1;
+        self.my_bytes_encoded += (1 + self.my_buf.len() - before) as u64;
+        first
+    }
+
+    #[inline]
+    /// Body of encode_utf32(), without counting
+    fn encode_utf32_to_pad(&mut self, cur_u32: u32) -> u8 {
+        // Try to determine the type of UTF32 encoding.
+        match classify_utf32(cur_u32) {
+            Utf8TypeEnum::Type1(v1) => {
+                v1
+            }
+            Utf8TypeEnum::Type2((v1,v2)) => {
+                self.my_buf.push_back(v2);
+                v1
+            }
+            Utf8TypeEnum::Type3((v1,v2,v3)) => {
+                self.my_buf.push_back(v2);
+                self.my_buf.push_back(v3);
+                v1
+            }
+            Utf8TypeEnum::Type4((v1,v2,v3,v4)) => {
+                self.my_buf.push_back(v2);
+                self.my_buf.push_back(v3);
+                self.my_buf.push_back(v4);
+                v1
+            }
+            _ => {
+                // Invalid UTF32 codepoint
+                // Emit replacement byte sequence.
+                self.signal_invalid_sequence();
+                self.my_buf.push_back(REPLACE_PART2);
+                self.my_buf.push_back(REPLACE_PART3);
+                REPLACE_PART1
+            }
+        }
+    }
+
+    #[inline]
+    /// Return the next UTF8 byte, pulling an UTF32 codepoint from the
+    /// iterator when the scratch pad is empty.  This is the inner routine
+    /// of the UTF32 iterator adapters.
+    fn encode_from_iter<I>(&mut self, iter: &mut I) -> Option<u8>
+    where I: Iterator<Item = u32> + ?Sized, {
+        // Check if we can pull an u8 from our ring buffer.
+        if let Option::Some(v1) = self.my_buf.pop_front() {
+            return Option::Some(v1);
+        }
+        if self.my_strict_error.is_some() {
+            // stopped by strict mode
+            return Option::None;
+        }
+        let utf32 = iter.next()?;
+        if ! self.check_strict(utf32) {
+            return Option::None;
+        }
+        Option::Some(self.encode_utf32(utf32))
+    }
+
+    /// Convert from UTF32 iter to UTF8 iter with a mutable reference
+    /// to the source UTF32 iterator.
+    pub fn utf32_to_utf8_with_iter<'d, I>(&'d mut self, iter: &'d mut I)
+    -> Utf32IterToUtf8Iter<'d, I>
+    where I: Iterator<Item = u32> + ?Sized, {
+        Utf32IterToUtf8Iter {
+            my_borrow_mut_iter: iter,
+            my_info: self,
+        }
+    }
+
+    /// Convert from char reference iter to UTF8 iter with a mutable reference
+    /// to the source char reference iterator.
+    pub fn char_ref_to_utf8_with_iter<'d, 'c, I>(&'d mut self, iter: &'d mut I)
+    -> CharRefIterToUtf8Iter<'d, I>
+    where I: Iterator<Item = &'c char> + ?Sized, {
+        CharRefIterToUtf8Iter {
+            my_borrow_mut_iter: iter,
+            my_info: self,
+        }
+    }
+
+    /// Convert from UTF32 to UTF8 with an iterator adapter that takes
+    /// ownership of a copy of this parser and of the source UTF32 iterator.
+    ///
+    /// A char iterator can be adapted with map(|v| v as u32).
+    pub fn utf32_into_utf8_iter<I>(self, iter: I) -> Utf32IntoUtf8Iter<I>
+    where I: Iterator<Item = u32>, {
+        Utf32IntoUtf8Iter {
+            my_iter: iter,
+            my_info: self,
+        }
+    }
+
+    /// Convert from a string slice to UTF8 with an iterator adapter that
+    /// takes ownership of a copy of this parser.
+    ///
+    /// The replacement character policy applies, so has_invalid_sequence()
+    /// returns true after a replacement character in the string.
+    pub fn str_into_utf8_iter(self, input: &str) -> Utf32IntoUtf8Iter<StrToUtf32Struct<'_>> {
+        self.utf32_into_utf8_iter(str_to_utf32_iter(input))
+    }
+
+    /// Encode an UTF32 value into the scratch pad, for draining with
+    /// drain_bytes().  Returns false, without storing anything, when the
+    /// scratch pad does not have room for 4 more bytes.
+    ///
+    /// Invalid codepoints are substituted with a replacement character,
+    /// and has_invalid_sequence() would return true after this event.
+    /// In strict mode false is also returned for an invalid codepoint,
+    /// with strict_error() set.
+    /// # Arguments
+    /// * `code` - the codepoint to be encoded
+    pub fn push_utf32(&mut self, code: u32) -> bool {
+        if self.my_buf.capacity() - self.my_buf.len() < 4 {
+            return false;
+        }
+        if self.my_strict_error.is_some() || ! self.check_strict(code) {
+            return false;
+        }
+        let before = self.my_buf.len();
+        self.push_utf32_to_pad(code);
+        self.my_chars_in += 1;
+        self.my_bytes_encoded += (self.my_buf.len() - before) as u64;
+        true
+    }
+
+    #[inline]
+    /// Body of push_utf32(), without the room check and counting
+    fn push_utf32_to_pad(&mut self, code: u32) {
+        match classify_utf32(code) {
+            Utf8TypeEnum::Type1(v1) => {
+                self.my_buf.push_back(v1);
+            }
+            Utf8TypeEnum::Type2((v1,v2)) => {
+                self.my_buf.push_back(v1);
+                self.my_buf.push_back(v2);
+            }
+            Utf8TypeEnum::Type3((v1,v2,v3)) => {
+                self.my_buf.push_back(v1);
+                self.my_buf.push_back(v2);
+                self.my_buf.push_back(v3);
+            }
+            Utf8TypeEnum::Type4((v1,v2,v3,v4)) => {
+                self.my_buf.push_back(v1);
+                self.my_buf.push_back(v2);
+                self.my_buf.push_back(v3);
+                self.my_buf.push_back(v4);
+            }
+            Utf8TypeEnum::Type0((v1,v2,v3)) => {
+                // Invalid UTF32 codepoint
+                // Store replacement byte sequence.
+                self.signal_invalid_sequence();
+                self.my_buf.push_back(v1);
+                self.my_buf.push_back(v2);
+                self.my_buf.push_back(v3);
+            }
+        }
+    }
+
+    #[inline]
+    /// Encode a char into the scratch pad, for draining with
+    /// drain_bytes().  Same as push_utf32().
+    /// # Arguments
+    /// * `ch` - the char to be encoded
+    pub fn push_char(&mut self, ch: char) -> bool {
+        self.push_utf32(ch as u32)
+    }
+
+    /// Move encoded bytes from the scratch pad into `output`, and return
+    /// the number of bytes written.  The scratch pad always has room for
+    /// another char after it is drained.
+    /// # Arguments
+    /// * `output` - the output buffer
+    pub fn drain_bytes(&mut self, output: &mut [u8]) -> usize {
+        let mut written:usize = 0;
+        for slot in output.iter_mut() {
+            match self.my_buf.pop_front() {
+                Option::Some(v) => {
+                    * slot = v;
+                    written += 1;
+                }
+                Option::None => {
+                    break;
+                }
+            }
+        }
+        written
+    }
+
+    #[inline]
+    /// Encode an UTF32 value to UTF16, keeping the low surrogate of a
+    /// surrogate pair, and return the first code unit.
+    fn encode_utf32_to_utf16(&mut self, cur_u32: u32) -> u16 {
+        self.my_chars_in += 1;
+        if utf32_error_kind(cur_u32).is_some() {
+            // Invalid UTF32 codepoint, or a replacement character
+            self.signal_invalid_sequence();
+            REPLACE_UTF32 as u16
+        }
+        else if cur_u32 >= 0x10000u32 {
+            let offset = cur_u32 - 0x10000u32;
+            self.my_low_surrogate = Option::Some((0xDC00u32 | (offset & 0x3FF)) as u16);
+            (0xD800u32 | (offset >> 10)) as u16
+        }
+        else {
+            cur_u32 as u16
+        }
+    }
+
+    #[inline]
+    /// Return the next UTF16 code unit, pulling an UTF32 codepoint from
+    /// `next` when no low surrogate is waiting.  'None' is returned when
+    /// `next` is drained, or in strict mode at an invalid codepoint.
+    /// This is the inner routine of the UTF16 encoders.
+    /// # Arguments
+    /// * `next` - returns the next codepoint of the input
+    fn encode_utf16_from<F: FnOnce() -> Option<u32>>(&mut self, next: F) -> Option<u16> {
+        if let Option::Some(low) = self.my_low_surrogate.take() {
+            return Option::Some(low);
+        }
+        if self.my_strict_error.is_some() {
+            // stopped by strict mode
+            return Option::None;
+        }
+        let cur_u32 = next()?;
+        if ! self.check_strict(cur_u32) {
+            return Option::None;
+        }
+        Option::Some(self.encode_utf32_to_utf16(cur_u32))
+    }
+
+    /// A parser takes in a CharSource, and returns a Result object with
+    /// either the output UTF16 code unit, or an MoreEnum that requests
+    /// additional data, or an end of data stream condition.  The source
+    /// is advanced past the chars consumed.
+    ///
+    /// A codepoint above FFFF produces a surrogate pair; the low
+    /// surrogate is returned by the next call.
+    ///
+    /// Invalid UTF32 decodes are indicated by Unicode replacement characters.
+    /// has_invalid_decodes() would return true after this event.
+    /// Encountering a replacement character is considered the same as having
+    /// an invalid decode.
+    pub fn source_to_utf16<S: CharSource + ?Sized>(&mut self, src: &mut S)
+    -> Result<u16, MoreEnum> {
+        if let Option::Some(low) = self.my_low_surrogate.take() {
+            return Result::Ok(low);
+        }
+        match src.next_utf32() {
+            Option::Some(cur_u32) => {
+                Result::Ok(self.encode_utf32_to_utf16(cur_u32))
+            }
+            Option::None => {
+                Result::Err(self.more_indication())
             }
         }
-        // Grab one UTF32 from input
-        let cur_u32 = my_cursor[0];
-        my_cursor = &my_cursor[1..];
-        // Try to determine the type of UTF32 encoding.
-        match classify_utf32(cur_u32) {
-            Utf8TypeEnum::Type1(v1) => {
-                Result::Ok((my_cursor, v1))
-            }
-            Utf8TypeEnum::Type2((v1,v2)) => {
-                self.my_buf.push_back(v2);
-                Result::Ok((my_cursor, v1))
-            }
-            Utf8TypeEnum::Type3((v1,v2,v3)) => {
-                self.my_buf.push_back(v2);
-                self.my_buf.push_back(v3);
-                Result::Ok((my_cursor, v1))
+    }
+
+    /// A parser takes in char slice, and returns a Result object with
+    /// either the remaining input and the output UTF16 code unit, or an
+    /// MoreEnum that requests additional data, or an end of data stream
+    /// condition.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUnicode::new();
+    /// let input = ['A', '\u{1F600}'];
+    /// let (rest, v1) = parser.char_to_utf16(& input).unwrap();
+    /// let (rest, v2) = parser.char_to_utf16(rest).unwrap();
+    /// let (rest, v3) = parser.char_to_utf16(rest).unwrap();
+    /// assert_eq!([0x41, 0xD83D, 0xDE00], [v1, v2, v3]);
+    /// assert_eq!(Err(MoreEnum::More(0)), parser.char_to_utf16(rest));
+    /// ```
+    pub fn char_to_utf16<'b>(&mut self, input: &'b [char])
+    -> Result<(&'b [char], u16), MoreEnum> {
+        let mut my_cursor = input;
+        self.source_to_utf16(& mut my_cursor).map(|v1| (my_cursor, v1))
+    }
+
+    /// A parser takes in UTF32 slice, and returns a Result object with
+    /// either the remaining input and the output UTF16 code unit, or an
+    /// MoreEnum that requests additional data, or an end of data stream
+    /// condition.
+    ///
+    /// Invalid UTF32 decodes are indicated by Unicode replacement characters.
+    /// has_invalid_decodes() would return true after this event.
+    pub fn utf32_to_utf16<'c>(&mut self, input: &'c [u32])
+    -> Result<(&'c [u32], u16), MoreEnum> {
+        let mut my_cursor = input;
+        self.source_to_utf16(& mut my_cursor).map(|v1| (my_cursor, v1))
+    }
+
+    /// A bulk parser takes in a CharSource, and fills the `output` slice
+    /// with UTF16 code units.  Returns a Result object with either the
+    /// number of code units written, or an MoreEnum that requests
+    /// additional data, or an end of data stream condition.
+    ///
+    /// The MoreEnum conditions are the same as source_to_utf16(); they are
+    /// only reported when not a single code unit could be written.
+    pub fn source_to_utf16_slice<S: CharSource + ?Sized>(&mut self, src: &mut S, output: &mut [u16])
+    -> Result<usize, MoreEnum> {
+        let mut written:usize = 0;
+        for slot in output.iter_mut() {
+            match self.source_to_utf16(src) {
+                Result::Ok(v) => {
+                    * slot = v;
+                    written += 1;
+                }
+                Result::Err(en) => {
+                    if written == 0 {
+                        return Result::Err(en);
+                    }
+                    break;
+                }
+            }
+        }
+        Result::Ok(written)
+    }
+
+    /// Convert from UTF32 iter to UTF16 iter with a mutable reference
+    /// to the source UTF32 iterator.
+    pub fn utf32_to_utf16_with_iter<'d, I>(&'d mut self, iter: &'d mut I)
+    -> Utf32IterToUtf16Iter<'d, I>
+    where I: Iterator<Item = u32> + ?Sized, {
+        Utf32IterToUtf16Iter {
+            my_borrow_mut_iter: iter,
+            my_info: self,
+        }
+    }
+
+    /// Convert from char reference iter to UTF16 iter with a mutable
+    /// reference to the source char reference iterator.
+    pub fn char_ref_to_utf16_with_iter<'d, 'c, I>(&'d mut self, iter: &'d mut I)
+    -> CharRefIterToUtf16Iter<'d, I>
+    where I: Iterator<Item = &'c char> + ?Sized, {
+        CharRefIterToUtf16Iter {
+            my_borrow_mut_iter: iter,
+            my_info: self,
+        }
+    }
+
+}
+
+/// Implementation of FromUnicode for strict encoding
+impl FromUnicode {
+
+    /// A parser takes in a CharSource, and returns a Result object with
+    /// either the output byte value, or an EncodeError.  Unlike
+    /// source_to_utf8(), an invalid value, including a replacement
+    /// character, is reported as EncodeError::Invalid instead of being
+    /// encoded as a replacement character, for callers that must never
+    /// produce one.
+    ///
+    /// The invalid value is taken from the source, so encoding may go
+    /// on after it.  has_invalid_sequence() returns true after an error.
+    /// The MoreEnum conditions are returned as EncodeError::More.  The
+    /// strict mode of set_strict() does not apply.
+    pub fn source_to_utf8_strict<S: CharSource + ?Sized>(&mut self, src: &mut S)
+    -> Result<u8, EncodeError> {
+        if let Option::Some(v1) = self.my_buf.pop_front() {
+            return Result::Ok(v1);
+        }
+        match src.next_utf32() {
+            Option::Some(cur_u32) => {
+                if let Result::Err(error) = self.strict_check(cur_u32) {
+                    self.my_chars_in += 1;
+                    return Result::Err(EncodeError::Invalid(error));
+                }
+                Result::Ok(self.encode_utf32(cur_u32))
+            }
+            Option::None => {
+                Result::Err(EncodeError::More(self.more_indication()))
+            }
+        }
+    }
+
+    /// A parser takes in char slice, and returns a Result object with
+    /// either the remaining input and the output byte value, or an
+    /// EncodeError.  The same as source_to_utf8_strict(); an invalid
+    /// value is the first char of `input`, and encoding may go on after
+    /// it.
+    pub fn char_to_utf8_strict<'b>(&mut self, input: &'b [char])
+    -> Result<(&'b [char], u8), EncodeError> {
+        let mut my_cursor = input;
+        self.source_to_utf8_strict(& mut my_cursor).map(|v1| (my_cursor, v1))
+    }
+
+    /// A parser takes in UTF32 slice, and returns a Result object with
+    /// either the remaining input and the output byte value, or an
+    /// EncodeError.  The same as source_to_utf8_strict(); an invalid
+    /// value is the first value of `input`, and encoding may go on after
+    /// it.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUnicode::new();
+    /// let input = [0x41u32, 0xD800u32, 0x42u32];
+    /// let (rest, b) = parser.utf32_to_utf8_strict(& input).unwrap();
+    /// assert_eq!(b'A', b);
+    /// let error = StrictEncodeError { index: 1, value: 0xD800, kind: Utf32ErrorKind::Surrogate };
+    /// assert_eq!(Err(EncodeError::Invalid(error)), parser.utf32_to_utf8_strict(rest));
+    /// assert_eq!(Ok((&[][..], b'B')), parser.utf32_to_utf8_strict(& rest[1 ..]));
+    /// assert_eq!(Err(EncodeError::More(MoreEnum::More(0))), parser.utf32_to_utf8_strict(& []));
+    /// ```
+    pub fn utf32_to_utf8_strict<'c>(&mut self, input: &'c [u32])
+    -> Result<(&'c [u32], u8), EncodeError> {
+        let mut my_cursor = input;
+        self.source_to_utf8_strict(& mut my_cursor).map(|v1| (my_cursor, v1))
+    }
+
+    /// A parser takes in a CharSource, and returns a Result object with
+    /// either the output UTF16 code unit, or an EncodeError.  The UTF16
+    /// form of source_to_utf8_strict().
+    pub fn source_to_utf16_strict<S: CharSource + ?Sized>(&mut self, src: &mut S)
+    -> Result<u16, EncodeError> {
+        if let Option::Some(low) = self.my_low_surrogate.take() {
+            return Result::Ok(low);
+        }
+        match src.next_utf32() {
+            Option::Some(cur_u32) => {
+                if let Result::Err(error) = self.strict_check(cur_u32) {
+                    self.my_chars_in += 1;
+                    return Result::Err(EncodeError::Invalid(error));
+                }
+                Result::Ok(self.encode_utf32_to_utf16(cur_u32))
+            }
+            Option::None => {
+                Result::Err(EncodeError::More(self.more_indication()))
+            }
+        }
+    }
+}
+
+/// Default for FromUtf8 is the same as FromUtf8::new()
+impl Default for FromUtf8 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Default for FromUnicode is the same as FromUnicode::new()
+impl Default for FromUnicode {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implementation of Utf8IterToCharIter
+impl<'g, I: ?Sized> Utf8IterToCharIter<'g, I> {
+
+    #[inline]
+    /// Point this adapter at the iterator of the next buffer, and return
+    /// the previous source iterator.
+    ///
+    /// The parser state, including a partial sequence from the end of
+    /// the previous buffer, is kept, so one adapter can serve every
+    /// buffer of a stream.  Set the last buffer flag before presenting
+    /// the last buffer.
+    /// # Arguments
+    /// * `iter` - the source iterator of the next buffer
+    pub fn set_source(&mut self, iter: &'g mut I) -> &'g mut I {
+        core::mem::replace(& mut self.my_borrow_mut_iter, iter)
+    }
+}
+
+/// Implementations of common operations for Utf8IterToCharIter
+impl<'g, I: ?Sized> UtfParserCommon for Utf8IterToCharIter<'g, I> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_info.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_info.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_info.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid UTF8 sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_info.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid decodes state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_info.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    fn reset_parser(&mut self) {
+        self.my_info.reset_parser();
+    }
+}
+
+/// Iterator for Utf8IterToCharIter
+impl<'g, I> Iterator for Utf8IterToCharIter<'g, I>
+where I: Iterator<Item = u8> + ?Sized, {
+    type Item = char;
+
+    /// A parser takes in an iterator of UTF8 byte stream, and returns
+    /// an iterator of char values.
+    ///
+    /// An invalid Unicode decode in the stream are substituted with
+    /// an Unicode replacement character.
+    ///
+    /// has_invalid_sequence() would return true after observing
+    /// invalid decodes, or observing a replacement character.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.my_info.decode_from_iter(self.my_borrow_mut_iter)
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.my_borrow_mut_iter.size_hint()
+    }
+}
+
+/// Implementation of Utf8RefIterToCharIter
+impl<'g, I: ?Sized> Utf8RefIterToCharIter<'g, I> {
+
+    #[inline]
+    /// Point this adapter at the iterator of the next buffer, and return
+    /// the previous source iterator.
+    ///
+    /// The parser state, including a partial sequence from the end of
+    /// the previous buffer, is kept, so one adapter can serve every
+    /// buffer of a stream.  Set the last buffer flag before presenting
+    /// the last buffer.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mybuffers = [& b"a\xE2\x82"[..], & b"\xACb"[..]];
+    /// let mut iters = [mybuffers[0].iter(), mybuffers[1].iter()];
+    /// let (first, rest) = iters.split_first_mut().unwrap();
+    /// let mut parser = FromUtf8::new();
+    /// parser.set_is_last_buffer(false);
+    /// let mut iterator = parser.utf8_ref_to_char_with_iter(first);
+    /// let mut text: String = iterator.by_ref().collect();
+    /// iterator.set_is_last_buffer(true);
+    /// iterator.set_source(& mut rest[0]);
+    /// text.extend(iterator.by_ref());
+    /// assert_eq!("a\u{20AC}b", text);
+    /// ```
+    /// # Arguments
+    /// * `iter` - the source iterator of the next buffer
+    pub fn set_source(&mut self, iter: &'g mut I) -> &'g mut I {
+        core::mem::replace(& mut self.my_borrow_mut_iter, iter)
+    }
+}
+
+/// Implementations of common operations for Utf8RefIterToCharIter
+impl<'g, I: ?Sized> UtfParserCommon for Utf8RefIterToCharIter<'g, I> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_info.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_info.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_info.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid UTF8 sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_info.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid decodes state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_info.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    fn reset_parser(&mut self) {
+        self.my_info.reset_parser();
+    }
+}
+
+/// Iterator for Utf8RefIterToCharIter
+impl<'g, 'c, I> Iterator for Utf8RefIterToCharIter<'g, I>
+where I: Iterator<Item = &'c u8> + ?Sized, {
+    type Item = char;
+
+    /// A parser takes in an iterator of UTF8 byte stream, and returns
+    /// an iterator of char values.
+    ///
+    /// An invalid Unicode decode in the stream are substituted with
+    /// an Unicode replacement character.
+    ///
+    /// has_invalid_sequence() would return true after observing
+    /// invalid decodes, or observing a replacement character.
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut bytes = (& mut * self.my_borrow_mut_iter).copied();
+        self.my_info.decode_from_iter(& mut bytes)
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.my_borrow_mut_iter.size_hint()
+    }
+}
+
+/// Implementation of Utf32IterToUtf8Iter
+impl<'h, I: ?Sized> Utf32IterToUtf8Iter<'h, I> {
+
+    #[inline]
+    /// Point this adapter at the iterator of the next buffer, and return
+    /// the previous source iterator.
+    ///
+    /// The parser state, including a partial sequence from the end of
+    /// the previous buffer, is kept, so one adapter can serve every
+    /// buffer of a stream.  Set the last buffer flag before presenting
+    /// the last buffer.
+    /// # Arguments
+    /// * `iter` - the source iterator of the next buffer
+    pub fn set_source(&mut self, iter: &'h mut I) -> &'h mut I {
+        core::mem::replace(& mut self.my_borrow_mut_iter, iter)
+    }
+}
+
+/// Implementations of common operations for Utf32IterToUtf8Iter
+impl<'h, I: ?Sized> UtfParserCommon for Utf32IterToUtf8Iter<'h, I> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_info.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_info.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF32 sequence occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_info.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid UTF32 sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_info.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid decodes state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_info.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    fn reset_parser(&mut self) {
+        self.my_info.reset_parser();
+    }
+}
+
+/// Iterator for Utf32IterToUtf8Iter
+impl<'h, I> Iterator for Utf32IterToUtf8Iter<'h, I>
+where I: Iterator<Item = u32> + ?Sized, {
+    type Item = u8;
+
+    /// A parser takes in an iterator of Unicode codepoints, and returns
+    /// the output UTF8 byte value.
+    ///
+    /// An invalid Unicode codepoint in the stream are substituted with
+    /// an Unicode replacement character.
+    ///
+    /// has_invalid_sequence() would return true after observing
+    /// invalid decodes, or observing a replacement character.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.my_info.encode_from_iter(self.my_borrow_mut_iter)
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.my_borrow_mut_iter.size_hint()
+    }
+
+}
+
+/// Implementation of CharRefIterToUtf8Iter
+impl<'h, I: ?Sized> CharRefIterToUtf8Iter<'h, I> {
+
+    #[inline]
+    /// Point this adapter at the iterator of the next buffer, and return
+    /// the previous source iterator.
+    ///
+    /// The parser state, including a partial sequence from the end of
+    /// the previous buffer, is kept, so one adapter can serve every
+    /// buffer of a stream.  Set the last buffer flag before presenting
+    /// the last buffer.
+    /// # Arguments
+    /// * `iter` - the source iterator of the next buffer
+    pub fn set_source(&mut self, iter: &'h mut I) -> &'h mut I {
+        core::mem::replace(& mut self.my_borrow_mut_iter, iter)
+    }
+}
+
+/// Implementations of common operations for CharRefIterToUtf8Iter
+impl<'h, I: ?Sized> UtfParserCommon for CharRefIterToUtf8Iter<'h, I> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_info.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_info.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF32 sequence occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_info.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid UTF32 sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_info.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid decodes state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_info.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    fn reset_parser(&mut self) {
+        self.my_info.reset_parser();
+    }
+}
+
+/// Iterator for CharRefIterToUtf8Iter
+impl<'h, 'c, I> Iterator for CharRefIterToUtf8Iter<'h, I>
+where I: Iterator<Item = &'c char> + ?Sized, {
+    type Item = u8;
+
+    /// A parser takes in an iterator of Unicode codepoints, and returns
+    /// the output UTF8 byte value.
+    ///
+    /// An invalid Unicode codepoint in the stream are substituted with
+    /// an Unicode replacement character.
+    ///
+    /// has_invalid_sequence() would return true after observing
+    /// invalid decodes, or observing a replacement character.
+    fn next(&mut self) -> Option<Self::Item> {
+        // Check if we can pull an u8 from our ring buffer.
+        if let Option::Some(v1) = self.my_info.my_buf.pop_front() {
+            return Option::Some(v1);
+        }
+        if self.my_info.my_strict_error.is_some() {
+            // stopped by strict mode
+            return Option::None;
+        }
+        // Processing for input being empty case
+        match self.my_borrow_mut_iter.next() {
+            Option::None => {
+                Option::None
             }
-            Utf8TypeEnum::Type4((v1,v2,v3,v4)) => {
-                self.my_buf.push_back(v2);
-                self.my_buf.push_back(v3);
-                self.my_buf.push_back(v4);
-                Result::Ok((my_cursor, v1))
+            Option::Some(ch_ref) if ! self.my_info.check_strict(* ch_ref as u32) => {
+                Option::None
             }
-            _ => {
-                // Invalid UTF32 codepoint
-                // Emit replacement byte sequence.
-                self.signal_invalid_sequence();
-                self.my_buf.push_back(REPLACE_PART2);
-                self.my_buf.push_back(REPLACE_PART3);
-                Result::Ok((my_cursor, REPLACE_PART1))
+            Option::Some(ch_ref) => {
+                Option::Some(self.my_info.encode_utf32(* ch_ref as u32))
             }
         }
     }
 
-    /// Convert from UTF32 iter to UTF8 iter with a mutable reference
-    /// to the source UTF32 iterator.
-    pub fn utf32_to_utf8_with_iter<'d>(&'d mut self, iter: &'d mut dyn Iterator<Item = u32>)
-    -> Utf32IterToUtf8Iter {
-        Utf32IterToUtf8Iter {
-            my_borrow_mut_iter: iter,
-            my_info: self,
-        }
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.my_borrow_mut_iter.size_hint()
     }
 
-    /// Convert from char reference iter to UTF8 iter with a mutable reference
-    /// to the source char reference iterator.
-    pub fn char_ref_to_utf8_with_iter<'d>(&'d mut self, iter: &'d mut dyn Iterator<Item = &'d char>)
-    -> CharRefIterToUtf8Iter {
-        CharRefIterToUtf8Iter {
-            my_borrow_mut_iter: iter,
-            my_info: self,
-        }
-    }
+}
 
+/// Implementation of Utf8IterToUtf16Iter
+impl<'g, I: ?Sized> Utf8IterToUtf16Iter<'g, I> {
+
+    #[inline]
+    /// Point this adapter at the iterator of the next buffer, and return
+    /// the previous source iterator.
+    ///
+    /// The parser state, including a partial sequence from the end of
+    /// the previous buffer, is kept, so one adapter can serve every
+    /// buffer of a stream.  Set the last buffer flag before presenting
+    /// the last buffer.
+    /// # Arguments
+    /// * `iter` - the source iterator of the next buffer
+    pub fn set_source(&mut self, iter: &'g mut I) -> &'g mut I {
+        core::mem::replace(& mut self.my_borrow_mut_iter, iter)
+    }
 }
 
-/// Implementations of common operations for Utf8IterToCharIter
-impl<'g> UtfParserCommon for Utf8IterToCharIter<'g> {
+/// Implementations of common operations for Utf8IterToUtf16Iter
+impl<'g, I: ?Sized> UtfParserCommon for Utf8IterToUtf16Iter<'g, I> {
 
     #[inline]
     /// If argument `b` is true, then any input buffer to be presented will
@@ -1444,17 +4209,20 @@ impl<'g> UtfParserCommon for Utf8IterToCharIter<'g> {
     /// Reset all parser states to the initial value.
     /// Last buffer indication is set to true.
     /// Invalid decodes indication is cleared.
+    /// A waiting low surrogate is dropped.
     fn reset_parser(&mut self) {
+        self.my_low_surrogate = Option::None;
         self.my_info.reset_parser();
     }
 }
 
-/// Iterator for Utf8IterToCharIter
-impl<'g> Iterator for Utf8IterToCharIter<'g> {
-    type Item = char;
+/// Iterator for Utf8IterToUtf16Iter
+impl<'g, I> Iterator for Utf8IterToUtf16Iter<'g, I>
+where I: Iterator<Item = u8> + ?Sized, {
+    type Item = u16;
 
     /// A parser takes in an iterator of UTF8 byte stream, and returns
-    /// an iterator of char values.
+    /// an iterator of UTF16 code units.
     ///
     /// An invalid Unicode decode in the stream are substituted with
     /// an Unicode replacement character.
@@ -1462,64 +4230,50 @@ impl<'g> Iterator for Utf8IterToCharIter<'g> {
     /// has_invalid_sequence() would return true after observing
     /// invalid decodes, or observing a replacement character.
     fn next(&mut self) -> Option<Self::Item> {
-        // Fill buffer phase.
-        loop {
-            if self.my_info.my_buf.is_full() {
-                break;
-            }
-            match self.my_borrow_mut_iter.next() {
-                Option::None => {
-                    break;
-                }
-                Option::Some(utf8) => {
-                    // Save it in our scratch pad.
-                    self.my_info.my_buf.push_back(utf8);
-                }
-            }
-        }
-        if self.my_info.my_buf.is_empty() {
-            // This is either the end of data, or the current buffer
-            // has run to the end without left-over data in the
-            // scratch pad.
-            Option::None
+        if let Option::Some(low) = self.my_low_surrogate.take() {
+            return Option::Some(low);
         }
-        else {
-            let last_buffer = self.my_info.is_last_buffer();
-            match utf8_decode(& mut self.my_info.my_buf, last_buffer) {
-                Utf8EndEnum::BadDecode(_) => {
-                    self.my_info.signal_invalid_sequence();
-                    Option::Some(char::REPLACEMENT_CHARACTER)
-                }
-                Utf8EndEnum::Finish(code) => {
-                    // Unsafe is justified because utf8_decode() finite state
-                    // machine checks for all cases of invalid decodes.
-                    let ch = unsafe { char::from_u32_unchecked(code) };
-                    Option::Some(ch)
-                }
-                Utf8EndEnum::TypeUnknown => {
-                    // Insufficient data to decode.
-                    if last_buffer {
-                        self.my_info.signal_invalid_sequence();
-                        // Buffer should be empty at this point.
-                        Option::Some(char::REPLACEMENT_CHARACTER)
-                    }
-                    else {
-                        // Ready for next buffer
-                        Option::None
-                    }
-                }
+        let ch = self.my_info.decode_from_iter(self.my_borrow_mut_iter)?;
+        let mut units = [0u16; 2];
+        match * ch.encode_utf16(& mut units) {
+            [high, low] => {
+                self.my_low_surrogate = Option::Some(low);
+                Option::Some(high)
             }
+            _ => Option::Some(units[0]),
         }
     }
 
     /// sizing hint for iterator, with a lower bound and optional upperbound
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.my_borrow_mut_iter.size_hint()
+        // A 4 byte sequence produces 2 code units; every other byte
+        // produces at most one.
+        let (_lower, upper) = self.my_borrow_mut_iter.size_hint();
+        let pending = self.my_low_surrogate.is_some() as usize + self.my_info.my_buf.len() as usize;
+        (self.my_low_surrogate.is_some() as usize, upper.and_then(|v| v.checked_add(pending)))
     }
 }
 
-/// Implementations of common operations for Utf8RefIterToCharIter
-impl<'g> UtfParserCommon for Utf8RefIterToCharIter<'g> {
+/// Implementation of Utf32IterToUtf16Iter
+impl<'h, I: ?Sized> Utf32IterToUtf16Iter<'h, I> {
+
+    #[inline]
+    /// Point this adapter at the iterator of the next buffer, and return
+    /// the previous source iterator.
+    ///
+    /// The parser state, including a low surrogate waiting to be
+    /// returned, is kept, so one adapter can serve every buffer of a
+    /// stream.  Set the last buffer flag before presenting the last
+    /// buffer.
+    /// # Arguments
+    /// * `iter` - the source iterator of the next buffer
+    pub fn set_source(&mut self, iter: &'h mut I) -> &'h mut I {
+        core::mem::replace(& mut self.my_borrow_mut_iter, iter)
+    }
+}
+
+/// Implementations of common operations for Utf32IterToUtf16Iter
+impl<'h, I: ?Sized> UtfParserCommon for Utf32IterToUtf16Iter<'h, I> {
 
     #[inline]
     /// If argument `b` is true, then any input buffer to be presented will
@@ -1535,14 +4289,14 @@ impl<'g> UtfParserCommon for Utf8RefIterToCharIter<'g> {
     }
 
     #[inline]
-    /// This function returns true if invalid UTF8 sequence occurred
+    /// This function returns true if invalid UTF32 sequence occurred
     /// in this parsing stream.
     fn has_invalid_sequence(&self) -> bool {
         self.my_info.has_invalid_sequence()
     }
 
     #[inline]
-    /// This function signals the occurrence of an invalid UTF8 sequence.
+    /// This function signals the occurrence of an invalid UTF32 sequence.
     fn signal_invalid_sequence(&mut self) {
         self.my_info.signal_invalid_sequence();
     }
@@ -1562,77 +4316,54 @@ impl<'g> UtfParserCommon for Utf8RefIterToCharIter<'g> {
     }
 }
 
-/// Iterator for Utf8RefIterToCharIter
-impl<'g> Iterator for Utf8RefIterToCharIter<'g> {
-    type Item = char;
+/// Iterator for Utf32IterToUtf16Iter
+impl<'h, I> Iterator for Utf32IterToUtf16Iter<'h, I>
+where I: Iterator<Item = u32> + ?Sized, {
+    type Item = u16;
 
-    /// A parser takes in an iterator of UTF8 byte stream, and returns
-    /// an iterator of char values.
+    /// A parser takes in an iterator of Unicode codepoints, and returns
+    /// the output UTF16 code unit.
     ///
-    /// An invalid Unicode decode in the stream are substituted with
+    /// An invalid Unicode codepoint in the stream are substituted with
     /// an Unicode replacement character.
     ///
     /// has_invalid_sequence() would return true after observing
     /// invalid decodes, or observing a replacement character.
     fn next(&mut self) -> Option<Self::Item> {
-        // Fill buffer phase.
-        loop {
-            if self.my_info.my_buf.is_full() {
-                break;
-            }
-            match self.my_borrow_mut_iter.next() {
-                Option::None => {
-                    break;
-                }
-                Option::Some(utf8) => {
-                    // Save it in our scratch pad.
-                    self.my_info.my_buf.push_back(* utf8);
-                }
-            }
-        }
-        if self.my_info.my_buf.is_empty() {
-            // This is either the end of data, or the current buffer
-            // has run to the end without left-over data in the
-            // scratch pad.
-            Option::None
-        }
-        else {
-            let last_buffer = self.my_info.is_last_buffer();
-            match utf8_decode(& mut self.my_info.my_buf, last_buffer) {
-                Utf8EndEnum::BadDecode(_) => {
-                    self.my_info.signal_invalid_sequence();
-                    Option::Some(char::REPLACEMENT_CHARACTER)
-                }
-                Utf8EndEnum::Finish(code) => {
-                    // Unsafe is justified because utf8_decode() finite state
-                    // machine checks for all cases of invalid decodes.
-                    let ch = unsafe { char::from_u32_unchecked(code) };
-                    Option::Some(ch)
-                }
-                Utf8EndEnum::TypeUnknown => {
-                    // Insufficient data to decode.
-                    if last_buffer {
-                        self.my_info.signal_invalid_sequence();
-                        // Buffer should be empty at this point.
-                        Option::Some(char::REPLACEMENT_CHARACTER)
-                    }
-                    else {
-                        // Ready for next buffer
-                        Option::None
-                    }
-                }
-            }
-        }
+        let iter = & mut * self.my_borrow_mut_iter;
+        self.my_info.encode_utf16_from(|| iter.next())
     }
 
     /// sizing hint for iterator, with a lower bound and optional upperbound
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.my_borrow_mut_iter.size_hint()
+        // Each codepoint produces up to 2 code units; strict mode may
+        // stop early.
+        let (_lower, upper) = self.my_borrow_mut_iter.size_hint();
+        let pending = self.my_info.my_low_surrogate.is_some() as usize;
+        (pending, upper.and_then(|v| v.checked_mul(2)).and_then(|v| v.checked_add(pending)))
     }
 }
 
-/// Implementations of common operations for Utf32IterToUtf8Iter
-impl<'h> UtfParserCommon for Utf32IterToUtf8Iter<'h> {
+/// Implementation of CharRefIterToUtf16Iter
+impl<'h, I: ?Sized> CharRefIterToUtf16Iter<'h, I> {
+
+    #[inline]
+    /// Point this adapter at the iterator of the next buffer, and return
+    /// the previous source iterator.
+    ///
+    /// The parser state, including a low surrogate waiting to be
+    /// returned, is kept, so one adapter can serve every buffer of a
+    /// stream.  Set the last buffer flag before presenting the last
+    /// buffer.
+    /// # Arguments
+    /// * `iter` - the source iterator of the next buffer
+    pub fn set_source(&mut self, iter: &'h mut I) -> &'h mut I {
+        core::mem::replace(& mut self.my_borrow_mut_iter, iter)
+    }
+}
+
+/// Implementations of common operations for CharRefIterToUtf16Iter
+impl<'h, I: ?Sized> UtfParserCommon for CharRefIterToUtf16Iter<'h, I> {
 
     #[inline]
     /// If argument `b` is true, then any input buffer to be presented will
@@ -1675,12 +4406,13 @@ impl<'h> UtfParserCommon for Utf32IterToUtf8Iter<'h> {
     }
 }
 
-/// Iterator for Utf32IterToUtf8Iter
-impl<'h> Iterator for Utf32IterToUtf8Iter<'h> {
-    type Item = u8;
+/// Iterator for CharRefIterToUtf16Iter
+impl<'h, 'c, I> Iterator for CharRefIterToUtf16Iter<'h, I>
+where I: Iterator<Item = &'c char> + ?Sized, {
+    type Item = u16;
 
     /// A parser takes in an iterator of Unicode codepoints, and returns
-    /// the output UTF8 byte value.
+    /// the output UTF16 code unit.
     ///
     /// An invalid Unicode codepoint in the stream are substituted with
     /// an Unicode replacement character.
@@ -1688,61 +4420,92 @@ impl<'h> Iterator for Utf32IterToUtf8Iter<'h> {
     /// has_invalid_sequence() would return true after observing
     /// invalid decodes, or observing a replacement character.
     fn next(&mut self) -> Option<Self::Item> {
-        // Check if we can pull an u8 from our ring buffer.
-        match self.my_info.my_buf.pop_front() {
-            Option::Some(v1) => {
-                return Option::Some(v1);
-            }
-            Option::None => {}
-        }
-        // Processing for input being empty case
-        match self.my_borrow_mut_iter.next() {
-            Option::None => {
-                return Option::None;
-            }
-            Option::Some(utf32) => {
-                // Try to determine the type of UTFf32 encoding.
-                match classify_utf32(utf32) {
-                    Utf8TypeEnum::Type1(v1) => {
-                        Option::Some(v1)
-                    }
-                    Utf8TypeEnum::Type2((v1,v2)) => {
-                        self.my_info.my_buf.push_back(v2);
-                        Option::Some(v1)
-                    }
-                    Utf8TypeEnum::Type3((v1,v2,v3)) => {
-                        self.my_info.my_buf.push_back(v2);
-                        self.my_info.my_buf.push_back(v3);
-                        Option::Some(v1)
-                    }
-                    Utf8TypeEnum::Type4((v1,v2,v3,v4)) => {
-                        self.my_info.my_buf.push_back(v2);
-                        self.my_info.my_buf.push_back(v3);
-                        self.my_info.my_buf.push_back(v4);
-                        Option::Some(v1)
-                    }
-                    _ => {
-                        // Invalid UTF32 codepoint
-                        // Emit replacement byte sequence.
-                        self.my_info.signal_invalid_sequence();
-                        self.my_info.my_buf.push_back(REPLACE_PART2);
-                        self.my_info.my_buf.push_back(REPLACE_PART3);
-                        Option::Some(REPLACE_PART1)
-                    }
-                }
-            }
-        }
+        let iter = & mut * self.my_borrow_mut_iter;
+        self.my_info.encode_utf16_from(|| iter.next().map(|ch_ref| * ch_ref as u32))
     }
 
     /// sizing hint for iterator, with a lower bound and optional upperbound
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.my_borrow_mut_iter.size_hint()
+        // Each codepoint produces up to 2 code units; strict mode may
+        // stop early.
+        let (_lower, upper) = self.my_borrow_mut_iter.size_hint();
+        let pending = self.my_info.my_low_surrogate.is_some() as usize;
+        (pending, upper.and_then(|v| v.checked_mul(2)).and_then(|v| v.checked_add(pending)))
+    }
+}
+
+/// Implementations of common operations for Utf8IntoCharIter
+impl<I> UtfParserCommon for Utf8IntoCharIter<I> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_info.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_info.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_info.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid UTF8 sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_info.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid decodes state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_info.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    fn reset_parser(&mut self) {
+        self.my_info.reset_parser();
     }
+}
+
+/// Inherent functions of Utf8IntoCharIter
+impl<I> Utf8IntoCharIter<I> {
 
+    /// Return the source iterator and the parser, so that the parser
+    /// state can be carried over to the next buffer.
+    pub fn into_parts(self) -> (I, FromUtf8) {
+        (self.my_iter, self.my_info)
+    }
 }
 
-/// Implementations of common operations for CharRefIterToUtf8Iter
-impl<'h> UtfParserCommon for CharRefIterToUtf8Iter<'h> {
+/// Iterator for Utf8IntoCharIter
+impl<I> Iterator for Utf8IntoCharIter<I>
+where I: Iterator<Item = u8>, {
+    type Item = char;
+
+    /// Same as the Utf8IterToCharIter iterator.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.my_info.decode_from_iter(& mut self.my_iter)
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.my_iter.size_hint()
+    }
+}
+
+/// Implementations of common operations for Utf32IntoUtf8Iter
+impl<I> UtfParserCommon for Utf32IntoUtf8Iter<I> {
 
     #[inline]
     /// If argument `b` is true, then any input buffer to be presented will
@@ -1764,94 +4527,208 @@ impl<'h> UtfParserCommon for CharRefIterToUtf8Iter<'h> {
         self.my_info.has_invalid_sequence()
     }
 
-    #[inline]
-    /// This function signals the occurrence of an invalid UTF32 sequence.
-    fn signal_invalid_sequence(&mut self) {
-        self.my_info.signal_invalid_sequence();
+    #[inline]
+    /// This function signals the occurrence of an invalid UTF32 sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_info.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid decodes state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_info.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    fn reset_parser(&mut self) {
+        self.my_info.reset_parser();
+    }
+}
+
+/// Inherent functions of Utf32IntoUtf8Iter
+impl<I> Utf32IntoUtf8Iter<I> {
+
+    /// Return the source iterator and the parser, so that the parser
+    /// state can be carried over to the next buffer.
+    pub fn into_parts(self) -> (I, FromUnicode) {
+        (self.my_iter, self.my_info)
+    }
+}
+
+/// Iterator for Utf32IntoUtf8Iter
+impl<I> Iterator for Utf32IntoUtf8Iter<I>
+where I: Iterator<Item = u32>, {
+    type Item = u8;
+
+    /// Same as the Utf32IterToUtf8Iter iterator.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.my_info.encode_from_iter(& mut self.my_iter)
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.my_iter.size_hint()
+    }
+}
+
+/// defmt formatting of FromUtf8: the parser flags, and the number of
+/// bytes pending in the scratch pad
+#[cfg(feature = "defmt")]
+impl defmt::Format for FromUtf8 {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "FromUtf8 {{ last_buffer: {=bool}, invalid_sequence: {=bool}, pending_len: {=u32:#x} }}",
+            self.my_last_buffer, self.my_invalid_sequence, self.my_buf.len());
+    }
+}
+
+/// defmt formatting of FromUnicode: the parser flags, and the number of
+/// bytes pending in the scratch pad
+#[cfg(feature = "defmt")]
+impl defmt::Format for FromUnicode {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "FromUnicode {{ last_buffer: {=bool}, invalid_sequence: {=bool}, pending_len: {=u32:#x} }}",
+            self.my_last_buffer, self.my_invalid_sequence, self.my_buf.len());
+    }
+}
+
+/// Debug formatting of the bytes pending in a scratch pad, in hex
+struct PendingBytes<'a>(&'a EightBytes);
+
+impl<'a> fmt::Debug for PendingBytes<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for indx in 0 .. self.0.len() as usize {
+            if let Option::Some(v) = self.0.peek_at(indx) {
+                list.entry(&format_args!("{:#04x}", v));
+            }
+        }
+        list.finish()
+    }
+}
+
+/// Debug formatting shared by the parser adapters: the parser flags,
+/// and the bytes pending in the scratch pad.
+fn fmt_parser_adapter(f: &mut fmt::Formatter<'_>, name: &str, buf: &EightBytes,
+last_buffer: bool, invalid_sequence: bool) -> fmt::Result {
+    f.debug_struct(name)
+    .field("last_buffer", &last_buffer)
+    .field("invalid_sequence", &invalid_sequence)
+    .field("pending_len", &format_args!("{:#x}", buf.len()))
+    .field("pending", &PendingBytes(buf))
+    .finish_non_exhaustive()
+}
+
+impl<'g, I: ?Sized> fmt::Debug for Utf8IterToCharIter<'g, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_parser_adapter(f, "Utf8IterToCharIter", & self.my_info.my_buf,
+            self.my_info.my_last_buffer, self.my_info.my_invalid_sequence)
+    }
+}
+
+impl<'g, I: ?Sized> fmt::Debug for Utf8RefIterToCharIter<'g, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_parser_adapter(f, "Utf8RefIterToCharIter", & self.my_info.my_buf,
+            self.my_info.my_last_buffer, self.my_info.my_invalid_sequence)
+    }
+}
+
+impl<'h, I: ?Sized> fmt::Debug for Utf32IterToUtf8Iter<'h, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_parser_adapter(f, "Utf32IterToUtf8Iter", & self.my_info.my_buf,
+            self.my_info.my_last_buffer, self.my_info.my_invalid_sequence)
     }
+}
 
-    #[inline]
-    /// This function resets the invalid decodes state.
-    fn reset_invalid_sequence(& mut self) {
-        self.my_info.reset_invalid_sequence();
+impl<'h, I: ?Sized> fmt::Debug for CharRefIterToUtf8Iter<'h, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_parser_adapter(f, "CharRefIterToUtf8Iter", & self.my_info.my_buf,
+            self.my_info.my_last_buffer, self.my_info.my_invalid_sequence)
     }
+}
 
-    #[inline]
-    /// Reset all parser states to the initial value.
-    /// Last buffer indication is set to true.
-    /// Invalid decodes indication is cleared.
-    fn reset_parser(&mut self) {
-        self.my_info.reset_parser();
+impl<'g, I: ?Sized> fmt::Debug for Utf8IterToUtf16Iter<'g, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_parser_adapter(f, "Utf8IterToUtf16Iter", & self.my_info.my_buf,
+            self.my_info.my_last_buffer, self.my_info.my_invalid_sequence)
     }
 }
 
-/// Iterator for CharRefIterToUtf8Iter
-impl<'h> Iterator for CharRefIterToUtf8Iter<'h> {
-    type Item = u8;
+impl<'h, I: ?Sized> fmt::Debug for Utf32IterToUtf16Iter<'h, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf32IterToUtf16Iter")
+        .field("parser", & self.my_info)
+        .finish_non_exhaustive()
+    }
+}
 
-    /// A parser takes in an iterator of Unicode codepoints, and returns
-    /// the output UTF8 byte value.
-    ///
-    /// An invalid Unicode codepoint in the stream are substituted with
-    /// an Unicode replacement character.
-    ///
-    /// has_invalid_sequence() would return true after observing
-    /// invalid decodes, or observing a replacement character.
-    fn next(&mut self) -> Option<Self::Item> {
-        // Check if we can pull an u8 from our ring buffer.
-        match self.my_info.my_buf.pop_front() {
-            Option::Some(v1) => {
-                return Option::Some(v1);
-            }
-            Option::None => {}
-        }
-        // Processing for input being empty case
-        match self.my_borrow_mut_iter.next() {
-            Option::None => {
-                return Option::None;
-            }
-            Option::Some(ch_ref) => {
-                let utf32 = (* ch_ref) as u32;
-                // Try to determine the type of UTFf32 encoding.
-                match classify_utf32(utf32) {
-                    Utf8TypeEnum::Type1(v1) => {
-                        Option::Some(v1)
-                    }
-                    Utf8TypeEnum::Type2((v1,v2)) => {
-                        self.my_info.my_buf.push_back(v2);
-                        Option::Some(v1)
-                    }
-                    Utf8TypeEnum::Type3((v1,v2,v3)) => {
-                        self.my_info.my_buf.push_back(v2);
-                        self.my_info.my_buf.push_back(v3);
-                        Option::Some(v1)
-                    }
-                    Utf8TypeEnum::Type4((v1,v2,v3,v4)) => {
-                        self.my_info.my_buf.push_back(v2);
-                        self.my_info.my_buf.push_back(v3);
-                        self.my_info.my_buf.push_back(v4);
-                        Option::Some(v1)
-                    }
-                    _ => {
-                        // Invalid UTF32 codepoint
-                        // Emit replacement byte sequence.
-                        self.my_info.signal_invalid_sequence();
-                        self.my_info.my_buf.push_back(REPLACE_PART2);
-                        self.my_info.my_buf.push_back(REPLACE_PART3);
-                        Option::Some(REPLACE_PART1)
-                    }
-                }
-            }
-        }
+impl<'h, I: ?Sized> fmt::Debug for CharRefIterToUtf16Iter<'h, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CharRefIterToUtf16Iter")
+        .field("parser", & self.my_info)
+        .finish_non_exhaustive()
+    }
+}
+
+impl<I> fmt::Debug for Utf8IntoCharIter<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_parser_adapter(f, "Utf8IntoCharIter", & self.my_info.my_buf,
+            self.my_info.my_last_buffer, self.my_info.my_invalid_sequence)
     }
+}
 
-    /// sizing hint for iterator, with a lower bound and optional upperbound
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.my_borrow_mut_iter.size_hint()
+impl<I> fmt::Debug for Utf32IntoUtf8Iter<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_parser_adapter(f, "Utf32IntoUtf8Iter", & self.my_info.my_buf,
+            self.my_info.my_last_buffer, self.my_info.my_invalid_sequence)
     }
+}
 
+impl<'b, I: ?Sized> fmt::Debug for BomAndCarriageReturnFilterStruct<'b, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BomAndCarriageReturnFilterStruct")
+        .field("start_stream", &self.my_start_stream)
+        .field("prev_cr", &self.my_prev_cr)
+        .finish_non_exhaustive()
+    }
 }
 
+// Compile time checks that the parsers are Send and Sync, and that
+// the adapters are Send and Sync whenever the source iterator is.
+const _: () = {
+    fn is_send_sync<T: Send + Sync>() {}
+
+    #[allow(dead_code)]
+    fn check_send_sync<'a, B, C, U, RB, RC, RU>()
+    where B: 'a + Iterator<Item = u8> + Send + Sync,
+    C: 'a + Iterator<Item = char> + Send + Sync,
+    U: 'a + Iterator<Item = u32> + Send + Sync,
+    RB: 'a + Iterator<Item = &'a u8> + Send + Sync,
+    RC: 'a + Iterator<Item = &'a char> + Send + Sync,
+    RU: 'a + Iterator<Item = &'a u32> + Send + Sync, {
+        is_send_sync::<FromUtf8>();
+        is_send_sync::<FromUnicode>();
+        is_send_sync::<Utf8IterToCharIter<'a, B>>();
+        is_send_sync::<Utf8RefIterToCharIter<'a, RB>>();
+        is_send_sync::<Utf32IterToUtf8Iter<'a, U>>();
+        is_send_sync::<CharRefIterToUtf8Iter<'a, RC>>();
+        is_send_sync::<Utf8IterToUtf16Iter<'a, B>>();
+        is_send_sync::<Utf32IterToUtf16Iter<'a, U>>();
+        is_send_sync::<CharRefIterToUtf16Iter<'a, RC>>();
+        is_send_sync::<Utf8IntoCharIter<B>>();
+        is_send_sync::<Utf32IntoUtf8Iter<U>>();
+        is_send_sync::<CharRefToCharStruct<'a, RC>>();
+        is_send_sync::<Utf32RefToUtf32Struct<'a, RU>>();
+        is_send_sync::<Utf8RefToUtf8Struct<'a, RB>>();
+        is_send_sync::<CharToUtf32Struct<'a, C>>();
+        is_send_sync::<BomAndCarriageReturnFilterStruct<'a, C>>();
+        is_send_sync::<CharToCharChunkStruct<'a, 4, C>>();
+        is_send_sync::<CharToStrStruct<'a, C>>();
+    }
+};
+
 #[cfg(test)]
 mod tests {
     extern crate std;
@@ -1860,11 +4737,10 @@ mod tests {
 
     // Print bytes in hex codes.
     fn _print_bytes(u8_slice: & [u8]) {
-        for indx in 0 .. u8_slice.len() {
-            let b = u8_slice[indx] as u32;
-            print!(" {:#02x}", b);
+        for b in u8_slice {
+            print!(" {:#02x}", * b as u32);
         }
-        println!("");
+        println!();
     }
 
     #[test]
@@ -1897,6 +4773,969 @@ mod tests {
         assert_eq!(Option::None, filter_iter.next());
     }
 
+    #[test]
+    // Test BOM removal at file boundaries of a concatenated stream
+    fn test_filter_bom_new_file() {
+        // input and output of each file
+        let files = [
+            ("\u{FEFF}one\r", "one\n"),
+            ("\u{FEFF}\ntwo", "\ntwo"),
+            ("three\u{FEFF}", "three\u{FEFF}"),
+            ("\u{FEFF}\u{FEFF}", "\u{FEFF}"),
+        ];
+        let stream: std::string::String = files.iter().map(|v| v.0).collect();
+        let mut chars = stream.chars();
+        let mut filter_iter = filter_bom_and_cr_iter(& mut chars);
+        for (_input, truth) in files.iter() {
+            filter_iter.start_new_file();
+            let output: std::string::String = filter_iter.by_ref().take(truth.chars().count()).collect();
+            assert_eq!(* truth, output);
+        }
+        assert_eq!(Option::None, filter_iter.next());
+    }
+
+    #[test]
+    // Test scanning for the first non-ASCII byte
+    fn test_find_non_ascii() {
+        let mut byte_box = [b'a'; 80];
+        assert_eq!(Option::None, find_non_ascii(& byte_box));
+        assert_eq!(Option::None, find_non_ascii(& byte_box[0 .. 0]));
+        for pos in 0 .. byte_box.len() {
+            byte_box[pos] = 0x80;
+            for start in 0 .. 9 {
+                let expected = if pos >= start { Some(pos - start) } else { None };
+                assert_eq!(expected, find_non_ascii(& byte_box[start ..]));
+            }
+            byte_box[pos] = 0x7F;
+        }
+        assert_eq!(Some(2), find_non_ascii("ab\u{FF}cdefghijk".as_bytes()));
+    }
+
+    #[test]
+    // Test measuring, then converting into exact size buffers
+    fn test_measure_then_convert() {
+        let bytes = [0x41u8, 0xE2u8, 0x82u8, 0xACu8, 0xC0u8, 0xE2u8, 0x41u8, 0xF0u8, 0x9Fu8];
+        let num_chars = measure_utf8_to_char(& bytes);
+        assert_eq!(std::string::String::from_utf8_lossy(& bytes).chars().count(), num_chars);
+        let mut chars = std::vec![char::MAX; num_chars];
+        assert_eq!(Option::Some(num_chars), convert_utf8_to_char(& bytes, & mut chars));
+        assert_eq!(Option::None, convert_utf8_to_char(& bytes, & mut chars[1 ..]));
+        assert_eq!(Option::None, convert_utf8_to_char(& bytes, & mut []));
+        assert_eq!(Option::Some(0), convert_utf8_to_char(& [], & mut []));
+
+        let num_bytes = utf8_len_of_slice(& chars);
+        let mut encoded = std::vec![0u8; num_bytes];
+        assert_eq!(Option::Some(num_bytes), convert_to_utf8(& chars, & mut encoded));
+        let text: std::string::String = chars.iter().collect();
+        assert_eq!(text.as_bytes(), & encoded[..]);
+        assert_eq!(Option::None, convert_to_utf8(& chars, & mut encoded[1 ..]));
+        let codes = [0x41u32, 0x110000];
+        assert_eq!(Option::Some(4), convert_to_utf8(& codes, & mut encoded));
+    }
+
+    #[test]
+    // Test the one-liner conversions
+    fn test_decode_lossy_encode() {
+        let bytes = [0x41u8, 0xE2u8, 0x82u8, 0xACu8, 0xC0u8, 0xF0u8, 0x9Fu8];
+        let text: std::string::String = decode_lossy(& bytes).collect();
+        assert_eq!(std::string::String::from_utf8_lossy(& bytes), text);
+        let encoded: std::vec::Vec<u8> = encode(text.chars()).collect();
+        assert_eq!(text.as_bytes(), & encoded[..]);
+        assert_eq!(0, decode_lossy(& []).count());
+        assert_eq!(0, encode(['\0'; 0]).count());
+    }
+
+    #[test]
+    // Test lossy display of byte slices
+    fn test_display_utf8_lossy() {
+        let bytes = [0x41u8, 0xEDu8, 0xA0u8, 0x80u8, 0xF0u8, 0x90u8, 0x8Du8, 0x88u8, 0xE2u8, 0x82u8];
+        let truth = std::string::String::from_utf8_lossy(& bytes);
+        assert_eq!(truth, std::format!("{}", DisplayUtf8Lossy(& bytes)));
+        // Longer than one char box
+        let text = "0123456789\u{20AC}".repeat(10);
+        assert_eq!(text, std::format!("{}", DisplayUtf8Lossy(text.as_bytes())));
+        assert_eq!("[]", std::format!("[{}]", DisplayUtf8Lossy(& [])));
+    }
+
+    #[test]
+    // Test every CharSource with the same encoder method
+    fn test_char_source() {
+        let text = "a\u{7FF}\u{20AC}\u{10348}";
+        let chars: std::vec::Vec<char> = text.chars().collect();
+        let codes: std::vec::Vec<u32> = text.chars().map(|v| v as u32).collect();
+        let mut encoder = FromUnicode::new();
+
+        fn encode_all<S: CharSource>(encoder: & mut FromUnicode, mut src: S) -> std::vec::Vec<u8> {
+            let mut byte_box = [0u8; 3];
+            let mut output = std::vec::Vec::new();
+            // The loop stops at MoreEnum::More(_amt);
+            // _amt equals to 0 when end of data
+            while let Result::Ok(num_bytes) = encoder.source_to_utf8_slice(& mut src, & mut byte_box) {
+                output.extend_from_slice(& byte_box[0 .. num_bytes]);
+            }
+            output
+        }
+
+        assert_eq!(text.as_bytes(), & encode_all(& mut encoder, text)[..]);
+        assert_eq!(text.as_bytes(), & encode_all(& mut encoder, & chars[..])[..]);
+        assert_eq!(text.as_bytes(), & encode_all(& mut encoder, & codes[..])[..]);
+        assert_eq!(text.as_bytes(), & encode_all(& mut encoder, IterSource(text.chars()))[..]);
+        assert!(! encoder.has_invalid_sequence());
+
+        // A slice source is left at the remaining input.
+        let mut src = "\u{20AC}b";
+        encoder.set_is_last_buffer(false);
+        assert_eq!(Result::Ok(0xE2u8), encoder.source_to_utf8(& mut src));
+        assert_eq!("b", src);
+        assert_eq!(Result::Ok(2), encoder.source_to_utf8_slice(& mut src, & mut [0u8; 2]));
+        assert_eq!(Result::Ok(1), encoder.source_to_utf8_slice(& mut src, & mut [0u8; 2]));
+        assert_eq!(Result::Err(MoreEnum::More(1024)), encoder.source_to_utf8(& mut src));
+        assert_eq!(Result::Ok(0xEFu8), encoder.source_to_utf8(& mut IterSource([0xDFFFu32].iter().copied())));
+        assert!(encoder.has_invalid_sequence());
+    }
+
+    #[test]
+    // Test push style encoding
+    fn test_push_char_drain() {
+        let mut encoder = FromUnicode::new();
+        let mut output = std::vec::Vec::new();
+        let mut byte_box = [0u8; 3];
+        for code in "a\u{7FF}\u{20AC}\u{10348}".chars().map(|v| v as u32).chain([0xD800u32]) {
+            while ! encoder.push_utf32(code) {
+                let num_bytes = encoder.drain_bytes(& mut byte_box);
+                output.extend_from_slice(& byte_box[0 .. num_bytes]);
+            }
+        }
+        assert!(encoder.has_invalid_sequence());
+        assert!(! encoder.push_char('\u{10000}'));
+        loop {
+            let num_bytes = encoder.drain_bytes(& mut byte_box);
+            if num_bytes == 0 {
+                break;
+            }
+            output.extend_from_slice(& byte_box[0 .. num_bytes]);
+        }
+        assert_eq!("a\u{7FF}\u{20AC}\u{10348}\u{FFFD}".as_bytes(), & output[..]);
+        assert_eq!(0, encoder.drain_bytes(& mut []));
+    }
+
+    #[test]
+    // Test string slice sources for the encoder
+    fn test_str_source() {
+        let text = "a\u{7FF}\u{20AC}\u{10348}";
+        let codes: std::vec::Vec<u32> = str_to_utf32_iter(text).collect();
+        assert_eq!([0x61u32, 0x7FF, 0x20AC, 0x10348], codes[..]);
+        let mut iter = FromUnicode::new().str_into_utf8_iter(text);
+        let bytes: std::vec::Vec<u8> = iter.by_ref().collect();
+        assert_eq!(text.as_bytes(), & bytes[..]);
+        assert!(! iter.has_invalid_sequence());
+        let mut iter = FromUnicode::new().str_into_utf8_iter("x\u{FFFD}");
+        assert_eq!(4, iter.by_ref().count());
+        assert!(iter.has_invalid_sequence());
+    }
+
+    #[test]
+    // Test the number of bytes consumed by utf8_decode_with_len()
+    fn test_utf8_decode_with_len() {
+        let cases: [(& [u8], bool, Utf8EndEnum, u32); 8] = [
+            (& [0x41u8, 0x42u8], false, Utf8EndEnum::Finish(0x41), 1),
+            (& [0xF0u8, 0x90u8, 0x8Du8, 0x88u8], false, Utf8EndEnum::Finish(0x10348), 4),
+            (& [0xE2u8, 0x41u8, 0x41u8], false, Utf8EndEnum::BadDecode(1), 1),
+            (& [0xF4u8, 0x8Fu8, 0xBFu8, 0x41u8], false, Utf8EndEnum::BadDecode(3), 3),
+            (& [0xEFu8, 0xBFu8, 0xBDu8], false, Utf8EndEnum::BadDecode(3), 3),
+            (& [0xE2u8, 0x82u8], false, Utf8EndEnum::TypeUnknown, 0),
+            (& [0xE2u8, 0x82u8], true, Utf8EndEnum::TypeUnknown, 2),
+            (& [], true, Utf8EndEnum::TypeUnknown, 0),
+        ];
+        for (bytes, last_buffer, result, consumed) in cases.iter() {
+            let mut mybuf = EightBytes::new();
+            mybuf.fill_from_slice(bytes);
+            assert_eq!((* result, * consumed), utf8_decode_with_len(& mut mybuf, * last_buffer));
+            assert_eq!(bytes.len() as u32 - consumed, mybuf.len());
+        }
+    }
+
+    #[test]
+    // Test the sequence length and const classification
+    fn test_utf8_sequence_len() {
+        const EURO: Utf8TypeEnum = classify_utf32(0x20AC);
+        const LENGTHS: [u8; 4] = [
+            utf8_sequence_len(0x41), utf8_sequence_len(0xDC00),
+            utf8_sequence_len(0x10FFFF), utf8_sequence_len(0x110000),
+        ];
+        assert_eq!(Utf8TypeEnum::Type3((0xE2, 0x82, 0xAC)), EURO);
+        assert_eq!([1u8, 0, 4, 0], LENGTHS);
+        // Every codepoint, and a few values beyond the UTF32 range.
+        for code in (0 .. 0x110010u32).chain([0x7FFF_FFFFu32, u32::MAX]) {
+            let expected:u8 = match classify_utf32(code) {
+                Utf8TypeEnum::Type1(_) => 1,
+                Utf8TypeEnum::Type2(_) => 2,
+                Utf8TypeEnum::Type3(_) => 3,
+                Utf8TypeEnum::Type4(_) => 4,
+                Utf8TypeEnum::Type0(_) => 0,
+            };
+            assert_eq!(expected, utf8_sequence_len(code));
+        }
+    }
+
+    #[test]
+    // Test one-off encoding into a 4 byte buffer
+    fn test_encode_to_buf() {
+        let mut buf = [0u8; 4];
+        for ch in ['\0', 'a', '\u{7FF}', '\u{800}', '\u{FFFD}', '\u{FFFF}', '\u{10000}', char::MAX] {
+            let mut truth = [0u8; 4];
+            assert_eq!(ch.encode_utf8(& mut truth), encode_char_to_buf(ch, & mut buf));
+        }
+        assert_eq!("\u{FFFD}", encode_utf32_to_buf(0xD800, & mut buf));
+        assert_eq!("\u{FFFD}", encode_utf32_to_buf(0x110000, & mut buf));
+        assert_eq!("\u{10000}", encode_utf32_to_buf(0x10000, & mut buf));
+    }
+
+    #[test]
+    // Test UTF8 length calculation against the encoder
+    fn test_utf8_len() {
+        let codes = [0u32, 0x7F, 0x80, 0x7FF, 0x800, 0xD800, 0xDFFF, 0xFFFD,
+            0xFFFF, 0x10000, 0x10FFFF, 0x110000, u32::MAX];
+        let lengths = [1usize, 1, 2, 2, 3, 3, 3, 3, 3, 4, 4, 3, 3];
+        let mut parser = FromUnicode::new();
+        for (code, len) in codes.iter().zip(lengths.iter()) {
+            assert_eq!(* len, utf8_len(* code));
+            let mut code_iter = core::iter::once(* code);
+            assert_eq!(* len, parser.utf32_to_utf8_with_iter(& mut code_iter).count());
+        }
+        assert_eq!(lengths.iter().sum::<usize>(), utf8_len_of_slice(& codes));
+        let text = "a\u{7FF}\u{FFFF}\u{10000}";
+        let chars: std::vec::Vec<char> = text.chars().collect();
+        assert_eq!(text.len(), utf8_len_of_slice(& chars));
+        assert_eq!(0, utf8_len_of_slice::<char>(& []));
+    }
+
+    #[test]
+    // Test the overlong tolerant mode across buffer splits
+    fn test_overlong_tolerant() {
+        let bytes = [0xC1u8, 0x81u8, 0xE0u8, 0x81u8, 0x81u8, 0xF0u8, 0x80u8, 0x81u8, 0x81u8,
+            0xE0u8, 0x9Fu8, 0xBFu8, 0xC0u8, 0x80u8, 0xE2u8, 0x82u8, 0xACu8, 0xF0u8, 0x8Du8,
+            0xA0u8, 0x80u8, 0xC0u8];
+        let truth = "AAA\u{7FF}\0\u{20AC}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}";
+        let mut parser = FromUtf8::new();
+        parser.set_overlong_tolerant(true);
+        for split in 0 ..= bytes.len() {
+            let buffers = [& bytes[.. split], & bytes[split ..]];
+            let text: std::string::String = parser.chained_buffers(buffers.iter().copied()).collect();
+            assert_eq!(truth, text);
+            assert!(parser.has_invalid_sequence());
+            parser.reset_parser();
+            assert!(parser.is_overlong_tolerant());
+        }
+        let mut char_box = ['\0'; 4];
+        let (_rest, num_chars) = parser.utf8_to_char_slice(b"a\xC1\xBFb", & mut char_box).unwrap();
+        assert_eq!(['a', '\u{7F}', 'b'], char_box[.. num_chars]);
+        // The mode is off by default.
+        let text: std::string::String = decode_lossy(& bytes[.. 2]).collect();
+        assert_eq!("\u{FFFD}\u{FFFD}", text);
+    }
+
+    #[test]
+    // Test UTF32 validation against the invalid sequence flag of the encoder
+    fn test_validate_utf32_slice() {
+        let codes = [0u32, 0xD7FF, 0xD800, 0xDBFF, 0xDC00, 0xDFFF, 0xE000, 0xFFFD,
+            0xFFFE, 0x10FFFF, 0x110000, u32::MAX];
+        let mut parser = FromUnicode::new();
+        for code in codes.iter() {
+            // Put the value at every position of a few blocks.
+            for pos in 0 .. 40 {
+                let mut input = [0x41u32; 40];
+                input[pos] = * code;
+                let mut code_iter = input.iter().copied();
+                parser.reset_parser();
+                let _ = parser.utf32_to_utf8_with_iter(& mut code_iter).count();
+                match validate_utf32_slice(& input) {
+                    Result::Ok(()) => assert!(! parser.has_invalid_sequence()),
+                    Result::Err(error) => {
+                        assert!(parser.has_invalid_sequence());
+                        assert_eq!(pos, error.index);
+                    }
+                }
+            }
+        }
+        let error = validate_utf32_slice(& [0xFFFDu32, 0xD800]).unwrap_err();
+        assert_eq!(Utf32ErrorKind::ReplacementCharacter, error.kind);
+        let error = validate_utf32_slice(& [0x20u32, 0x110000]).unwrap_err();
+        let mut buf = [0u8; 64];
+        assert_eq!("out of range value in UTF32 input at index 1", error.format_into(& mut buf));
+        assert_eq!(Result::Ok(()), validate_utf32_slice(& []));
+        assert_eq!(Result::Ok(()), validate_char_slice(& ['a', '\u{10FFFF}']));
+        assert_eq!(Result::Err(Utf32Error { index: 1, kind: Utf32ErrorKind::ReplacementCharacter }),
+            validate_char_slice(& ['a', '\u{FFFD}']));
+    }
+
+    #[test]
+    // Test comparing and hashing parser snapshots
+    fn test_parser_eq_hash() {
+        use core::hash::{Hash, Hasher};
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash_of<T: Hash>(v: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            v.hash(& mut hasher);
+            hasher.finish()
+        }
+
+        let mut parser = FromUtf8::new();
+        let snapshot = parser;
+        assert_eq!(snapshot, FromUtf8::default());
+        parser.set_is_last_buffer(false);
+        assert_eq!(Result::Err(MoreEnum::More(1)), parser.utf8_to_char(& [0xE2u8, 0x82u8]));
+        assert_ne!(snapshot, parser);
+        let mut parser2 = FromUtf8::new();
+        parser2.set_is_last_buffer(false);
+        assert_eq!(Result::Err(MoreEnum::More(1)), parser2.utf8_to_char(& [0xE2u8, 0x82u8]));
+        assert_eq!(parser, parser2);
+        assert_eq!(hash_of(& parser), hash_of(& parser2));
+        parser.reset_parser();
+        assert_eq!(snapshot, parser);
+
+        let mut encoder = FromUnicode::new();
+        assert_eq!(hash_of(& encoder), hash_of(& FromUnicode::default()));
+        encoder.signal_invalid_sequence();
+        assert_ne!(FromUnicode::new(), encoder);
+    }
+
+    #[test]
+    // Test worst-case output lengths against the bulk parser
+    fn test_max_len_helpers() {
+        assert_eq!(Option::Some(0), max_utf8_len_for_chars(0));
+        assert_eq!(Option::Some(40), max_utf8_len_for_chars(10));
+        assert_eq!(Option::None, max_utf8_len_for_chars(usize::MAX / 2));
+        assert_eq!(Option::None, max_utf8_len_for_utf16(usize::MAX));
+        assert_eq!(Option::Some(6), max_utf8_len_for_utf16(2));
+        assert_eq!(Option::Some(7), max_utf16_len_for_utf8(7));
+        let bytes = [0xFFu8, 0x80u8, 0xC2u8, 0x41u8, 0xF0u8, 0x90u8, 0x80u8];
+        let mut char_box = [char::MAX; 7];
+        assert_eq!(Option::Some(char_box.len()), max_chars_for_utf8_len(bytes.len()));
+        let mut parser = FromUtf8::new();
+        let (rest, num_chars) = parser.utf8_to_char_slice(& bytes, & mut char_box).unwrap();
+        assert_eq!(0, rest.len());
+        assert_eq!(5, num_chars);
+        let encoded: std::string::String = char_box[0 .. num_chars].iter().collect();
+        assert!(encoded.len() <= max_utf8_len_for_chars(num_chars).unwrap());
+    }
+
+    #[test]
+    // Test speculative parsing with cloned owned adapters
+    fn test_owned_adapter_clone() {
+        let bytes = [0x41u8, 0xE2u8, 0x82u8, 0xACu8, 0xFFu8, 0x42u8];
+        let mut iter = FromUtf8::new().utf8_into_char_iter(bytes.iter().copied());
+        assert_eq!(Option::Some('A'), iter.next());
+        // Try ahead on a clone, then discard it.
+        let mut trial = iter.clone();
+        assert_eq!(Option::Some('\u{20AC}'), trial.next());
+        assert_eq!(Option::Some(char::REPLACEMENT_CHARACTER), trial.next());
+        assert!(trial.has_invalid_sequence());
+        assert!(! iter.has_invalid_sequence());
+        let rest: std::string::String = iter.by_ref().collect();
+        assert_eq!("\u{20AC}\u{FFFD}B", rest);
+
+        let chars = ['a', '\u{10000}'];
+        let iter = FromUnicode::new().utf32_into_utf8_iter(chars.iter().map(|v| * v as u32));
+        let trial = iter.clone();
+        assert_eq!(5, trial.count());
+        let (_source, parser) = iter.into_parts();
+        assert!(! parser.has_invalid_sequence());
+    }
+
+    #[test]
+    // Test running a decoding pipeline in a worker thread
+    fn test_adapter_in_thread() {
+        let bytes = "\u{FEFF}A\r\n\u{20AC}".as_bytes();
+        let mut parser = FromUtf8::new();
+        let mut byte_ref_iter = bytes.iter();
+        let mut char_iter = parser.utf8_ref_to_char_with_iter(& mut byte_ref_iter);
+        let mut filter_iter = filter_bom_and_cr_iter(& mut char_iter);
+        let output: std::string::String = std::thread::scope(|s| {
+            s.spawn(|| filter_iter.by_ref().collect()).join().unwrap()
+        });
+        assert_eq!("A\n\u{20AC}", output);
+    }
+
+    #[test]
+    // Test Default and the Debug output of adapters
+    fn test_default_and_debug() {
+        let mut parser = FromUtf8::default();
+        assert!(parser.is_last_buffer());
+        parser.set_is_last_buffer(false);
+        let bytes = [0x41u8, 0xE2u8, 0x82u8];
+        let mut byte_ref_iter = bytes.iter();
+        let mut iter = parser.utf8_ref_to_char_with_iter(& mut byte_ref_iter);
+        assert_eq!(Option::Some('A'), iter.next());
+        assert_eq!(Option::None, iter.next());
+        assert_eq!(
+            "Utf8RefIterToCharIter { last_buffer: false, invalid_sequence: false, pending_len: 0x2, pending: [0xe2, 0x82], .. }",
+            std::format!("{:?}", iter));
+
+        let mut encoder = FromUnicode::default();
+        let codes = [0x110000u32];
+        let mut code_iter = codes.iter();
+        let mut glue_iter = utf32_ref_iter_to_utf32_iter(& mut code_iter);
+        let mut iter = encoder.utf32_to_utf8_with_iter(& mut glue_iter);
+        assert_eq!(Option::Some(REPLACE_PART1), iter.next());
+        assert_eq!(
+            "Utf32IterToUtf8Iter { last_buffer: true, invalid_sequence: true, pending_len: 0x2, pending: [0xbf, 0xbd], .. }",
+            std::format!("{:?}", iter));
+    }
+
+    #[test]
+    // Test selecting converters through the Decoder and Encoder traits
+    fn test_decoder_encoder_traits() {
+        let mut decoder: std::boxed::Box<dyn Decoder<Unit = u8>> = std::boxed::Box::new(FromUtf8::new());
+        let mut encoder: std::boxed::Box<dyn Encoder<Unit = u8>> = std::boxed::Box::new(FromUnicode::new());
+        let char_box = ['a', '\u{20AC}', '\u{10000}'];
+        let mut byte_box = [0u8; 16];
+        encoder.set_is_last_buffer(false);
+        let (rest, num_bytes) = encoder.encode_to_slice(& char_box, & mut byte_box).unwrap();
+        assert_eq!(0, rest.len());
+        assert_eq!("a\u{20AC}\u{10000}".as_bytes(), & byte_box[0 .. num_bytes]);
+        assert_eq!(Option::None, encoder.finish());
+
+        // Decode with the last byte held back.
+        let mut char_box2 = [char::MAX; 4];
+        decoder.set_is_last_buffer(false);
+        let (rest, num_chars) = decoder.decode_to_slice(& byte_box[0 .. num_bytes - 1], & mut char_box2).unwrap();
+        assert_eq!(0, rest.len());
+        assert_eq!(['a', '\u{20AC}'], char_box2[0 .. num_chars]);
+        assert_eq!(Result::Err(MoreEnum::More(1)), decoder.decode_char(& []));
+        // The incomplete sequence becomes a replacement character.
+        assert_eq!(Option::Some(char::REPLACEMENT_CHARACTER), decoder.finish());
+        assert_eq!(Option::None, decoder.finish());
+        assert!(decoder.has_invalid_sequence());
+    }
+
+    #[test]
+    // Test grouping chars into fixed size arrays
+    fn test_char_chunk_iter() {
+        let byte_slice = "abcd\u{FEFF}\u{10FFFF}e".as_bytes();
+        let mut byte_ref_iter = byte_slice.iter();
+        let mut from_utf8 = FromUtf8::new();
+        let mut utf8_to_char_iter = from_utf8.utf8_ref_to_char_with_iter(& mut byte_ref_iter);
+        let mut chunk_iter = char_iter_to_char_chunk_iter::<_, 3>(& mut utf8_to_char_iter);
+        assert_eq!(Some((['a', 'b', 'c'], 3)), chunk_iter.next());
+        assert_eq!(Some((['d', '\u{FEFF}', '\u{10FFFF}'], 3)), chunk_iter.next());
+        // Last array is partially filled.
+        assert_eq!(Some((['e', '\0', '\0'], 1)), chunk_iter.next());
+        assert_eq!(Option::None, chunk_iter.next());
+
+        let char_array = ['x', 'y'];
+        let mut char_ref_iter = char_array.iter();
+        let mut char_iter = char_ref_iter_to_char_iter(& mut char_ref_iter);
+        let mut chunk_iter = char_iter_to_char_chunk_iter::<_, 2>(& mut char_iter);
+        assert_eq!((1, Some(1)), chunk_iter.size_hint());
+        assert_eq!(Some((['x', 'y'], 2)), chunk_iter.next());
+        assert_eq!(Option::None, chunk_iter.next());
+    }
+
+    #[test]
+    // Test string slices of decoded chars against the standard library
+    fn test_char_str_iter() {
+        let text = "a\u{7F}\u{80}\u{7FF}\u{800}\u{FFFF}\u{10000}\u{10FFFF}";
+        let mut utf8_iter = text.bytes();
+        let mut from_utf8 = FromUtf8::new();
+        let mut char_iter = from_utf8.utf8_to_char_with_iter(& mut utf8_iter);
+        let mut str_iter = char_iter_to_str_iter(& mut char_iter);
+        let mut joined = std::string::String::new();
+        let mut truth = text.chars();
+        while let Option::Some(ch_str) = str_iter.next_str() {
+            assert_eq!(truth.next().map(|ch| ch.len_utf8()), Option::Some(ch_str.len()));
+            joined.push_str(ch_str);
+        }
+        assert_eq!(text, joined);
+        assert_eq!(Option::None, str_iter.next_str());
+        assert_eq!((0, Option::Some(0)), str_iter.size_hint());
+    }
+
+    #[test]
+    // Test one adapter per stream, re-pointed at every buffer
+    fn test_set_source() {
+        let text = "ab\u{20AC}\u{10348}\u{7FF}z";
+        let truth: std::vec::Vec<char> = text.chars().collect();
+        for split in 0 ..= text.len() {
+            let (head, tail) = text.as_bytes().split_at(split);
+            let mut iters = [head.iter().copied(), tail.iter().copied()];
+            let (first, rest) = iters.split_first_mut().unwrap();
+            let mut from_utf8 = FromUtf8::new();
+            from_utf8.set_is_last_buffer(false);
+            let mut char_iter = from_utf8.utf8_to_char_with_iter(first);
+            let mut chars: std::vec::Vec<char> = char_iter.by_ref().collect();
+            char_iter.set_is_last_buffer(true);
+            let old = char_iter.set_source(& mut rest[0]);
+            assert_eq!(Option::None, old.next());
+            chars.extend(char_iter.by_ref());
+            assert_eq!(truth, chars);
+            assert!(! char_iter.has_invalid_sequence());
+        }
+        for split in 0 ..= truth.len() {
+            let (head, tail) = truth.split_at(split);
+            let mut char_refs = [head.iter(), tail.iter()];
+            let (first, rest) = char_refs.split_first_mut().unwrap();
+            let mut from_unicode = FromUnicode::new();
+            from_unicode.set_is_last_buffer(false);
+            let mut utf8_iter = from_unicode.char_ref_to_utf8_with_iter(first);
+            let mut bytes: std::vec::Vec<u8> = utf8_iter.by_ref().collect();
+            utf8_iter.set_is_last_buffer(true);
+            utf8_iter.set_source(& mut rest[0]);
+            bytes.extend(utf8_iter.by_ref());
+            assert_eq!(text.as_bytes(), & bytes[..]);
+        }
+    }
+
+    #[test]
+    // Test fields with and without a NUL byte
+    fn test_decode_nul_terminated() {
+        let cases: [(& [u8], & str, usize); 4] = [
+            (b"ab\0cd", "ab", 2),
+            (b"\xE2\x82\xAC\xC3", "\u{20AC}\u{FFFD}", 4),
+            (b"\0\xFF", "", 0),
+            (b"", "", 0),
+        ];
+        for (field, truth, truth_len) in cases.iter().copied() {
+            let (chars, len) = decode_nul_terminated(field);
+            assert_eq!(truth_len, len);
+            assert_eq!(truth, chars.collect::<std::string::String>());
+        }
+    }
+
+    #[test]
+    // Test exact decoding against every cut of valid and invalid inputs
+    fn test_decode_utf8_exact() {
+        let text = "a\u{7FF}\u{20AC}\u{10348}";
+        let bytes = text.as_bytes();
+        for cut in 0 ..= bytes.len() {
+            let field = & bytes[.. cut];
+            match decode_utf8_exact(field) {
+                Result::Ok(chars) => {
+                    let truth = core::str::from_utf8(field).unwrap();
+                    assert_eq!(truth, chars.collect::<std::string::String>());
+                }
+                Result::Err(error) => {
+                    let valid_up_to = core::str::from_utf8(field).unwrap_err().valid_up_to();
+                    assert_eq!(TruncatedInputError { offset: valid_up_to, len: cut - valid_up_to }, error);
+                }
+            }
+        }
+        // Invalid sequences at the end are not truncations.
+        let cases: [(& [u8], & str); 5] = [
+            (b"a\xE2\x41", "a\u{FFFD}A"),
+            (b"\xED\xA0", "\u{FFFD}\u{FFFD}"),
+            (b"\xF0\x9F\x98\x80\x80", "\u{1F600}\u{FFFD}"),
+            (b"\xF5\x80", "\u{FFFD}\u{FFFD}"),
+            (b"\xC0", "\u{FFFD}"),
+        ];
+        for (field, truth) in cases.iter().copied() {
+            let chars = decode_utf8_exact(field).unwrap();
+            assert_eq!(truth, chars.collect::<std::string::String>());
+        }
+        let error = decode_utf8_exact(b"\xFF\xE0\xA0").err().unwrap();
+        let mut buf = [0u8; 64];
+        assert_eq!("truncated input: 2 byte UTF8 sequence at byte 1", error.format_into(& mut buf));
+    }
+
+    #[test]
+    // Test random access to chars against chars().nth()
+    fn test_char_at() {
+        let text = "a\u{7FF}\u{20AC}\u{10348}z\u{FFFF}";
+        let count = text.chars().count();
+        for n in 0 ..= count {
+            assert_eq!(text.chars().nth(n), char_at(text.as_bytes(), n));
+        }
+        // Stray continuation bytes are skipped; invalid targets are replaced.
+        let bytes = [0x80u8, 0x61u8, 0xE2u8, 0x82u8, 0x62u8, 0xEDu8, 0xA0u8, 0x80u8, 0xF0u8];
+        let expected = ['a', '\u{FFFD}', 'b', '\u{FFFD}', '\u{FFFD}'];
+        for (n, ch) in expected.iter().enumerate() {
+            assert_eq!(Option::Some(* ch), char_at(& bytes, n));
+        }
+        assert_eq!(Option::None, char_at(& bytes, expected.len()));
+        assert_eq!(Option::None, char_at(& [], 0));
+    }
+
+    #[test]
+    // Test the char map of every decoding path, with dropped runs across buffers
+    fn test_char_map() {
+        fn strip_zero_width(ch: char) -> Option<char> {
+            match ch {
+                '\u{200B}' | '\u{FEFF}' => Option::None,
+                '\u{A0}' => Option::Some(' '),
+                _ => Option::Some(ch),
+            }
+        }
+        let mut text = std::string::String::from("a\u{A0}");
+        text.extend(core::iter::repeat('\u{200B}').take(20));
+        text.push_str("b\u{FEFF}\u{A0}\u{FEFF}");
+        let mut bytes = text.into_bytes();
+        bytes.push(0xFF);
+        let truth = "a b \u{FFFD}";
+        for split in 0 ..= bytes.len() {
+            let (head, tail) = bytes.split_at(split);
+            let mut from_utf8 = FromUtf8::new();
+            from_utf8.set_char_map(Option::Some(strip_zero_width));
+            let mut parser = from_utf8;
+            let mut output = std::string::String::new();
+            for (indx, buf) in [head, tail].iter().enumerate() {
+                parser.set_is_last_buffer(indx == 1);
+                let mut cur_slice = * buf;
+                let mut char_box = ['\0'; 3];
+                // The loop stops at MoreEnum::More(_amt);
+                // _amt equals to 0 when end of data
+                while let Result::Ok((slice_pos, num_chars)) = parser.utf8_to_char_slice(cur_slice, & mut char_box) {
+                    cur_slice = slice_pos;
+                    output.extend(char_box[.. num_chars].iter());
+                }
+            }
+            assert_eq!(truth, output);
+            let mut parser = from_utf8;
+            let mut output = std::string::String::new();
+            for (indx, buf) in [head, tail].iter().enumerate() {
+                parser.set_is_last_buffer(indx == 1);
+                let mut cur_slice = * buf;
+                while let Result::Ok((slice_pos, ch)) = parser.utf8_to_char(cur_slice) {
+                    cur_slice = slice_pos;
+                    output.push(ch);
+                }
+            }
+            assert_eq!(truth, output);
+            let mut parser = from_utf8;
+            parser.set_is_last_buffer(false);
+            let mut output: std::string::String = parser.utf8_ref_to_char_with_iter(& mut head.iter()).collect();
+            parser.set_is_last_buffer(true);
+            output.extend(parser.utf8_to_char_with_iter(& mut tail.iter().copied()));
+            assert_eq!(truth, output);
+            assert_eq!(5, parser.num_chars_out());
+        }
+        let mut parser = FromUtf8::new();
+        assert!(parser.char_map().is_none());
+        parser.set_char_map(Option::Some(strip_zero_width));
+        parser.reset_parser();
+        assert!(parser.char_map().is_some());
+        assert_eq!(Result::Err(MoreEnum::More(0)), parser.utf8_to_char("\u{200B}".as_bytes()));
+    }
+
+    #[test]
+    // Test the throughput counters of the slice, iterator and encoder paths
+    fn test_throughput_counters() {
+        let bytes = b"abc\xE2\x82\xAC\xFFdef\xF0\x90";
+        let mut from_utf8 = FromUtf8::new();
+        from_utf8.set_is_last_buffer(false);
+        let mut char_box = ['\0'; 4];
+        let (rest, num_chars) = from_utf8.utf8_to_char_slice(bytes, & mut char_box).unwrap();
+        assert_eq!((4, 1), (num_chars, rest.len()));
+        // Bytes held in the scratch pad are counted as input.
+        assert_eq!((11, 4), (from_utf8.num_bytes_in(), from_utf8.num_chars_out()));
+        from_utf8.set_is_last_buffer(true);
+        let mut tail = rest.iter().chain(b"\x8D\x88".iter()).copied();
+        let chars: std::vec::Vec<char> = from_utf8.utf8_to_char_with_iter(& mut tail).collect();
+        assert_eq!(['\u{FFFD}', 'd', 'e', 'f', '\u{10348}'], & chars[..]);
+        assert_eq!((14, 9), (from_utf8.num_bytes_in(), from_utf8.num_chars_out()));
+        from_utf8.reset_parser();
+        assert_eq!((0, 0), (from_utf8.num_bytes_in(), from_utf8.num_chars_out()));
+        from_utf8.set_is_last_buffer(false);
+        assert_eq!(Result::Err(MoreEnum::More(1)), from_utf8.utf8_to_char(b"\xC3"));
+        // Clearing the counters keeps the partial sequence.
+        from_utf8.reset_counters();
+        from_utf8.set_is_last_buffer(true);
+        assert_eq!(1, from_utf8.utf8_ref_to_char_with_iter(& mut b"\xA9".iter()).count());
+        assert_eq!((1, 1), (from_utf8.num_bytes_in(), from_utf8.num_chars_out()));
+
+        let text = ['a', '\u{20AC}', '\u{10348}'];
+        let mut from_unicode = FromUnicode::new();
+        assert_eq!(Result::Ok((& text[1 ..], 0x61u8)), from_unicode.char_to_utf8(& text));
+        assert_eq!(Result::Ok((& text[2 ..], 0xE2u8)), from_unicode.char_to_utf8(& text[1 ..]));
+        assert_eq!((2, 2), (from_unicode.num_chars_in(), from_unicode.num_bytes_out()));
+        from_unicode.reset_counters();
+        // The last 2 bytes of the euro sign are drained before the next char.
+        assert_eq!(3, from_unicode.char_ref_to_utf8_with_iter(& mut text[2 ..].iter()).take(3).count());
+        assert_eq!((1, 3), (from_unicode.num_chars_in(), from_unicode.num_bytes_out()));
+        assert!(from_unicode.push_utf32(0xD800));
+        assert_eq!((2, 3), (from_unicode.num_chars_in(), from_unicode.num_bytes_out()));
+        let mut byte_box = [0u8; 8];
+        assert_eq!(6, from_unicode.drain_bytes(& mut byte_box));
+        assert_eq!((2, 9), (from_unicode.num_chars_in(), from_unicode.num_bytes_out()));
+        from_unicode.reset_parser();
+        assert_eq!((0, 0), (from_unicode.num_chars_in(), from_unicode.num_bytes_out()));
+    }
+
+    #[test]
+    // Test counting decode errors apart from encoded replacement characters
+    fn test_decode_error_counters() {
+        let bytes = "a\u{FFFD}b\u{FFFD}".as_bytes();
+        let mut parser = FromUtf8::new();
+        assert_eq!(4, parser.utf8_ref_to_char_with_iter(& mut bytes.iter()).count());
+        assert!(parser.has_invalid_sequence());
+        assert_eq!((0, 2), (parser.num_decode_errors(), parser.num_encoded_replacements()));
+
+        // Split sequences, overlongs and truncated sequences at the end.
+        let bytes = b"\xEF\xBF\xBD\xC0\xAF\xED\xA0\x80\xE2\x82";
+        for split in 0 .. bytes.len() + 1 {
+            let mut parser = FromUtf8::new();
+            parser.set_is_last_buffer(false);
+            let mut char_box = ['\0'; 16];
+            let (rest, _num_chars) = parser.utf8_to_char_slice(& bytes[.. split], & mut char_box).unwrap_or((&[], 0));
+            assert!(rest.is_empty());
+            parser.set_is_last_buffer(true);
+            let count = parser.utf8_ref_to_char_with_iter(& mut bytes[split ..].iter()).count();
+            assert!(count > 0 || split == bytes.len());
+            assert_eq!((6, 1), (parser.num_decode_errors(), parser.num_encoded_replacements()));
+        }
+        let mut parser = FromUtf8::new();
+        parser.set_overlong_tolerant(true);
+        assert_eq!(Result::Ok((&b""[..], '/')), parser.utf8_to_char(b"\xC0\xAF"));
+        assert_eq!((1, 0), (parser.num_decode_errors(), parser.num_encoded_replacements()));
+        parser.reset_counters();
+        assert!(parser.has_invalid_sequence());
+        assert_eq!((0, 0), (parser.num_decode_errors(), parser.num_encoded_replacements()));
+    }
+
+    #[test]
+    // Test the ASCII flag on the fast path, the scratch pad, and errors
+    fn test_is_ascii_so_far() {
+        for (bytes, ascii) in [(& b"plain ASCII text, long enough for the fast path"[..], true),
+                (b"long enough for the fast path, then caf\xC3\xA9", false),
+                (b"a\xFF", false), (b"a\xE2\x82", false), (b"", true)] {
+            let mut parser = FromUtf8::new();
+            let mut char_box = ['\0'; 64];
+            parser.utf8_to_char_slice(bytes, & mut char_box).unwrap_or((&[], 0));
+            assert_eq!(ascii, parser.is_ascii_so_far());
+            let mut parser = FromUtf8::new();
+            let _count = parser.utf8_ref_to_char_with_iter(& mut bytes.iter()).count();
+            assert_eq!(ascii, parser.is_ascii_so_far());
+        }
+        let mut parser = FromUtf8::new();
+        assert_eq!(Result::Ok((&b""[..], '\u{E9}')), parser.utf8_to_char(b"\xC3\xA9"));
+        assert!(! parser.is_ascii_so_far());
+        parser.reset_counters();
+        assert!(parser.is_ascii_so_far());
+    }
+
+    #[test]
+    // Test UTF8 to UTF16 transcoding against from_utf8_lossy(), over
+    // buffer splits
+    fn test_utf8_to_utf16_iter() {
+        let bytes = b"a\xC3\xA9\xE2\x82\xAC\xF0\x9F\x98\x80\xFF\xED\xA0\x80\xF4\x8F\xBF\xBFz\xF0\x9F";
+        let truth: std::vec::Vec<u16> = std::string::String::from_utf8_lossy(bytes).encode_utf16().collect();
+        for split in 0 ..= bytes.len() {
+            let mut parser = FromUtf8::new();
+            parser.set_is_last_buffer(false);
+            let mut head = bytes[.. split].iter().copied();
+            let mut tail = bytes[split ..].iter().copied();
+            let mut iter = parser.utf8_to_utf16_with_iter(& mut head);
+            let mut units: std::vec::Vec<u16> = iter.by_ref().collect();
+            iter.set_source(& mut tail);
+            iter.set_is_last_buffer(true);
+            let (lower, upper) = iter.size_hint();
+            let rest: std::vec::Vec<u16> = iter.by_ref().collect();
+            assert!(lower <= rest.len() && rest.len() <= upper.unwrap());
+            units.extend(rest);
+            assert_eq!(truth, units, "split {}", split);
+            assert!(iter.has_invalid_sequence());
+        }
+    }
+
+    #[test]
+    // Test UTF16 encoding against str::encode_utf16(), over buffer splits
+    // and output buffer sizes, and of invalid UTF32 values
+    fn test_utf16_encoding() {
+        let text = "A\u{7FF}\u{20AC}\u{FFFF}\u{10000}\u{1F600}\u{10FFFF}z";
+        let truth: std::vec::Vec<u16> = text.encode_utf16().collect();
+        let chars: std::vec::Vec<char> = text.chars().collect();
+        for split in 0 ..= chars.len() {
+            for out_len in [1, 2, 64] {
+                let mut parser = FromUnicode::new();
+                let mut units = std::vec::Vec::new();
+                let mut unit_box = std::vec![0u16; out_len];
+                for (indx, buf) in [& chars[.. split], & chars[split ..]].iter().enumerate() {
+                    parser.set_is_last_buffer(indx == 1);
+                    let mut src = * buf;
+                    while let Result::Ok(num_units) = parser.source_to_utf16_slice(& mut src, & mut unit_box) {
+                        units.extend_from_slice(& unit_box[.. num_units]);
+                    }
+                }
+                assert_eq!(truth, units);
+                assert!(! parser.has_invalid_sequence());
+            }
+            let mut parser = FromUnicode::new();
+            parser.set_is_last_buffer(false);
+            let mut head = chars[.. split].iter();
+            let mut tail = chars[split ..].iter();
+            let mut iter = parser.char_ref_to_utf16_with_iter(& mut head);
+            let mut units: std::vec::Vec<u16> = iter.by_ref().collect();
+            iter.set_source(& mut tail);
+            iter.set_is_last_buffer(true);
+            units.extend(iter);
+            assert_eq!(truth, units);
+        }
+
+        let input = [0x41u32, 0xD800u32, 0x110000u32, 0xFFFDu32, 0x1F600u32];
+        let mut parser = FromUnicode::new();
+        let mut iter = input.iter().copied();
+        let units: std::vec::Vec<u16> = parser.utf32_to_utf16_with_iter(& mut iter).collect();
+        assert_eq!(std::vec![0x41, 0xFFFD, 0xFFFD, 0xFFFD, 0xD83D, 0xDE00], units);
+        assert!(parser.has_invalid_sequence());
+        assert_eq!(5, parser.num_chars_in());
+
+        let mut parser = FromUnicode::new();
+        parser.set_strict(true);
+        let mut iter = input.iter().copied();
+        let units: std::vec::Vec<u16> = parser.utf32_to_utf16_with_iter(& mut iter).collect();
+        assert_eq!(std::vec![0x41], units);
+        assert_eq!(Option::Some((1, Utf32ErrorKind::Surrogate)), parser.strict_error().map(|v| (v.index, v.kind)));
+        parser.set_is_last_buffer(false);
+        parser.reset_invalid_sequence();
+        assert_eq!(Result::Ok((&[][..], 0xD83D)), parser.utf32_to_utf16(& [0x1F600]));
+        parser.reset_parser();
+        assert_eq!(Result::Err(MoreEnum::More(0)), parser.char_to_utf16(& []));
+    }
+
+    #[cfg(not(feature = "safe"))]
+    #[test]
+    // Test the bulk converters into uninitialized buffers against the
+    // slice converters
+    fn test_uninit_slices() {
+        use core::mem::MaybeUninit;
+        let bytes = b"ASCII run then \xC3\xA9\xE2\x82\xAC\xF0\x9F\x98\x80 and \xFF\xC0 end";
+        for out_len in [1, 3, 7, 64] {
+            let mut parser = FromUtf8::new();
+            let mut uninit_parser = FromUtf8::new();
+            let mut char_box = std::vec!['\0'; out_len];
+            let mut uninit_box = std::vec![MaybeUninit::<char>::uninit(); out_len];
+            let mut cur_slice = & bytes[..];
+            let mut uninit_slice = & bytes[..];
+            loop {
+                let expected = parser.utf8_to_char_slice(cur_slice, & mut char_box);
+                match uninit_parser.utf8_to_char_uninit(uninit_slice, & mut uninit_box) {
+                    Result::Ok((rest, chars)) => {
+                        let (slice_pos, num_chars) = expected.unwrap();
+                        assert_eq!((slice_pos, & char_box[.. num_chars]), (rest, &* chars));
+                        cur_slice = slice_pos;
+                        uninit_slice = rest;
+                    }
+                    Result::Err(en) => {
+                        assert_eq!(Result::Err(en), expected);
+                        break;
+                    }
+                }
+            }
+
+            let text = "ASCII \u{E9}\u{20AC}\u{1F600}";
+            let mut encoder = FromUnicode::new();
+            let mut src = text;
+            let mut encoded = std::vec::Vec::new();
+            let mut uninit_box = std::vec![MaybeUninit::<u8>::uninit(); out_len];
+            while let Result::Ok(out) = encoder.source_to_utf8_uninit(& mut src, & mut uninit_box) {
+                encoded.extend_from_slice(out);
+            }
+            assert_eq!(text.as_bytes(), & encoded[..]);
+        }
+    }
+
+    #[test]
+    // Test the number of bytes requested for a partial sequence
+    fn test_more_bytes_needed() {
+        let text = "\u{7FF}\u{20AC}\u{10348}".as_bytes();
+        let mut char_box = ['\0'; 4];
+        let mut parser = FromUtf8::new();
+        parser.set_is_last_buffer(false);
+        let mut diag_parser = FromUtf8WithDiagnostics::new(FnSink(|_diag| {}));
+        diag_parser.set_is_last_buffer(false);
+        for (start, len) in [(0, 2), (2, 3), (5, 4)] {
+            for indx in start .. start + len - 1 {
+                let more = MoreEnum::More((start + len - 1 - indx) as u32);
+                assert_eq!(Result::Err(more), parser.utf8_to_char_slice(& text[indx .. indx + 1], & mut char_box));
+                assert_eq!(Result::Err(more), diag_parser.utf8_to_char(& text[indx .. indx + 1]));
+            }
+            let last = & text[start + len - 1 .. start + len];
+            assert_eq!(1, parser.utf8_to_char_slice(last, & mut char_box).unwrap().1);
+            assert_eq!(1, diag_parser.utf8_to_char_slice(last, & mut char_box).unwrap().1);
+        }
+        // Nothing pending: ask for the next buffer.
+        assert_eq!(Result::Err(MoreEnum::More(4096)), parser.utf8_to_char(b""));
+        parser.set_overlong_tolerant(true);
+        assert_eq!(Result::Err(MoreEnum::More(1)), parser.utf8_to_char(b"\xC0"));
+        assert_eq!(Result::Ok((&b""[..], '\0')), parser.utf8_to_char(b"\x80"));
+        parser.set_is_last_buffer(true);
+        assert_eq!(Result::Err(MoreEnum::More(0)), parser.utf8_to_char(b""));
+    }
+
+    #[test]
+    // Test strict encoding over slices, iterators and the scratch pad
+    fn test_strict_encoding() {
+        let input = [0x41u32, 0x20ACu32, 0x110000u32, 0x42u32, 0xFFFDu32];
+        let mut from_unicode = FromUnicode::new();
+        let mut output = std::vec::Vec::new();
+        let mut errors = std::vec::Vec::new();
+        let mut cur_slice = & input[..];
+        // The loop stops at EncodeError::More(_amt);
+        // _amt equals to 0 when end of data
+        loop {
+            match from_unicode.utf32_to_utf8_strict(cur_slice) {
+                Result::Ok((slice_pos, b)) => {
+                    cur_slice = slice_pos;
+                    output.push(b);
+                }
+                Result::Err(EncodeError::Invalid(error)) => {
+                    // Skip the value, and go on.
+                    errors.push(error);
+                    cur_slice = & cur_slice[1 ..];
+                }
+                Result::Err(EncodeError::More(_amt)) => {
+                    break;
+                }
+            }
+        }
+        assert_eq!(b"A\xE2\x82\xACB", & output[..]);
+        assert_eq!(std::vec![
+            StrictEncodeError { index: 2, value: 0x110000, kind: Utf32ErrorKind::OutOfRange },
+            StrictEncodeError { index: 4, value: 0xFFFD, kind: Utf32ErrorKind::ReplacementCharacter },
+        ], errors);
+        assert!(from_unicode.has_invalid_sequence());
+        assert_eq!(5, from_unicode.num_chars_in());
+        let mut buf = [0u8; 80];
+        assert_eq!("replacement character in UTF32 input at index 4, value 0xFFFD",
+            EncodeError::Invalid(errors[1]).format_into(& mut buf));
+        assert_eq!("end of data", EncodeError::More(MoreEnum::More(0)).format_into(& mut buf));
+
+        // The plain parsers are lossy in strict mode too.
+        from_unicode.reset_parser();
+        from_unicode.set_strict(true);
+        let chars = ['a', '\u{FFFD}'];
+        let mut src = & chars[..];
+        let mut byte_box = [0u8; 8];
+        assert_eq!(Result::Ok(4), from_unicode.source_to_utf8_slice(& mut src, & mut byte_box));
+        assert_eq!(Option::None, from_unicode.strict_error());
+        let mut src = & chars[..];
+        assert_eq!(Result::Ok(0x61), from_unicode.source_to_utf16_strict(& mut src));
+        assert!(matches!(from_unicode.source_to_utf16_strict(& mut src), Result::Err(EncodeError::Invalid(_))));
+        assert_eq!(Result::Err(EncodeError::More(MoreEnum::More(0))), from_unicode.source_to_utf16_strict(& mut src));
+
+        from_unicode.reset_parser();
+        assert!(from_unicode.is_strict());
+        assert_eq!(Option::None, from_unicode.strict_error());
+        let chars = ['a', '\u{FFFD}', 'b'];
+        let encoded: std::vec::Vec<u8> = from_unicode.char_ref_to_utf8_with_iter(& mut chars.iter()).collect();
+        assert_eq!(b"a", & encoded[..]);
+        from_unicode.reset_parser();
+        let mut iter = input.iter().copied();
+        let encoded: std::vec::Vec<u8> = from_unicode.utf32_to_utf8_with_iter(& mut iter).collect();
+        assert_eq!(b"A\xE2\x82\xAC", & encoded[..]);
+        assert_eq!(Option::Some(0x42u32), iter.next());
+        from_unicode.reset_parser();
+        assert!(from_unicode.push_char('x'));
+        assert!(! from_unicode.push_utf32(0xDC00));
+        assert_eq!(Utf32ErrorKind::Surrogate, from_unicode.strict_error().unwrap().kind);
+        assert_eq!(1, from_unicode.drain_bytes(& mut buf));
+
+        from_unicode.set_strict(false);
+        from_unicode.reset_parser();
+        let encoded: std::vec::Vec<u8> = from_unicode.utf32_to_utf8_with_iter(& mut input.iter().copied()).collect();
+        assert_eq!(b"A\xE2\x82\xAC\xEF\xBF\xBDB\xEF\xBF\xBD", & encoded[..]);
+    }
+
     // Have a char value go through a round trip of conversions.
     fn round_trip_parsing1(char_val: char) {
         let char_box: [char; 1] = [char_val; 1];
@@ -1905,39 +5744,29 @@ mod tests {
 
         let mut char_ref = & char_box[..];
         let mut utf32_parser = FromUnicode::new();
-        loop {
-            match utf32_parser.char_to_utf8(char_ref) {
-                Result::Ok((char_pos, b)) => {
-                    if char_val == char::REPLACEMENT_CHARACTER {
-                        assert_eq!(true, utf32_parser.has_invalid_sequence());
-                    }
-                    utf8_box[utf8_len] = b;
-                    utf8_len += 1;
-                    char_ref = char_pos;
-                }
-                Result::Err(MoreEnum::More(_)) => {
-                    break;
-                }
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((char_pos, b)) = utf32_parser.char_to_utf8(char_ref) {
+            if char_val == char::REPLACEMENT_CHARACTER {
+                assert!(utf32_parser.has_invalid_sequence());
             }
+            utf8_box[utf8_len] = b;
+            utf8_len += 1;
+            char_ref = char_pos;
         }
         let mut utf8_ref = & utf8_box[0 .. utf8_len];
         let mut char_box2: [char; 1] = [char::MAX; 1];
         let mut char_len: usize = 0;
         let mut utf8_parser = FromUtf8::new();
-        loop {
-            match utf8_parser.utf8_to_char(utf8_ref) {
-                Result::Ok((utf8_pos, ch)) => {
-                    if char_val == char::REPLACEMENT_CHARACTER {
-                        assert_eq!(true, utf8_parser.has_invalid_sequence());
-                    }
-                    char_box2[char_len] = ch;
-                    char_len += 1;
-                    utf8_ref = utf8_pos;
-                }
-                Result::Err(MoreEnum::More(_)) => {
-                    break;
-                }
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((utf8_pos, ch)) = utf8_parser.utf8_to_char(utf8_ref) {
+            if char_val == char::REPLACEMENT_CHARACTER {
+                assert!(utf8_parser.has_invalid_sequence());
             }
+            char_box2[char_len] = ch;
+            char_len += 1;
+            utf8_ref = utf8_pos;
         }
         assert_eq!(1, char_len);
         assert_eq!(char_val, char_box2[0]);
@@ -1951,39 +5780,29 @@ mod tests {
 
         let mut utf32_ref = & utf32_box[..];
         let mut utf32_parser = FromUnicode::new();
-        loop {
-            match utf32_parser.utf32_to_utf8(utf32_ref) {
-                Result::Ok((utf32_pos, b)) => {
-                    if code_val == REPLACE_UTF32 {
-                        assert_eq!(true, utf32_parser.has_invalid_sequence());
-                    }
-                    utf8_box[utf8_len] = b;
-                    utf8_len += 1;
-                    utf32_ref = utf32_pos;
-                }
-                Result::Err(MoreEnum::More(_)) => {
-                    break;
-                }
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((utf32_pos, b)) = utf32_parser.utf32_to_utf8(utf32_ref) {
+            if code_val == REPLACE_UTF32 {
+                assert!(utf32_parser.has_invalid_sequence());
             }
+            utf8_box[utf8_len] = b;
+            utf8_len += 1;
+            utf32_ref = utf32_pos;
         }
         let mut utf8_ref = & utf8_box[0 .. utf8_len];
         let mut utf32_box2: [u32; 1] = [0; 1];
         let mut utf32_len: usize = 0;
         let mut utf8_parser = FromUtf8::new();
-        loop {
-            match utf8_parser.utf8_to_utf32(utf8_ref) {
-                Result::Ok((utf8_pos, co)) => {
-                    if code_val == REPLACE_UTF32 {
-                        assert_eq!(true, utf8_parser.has_invalid_sequence());
-                    }
-                    utf32_box2[utf32_len] = co;
-                    utf32_len += 1;
-                    utf8_ref = utf8_pos;
-                }
-                Result::Err(MoreEnum::More(_)) => {
-                    break;
-                }
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((utf8_pos, co)) = utf8_parser.utf8_to_utf32(utf8_ref) {
+            if code_val == REPLACE_UTF32 {
+                assert!(utf8_parser.has_invalid_sequence());
             }
+            utf32_box2[utf32_len] = co;
+            utf32_len += 1;
+            utf8_ref = utf8_pos;
         }
         assert_eq!(1, utf32_len);
         assert_eq!(code_val, utf32_box2[0]);
@@ -2010,4 +5829,53 @@ mod tests {
 
 }
 
+#[cfg(feature = "futures")]
+pub mod async_stream;
+pub mod bom;
 pub mod buf;
+pub mod chain;
+#[cfg(feature = "tokio-codec")]
+pub mod codec;
+#[cfg(all(feature = "windows", windows, not(feature = "safe")))]
+pub mod console;
+pub mod context;
+pub mod cursor;
+pub mod diag;
+pub mod display;
+pub mod ebcdic;
+#[cfg(feature = "embedded-io")]
+pub mod embedded;
+#[cfg(feature = "embedded-io-async")]
+pub mod embedded_async;
+#[cfg(all(feature = "ffi", not(feature = "safe")))]
+pub mod ffi;
+pub mod framer;
+#[cfg(feature = "gb18030")]
+pub mod gb18030;
+pub mod html;
+pub mod index;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod json;
+pub mod latin1;
+pub mod machine;
+pub mod offsets;
+#[cfg(feature = "std")]
+pub mod pipeline;
+pub mod recode;
+pub mod repair;
+pub mod sanitize;
+pub mod sink;
+#[cfg(feature = "alloc")]
+pub mod text;
+pub mod transcoder;
+pub mod utf16;
+pub mod valid;
+pub mod whatwg;
+pub mod xml;
+
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+
+#[cfg(test)]
+mod test_fixtures;
@@ -0,0 +1,251 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::async_stream
+
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+use std::io;
+
+use futures_core::Stream;
+use futures_io::AsyncRead;
+
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::MoreEnum;
+use crate::utf8conv::UtfParserCommon;
+
+/// size of the input buffer of the stream
+const STREAM_BUF_SIZE: usize = 4096;
+
+/// A char stream decoding UTF8 from a futures::io::AsyncRead source,
+/// the async form of Utf8ReadChars.
+///
+/// Sequences split across reads are joined by the parser, which keeps
+/// its state while a read is pending.  Invalid UTF8 sequences become
+/// replacement characters.  A read error ends the stream; it is kept
+/// for take_error().
+///
+/// ```rust
+/// use std::pin::Pin;
+/// use std::sync::Arc;
+/// use std::task::Context;
+/// use std::task::Poll;
+/// use std::task::Wake;
+/// use std::task::Waker;
+/// use futures_core::Stream;
+/// use utf8conv::*;
+///
+/// struct NoopWaker;
+///
+/// impl Wake for NoopWaker {
+///     fn wake(self: Arc<Self>) {}
+/// }
+///
+/// let mut chars = Utf8CharStream::new(& b"a\xE2\x82\xAC"[..]);
+/// let waker = Waker::from(Arc::new(NoopWaker));
+/// let mut cx = Context::from_waker(& waker);
+/// assert_eq!(Poll::Ready(Some('a')), Pin::new(& mut chars).poll_next(& mut cx));
+/// assert_eq!(Poll::Ready(Some('\u{20AC}')), Pin::new(& mut chars).poll_next(& mut cx));
+/// assert_eq!(Poll::Ready(None), Pin::new(& mut chars).poll_next(& mut cx));
+/// ```
+pub struct Utf8CharStream<R> {
+
+    /// the inner reader
+    my_inner: R,
+
+    /// the UTF8 parser
+    my_parser: FromUtf8,
+
+    /// bytes read from the inner reader
+    my_in: [u8; STREAM_BUF_SIZE],
+
+    /// start of the unparsed bytes in my_in
+    my_in_start: usize,
+
+    /// end of the unparsed bytes in my_in
+    my_in_end: usize,
+
+    /// the read error that ended the stream
+    my_error: Option<io::Error>,
+}
+
+/// Implementation of Utf8CharStream
+impl<R: AsyncRead + Unpin> Utf8CharStream<R> {
+
+    /// Make a new Utf8CharStream reading UTF8 from `inner`.
+    pub fn new(inner: R) -> Utf8CharStream<R> {
+        let mut parser = FromUtf8::new();
+        parser.set_is_last_buffer(false);
+        Utf8CharStream {
+            my_inner: inner,
+            my_parser: parser,
+            my_in: [0; STREAM_BUF_SIZE],
+            my_in_start: 0,
+            my_in_end: 0,
+            my_error: Option::None,
+        }
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this stream.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_parser.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// Take the read error that ended the stream, if any.
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.my_error.take()
+    }
+
+    #[inline]
+    /// Get a reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        & self.my_inner
+    }
+
+    /// Unwrap this Utf8CharStream, returning the inner reader.
+    /// Bytes buffered but not yet decoded are lost.
+    pub fn into_inner(self) -> R {
+        self.my_inner
+    }
+}
+
+/// Stream for Utf8CharStream
+impl<R: AsyncRead + Unpin> Stream for Utf8CharStream<R> {
+    type Item = char;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<char>> {
+        let this = self.get_mut();
+        loop {
+            let input = this.my_in.get(this.my_in_start .. this.my_in_end).unwrap_or(&[]);
+            match this.my_parser.utf8_to_char(input) {
+                Result::Ok((rest, ch)) => {
+                    this.my_in_start = this.my_in_end - rest.len();
+                    return Poll::Ready(Option::Some(ch));
+                }
+                Result::Err(MoreEnum::More(0)) => {
+                    // end of data
+                    return Poll::Ready(Option::None);
+                }
+                Result::Err(MoreEnum::More(_amt)) => {
+                    // The parser holds an incomplete sequence while the
+                    // read is pending.
+                    this.my_in_start = 0;
+                    this.my_in_end = 0;
+                    match Pin::new(& mut this.my_inner).poll_read(cx, & mut this.my_in) {
+                        Poll::Pending => {
+                            return Poll::Pending;
+                        }
+                        Poll::Ready(Result::Ok(0)) => {
+                            this.my_parser.set_is_last_buffer(true);
+                        }
+                        Poll::Ready(Result::Ok(len)) => {
+                            this.my_in_end = len;
+                        }
+                        Poll::Ready(Result::Err(er)) if er.kind() == io::ErrorKind::Interrupted => {}
+                        Poll::Ready(Result::Err(er)) => {
+                            // An incomplete sequence before the error
+                            // still comes out as a replacement character.
+                            this.my_error = Option::Some(er);
+                            this.my_parser.set_is_last_buffer(true);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::pin::Pin;
+    use core::task::Context;
+    use core::task::Poll;
+    use core::task::Waker;
+    use std::sync::Arc;
+    use std::task::Wake;
+
+    use futures_core::Stream;
+
+    use crate::*;
+
+    /// A waker that does nothing, for polling by hand
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    /// A reader that hands out at most `my_step` bytes per read, and
+    /// answers Pending before every read.
+    struct TrickleReader<'a> {
+        my_bytes: &'a [u8],
+        my_step: usize,
+        my_ready: bool,
+    }
+
+    impl futures_io::AsyncRead for TrickleReader<'_> {
+        fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8])
+        -> Poll<std::io::Result<usize>> {
+            let this = self.get_mut();
+            this.my_ready = ! this.my_ready;
+            if ! this.my_ready {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            if this.my_bytes.is_empty() && (this.my_step == 0) {
+                return Poll::Ready(Result::Err(std::io::Error::from(std::io::ErrorKind::BrokenPipe)));
+            }
+            let len = this.my_step.min(buf.len()).min(this.my_bytes.len());
+            buf[.. len].copy_from_slice(& this.my_bytes[.. len]);
+            this.my_bytes = & this.my_bytes[len ..];
+            Poll::Ready(Result::Ok(len))
+        }
+    }
+
+    // collect the chars of `chars`, polling through Pending answers
+    fn collect(chars: & mut Utf8CharStream<TrickleReader<'_>>) -> std::string::String {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(& waker);
+        let mut text = std::string::String::new();
+        loop {
+            match Pin::new(& mut * chars).poll_next(& mut cx) {
+                Poll::Ready(Option::Some(ch)) => text.push(ch),
+                Poll::Ready(Option::None) => return text,
+                Poll::Pending => {}
+            }
+        }
+    }
+
+    #[test]
+    // Test decoding bytes trickling in at every read size, and read errors
+    fn test_char_stream() {
+        let bytes = b"a\xE2\x82\xACb\xF0\x9F\x98\x80\xFFc\xE2\x82";
+        let truth = "a\u{20AC}b\u{1F600}\u{FFFD}c\u{FFFD}";
+        for step in 1 .. bytes.len() + 1 {
+            let reader = TrickleReader { my_bytes: bytes, my_step: step, my_ready: false };
+            let mut chars = Utf8CharStream::new(reader);
+            assert_eq!(truth, collect(& mut chars));
+            assert!(chars.has_invalid_sequence());
+            assert!(chars.take_error().is_none());
+            assert_eq!("", collect(& mut chars));
+        }
+        // A reader failing at once
+        let reader = TrickleReader { my_bytes: &[], my_step: 0, my_ready: false };
+        let mut chars = Utf8CharStream::new(reader);
+        assert_eq!("", collect(& mut chars));
+        assert_eq!(std::io::ErrorKind::BrokenPipe, chars.take_error().unwrap().kind());
+        assert!(! chars.has_invalid_sequence());
+        assert!(chars.into_inner().my_bytes.is_empty());
+    }
+}
@@ -0,0 +1,474 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::bom
+
+use crate::utf8conv::MoreEnum;
+
+/// UTF8 byte order mark
+const BOM_UTF8: [u8; 3] = [0xEF, 0xBB, 0xBF];
+
+/// UTF16 little endian byte order mark
+const BOM_UTF16LE: [u8; 2] = [0xFF, 0xFE];
+
+/// UTF16 big endian byte order mark
+const BOM_UTF16BE: [u8; 2] = [0xFE, 0xFF];
+
+/// UTF32 little endian byte order mark
+const BOM_UTF32LE: [u8; 4] = [0xFF, 0xFE, 0x00, 0x00];
+
+/// UTF32 big endian byte order mark
+const BOM_UTF32BE: [u8; 4] = [0x00, 0x00, 0xFE, 0xFF];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// Unicode encoding forms that a byte order mark can identify
+pub enum Encoding {
+
+    /// UTF8
+    Utf8,
+
+    /// UTF16 little endian
+    Utf16Le,
+
+    /// UTF16 big endian
+    Utf16Be,
+
+    /// UTF32 little endian
+    Utf32Le,
+
+    /// UTF32 big endian
+    Utf32Be,
+}
+
+/// Returns the byte order mark of an encoding.
+/// # Arguments
+/// * `encoding` - the encoding
+pub fn bom_bytes(encoding: Encoding) -> &'static [u8] {
+    match encoding {
+        Encoding::Utf8 => & BOM_UTF8,
+        Encoding::Utf16Le => & BOM_UTF16LE,
+        Encoding::Utf16Be => & BOM_UTF16BE,
+        Encoding::Utf32Le => & BOM_UTF32LE,
+        Encoding::Utf32Be => & BOM_UTF32BE,
+    }
+}
+
+/// Write the byte order mark of an encoding to the front of `output`,
+/// and return the number of bytes written, or 'None' when `output` is
+/// too small.
+/// # Arguments
+/// * `encoding` - the encoding
+/// * `output` - the output buffer
+pub fn write_bom(encoding: Encoding, output: &mut [u8]) -> Option<usize> {
+    let bom = bom_bytes(encoding);
+    output.get_mut(.. bom.len())?.copy_from_slice(bom);
+    Option::Some(bom.len())
+}
+
+/// Detect a byte order mark at the start of `input`, and return its
+/// encoding with the input after it.  Input without a byte order mark
+/// is returned whole, with 'None'.
+///
+/// FF FE 00 00 is taken as UTF32 little endian, although it is also
+/// UTF16 little endian text starting with a NUL char.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let bytes = [0xEFu8, 0xBBu8, 0xBFu8, 0x61u8];
+/// assert_eq!((Some(Encoding::Utf8), & b"a"[..]), strip_bom(& bytes));
+/// assert_eq!((None, & b"a"[..]), strip_bom(b"a"));
+/// ```
+/// # Arguments
+/// * `input` - the input bytes
+pub fn strip_bom(input: &[u8]) -> (Option<Encoding>, &[u8]) {
+    // Longer marks are tried first, as FF FE starts both
+    // UTF32LE and UTF16LE marks.
+    let candidates = [
+        Encoding::Utf32Le,
+        Encoding::Utf32Be,
+        Encoding::Utf8,
+        Encoding::Utf16Le,
+        Encoding::Utf16Be,
+    ];
+    for encoding in candidates.iter().copied() {
+        if let Option::Some(rest) = input.strip_prefix(bom_bytes(encoding)) {
+            return (Option::Some(encoding), rest);
+        }
+    }
+    (Option::None, input)
+}
+
+/// Detect a byte order mark at the start of a complete `input`, and
+/// return its encoding with the number of bytes to skip.  Input without
+/// a byte order mark gives 'None' and 0.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// assert_eq!((Some(Encoding::Utf16Be), 2), detect_encoding(& [0xFEu8, 0xFFu8, 0x00u8, 0x61u8]));
+/// assert_eq!((None, 0), detect_encoding(b"a"));
+/// ```
+/// # Arguments
+/// * `input` - the input bytes
+pub fn detect_encoding(input: &[u8]) -> (Option<Encoding>, usize) {
+    let (encoding, rest) = strip_bom(input);
+    (encoding, input.len() - rest.len())
+}
+
+/// Detects a byte order mark from the first buffers of a stream, for a
+/// mark split between buffers.
+///
+/// Each buffer is given to feed() until it returns the encoding, with
+/// the number of bytes of that buffer to skip.  Bytes of earlier
+/// buffers are kept by the sniffer while the mark is undecided; the
+/// kept bytes that turn out not to be part of the mark are returned by
+/// replay(), and come before the rest of the buffer.  At end of data,
+/// finish() decides with the bytes kept.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut sniffer = BomSniffer::new();
+/// assert_eq!(Err(MoreEnum::More(3)), sniffer.feed(& [0xFFu8]));
+/// assert_eq!(Err(MoreEnum::More(2)), sniffer.feed(& [0xFEu8]));
+/// assert_eq!(Ok((Some(Encoding::Utf16Le), 0)), sniffer.feed(& [0x61u8, 0x00u8]));
+/// assert!(sniffer.replay().is_empty());
+///
+/// let mut sniffer = BomSniffer::new();
+/// assert_eq!(Err(MoreEnum::More(2)), sniffer.feed(& [0xEFu8, 0xBBu8]));
+/// assert_eq!(Ok((None, 0)), sniffer.feed(b"a"));
+/// assert_eq!(& [0xEFu8, 0xBBu8], sniffer.replay());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BomSniffer {
+
+    /// bytes kept from earlier buffers
+    my_held: [u8; 4],
+
+    /// number of bytes in my_held
+    my_held_len: u8,
+
+    /// number of kept bytes that are part of the mark
+    my_bom_held: u8,
+
+    /// the detected encoding, once decided
+    my_decision: Option<Option<Encoding>>,
+}
+
+/// Implementation of BomSniffer
+impl BomSniffer {
+
+    /// Make a new BomSniffer
+    pub fn new() -> BomSniffer {
+        BomSniffer::default()
+    }
+
+    /// Decide on the bytes of `window`.  Returns the encoding of the
+    /// mark, or 'None' as the inner value when there is no mark, or
+    /// 'None' when more bytes are needed to decide.
+    /// # Arguments
+    /// * `window` - the first bytes of the stream, up to 4
+    /// * `last_buffer` - true when no more bytes follow
+    fn decide(window: &[u8], last_buffer: bool) -> Option<Option<Encoding>> {
+        // Longer marks are tried first, as FF FE starts both
+        // UTF32LE and UTF16LE marks.
+        let candidates = [
+            Encoding::Utf32Le,
+            Encoding::Utf32Be,
+            Encoding::Utf8,
+            Encoding::Utf16Le,
+            Encoding::Utf16Be,
+        ];
+        for encoding in candidates.iter().copied() {
+            let bom = bom_bytes(encoding);
+            if window.starts_with(bom) {
+                return Option::Some(Option::Some(encoding));
+            }
+            if ! last_buffer && bom.starts_with(window) {
+                // The window may still grow into this mark.
+                return Option::None;
+            }
+        }
+        Option::Some(Option::None)
+    }
+
+    /// Record the decision, and return the number of bytes of the
+    /// current buffer to skip.
+    /// # Arguments
+    /// * `decision` - the detected encoding
+    fn settle(&mut self, decision: Option<Encoding>) -> usize {
+        let bom_len = decision.map(|v| bom_bytes(v).len()).unwrap_or(0);
+        let held_len = self.my_held_len as usize;
+        self.my_decision = Option::Some(decision);
+        self.my_bom_held = bom_len.min(held_len) as u8;
+        bom_len.saturating_sub(held_len)
+    }
+
+    /// Look for the byte order mark in the next buffer of the stream.
+    /// Returns the encoding of the mark, or 'None' without a mark, with
+    /// the number of bytes at the front of `input` that belong to the
+    /// mark; or an MoreEnum with the most bytes needed to decide, after
+    /// the sniffer took all of `input`.
+    ///
+    /// After the decision, feed() returns the same encoding with no
+    /// bytes to skip.
+    /// # Arguments
+    /// * `input` - the next buffer
+    pub fn feed(&mut self, input: &[u8]) -> Result<(Option<Encoding>, usize), MoreEnum> {
+        if let Option::Some(decision) = self.my_decision {
+            return Result::Ok((decision, 0));
+        }
+        let held_len = self.my_held_len as usize;
+        let mut window = self.my_held;
+        let take = input.len().min(4 - held_len);
+        for (slot, b) in window.iter_mut().skip(held_len).zip(input.iter().take(take)) {
+            * slot = * b;
+        }
+        let window_len = held_len + take;
+        match BomSniffer::decide(window.get(.. window_len).unwrap_or(&[]), false) {
+            Option::Some(decision) => {
+                Result::Ok((decision, self.settle(decision)))
+            }
+            Option::None => {
+                // Every byte of input fits in the window here.
+                self.my_held = window;
+                self.my_held_len = window_len as u8;
+                Result::Err(MoreEnum::More((4 - window_len) as u32))
+            }
+        }
+    }
+
+    /// Decide at end of data, with the bytes kept from earlier buffers.
+    /// Returns the encoding of the mark, or 'None' without a mark.
+    pub fn finish(&mut self) -> Option<Encoding> {
+        if let Option::Some(decision) = self.my_decision {
+            return decision;
+        }
+        let held_len = self.my_held_len as usize;
+        let decision = BomSniffer::decide(self.my_held.get(.. held_len).unwrap_or(&[]), true)
+            .unwrap_or(Option::None);
+        self.settle(decision);
+        decision
+    }
+
+    #[inline]
+    /// Returns the encoding once decided, or 'None' before the decision.
+    pub fn encoding(&self) -> Option<Option<Encoding>> {
+        self.my_decision
+    }
+
+    #[inline]
+    /// Returns the bytes kept from earlier buffers that are not part of
+    /// the mark, to be decoded before the rest of the buffer that
+    /// decided.  Empty before the decision.
+    pub fn replay(&self) -> &[u8] {
+        if self.my_decision.is_none() {
+            return &[];
+        }
+        self.my_held.get(self.my_bom_held as usize .. self.my_held_len as usize).unwrap_or(&[])
+    }
+
+    /// Reset the sniffer for a new stream.
+    pub fn reset(&mut self) {
+        * self = BomSniffer::new();
+    }
+}
+
+/// number of code units held by the UTF16 byte swap repair modes
+pub(crate) const SWAP_HOLD_UNITS: usize = 8;
+
+/// Byte order detection of the UTF16 byte swap repair modes.
+///
+/// Code units are held until the byte order is settled.  A byte order
+/// mark settles it at once.  Otherwise every held unit votes: an ASCII
+/// char in the 0x00XX pattern votes for the order as read, and one in
+/// the 0xXX00 pattern votes for swapped bytes.  The majority settles the
+/// order when the vote can no longer change, when the hold window is
+/// full, or at end of data.  A tie keeps the order as read, so text
+/// that starts with a char such as U+3000 or U+0100 is not swapped on
+/// its own.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct SwapDetector {
+
+    /// code units held until the byte order is settled
+    my_held: [u16; SWAP_HOLD_UNITS],
+
+    /// number of units in my_held
+    my_held_len: usize,
+
+    /// position of the next held unit to be taken back
+    my_held_pos: usize,
+
+    /// votes for the byte order as read
+    my_straight_votes: usize,
+
+    /// votes for swapped bytes
+    my_swapped_votes: usize,
+
+    /// true when the bytes are swapped, once settled
+    my_swapped: Option<bool>,
+}
+
+/// Implementation of SwapDetector
+impl SwapDetector {
+
+    #[inline]
+    /// Returns true once the byte order is settled.
+    pub(crate) fn is_settled(&self) -> bool {
+        self.my_swapped.is_some()
+    }
+
+    #[inline]
+    /// Returns true if the byte order is settled as swapped.
+    pub(crate) fn is_swapped(&self) -> bool {
+        self.my_swapped == Option::Some(true)
+    }
+
+    /// Hold a code unit, as read, while the byte order is unknown.
+    /// # Arguments
+    /// * `unit` - the code unit
+    pub(crate) fn hold(&mut self, unit: u16) {
+        self.my_held[self.my_held_len] = unit;
+        self.my_held_len += 1;
+        match unit {
+            0xFEFF => {
+                self.my_swapped = Option::Some(false);
+                return;
+            }
+            0xFFFE => {
+                self.my_swapped = Option::Some(true);
+                return;
+            }
+            _ => {}
+        }
+        if (unit > 0) && (unit < 0x80) {
+            self.my_straight_votes += 1;
+        }
+        let swapped_unit = unit.swap_bytes();
+        if (swapped_unit > 0) && (swapped_unit < 0x80) {
+            self.my_swapped_votes += 1;
+        }
+        let open = SWAP_HOLD_UNITS - self.my_held_len;
+        if (open == 0) || (self.my_straight_votes > self.my_swapped_votes + open)
+        || (self.my_swapped_votes > self.my_straight_votes + open) {
+            self.settle();
+        }
+    }
+
+    /// Settle the byte order by the votes so far, at end of data.
+    pub(crate) fn settle(&mut self) {
+        if self.my_swapped.is_none() {
+            self.my_swapped = Option::Some(self.my_swapped_votes > self.my_straight_votes);
+        }
+    }
+
+    #[inline]
+    /// Put a code unit in the settled byte order.
+    /// # Arguments
+    /// * `unit` - the code unit, as read
+    pub(crate) fn fix_order(&self, unit: u16) -> u16 {
+        if self.is_swapped() {
+            unit.swap_bytes()
+        }
+        else {
+            unit
+        }
+    }
+
+    /// Take back the next held code unit in the settled byte order, or
+    /// 'None' when the order is unknown or no unit is held.
+    pub(crate) fn take_held(&mut self) -> Option<u16> {
+        if ! self.is_settled() || (self.my_held_pos == self.my_held_len) {
+            return Option::None;
+        }
+        let unit = self.my_held[self.my_held_pos];
+        self.my_held_pos += 1;
+        Option::Some(self.fix_order(unit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    #[test]
+    // Test writing and stripping the marks of every encoding
+    fn test_bom_round_trip() {
+        let encodings = [
+            Encoding::Utf8,
+            Encoding::Utf16Le,
+            Encoding::Utf16Be,
+            Encoding::Utf32Le,
+            Encoding::Utf32Be,
+        ];
+        for encoding in encodings.iter().copied() {
+            let mut buf = [0x41u8; 6];
+            let len = write_bom(encoding, & mut buf).unwrap();
+            assert_eq!(bom_bytes(encoding), & buf[.. len]);
+            assert_eq!(Option::None, write_bom(encoding, & mut buf[.. len - 1]));
+            let (found, rest) = strip_bom(& buf);
+            assert_eq!(Option::Some(encoding), found);
+            assert_eq!(& buf[len ..], rest);
+        }
+        assert_eq!((Option::Some(Encoding::Utf16Le), & [0x00u8][..]), strip_bom(& [0xFFu8, 0xFEu8, 0x00u8]));
+        assert_eq!((Option::None, & [0xEFu8, 0xBBu8][..]), strip_bom(& [0xEFu8, 0xBBu8]));
+        assert_eq!((Option::None, & [][..]), strip_bom(& []));
+    }
+
+    #[test]
+    // Test the sniffer against detect_encoding() over every split of
+    // the first buffers
+    fn test_bom_sniffer() {
+        let cases: [&[u8]; 9] = [
+            & [0xEF, 0xBB, 0xBF, 0x61],
+            & [0xFF, 0xFE, 0x00, 0x00, 0x61],
+            & [0xFF, 0xFE, 0x61, 0x00],
+            & [0xFF, 0xFE, 0x00, 0x61],
+            & [0x00, 0x00, 0xFE, 0xFF],
+            & [0xFE, 0xFF, 0x00],
+            & [0xEF, 0xBB, 0x61],
+            & [0x00, 0x00, 0x61],
+            & [0x61],
+        ];
+        for bytes in cases.iter().copied() {
+            let (truth, truth_len) = detect_encoding(bytes);
+            for split in 0 ..= bytes.len() {
+                let mut sniffer = BomSniffer::new();
+                let (encoding, text) = match sniffer.feed(& bytes[.. split]) {
+                    Result::Ok((encoding, skip)) => {
+                        assert!(sniffer.replay().is_empty());
+                        (encoding, std::vec::Vec::from(& bytes[skip ..]))
+                    }
+                    Result::Err(MoreEnum::More(_)) => {
+                        let (encoding, skip) = match sniffer.feed(& bytes[split ..]) {
+                            Result::Ok(decision) => decision,
+                            Result::Err(_) => (sniffer.finish(), bytes.len() - split),
+                        };
+                        let mut text = std::vec::Vec::from(sniffer.replay());
+                        text.extend_from_slice(& bytes[split + skip ..]);
+                        (encoding, text)
+                    }
+                };
+                assert_eq!(truth, encoding, "bytes {:02X?} split {}", bytes, split);
+                assert_eq!(& bytes[truth_len ..], & text[..], "bytes {:02X?} split {}", bytes, split);
+                assert_eq!(Option::Some(truth), sniffer.encoding());
+                assert_eq!(Result::Ok((truth, 0)), sniffer.feed(b"x"));
+            }
+        }
+        let mut sniffer = BomSniffer::new();
+        assert_eq!(Result::Err(MoreEnum::More(2)), sniffer.feed(& [0xFF, 0xFE]));
+        assert_eq!(Option::Some(Encoding::Utf16Le), sniffer.finish());
+        assert!(sniffer.replay().is_empty());
+        sniffer.reset();
+        assert_eq!(Option::None, sniffer.encoding());
+    }
+}
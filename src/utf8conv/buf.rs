@@ -135,7 +135,7 @@ impl EightBytes {
             // so curlen * 8 always less than 64
             let curlen = self.mylen;
             let opword = (v as u64) << (curlen << 3);
-            self.buf += opword;
+            self.buf |= opword;
             self.mylen = curlen + 1;
         }
     }
@@ -150,7 +150,7 @@ impl EightBytes {
             // curlen can be from 0 to 7 when it is not full
             // so curlen * 8 always less than 64
             let curlen = self.mylen;
-            let opword = (v as u64) + (self.buf << 8);
+            let opword = (v as u64) | (self.buf << 8);
             self.buf = opword;
             self.mylen = curlen + 1;
         }
@@ -183,11 +183,45 @@ impl EightBytes {
             let oldbuf = self.buf;
             let res = oldbuf >> (newlen << 3);
             self.mylen = newlen;
-            self.buf = oldbuf - res << (newlen << 3);
+            self.buf = oldbuf - (res << (newlen << 3));
             Option::Some(res as u8)
         }
     }
 
+    #[inline]
+    /// Append bytes from the front of a slice until the buffer is full
+    /// or the slice runs out.  Returns the number of bytes consumed.
+    ///
+    /// When at least 8 bytes are available, the buffer is refilled
+    /// with a single unaligned u64 read instead of one push_back() per
+    /// byte, unless the "small" feature is on.
+    /// # Arguments
+    /// * `input` - bytes to be appended
+    pub fn fill_from_slice(& mut self, input: &[u8]) -> usize {
+        let curlen = self.mylen;
+        let room = (BUFFER_SIZE - curlen) as usize;
+        if room == 0 {
+            return 0;
+        }
+        if ! cfg!(feature = "small") {
+            if let Option::Some(word_bytes) = input.get(0 .. 8) {
+                let mut word_box = [0u8; 8];
+                word_box.copy_from_slice(word_bytes);
+                // curlen is from 0 to 7 here, so curlen * 8 always less
+                // than 64.  Bytes that do not fit are shifted out.
+                self.buf |= u64::from_le_bytes(word_box) << (curlen << 3);
+                self.mylen = BUFFER_SIZE;
+                return room;
+            }
+        }
+        // The "small" feature always appends a byte at a time.
+        let count = room.min(input.len());
+        for v in input.iter().take(count) {
+            self.push_back(* v);
+        }
+        count
+    }
+
     /// Returns the byte at the 'index' position without removing
     /// it from the collection.  The zeroth item is the oldest
     /// item from the collection.
@@ -250,22 +284,22 @@ mod tests {
         let mut b1:EightBytes = EightBytes::new();
         assert_eq!(b1.capacity(), 8);
         assert_eq!(b1.len(), 0);
-        assert_eq!(b1.is_empty(), true);
-        assert_eq!(b1.is_full(), false);
+        assert!(b1.is_empty());
+        assert!(! b1.is_full());
         assert_eq!(b1.pop_front(), Option::None);
         assert_eq!(b1.pop_back(), Option::None);
         assert_eq!(b1.front(), Option::None);
         assert_eq!(b1.back(), Option::None);
         b1.push_back(11u8);
-        assert_eq!(true, b1.eq(&b1));
+        assert!(b1.eq(&b1));
         assert_eq!(b1.len(), 1);
-        assert_eq!(b1.is_empty(), false);
-        assert_eq!(b1.is_full(), false);
+        assert!(! b1.is_empty());
+        assert!(! b1.is_full());
         assert_eq!(b1.front(), Option::Some(11u8));
         assert_eq!(b1.back(), Option::Some(11u8));
         assert_eq!(b1.pop_back(), Option::Some(11u8));
-        assert_eq!(b1.is_empty(), true);
-        assert_eq!(b1.is_full(), false);
+        assert!(b1.is_empty());
+        assert!(! b1.is_full());
         assert_eq!(b1.front(), Option::None);
         assert_eq!(b1.back(), Option::None);
         assert_eq!(b1.len(), 0u32);
@@ -305,6 +339,50 @@ mod tests {
         assert_eq!(b1.pop_back(), Option::None);
     }
 
+    #[test]
+    /// Test pop_back() leaving the remaining bytes in place.
+    fn test_eightbytes_pop_back() {
+        let mut b1:EightBytes = EightBytes::new();
+        for indx in 1u8 .. 9 {
+            b1.push_back(indx);
+        }
+        for indx in (1u8 .. 9).rev() {
+            assert_eq!(b1.pop_back(), Option::Some(indx));
+            assert_eq!(b1.len(), (indx - 1) as u32);
+            for pos in 0 .. indx - 1 {
+                assert_eq!(b1.peek_at(pos as usize), Option::Some(pos + 1));
+            }
+        }
+        assert!(b1.is_empty());
+    }
+
+    #[test]
+    /// Test refilling from slices of various lengths.
+    fn test_eightbytes_fill_from_slice() {
+        let source: [u8; 12] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        for start_len in 0u32 .. 9 {
+            for slice_len in 0usize .. source.len() {
+                let mut b1:EightBytes = EightBytes::new();
+                let mut b2:EightBytes = EightBytes::new();
+                for indx in 0 .. start_len {
+                    b1.push_back(0xF0u8 + indx as u8);
+                    b2.push_back(0xF0u8 + indx as u8);
+                }
+                let consumed = b1.fill_from_slice(& source[0 .. slice_len]);
+                let mut expected:usize = 0;
+                for v in & source[0 .. slice_len] {
+                    if b2.is_full() {
+                        break;
+                    }
+                    b2.push_back(* v);
+                    expected += 1;
+                }
+                assert_eq!(expected, consumed);
+                assert_eq!(b2, b1);
+            }
+        }
+    }
+
     #[test]
     /// Test pusing to full, then empty.
     fn test_eightbytes_add_del() {
@@ -312,24 +390,24 @@ mod tests {
         for indx in 0u32 .. b1.capacity() + 1 {
             if indx < b1.capacity() {
                 assert_eq!(indx, b1.len());
-                assert_eq!(! b1.is_full(), true);
+                assert!(! b1.is_full());
                 b1.push_back(indx as u8);
             }
             else {
                 b1.push_back(indx as u8);
-                assert_eq!(b1.is_full(), true);
+                assert!(b1.is_full());
             }
         }
         assert_eq!(Option::Some(0u8), b1.front());
         for indx in 0u32 .. b1.capacity() + 1 {
             if indx < b1.capacity() {
                 assert_eq!(b1.capacity() - indx, b1.len());
-                assert_eq!(! b1.is_empty(), true);
+                assert!(! b1.is_empty());
                 assert_eq!(b1.pop_front(), Option::Some(indx as u8));
             }
             else {
                 assert_eq!(b1.pop_front(), Option::None);
-                assert_eq!(b1.is_empty(), true);
+                assert!(b1.is_empty());
             }
         }
     }
@@ -373,7 +451,7 @@ mod tests {
                 }
             }
             else {
-                if b1.len() >= 1 {
+                if ! b1.is_empty() {
                     match b1.front() {
                         Some(_) => {}
                         None => {
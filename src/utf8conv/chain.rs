@@ -0,0 +1,515 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::chain
+
+use core::fmt;
+use core::iter::Peekable;
+
+use crate::utf8conv::fmt_parser_adapter;
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::MoreEnum;
+use crate::utf8conv::UtfParserCommon;
+
+/// adapter iterator presenting a sequence of byte buffers as one UTF8
+/// stream, and returning chars
+/// (This iterator contains a mutable borrow to the launching
+/// FromUtf8 object while this iterator is alive.)
+///
+/// The last buffer flag of the parser is managed by the iterator: it is
+/// set when the final buffer is presented.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut parser = FromUtf8::new();
+/// let buffers: [&[u8]; 3] = [b"ab", & [0xE2u8, 0x82u8], & [0xACu8, 0x21u8]];
+/// let text: String = parser.chained_buffers(buffers).collect();
+/// assert_eq!("ab\u{20AC}!", text);
+/// assert!(parser.is_last_buffer());
+/// ```
+pub struct ChainedBuffers<'p, 'b, I: Iterator<Item = &'b [u8]>> {
+
+    /// the remaining buffers
+    my_buffers: Peekable<I>,
+
+    /// the rest of the current buffer
+    my_cur: &'b [u8],
+
+    /// mutable reference to FromUtf8 object
+    my_info: &'p mut FromUtf8,
+}
+
+/// Implementation of FromUtf8 for chained buffers
+impl FromUtf8 {
+
+    /// Convert from a sequence of UTF8 buffers to char, setting the last
+    /// buffer flag on the final buffer.
+    /// # Arguments
+    /// * `buffers` - byte buffers in stream order
+    pub fn chained_buffers<'p, 'b, B>(&'p mut self, buffers: B)
+    -> ChainedBuffers<'p, 'b, B::IntoIter>
+    where B: IntoIterator<Item = &'b [u8]>, {
+        // The first call to next() fetches the first buffer.
+        self.set_is_last_buffer(false);
+        ChainedBuffers {
+            my_buffers: buffers.into_iter().peekable(),
+            my_cur: &[],
+            my_info: self,
+        }
+    }
+}
+
+/// Implementations of common operations for ChainedBuffers
+impl<'p, 'b, I: Iterator<Item = &'b [u8]>> UtfParserCommon for ChainedBuffers<'p, 'b, I> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_info.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_info.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_info.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid UTF8 sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_info.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid decodes state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_info.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    fn reset_parser(&mut self) {
+        self.my_info.reset_parser();
+    }
+}
+
+/// Iterator for ChainedBuffers
+impl<'p, 'b, I: Iterator<Item = &'b [u8]>> Iterator for ChainedBuffers<'p, 'b, I> {
+    type Item = char;
+
+    /// Returns the next char of the stream, moving on to the next
+    /// buffer as each one runs out.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.my_info.utf8_to_char(self.my_cur) {
+                Result::Ok((slice_pos, ch)) => {
+                    self.my_cur = slice_pos;
+                    return Option::Some(ch);
+                }
+                Result::Err(MoreEnum::More(0)) => {
+                    // End of data after the last buffer
+                    return Option::None;
+                }
+                Result::Err(MoreEnum::More(_amt)) => {
+                    self.my_cur = self.my_buffers.next().unwrap_or(&[]);
+                    let last_buffer = self.my_buffers.peek().is_none();
+                    self.my_info.set_is_last_buffer(last_buffer);
+                }
+            }
+        }
+    }
+}
+
+impl<'p, 'b, I: Iterator<Item = &'b [u8]>> fmt::Debug for ChainedBuffers<'p, 'b, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_parser_adapter(f, "ChainedBuffers", & self.my_info.my_buf,
+            self.my_info.my_last_buffer, self.my_info.my_invalid_sequence)
+    }
+}
+
+/// adapter iterator decoding a sequence of UTF8 chunks, such as
+/// message payloads, into chars across chunk boundaries
+/// (This iterator owns its FromUtf8 parser and its chunk iterator.)
+///
+/// Chunks can be any `AsRef<[u8]>` value, owned or borrowed.  The last
+/// buffer flag of the parser is managed by the iterator, as with
+/// ChainedBuffers.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let frames = vec![vec![0x61u8, 0xE2u8], vec![0x82u8, 0xACu8, 0xF0u8]];
+/// let mut iter = FromUtf8::new().utf8_chunks_into_char_iter(frames);
+/// let text: String = iter.by_ref().collect();
+/// assert_eq!("a\u{20AC}\u{FFFD}", text);
+/// assert!(iter.has_invalid_sequence());
+/// ```
+pub struct Utf8ChunksIntoCharIter<I: Iterator> {
+
+    /// the remaining chunks
+    my_chunks: Peekable<I>,
+
+    /// the current chunk
+    my_cur: Option<I::Item>,
+
+    /// position of the rest of the current chunk
+    my_pos: usize,
+
+    /// the parser
+    my_info: FromUtf8,
+}
+
+/// Implementation of FromUtf8 for chunk iterators
+impl FromUtf8 {
+
+    /// Convert from a sequence of UTF8 chunks to char with an iterator
+    /// adapter that takes ownership of a copy of this parser and of the
+    /// chunk iterator, setting the last buffer flag on the final chunk.
+    /// # Arguments
+    /// * `chunks` - UTF8 chunks in stream order
+    pub fn utf8_chunks_into_char_iter<C>(self, chunks: C)
+    -> Utf8ChunksIntoCharIter<C::IntoIter>
+    where C: IntoIterator, C::Item: AsRef<[u8]>, {
+        let mut parser = self;
+        // The first call to next() fetches the first chunk.
+        parser.set_is_last_buffer(false);
+        Utf8ChunksIntoCharIter {
+            my_chunks: chunks.into_iter().peekable(),
+            my_cur: Option::None,
+            my_pos: 0,
+            my_info: parser,
+        }
+    }
+}
+
+/// Implementations of common operations for Utf8ChunksIntoCharIter
+impl<I: Iterator> UtfParserCommon for Utf8ChunksIntoCharIter<I> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_info.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_info.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_info.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid UTF8 sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_info.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid decodes state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_info.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    fn reset_parser(&mut self) {
+        self.my_info.reset_parser();
+    }
+}
+
+/// Iterator for Utf8ChunksIntoCharIter
+impl<I> Iterator for Utf8ChunksIntoCharIter<I>
+where I: Iterator, I::Item: AsRef<[u8]>, {
+    type Item = char;
+
+    /// Returns the next char of the stream, moving on to the next
+    /// chunk as each one runs out.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let cur = self.my_cur.as_ref().map(|c| c.as_ref()).unwrap_or(&[]);
+            let rest = cur.get(self.my_pos ..).unwrap_or(&[]);
+            match self.my_info.utf8_to_char(rest) {
+                Result::Ok((slice_pos, ch)) => {
+                    self.my_pos = cur.len() - slice_pos.len();
+                    return Option::Some(ch);
+                }
+                Result::Err(MoreEnum::More(0)) => {
+                    // End of data after the last chunk
+                    return Option::None;
+                }
+                Result::Err(MoreEnum::More(_amt)) => {
+                    self.my_cur = self.my_chunks.next();
+                    self.my_pos = 0;
+                    let last_buffer = self.my_chunks.peek().is_none();
+                    self.my_info.set_is_last_buffer(last_buffer);
+                }
+            }
+        }
+    }
+}
+
+impl<I: Iterator> fmt::Debug for Utf8ChunksIntoCharIter<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_parser_adapter(f, "Utf8ChunksIntoCharIter", & self.my_info.my_buf,
+            self.my_info.my_last_buffer, self.my_info.my_invalid_sequence)
+    }
+}
+
+/// adapter iterator decoding UTF8 buffers handed out by a refill
+/// closure, so iteration runs on across buffers
+/// (This iterator contains a mutable borrow to the launching
+/// FromUtf8 object while this iterator is alive.)
+///
+/// The closure is called only when the current buffer is exhausted;
+/// it is never called ahead of time, so it may block or read from a
+/// device.  Buffers can be any `AsRef<[u8]>` value, owned or borrowed.
+/// Returning 'None' ends the stream: the last buffer flag is set, and
+/// the closure is not called again.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut source = vec![vec![0x61u8, 0xE2u8], vec![0x82u8], vec![0xACu8]].into_iter();
+/// let mut parser = FromUtf8::new();
+/// let text: String = parser.refill_buffers(|| source.next()).collect();
+/// assert_eq!("a\u{20AC}", text);
+/// assert!(parser.is_last_buffer());
+/// ```
+pub struct RefillBuffers<'p, B, F> {
+
+    /// the closure handing out the next buffer
+    my_source: F,
+
+    /// the current buffer
+    my_cur: Option<B>,
+
+    /// position of the rest of the current buffer
+    my_pos: usize,
+
+    /// mutable reference to FromUtf8 object
+    my_info: &'p mut FromUtf8,
+}
+
+/// Implementation of FromUtf8 for refill closures
+impl FromUtf8 {
+
+    /// Convert from UTF8 buffers handed out by `refill` to char, setting
+    /// the last buffer flag when `refill` returns 'None'.
+    /// # Arguments
+    /// * `refill` - a closure returning the next buffer, or 'None' at end of data
+    pub fn refill_buffers<B, F>(&mut self, refill: F) -> RefillBuffers<'_, B, F>
+    where B: AsRef<[u8]>, F: FnMut() -> Option<B>, {
+        // The first call to next() fetches the first buffer.
+        self.set_is_last_buffer(false);
+        RefillBuffers {
+            my_source: refill,
+            my_cur: Option::None,
+            my_pos: 0,
+            my_info: self,
+        }
+    }
+}
+
+/// Implementations of common operations for RefillBuffers
+impl<'p, B, F> UtfParserCommon for RefillBuffers<'p, B, F> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_info.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_info.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_info.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid UTF8 sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_info.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid decodes state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_info.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    fn reset_parser(&mut self) {
+        self.my_info.reset_parser();
+    }
+}
+
+/// Iterator for RefillBuffers
+impl<'p, B, F> Iterator for RefillBuffers<'p, B, F>
+where B: AsRef<[u8]>, F: FnMut() -> Option<B>, {
+    type Item = char;
+
+    /// Returns the next char of the stream, calling the refill closure
+    /// as each buffer runs out.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let cur = self.my_cur.as_ref().map(|c| c.as_ref()).unwrap_or(&[]);
+            let rest = cur.get(self.my_pos ..).unwrap_or(&[]);
+            match self.my_info.utf8_to_char(rest) {
+                Result::Ok((slice_pos, ch)) => {
+                    self.my_pos = cur.len() - slice_pos.len();
+                    return Option::Some(ch);
+                }
+                Result::Err(MoreEnum::More(0)) => {
+                    // End of data after the refill closure ran dry
+                    return Option::None;
+                }
+                Result::Err(MoreEnum::More(_amt)) => {
+                    self.my_cur = (self.my_source)();
+                    self.my_pos = 0;
+                    if self.my_cur.is_none() {
+                        self.my_info.set_is_last_buffer(true);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'p, B, F> fmt::Debug for RefillBuffers<'p, B, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_parser_adapter(f, "RefillBuffers", & self.my_info.my_buf,
+            self.my_info.my_last_buffer, self.my_info.my_invalid_sequence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[test]
+    // Test every split of a stream into three buffers
+    fn test_chained_buffers_splits() {
+        let bytes = [0x41u8, 0xF0u8, 0x90u8, 0x8Du8, 0x88u8, 0xE2u8, 0x41u8, 0xC2u8];
+        let truth = String::from_utf8_lossy(& bytes);
+        let mut parser = FromUtf8::new();
+        for pos1 in 0 ..= bytes.len() {
+            for pos2 in pos1 ..= bytes.len() {
+                let buffers = [& bytes[.. pos1], & bytes[pos1 .. pos2], & bytes[pos2 ..]];
+                let output: String = parser.chained_buffers(buffers.iter().copied()).collect();
+                assert_eq!(truth, output);
+                assert!(parser.has_invalid_sequence());
+                parser.reset_parser();
+            }
+        }
+    }
+
+    #[test]
+    // Test every split of a stream into three owned chunks
+    fn test_chunks_into_char_iter_splits() {
+        let bytes = [0xC2u8, 0x41u8, 0xF0u8, 0x90u8, 0x8Du8, 0x88u8, 0xE2u8, 0x82u8, 0xACu8, 0xF4u8];
+        let truth = String::from_utf8_lossy(& bytes);
+        for pos1 in 0 ..= bytes.len() {
+            for pos2 in pos1 ..= bytes.len() {
+                let chunks = std::vec![bytes[.. pos1].to_vec(), bytes[pos1 .. pos2].to_vec(), bytes[pos2 ..].to_vec()];
+                let mut iter = FromUtf8::new().utf8_chunks_into_char_iter(chunks);
+                let output: String = iter.by_ref().collect();
+                assert_eq!(truth, output);
+                assert!(iter.has_invalid_sequence());
+                assert!(iter.is_last_buffer());
+                assert_eq!(Option::None, iter.next());
+            }
+        }
+        let mut iter = FromUtf8::new().utf8_chunks_into_char_iter(Vec::<& str>::new());
+        assert_eq!(Option::None, iter.next());
+        assert!(! iter.has_invalid_sequence());
+        let iter = FromUtf8::new().utf8_chunks_into_char_iter(["ab", "", "c"].iter());
+        assert_eq!("abc", iter.collect::<String>());
+    }
+
+    #[test]
+    // Test every split of a stream into three refilled buffers, and
+    // that the refill closure is only called when a buffer runs out
+    fn test_refill_buffers_splits() {
+        let bytes = [0xE2u8, 0x82u8, 0xACu8, 0x41u8, 0xF0u8, 0x90u8, 0x8Du8, 0x88u8, 0xC2u8];
+        let truth = String::from_utf8_lossy(& bytes);
+        let mut parser = FromUtf8::new();
+        for pos1 in 0 ..= bytes.len() {
+            for pos2 in pos1 ..= bytes.len() {
+                let splits = [& bytes[.. pos1], & bytes[pos1 .. pos2], & bytes[pos2 ..]];
+                let mut buffers = splits.iter();
+                let output: String = parser.refill_buffers(|| buffers.next()).collect();
+                assert_eq!(truth, output);
+                assert!(parser.has_invalid_sequence());
+                assert!(parser.is_last_buffer());
+                parser.reset_parser();
+            }
+        }
+        let calls = core::cell::Cell::new(0);
+        let mut iter = parser.refill_buffers(|| {
+            calls.set(calls.get() + 1);
+            if calls.get() <= 2 { Option::Some(b"ab".to_vec()) } else { Option::None }
+        });
+        assert_eq!(Option::Some('a'), iter.next());
+        assert_eq!(Option::Some('b'), iter.next());
+        assert_eq!(1, calls.get());
+        assert_eq!("ab", iter.by_ref().collect::<String>());
+        assert_eq!(Option::None, iter.next());
+        assert_eq!(3, calls.get());
+        assert!(! iter.has_invalid_sequence());
+    }
+
+    #[test]
+    // Test empty sequences of buffers
+    fn test_chained_buffers_empty() {
+        let mut parser = FromUtf8::new();
+        let buffers: Vec<& [u8]> = Vec::new();
+        assert_eq!(0, parser.chained_buffers(buffers).count());
+        assert!(parser.is_last_buffer());
+        let mut iter = parser.chained_buffers([& [0xE2u8][..], & [][..]]);
+        assert_eq!(Option::Some(char::REPLACEMENT_CHARACTER), iter.next());
+        assert_eq!(Option::None, iter.next());
+        assert_eq!(Option::None, iter.next());
+        assert!(iter.has_invalid_sequence());
+    }
+}
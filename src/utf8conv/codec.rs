@@ -0,0 +1,193 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::codec
+
+use std::io;
+use std::string::String;
+
+use bytes::BytesMut;
+use tokio_util::codec::Decoder;
+use tokio_util::codec::Encoder;
+
+use crate::utf8conv::sink::FmtSink;
+use crate::utf8conv::FromUnicode;
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::UtfParserCommon;
+
+/// size of the encoder output buffer
+const CODEC_BUF_SIZE: usize = 1024;
+
+/// A tokio_util codec for UTF8 text streams, with lossy decoding.
+///
+/// The decoder returns the text decoded from the bytes received so
+/// far, one String per call, and keeps an incomplete sequence at the
+/// end for the next call.  Invalid sequences become replacement
+/// characters; at the end of the stream an incomplete sequence becomes
+/// one as well.  The encoder writes the UTF8 bytes of anything that
+/// dereferences to a str.
+///
+/// Use it with FramedRead and FramedWrite, or with Framed for both.
+///
+/// ```rust
+/// use bytes::BytesMut;
+/// use tokio_util::codec::Decoder;
+/// use tokio_util::codec::Encoder;
+/// use utf8conv::*;
+///
+/// let mut codec = Utf8Codec::new();
+/// let mut src = BytesMut::from(& b"caf\xC3"[..]);
+/// assert_eq!(Some(String::from("caf")), codec.decode(& mut src).unwrap());
+/// src.extend_from_slice(b"\xA9\xFF");
+/// assert_eq!(Some(String::from("\u{E9}\u{FFFD}")), codec.decode(& mut src).unwrap());
+/// assert!(codec.has_invalid_sequence());
+///
+/// let mut dst = BytesMut::new();
+/// codec.encode("\u{20AC}", & mut dst).unwrap();
+/// assert_eq!(& b"\xE2\x82\xAC"[..], & dst[..]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Utf8Codec {
+
+    /// the UTF8 parser of the decoder
+    my_parser: FromUtf8,
+
+    /// the UTF8 encoder
+    my_encoder: FromUnicode,
+}
+
+/// Implementation of Utf8Codec
+impl Utf8Codec {
+
+    /// Make a new Utf8Codec.
+    pub fn new() -> Utf8Codec {
+        let mut parser = FromUtf8::new();
+        parser.set_is_last_buffer(false);
+        let mut encoder = FromUnicode::new();
+        encoder.set_is_last_buffer(false);
+        Utf8Codec {
+            my_parser: parser,
+            my_encoder: encoder,
+        }
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in the decoded stream.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_parser.has_invalid_sequence()
+    }
+
+    /// Decode all of `src`, and return the text, or 'None' when no char
+    /// came out.
+    fn decode_all(&mut self, src: &mut BytesMut) -> Option<String> {
+        let mut text = String::with_capacity(src.len());
+        let mut cur_slice: &[u8] = src;
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((rest, _num_chars)) = self.my_parser.utf8_to_sink(cur_slice, & mut FmtSink(& mut text)) {
+            cur_slice = rest;
+        }
+        // The parser holds an incomplete sequence at the end.
+        src.clear();
+        if text.is_empty() {
+            Option::None
+        }
+        else {
+            Option::Some(text)
+        }
+    }
+}
+
+/// Default for Utf8Codec
+impl Default for Utf8Codec {
+    fn default() -> Utf8Codec {
+        Utf8Codec::new()
+    }
+}
+
+/// Decoder of UTF8 bytes into text
+impl Decoder for Utf8Codec {
+    type Item = String;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<String>> {
+        self.my_parser.set_is_last_buffer(false);
+        Result::Ok(self.decode_all(src))
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> io::Result<Option<String>> {
+        self.my_parser.set_is_last_buffer(true);
+        Result::Ok(self.decode_all(src))
+    }
+}
+
+/// Encoder of text into UTF8 bytes
+impl<T: AsRef<str>> Encoder<T> for Utf8Codec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> io::Result<()> {
+        let mut src = item.as_ref();
+        let mut byte_box = [0u8; CODEC_BUF_SIZE];
+        dst.reserve(src.len());
+        // The loop stops at MoreEnum::More(_amt), when the text is
+        // used up.
+        while let Result::Ok(len) = self.my_encoder.source_to_utf8_slice(& mut src, & mut byte_box) {
+            dst.extend_from_slice(& byte_box[.. len]);
+        }
+        Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use bytes::BytesMut;
+    use tokio_util::codec::Decoder;
+    use tokio_util::codec::Encoder;
+
+    use crate::*;
+
+    use std::string::String;
+
+    #[test]
+    // Test decoding every split of an input against from_utf8_lossy()
+    fn test_utf8_codec_decode() {
+        let bytes = b"ASCII \xC3\xA9\xE2\x82\xAC\xF0\x9F\x98\x80 \xFF\xC0\xAF end\xF0\x9F";
+        let expected = String::from_utf8_lossy(bytes);
+        for split in 0 ..= bytes.len() {
+            let mut codec = Utf8Codec::default();
+            let mut src = BytesMut::from(& bytes[.. split]);
+            let mut text = codec.decode(& mut src).unwrap().unwrap_or_default();
+            assert!(src.is_empty());
+            src.extend_from_slice(& bytes[split ..]);
+            text.push_str(& codec.decode(& mut src).unwrap().unwrap_or_default());
+            text.push_str(& codec.decode_eof(& mut src).unwrap().unwrap_or_default());
+            assert_eq!(expected, text);
+            assert_eq!(Option::None, codec.decode_eof(& mut src).unwrap());
+            assert!(codec.has_invalid_sequence());
+        }
+        let mut codec = Utf8Codec::new();
+        assert_eq!(Option::None, codec.decode(& mut BytesMut::new()).unwrap());
+        assert_eq!(Option::None, codec.decode(& mut BytesMut::from(& b"\xE2\x82"[..])).unwrap());
+        assert!(! codec.has_invalid_sequence());
+    }
+
+    #[test]
+    // Test encoding str and String items
+    fn test_utf8_codec_encode() {
+        let text: String = "x\u{10348}\u{20AC}\n".repeat(300);
+        let mut codec = Utf8Codec::new();
+        let mut dst = BytesMut::new();
+        codec.encode(text.as_str(), & mut dst).unwrap();
+        codec.encode(String::from("\u{7FF}"), & mut dst).unwrap();
+        codec.encode("", & mut dst).unwrap();
+        assert_eq!(std::format!("{}\u{7FF}", text).as_bytes(), & dst[..]);
+    }
+}
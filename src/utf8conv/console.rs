@@ -0,0 +1,145 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::console
+
+use core::ffi::c_void;
+use core::ptr;
+use std::io;
+use std::io::Write;
+use std::os::windows::io::AsRawHandle;
+use std::os::windows::io::RawHandle;
+
+use crate::utf8conv::encode;
+
+/// number of UTF16 code units written to the console at a time
+const CONSOLE_BUF_UNITS: usize = 512;
+
+/// number of UTF8 bytes written to a redirected handle at a time
+const FILE_BUF_SIZE: usize = 1024;
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn GetConsoleMode(handle: RawHandle, mode: *mut u32) -> i32;
+    fn WriteConsoleW(handle: RawHandle, buffer: *const u16, len: u32,
+        written: *mut u32, reserved: *mut c_void) -> i32;
+    fn WriteFile(handle: RawHandle, buffer: *const u8, len: u32,
+        written: *mut u32, overlapped: *mut c_void) -> i32;
+}
+
+/// Returns true if `handle` is a console.
+fn is_console(handle: RawHandle) -> bool {
+    let mut mode:u32 = 0;
+    // Unsafe is justified because the mode is written to a local.
+    unsafe { GetConsoleMode(handle, & mut mode) != 0 }
+}
+
+/// Write all the UTF16 code units of `units` to the console `handle`.
+fn write_console_units(handle: RawHandle, units: &[u16]) -> io::Result<()> {
+    let mut rest = units;
+    while ! rest.is_empty() {
+        let mut written:u32 = 0;
+        // Unsafe is justified because the buffer is a live slice, and
+        // its length fits in u32.
+        let ok = unsafe {
+            WriteConsoleW(handle, rest.as_ptr(), rest.len() as u32, & mut written, ptr::null_mut())
+        };
+        if ok == 0 {
+            return Result::Err(io::Error::last_os_error());
+        }
+        rest = rest.get(written as usize ..).unwrap_or(&[]);
+    }
+    Result::Ok(())
+}
+
+/// Write all the bytes of `bytes` to the file or pipe `handle`.
+fn write_file_bytes(handle: RawHandle, bytes: &[u8]) -> io::Result<()> {
+    let mut rest = bytes;
+    while ! rest.is_empty() {
+        let mut written:u32 = 0;
+        // Unsafe is justified because the buffer is a live slice, and
+        // its length fits in u32.
+        let ok = unsafe {
+            WriteFile(handle, rest.as_ptr(), rest.len() as u32, & mut written, ptr::null_mut())
+        };
+        if ok == 0 {
+            return Result::Err(io::Error::last_os_error());
+        }
+        if written == 0 {
+            return Result::Err(io::Error::from(io::ErrorKind::WriteZero));
+        }
+        rest = rest.get(written as usize ..).unwrap_or(&[]);
+    }
+    Result::Ok(())
+}
+
+/// Write chars to a Windows handle: as UTF16 with WriteConsoleW when
+/// the handle is a console, so non-ASCII text shows correctly whatever
+/// the console code page is, or as UTF8 with WriteFile when the handle
+/// is redirected to a file or a pipe.
+///
+/// The handle is written directly; flush any std::io buffer in front of
+/// it first.
+///
+/// ```rust,no_run
+/// use utf8conv::*;
+///
+/// let stderr = std::io::stderr();
+/// write_console_chars(& stderr, "\u{4E2D}\u{6587}\n".chars()).unwrap();
+/// ```
+/// # Arguments
+/// * `handle` - the console or file handle, such as std::io::Stdout
+/// * `input` - the chars to be written
+pub fn write_console_chars<H, I>(handle: &H, input: I) -> io::Result<()>
+where H: AsRawHandle + ?Sized, I: IntoIterator<Item = char>, {
+    let handle = handle.as_raw_handle();
+    if is_console(handle) {
+        let mut out = [0u16; CONSOLE_BUF_UNITS];
+        let mut len:usize = 0;
+        for ch in input {
+            // Surrogate pairs are not split across writes.
+            if len + 2 > CONSOLE_BUF_UNITS {
+                write_console_units(handle, & out[.. len])?;
+                len = 0;
+            }
+            len += ch.encode_utf16(& mut out[len ..]).len();
+        }
+        write_console_units(handle, & out[.. len])
+    }
+    else {
+        let mut out = [0u8; FILE_BUF_SIZE];
+        let mut len:usize = 0;
+        for b in encode(input) {
+            out[len] = b;
+            len += 1;
+            if len == FILE_BUF_SIZE {
+                write_file_bytes(handle, & out)?;
+                len = 0;
+            }
+        }
+        write_file_bytes(handle, & out[.. len])
+    }
+}
+
+/// Write chars to standard output with write_console_chars(), after
+/// flushing the buffer of std::io::Stdout.
+///
+/// ```rust,no_run
+/// use utf8conv::*;
+///
+/// print_console_chars("caf\u{E9}\n".chars()).unwrap();
+/// ```
+/// # Arguments
+/// * `input` - the chars to be printed
+pub fn print_console_chars<I>(input: I) -> io::Result<()>
+where I: IntoIterator<Item = char>, {
+    let stdout = io::stdout();
+    let mut lock = stdout.lock();
+    lock.flush()?;
+    write_console_chars(& stdout, input)
+}
@@ -0,0 +1,331 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::context
+
+use core::fmt;
+
+use crate::utf8conv::format_into;
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::MoreEnum;
+use crate::utf8conv::UtfParserCommon;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Where an invalid UTF8 sequence occurred, with up to K chars decoded
+/// right before it.
+///
+/// Display gives a message such as
+/// `invalid UTF8 sequence after "user_na" at byte 1042`.
+pub struct Utf8ErrorContext<const K: usize> {
+
+    /// the chars before the invalid sequence, oldest first
+    my_recent: [char; K],
+
+    /// number of chars in my_recent
+    my_recent_len: usize,
+
+    /// byte offset of the invalid sequence in the stream
+    my_offset: u64,
+}
+
+/// Implementation of Utf8ErrorContext
+impl<const K: usize> Utf8ErrorContext<K> {
+
+    #[inline]
+    /// The chars decoded right before the invalid sequence, oldest first
+    pub fn recent(&self) -> &[char] {
+        self.my_recent.get(.. self.my_recent_len).unwrap_or(&[])
+    }
+
+    #[inline]
+    /// Byte offset of the invalid sequence from the start of the stream
+    pub fn offset(&self) -> u64 {
+        self.my_offset
+    }
+
+    /// Write the Display message into `buf` without allocation, and
+    /// return it.  A message too long for the buffer is cut short at a
+    /// char boundary.
+    /// # Arguments
+    /// * `buf` - the output buffer
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        format_into(self, buf)
+    }
+}
+
+/// Display for Utf8ErrorContext
+impl<const K: usize> fmt::Display for Utf8ErrorContext<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid UTF8 sequence after \"")?;
+        for ch in self.recent() {
+            write!(f, "{}", ch.escape_debug())?;
+        }
+        write!(f, "\" at byte {}", self.my_offset)
+    }
+}
+
+/// Error for Utf8ErrorContext
+impl<const K: usize> core::error::Error for Utf8ErrorContext<K> {}
+
+/// A FromUtf8 parser that also keeps the last K decoded chars and the
+/// stream position, to describe where invalid sequences occur.
+///
+/// The bookkeeping costs a little on every char, so it lives in this
+/// separate parser; FromUtf8 itself stays as small as before.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut parser = FromUtf8WithContext::<8>::new();
+/// let mut cur_slice = & b"user_name\xFF"[..];
+/// // The loop stops at MoreEnum::More(_amt);
+/// // _amt equals to 0 when end of data
+/// while let Ok((slice_pos, _ch)) = parser.utf8_to_char(cur_slice) {
+///     cur_slice = slice_pos;
+/// }
+/// let context = parser.last_error().unwrap();
+/// assert_eq!("invalid UTF8 sequence after \"ser_name\" at byte 9", format!("{}", context));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FromUtf8WithContext<const K: usize> {
+
+    /// the underlying parser
+    my_parser: FromUtf8,
+
+    /// ring of the last K chars
+    my_ring: [char; K],
+
+    /// position of the oldest char in my_ring
+    my_ring_start: usize,
+
+    /// number of chars in my_ring
+    my_ring_len: usize,
+
+    /// number of bytes taken from input slices
+    my_fed: u64,
+
+    /// context of the latest invalid sequence
+    my_last_error: Option<Utf8ErrorContext<K>>,
+}
+
+/// Implementation of FromUtf8WithContext
+impl<const K: usize> FromUtf8WithContext<K> {
+
+    /// Make a new FromUtf8WithContext
+    pub fn new() -> FromUtf8WithContext<K> {
+        FromUtf8WithContext {
+            my_parser: FromUtf8::new(),
+            my_ring: ['\0'; K],
+            my_ring_start: 0,
+            my_ring_len: 0,
+            my_fed: 0,
+            my_last_error: Option::None,
+        }
+    }
+
+    #[inline]
+    /// Byte offset of the next char to be decoded
+    pub fn position(&self) -> u64 {
+        self.my_fed - self.my_parser.my_buf.len() as u64
+    }
+
+    #[inline]
+    /// Context of the latest invalid sequence, if any
+    pub fn last_error(&self) -> Option<Utf8ErrorContext<K>> {
+        self.my_last_error
+    }
+
+    /// Append a char to the ring, dropping the oldest when full.
+    fn remember(&mut self, ch: char) {
+        if K == 0 {
+            return;
+        }
+        let end = (self.my_ring_start + self.my_ring_len) % K;
+        if let Option::Some(slot) = self.my_ring.get_mut(end) {
+            * slot = ch;
+        }
+        if self.my_ring_len < K {
+            self.my_ring_len += 1;
+        }
+        else {
+            self.my_ring_start = (self.my_ring_start + 1) % K;
+        }
+    }
+
+    /// Copy the ring into an error context.
+    fn capture(&self, offset: u64) -> Utf8ErrorContext<K> {
+        let mut recent = ['\0'; K];
+        for (indx, slot) in recent.iter_mut().take(self.my_ring_len).enumerate() {
+            * slot = self.my_ring[(self.my_ring_start + indx) % K];
+        }
+        Utf8ErrorContext {
+            my_recent: recent,
+            my_recent_len: self.my_ring_len,
+            my_offset: offset,
+        }
+    }
+
+    /// A parser takes in byte slice, and returns a Result object with
+    /// either the remaining input and the output char value, or an MoreEnum
+    /// that requests additional data, or an end of data stream condition.
+    ///
+    /// Same as FromUtf8::utf8_to_char(); in addition, the context of
+    /// each invalid sequence is recorded for last_error().
+    pub fn utf8_to_char<'b>(&mut self, input: &'b [u8])
+    -> Result<(&'b [u8], char), MoreEnum> {
+        let start = self.position();
+        let was_invalid = self.my_parser.has_invalid_sequence();
+        self.my_parser.reset_invalid_sequence();
+        let result = self.my_parser.utf8_to_char(input);
+        let rest_len = match & result {
+            Result::Ok((slice_pos, _ch)) => slice_pos.len(),
+            Result::Err(_) => 0,
+        };
+        self.my_fed += (input.len() - rest_len) as u64;
+        if let Result::Ok((_slice_pos, ch)) = & result {
+            if self.my_parser.has_invalid_sequence() {
+                self.my_last_error = Option::Some(self.capture(start));
+            }
+            self.remember(* ch);
+        }
+        if was_invalid {
+            self.my_parser.signal_invalid_sequence();
+        }
+        result
+    }
+}
+
+/// Default for FromUtf8WithContext
+impl<const K: usize> Default for FromUtf8WithContext<K> {
+    fn default() -> FromUtf8WithContext<K> {
+        FromUtf8WithContext::new()
+    }
+}
+
+/// Implementations of common operations for FromUtf8WithContext
+impl<const K: usize> UtfParserCommon for FromUtf8WithContext<K> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_parser.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_parser.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_parser.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid UTF8 sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_parser.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid decodes state, and the context
+    /// of the latest invalid sequence.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_parser.reset_invalid_sequence();
+        self.my_last_error = Option::None;
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    /// The stream position and the recent chars are cleared.
+    fn reset_parser(&mut self) {
+        * self = FromUtf8WithContext::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::vec::Vec;
+
+    #[test]
+    // Test error offsets and recent chars across buffers
+    fn test_context_across_buffers() {
+        let buffers: [& [u8]; 3] = [b"ab\xE2\x82", b"\xAC\xC0cd\xF0", b"\x90"];
+        let mut parser = FromUtf8WithContext::<3>::new();
+        let mut contexts = Vec::new();
+        for (indx, buf) in buffers.iter().enumerate() {
+            parser.set_is_last_buffer(indx + 1 == buffers.len());
+            let mut cur_slice = * buf;
+            // The loop stops at MoreEnum::More(_amt);
+            // _amt equals to 0 when end of data
+            while let Result::Ok((slice_pos, _ch)) = parser.utf8_to_char(cur_slice) {
+                cur_slice = slice_pos;
+                if let Option::Some(context) = parser.last_error() {
+                    contexts.push(context);
+                    parser.reset_invalid_sequence();
+                }
+            }
+        }
+        assert_eq!(10, parser.position());
+        assert_eq!(2, contexts.len());
+        assert_eq!((5, & ['a', 'b', '\u{20AC}'][..]), (contexts[0].offset(), contexts[0].recent()));
+        assert_eq!((8, & ['\u{FFFD}', 'c', 'd'][..]), (contexts[1].offset(), contexts[1].recent()));
+        assert!(! parser.has_invalid_sequence());
+    }
+
+    #[test]
+    // Test the message, a zero sized ring and the sticky invalid flag
+    fn test_context_display() {
+        let mut parser = FromUtf8WithContext::<0>::default();
+        assert_eq!(Result::Ok((& b""[..], '\u{FFFD}')), parser.utf8_to_char(b"\x80\n"));
+        assert_eq!(1, parser.position());
+        assert_eq!(Result::Ok((& b""[..], '\n')), parser.utf8_to_char(b""));
+        assert!(parser.has_invalid_sequence());
+        let context = parser.last_error().unwrap();
+        assert_eq!("invalid UTF8 sequence after \"\" at byte 0", std::format!("{}", context));
+        let mut parser = FromUtf8WithContext::<4>::new();
+        let mut cur_slice = & b"\"\t\xFFz"[..];
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, _ch)) = parser.utf8_to_char(cur_slice) {
+            cur_slice = slice_pos;
+        }
+        assert_eq!("invalid UTF8 sequence after \"\\\"\\t\" at byte 2",
+            std::format!("{}", parser.last_error().unwrap()));
+        parser.reset_parser();
+        assert_eq!((0, Option::None), (parser.position(), parser.last_error()));
+    }
+
+    #[test]
+    // Test formatting into buffers of every size
+    fn test_context_format_into() {
+        let mut parser = FromUtf8WithContext::<4>::new();
+        let _ = parser.utf8_to_char(b"\xC3\xA9t\xC3\xA9\x80");
+        while parser.utf8_to_char(b"").is_ok() {}
+        let context = parser.last_error().unwrap();
+        let message = std::format!("{}", context);
+        assert_eq!("invalid UTF8 sequence after \"\u{E9}t\u{E9}\" at byte 5", message);
+        for size in 0 ..= message.len() + 1 {
+            let mut buf = [0u8; 64];
+            let text = context.format_into(& mut buf[0 .. size]);
+            assert!(message.starts_with(text));
+            // A cut never splits a char.
+            assert!(size - text.len() < 2 || text.len() == message.len());
+        }
+    }
+}
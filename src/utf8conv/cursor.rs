@@ -0,0 +1,306 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::cursor
+
+use crate::utf8conv::buf::EightBytes;
+use crate::utf8conv::finished_code_to_char;
+use crate::utf8conv::utf8_decode_with_len;
+use crate::utf8conv::Utf8EndEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A cursor reading chars from a complete UTF8 byte slice.
+///
+/// The cursor tracks its own position, so there is no remainder slice
+/// to reassign after every char.  Invalid sequences are returned as
+/// replacement characters, the same as FromUtf8 with the last buffer
+/// flag set.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut cursor = Utf8Cursor::new("a\u{20AC}b".as_bytes());
+/// assert_eq!(Some('a'), cursor.next_char());
+/// assert_eq!(Some('\u{20AC}'), cursor.peek_char());
+/// assert_eq!(1, cursor.position());
+/// assert_eq!(Some('\u{20AC}'), cursor.next_char());
+/// assert_eq!(b"b", cursor.remaining());
+/// ```
+pub struct Utf8Cursor<'a> {
+
+    /// the input slice
+    my_input: &'a [u8],
+
+    /// byte position of the next char
+    my_pos: usize,
+
+    /// true after returning a char from an invalid sequence
+    my_invalid_sequence: bool,
+}
+
+/// Implementation of Utf8Cursor
+impl<'a> Utf8Cursor<'a> {
+
+    /// Make a new Utf8Cursor at the start of `input`.
+    /// # Arguments
+    /// * `input` - the complete UTF8 input
+    pub fn new(input: &'a [u8]) -> Utf8Cursor<'a> {
+        Utf8Cursor {
+            my_input: input,
+            my_pos: 0,
+            my_invalid_sequence: false,
+        }
+    }
+
+    /// Decode the char at the current position.  Returns the char,
+    /// its length in bytes, and whether it came from an invalid sequence.
+    fn decode_at(&self) -> Option<(char, usize, bool)> {
+        let rest = self.remaining();
+        if rest.is_empty() {
+            return Option::None;
+        }
+        // A sequence is at most 4 bytes, so the scratch pad always holds
+        // a whole sequence unless the input ends first.
+        let mut mybuf = EightBytes::new();
+        mybuf.fill_from_slice(rest);
+        match utf8_decode_with_len(& mut mybuf, true) {
+            (Utf8EndEnum::Finish(code), len) => {
+                Option::Some((finished_code_to_char(code), len as usize, false))
+            }
+            (_, len) => {
+                // A bad decode, or a sequence truncated by the end of input
+                Option::Some((char::REPLACEMENT_CHARACTER, len as usize, true))
+            }
+        }
+    }
+
+    /// Return the next char, and advance past it.
+    /// Returns 'None' at the end of input.
+    pub fn next_char(&mut self) -> Option<char> {
+        let (ch, len, invalid) = self.decode_at()?;
+        self.my_pos += len;
+        self.my_invalid_sequence |= invalid;
+        Option::Some(ch)
+    }
+
+    /// Return the next char without advancing.
+    /// Returns 'None' at the end of input.
+    pub fn peek_char(&self) -> Option<char> {
+        self.decode_at().map(|(ch, _len, _invalid)| ch)
+    }
+
+    #[inline]
+    /// Byte position of the next char in the input
+    pub fn position(&self) -> usize {
+        self.my_pos
+    }
+
+    #[inline]
+    /// The input not yet read
+    pub fn remaining(&self) -> &'a [u8] {
+        self.my_input.get(self.my_pos ..).unwrap_or(&[])
+    }
+
+    #[inline]
+    /// Returns true if an invalid sequence was read.
+    /// Encountering a replacement character is considered the same as
+    /// having an invalid decode.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_invalid_sequence
+    }
+}
+
+/// Iterator for Utf8Cursor
+impl<'a> Iterator for Utf8Cursor<'a> {
+    type Item = char;
+
+    #[inline]
+    /// Same as next_char()
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_char()
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining().len();
+        (len.div_ceil(4), Option::Some(len))
+    }
+}
+
+#[derive(Debug, Clone)]
+/// adapter iterator decoding an UTF8 byte iterator into chars, each
+/// with the source bytes that produced it
+///
+/// Each item is the char, the source bytes in the first part of a
+/// 4 byte array, and the number of source bytes.  For an invalid
+/// sequence the char is a replacement character, and the bytes are
+/// the invalid bytes that were replaced, so the input can be echoed
+/// verbatim.  Invalid sequences are found the same way as FromUtf8
+/// with the last buffer flag set.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut iter = chars_with_bytes(b"a\xE2\x82\xAC\xFF".iter().copied());
+/// assert_eq!(Some(('a', [0x61, 0, 0, 0], 1)), iter.next());
+/// assert_eq!(Some(('\u{20AC}', [0xE2, 0x82, 0xAC, 0], 3)), iter.next());
+/// assert_eq!(Some(('\u{FFFD}', [0xFF, 0, 0, 0], 1)), iter.next());
+/// assert_eq!(None, iter.next());
+/// assert!(iter.has_invalid_sequence());
+/// ```
+pub struct Utf8CharsWithBytes<I> {
+
+    /// the source iterator
+    my_iter: I,
+
+    /// bytes read from the source, not yet decoded
+    my_buf: EightBytes,
+
+    /// true after returning a char from an invalid sequence
+    my_invalid_sequence: bool,
+}
+
+/// Implementation of Utf8CharsWithBytes
+impl<I> Utf8CharsWithBytes<I> {
+
+    #[inline]
+    /// Returns true if an invalid sequence was read.
+    /// Encountering a replacement character is considered the same as
+    /// having an invalid decode.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_invalid_sequence
+    }
+}
+
+/// Iterator for Utf8CharsWithBytes
+impl<I: Iterator<Item = u8>> Iterator for Utf8CharsWithBytes<I> {
+    type Item = (char, [u8; 4], usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // A sequence is at most 4 bytes, so a full scratch pad always
+        // holds a whole sequence.
+        let mut last_buffer = false;
+        while ! self.my_buf.is_full() {
+            match self.my_iter.next() {
+                Option::Some(b) => self.my_buf.push_back(b),
+                Option::None => {
+                    last_buffer = true;
+                    break;
+                }
+            }
+        }
+        if self.my_buf.is_empty() {
+            return Option::None;
+        }
+        let mut bytes = [0u8; 4];
+        for (indx, slot) in bytes.iter_mut().enumerate() {
+            * slot = self.my_buf.peek_at(indx).unwrap_or(0);
+        }
+        let (ch, len) = match utf8_decode_with_len(& mut self.my_buf, last_buffer) {
+            (Utf8EndEnum::Finish(code), len) => (finished_code_to_char(code), len as usize),
+            (_, len) => {
+                // A bad decode, or a sequence truncated by the end of input
+                self.my_invalid_sequence = true;
+                (char::REPLACEMENT_CHARACTER, len as usize)
+            }
+        };
+        for slot in bytes.iter_mut().skip(len) {
+            * slot = 0;
+        }
+        Option::Some((ch, bytes, len))
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.my_iter.size_hint();
+        let held = self.my_buf.len() as usize;
+        let lower = (lower > 0 || held > 0) as usize;
+        (lower, upper.and_then(|v| v.checked_add(held)))
+    }
+}
+
+/// Function chars_with_bytes() takes an UTF8 byte iterator, and returns
+/// an iterator of chars, each with the source bytes that produced it.
+/// # Arguments
+/// * `input` - the UTF8 bytes
+pub fn chars_with_bytes<I>(input: I) -> Utf8CharsWithBytes<I::IntoIter>
+where I: IntoIterator<Item = u8>, {
+    Utf8CharsWithBytes {
+        my_iter: input.into_iter(),
+        my_buf: EightBytes::new(),
+        my_invalid_sequence: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    #[test]
+    // Test positions across valid and invalid sequences
+    fn test_cursor_positions() {
+        let bytes = [0x41u8, 0xE2u8, 0x41u8, 0xF0u8, 0x90u8, 0x8Du8, 0x88u8, 0xEFu8, 0xBFu8, 0xBDu8, 0xF4u8, 0x8Fu8];
+        let mut cursor = Utf8Cursor::new(& bytes);
+        let mut steps = std::vec::Vec::new();
+        while let Option::Some(ch) = cursor.next_char() {
+            steps.push((ch, cursor.position()));
+        }
+        assert_eq!(steps, [
+            ('A', 1), ('\u{FFFD}', 2), ('A', 3), ('\u{10348}', 7),
+            ('\u{FFFD}', 10), ('\u{FFFD}', 12),
+        ]);
+        assert!(cursor.has_invalid_sequence());
+        assert_eq!(Option::None, cursor.peek_char());
+        assert_eq!(0, cursor.remaining().len());
+    }
+
+    #[test]
+    // Test the cursor against the lossy conversion of the standard library
+    fn test_cursor_matches_lossy() {
+        let bytes = [0xC2u8, 0x41u8, 0xE0u8, 0x80u8, 0xAFu8, 0xEDu8, 0xA0u8, 0x80u8, 0x7Fu8, 0xDFu8, 0xBFu8];
+        let cursor = Utf8Cursor::new(& bytes);
+        assert_eq!((3, Option::Some(11)), cursor.size_hint());
+        let output: std::string::String = cursor.collect();
+        assert_eq!(std::string::String::from_utf8_lossy(& bytes), output);
+        let mut cursor = Utf8Cursor::new(b"ok");
+        assert_eq!(Option::Some('o'), cursor.peek_char());
+        assert_eq!(0, cursor.position());
+        assert_eq!("ok", cursor.by_ref().collect::<std::string::String>());
+        assert!(! cursor.has_invalid_sequence());
+    }
+
+    #[test]
+    // Test that the source bytes of every char put together are the input
+    fn test_chars_with_bytes() {
+        let bytes = [
+            0x41u8, 0xE2u8, 0x41u8, 0xF0u8, 0x90u8, 0x8Du8, 0x88u8, 0xEFu8, 0xBFu8, 0xBDu8,
+            0xC0u8, 0xAFu8, 0xEDu8, 0xA0u8, 0x80u8, 0xDFu8, 0xBFu8, 0xF4u8, 0x8Fu8,
+        ];
+        for end in 0 ..= bytes.len() {
+            let input = & bytes[.. end];
+            let mut iter = chars_with_bytes(input.iter().copied());
+            let mut text = std::string::String::new();
+            let mut echo = std::vec::Vec::new();
+            for (ch, source, len) in iter.by_ref() {
+                assert!((1 ..= 4).contains(& len));
+                assert!(source[len ..].iter().all(|v| * v == 0));
+                if ch != char::REPLACEMENT_CHARACTER {
+                    assert_eq!(ch.encode_utf8(& mut [0u8; 4]).as_bytes(), & source[.. len]);
+                }
+                text.push(ch);
+                echo.extend_from_slice(& source[.. len]);
+            }
+            assert_eq!(input, & echo[..]);
+            assert_eq!(Utf8Cursor::new(input).collect::<std::string::String>(), text);
+            assert_eq!(end > 1, iter.has_invalid_sequence());
+            assert_eq!(Option::None, iter.next());
+        }
+    }
+}
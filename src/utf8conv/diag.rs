@@ -0,0 +1,582 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::diag
+
+use core::fmt;
+
+use crate::utf8conv::bad_decode_kind;
+use crate::utf8conv::find_non_ascii;
+use crate::utf8conv::format_into;
+use crate::utf8conv::sink::FnSink;
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::MoreEnum;
+use crate::utf8conv::Utf8ErrorKind;
+use crate::utf8conv::UtfParserCommon;
+use crate::utf8conv::REPLACE_PART1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// One invalid sequence met by FromUtf8WithDiagnostics
+pub struct Utf8Diagnostic {
+
+    /// byte offset of the sequence from the start of the stream
+    pub offset: u64,
+
+    /// length of the sequence in bytes
+    pub len: u32,
+
+    /// the kind of the sequence
+    pub kind: Utf8ErrorKind,
+}
+
+/// Default for Utf8Diagnostic, an empty entry to fill buffers with
+impl Default for Utf8Diagnostic {
+    fn default() -> Utf8Diagnostic {
+        Utf8Diagnostic {
+            offset: 0,
+            len: 0,
+            kind: Utf8ErrorKind::InvalidLead,
+        }
+    }
+}
+
+/// Display for Utf8Diagnostic
+impl fmt::Display for Utf8Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}, length {}", self.kind, self.offset, self.len)
+    }
+}
+
+/// Implementation of Utf8Diagnostic
+impl Utf8Diagnostic {
+
+    /// Write the Display message into `buf` without allocation, and
+    /// return it.  A message too long for the buffer is cut short.
+    /// # Arguments
+    /// * `buf` - the output buffer
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        format_into(self, buf)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// The outcome of FromUtf8::utf8_to_char_strict() when no char came out
+pub enum DecodeError {
+
+    /// an invalid sequence, skipped by the parser
+    Invalid {
+
+        /// the kind of the sequence
+        kind: Utf8ErrorKind,
+
+        /// length of the sequence in bytes
+        len: u32,
+
+        /// number of input bytes taken by the parser; decoding may go
+        /// on from the input after them
+        read: usize,
+    },
+
+    /// the MoreEnum condition of utf8_to_char(): a request for more
+    /// data, or the end of data with More(0)
+    More(MoreEnum),
+}
+
+/// Display for DecodeError
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Invalid { kind, len, read: _ } => write!(f, "{}, length {}", kind, len),
+            DecodeError::More(MoreEnum::More(0)) => f.write_str("end of data"),
+            DecodeError::More(MoreEnum::More(_amt)) => f.write_str("more data needed"),
+        }
+    }
+}
+
+/// Error for DecodeError
+impl core::error::Error for DecodeError {}
+
+/// Implementation of DecodeError
+impl DecodeError {
+
+    /// Write the Display message into `buf` without allocation, and
+    /// return it.  A message too long for the buffer is cut short.
+    /// # Arguments
+    /// * `buf` - the output buffer
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        format_into(self, buf)
+    }
+}
+
+/// Implementation of FromUtf8 for classified and strict decoding
+impl FromUtf8 {
+
+    /// Decode one char from the scratch pad.  Returns the char, with the
+    /// length and the kind of the sequence when it came from an invalid
+    /// sequence.
+    pub(crate) fn decode_classified(&mut self, last_buffer: bool) -> Option<(char, Option<(u32, Utf8ErrorKind)>)> {
+        let lead = self.my_buf.front().unwrap_or(0);
+        let before = self.my_buf.len();
+        let was_invalid = self.my_invalid_sequence;
+        self.my_invalid_sequence = false;
+        let result = self.decode_buffered(last_buffer);
+        let len = before - self.my_buf.len();
+        let invalid = self.my_invalid_sequence;
+        self.my_invalid_sequence |= was_invalid;
+        let ch = result?;
+        if ! invalid {
+            return Option::Some((ch, Option::None));
+        }
+        let kind = if ch != char::REPLACEMENT_CHARACTER {
+            Utf8ErrorKind::Overlong
+        }
+        else if last_buffer && self.my_buf.is_empty()
+        && (0xC2u8 ..= 0xF4u8).contains(& lead) && ! ((lead == REPLACE_PART1) && (len == 3)) {
+            // A failed sequence that used up the last buffer was
+            // cut short by the end of data.
+            Utf8ErrorKind::Truncated
+        }
+        else {
+            bad_decode_kind(lead, len)
+        };
+        Option::Some((ch, Option::Some((len, kind))))
+    }
+
+    /// A parser takes in byte slice, and returns a Result object with
+    /// either the remaining input and the output char value, or a
+    /// DecodeError.  Unlike utf8_to_char(), an invalid sequence is
+    /// reported as DecodeError::Invalid instead of a replacement
+    /// character, for callers that reject malformed input.
+    ///
+    /// Every sequence that utf8_to_char() counts as invalid is an error,
+    /// including an encoded replacement character, and overlong
+    /// sequences in the overlong tolerant mode.  has_invalid_sequence()
+    /// returns true after an error.  The MoreEnum conditions are
+    /// returned as DecodeError::More.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUtf8::new();
+    /// let (rest, ch) = parser.utf8_to_char_strict(b"a\xC0b").unwrap();
+    /// assert_eq!('a', ch);
+    /// let error = parser.utf8_to_char_strict(rest).unwrap_err();
+    /// assert!(matches!(error, DecodeError::Invalid { kind: Utf8ErrorKind::InvalidLead, len: 1, .. }));
+    /// assert_eq!(Ok((rest, 'b')), parser.utf8_to_char_strict(rest));
+    /// assert_eq!(Err(DecodeError::More(MoreEnum::More(0))), parser.utf8_to_char_strict(rest));
+    /// ```
+    /// # Arguments
+    /// * `input` - UTF8 input slice
+    pub fn utf8_to_char_strict<'b>(&mut self, input: &'b [u8])
+    -> Result<(&'b [u8], char), DecodeError> {
+        let last_buffer = self.my_last_buffer;
+        let mut my_cursor = input;
+        loop {
+            my_cursor = self.fill_buffer(my_cursor);
+            match self.decode_classified(last_buffer) {
+                Option::Some((ch, Option::None)) => {
+                    return Result::Ok((my_cursor, ch));
+                }
+                Option::Some((_ch, Option::Some((len, kind)))) => {
+                    let read = input.len() - my_cursor.len();
+                    return Result::Err(DecodeError::Invalid { kind, len, read });
+                }
+                Option::None if self.take_refill() => {
+                    // Chars were dropped by the char map; refill.
+                }
+                Option::None => {
+                    return Result::Err(DecodeError::More(self.more_indication(last_buffer)));
+                }
+            }
+        }
+    }
+}
+
+/// A destination for diagnostics, implemented for slice cursors of
+/// Utf8Diagnostic and closures wrapped in FnSink.
+pub trait DiagnosticSink {
+
+    /// Take one diagnostic.  Returns false if it was not taken.
+    fn put_diagnostic(&mut self, diag: Utf8Diagnostic) -> bool;
+}
+
+/// DiagnosticSink for Utf8Diagnostic slice cursors
+impl DiagnosticSink for &mut [Utf8Diagnostic] {
+    #[inline]
+    fn put_diagnostic(&mut self, diag: Utf8Diagnostic) -> bool {
+        match core::mem::take(self).split_first_mut() {
+            Option::Some((slot, rest)) => {
+                * slot = diag;
+                * self = rest;
+                true
+            }
+            Option::None => false,
+        }
+    }
+}
+
+/// DiagnosticSink for closures taking diagnostics
+impl<F: FnMut(Utf8Diagnostic)> DiagnosticSink for FnSink<F> {
+    #[inline]
+    fn put_diagnostic(&mut self, diag: Utf8Diagnostic) -> bool {
+        (self.0)(diag);
+        true
+    }
+}
+
+/// A FromUtf8 parser that also reports every invalid sequence, with its
+/// byte offset, length and kind, to a diagnostics sink, while the output
+/// stays the same lossy char stream.
+///
+/// Encoded replacement characters are reported with their own kind.  In
+/// the overlong tolerant mode, decoded overlong sequences are reported as
+/// Utf8ErrorKind::Overlong.  When the sink is full, further diagnostics
+/// are counted but dropped.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut entries = [Utf8Diagnostic::default(); 4];
+/// let mut parser = FromUtf8WithDiagnostics::new(& mut entries[..]);
+/// let mut char_box = ['\0'; 8];
+/// let (_rest, num_chars) = parser.utf8_to_char_slice(b"ab\xFFc\xE2\x82", & mut char_box).unwrap();
+/// assert_eq!(['a', 'b', '\u{FFFD}', 'c', '\u{FFFD}'], char_box[.. num_chars]);
+/// assert_eq!(2, parser.num_reported());
+/// assert_eq!(Utf8Diagnostic { offset: 2, len: 1, kind: Utf8ErrorKind::InvalidLead }, entries[0]);
+/// assert_eq!(Utf8Diagnostic { offset: 4, len: 2, kind: Utf8ErrorKind::Truncated }, entries[1]);
+/// ```
+#[derive(Debug)]
+pub struct FromUtf8WithDiagnostics<S> {
+
+    /// the underlying parser
+    my_parser: FromUtf8,
+
+    /// the diagnostics destination
+    my_sink: S,
+
+    /// number of bytes taken from input slices
+    my_fed: u64,
+
+    /// number of diagnostics met
+    my_reported: u64,
+
+    /// number of diagnostics the sink did not take
+    my_dropped: u64,
+}
+
+/// Implementation of FromUtf8WithDiagnostics
+impl<S: DiagnosticSink> FromUtf8WithDiagnostics<S> {
+
+    /// Make a new FromUtf8WithDiagnostics reporting into `sink`.
+    pub fn new(sink: S) -> FromUtf8WithDiagnostics<S> {
+        FromUtf8WithDiagnostics {
+            my_parser: FromUtf8::new(),
+            my_sink: sink,
+            my_fed: 0,
+            my_reported: 0,
+            my_dropped: 0,
+        }
+    }
+
+    /// Turn the overlong tolerant mode of the parser on or off.
+    /// # Arguments
+    /// * `b` - true to decode overlong sequences
+    pub fn set_overlong_tolerant(&mut self, b: bool) {
+        self.my_parser.set_overlong_tolerant(b);
+    }
+
+    #[inline]
+    /// Byte offset of the next char to be decoded
+    pub fn position(&self) -> u64 {
+        self.my_fed - self.my_parser.my_buf.len() as u64
+    }
+
+    #[inline]
+    /// Number of invalid sequences met, including dropped diagnostics
+    pub fn num_reported(&self) -> u64 {
+        self.my_reported
+    }
+
+    #[inline]
+    /// Number of diagnostics that the sink did not take
+    pub fn num_dropped(&self) -> u64 {
+        self.my_dropped
+    }
+
+    #[inline]
+    /// Get a reference to the diagnostics sink
+    pub fn sink(&self) -> &S {
+        & self.my_sink
+    }
+
+    /// Unwrap this FromUtf8WithDiagnostics, returning the sink.
+    pub fn into_sink(self) -> S {
+        self.my_sink
+    }
+
+    /// Move bytes from `input` into the scratch pad, counting them.
+    fn fill<'b>(&mut self, input: &'b [u8]) -> &'b [u8] {
+        let rest = self.my_parser.fill_buffer(input);
+        self.my_fed += (input.len() - rest.len()) as u64;
+        rest
+    }
+
+    /// Decode one char from the scratch pad, and report it when it came
+    /// from an invalid sequence.
+    fn decode_next(&mut self, last_buffer: bool) -> Option<char> {
+        let offset = self.position();
+        let (ch, invalid) = self.my_parser.decode_classified(last_buffer)?;
+        if let Option::Some((len, kind)) = invalid {
+            self.my_reported += 1;
+            if ! self.my_sink.put_diagnostic(Utf8Diagnostic { offset, len, kind }) {
+                self.my_dropped += 1;
+            }
+        }
+        Option::Some(ch)
+    }
+
+    /// A parser takes in byte slice, and returns a Result object with
+    /// either the remaining input and the output char value, or an MoreEnum
+    /// that requests additional data, or an end of data stream condition.
+    ///
+    /// Same as FromUtf8::utf8_to_char(); in addition, each invalid
+    /// sequence is reported to the sink.
+    pub fn utf8_to_char<'b>(&mut self, input: &'b [u8])
+    -> Result<(&'b [u8], char), MoreEnum> {
+        let last_buffer = self.my_parser.my_last_buffer;
+        let rest = self.fill(input);
+        match self.decode_next(last_buffer) {
+            Option::Some(ch) => Result::Ok((rest, ch)),
+            Option::None => Result::Err(self.my_parser.more_indication(last_buffer)),
+        }
+    }
+
+    /// A bulk parser takes in byte slice, and fills the `output` slice
+    /// with decoded char values.  Returns a Result object with either
+    /// the remaining input and the number of chars written, or an MoreEnum
+    /// that requests additional data, or an end of data stream condition.
+    ///
+    /// Same as FromUtf8::utf8_to_char_slice(); in addition, each invalid
+    /// sequence is reported to the sink.
+    pub fn utf8_to_char_slice<'b>(&mut self, input: &'b [u8], output: &mut [char])
+    -> Result<(&'b [u8], usize), MoreEnum> {
+        let last_buffer = self.my_parser.my_last_buffer;
+        let mut my_cursor: &[u8] = input;
+        let mut written: usize = 0;
+        while let Option::Some(out_rest) = output.get_mut(written ..) {
+            if out_rest.is_empty() {
+                break;
+            }
+            if self.my_parser.my_buf.is_empty() {
+                // A run of ASCII bytes bypasses the scratch pad.
+                let window = my_cursor.get(.. out_rest.len()).unwrap_or(my_cursor);
+                let run = find_non_ascii(window).unwrap_or(window.len());
+                if run > 0 {
+                    for (slot, v) in out_rest.iter_mut().zip(window.iter().take(run)) {
+                        * slot = * v as char;
+                    }
+                    written += run;
+                    self.my_fed += run as u64;
+                    my_cursor = my_cursor.get(run ..).unwrap_or(&[]);
+                    continue;
+                }
+            }
+            my_cursor = self.fill(my_cursor);
+            match self.decode_next(last_buffer) {
+                Option::Some(ch) => {
+                    if let Option::Some(slot) = out_rest.first_mut() {
+                        * slot = ch;
+                    }
+                    written += 1;
+                }
+                Option::None => {
+                    break;
+                }
+            }
+        }
+        if (written == 0) && ! output.is_empty() {
+            Result::Err(self.my_parser.more_indication(last_buffer))
+        }
+        else {
+            Result::Ok((my_cursor, written))
+        }
+    }
+}
+
+/// Implementations of common operations for FromUtf8WithDiagnostics
+impl<S: DiagnosticSink> UtfParserCommon for FromUtf8WithDiagnostics<S> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_parser.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_parser.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_parser.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid UTF8 sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_parser.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid decodes state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_parser.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    /// The stream position and the counts are cleared; the sink is kept.
+    fn reset_parser(&mut self) {
+        self.my_parser.reset_parser();
+        self.my_fed = 0;
+        self.my_reported = 0;
+        self.my_dropped = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::vec::Vec;
+
+    #[test]
+    // Test streamed diagnostics against repair plans at every buffer split
+    fn test_diagnostics_match_repair_plan() {
+        let bytes = [
+            0x41u8, 0xC2u8, 0x41u8, 0xE0u8, 0x80u8, 0xAFu8, 0xEDu8, 0xA0u8, 0x80u8, 0xEFu8,
+            0xBFu8, 0xBDu8, 0xF4u8, 0x90u8, 0xFFu8, 0x42u8, 0x43u8, 0x44u8, 0x45u8, 0x46u8,
+            0x47u8, 0x48u8, 0x49u8, 0xF0u8, 0x9Fu8, 0x98u8,
+        ];
+        let mut edits = [RepairEdit::default(); 16];
+        let summary = plan_repairs(& bytes, & mut edits);
+        let truth = std::string::String::from_utf8_lossy(& bytes);
+        for split in 0 ..= bytes.len() {
+            let mut found = Vec::new();
+            let mut parser = FromUtf8WithDiagnostics::new(FnSink(|diag| found.push(diag)));
+            let mut text = std::string::String::new();
+            let buffers = [& bytes[.. split], & bytes[split ..]];
+            for (indx, buf) in buffers.iter().enumerate() {
+                parser.set_is_last_buffer(indx == 1);
+                let mut cur_slice = * buf;
+                let mut char_box = ['\0'; 3];
+                // The loop stops at MoreEnum::More(_amt);
+                // _amt equals to 0 when end of data
+                while let Result::Ok((slice_pos, num_chars)) = parser.utf8_to_char_slice(cur_slice, & mut char_box) {
+                    cur_slice = slice_pos;
+                    text.extend(char_box[.. num_chars].iter());
+                }
+            }
+            assert_eq!(truth, text);
+            assert_eq!(bytes.len() as u64, parser.position());
+            assert!(parser.has_invalid_sequence());
+            let planned: Vec<Utf8Diagnostic> = edits[.. summary.num_written].iter()
+                .map(|e| Utf8Diagnostic { offset: e.start as u64, len: e.len as u32, kind: e.kind })
+                .collect();
+            let streamed: Vec<Utf8Diagnostic> = found.iter().copied()
+                .filter(|d| d.kind != Utf8ErrorKind::ReplacementCharacter).collect();
+            assert_eq!(planned, streamed);
+            assert_eq!(found.len(), streamed.len() + 1);
+        }
+    }
+
+    #[test]
+    // Test a full diagnostics buffer, overlong reports and messages
+    fn test_diagnostics_full_and_overlong() {
+        let mut entries = [Utf8Diagnostic::default(); 1];
+        let mut parser = FromUtf8WithDiagnostics::new(& mut entries[..]);
+        parser.set_overlong_tolerant(true);
+        let mut cur_slice = & b"\xC0\xAFx\x80\x80"[..];
+        let mut text = std::string::String::new();
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, ch)) = parser.utf8_to_char(cur_slice) {
+            cur_slice = slice_pos;
+            text.push(ch);
+        }
+        assert_eq!("/x\u{FFFD}\u{FFFD}", text);
+        assert_eq!((3, 2), (parser.num_reported(), parser.num_dropped()));
+        assert!(parser.sink().is_empty());
+        parser.reset_parser();
+        assert_eq!((0, 0, 0), (parser.position(), parser.num_reported(), parser.num_dropped()));
+        assert_eq!(Utf8Diagnostic { offset: 0, len: 2, kind: Utf8ErrorKind::Overlong }, entries[0]);
+        let mut buf = [0u8; 64];
+        assert_eq!("overlong UTF8 sequence at byte 0, length 2", entries[0].format_into(& mut buf));
+    }
+
+    #[test]
+    // Test strict errors against diagnostics at every buffer split
+    fn test_utf8_to_char_strict() {
+        let bytes = b"A\xC2A\xE0\x80\xAF\xED\xA0\x80\xEF\xBF\xBD\xF4\x90\xFFBCDEFGHI\xF0\x9F\x98";
+        let truth = std::string::String::from_utf8_lossy(bytes);
+        let mut found = Vec::new();
+        {
+            let mut parser = FromUtf8WithDiagnostics::new(FnSink(|diag: Utf8Diagnostic| found.push((diag.kind, diag.len))));
+            let mut cur_slice = & bytes[..];
+            // The loop stops at MoreEnum::More(0), end of data
+            while let Result::Ok((slice_pos, _ch)) = parser.utf8_to_char(cur_slice) {
+                cur_slice = slice_pos;
+            }
+        }
+        for split in 0 ..= bytes.len() {
+            let mut parser = FromUtf8::new();
+            let mut text = std::string::String::new();
+            let mut errors = Vec::new();
+            let buffers = [& bytes[.. split], & bytes[split ..]];
+            for (indx, buf) in buffers.iter().enumerate() {
+                parser.set_is_last_buffer(indx == 1);
+                let mut cur_slice = * buf;
+                loop {
+                    match parser.utf8_to_char_strict(cur_slice) {
+                        Result::Ok((slice_pos, ch)) => {
+                            cur_slice = slice_pos;
+                            text.push(ch);
+                        }
+                        Result::Err(DecodeError::Invalid { kind, len, read }) => {
+                            cur_slice = & cur_slice[read ..];
+                            text.push(char::REPLACEMENT_CHARACTER);
+                            errors.push((kind, len));
+                        }
+                        Result::Err(DecodeError::More(more)) => {
+                            assert_eq!(indx == 1, more == MoreEnum::More(0));
+                            break;
+                        }
+                    }
+                }
+            }
+            assert_eq!(truth, text);
+            assert_eq!(found, errors);
+            assert!(parser.has_invalid_sequence());
+        }
+        let mut buf = [0u8; 64];
+        let error = DecodeError::Invalid { kind: Utf8ErrorKind::Truncated, len: 3, read: 0 };
+        assert_eq!("truncated UTF8 sequence, length 3", error.format_into(& mut buf));
+        assert_eq!("end of data", DecodeError::More(MoreEnum::More(0)).format_into(& mut buf));
+    }
+}
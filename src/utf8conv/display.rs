@@ -0,0 +1,116 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::display
+
+use core::cell::Cell;
+use core::fmt;
+
+use crate::utf8conv::encode;
+
+/// size of the UTF8 block handed to the formatter at a time
+const DISPLAY_BUF_SIZE: usize = 256;
+
+/// A wrapper that displays the chars of an iterator, so conversion
+/// results can be printed or written with write!() without collecting
+/// them first.
+///
+/// The chars are encoded into UTF8 blocks on the stack and written to
+/// the formatter a block at a time.  The iterator is drained by the
+/// first formatting; formatting again writes nothing.  Width and fill
+/// options of the formatter are ignored.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let text = format!("[{}]", DisplayChars::new(decode_lossy(b"a\xE2\x82\xAC\xFF")));
+/// assert_eq!("[a\u{20AC}\u{FFFD}]", text);
+/// ```
+pub struct DisplayChars<I> {
+
+    /// the chars to display, taken by the first formatting
+    my_iter: Cell<Option<I>>,
+}
+
+/// Implementation of DisplayChars
+impl<I: Iterator<Item = char>> DisplayChars<I> {
+
+    /// Make a new DisplayChars displaying the chars of `input`.
+    /// # Arguments
+    /// * `input` - the chars to be displayed
+    pub fn new<T>(input: T) -> DisplayChars<I>
+    where T: IntoIterator<Item = char, IntoIter = I>, {
+        DisplayChars {
+            my_iter: Cell::new(Option::Some(input.into_iter())),
+        }
+    }
+}
+
+/// Display for DisplayChars
+impl<I: Iterator<Item = char>> fmt::Display for DisplayChars<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let iter = match self.my_iter.take() {
+            Option::Some(iter) => iter,
+            Option::None => {
+                return Result::Ok(());
+            }
+        };
+        let mut out = [0u8; DISPLAY_BUF_SIZE];
+        let mut len:usize = 0;
+        for b in encode(iter) {
+            // Blocks end on char boundaries: a lead byte starts a new
+            // block when a 4 byte sequence might not fit.
+            if ((b & 0xC0) != 0x80) && (len + 4 > DISPLAY_BUF_SIZE) {
+                f.write_str(core::str::from_utf8(& out[.. len]).map_err(|_| fmt::Error)?)?;
+                len = 0;
+            }
+            out[len] = b;
+            len += 1;
+        }
+        f.write_str(core::str::from_utf8(& out[.. len]).map_err(|_| fmt::Error)?)
+    }
+}
+
+/// Debug for DisplayChars
+impl<I> fmt::Debug for DisplayChars<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DisplayChars")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::fmt::Write;
+    use std::string::String;
+
+    use crate::*;
+
+    #[test]
+    // Test displaying chars across many blocks, and displaying twice
+    fn test_display_chars() {
+        let mut truth = String::new();
+        for indx in 0 .. 400u32 {
+            truth.push(['a', '\u{7FF}', '\u{20AC}', '\u{1F600}'][(indx % 4) as usize]);
+        }
+        for skip in 0 .. 4 {
+            let expected: String = truth.chars().skip(skip).collect();
+            let display = DisplayChars::new(truth.chars().skip(skip));
+            let mut text = String::new();
+            write!(text, "{}", display).unwrap();
+            assert_eq!(expected, text);
+            write!(text, "{}", display).unwrap();
+            assert_eq!(expected, text);
+        }
+        let mut chars = "&#x41;&#66;".chars();
+        let display = DisplayChars::new(html_char_ref_iter(& mut chars));
+        assert_eq!("AB", std::format!("{}", display));
+        assert_eq!("", std::format!("{}", DisplayChars::new("".chars())));
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::ebcdic
+
+use crate::utf8conv::Decoder;
+use crate::utf8conv::MoreEnum;
+use crate::utf8conv::UtfParserCommon;
+
+/// Latin1 codepoint of every EBCDIC code page 037 byte
+const CP037_TO_LATIN1: [u8; 256] = [
+    0x00, 0x01, 0x02, 0x03, 0x9C, 0x09, 0x86, 0x7F, 0x97, 0x8D, 0x8E, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+    0x10, 0x11, 0x12, 0x13, 0x9D, 0x85, 0x08, 0x87, 0x18, 0x19, 0x92, 0x8F, 0x1C, 0x1D, 0x1E, 0x1F,
+    0x80, 0x81, 0x82, 0x83, 0x84, 0x0A, 0x17, 0x1B, 0x88, 0x89, 0x8A, 0x8B, 0x8C, 0x05, 0x06, 0x07,
+    0x90, 0x91, 0x16, 0x93, 0x94, 0x95, 0x96, 0x04, 0x98, 0x99, 0x9A, 0x9B, 0x14, 0x15, 0x9E, 0x1A,
+    0x20, 0xA0, 0xE2, 0xE4, 0xE0, 0xE1, 0xE3, 0xE5, 0xE7, 0xF1, 0xA2, 0x2E, 0x3C, 0x28, 0x2B, 0x7C,
+    0x26, 0xE9, 0xEA, 0xEB, 0xE8, 0xED, 0xEE, 0xEF, 0xEC, 0xDF, 0x21, 0x24, 0x2A, 0x29, 0x3B, 0xAC,
+    0x2D, 0x2F, 0xC2, 0xC4, 0xC0, 0xC1, 0xC3, 0xC5, 0xC7, 0xD1, 0xA6, 0x2C, 0x25, 0x5F, 0x3E, 0x3F,
+    0xF8, 0xC9, 0xCA, 0xCB, 0xC8, 0xCD, 0xCE, 0xCF, 0xCC, 0x60, 0x3A, 0x23, 0x40, 0x27, 0x3D, 0x22,
+    0xD8, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0xAB, 0xBB, 0xF0, 0xFD, 0xFE, 0xB1,
+    0xB0, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0xAA, 0xBA, 0xE6, 0xB8, 0xC6, 0xA4,
+    0xB5, 0x7E, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0xA1, 0xBF, 0xD0, 0xDD, 0xDE, 0xAE,
+    0x5E, 0xA3, 0xA5, 0xB7, 0xA9, 0xA7, 0xB6, 0xBC, 0xBD, 0xBE, 0x5B, 0x5D, 0xAF, 0xA8, 0xB4, 0xD7,
+    0x7B, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0xAD, 0xF4, 0xF6, 0xF2, 0xF3, 0xF5,
+    0x7D, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0xB9, 0xFB, 0xFC, 0xF9, 0xFA, 0xFF,
+    0x5C, 0xF7, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0xB2, 0xD4, 0xD6, 0xD2, 0xD3, 0xD5,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0xB3, 0xDB, 0xDC, 0xD9, 0xDA, 0x9F,
+];
+
+/// Convert an EBCDIC code page 037 byte to a char.
+///
+/// Every byte maps to a distinct char in the range 0 to 0xFF.
+/// # Arguments
+/// * `b` - the EBCDIC byte
+#[inline]
+pub const fn cp037_to_char(b: u8) -> char {
+    CP037_TO_LATIN1[b as usize] as char
+}
+
+/// Provides conversion functions from EBCDIC code page 037 to char,
+/// with the same streaming interface as FromUtf8.
+///
+/// Each byte is one char, so no data is kept between buffers, and there
+/// are no invalid sequences.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut parser = FromCp037::new();
+/// let mut char_box = ['\0'; 8];
+/// let (_rest, num_chars) = parser.cp037_to_char_slice(& [0xC8u8, 0x85u8, 0x93u8, 0x93u8, 0x96u8, 0x5Au8], & mut char_box).unwrap();
+/// assert_eq!(['H', 'e', 'l', 'l', 'o', '!'], char_box[.. num_chars]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FromCp037 {
+    my_last_buffer: bool,
+    my_invalid_sequence: bool,
+}
+
+/// Implementation of FromCp037
+impl FromCp037 {
+
+    /// Make a new FromCp037
+    pub fn new() -> FromCp037 {
+        FromCp037 {
+            my_last_buffer: true,
+            my_invalid_sequence: false,
+        }
+    }
+
+    #[inline]
+    /// Returns the MoreEnum to report when the input is empty.
+    fn more_indication(&self) -> MoreEnum {
+        if self.my_last_buffer {
+            // at end of data condition
+            MoreEnum::More(0)
+        }
+        else {
+            // Returning an indication to request a new buffer.
+            MoreEnum::More(4096)
+        }
+    }
+
+    /// A parser takes in byte slice, and returns a Result object with
+    /// either the remaining input and the output char value, or an MoreEnum
+    /// that requests additional data, or an end of data stream condition.
+    pub fn cp037_to_char<'b>(&mut self, input: &'b [u8])
+    -> Result<(&'b [u8], char), MoreEnum> {
+        match input.split_first() {
+            Option::Some((b, rest)) => Result::Ok((rest, cp037_to_char(* b))),
+            Option::None => Result::Err(self.more_indication()),
+        }
+    }
+
+    /// A bulk parser takes in byte slice, and fills the `output` slice
+    /// with decoded char values.  Returns a Result object with either
+    /// the remaining input and the number of chars written, or an MoreEnum
+    /// that requests additional data, or an end of data stream condition.
+    pub fn cp037_to_char_slice<'b>(&mut self, input: &'b [u8], output: &mut [char])
+    -> Result<(&'b [u8], usize), MoreEnum> {
+        if input.is_empty() && ! output.is_empty() {
+            return Result::Err(self.more_indication());
+        }
+        let len = input.len().min(output.len());
+        for (slot, b) in output.iter_mut().zip(input.iter()) {
+            * slot = cp037_to_char(* b);
+        }
+        Result::Ok((input.get(len ..).unwrap_or(&[]), len))
+    }
+}
+
+/// Default for FromCp037 is the same as FromCp037::new()
+impl Default for FromCp037 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implementations of common operations for FromCp037
+impl UtfParserCommon for FromCp037 {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_last_buffer = b;
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_last_buffer
+    }
+
+    #[inline]
+    /// This function returns true if signal_invalid_sequence() was
+    /// called; EBCDIC input itself has no invalid sequences.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_invalid_sequence
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_invalid_sequence = true;
+    }
+
+    #[inline]
+    /// This function resets the invalid sequence state.
+    fn reset_invalid_sequence(&mut self) {
+        self.my_invalid_sequence = false;
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid sequence indication is cleared.
+    fn reset_parser(&mut self) {
+        * self = FromCp037::new();
+    }
+}
+
+/// Decoder implementation of FromCp037
+impl Decoder for FromCp037 {
+    type Unit = u8;
+
+    #[inline]
+    /// Same as cp037_to_char()
+    fn decode_char<'b>(&mut self, input: &'b [u8])
+    -> Result<(&'b [u8], char), MoreEnum> {
+        self.cp037_to_char(input)
+    }
+
+    #[inline]
+    /// Same as cp037_to_char_slice()
+    fn decode_to_slice<'b>(&mut self, input: &'b [u8], output: &mut [char])
+    -> Result<(&'b [u8], usize), MoreEnum> {
+        self.cp037_to_char_slice(input, output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::vec::Vec;
+
+    #[test]
+    // Test the code page table, and decoding over buffer splits
+    fn test_cp037_decoder() {
+        let mut seen = [false; 256];
+        for b in 0 ..= 255u8 {
+            let ch = cp037_to_char(b);
+            assert!(! seen[ch as usize]);
+            seen[ch as usize] = true;
+        }
+        // "Sum: [1+2] = \u{A3}3" in code page 037
+        let bytes = [
+            0xE2u8, 0xA4u8, 0x94u8, 0x7Au8, 0x40u8, 0xBAu8, 0xF1u8, 0x4Eu8, 0xF2u8, 0xBBu8,
+            0x40u8, 0x7Eu8, 0x40u8, 0xB1u8, 0xF3u8,
+        ];
+        let truth: Vec<char> = "Sum: [1+2] = \u{A3}3".chars().collect();
+        for split in 0 ..= bytes.len() {
+            let mut parser = FromCp037::new();
+            let decoder: & mut dyn Decoder<Unit = u8> = & mut parser;
+            let mut chars = Vec::new();
+            let (head, tail) = bytes.split_at(split);
+            for (indx, buf) in [head, tail].iter().enumerate() {
+                decoder.set_is_last_buffer(indx == 1);
+                let mut cur_slice = * buf;
+                let mut char_box = ['\0'; 4];
+                // The loop stops at MoreEnum::More(_amt);
+                // _amt equals to 0 when end of data
+                while let Result::Ok((slice_pos, num_chars)) = decoder.decode_to_slice(cur_slice, & mut char_box) {
+                    cur_slice = slice_pos;
+                    chars.extend_from_slice(& char_box[.. num_chars]);
+                }
+                let amt = if indx == 1 { 0 } else { 4096 };
+                assert_eq!(Result::Err(MoreEnum::More(amt)), decoder.decode_char(cur_slice));
+            }
+            assert_eq!(truth, chars);
+            assert!(! decoder.has_invalid_sequence());
+        }
+    }
+}
@@ -0,0 +1,323 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::embedded
+
+use embedded_io::Error;
+use embedded_io::ErrorKind;
+use embedded_io::Read;
+use embedded_io::Write;
+
+use crate::utf8conv::encode;
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::MoreEnum;
+use crate::utf8conv::UtfParserCommon;
+
+/// size of the input buffer of the reader, kept small for firmware
+const EMBEDDED_READ_BUF_SIZE: usize = 64;
+
+/// size of the output buffer of the writer, kept small for firmware
+const EMBEDDED_WRITE_BUF_SIZE: usize = 64;
+
+/// A char reader that decodes UTF8 bytes from an embedded_io::Read
+/// source, such as a UART driver, without the standard library; the
+/// blocking form of AsyncUtf8Reader.
+///
+/// Sequences split across reads are joined by the parser.  Invalid UTF8
+/// sequences become replacement characters.  Read errors are returned
+/// to the caller; the decoding state is kept, so reading can go on
+/// after an error.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut chars = EmbeddedUtf8Reader::new(& b"a\xE2\x82\xAC\n"[..]);
+/// let mut lines = 0;
+/// while let Some(ch) = chars.next_char().unwrap() {
+///     if ch == '\n' {
+///         lines += 1;
+///     }
+/// }
+/// assert_eq!(1, lines);
+/// ```
+pub struct EmbeddedUtf8Reader<R> {
+
+    /// the inner reader
+    my_inner: R,
+
+    /// the UTF8 parser
+    my_parser: FromUtf8,
+
+    /// bytes read from the inner reader
+    my_in: [u8; EMBEDDED_READ_BUF_SIZE],
+
+    /// start of the unparsed bytes in my_in
+    my_in_start: usize,
+
+    /// end of the unparsed bytes in my_in
+    my_in_end: usize,
+}
+
+/// Implementation of EmbeddedUtf8Reader
+impl<R: Read> EmbeddedUtf8Reader<R> {
+
+    /// Make a new EmbeddedUtf8Reader reading UTF8 from `inner`.
+    pub fn new(inner: R) -> EmbeddedUtf8Reader<R> {
+        let mut parser = FromUtf8::new();
+        parser.set_is_last_buffer(false);
+        EmbeddedUtf8Reader {
+            my_inner: inner,
+            my_parser: parser,
+            my_in: [0; EMBEDDED_READ_BUF_SIZE],
+            my_in_start: 0,
+            my_in_end: 0,
+        }
+    }
+
+    /// Read the next decoded char; 'None' at end of data, which is a
+    /// read of 0 bytes from the inner reader.
+    pub fn next_char(&mut self) -> Result<Option<char>, R::Error> {
+        let mut char_box = ['\0'; 1];
+        match self.read_chars(& mut char_box)? {
+            0 => Result::Ok(Option::None),
+            _ => Result::Ok(Option::Some(char_box[0])),
+        }
+    }
+
+    /// Read decoded chars into `output`, and return the number of chars
+    /// written.  Reads the inner reader only when no char can be
+    /// decoded from the buffered bytes.  Returns 0 at end of data, or
+    /// when `output` is empty.
+    /// # Arguments
+    /// * `output` - the output char buffer
+    pub fn read_chars(&mut self, output: &mut [char]) -> Result<usize, R::Error> {
+        if output.is_empty() {
+            return Result::Ok(0);
+        }
+        loop {
+            let input = self.my_in.get(self.my_in_start .. self.my_in_end).unwrap_or(&[]);
+            match self.my_parser.utf8_to_char_slice(input, output) {
+                Result::Ok((rest, num_chars)) => {
+                    self.my_in_start = self.my_in_end - rest.len();
+                    return Result::Ok(num_chars);
+                }
+                Result::Err(MoreEnum::More(0)) => {
+                    // end of data
+                    return Result::Ok(0);
+                }
+                Result::Err(MoreEnum::More(_amt)) => {
+                    self.my_in_start = 0;
+                    self.my_in_end = 0;
+                    match self.my_inner.read(& mut self.my_in) {
+                        Result::Ok(0) => {
+                            self.my_parser.set_is_last_buffer(true);
+                        }
+                        Result::Ok(len) => {
+                            self.my_in_end = len;
+                        }
+                        Result::Err(er) if er.kind() == ErrorKind::Interrupted => {}
+                        Result::Err(er) => {
+                            return Result::Err(er);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this stream.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_parser.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// Get a reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        & self.my_inner
+    }
+
+    #[inline]
+    /// Get a mutable reference to the inner reader
+    pub fn get_mut(&mut self) -> &mut R {
+        & mut self.my_inner
+    }
+
+    /// Unwrap this EmbeddedUtf8Reader, returning the inner reader.
+    /// Bytes buffered but not yet decoded are lost.
+    pub fn into_inner(self) -> R {
+        self.my_inner
+    }
+}
+
+/// A writer of chars and strings, encoding them as UTF8 into an
+/// embedded_io::Write target without the standard library.
+///
+/// Each call writes whole chars with write_all(), so short writes of
+/// the inner writer are retried.  Chars are encoded in small blocks;
+/// nothing is buffered between calls.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut byte_box = [0u8; 8];
+/// let mut writer = EmbeddedUtf8Writer::new(& mut byte_box[..]);
+/// writer.write_str("T=").unwrap();
+/// writer.write_chars("21\u{B0}".chars()).unwrap();
+/// assert_eq!(2, writer.into_inner().len());
+/// assert_eq!("T=21\u{B0}".as_bytes(), & byte_box[.. 6]);
+/// ```
+pub struct EmbeddedUtf8Writer<W> {
+
+    /// the inner writer
+    my_inner: W,
+}
+
+/// Implementation of EmbeddedUtf8Writer
+impl<W: Write> EmbeddedUtf8Writer<W> {
+
+    /// Make a new EmbeddedUtf8Writer writing UTF8 into `inner`.
+    pub fn new(inner: W) -> EmbeddedUtf8Writer<W> {
+        EmbeddedUtf8Writer { my_inner: inner }
+    }
+
+    /// Write the UTF8 encoding of `ch`.
+    /// # Arguments
+    /// * `ch` - the char to be written
+    pub fn write_char(&mut self, ch: char) -> Result<(), W::Error> {
+        let mut out = [0u8; 4];
+        self.my_inner.write_all(ch.encode_utf8(& mut out).as_bytes())
+    }
+
+    /// Write the UTF8 encoding of `text`.
+    /// # Arguments
+    /// * `text` - the text to be written
+    pub fn write_str(&mut self, text: &str) -> Result<(), W::Error> {
+        self.my_inner.write_all(text.as_bytes())
+    }
+
+    /// Write the UTF8 encoding of the chars of `input`.
+    /// # Arguments
+    /// * `input` - the chars to be written
+    pub fn write_chars<I>(&mut self, input: I) -> Result<(), W::Error>
+    where I: IntoIterator<Item = char>, {
+        let mut out = [0u8; EMBEDDED_WRITE_BUF_SIZE];
+        let mut len:usize = 0;
+        for b in encode(input) {
+            out[len] = b;
+            len += 1;
+            if len == EMBEDDED_WRITE_BUF_SIZE {
+                self.my_inner.write_all(& out)?;
+                len = 0;
+            }
+        }
+        self.my_inner.write_all(& out[.. len])
+    }
+
+    #[inline]
+    /// Flush the inner writer.
+    pub fn flush(&mut self) -> Result<(), W::Error> {
+        self.my_inner.flush()
+    }
+
+    #[inline]
+    /// Get a reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        & self.my_inner
+    }
+
+    #[inline]
+    /// Get a mutable reference to the inner writer
+    pub fn get_mut(&mut self) -> &mut W {
+        & mut self.my_inner
+    }
+
+    /// Unwrap this EmbeddedUtf8Writer, returning the inner writer.
+    pub fn into_inner(self) -> W {
+        self.my_inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::string::String;
+
+    /// A reader that hands out at most `my_step` bytes per read, and
+    /// fails every other read with an interrupted error.
+    struct TrickleReader<'a> {
+        my_bytes: &'a [u8],
+        my_step: usize,
+        my_ready: bool,
+    }
+
+    impl embedded_io::ErrorType for TrickleReader<'_> {
+        type Error = embedded_io::ErrorKind;
+    }
+
+    impl embedded_io::Read for TrickleReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            self.my_ready = ! self.my_ready;
+            if ! self.my_ready {
+                return Result::Err(embedded_io::ErrorKind::Interrupted);
+            }
+            let len = self.my_step.min(buf.len()).min(self.my_bytes.len());
+            buf[.. len].copy_from_slice(& self.my_bytes[.. len]);
+            self.my_bytes = & self.my_bytes[len ..];
+            Result::Ok(len)
+        }
+    }
+
+    #[test]
+    // Test decoding bytes trickling in at every read size
+    fn test_embedded_reader() {
+        let bytes = b"a\xE2\x82\xACb\xF0\x9F\x98\x80\xFFc\xE2\x82";
+        let truth = "a\u{20AC}b\u{1F600}\u{FFFD}c\u{FFFD}";
+        for step in 1 .. bytes.len() + 1 {
+            let reader = TrickleReader { my_bytes: bytes, my_step: step, my_ready: false };
+            let mut chars = EmbeddedUtf8Reader::new(reader);
+            let mut text = String::new();
+            while let Option::Some(ch) = chars.next_char().unwrap() {
+                text.push(ch);
+            }
+            assert_eq!(truth, text);
+            assert!(chars.has_invalid_sequence());
+            assert_eq!(Result::Ok(Option::None), chars.next_char());
+        }
+        let mut chars = EmbeddedUtf8Reader::new("x\u{7FF}yz".as_bytes());
+        let mut char_box = ['\0'; 8];
+        assert_eq!(Result::Ok(0), chars.read_chars(& mut []));
+        assert_eq!(Result::Ok(4), chars.read_chars(& mut char_box));
+        assert_eq!(['x', '\u{7FF}', 'y', 'z'], char_box[.. 4]);
+        assert_eq!(Result::Ok(0), chars.read_chars(& mut char_box));
+        assert!(! chars.has_invalid_sequence());
+    }
+
+    #[test]
+    // Test encoding in blocks, and a full output
+    fn test_embedded_writer() {
+        let text: String = "x\u{10348}\u{20AC}\n".repeat(50);
+        let expected = std::format!("{}\u{7FF}!", text);
+        let mut byte_box = [0u8; 512];
+        let mut writer = EmbeddedUtf8Writer::new(& mut byte_box[..]);
+        writer.write_chars(text.chars()).unwrap();
+        writer.write_str("\u{7FF}").unwrap();
+        writer.write_char('!').unwrap();
+        writer.flush().unwrap();
+        assert_eq!(512 - expected.len(), writer.get_ref().len());
+        assert_eq!(expected.as_bytes(), & byte_box[.. expected.len()]);
+        let mut byte_box = [0u8; 3];
+        let mut writer = EmbeddedUtf8Writer::new(& mut byte_box[..]);
+        assert!(writer.write_char('\u{10348}').is_err());
+        assert!(writer.get_mut().is_empty());
+    }
+}
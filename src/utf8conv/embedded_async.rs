@@ -0,0 +1,241 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::embedded_async
+
+use embedded_io_async::Error;
+use embedded_io_async::ErrorKind;
+use embedded_io_async::Read;
+
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::MoreEnum;
+use crate::utf8conv::UtfParserCommon;
+
+/// size of the input buffer of the reader, kept small for firmware
+const ASYNC_READ_BUF_SIZE: usize = 64;
+
+/// A char reader that awaits UTF8 bytes from an embedded_io_async::Read
+/// source, such as an embassy UART, and decodes them incrementally.
+///
+/// Sequences split across reads are joined by the parser.  Invalid UTF8
+/// sequences become replacement characters.  Read errors are returned
+/// to the caller; the decoding state is kept, so reading can go on
+/// after an error.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// async fn count_lines<R: embedded_io_async::Read>(uart: R) -> Result<usize, R::Error> {
+///     let mut chars = AsyncUtf8Reader::new(uart);
+///     let mut lines = 0;
+///     while let Some(ch) = chars.next_char().await? {
+///         if ch == '\n' {
+///             lines += 1;
+///         }
+///     }
+///     Ok(lines)
+/// }
+/// ```
+pub struct AsyncUtf8Reader<R> {
+
+    /// the inner reader
+    my_inner: R,
+
+    /// the UTF8 parser
+    my_parser: FromUtf8,
+
+    /// bytes read from the inner reader
+    my_in: [u8; ASYNC_READ_BUF_SIZE],
+
+    /// start of the unparsed bytes in my_in
+    my_in_start: usize,
+
+    /// end of the unparsed bytes in my_in
+    my_in_end: usize,
+}
+
+/// Implementation of AsyncUtf8Reader
+impl<R: Read> AsyncUtf8Reader<R> {
+
+    /// Make a new AsyncUtf8Reader reading UTF8 from `inner`.
+    pub fn new(inner: R) -> AsyncUtf8Reader<R> {
+        let mut parser = FromUtf8::new();
+        parser.set_is_last_buffer(false);
+        AsyncUtf8Reader {
+            my_inner: inner,
+            my_parser: parser,
+            my_in: [0; ASYNC_READ_BUF_SIZE],
+            my_in_start: 0,
+            my_in_end: 0,
+        }
+    }
+
+    /// Await the next decoded char; 'None' at end of data, which is a
+    /// read of 0 bytes from the inner reader.
+    pub async fn next_char(&mut self) -> Result<Option<char>, R::Error> {
+        let mut char_box = ['\0'; 1];
+        match self.read_chars(& mut char_box).await? {
+            0 => Result::Ok(Option::None),
+            _ => Result::Ok(Option::Some(char_box[0])),
+        }
+    }
+
+    /// Await decoded chars into `output`, and return the number of
+    /// chars written.  Awaits the inner reader only when no char can be
+    /// decoded from the buffered bytes.  Returns 0 at end of data, or
+    /// when `output` is empty.
+    /// # Arguments
+    /// * `output` - the output char buffer
+    pub async fn read_chars(&mut self, output: &mut [char]) -> Result<usize, R::Error> {
+        if output.is_empty() {
+            return Result::Ok(0);
+        }
+        loop {
+            let input = self.my_in.get(self.my_in_start .. self.my_in_end).unwrap_or(&[]);
+            match self.my_parser.utf8_to_char_slice(input, output) {
+                Result::Ok((rest, num_chars)) => {
+                    self.my_in_start = self.my_in_end - rest.len();
+                    return Result::Ok(num_chars);
+                }
+                Result::Err(MoreEnum::More(0)) => {
+                    // end of data
+                    return Result::Ok(0);
+                }
+                Result::Err(MoreEnum::More(_amt)) => {
+                    self.my_in_start = 0;
+                    self.my_in_end = 0;
+                    match self.my_inner.read(& mut self.my_in).await {
+                        Result::Ok(0) => {
+                            self.my_parser.set_is_last_buffer(true);
+                        }
+                        Result::Ok(len) => {
+                            self.my_in_end = len;
+                        }
+                        Result::Err(er) if er.kind() == ErrorKind::Interrupted => {}
+                        Result::Err(er) => {
+                            return Result::Err(er);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this stream.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_parser.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// Get a reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        & self.my_inner
+    }
+
+    #[inline]
+    /// Get a mutable reference to the inner reader
+    pub fn get_mut(&mut self) -> &mut R {
+        & mut self.my_inner
+    }
+
+    /// Unwrap this AsyncUtf8Reader, returning the inner reader.
+    /// Bytes buffered but not yet decoded are lost.
+    pub fn into_inner(self) -> R {
+        self.my_inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::future::Future;
+    use core::task::Context;
+    use core::task::Poll;
+    use core::task::Waker;
+    use std::sync::Arc;
+    use std::task::Wake;
+
+    use crate::*;
+
+    /// A waker that does nothing, for polling by hand
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    /// A reader that hands out at most `my_step` bytes per read, and
+    /// answers Pending before every read.
+    struct TrickleReader<'a> {
+        my_bytes: &'a [u8],
+        my_step: usize,
+        my_ready: bool,
+    }
+
+    impl embedded_io_async::ErrorType for TrickleReader<'_> {
+        type Error = core::convert::Infallible;
+    }
+
+    impl embedded_io_async::Read for TrickleReader<'_> {
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            core::future::poll_fn(|cx| {
+                self.my_ready = ! self.my_ready;
+                if self.my_ready {
+                    Poll::Ready(())
+                }
+                else {
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+            }).await;
+            let len = self.my_step.min(buf.len()).min(self.my_bytes.len());
+            buf[.. len].copy_from_slice(& self.my_bytes[.. len]);
+            self.my_bytes = & self.my_bytes[len ..];
+            Result::Ok(len)
+        }
+    }
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let mut fut = core::pin::pin!(fut);
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(& waker);
+        loop {
+            if let Poll::Ready(out) = fut.as_mut().poll(& mut cx) {
+                return out;
+            }
+        }
+    }
+
+    #[test]
+    // Test decoding bytes trickling in at every read size
+    fn test_async_reader() {
+        let bytes = b"a\xE2\x82\xACb\xF0\x9F\x98\x80\xFFc\xE2\x82";
+        let truth = "a\u{20AC}b\u{1F600}\u{FFFD}c\u{FFFD}";
+        for step in 1 .. bytes.len() + 1 {
+            let reader = TrickleReader { my_bytes: bytes, my_step: step, my_ready: false };
+            let mut chars = AsyncUtf8Reader::new(reader);
+            let mut text = std::string::String::new();
+            while let Option::Some(ch) = block_on(chars.next_char()).unwrap() {
+                text.push(ch);
+            }
+            assert_eq!(truth, text);
+            assert!(chars.has_invalid_sequence());
+            assert_eq!(Result::Ok(Option::None), block_on(chars.next_char()));
+        }
+        let mut chars = AsyncUtf8Reader::new("x\u{7FF}yz".as_bytes());
+        let mut char_box = ['\0'; 8];
+        assert_eq!(Result::Ok(0), block_on(chars.read_chars(& mut [])));
+        assert_eq!(Result::Ok(4), block_on(chars.read_chars(& mut char_box)));
+        assert_eq!(['x', '\u{7FF}', 'y', 'z'], char_box[.. 4]);
+        assert_eq!(Result::Ok(0), block_on(chars.read_chars(& mut char_box)));
+        assert!(! chars.has_invalid_sequence());
+    }
+}
@@ -0,0 +1,364 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::ffi
+
+//! C interface, enabled by the "ffi" feature.
+//!
+//! The streaming decoder (UTF8 to UTF32) and encoder (UTF32 to UTF8)
+//! are exposed as opaque handles with create, feed, finish, and destroy
+//! functions.  All data is passed in caller provided buffers; the only
+//! allocation is the handle itself.  The declarations are in
+//! `include/utf8conv.h`.
+//!
+//! Build a C library with
+//! `cargo rustc --release --features ffi --crate-type staticlib`
+//! (or `cdylib`).
+//!
+//! A feed function converts as much of the input as fits in the output,
+//! and reports the number of input units used; incomplete sequences at
+//! the end of the input are held in the handle for the next call.  A
+//! finish function signals end of data and drains the held units; call
+//! it until it returns less than the output length.  The handle is then
+//! ready for a new stream.
+//!
+//! Invalid input is replaced with U+FFFD, and recorded in the
+//! has_invalid flag.  Null pointers are treated as empty buffers, and a
+//! null handle as a handle with nothing to do.
+
+use alloc::boxed::Box;
+use core::slice;
+
+use crate::utf8conv::FromUnicode;
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::UtfParserCommon;
+
+/// View a caller input buffer as a slice.
+///
+/// # Safety
+///
+/// `data` must be null, or valid for reads of `len` elements.
+unsafe fn input_slice<'a, T>(data: *const T, len: usize) -> &'a [T] {
+    if data.is_null() || len == 0 {
+        &[]
+    }
+    else {
+        // Unsafe is justified by the caller contract.
+        unsafe { slice::from_raw_parts(data, len) }
+    }
+}
+
+/// View a caller output buffer as a slice.
+///
+/// # Safety
+///
+/// `data` must be null, or valid for writes of `len` elements.
+unsafe fn output_slice<'a, T>(data: *mut T, len: usize) -> &'a mut [T] {
+    if data.is_null() || len == 0 {
+        &mut []
+    }
+    else {
+        // Unsafe is justified by the caller contract.
+        unsafe { slice::from_raw_parts_mut(data, len) }
+    }
+}
+
+/// Store the number of input units used, if the caller asked for it.
+///
+/// # Safety
+///
+/// `used` must be null, or valid for a write.
+unsafe fn store_used(used: *mut usize, value: usize) {
+    if ! used.is_null() {
+        // Unsafe is justified by the caller contract.
+        unsafe { * used = value; }
+    }
+}
+
+/// Create a decoder handle, converting UTF8 to UTF32.
+/// Release it with utf8conv_decoder_destroy().
+#[no_mangle]
+pub extern "C" fn utf8conv_decoder_create() -> *mut FromUtf8 {
+    Box::into_raw(Box::new(FromUtf8::new()))
+}
+
+/// Convert UTF8 bytes to UTF32 code points.  Returns the number of code
+/// points written, and stores the number of bytes used in `input_used`.
+///
+/// # Safety
+///
+/// `dec` must be null or a live decoder handle.  `input` must be null
+/// or valid for reads of `input_len` bytes; `output` must be null or
+/// valid for writes of `output_len` code points; `input_used` must be
+/// null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn utf8conv_decoder_feed(dec: *mut FromUtf8,
+    input: *const u8, input_len: usize, input_used: *mut usize,
+    output: *mut u32, output_len: usize) -> usize {
+    // Unsafe is justified by the caller contract.
+    let (parser, input, mut cursor) = unsafe {
+        match dec.as_mut() {
+            Option::Some(parser) => {
+                (parser, input_slice(input, input_len), output_slice(output, output_len))
+            }
+            Option::None => {
+                store_used(input_used, 0);
+                return 0;
+            }
+        }
+    };
+    parser.set_is_last_buffer(false);
+    let (rest, written) = match parser.utf8_to_sink(input, & mut cursor) {
+        Result::Ok(v) => v,
+        // All of the input is held in the decoder.
+        Result::Err(_) => (&[][..], 0),
+    };
+    // Unsafe is justified by the caller contract.
+    unsafe { store_used(input_used, input.len() - rest.len()); }
+    written
+}
+
+/// Signal end of data, and drain the decoder.  Returns the number of
+/// code points written.
+///
+/// # Safety
+///
+/// `dec` must be null or a live decoder handle.  `output` must be null
+/// or valid for writes of `output_len` code points.
+#[no_mangle]
+pub unsafe extern "C" fn utf8conv_decoder_finish(dec: *mut FromUtf8,
+    output: *mut u32, output_len: usize) -> usize {
+    // Unsafe is justified by the caller contract.
+    let (parser, mut cursor) = match unsafe { dec.as_mut() } {
+        Option::Some(parser) => (parser, unsafe { output_slice(output, output_len) }),
+        Option::None => {
+            return 0;
+        }
+    };
+    parser.set_is_last_buffer(true);
+    match parser.utf8_to_sink(&[], & mut cursor) {
+        Result::Ok((_rest, written)) => written,
+        Result::Err(_) => 0,
+    }
+}
+
+/// Returns 1 if invalid input was seen since the last reset, else 0.
+///
+/// # Safety
+///
+/// `dec` must be null or a live decoder handle.
+#[no_mangle]
+pub unsafe extern "C" fn utf8conv_decoder_has_invalid(dec: *const FromUtf8) -> i32 {
+    // Unsafe is justified by the caller contract.
+    match unsafe { dec.as_ref() } {
+        Option::Some(parser) => parser.has_invalid_sequence() as i32,
+        Option::None => 0,
+    }
+}
+
+/// Discard held bytes and clear the invalid input flag.
+///
+/// # Safety
+///
+/// `dec` must be null or a live decoder handle.
+#[no_mangle]
+pub unsafe extern "C" fn utf8conv_decoder_reset(dec: *mut FromUtf8) {
+    // Unsafe is justified by the caller contract.
+    if let Option::Some(parser) = unsafe { dec.as_mut() } {
+        parser.reset_parser();
+    }
+}
+
+/// Release a decoder handle.
+///
+/// # Safety
+///
+/// `dec` must be null or a live decoder handle, which is not used again.
+#[no_mangle]
+pub unsafe extern "C" fn utf8conv_decoder_destroy(dec: *mut FromUtf8) {
+    if ! dec.is_null() {
+        // Unsafe is justified by the caller contract.
+        drop(unsafe { Box::from_raw(dec) });
+    }
+}
+
+/// Create an encoder handle, converting UTF32 to UTF8.
+/// Release it with utf8conv_encoder_destroy().
+#[no_mangle]
+pub extern "C" fn utf8conv_encoder_create() -> *mut FromUnicode {
+    Box::into_raw(Box::new(FromUnicode::new()))
+}
+
+/// Convert UTF32 code points to UTF8 bytes.  Returns the number of
+/// bytes written, and stores the number of code points used in
+/// `input_used`.
+///
+/// # Safety
+///
+/// `enc` must be null or a live encoder handle.  `input` must be null
+/// or valid for reads of `input_len` code points; `output` must be null
+/// or valid for writes of `output_len` bytes; `input_used` must be null
+/// or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn utf8conv_encoder_feed(enc: *mut FromUnicode,
+    input: *const u32, input_len: usize, input_used: *mut usize,
+    output: *mut u8, output_len: usize) -> usize {
+    // Unsafe is justified by the caller contract.
+    let (parser, input, mut cursor) = unsafe {
+        match enc.as_mut() {
+            Option::Some(parser) => {
+                (parser, input_slice(input, input_len), output_slice(output, output_len))
+            }
+            Option::None => {
+                store_used(input_used, 0);
+                return 0;
+            }
+        }
+    };
+    parser.set_is_last_buffer(false);
+    let mut src = input;
+    let written = parser.source_to_sink(& mut src, & mut cursor).unwrap_or(0);
+    // Unsafe is justified by the caller contract.
+    unsafe { store_used(input_used, input.len() - src.len()); }
+    written
+}
+
+/// Signal end of data, and drain the encoder.  Returns the number of
+/// bytes written.
+///
+/// # Safety
+///
+/// `enc` must be null or a live encoder handle.  `output` must be null
+/// or valid for writes of `output_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn utf8conv_encoder_finish(enc: *mut FromUnicode,
+    output: *mut u8, output_len: usize) -> usize {
+    // Unsafe is justified by the caller contract.
+    let (parser, mut cursor) = match unsafe { enc.as_mut() } {
+        Option::Some(parser) => (parser, unsafe { output_slice(output, output_len) }),
+        Option::None => {
+            return 0;
+        }
+    };
+    parser.set_is_last_buffer(true);
+    let mut src: &[u32] = &[];
+    parser.source_to_sink(& mut src, & mut cursor).unwrap_or(0)
+}
+
+/// Returns 1 if invalid input was seen since the last reset, else 0.
+///
+/// # Safety
+///
+/// `enc` must be null or a live encoder handle.
+#[no_mangle]
+pub unsafe extern "C" fn utf8conv_encoder_has_invalid(enc: *const FromUnicode) -> i32 {
+    // Unsafe is justified by the caller contract.
+    match unsafe { enc.as_ref() } {
+        Option::Some(parser) => parser.has_invalid_sequence() as i32,
+        Option::None => 0,
+    }
+}
+
+/// Discard held bytes and clear the invalid input flag.
+///
+/// # Safety
+///
+/// `enc` must be null or a live encoder handle.
+#[no_mangle]
+pub unsafe extern "C" fn utf8conv_encoder_reset(enc: *mut FromUnicode) {
+    // Unsafe is justified by the caller contract.
+    if let Option::Some(parser) = unsafe { enc.as_mut() } {
+        parser.reset_parser();
+    }
+}
+
+/// Release an encoder handle.
+///
+/// # Safety
+///
+/// `enc` must be null or a live encoder handle, which is not used again.
+#[no_mangle]
+pub unsafe extern "C" fn utf8conv_encoder_destroy(enc: *mut FromUnicode) {
+    if ! enc.is_null() {
+        // Unsafe is justified by the caller contract.
+        drop(unsafe { Box::from_raw(enc) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    use core::ptr;
+    use std::vec::Vec;
+
+    #[test]
+    // Test decoding one byte at a time through the C interface
+    fn test_ffi_decoder() {
+        let bytes = [0x41u8, 0xE2u8, 0x82u8, 0xACu8, 0xF0u8, 0x90u8, 0x8Du8, 0xC2u8];
+        let mut output = Vec::new();
+        let mut code_box = [0u32; 2];
+        unsafe {
+            let dec = utf8conv_decoder_create();
+            for b in bytes.iter() {
+                let mut used = 0usize;
+                let n = utf8conv_decoder_feed(dec, b, 1, & mut used, code_box.as_mut_ptr(), code_box.len());
+                assert_eq!(1, used);
+                output.extend_from_slice(& code_box[.. n]);
+            }
+            loop {
+                let n = utf8conv_decoder_finish(dec, code_box.as_mut_ptr(), 1);
+                output.extend_from_slice(& code_box[.. n]);
+                if n < 1 {
+                    break;
+                }
+            }
+            assert_eq!(1, utf8conv_decoder_has_invalid(dec));
+            utf8conv_decoder_reset(dec);
+            assert_eq!(0, utf8conv_decoder_has_invalid(dec));
+            // A full output buffer leaves input unused.
+            let mut used = 9usize;
+            assert_eq!(0, utf8conv_decoder_feed(dec, bytes.as_ptr(), bytes.len(), & mut used, code_box.as_mut_ptr(), 0));
+            assert_eq!(0, used);
+            utf8conv_decoder_destroy(dec);
+            assert_eq!(0, utf8conv_decoder_feed(ptr::null_mut(), bytes.as_ptr(), 1, ptr::null_mut(), code_box.as_mut_ptr(), 2));
+        }
+        assert_eq!(output, [0x41u32, 0x20AC, 0xFFFD, 0xFFFD]);
+    }
+
+    #[test]
+    // Test encoding with a small output buffer through the C interface
+    fn test_ffi_encoder() {
+        let codes = [0x41u32, 0x7FF, 0xD800, 0x10348];
+        let mut output = Vec::new();
+        let mut byte_box = [0u8; 3];
+        unsafe {
+            let enc = utf8conv_encoder_create();
+            let mut pos = 0usize;
+            while pos < codes.len() {
+                let mut used = 0usize;
+                let n = utf8conv_encoder_feed(enc, codes[pos ..].as_ptr(), codes.len() - pos, & mut used, byte_box.as_mut_ptr(), byte_box.len());
+                pos += used;
+                output.extend_from_slice(& byte_box[.. n]);
+            }
+            loop {
+                let n = utf8conv_encoder_finish(enc, byte_box.as_mut_ptr(), byte_box.len());
+                output.extend_from_slice(& byte_box[.. n]);
+                if n < byte_box.len() {
+                    break;
+                }
+            }
+            assert_eq!(1, utf8conv_encoder_has_invalid(enc));
+            utf8conv_encoder_destroy(enc);
+            utf8conv_encoder_destroy(ptr::null_mut());
+        }
+        assert_eq!("A\u{7FF}\u{FFFD}\u{10348}".as_bytes(), & output[..]);
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::framer
+
+/// Returns the sequence length announced by a lead byte, or 1 for
+/// bytes that cannot start a multi byte sequence.
+#[inline]
+fn lead_len(b: u8) -> usize {
+    match b {
+        0xC2 ..= 0xDF => 2,
+        0xE0 ..= 0xEF => 3,
+        0xF0 ..= 0xF4 => 4,
+        _ => 1,
+    }
+}
+
+#[inline]
+/// Returns true for UTF8 continuation bytes.
+fn is_continuation(b: u8) -> bool {
+    (b & 0xC0) == 0x80
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// Splits a stream of byte chunks into frames that only hold complete
+/// UTF8 characters, for proxies forwarding text without splitting a
+/// character across messages.
+///
+/// feed() returns each frame as two slices: the character completed
+/// from bytes held over from the previous chunk, then the complete
+/// characters of the chunk itself.  Up to 3 trailing bytes of an
+/// incomplete character are held in the framer.
+///
+/// The framer does not validate its input; invalid bytes are framed
+/// as single bytes and forwarded as they are.  Use the sanitizing
+/// adapter when the output must be valid UTF8.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut framer = Utf8Framer::new();
+/// let (head, body) = framer.feed(& [0x41u8, 0xE2u8, 0x82u8]);
+/// assert_eq!((& b""[..], & b"A"[..]), (head, body));
+/// assert_eq!(2, framer.pending_len());
+/// let (head, body) = framer.feed(& [0xACu8, 0x42u8]);
+/// assert_eq!((& [0xE2u8, 0x82u8, 0xACu8][..], & b"B"[..]), (head, body));
+/// assert_eq!(0, framer.finish().len());
+/// ```
+pub struct Utf8Framer {
+
+    /// bytes of the character completed by the current chunk
+    my_head: [u8; 4],
+
+    /// number of bytes in my_head
+    my_head_len: usize,
+
+    /// trailing bytes of an incomplete character
+    my_pending: [u8; 4],
+
+    /// number of bytes in my_pending
+    my_pending_len: usize,
+}
+
+/// Implementation of Utf8Framer
+impl Utf8Framer {
+
+    /// Make a new Utf8Framer
+    pub fn new() -> Utf8Framer {
+        Utf8Framer {
+            my_head: [0u8; 4],
+            my_head_len: 0,
+            my_pending: [0u8; 4],
+            my_pending_len: 0,
+        }
+    }
+
+    /// Present the next chunk, and return the frame it completes as
+    /// a head slice held in the framer and a body slice of the chunk.
+    /// Either slice may be empty.
+    /// # Arguments
+    /// * `chunk` - the next bytes of the stream
+    pub fn feed<'a>(&mut self, chunk: &'a [u8]) -> (&[u8], &'a [u8]) {
+        self.my_head_len = 0;
+        let mut rest = chunk;
+        if self.my_pending_len > 0 {
+            let need = lead_len(self.my_pending[0]);
+            // Continuation bytes complete the held character; any other
+            // byte ends it early, and the held bytes are framed as is.
+            while self.my_pending_len < need {
+                match rest.split_first() {
+                    Option::Some((b, tail)) if is_continuation(* b) => {
+                        self.my_pending[self.my_pending_len] = * b;
+                        self.my_pending_len += 1;
+                        rest = tail;
+                    }
+                    Option::Some(_) => {
+                        break;
+                    }
+                    Option::None => {
+                        // Still incomplete; the whole chunk is held.
+                        return (&[], &[]);
+                    }
+                }
+            }
+            self.my_head = self.my_pending;
+            self.my_head_len = self.my_pending_len;
+            self.my_pending_len = 0;
+        }
+        // Find a lead byte among the last 3 bytes whose character
+        // does not fit in the chunk.
+        let mut split = rest.len();
+        for (back, b) in rest.iter().rev().take(3).enumerate() {
+            if ! is_continuation(* b) {
+                if lead_len(* b) > back + 1 {
+                    split = rest.len() - back - 1;
+                }
+                break;
+            }
+        }
+        let (body, tail) = rest.split_at(split);
+        self.my_pending[.. tail.len()].copy_from_slice(tail);
+        self.my_pending_len = tail.len();
+        (& self.my_head[.. self.my_head_len], body)
+    }
+
+    /// Signal end of data, and return the held bytes of an incomplete
+    /// character, if any.  The framer is then ready for a new stream.
+    pub fn finish(&mut self) -> &[u8] {
+        self.my_head = self.my_pending;
+        self.my_head_len = self.my_pending_len;
+        self.my_pending_len = 0;
+        & self.my_head[.. self.my_head_len]
+    }
+
+    #[inline]
+    /// Discard any held bytes.
+    pub fn reset(&mut self) {
+        self.my_head_len = 0;
+        self.my_pending_len = 0;
+    }
+
+    #[inline]
+    /// Number of bytes held in the framer.
+    pub fn pending_len(&self) -> usize {
+        self.my_pending_len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::vec::Vec;
+
+    // Frame a sequence of chunks, returning the frames and the final
+    // held bytes.
+    fn frame_chunks(chunks: & [& [u8]]) -> (Vec<Vec<u8>>, Vec<u8>) {
+        let mut framer = Utf8Framer::new();
+        let mut frames = Vec::new();
+        for chunk in chunks {
+            let (head, body) = framer.feed(chunk);
+            let mut frame = head.to_vec();
+            frame.extend_from_slice(body);
+            frames.push(frame);
+        }
+        (frames, framer.finish().to_vec())
+    }
+
+    #[test]
+    // Test that every split of valid text yields complete-character frames
+    fn test_framer_splits() {
+        let text = "A\u{7FF}\u{20AC}\u{10348}z";
+        let bytes = text.as_bytes();
+        for pos1 in 0 ..= bytes.len() {
+            for pos2 in pos1 ..= bytes.len() {
+                let chunks = [& bytes[.. pos1], & bytes[pos1 .. pos2], & bytes[pos2 ..]];
+                let (frames, held) = frame_chunks(& chunks);
+                assert!(held.is_empty());
+                for frame in frames.iter() {
+                    assert!(core::str::from_utf8(frame).is_ok());
+                }
+                assert_eq!(bytes, & frames.concat()[..]);
+            }
+        }
+    }
+
+    #[test]
+    // Test that invalid bytes are forwarded, and truncation is reported
+    fn test_framer_invalid() {
+        let (frames, held) = frame_chunks(& [& [0xE2u8], & [0x41u8, 0x80u8], & [0xF0u8, 0x90u8]]);
+        assert_eq!(frames, [std::vec![], std::vec![0xE2u8, 0x41u8, 0x80u8], std::vec![]]);
+        assert_eq!(held, [0xF0u8, 0x90u8]);
+        let (frames, held) = frame_chunks(& [& [0xC0u8, 0xFFu8, 0xF5u8]]);
+        assert_eq!(frames, [std::vec![0xC0u8, 0xFFu8, 0xF5u8]]);
+        assert!(held.is_empty());
+        let mut framer = Utf8Framer::default();
+        assert_eq!((& [][..], & [][..]), framer.feed(& [0xF4u8, 0x8Fu8, 0xBFu8]));
+        framer.reset();
+        assert_eq!(0, framer.pending_len());
+    }
+}
@@ -0,0 +1,2230 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::gb18030
+
+use crate::utf8conv::Decoder;
+use crate::utf8conv::MoreEnum;
+use crate::utf8conv::UtfParserCommon;
+
+// Two byte codes are numbered (lead - 0x81) * 190 + trail position,
+// with trail bytes 40 to 7E and 80 to FE.  Four byte codes below
+// U+10000 are numbered by their linear index from 81 30 81 30.
+// Both are mapped by runs of consecutive codepoints; a code maps to
+// the codepoint of its run plus its distance from the run start.
+// The mappings are those of GB18030-2005.
+
+/// index of the first two byte code of each run
+static TWO_BYTE_RUN_START: [u16; 10351] = [
+    0x0000, 0x0001, 0x0004, 0x0005, 0x0006, 0x0007, 0x000A, 0x000B, 0x000C, 0x000D, 0x000F, 0x0010,
+    0x0011, 0x0012, 0x0013, 0x0014, 0x0017, 0x0018, 0x0019, 0x001A, 0x001B, 0x001C, 0x001D, 0x001F,
+    0x0023, 0x0025, 0x002B, 0x002C, 0x0036, 0x003D, 0x003E, 0x003F, 0x0040, 0x0042, 0x0043, 0x0046,
+    0x0047, 0x0048, 0x004B, 0x004C, 0x0050, 0x0053, 0x0054, 0x0055, 0x0057, 0x0058, 0x005B, 0x005C,
+    0x005D, 0x005F, 0x0060, 0x0063, 0x0064, 0x0065, 0x0068, 0x0069, 0x006A, 0x006B, 0x0072, 0x0074,
+    0x0079, 0x007B, 0x007C, 0x007D, 0x007F, 0x0082, 0x0083, 0x0084, 0x0085, 0x0086, 0x0087, 0x0088,
+    0x008D, 0x008F, 0x0095, 0x0096, 0x0097, 0x0098, 0x009A, 0x009B, 0x009C, 0x009E, 0x00A0, 0x00A2,
+    0x00A3, 0x00A7, 0x00A8, 0x00AB, 0x00AE, 0x00AF, 0x00B0, 0x00B1, 0x00B2, 0x00B4, 0x00B6, 0x00B9,
+    0x00BA, 0x00BC, 0x00BE, 0x00BF, 0x00C0, 0x00C1, 0x00C6, 0x00CF, 0x00D2, 0x00D6, 0x00D9, 0x00DE,
+    0x00DF, 0x00E0, 0x00E1, 0x00E2, 0x00E4, 0x00E5, 0x00E7, 0x00E8, 0x00E9, 0x00ED, 0x00EE, 0x00F1,
+    0x00FE, 0x00FF, 0x0101, 0x0102, 0x0105, 0x0106, 0x0108, 0x0109, 0x010C, 0x010D, 0x010E, 0x0119,
+    0x011A, 0x011B, 0x011F, 0x0122, 0x0125, 0x0126, 0x012B, 0x012F, 0x0130, 0x0138, 0x013E, 0x0147,
+    0x014A, 0x014C, 0x0150, 0x0152, 0x0156, 0x016B, 0x016C, 0x016D, 0x016F, 0x0174, 0x017B, 0x018E,
+    0x0194, 0x0197, 0x01A2, 0x01A6, 0x01AA, 0x01AB, 0x01B0, 0x01BA, 0x01BD, 0x01C3, 0x01D1, 0x01EE,
+    0x01EF, 0x01F0, 0x01F1, 0x01F2, 0x01F5, 0x01F7, 0x01FA, 0x01FB, 0x0200, 0x0202, 0x0204, 0x0206,
+    0x0207, 0x0208, 0x0209, 0x020B, 0x020D, 0x020F, 0x0211, 0x0215, 0x0217, 0x0218, 0x0219, 0x021C,
+    0x021D, 0x021E, 0x0223, 0x0225, 0x0226, 0x0229, 0x022B, 0x022E, 0x022F, 0x0230, 0x0231, 0x0233,
+    0x0234, 0x023D, 0x023E, 0x0240, 0x0242, 0x0248, 0x0249, 0x024A, 0x024C, 0x024D, 0x024E, 0x024F,
+    0x0251, 0x0252, 0x0254, 0x0256, 0x0259, 0x025A, 0x025C, 0x025F, 0x0262, 0x0263, 0x0264, 0x0265,
+    0x0267, 0x0269, 0x026A, 0x026B, 0x0271, 0x0272, 0x0274, 0x0276, 0x0277, 0x027C, 0x027D, 0x027F,
+    0x0282, 0x0283, 0x0284, 0x0288, 0x028A, 0x0294, 0x0295, 0x0296, 0x029B, 0x02A2, 0x02A4, 0x02AB,
+    0x02AC, 0x02B0, 0x02B3, 0x02BD, 0x02C0, 0x02C3, 0x02C4, 0x02C5, 0x02C9, 0x02CA, 0x02CD, 0x02CE,
+    0x02D4, 0x02D8, 0x02E3, 0x02EB, 0x02EE, 0x02F2, 0x02F3, 0x02F7, 0x02F8, 0x02FC, 0x02FD, 0x02FF,
+    0x0301, 0x0302, 0x0304, 0x0307, 0x030A, 0x0314, 0x0316, 0x0317, 0x0318, 0x0319, 0x031A, 0x031D,
+    0x031E, 0x031F, 0x0321, 0x0322, 0x0323, 0x0324, 0x0325, 0x0326, 0x0328, 0x0329, 0x032A, 0x032B,
+    0x032F, 0x0331, 0x0332, 0x0334, 0x0335, 0x033C, 0x033E, 0x033F, 0x0341, 0x0342, 0x0344, 0x0345,
+    0x0346, 0x034A, 0x0351, 0x0355, 0x0358, 0x0359, 0x035E, 0x0361, 0x0363, 0x0364, 0x0365, 0x0368,
+    0x036A, 0x036B, 0x036C, 0x036D, 0x0370, 0x0371, 0x0372, 0x0373, 0x0374, 0x0375, 0x0378, 0x0379,
+    0x037A, 0x037C, 0x037D, 0x037E, 0x037F, 0x0381, 0x0382, 0x0383, 0x0384, 0x0386, 0x0388, 0x0389,
+    0x038A, 0x038E, 0x038F, 0x0390, 0x0395, 0x0396, 0x0397, 0x0398, 0x03A0, 0x03A1, 0x03A3, 0x03A5,
+    0x03A6, 0x03A7, 0x03A8, 0x03AC, 0x03AD, 0x03AE, 0x03AF, 0x03B1, 0x03B2, 0x03B7, 0x03B8, 0x03B9,
+    0x03BA, 0x03BB, 0x03BE, 0x03C0, 0x03C1, 0x03C2, 0x03C3, 0x03C4, 0x03C6, 0x03C7, 0x03C8, 0x03C9,
+    0x03CE, 0x03D0, 0x03D3, 0x03D9, 0x03DA, 0x03DB, 0x03DC, 0x03E0, 0x03E1, 0x03E6, 0x03E8, 0x03EE,
+    0x03F2, 0x03F3, 0x03F5, 0x03F7, 0x03F8, 0x03F9, 0x03FA, 0x03FD, 0x0401, 0x0402, 0x0403, 0x0404,
+    0x0405, 0x0407, 0x040C, 0x0410, 0x0415, 0x0419, 0x041B, 0x041D, 0x041E, 0x0424, 0x0426, 0x0427,
+    0x0428, 0x042A, 0x042D, 0x042E, 0x0430, 0x0433, 0x0435, 0x0436, 0x043D, 0x0446, 0x0447, 0x0448,
+    0x0449, 0x044A, 0x044B, 0x044C, 0x0451, 0x0454, 0x0456, 0x0459, 0x045A, 0x045F, 0x0460, 0x0461,
+    0x0462, 0x0463, 0x0464, 0x0466, 0x0468, 0x0469, 0x046A, 0x046F, 0x0470, 0x0476, 0x0478, 0x0479,
+    0x0481, 0x0483, 0x0485, 0x0488, 0x048A, 0x048E, 0x0491, 0x0492, 0x0493, 0x0495, 0x0496, 0x0499,
+    0x04A5, 0x04AA, 0x04AC, 0x04AE, 0x04B3, 0x04B4, 0x04B7, 0x04BB, 0x04BF, 0x04C3, 0x04CB, 0x04D2,
+    0x04D5, 0x04E4, 0x04EF, 0x04F6, 0x04FA, 0x0507, 0x0510, 0x0512, 0x0514, 0x0515, 0x0516, 0x051C,
+    0x051D, 0x051F, 0x0521, 0x0524, 0x0526, 0x0529, 0x052A, 0x052B, 0x053C, 0x053E, 0x0541, 0x0545,
+    0x0546, 0x0548, 0x054D, 0x054F, 0x0550, 0x0551, 0x0555, 0x0557, 0x0558, 0x055D, 0x055F, 0x0561,
+    0x0562, 0x0563, 0x0564, 0x0565, 0x0568, 0x056A, 0x056D, 0x0572, 0x0576, 0x057B, 0x0582, 0x0586,
+    0x0587, 0x0588, 0x0589, 0x058A, 0x058D, 0x058E, 0x0591, 0x059A, 0x05A1, 0x05A3, 0x05A5, 0x05A6,
+    0x05A8, 0x05AA, 0x05AB, 0x05AE, 0x05B6, 0x05B7, 0x05BB, 0x05BE, 0x05C0, 0x05C2, 0x05C3, 0x05C6,
+    0x05C9, 0x05CA, 0x05CD, 0x05D0, 0x05D3, 0x05D7, 0x05D8, 0x05DA, 0x05DF, 0x05E4, 0x05E8, 0x05F6,
+    0x05FD, 0x0600, 0x0602, 0x0605, 0x060A, 0x0610, 0x0615, 0x0626, 0x0627, 0x0628, 0x0629, 0x062C,
+    0x0634, 0x0639, 0x063C, 0x0644, 0x0656, 0x065A, 0x065D, 0x0668, 0x066B, 0x0679, 0x067F, 0x0680,
+    0x0681, 0x0683, 0x0685, 0x0687, 0x068F, 0x0690, 0x0692, 0x0697, 0x0698, 0x069C, 0x069E, 0x069F,
+    0x06A1, 0x06A5, 0x06A6, 0x06A7, 0x06A8, 0x06A9, 0x06AB, 0x06AD, 0x06AE, 0x06B2, 0x06B3, 0x06B5,
+    0x06B6, 0x06B8, 0x06B9, 0x06BB, 0x06BC, 0x06C1, 0x06C2, 0x06C4, 0x06D1, 0x06D2, 0x06D3, 0x06D6,
+    0x06D9, 0x06DA, 0x06DB, 0x06DD, 0x06E1, 0x06E3, 0x06E4, 0x06E8, 0x06EC, 0x06EE, 0x06F0, 0x06F2,
+    0x06F8, 0x06F9, 0x06FB, 0x0702, 0x0705, 0x0709, 0x070B, 0x070C, 0x070D, 0x0712, 0x0713, 0x0715,
+    0x0718, 0x0724, 0x0725, 0x0728, 0x0729, 0x072A, 0x072C, 0x0730, 0x0731, 0x0735, 0x0738, 0x073A,
+    0x073C, 0x073D, 0x0740, 0x0747, 0x0748, 0x0749, 0x074E, 0x0751, 0x0756, 0x0758, 0x0762, 0x0766,
+    0x076D, 0x0771, 0x0773, 0x077C, 0x077E, 0x0782, 0x0794, 0x079B, 0x07A9, 0x07B0, 0x07B1, 0x07B3,
+    0x07B8, 0x07BA, 0x07C0, 0x07C2, 0x07C7, 0x07C8, 0x07C9, 0x07CA, 0x07CD, 0x07D0, 0x07D1, 0x07D3,
+    0x07D5, 0x07D6, 0x07DB, 0x07F2, 0x07FE, 0x0818, 0x0819, 0x081B, 0x0823, 0x0832, 0x0833, 0x0834,
+    0x0835, 0x0837, 0x0839, 0x083A, 0x083D, 0x083E, 0x083F, 0x0843, 0x0845, 0x0847, 0x0848, 0x0849,
+    0x084A, 0x084C, 0x084F, 0x0850, 0x0851, 0x0852, 0x0855, 0x0859, 0x085B, 0x085C, 0x085F, 0x0861,
+    0x0862, 0x0866, 0x0869, 0x086A, 0x0873, 0x0874, 0x0876, 0x0878, 0x087D, 0x087E, 0x0885, 0x0887,
+    0x0888, 0x088A, 0x088B, 0x088D, 0x0891, 0x0892, 0x0894, 0x0895, 0x0896, 0x0897, 0x089B, 0x089C,
+    0x089D, 0x08A1, 0x08A5, 0x08A7, 0x08AA, 0x08AC, 0x08AE, 0x08B0, 0x08B3, 0x08B6, 0x08BA, 0x08BB,
+    0x08BC, 0x08BD, 0x08C4, 0x08C5, 0x08CC, 0x08D0, 0x08D1, 0x08D6, 0x08D9, 0x08DB, 0x08DD, 0x08DE,
+    0x08E3, 0x08E8, 0x08E9, 0x08EC, 0x08ED, 0x08EE, 0x08EF, 0x08F3, 0x08F4, 0x08F5, 0x08F7, 0x08FD,
+    0x0903, 0x0909, 0x0910, 0x0912, 0x0913, 0x0914, 0x0916, 0x0918, 0x0922, 0x0928, 0x092A, 0x0930,
+    0x0935, 0x0936, 0x093A, 0x093C, 0x0941, 0x0942, 0x0943, 0x0946, 0x094B, 0x0953, 0x095B, 0x095D,
+    0x0968, 0x096A, 0x096B, 0x0976, 0x0977, 0x0979, 0x097D, 0x098A, 0x09A0, 0x09A3, 0x09BC, 0x09C9,
+    0x09D0, 0x09DD, 0x09DE, 0x09E0, 0x09E2, 0x09E3, 0x09E5, 0x09E6, 0x09E8, 0x09ED, 0x09EF, 0x09F0,
+    0x09F1, 0x09F4, 0x09F6, 0x09F8, 0x09F9, 0x0A01, 0x0A06, 0x0A08, 0x0A0D, 0x0A0F, 0x0A13, 0x0A14,
+    0x0A1A, 0x0A21, 0x0A26, 0x0A28, 0x0A2A, 0x0A39, 0x0A3A, 0x0A3B, 0x0A3C, 0x0A3D, 0x0A40, 0x0A41,
+    0x0A43, 0x0A46, 0x0A47, 0x0A48, 0x0A49, 0x0A4A, 0x0A4E, 0x0A53, 0x0A58, 0x0A59, 0x0A5D, 0x0A67,
+    0x0A6D, 0x0A6F, 0x0A73, 0x0A7F, 0x0A80, 0x0A89, 0x0A8A, 0x0A8C, 0x0A8F, 0x0A92, 0x0A93, 0x0A96,
+    0x0A97, 0x0A98, 0x0A99, 0x0A9A, 0x0A9C, 0x0A9F, 0x0AA3, 0x0AA4, 0x0AA6, 0x0AA7, 0x0AA8, 0x0AAF,
+    0x0AB0, 0x0AB3, 0x0AC2, 0x0AC3, 0x0AC4, 0x0AC8, 0x0ACB, 0x0ACC, 0x0ACD, 0x0ACF, 0x0AD0, 0x0AD2,
+    0x0AD3, 0x0AD6, 0x0AD7, 0x0AD8, 0x0ADB, 0x0ADC, 0x0ADD, 0x0AE0, 0x0AE1, 0x0AE3, 0x0AE4, 0x0AE6,
+    0x0AEA, 0x0AF0, 0x0AF1, 0x0AF3, 0x0AF9, 0x0AFA, 0x0AFE, 0x0B03, 0x0B05, 0x0B07, 0x0B08, 0x0B0B,
+    0x0B0E, 0x0B10, 0x0B12, 0x0B14, 0x0B16, 0x0B17, 0x0B19, 0x0B1C, 0x0B1E, 0x0B20, 0x0B21, 0x0B24,
+    0x0B26, 0x0B28, 0x0B29, 0x0B2B, 0x0B2C, 0x0B2E, 0x0B31, 0x0B34, 0x0B39, 0x0B3E, 0x0B40, 0x0B41,
+    0x0B48, 0x0B49, 0x0B4B, 0x0B4C, 0x0B4E, 0x0B51, 0x0B53, 0x0B57, 0x0B59, 0x0B5A, 0x0B5C, 0x0B5E,
+    0x0B5F, 0x0B60, 0x0B63, 0x0B67, 0x0B69, 0x0B6D, 0x0B6E, 0x0B6F, 0x0B72, 0x0B73, 0x0B74, 0x0B76,
+    0x0B78, 0x0B79, 0x0B7B, 0x0B7C, 0x0B7D, 0x0B7E, 0x0B81, 0x0B83, 0x0B8B, 0x0B8E, 0x0B93, 0x0B96,
+    0x0B98, 0x0B99, 0x0B9A, 0x0B9B, 0x0BA0, 0x0BA1, 0x0BA3, 0x0BA4, 0x0BA6, 0x0BAB, 0x0BAF, 0x0BB0,
+    0x0BB3, 0x0BB8, 0x0BBC, 0x0BC0, 0x0BC2, 0x0BC3, 0x0BC6, 0x0BD9, 0x0BE1, 0x0BE2, 0x0BE3, 0x0BE4,
+    0x0BE6, 0x0BE9, 0x0BF0, 0x0BF4, 0x0BF8, 0x0BFF, 0x0C03, 0x0C04, 0x0C17, 0x0C19, 0x0C1E, 0x0C26,
+    0x0C2F, 0x0C31, 0x0C3B, 0x0C41, 0x0C44, 0x0C49, 0x0C4A, 0x0C4F, 0x0C50, 0x0C61, 0x0C6F, 0x0C78,
+    0x0C7E, 0x0C7F, 0x0C80, 0x0C82, 0x0C83, 0x0C86, 0x0C87, 0x0C88, 0x0C8C, 0x0C8D, 0x0C8E, 0x0C92,
+    0x0C94, 0x0C99, 0x0C9A, 0x0C9D, 0x0C9E, 0x0CA0, 0x0CA3, 0x0CA5, 0x0CAC, 0x0CAE, 0x0CAF, 0x0CB1,
+    0x0CB3, 0x0CB5, 0x0CB7, 0x0CB8, 0x0CBB, 0x0CBF, 0x0CC5, 0x0CC6, 0x0CC7, 0x0CCA, 0x0CCB, 0x0CCD,
+    0x0CCF, 0x0CD3, 0x0CD6, 0x0CD9, 0x0CDA, 0x0CDB, 0x0CDD, 0x0CDE, 0x0CDF, 0x0CE0, 0x0CE1, 0x0CE2,
+    0x0CE4, 0x0CE6, 0x0CE7, 0x0CE9, 0x0CEA, 0x0CEB, 0x0CEC, 0x0CF0, 0x0CF1, 0x0CF5, 0x0CF9, 0x0CFB,
+    0x0CFF, 0x0D02, 0x0D03, 0x0D05, 0x0D06, 0x0D09, 0x0D0B, 0x0D11, 0x0D13, 0x0D17, 0x0D18, 0x0D1A,
+    0x0D1B, 0x0D1F, 0x0D27, 0x0D28, 0x0D2B, 0x0D2C, 0x0D2F, 0x0D31, 0x0D35, 0x0D37, 0x0D3B, 0x0D3C,
+    0x0D40, 0x0D41, 0x0D42, 0x0D43, 0x0D46, 0x0D47, 0x0D4E, 0x0D4F, 0x0D50, 0x0D51, 0x0D52, 0x0D53,
+    0x0D55, 0x0D57, 0x0D58, 0x0D59, 0x0D5A, 0x0D5F, 0x0D60, 0x0D62, 0x0D65, 0x0D66, 0x0D69, 0x0D70,
+    0x0D71, 0x0D72, 0x0D77, 0x0D79, 0x0D7D, 0x0D7E, 0x0D7F, 0x0D80, 0x0D84, 0x0D85, 0x0D87, 0x0D8C,
+    0x0D8E, 0x0D90, 0x0D96, 0x0D97, 0x0D98, 0x0D9C, 0x0D9F, 0x0DA0, 0x0DA6, 0x0DAB, 0x0DAD, 0x0DAE,
+    0x0DAF, 0x0DB1, 0x0DB2, 0x0DB9, 0x0DBA, 0x0DBD, 0x0DC2, 0x0DCA, 0x0DCB, 0x0DCE, 0x0DD8, 0x0DDF,
+    0x0DE0, 0x0DE1, 0x0DEA, 0x0DEC, 0x0DEE, 0x0DF2, 0x0DF7, 0x0DFB, 0x0DFD, 0x0DFE, 0x0E02, 0x0E03,
+    0x0E04, 0x0E05, 0x0E08, 0x0E09, 0x0E0B, 0x0E12, 0x0E13, 0x0E14, 0x0E18, 0x0E1D, 0x0E20, 0x0E21,
+    0x0E22, 0x0E3B, 0x0E43, 0x0E4B, 0x0E50, 0x0E5C, 0x0E61, 0x0E63, 0x0E67, 0x0E68, 0x0E69, 0x0E6B,
+    0x0E70, 0x0E72, 0x0E74, 0x0E76, 0x0E77, 0x0E7A, 0x0E7C, 0x0E7D, 0x0E7E, 0x0E81, 0x0E83, 0x0E87,
+    0x0E8A, 0x0E8B, 0x0E8C, 0x0E8E, 0x0E9D, 0x0EA0, 0x0EA3, 0x0EA4, 0x0EA7, 0x0EA8, 0x0EA9, 0x0EAB,
+    0x0EAC, 0x0EAE, 0x0EAF, 0x0EB0, 0x0EB1, 0x0EB2, 0x0EB3, 0x0EBB, 0x0EBD, 0x0EC0, 0x0EC1, 0x0EC5,
+    0x0EC6, 0x0EC8, 0x0ECB, 0x0ED3, 0x0ED4, 0x0ED6, 0x0ED8, 0x0EDC, 0x0EDE, 0x0EE3, 0x0EE4, 0x0EE6,
+    0x0EE9, 0x0EEA, 0x0EEB, 0x0EEE, 0x0EF1, 0x0EF4, 0x0EF5, 0x0EF9, 0x0EFA, 0x0EFE, 0x0EFF, 0x0F00,
+    0x0F02, 0x0F07, 0x0F08, 0x0F0A, 0x0F0B, 0x0F12, 0x0F14, 0x0F16, 0x0F18, 0x0F1C, 0x0F1D, 0x0F1F,
+    0x0F20, 0x0F21, 0x0F26, 0x0F29, 0x0F2A, 0x0F2C, 0x0F2F, 0x0F32, 0x0F33, 0x0F35, 0x0F39, 0x0F3D,
+    0x0F41, 0x0F46, 0x0F4F, 0x0F54, 0x0F59, 0x0F5D, 0x0F61, 0x0F7B, 0x0F7C, 0x0F84, 0x0F86, 0x0F8C,
+    0x0F8D, 0x0F8F, 0x0F90, 0x0F92, 0x0F93, 0x0F9A, 0x0F9B, 0x0F9D, 0x0FA0, 0x0FA1, 0x0FA4, 0x0FA5,
+    0x0FA6, 0x0FAC, 0x0FAD, 0x0FAE, 0x0FAF, 0x0FB0, 0x0FB2, 0x0FB6, 0x0FB8, 0x0FBA, 0x0FBB, 0x0FBD,
+    0x0FBE, 0x0FC0, 0x0FC1, 0x0FC2, 0x0FC4, 0x0FC9, 0x0FCA, 0x0FCD, 0x0FCF, 0x0FD1, 0x0FD2, 0x0FD3,
+    0x0FD4, 0x0FD5, 0x0FD9, 0x0FDA, 0x0FDB, 0x0FDD, 0x0FDF, 0x0FE0, 0x0FE1, 0x0FE5, 0x0FE9, 0x0FEA,
+    0x0FEB, 0x0FEC, 0x0FEF, 0x0FF0, 0x0FF1, 0x0FF2, 0x0FF3, 0x0FF4, 0x0FF6, 0x0FF7, 0x0FFF, 0x1000,
+    0x100A, 0x100D, 0x100E, 0x100F, 0x1010, 0x1012, 0x1015, 0x1017, 0x1019, 0x101A, 0x1022, 0x1023,
+    0x1027, 0x1028, 0x1029, 0x102A, 0x102B, 0x102D, 0x1032, 0x1035, 0x103C, 0x1043, 0x1046, 0x1048,
+    0x1049, 0x104A, 0x104B, 0x104C, 0x104D, 0x104E, 0x1058, 0x1059, 0x1061, 0x1062, 0x106B, 0x106C,
+    0x106D, 0x1075, 0x1078, 0x107B, 0x1085, 0x1088, 0x108C, 0x108D, 0x108F, 0x1090, 0x109A, 0x109B,
+    0x10A1, 0x10A2, 0x10A3, 0x10A7, 0x10A9, 0x10AB, 0x10AC, 0x10B1, 0x10B3, 0x10BD, 0x10BE, 0x10C1,
+    0x10C4, 0x10C5, 0x10C9, 0x10CC, 0x10D1, 0x10D2, 0x10D3, 0x10D4, 0x10E0, 0x10E3, 0x10EB, 0x10ED,
+    0x10F0, 0x10F4, 0x10F7, 0x10F8, 0x10FA, 0x110B, 0x110D, 0x110F, 0x1111, 0x1112, 0x1114, 0x1116,
+    0x111A, 0x111C, 0x111E, 0x1123, 0x1125, 0x1128, 0x1129, 0x112A, 0x112B, 0x112E, 0x1134, 0x1136,
+    0x1138, 0x1142, 0x1144, 0x1145, 0x1148, 0x114A, 0x114C, 0x114F, 0x1154, 0x115C, 0x115D, 0x115E,
+    0x115F, 0x1162, 0x1168, 0x116B, 0x1177, 0x117B, 0x1185, 0x1186, 0x1190, 0x119C, 0x11A2, 0x11A3,
+    0x11A9, 0x11AA, 0x11AE, 0x11AF, 0x11B2, 0x11B9, 0x11BE, 0x11C0, 0x11C8, 0x11CF, 0x11D0, 0x11D5,
+    0x11D8, 0x11E3, 0x11EA, 0x11EC, 0x11EF, 0x11F2, 0x11FB, 0x11FC, 0x1201, 0x1209, 0x1211, 0x1212,
+    0x1285, 0x1287, 0x1291, 0x1295, 0x1296, 0x1299, 0x129D, 0x129F, 0x12A0, 0x12A2, 0x12AE, 0x12B6,
+    0x12B8, 0x12C6, 0x12C7, 0x12CB, 0x12CC, 0x12CD, 0x12CE, 0x12D2, 0x12D4, 0x12D7, 0x12DC, 0x12E4,
+    0x12EC, 0x12ED, 0x12F4, 0x12F5, 0x12F7, 0x12FC, 0x12FD, 0x12FE, 0x1300, 0x1301, 0x1302, 0x1307,
+    0x130F, 0x1312, 0x1315, 0x1316, 0x1319, 0x131C, 0x1323, 0x1328, 0x1329, 0x132A, 0x132B, 0x132E,
+    0x132F, 0x1330, 0x1331, 0x1334, 0x1335, 0x1336, 0x1338, 0x133C, 0x133E, 0x1341, 0x1342, 0x1347,
+    0x134A, 0x134B, 0x134E, 0x1350, 0x1353, 0x1358, 0x1359, 0x135A, 0x135B, 0x135D, 0x1360, 0x1362,
+    0x1363, 0x1364, 0x1366, 0x1368, 0x136A, 0x136E, 0x136F, 0x1372, 0x1373, 0x1374, 0x1375, 0x1376,
+    0x1378, 0x137C, 0x137D, 0x1381, 0x1384, 0x1385, 0x1388, 0x138A, 0x138D, 0x138F, 0x1390, 0x1391,
+    0x1393, 0x1394, 0x1396, 0x1397, 0x1398, 0x1399, 0x139B, 0x139D, 0x139F, 0x13A2, 0x13A3, 0x13A6,
+    0x13AA, 0x13AB, 0x13AD, 0x13B3, 0x13B4, 0x13B6, 0x13B8, 0x13BA, 0x13BB, 0x13BE, 0x13BF, 0x13C1,
+    0x13C2, 0x13C3, 0x13C4, 0x13C5, 0x13C6, 0x13CA, 0x13CB, 0x13CC, 0x13CD, 0x13CF, 0x13D1, 0x13D3,
+    0x13D6, 0x13DA, 0x13DC, 0x13DF, 0x13E4, 0x13E6, 0x13E8, 0x13EA, 0x13EB, 0x13ED, 0x13EE, 0x13F3,
+    0x13F4, 0x13F5, 0x13F6, 0x13F8, 0x13FA, 0x13FC, 0x13FE, 0x1404, 0x1407, 0x140A, 0x140E, 0x1412,
+    0x1413, 0x1416, 0x1417, 0x1419, 0x141A, 0x141E, 0x141F, 0x1421, 0x1422, 0x1425, 0x1426, 0x1427,
+    0x142F, 0x1433, 0x1434, 0x1435, 0x1437, 0x1438, 0x143A, 0x143C, 0x143E, 0x143F, 0x1442, 0x1443,
+    0x1444, 0x1445, 0x1447, 0x1448, 0x144B, 0x144C, 0x1454, 0x145C, 0x1460, 0x1461, 0x1462, 0x1464,
+    0x1467, 0x1472, 0x1474, 0x1483, 0x1486, 0x1487, 0x1489, 0x148E, 0x1495, 0x1498, 0x149A, 0x149C,
+    0x149E, 0x149F, 0x14A1, 0x14A5, 0x14A6, 0x14A7, 0x14A8, 0x14AA, 0x14AB, 0x14AE, 0x14B2, 0x14B5,
+    0x14B8, 0x14B9, 0x14BA, 0x14BB, 0x14BD, 0x14C0, 0x14C1, 0x14C2, 0x14CC, 0x14D0, 0x14D8, 0x14DB,
+    0x14DD, 0x14E2, 0x14E5, 0x14EF, 0x14F2, 0x14F6, 0x14F7, 0x14F8, 0x14F9, 0x14FA, 0x14FC, 0x1503,
+    0x150A, 0x150D, 0x150E, 0x1518, 0x151B, 0x151C, 0x151F, 0x1522, 0x1528, 0x152B, 0x152C, 0x152F,
+    0x1530, 0x1531, 0x1538, 0x153B, 0x153D, 0x154A, 0x154E, 0x1553, 0x155E, 0x1560, 0x1562, 0x1568,
+    0x1569, 0x156F, 0x1576, 0x1581, 0x1582, 0x158E, 0x15AF, 0x15B7, 0x15BE, 0x15CF, 0x15D2, 0x15E4,
+    0x15E6, 0x15F4, 0x1600, 0x1601, 0x1605, 0x1606, 0x1609, 0x160A, 0x160E, 0x1611, 0x1614, 0x1617,
+    0x1618, 0x161A, 0x161C, 0x1629, 0x162A, 0x162B, 0x162E, 0x162F, 0x1631, 0x1635, 0x1636, 0x1643,
+    0x1644, 0x1647, 0x164B, 0x164C, 0x164D, 0x164E, 0x1655, 0x1656, 0x1659, 0x1664, 0x1669, 0x166B,
+    0x166C, 0x166D, 0x1678, 0x1680, 0x1684, 0x1692, 0x1696, 0x1697, 0x1698, 0x16A5, 0x16A6, 0x16AB,
+    0x16AC, 0x16B1, 0x16B4, 0x16B8, 0x16B9, 0x16BB, 0x16C1, 0x16C6, 0x16CA, 0x16CE, 0x16D1, 0x16D6,
+    0x16DD, 0x16E0, 0x16E6, 0x16E7, 0x16EA, 0x16F3, 0x16FD, 0x1702, 0x170C, 0x1710, 0x1711, 0x1717,
+    0x1721, 0x172D, 0x1743, 0x174D, 0x174E, 0x174F, 0x1752, 0x1755, 0x1756, 0x1757, 0x1758, 0x175F,
+    0x1762, 0x1766, 0x1769, 0x176B, 0x176C, 0x176D, 0x176E, 0x176F, 0x1772, 0x1773, 0x1777, 0x1779,
+    0x177B, 0x177E, 0x1781, 0x1783, 0x1788, 0x1789, 0x178A, 0x178C, 0x1798, 0x17A4, 0x17A5, 0x17A8,
+    0x17A9, 0x17B0, 0x17B3, 0x17B7, 0x17B8, 0x17B9, 0x17BD, 0x17BE, 0x17C0, 0x1820, 0x1823, 0x1824,
+    0x1825, 0x1826, 0x1827, 0x1828, 0x1829, 0x182A, 0x182B, 0x182C, 0x182D, 0x182F, 0x1831, 0x1833,
+    0x183B, 0x183D, 0x183F, 0x1840, 0x1841, 0x1842, 0x1843, 0x1845, 0x1846, 0x1847, 0x1848, 0x1849,
+    0x184A, 0x184B, 0x184C, 0x184D, 0x184E, 0x184F, 0x1850, 0x1851, 0x1852, 0x1853, 0x1854, 0x1855,
+    0x1856, 0x1857, 0x1858, 0x1859, 0x185B, 0x185D, 0x185E, 0x185F, 0x1860, 0x1861, 0x1862, 0x1863,
+    0x1865, 0x1866, 0x1867, 0x1868, 0x186A, 0x186B, 0x186C, 0x186D, 0x186E, 0x186F, 0x1870, 0x1871,
+    0x1872, 0x1873, 0x1874, 0x1875, 0x1876, 0x1877, 0x1878, 0x1879, 0x187A, 0x187C, 0x187D, 0x187E,
+    0x18DE, 0x18E8, 0x18EE, 0x1902, 0x1916, 0x1920, 0x1921, 0x1922, 0x192C, 0x192E, 0x193A, 0x193C,
+    0x199C, 0x199F, 0x19A0, 0x19F9, 0x19FA, 0x1A5A, 0x1AAD, 0x1AB8, 0x1B18, 0x1B6E, 0x1B76, 0x1BD6,
+    0x1BE7, 0x1BEE, 0x1BF6, 0x1C07, 0x1C0E, 0x1C15, 0x1C17, 0x1C19, 0x1C1B, 0x1C1D, 0x1C21, 0x1C23,
+    0x1C25, 0x1C27, 0x1C28, 0x1C29, 0x1C2B, 0x1C34, 0x1C94, 0x1C9A, 0x1C9B, 0x1CB5, 0x1CC4, 0x1CCA,
+    0x1CCB, 0x1CE5, 0x1CF2, 0x1CF4, 0x1CF5, 0x1CF6, 0x1CF7, 0x1CF8, 0x1CF9, 0x1CFA, 0x1CFB, 0x1CFF,
+    0x1D00, 0x1D01, 0x1D02, 0x1D03, 0x1D05, 0x1D06, 0x1D2A, 0x1D39, 0x1D3C, 0x1D3E, 0x1D42, 0x1D43,
+    0x1D44, 0x1D45, 0x1D47, 0x1D52, 0x1D53, 0x1D54, 0x1D55, 0x1D56, 0x1D57, 0x1D58, 0x1D59, 0x1D5A,
+    0x1D5B, 0x1D5C, 0x1D5D, 0x1D5E, 0x1D5F, 0x1D60, 0x1D61, 0x1D62, 0x1D63, 0x1D64, 0x1D65, 0x1D66,
+    0x1D67, 0x1D68, 0x1D69, 0x1D6A, 0x1D6B, 0x1D6C, 0x1D6D, 0x1D6E, 0x1D6F, 0x1D70, 0x1D71, 0x1D72,
+    0x1D76, 0x1D9B, 0x1DB0, 0x1DB9, 0x1DBA, 0x1DBC, 0x1DBF, 0x1DC0, 0x1DC1, 0x1DC2, 0x1DC4, 0x1DC5,
+    0x1DC6, 0x1DC7, 0x1DC8, 0x1DC9, 0x1DCA, 0x1DCB, 0x1DCC, 0x1DCD, 0x1DD0, 0x1DD1, 0x1DD3, 0x1DD5,
+    0x1DD6, 0x1DD8, 0x1DE2, 0x1DE6, 0x1DF4, 0x1DF8, 0x1DF9, 0x1E05, 0x1E06, 0x1E13, 0x1E5F, 0x1E6E,
+    0x1E70, 0x1E71, 0x1E77, 0x1E79, 0x1E7B, 0x1E7C, 0x1E80, 0x1E81, 0x1E87, 0x1E8A, 0x1E8E, 0x1E8F,
+    0x1E92, 0x1E94, 0x1E96, 0x1E99, 0x1E9A, 0x1E9C, 0x1E9E, 0x1EA0, 0x1EA4, 0x1EB1, 0x1EB3, 0x1EB4,
+    0x1EB8, 0x1EC0, 0x1ECB, 0x1ECC, 0x1ECE, 0x1F2C, 0x1F38, 0x1F3D, 0x1F3F, 0x1F40, 0x1F41, 0x1F43,
+    0x1F45, 0x1F49, 0x1F4D, 0x1F50, 0x1F52, 0x1F58, 0x1F59, 0x1F5B, 0x1F5C, 0x1F5F, 0x1F61, 0x1F65,
+    0x1F66, 0x1F6B, 0x1F6D, 0x1F6E, 0x1F75, 0x1F79, 0x1F7A, 0x1F7E, 0x1F7F, 0x1F80, 0x1F83, 0x1F87,
+    0x1F8C, 0x1FEA, 0x1FF5, 0x1FF6, 0x1FF8, 0x1FFC, 0x2005, 0x200B, 0x200D, 0x200E, 0x200F, 0x2010,
+    0x2011, 0x2012, 0x2014, 0x2019, 0x201D, 0x2030, 0x2031, 0x2032, 0x2033, 0x2040, 0x2042, 0x2047,
+    0x204A, 0x20A8, 0x20AB, 0x20AC, 0x20AD, 0x20B0, 0x20B3, 0x20B5, 0x20B6, 0x20C1, 0x20C2, 0x20CA,
+    0x20DA, 0x20F1, 0x20FA, 0x20FB, 0x20FC, 0x20FD, 0x20FE, 0x2105, 0x2108, 0x2166, 0x2167, 0x2168,
+    0x216F, 0x2173, 0x217B, 0x217C, 0x217D, 0x217E, 0x2182, 0x2183, 0x2185, 0x218A, 0x218C, 0x218D,
+    0x218E, 0x218F, 0x2190, 0x2191, 0x2193, 0x2194, 0x2198, 0x219A, 0x219C, 0x219D, 0x21A1, 0x21A5,
+    0x21AD, 0x21B0, 0x21B7, 0x21B8, 0x21BB, 0x21C0, 0x21C3, 0x21C5, 0x21C6, 0x2224, 0x2227, 0x222A,
+    0x222B, 0x222C, 0x222D, 0x222E, 0x2230, 0x2231, 0x2232, 0x2237, 0x2238, 0x223A, 0x223C, 0x223F,
+    0x2240, 0x2242, 0x2246, 0x2247, 0x2248, 0x224A, 0x224C, 0x224F, 0x2250, 0x2251, 0x2255, 0x2257,
+    0x225B, 0x225D, 0x225F, 0x2260, 0x2261, 0x2265, 0x2266, 0x2269, 0x226D, 0x226E, 0x226F, 0x2272,
+    0x2273, 0x2274, 0x2276, 0x2277, 0x2279, 0x227B, 0x227D, 0x2280, 0x2281, 0x2283, 0x2284, 0x22E2,
+    0x22E9, 0x22EF, 0x22F0, 0x22F5, 0x22F6, 0x22FA, 0x2301, 0x2304, 0x230C, 0x230E, 0x230F, 0x2312,
+    0x2313, 0x2314, 0x2316, 0x2318, 0x231A, 0x231B, 0x231D, 0x231F, 0x2329, 0x2332, 0x2334, 0x2335,
+    0x233F, 0x2341, 0x2342, 0x2343, 0x2344, 0x2345, 0x2346, 0x2347, 0x2348, 0x2349, 0x234A, 0x234B,
+    0x234C, 0x234D, 0x234E, 0x234F, 0x2350, 0x2351, 0x2352, 0x2353, 0x2354, 0x2355, 0x2356, 0x2357,
+    0x2358, 0x2359, 0x235A, 0x235B, 0x235C, 0x235D, 0x235E, 0x235F, 0x2360, 0x2361, 0x2362, 0x2363,
+    0x2364, 0x2365, 0x2366, 0x2367, 0x2368, 0x2369, 0x236A, 0x236B, 0x236C, 0x236D, 0x236E, 0x236F,
+    0x2370, 0x2371, 0x2372, 0x2373, 0x2374, 0x2375, 0x2376, 0x2377, 0x2378, 0x2379, 0x237A, 0x237B,
+    0x237C, 0x237D, 0x237E, 0x237F, 0x2380, 0x2381, 0x2382, 0x2383, 0x2384, 0x2385, 0x2386, 0x2387,
+    0x2388, 0x2389, 0x238A, 0x238B, 0x238C, 0x238D, 0x238E, 0x238F, 0x2390, 0x2391, 0x2392, 0x2393,
+    0x2394, 0x2395, 0x2396, 0x2397, 0x2398, 0x2399, 0x239A, 0x239B, 0x239C, 0x239D, 0x239E, 0x239F,
+    0x23A0, 0x23A1, 0x23A2, 0x23A3, 0x23A5, 0x23A6, 0x23A7, 0x23A9, 0x23AC, 0x23B1, 0x23B9, 0x23BA,
+    0x23BB, 0x23BE, 0x23C0, 0x23C1, 0x23C3, 0x23C5, 0x23C7, 0x23C8, 0x23C9, 0x23D4, 0x23D8, 0x23D9,
+    0x23DC, 0x23DD, 0x23E0, 0x23E1, 0x23E6, 0x23E7, 0x23E8, 0x23EB, 0x23EC, 0x23EF, 0x23F7, 0x23FF,
+    0x2400, 0x2401, 0x2402, 0x2403, 0x2404, 0x2405, 0x2406, 0x2407, 0x2408, 0x2409, 0x240A, 0x240B,
+    0x240C, 0x240D, 0x240E, 0x240F, 0x2410, 0x2411, 0x2412, 0x2413, 0x2414, 0x2415, 0x2416, 0x2417,
+    0x2418, 0x2419, 0x241A, 0x241B, 0x241C, 0x241D, 0x241E, 0x241F, 0x2420, 0x2421, 0x2422, 0x2423,
+    0x2424, 0x2425, 0x2426, 0x2427, 0x2428, 0x2429, 0x242A, 0x242B, 0x242C, 0x242D, 0x242E, 0x242F,
+    0x2430, 0x2431, 0x2432, 0x2433, 0x2434, 0x2435, 0x2436, 0x2437, 0x2438, 0x2439, 0x243A, 0x243B,
+    0x243C, 0x243D, 0x243E, 0x243F, 0x2440, 0x2441, 0x2442, 0x2443, 0x2444, 0x2445, 0x2447, 0x2448,
+    0x2449, 0x244A, 0x244B, 0x244C, 0x244D, 0x244E, 0x244F, 0x2450, 0x2451, 0x2452, 0x2453, 0x2454,
+    0x2455, 0x2456, 0x2457, 0x2458, 0x2459, 0x245A, 0x245B, 0x245C, 0x245D, 0x245E, 0x2462, 0x2463,
+    0x2464, 0x2466, 0x2472, 0x2475, 0x2478, 0x247E, 0x2480, 0x248C, 0x248D, 0x248F, 0x2490, 0x2491,
+    0x2492, 0x2495, 0x2497, 0x2498, 0x249D, 0x249E, 0x249F, 0x24AC, 0x24B5, 0x24B8, 0x24BD, 0x24BE,
+    0x24BF, 0x24C0, 0x24C1, 0x24C2, 0x24C3, 0x24C4, 0x24C5, 0x24C6, 0x24C7, 0x24C8, 0x24C9, 0x24CA,
+    0x24CB, 0x24CC, 0x24CD, 0x24CE, 0x24CF, 0x24D0, 0x24D1, 0x24D2, 0x24D3, 0x24D4, 0x24D5, 0x24D6,
+    0x24D7, 0x24D8, 0x24D9, 0x24DA, 0x24DB, 0x24DC, 0x24DD, 0x24DE, 0x24DF, 0x24E0, 0x24E1, 0x24E2,
+    0x24E3, 0x24E4, 0x24E5, 0x24E6, 0x24E7, 0x24E8, 0x24E9, 0x24EA, 0x24EB, 0x24EC, 0x24ED, 0x24EE,
+    0x24EF, 0x24F0, 0x24F1, 0x24F2, 0x24F3, 0x24F4, 0x24F5, 0x24F6, 0x24F7, 0x24F8, 0x24F9, 0x24FA,
+    0x24FB, 0x24FC, 0x24FD, 0x24FE, 0x24FF, 0x2500, 0x2501, 0x2502, 0x2503, 0x2504, 0x2505, 0x2506,
+    0x2507, 0x2508, 0x2509, 0x250A, 0x250B, 0x250C, 0x250D, 0x250E, 0x250F, 0x2510, 0x2511, 0x2512,
+    0x2513, 0x2514, 0x2515, 0x2516, 0x2517, 0x2518, 0x2519, 0x251A, 0x251B, 0x251C, 0x251D, 0x251E,
+    0x251F, 0x2523, 0x2525, 0x2526, 0x252A, 0x2530, 0x2532, 0x2535, 0x2536, 0x2537, 0x2538, 0x2539,
+    0x253A, 0x253D, 0x253E, 0x253F, 0x2541, 0x2543, 0x2546, 0x2548, 0x2549, 0x254A, 0x254E, 0x254F,
+    0x2553, 0x2554, 0x2555, 0x2556, 0x2558, 0x255D, 0x2569, 0x2571, 0x2575, 0x257C, 0x257D, 0x257E,
+    0x257F, 0x2580, 0x2581, 0x2582, 0x2583, 0x2584, 0x2585, 0x2586, 0x2587, 0x2588, 0x2589, 0x258A,
+    0x258B, 0x258C, 0x258D, 0x258E, 0x258F, 0x2590, 0x2591, 0x2592, 0x2593, 0x2594, 0x2595, 0x2596,
+    0x2597, 0x2598, 0x2599, 0x259A, 0x259B, 0x259C, 0x259D, 0x259E, 0x259F, 0x25A0, 0x25A1, 0x25A2,
+    0x25A3, 0x25A4, 0x25A5, 0x25A6, 0x25A7, 0x25A8, 0x25A9, 0x25AA, 0x25AB, 0x25AC, 0x25AD, 0x25AE,
+    0x25AF, 0x25B0, 0x25B1, 0x25B2, 0x25B3, 0x25B4, 0x25B5, 0x25B6, 0x25B7, 0x25B8, 0x25B9, 0x25BA,
+    0x25BB, 0x25BC, 0x25BD, 0x25BE, 0x25BF, 0x25C0, 0x25C1, 0x25C2, 0x25C3, 0x25C4, 0x25C5, 0x25C6,
+    0x25C7, 0x25C8, 0x25C9, 0x25CA, 0x25CB, 0x25CC, 0x25CD, 0x25CE, 0x25CF, 0x25D0, 0x25D1, 0x25D2,
+    0x25D3, 0x25D4, 0x25D5, 0x25D6, 0x25D7, 0x25D8, 0x25D9, 0x25DA, 0x25DD, 0x25DE, 0x25E0, 0x25E2,
+    0x25E3, 0x25E6, 0x25E7, 0x25E9, 0x25EA, 0x25EB, 0x25EC, 0x25ED, 0x25F5, 0x25F9, 0x25FD, 0x25FF,
+    0x2602, 0x2605, 0x2609, 0x260C, 0x260F, 0x261D, 0x2620, 0x2625, 0x2626, 0x2628, 0x262A, 0x2630,
+    0x2633, 0x263A, 0x263B, 0x263C, 0x263D, 0x263E, 0x263F, 0x2640, 0x2641, 0x2642, 0x2643, 0x2644,
+    0x2645, 0x2646, 0x2647, 0x2648, 0x2649, 0x264A, 0x264B, 0x264C, 0x264D, 0x264E, 0x264F, 0x2650,
+    0x2651, 0x2652, 0x2653, 0x2654, 0x2655, 0x2656, 0x2657, 0x2658, 0x2659, 0x265A, 0x265B, 0x265C,
+    0x265D, 0x265E, 0x265F, 0x2660, 0x2661, 0x2662, 0x2663, 0x2664, 0x2665, 0x2666, 0x2667, 0x2668,
+    0x2669, 0x266A, 0x266B, 0x266C, 0x266D, 0x266E, 0x266F, 0x2670, 0x2671, 0x2672, 0x2673, 0x2674,
+    0x2675, 0x2676, 0x2677, 0x2678, 0x2679, 0x267A, 0x267B, 0x267C, 0x267D, 0x267E, 0x267F, 0x2680,
+    0x2681, 0x2682, 0x2683, 0x2684, 0x2685, 0x2686, 0x2687, 0x2688, 0x2689, 0x268A, 0x268B, 0x268C,
+    0x268D, 0x268E, 0x268F, 0x2690, 0x2691, 0x2692, 0x2693, 0x2694, 0x2695, 0x2696, 0x2697, 0x2698,
+    0x269E, 0x26A8, 0x26AD, 0x26BC, 0x26C1, 0x26C2, 0x26C3, 0x26C7, 0x26C8, 0x26D1, 0x26D3, 0x26D5,
+    0x26D6, 0x26D8, 0x26D9, 0x26DD, 0x26DE, 0x26E5, 0x26E6, 0x26EB, 0x26ED, 0x26F1, 0x26F5, 0x26F8,
+    0x26F9, 0x26FA, 0x26FB, 0x26FC, 0x26FD, 0x26FE, 0x26FF, 0x2700, 0x2701, 0x2702, 0x2703, 0x2704,
+    0x2705, 0x2706, 0x2707, 0x2708, 0x2709, 0x270A, 0x270B, 0x270C, 0x270D, 0x270E, 0x270F, 0x2710,
+    0x2711, 0x2712, 0x2713, 0x2714, 0x2715, 0x2716, 0x2717, 0x2718, 0x2719, 0x271A, 0x271B, 0x271C,
+    0x271D, 0x271E, 0x271F, 0x2720, 0x2721, 0x2722, 0x2723, 0x2724, 0x2725, 0x2726, 0x2727, 0x2728,
+    0x2729, 0x272A, 0x272B, 0x272C, 0x272D, 0x272E, 0x272F, 0x2730, 0x2731, 0x2732, 0x2733, 0x2734,
+    0x2735, 0x2736, 0x2737, 0x2738, 0x2739, 0x273A, 0x273B, 0x273C, 0x273D, 0x273E, 0x273F, 0x2740,
+    0x2741, 0x2742, 0x2743, 0x2744, 0x2745, 0x2746, 0x2747, 0x2748, 0x2749, 0x274A, 0x274B, 0x274C,
+    0x274D, 0x274E, 0x274F, 0x2750, 0x2751, 0x2752, 0x2753, 0x2754, 0x2755, 0x2756, 0x275D, 0x2769,
+    0x2774, 0x2779, 0x277A, 0x277B, 0x277C, 0x277E, 0x2780, 0x2781, 0x2782, 0x2785, 0x2787, 0x2789,
+    0x278F, 0x2792, 0x2793, 0x2794, 0x2795, 0x2796, 0x2797, 0x279E, 0x27A0, 0x27A1, 0x27A3, 0x27A4,
+    0x27A6, 0x27AA, 0x27AB, 0x27B0, 0x27B2, 0x27B4, 0x27B6, 0x27B7, 0x27B8, 0x27B9, 0x27BA, 0x27BB,
+    0x27BC, 0x27BD, 0x27BE, 0x27BF, 0x27C0, 0x27C1, 0x27C2, 0x27C3, 0x27C4, 0x27C5, 0x27C6, 0x27C7,
+    0x27C8, 0x27C9, 0x27CA, 0x27CB, 0x27CC, 0x27CD, 0x27CE, 0x27CF, 0x27D0, 0x27D1, 0x27D2, 0x27D3,
+    0x27D4, 0x27D5, 0x27D6, 0x27D7, 0x27D8, 0x27D9, 0x27DA, 0x27DB, 0x27DC, 0x27DD, 0x27DE, 0x27DF,
+    0x27E0, 0x27E1, 0x27E2, 0x27E3, 0x27E4, 0x27E5, 0x27E6, 0x27E7, 0x27E8, 0x27E9, 0x27EA, 0x27EB,
+    0x27EC, 0x27ED, 0x27EE, 0x27EF, 0x27F0, 0x27F1, 0x27F2, 0x27F3, 0x27F4, 0x27F5, 0x27F6, 0x27F7,
+    0x27F8, 0x27F9, 0x27FA, 0x27FB, 0x27FC, 0x27FD, 0x27FE, 0x27FF, 0x2800, 0x2801, 0x2802, 0x2803,
+    0x2804, 0x2805, 0x2806, 0x2807, 0x2808, 0x2809, 0x280A, 0x280B, 0x280C, 0x280D, 0x280E, 0x280F,
+    0x2810, 0x2811, 0x2812, 0x2813, 0x2814, 0x2815, 0x2816, 0x2818, 0x2827, 0x282A, 0x282B, 0x282C,
+    0x282D, 0x2833, 0x283D, 0x2842, 0x285A, 0x285D, 0x285E, 0x2862, 0x2863, 0x2864, 0x2865, 0x2869,
+    0x286C, 0x286E, 0x2871, 0x2872, 0x2874, 0x2875, 0x2876, 0x2877, 0x2878, 0x2879, 0x287A, 0x287B,
+    0x287C, 0x287D, 0x287E, 0x287F, 0x2880, 0x2881, 0x2882, 0x2883, 0x2884, 0x2885, 0x2886, 0x2887,
+    0x2888, 0x2889, 0x288A, 0x288B, 0x288C, 0x288D, 0x288E, 0x288F, 0x2890, 0x2891, 0x2892, 0x2893,
+    0x2894, 0x2895, 0x2896, 0x2897, 0x2898, 0x2899, 0x289A, 0x289B, 0x289C, 0x289D, 0x289E, 0x289F,
+    0x28A0, 0x28A1, 0x28A2, 0x28A3, 0x28A4, 0x28A5, 0x28A6, 0x28A7, 0x28A8, 0x28A9, 0x28AA, 0x28AB,
+    0x28AC, 0x28AD, 0x28AE, 0x28AF, 0x28B0, 0x28B1, 0x28B2, 0x28B3, 0x28B4, 0x28B5, 0x28B6, 0x28B7,
+    0x28B8, 0x28B9, 0x28BA, 0x28BB, 0x28BC, 0x28BD, 0x28BE, 0x28BF, 0x28C0, 0x28C1, 0x28C2, 0x28C3,
+    0x28C4, 0x28C5, 0x28C6, 0x28C7, 0x28C8, 0x28C9, 0x28CA, 0x28CB, 0x28CC, 0x28CD, 0x28CE, 0x28CF,
+    0x28D0, 0x28D1, 0x28D2, 0x28D4, 0x28D5, 0x28D8, 0x28DD, 0x28E8, 0x28F3, 0x28FD, 0x28FF, 0x2903,
+    0x2905, 0x2906, 0x290C, 0x290D, 0x2916, 0x2918, 0x2919, 0x291C, 0x291D, 0x291E, 0x291F, 0x2922,
+    0x2923, 0x2925, 0x2928, 0x2929, 0x292B, 0x292C, 0x292F, 0x2930, 0x2931, 0x2932, 0x2933, 0x2934,
+    0x2935, 0x2936, 0x2937, 0x2938, 0x2939, 0x293A, 0x293B, 0x293C, 0x293D, 0x293E, 0x293F, 0x2940,
+    0x2941, 0x2942, 0x2943, 0x2944, 0x2945, 0x2946, 0x2947, 0x2948, 0x2949, 0x294A, 0x294B, 0x294C,
+    0x294D, 0x294E, 0x294F, 0x2950, 0x2951, 0x2952, 0x2953, 0x2954, 0x2955, 0x2956, 0x2957, 0x2958,
+    0x2959, 0x295A, 0x295B, 0x295C, 0x295D, 0x295E, 0x295F, 0x2960, 0x2961, 0x2962, 0x2963, 0x2964,
+    0x2965, 0x2966, 0x2967, 0x2968, 0x2969, 0x296A, 0x296B, 0x296C, 0x296D, 0x296E, 0x296F, 0x2970,
+    0x2971, 0x2972, 0x2973, 0x2974, 0x2975, 0x2976, 0x2977, 0x2978, 0x2979, 0x297A, 0x297B, 0x297C,
+    0x297D, 0x297E, 0x297F, 0x2980, 0x2981, 0x2982, 0x2983, 0x2984, 0x2985, 0x2986, 0x2987, 0x2988,
+    0x2989, 0x298A, 0x298B, 0x298C, 0x298D, 0x298E, 0x298F, 0x2990, 0x2992, 0x2993, 0x2997, 0x2998,
+    0x2999, 0x299A, 0x29A0, 0x29A1, 0x29A2, 0x29A3, 0x29A5, 0x29A6, 0x29A7, 0x29A8, 0x29A9, 0x29AA,
+    0x29AC, 0x29AD, 0x29B8, 0x29BA, 0x29BC, 0x29BD, 0x29BE, 0x29BF, 0x29C1, 0x29C2, 0x29C6, 0x29CD,
+    0x29CF, 0x29D2, 0x29D3, 0x29D7, 0x29DA, 0x29DD, 0x29E0, 0x29E2, 0x29E5, 0x29ED, 0x29F0, 0x29F1,
+    0x29F2, 0x29F3, 0x29F4, 0x29F5, 0x29F6, 0x29F7, 0x29F8, 0x29F9, 0x29FA, 0x29FB, 0x29FC, 0x29FD,
+    0x29FE, 0x29FF, 0x2A00, 0x2A01, 0x2A02, 0x2A03, 0x2A04, 0x2A05, 0x2A06, 0x2A07, 0x2A08, 0x2A09,
+    0x2A0A, 0x2A0B, 0x2A0C, 0x2A0D, 0x2A0E, 0x2A0F, 0x2A10, 0x2A11, 0x2A12, 0x2A13, 0x2A14, 0x2A15,
+    0x2A16, 0x2A17, 0x2A18, 0x2A19, 0x2A1A, 0x2A1B, 0x2A1C, 0x2A1D, 0x2A1E, 0x2A1F, 0x2A20, 0x2A21,
+    0x2A22, 0x2A23, 0x2A24, 0x2A25, 0x2A26, 0x2A27, 0x2A28, 0x2A29, 0x2A2A, 0x2A2B, 0x2A2C, 0x2A2D,
+    0x2A2E, 0x2A2F, 0x2A30, 0x2A31, 0x2A32, 0x2A33, 0x2A34, 0x2A35, 0x2A36, 0x2A37, 0x2A38, 0x2A39,
+    0x2A3A, 0x2A3B, 0x2A3C, 0x2A3D, 0x2A3E, 0x2A3F, 0x2A40, 0x2A41, 0x2A42, 0x2A43, 0x2A44, 0x2A45,
+    0x2A46, 0x2A47, 0x2A48, 0x2A49, 0x2A4A, 0x2A4B, 0x2A4C, 0x2A4D, 0x2A4E, 0x2A4F, 0x2A53, 0x2A57,
+    0x2A58, 0x2A5D, 0x2A5F, 0x2A62, 0x2A65, 0x2A68, 0x2A6B, 0x2A6D, 0x2A72, 0x2A76, 0x2A77, 0x2A7F,
+    0x2A82, 0x2A84, 0x2A8A, 0x2A92, 0x2A98, 0x2A9A, 0x2AA7, 0x2AAD, 0x2AAE, 0x2AAF, 0x2AB0, 0x2AB1,
+    0x2AB2, 0x2AB3, 0x2AB4, 0x2AB5, 0x2AB6, 0x2AB7, 0x2AB8, 0x2AB9, 0x2ABA, 0x2ABB, 0x2ABC, 0x2ABD,
+    0x2ABE, 0x2ABF, 0x2AC0, 0x2AC1, 0x2AC2, 0x2AC3, 0x2AC4, 0x2AC5, 0x2AC6, 0x2AC7, 0x2AC8, 0x2AC9,
+    0x2ACA, 0x2ACB, 0x2ACC, 0x2ACD, 0x2ACE, 0x2ACF, 0x2AD0, 0x2AD1, 0x2AD2, 0x2AD3, 0x2AD4, 0x2AD5,
+    0x2AD6, 0x2AD7, 0x2AD8, 0x2AD9, 0x2ADA, 0x2ADB, 0x2ADC, 0x2ADD, 0x2ADE, 0x2ADF, 0x2AE0, 0x2AE1,
+    0x2AE2, 0x2AE3, 0x2AE4, 0x2AE5, 0x2AE6, 0x2AE7, 0x2AE8, 0x2AE9, 0x2AEA, 0x2AEB, 0x2AEC, 0x2AED,
+    0x2AEE, 0x2AEF, 0x2AF0, 0x2AF1, 0x2AF2, 0x2AF3, 0x2AF4, 0x2AF5, 0x2AF6, 0x2AF7, 0x2AF8, 0x2AF9,
+    0x2AFA, 0x2AFB, 0x2AFC, 0x2AFD, 0x2AFE, 0x2AFF, 0x2B00, 0x2B01, 0x2B02, 0x2B03, 0x2B04, 0x2B05,
+    0x2B06, 0x2B07, 0x2B08, 0x2B09, 0x2B0A, 0x2B0B, 0x2B0C, 0x2B16, 0x2B3B, 0x2B41, 0x2B4C, 0x2B53,
+    0x2B55, 0x2B56, 0x2B59, 0x2B5B, 0x2B5C, 0x2B60, 0x2B63, 0x2B65, 0x2B6A, 0x2B6C, 0x2B6D, 0x2B6E,
+    0x2B6F, 0x2B70, 0x2B71, 0x2B72, 0x2B73, 0x2B74, 0x2B75, 0x2B76, 0x2B77, 0x2B78, 0x2B79, 0x2B7A,
+    0x2B7B, 0x2B7C, 0x2B7D, 0x2B7E, 0x2B7F, 0x2B80, 0x2B81, 0x2B82, 0x2B83, 0x2B84, 0x2B85, 0x2B86,
+    0x2B87, 0x2B88, 0x2B89, 0x2B8A, 0x2B8B, 0x2B8C, 0x2B8D, 0x2B8E, 0x2B8F, 0x2B90, 0x2B91, 0x2B92,
+    0x2B93, 0x2B94, 0x2B95, 0x2B96, 0x2B97, 0x2B98, 0x2B99, 0x2B9A, 0x2B9B, 0x2B9C, 0x2B9D, 0x2B9E,
+    0x2B9F, 0x2BA0, 0x2BA1, 0x2BA2, 0x2BA3, 0x2BA4, 0x2BA5, 0x2BA6, 0x2BA7, 0x2BA8, 0x2BA9, 0x2BAA,
+    0x2BAB, 0x2BAC, 0x2BAD, 0x2BAE, 0x2BAF, 0x2BB0, 0x2BB1, 0x2BB2, 0x2BB3, 0x2BB4, 0x2BB5, 0x2BB6,
+    0x2BB7, 0x2BB8, 0x2BB9, 0x2BBA, 0x2BBB, 0x2BBC, 0x2BBD, 0x2BBE, 0x2BBF, 0x2BC0, 0x2BC1, 0x2BC2,
+    0x2BC3, 0x2BC4, 0x2BC5, 0x2BC6, 0x2BC7, 0x2BC8, 0x2BC9, 0x2BCA, 0x2BCC, 0x2BCF, 0x2BD0, 0x2BD1,
+    0x2BD2, 0x2BD9, 0x2BDA, 0x2BDC, 0x2BDE, 0x2BE5, 0x2BEB, 0x2BF3, 0x2BF5, 0x2C03, 0x2C18, 0x2C19,
+    0x2C1D, 0x2C20, 0x2C23, 0x2C2A, 0x2C2B, 0x2C2C, 0x2C2D, 0x2C2E, 0x2C2F, 0x2C30, 0x2C31, 0x2C32,
+    0x2C33, 0x2C34, 0x2C35, 0x2C36, 0x2C37, 0x2C38, 0x2C39, 0x2C3A, 0x2C3B, 0x2C3C, 0x2C3D, 0x2C3E,
+    0x2C3F, 0x2C40, 0x2C41, 0x2C42, 0x2C43, 0x2C44, 0x2C45, 0x2C46, 0x2C47, 0x2C48, 0x2C49, 0x2C4A,
+    0x2C4B, 0x2C4C, 0x2C4D, 0x2C4E, 0x2C4F, 0x2C50, 0x2C51, 0x2C52, 0x2C53, 0x2C54, 0x2C55, 0x2C56,
+    0x2C57, 0x2C58, 0x2C59, 0x2C5A, 0x2C5B, 0x2C5C, 0x2C5D, 0x2C5E, 0x2C5F, 0x2C60, 0x2C61, 0x2C62,
+    0x2C63, 0x2C64, 0x2C65, 0x2C66, 0x2C67, 0x2C68, 0x2C69, 0x2C6A, 0x2C6B, 0x2C6C, 0x2C6D, 0x2C6E,
+    0x2C6F, 0x2C70, 0x2C71, 0x2C72, 0x2C73, 0x2C74, 0x2C75, 0x2C76, 0x2C77, 0x2C78, 0x2C79, 0x2C7A,
+    0x2C7B, 0x2C7C, 0x2C7D, 0x2C7E, 0x2C7F, 0x2C80, 0x2C81, 0x2C82, 0x2C83, 0x2C84, 0x2C85, 0x2C86,
+    0x2C87, 0x2C88, 0x2CBF, 0x2CC7, 0x2CE8, 0x2CE9, 0x2CEA, 0x2CEB, 0x2CEC, 0x2CED, 0x2CEE, 0x2CEF,
+    0x2CF0, 0x2CF1, 0x2CF2, 0x2CF3, 0x2CF4, 0x2CF5, 0x2CF6, 0x2CF7, 0x2CF8, 0x2CF9, 0x2CFA, 0x2CFB,
+    0x2CFC, 0x2CFD, 0x2CFE, 0x2CFF, 0x2D00, 0x2D01, 0x2D02, 0x2D03, 0x2D04, 0x2D05, 0x2D06, 0x2D07,
+    0x2D08, 0x2D09, 0x2D0A, 0x2D0B, 0x2D0C, 0x2D0D, 0x2D0E, 0x2D0F, 0x2D10, 0x2D11, 0x2D12, 0x2D13,
+    0x2D14, 0x2D15, 0x2D16, 0x2D17, 0x2D18, 0x2D19, 0x2D1A, 0x2D1B, 0x2D1C, 0x2D1D, 0x2D1E, 0x2D1F,
+    0x2D20, 0x2D21, 0x2D22, 0x2D23, 0x2D24, 0x2D25, 0x2D26, 0x2D27, 0x2D28, 0x2D29, 0x2D2A, 0x2D2B,
+    0x2D2C, 0x2D2D, 0x2D2E, 0x2D2F, 0x2D30, 0x2D31, 0x2D32, 0x2D33, 0x2D34, 0x2D35, 0x2D36, 0x2D37,
+    0x2D38, 0x2D39, 0x2D3A, 0x2D3B, 0x2D3C, 0x2D3D, 0x2D3E, 0x2D3F, 0x2D40, 0x2D41, 0x2D42, 0x2D43,
+    0x2D44, 0x2D45, 0x2D46, 0x2D53, 0x2D5A, 0x2DA6, 0x2DA7, 0x2DA8, 0x2DA9, 0x2DAA, 0x2DAB, 0x2DAC,
+    0x2DAD, 0x2DAE, 0x2DAF, 0x2DB0, 0x2DB1, 0x2DB2, 0x2DB3, 0x2DB4, 0x2DB5, 0x2DB6, 0x2DB7, 0x2DB8,
+    0x2DB9, 0x2DBA, 0x2DBB, 0x2DBC, 0x2DBD, 0x2DBE, 0x2DBF, 0x2DC0, 0x2DC1, 0x2DC2, 0x2DC3, 0x2DC4,
+    0x2DC5, 0x2DC6, 0x2DC7, 0x2DC8, 0x2DC9, 0x2DCA, 0x2DCB, 0x2DCC, 0x2DCD, 0x2DCE, 0x2DCF, 0x2DD0,
+    0x2DD1, 0x2DD2, 0x2DD3, 0x2DD4, 0x2DD5, 0x2DD6, 0x2DD7, 0x2DD8, 0x2DD9, 0x2DDA, 0x2DDB, 0x2DDC,
+    0x2DDD, 0x2DDE, 0x2DDF, 0x2DE0, 0x2DE1, 0x2DE2, 0x2DE3, 0x2DE4, 0x2DE5, 0x2DE6, 0x2DE7, 0x2DE8,
+    0x2DE9, 0x2DEA, 0x2DEB, 0x2DEC, 0x2DED, 0x2DEE, 0x2DEF, 0x2DF0, 0x2DF1, 0x2DF2, 0x2DF3, 0x2DF4,
+    0x2DF5, 0x2DF6, 0x2DF7, 0x2DF8, 0x2DF9, 0x2DFA, 0x2DFB, 0x2DFC, 0x2DFD, 0x2DFE, 0x2DFF, 0x2E00,
+    0x2E01, 0x2E02, 0x2E03, 0x2E04, 0x2E44, 0x2E49, 0x2E4E, 0x2E64, 0x2E65, 0x2E66, 0x2E67, 0x2E68,
+    0x2E69, 0x2E6A, 0x2E6B, 0x2E6C, 0x2E6D, 0x2E6E, 0x2E6F, 0x2E70, 0x2E71, 0x2E72, 0x2E73, 0x2E74,
+    0x2E75, 0x2E76, 0x2E77, 0x2E78, 0x2E79, 0x2E7A, 0x2E7B, 0x2E7C, 0x2E7D, 0x2E7E, 0x2E7F, 0x2E80,
+    0x2E81, 0x2E82, 0x2E83, 0x2E84, 0x2E85, 0x2E86, 0x2E87, 0x2E88, 0x2E89, 0x2E8A, 0x2E8B, 0x2E8C,
+    0x2E8D, 0x2E8E, 0x2E8F, 0x2E90, 0x2E91, 0x2E92, 0x2E93, 0x2E94, 0x2E95, 0x2E96, 0x2E97, 0x2E98,
+    0x2E99, 0x2E9A, 0x2E9B, 0x2E9C, 0x2E9D, 0x2E9E, 0x2E9F, 0x2EA0, 0x2EA1, 0x2EA2, 0x2EA3, 0x2EA4,
+    0x2EA5, 0x2EA6, 0x2EA7, 0x2EA8, 0x2EA9, 0x2EAA, 0x2EAB, 0x2EAC, 0x2EAD, 0x2EAE, 0x2EAF, 0x2EB0,
+    0x2EB1, 0x2EB2, 0x2EB3, 0x2EB4, 0x2EB5, 0x2EB6, 0x2EB7, 0x2EB8, 0x2EB9, 0x2EBA, 0x2EBB, 0x2EBC,
+    0x2EBD, 0x2EBE, 0x2EBF, 0x2EC0, 0x2EC1, 0x2EC2, 0x2EE6, 0x2EFE, 0x2F01, 0x2F02, 0x2F03, 0x2F05,
+    0x2F06, 0x2F07, 0x2F08, 0x2F09, 0x2F0A, 0x2F0B, 0x2F0C, 0x2F0D, 0x2F0E, 0x2F15, 0x2F16, 0x2F20,
+    0x2F22, 0x2F23, 0x2F24, 0x2F25, 0x2F26, 0x2F27, 0x2F28, 0x2F29, 0x2F2A, 0x2F2B, 0x2F2C, 0x2F2D,
+    0x2F2E, 0x2F2F, 0x2F30, 0x2F31, 0x2F32, 0x2F33, 0x2F34, 0x2F35, 0x2F36, 0x2F37, 0x2F38, 0x2F39,
+    0x2F3A, 0x2F3B, 0x2F3C, 0x2F3D, 0x2F3E, 0x2F3F, 0x2F40, 0x2F41, 0x2F42, 0x2F43, 0x2F44, 0x2F45,
+    0x2F46, 0x2F47, 0x2F48, 0x2F49, 0x2F4A, 0x2F4B, 0x2F4C, 0x2F4D, 0x2F4E, 0x2F4F, 0x2F50, 0x2F51,
+    0x2F52, 0x2F53, 0x2F54, 0x2F55, 0x2F56, 0x2F57, 0x2F58, 0x2F59, 0x2F5A, 0x2F5B, 0x2F5C, 0x2F5D,
+    0x2F5E, 0x2F5F, 0x2F60, 0x2F61, 0x2F62, 0x2F63, 0x2F64, 0x2F65, 0x2F66, 0x2F67, 0x2F68, 0x2F69,
+    0x2F6A, 0x2F6B, 0x2F6C, 0x2F6D, 0x2F6E, 0x2F6F, 0x2F70, 0x2F71, 0x2F72, 0x2F73, 0x2F74, 0x2F75,
+    0x2F76, 0x2F77, 0x2F78, 0x2F79, 0x2F7A, 0x2F7B, 0x2F7C, 0x2F7D, 0x2F7E, 0x2F7F, 0x2F80, 0x2F81,
+    0x2F82, 0x2F86, 0x2F87, 0x2F8C, 0x2F8F, 0x2F91, 0x2F92, 0x2F96, 0x2F9A, 0x2F9C, 0x2FA4, 0x2FA5,
+    0x2FA6, 0x2FAB, 0x2FB0, 0x2FB2, 0x2FB3, 0x2FB5, 0x2FB7, 0x2FBE, 0x2FBF, 0x2FC4, 0x2FC6, 0x2FC7,
+    0x2FC8, 0x2FCB, 0x2FCF, 0x2FD0, 0x2FD1, 0x2FD6, 0x2FD8, 0x2FDE, 0x2FE0, 0x2FE1, 0x2FE2, 0x2FE3,
+    0x2FE4, 0x2FE5, 0x2FE6, 0x2FE7, 0x2FE8, 0x2FE9, 0x2FEA, 0x2FEB, 0x2FEC, 0x2FED, 0x2FEE, 0x2FEF,
+    0x2FF0, 0x2FF1, 0x2FF2, 0x2FF3, 0x2FF4, 0x2FF5, 0x2FF6, 0x2FF7, 0x2FF8, 0x2FF9, 0x2FFA, 0x2FFB,
+    0x2FFC, 0x2FFD, 0x2FFE, 0x2FFF, 0x3000, 0x3001, 0x3002, 0x3003, 0x3004, 0x3005, 0x3006, 0x3007,
+    0x3008, 0x3009, 0x300A, 0x300B, 0x300C, 0x300D, 0x300E, 0x300F, 0x3010, 0x3011, 0x3012, 0x3013,
+    0x3014, 0x3015, 0x3016, 0x3017, 0x3018, 0x3019, 0x301A, 0x301B, 0x301C, 0x301D, 0x301E, 0x301F,
+    0x3020, 0x3021, 0x3022, 0x3023, 0x3024, 0x3025, 0x3026, 0x3027, 0x3028, 0x3029, 0x302A, 0x302B,
+    0x302C, 0x302D, 0x302E, 0x302F, 0x3030, 0x3031, 0x3032, 0x3033, 0x3034, 0x3035, 0x3036, 0x3037,
+    0x3038, 0x3039, 0x303A, 0x303B, 0x303C, 0x303D, 0x303E, 0x303F, 0x3041, 0x3045, 0x3046, 0x3047,
+    0x304E, 0x3051, 0x3052, 0x3056, 0x3058, 0x3059, 0x305A, 0x305C, 0x305F, 0x3060, 0x3062, 0x3068,
+    0x3069, 0x306A, 0x306C, 0x306D, 0x306E, 0x3070, 0x3072, 0x3075, 0x3079, 0x307A, 0x307D, 0x307E,
+    0x308C, 0x3092, 0x309E, 0x309F, 0x30A0, 0x30A1, 0x30A2, 0x30A3, 0x30A4, 0x30A5, 0x30A6, 0x30A7,
+    0x30A8, 0x30A9, 0x30AA, 0x30AB, 0x30AC, 0x30AD, 0x30AE, 0x30AF, 0x30B0, 0x30B1, 0x30B2, 0x30B3,
+    0x30B4, 0x30B5, 0x30B6, 0x30B7, 0x30B8, 0x30B9, 0x30BA, 0x30BB, 0x30BC, 0x30BD, 0x30BE, 0x30BF,
+    0x30C0, 0x30C1, 0x30C2, 0x30C3, 0x30C4, 0x30C5, 0x30C6, 0x30C7, 0x30C8, 0x30C9, 0x30CA, 0x30CB,
+    0x30CC, 0x30CD, 0x30CE, 0x30CF, 0x30D0, 0x30D1, 0x30D2, 0x30D3, 0x30D4, 0x30D5, 0x30D6, 0x30D7,
+    0x30D8, 0x30D9, 0x30DA, 0x30DB, 0x30DC, 0x30DD, 0x30DE, 0x30DF, 0x30E0, 0x30E1, 0x30E2, 0x30E3,
+    0x30E4, 0x30E5, 0x30E6, 0x30E7, 0x30E8, 0x30E9, 0x30EA, 0x30EB, 0x30EC, 0x30ED, 0x30EE, 0x30EF,
+    0x30F0, 0x30F1, 0x30F2, 0x30F3, 0x30F4, 0x30F5, 0x30F6, 0x30F7, 0x30F8, 0x30F9, 0x30FA, 0x30FB,
+    0x30FC, 0x30FD, 0x30FF, 0x3100, 0x3101, 0x3102, 0x3108, 0x310A, 0x310B, 0x310C, 0x310D, 0x310E,
+    0x3111, 0x3112, 0x3113, 0x3114, 0x3116, 0x3118, 0x3119, 0x311A, 0x311F, 0x3126, 0x3127, 0x3129,
+    0x312B, 0x312C, 0x312D, 0x312E, 0x312F, 0x3130, 0x3131, 0x3135, 0x3138, 0x313A, 0x313C, 0x313D,
+    0x313E, 0x313F, 0x3142, 0x314F, 0x3151, 0x3152, 0x3155, 0x3156, 0x315B, 0x315C, 0x315D, 0x315E,
+    0x315F, 0x3160, 0x3161, 0x3162, 0x3163, 0x3164, 0x3165, 0x3166, 0x3167, 0x3168, 0x3169, 0x316A,
+    0x316B, 0x316C, 0x316D, 0x316E, 0x316F, 0x3170, 0x3171, 0x3172, 0x3173, 0x3174, 0x3175, 0x3176,
+    0x3177, 0x3178, 0x3179, 0x317A, 0x317B, 0x317C, 0x317D, 0x317E, 0x317F, 0x3180, 0x3181, 0x3182,
+    0x3183, 0x3184, 0x3185, 0x3186, 0x3187, 0x3188, 0x3189, 0x318A, 0x318B, 0x318C, 0x318D, 0x318E,
+    0x318F, 0x3190, 0x3191, 0x3192, 0x3193, 0x3194, 0x3195, 0x3196, 0x3197, 0x3198, 0x3199, 0x319A,
+    0x319B, 0x319C, 0x319D, 0x319E, 0x319F, 0x31A0, 0x31A1, 0x31A2, 0x31A3, 0x31A4, 0x31A5, 0x31A6,
+    0x31A7, 0x31A8, 0x31A9, 0x31AA, 0x31AB, 0x31AC, 0x31AD, 0x31AE, 0x31AF, 0x31B0, 0x31B1, 0x31B2,
+    0x31B3, 0x31B4, 0x31B5, 0x31B6, 0x31B7, 0x31B8, 0x31B9, 0x31BA, 0x31C0, 0x31C1, 0x31C2, 0x31C5,
+    0x31C6, 0x31C9, 0x31CE, 0x31D2, 0x31D3, 0x31D4, 0x31D7, 0x31D8, 0x31DA, 0x31DE, 0x31DF, 0x31E4,
+    0x31E5, 0x31E9, 0x31EA, 0x31F0, 0x31F2, 0x31F7, 0x31F9, 0x31FA, 0x31FB, 0x3203, 0x3209, 0x320D,
+    0x320F, 0x3212, 0x3213, 0x321A, 0x321B, 0x321C, 0x321D, 0x321E, 0x321F, 0x3220, 0x3221, 0x3222,
+    0x3223, 0x3224, 0x3225, 0x3226, 0x3227, 0x3228, 0x3229, 0x322A, 0x322B, 0x322C, 0x322D, 0x322E,
+    0x322F, 0x3230, 0x3231, 0x3232, 0x3233, 0x3234, 0x3235, 0x3236, 0x3237, 0x3238, 0x3239, 0x323A,
+    0x323B, 0x323C, 0x323D, 0x323E, 0x323F, 0x3240, 0x3241, 0x3242, 0x3243, 0x3244, 0x3245, 0x3246,
+    0x3247, 0x3248, 0x3249, 0x324A, 0x324B, 0x324C, 0x324D, 0x324E, 0x324F, 0x3250, 0x3251, 0x3252,
+    0x3253, 0x3254, 0x3255, 0x3256, 0x3257, 0x3258, 0x3259, 0x325A, 0x325B, 0x325C, 0x325D, 0x325E,
+    0x325F, 0x3260, 0x3261, 0x3262, 0x3263, 0x3264, 0x3265, 0x3266, 0x3267, 0x3268, 0x3269, 0x326A,
+    0x326B, 0x326C, 0x326D, 0x326E, 0x326F, 0x3270, 0x3271, 0x3272, 0x3273, 0x3274, 0x3275, 0x3276,
+    0x3277, 0x3278, 0x3287, 0x328A, 0x328C, 0x328D, 0x3292, 0x3298, 0x3299, 0x329A, 0x329B, 0x32A0,
+    0x32A2, 0x32A3, 0x32A4, 0x32AA, 0x32AB, 0x32AC, 0x32B0, 0x32B1, 0x32B2, 0x32B3, 0x32B4, 0x32B6,
+    0x32BB, 0x32BD, 0x32BE, 0x32BF, 0x32C2, 0x32CA, 0x32CB, 0x32CF, 0x32D7, 0x32D8, 0x32D9, 0x32DA,
+    0x32DB, 0x32DC, 0x32DD, 0x32DE, 0x32DF, 0x32E0, 0x32E1, 0x32E2, 0x32E3, 0x32E4, 0x32E5, 0x32E6,
+    0x32E7, 0x32E8, 0x32E9, 0x32EA, 0x32EB, 0x32EC, 0x32ED, 0x32EE, 0x32EF, 0x32F0, 0x32F1, 0x32F2,
+    0x32F3, 0x32F4, 0x32F5, 0x32F6, 0x32F7, 0x32F8, 0x32F9, 0x32FA, 0x32FB, 0x32FC, 0x32FD, 0x32FE,
+    0x32FF, 0x3300, 0x3301, 0x3302, 0x3303, 0x3304, 0x3305, 0x3306, 0x3307, 0x3308, 0x3309, 0x330A,
+    0x330B, 0x330C, 0x330D, 0x330E, 0x330F, 0x3310, 0x3311, 0x3312, 0x3313, 0x3314, 0x3315, 0x3316,
+    0x3317, 0x3318, 0x3319, 0x331A, 0x331B, 0x331C, 0x331D, 0x331E, 0x331F, 0x3320, 0x3321, 0x3322,
+    0x3323, 0x3324, 0x3325, 0x3326, 0x3327, 0x3328, 0x3329, 0x332A, 0x332B, 0x332C, 0x332D, 0x332E,
+    0x332F, 0x3330, 0x3331, 0x3332, 0x3333, 0x3334, 0x3335, 0x3336, 0x333A, 0x333B, 0x333F, 0x3341,
+    0x3343, 0x3344, 0x3347, 0x3348, 0x3349, 0x334A, 0x334E, 0x3350, 0x3351, 0x3352, 0x3354, 0x3355,
+    0x3356, 0x3358, 0x335B, 0x335D, 0x335F, 0x3361, 0x3362, 0x3363, 0x3364, 0x3366, 0x3367, 0x3368,
+    0x336C, 0x336F, 0x3370, 0x3372, 0x3374, 0x3375, 0x3376, 0x337B, 0x337D, 0x337E, 0x337F, 0x3381,
+    0x3382, 0x3384, 0x338E, 0x3390, 0x3391, 0x3392, 0x3393, 0x3394, 0x3395, 0x3396, 0x3397, 0x3398,
+    0x3399, 0x339A, 0x339B, 0x339C, 0x339D, 0x339E, 0x339F, 0x33A0, 0x33A1, 0x33A2, 0x33A3, 0x33A4,
+    0x33A5, 0x33A6, 0x33A7, 0x33A8, 0x33A9, 0x33AA, 0x33AB, 0x33AC, 0x33AD, 0x33AE, 0x33AF, 0x33B0,
+    0x33B1, 0x33B2, 0x33B3, 0x33B4, 0x33B5, 0x33B6, 0x33B7, 0x33B8, 0x33B9, 0x33BA, 0x33BB, 0x33BC,
+    0x33BD, 0x33BE, 0x33BF, 0x33C0, 0x33C1, 0x33C2, 0x33C3, 0x33C4, 0x33C5, 0x33C6, 0x33C7, 0x33C8,
+    0x33C9, 0x33CA, 0x33CB, 0x33CC, 0x33CD, 0x33CE, 0x33CF, 0x33D0, 0x33D1, 0x33D2, 0x33D3, 0x33D4,
+    0x33D5, 0x33D6, 0x33D7, 0x33D8, 0x33D9, 0x33DA, 0x33DB, 0x33DC, 0x33DD, 0x33DE, 0x33DF, 0x33E0,
+    0x33E1, 0x33E2, 0x33E3, 0x33E4, 0x33E5, 0x33E6, 0x33E7, 0x33E8, 0x33E9, 0x33EA, 0x33EB, 0x33EC,
+    0x33ED, 0x33EE, 0x33EF, 0x33F0, 0x33F1, 0x33F2, 0x33F3, 0x33F4, 0x33F6, 0x33F8, 0x33FA, 0x33FB,
+    0x3400, 0x3401, 0x3406, 0x3407, 0x3408, 0x340F, 0x3411, 0x3418, 0x341A, 0x341E, 0x3421, 0x3425,
+    0x3427, 0x3428, 0x3429, 0x3430, 0x3434, 0x3435, 0x3436, 0x3438, 0x343B, 0x343C, 0x343E, 0x343F,
+    0x3441, 0x3445, 0x3446, 0x3447, 0x344A, 0x344B, 0x344E, 0x3451, 0x3454, 0x3455, 0x3456, 0x3457,
+    0x3458, 0x3459, 0x345A, 0x345B, 0x345C, 0x345D, 0x345E, 0x345F, 0x3460, 0x3461, 0x3462, 0x3463,
+    0x3464, 0x3465, 0x3466, 0x3467, 0x3468, 0x3469, 0x346A, 0x346B, 0x346C, 0x346D, 0x346E, 0x346F,
+    0x3470, 0x3471, 0x3472, 0x3473, 0x3474, 0x3475, 0x3476, 0x3477, 0x3478, 0x3479, 0x347A, 0x347B,
+    0x347C, 0x347D, 0x347E, 0x347F, 0x3480, 0x3481, 0x3482, 0x3483, 0x3484, 0x3485, 0x3486, 0x3487,
+    0x3488, 0x3489, 0x348A, 0x348B, 0x348C, 0x348D, 0x348E, 0x348F, 0x3490, 0x3491, 0x3492, 0x3493,
+    0x3494, 0x3495, 0x3496, 0x3497, 0x3498, 0x3499, 0x349A, 0x349B, 0x349C, 0x349D, 0x349E, 0x349F,
+    0x34A0, 0x34A1, 0x34A2, 0x34A3, 0x34A4, 0x34A5, 0x34A6, 0x34A7, 0x34A8, 0x34A9, 0x34AA, 0x34AB,
+    0x34AC, 0x34AD, 0x34AE, 0x34AF, 0x34B0, 0x34B1, 0x34B2, 0x34B4, 0x34B9, 0x34BC, 0x34BF, 0x34C0,
+    0x34C1, 0x34C5, 0x34C6, 0x34CC, 0x34CF, 0x34D5, 0x34DD, 0x34E3, 0x34E6, 0x34EE, 0x34F8, 0x34FD,
+    0x34FE, 0x3502, 0x3503, 0x3508, 0x3509, 0x350C, 0x350D, 0x350E, 0x350F, 0x3510, 0x3512, 0x3513,
+    0x3514, 0x3515, 0x3516, 0x3517, 0x3518, 0x3519, 0x351A, 0x351B, 0x351C, 0x351D, 0x351E, 0x351F,
+    0x3520, 0x3521, 0x3522, 0x3523, 0x3524, 0x3525, 0x3526, 0x3527, 0x3528, 0x3529, 0x352A, 0x352B,
+    0x352C, 0x352D, 0x352E, 0x352F, 0x3530, 0x3531, 0x3532, 0x3533, 0x3534, 0x3535, 0x3536, 0x3537,
+    0x3538, 0x3539, 0x353A, 0x353B, 0x353C, 0x353D, 0x353E, 0x353F, 0x3540, 0x3541, 0x3542, 0x3543,
+    0x3544, 0x3545, 0x3546, 0x3547, 0x3548, 0x3549, 0x354A, 0x354B, 0x354C, 0x354D, 0x354E, 0x354F,
+    0x3550, 0x3551, 0x3552, 0x3553, 0x3554, 0x3555, 0x3556, 0x3557, 0x3558, 0x3559, 0x355A, 0x355B,
+    0x355C, 0x355D, 0x355E, 0x355F, 0x3560, 0x3561, 0x3562, 0x3563, 0x3564, 0x3565, 0x3566, 0x3567,
+    0x3568, 0x3569, 0x356A, 0x356B, 0x356C, 0x356D, 0x356E, 0x356F, 0x3570, 0x3575, 0x3579, 0x357A,
+    0x357B, 0x3583, 0x3584, 0x3586, 0x358A, 0x3597, 0x3599, 0x359A, 0x359D, 0x359F, 0x35A0, 0x35A1,
+    0x35A3, 0x35A7, 0x35A9, 0x35AB, 0x35AC, 0x35AE, 0x35B4, 0x35B5, 0x35B7, 0x35B8, 0x35BD, 0x35C0,
+    0x35CB, 0x35CD, 0x35D0, 0x35D1, 0x35D2, 0x35D3, 0x35D4, 0x35D5, 0x35D6, 0x35D7, 0x35D8, 0x35D9,
+    0x35DA, 0x35DB, 0x35DC, 0x35DD, 0x35DE, 0x35DF, 0x35E0, 0x35E1, 0x35E2, 0x35E3, 0x35E4, 0x35E5,
+    0x35E6, 0x35E7, 0x35E8, 0x35E9, 0x35EA, 0x35EB, 0x35EC, 0x35ED, 0x35EE, 0x35EF, 0x35F0, 0x35F1,
+    0x35F2, 0x35F3, 0x35F4, 0x35F5, 0x35F6, 0x35F7, 0x35F8, 0x35F9, 0x35FA, 0x35FB, 0x35FC, 0x35FD,
+    0x35FE, 0x35FF, 0x3600, 0x3601, 0x3602, 0x3603, 0x3604, 0x3605, 0x3606, 0x3607, 0x3608, 0x3609,
+    0x360A, 0x360B, 0x360C, 0x360D, 0x360E, 0x360F, 0x3611, 0x3612, 0x3613, 0x3614, 0x3615, 0x3616,
+    0x3617, 0x3618, 0x3619, 0x361A, 0x361B, 0x361C, 0x361D, 0x361E, 0x361F, 0x3620, 0x3621, 0x3622,
+    0x3623, 0x3624, 0x3625, 0x3626, 0x3627, 0x3628, 0x3629, 0x362A, 0x362B, 0x362C, 0x362D, 0x362E,
+    0x3637, 0x363B, 0x363C, 0x363F, 0x3641, 0x3645, 0x3646, 0x364F, 0x3659, 0x365E, 0x3662, 0x366D,
+    0x366F, 0x3673, 0x3678, 0x367B, 0x3684, 0x3685, 0x3689, 0x368B, 0x368E, 0x368F, 0x3690, 0x3691,
+    0x3692, 0x3693, 0x3694, 0x3695, 0x3696, 0x3697, 0x3698, 0x3699, 0x369A, 0x369B, 0x369C, 0x369D,
+    0x369E, 0x369F, 0x36A0, 0x36A1, 0x36A2, 0x36A3, 0x36A4, 0x36A5, 0x36A6, 0x36A7, 0x36A8, 0x36A9,
+    0x36AA, 0x36AB, 0x36AC, 0x36AD, 0x36AE, 0x36AF, 0x36B0, 0x36B1, 0x36B2, 0x36B3, 0x36B4, 0x36B5,
+    0x36B6, 0x36B7, 0x36B8, 0x36B9, 0x36BA, 0x36BB, 0x36BC, 0x36BD, 0x36BE, 0x36BF, 0x36C0, 0x36C1,
+    0x36C2, 0x36C3, 0x36C4, 0x36C5, 0x36C6, 0x36C7, 0x36C8, 0x36C9, 0x36CA, 0x36CB, 0x36CC, 0x36CD,
+    0x36CE, 0x36CF, 0x36D0, 0x36D1, 0x36D2, 0x36D3, 0x36D4, 0x36D5, 0x36D6, 0x36D7, 0x36D8, 0x36D9,
+    0x36DA, 0x36DB, 0x36DC, 0x36DD, 0x36DE, 0x36DF, 0x36E0, 0x36E1, 0x36E2, 0x36E3, 0x36E4, 0x36E5,
+    0x36E6, 0x36E7, 0x36E8, 0x36E9, 0x36EA, 0x36EB, 0x36EC, 0x36EE, 0x36EF, 0x36F6, 0x3701, 0x3708,
+    0x370B, 0x370C, 0x370F, 0x3715, 0x3716, 0x371D, 0x3724, 0x3729, 0x372B, 0x372C, 0x3732, 0x3739,
+    0x373D, 0x374C, 0x374D, 0x374E, 0x374F, 0x3750, 0x3751, 0x3752, 0x3753, 0x3754, 0x3755, 0x3756,
+    0x3757, 0x3758, 0x3759, 0x375A, 0x375B, 0x375C, 0x375D, 0x375E, 0x375F, 0x3760, 0x3761, 0x3762,
+    0x3763, 0x3764, 0x3765, 0x3766, 0x3767, 0x3768, 0x3769, 0x376A, 0x376B, 0x376C, 0x376D, 0x376E,
+    0x376F, 0x3770, 0x3771, 0x3772, 0x3773, 0x3774, 0x3775, 0x3776, 0x3777, 0x3778, 0x3779, 0x377A,
+    0x377B, 0x377C, 0x377D, 0x377E, 0x377F, 0x3780, 0x3781, 0x3782, 0x3783, 0x3784, 0x3785, 0x3786,
+    0x3787, 0x3788, 0x3789, 0x378A, 0x378B, 0x378C, 0x378D, 0x378E, 0x378F, 0x3790, 0x3791, 0x3792,
+    0x3793, 0x3794, 0x3795, 0x3796, 0x3797, 0x3798, 0x3799, 0x379A, 0x379B, 0x379C, 0x379D, 0x379E,
+    0x379F, 0x37A0, 0x37A1, 0x37A2, 0x37A3, 0x37A4, 0x37A5, 0x37A6, 0x37A7, 0x37A8, 0x37A9, 0x37AA,
+    0x37AC, 0x37AF, 0x37B4, 0x37BF, 0x37C3, 0x37D3, 0x37D4, 0x37E2, 0x37E5, 0x37F5, 0x37F7, 0x37FC,
+    0x37FF, 0x3802, 0x380A, 0x380B, 0x380C, 0x380D, 0x380E, 0x380F, 0x3810, 0x3811, 0x3812, 0x3813,
+    0x3814, 0x3815, 0x3816, 0x3817, 0x3818, 0x3819, 0x381A, 0x381B, 0x381C, 0x381D, 0x381E, 0x381F,
+    0x3820, 0x3821, 0x3822, 0x3823, 0x3824, 0x3825, 0x3826, 0x3827, 0x3828, 0x3829, 0x382A, 0x382B,
+    0x382C, 0x382D, 0x382E, 0x382F, 0x3830, 0x3831, 0x3832, 0x3833, 0x3834, 0x3835, 0x3836, 0x3837,
+    0x3838, 0x3839, 0x383A, 0x383B, 0x383C, 0x383D, 0x383E, 0x383F, 0x3840, 0x3841, 0x3842, 0x3843,
+    0x3844, 0x3845, 0x3846, 0x3847, 0x3848, 0x3849, 0x384A, 0x384B, 0x384C, 0x384D, 0x384E, 0x384F,
+    0x3850, 0x3851, 0x3852, 0x3853, 0x3854, 0x3855, 0x3856, 0x3857, 0x3858, 0x3859, 0x385A, 0x385B,
+    0x385C, 0x385D, 0x385E, 0x385F, 0x3860, 0x3861, 0x3862, 0x3863, 0x3864, 0x3865, 0x3866, 0x3867,
+    0x3868, 0x3869, 0x386B, 0x3872, 0x3879, 0x387E, 0x387F, 0x3885, 0x388A, 0x388C, 0x388D, 0x388F,
+    0x3891, 0x3894, 0x3899, 0x389C, 0x389D, 0x389E, 0x38A0, 0x38A2, 0x38A5, 0x38A6, 0x38A8, 0x38AC,
+    0x38B0, 0x38B3, 0x38B4, 0x38B7, 0x38BB, 0x38BC, 0x38BD, 0x38C0, 0x38C2, 0x38C6, 0x38C7, 0x38C8,
+    0x38C9, 0x38CA, 0x38CB, 0x38CC, 0x38CD, 0x38CE, 0x38CF, 0x38D0, 0x38D1, 0x38D2, 0x38D3, 0x38D4,
+    0x38D5, 0x38D6, 0x38D7, 0x38D8, 0x38D9, 0x38DA, 0x38DB, 0x38DC, 0x38DD, 0x38DE, 0x38DF, 0x38E0,
+    0x38E1, 0x38E2, 0x38E3, 0x38E4, 0x38E5, 0x38E6, 0x38E7, 0x38E8, 0x38E9, 0x38EA, 0x38EB, 0x38EC,
+    0x38ED, 0x38EE, 0x38EF, 0x38F0, 0x38F1, 0x38F2, 0x38F3, 0x38F4, 0x38F5, 0x38F6, 0x38F7, 0x38F8,
+    0x38F9, 0x38FA, 0x38FB, 0x38FC, 0x38FD, 0x38FE, 0x38FF, 0x3900, 0x3901, 0x3902, 0x3903, 0x3904,
+    0x3905, 0x3906, 0x3907, 0x3908, 0x3909, 0x390A, 0x390B, 0x390C, 0x390D, 0x390E, 0x390F, 0x3910,
+    0x3911, 0x3912, 0x3913, 0x3914, 0x3915, 0x3916, 0x3917, 0x3918, 0x3919, 0x391A, 0x391B, 0x391C,
+    0x391D, 0x391E, 0x391F, 0x3920, 0x3921, 0x3922, 0x3923, 0x3924, 0x3925, 0x3926, 0x3927, 0x3928,
+    0x3929, 0x392B, 0x392C, 0x392F, 0x3933, 0x3935, 0x3937, 0x3939, 0x393C, 0x393E, 0x3945, 0x3947,
+    0x3948, 0x394C, 0x394F, 0x3950, 0x3956, 0x3958, 0x3960, 0x3961, 0x3964, 0x3965, 0x3969, 0x396C,
+    0x396D, 0x396F, 0x3971, 0x3972, 0x3977, 0x397A, 0x3981, 0x3986, 0x3987, 0x3988, 0x3989, 0x398A,
+    0x398B, 0x398C, 0x398D, 0x398E, 0x398F, 0x3990, 0x3991, 0x3992, 0x3993, 0x3994, 0x3995, 0x3996,
+    0x3997, 0x3998, 0x3999, 0x399A, 0x399B, 0x399C, 0x399D, 0x399E, 0x399F, 0x39A0, 0x39A1, 0x39A2,
+    0x39A3, 0x39A4, 0x39A5, 0x39A6, 0x39A7, 0x39A8, 0x39A9, 0x39AA, 0x39AB, 0x39AC, 0x39AD, 0x39AE,
+    0x39AF, 0x39B0, 0x39B1, 0x39B2, 0x39B3, 0x39B4, 0x39B5, 0x39B6, 0x39B7, 0x39B8, 0x39B9, 0x39BA,
+    0x39BB, 0x39BC, 0x39BD, 0x39BE, 0x39BF, 0x39C0, 0x39C1, 0x39C2, 0x39C3, 0x39C4, 0x39C5, 0x39C6,
+    0x39C7, 0x39C8, 0x39C9, 0x39CA, 0x39CB, 0x39CC, 0x39CD, 0x39CE, 0x39CF, 0x39D0, 0x39D1, 0x39D2,
+    0x39D3, 0x39D4, 0x39D5, 0x39D6, 0x39D7, 0x39D8, 0x39D9, 0x39DA, 0x39DB, 0x39DC, 0x39DD, 0x39DE,
+    0x39DF, 0x39E0, 0x39E1, 0x39E2, 0x39E3, 0x39E4, 0x39E7, 0x39E9, 0x39EA, 0x39ED, 0x39EE, 0x39F2,
+    0x39F4, 0x39F6, 0x39FB, 0x39FE, 0x3A03, 0x3A0A, 0x3A0E, 0x3A12, 0x3A16, 0x3A19, 0x3A23, 0x3A27,
+    0x3A2B, 0x3A31, 0x3A39, 0x3A3A, 0x3A3E, 0x3A43, 0x3A44, 0x3A45, 0x3A46, 0x3A47, 0x3A48, 0x3A49,
+    0x3A4A, 0x3A4B, 0x3A4C, 0x3A4D, 0x3A4E, 0x3A4F, 0x3A50, 0x3A51, 0x3A52, 0x3A53, 0x3A54, 0x3A55,
+    0x3A56, 0x3A57, 0x3A58, 0x3A59, 0x3A5A, 0x3A5B, 0x3A5C, 0x3A5D, 0x3A5E, 0x3A5F, 0x3A60, 0x3A61,
+    0x3A62, 0x3A63, 0x3A64, 0x3A65, 0x3A66, 0x3A67, 0x3A68, 0x3A69, 0x3A6A, 0x3A6B, 0x3A6C, 0x3A6D,
+    0x3A6E, 0x3A6F, 0x3A70, 0x3A71, 0x3A72, 0x3A73, 0x3A74, 0x3A75, 0x3A76, 0x3A77, 0x3A78, 0x3A79,
+    0x3A7A, 0x3A7B, 0x3A7C, 0x3A7D, 0x3A7E, 0x3A7F, 0x3A80, 0x3A81, 0x3A82, 0x3A83, 0x3A84, 0x3A85,
+    0x3A86, 0x3A87, 0x3A88, 0x3A89, 0x3A8A, 0x3A8B, 0x3A8C, 0x3A8D, 0x3A8E, 0x3A8F, 0x3A90, 0x3A91,
+    0x3A92, 0x3A93, 0x3A94, 0x3A95, 0x3A96, 0x3A97, 0x3A98, 0x3A99, 0x3A9A, 0x3A9B, 0x3A9C, 0x3A9D,
+    0x3A9E, 0x3A9F, 0x3AA0, 0x3AA1, 0x3AA2, 0x3AB0, 0x3AB6, 0x3AB8, 0x3ABB, 0x3ABE, 0x3AC4, 0x3ACA,
+    0x3ACC, 0x3ACD, 0x3AD4, 0x3AD6, 0x3AD7, 0x3AD8, 0x3AD9, 0x3ADA, 0x3ADE, 0x3AE3, 0x3AE4, 0x3AE5,
+    0x3AE7, 0x3AE9, 0x3AEA, 0x3AEE, 0x3AF1, 0x3AF6, 0x3AFB, 0x3AFC, 0x3B02, 0x3B03, 0x3B04, 0x3B05,
+    0x3B06, 0x3B07, 0x3B08, 0x3B09, 0x3B0A, 0x3B0B, 0x3B0C, 0x3B0D, 0x3B0E, 0x3B0F, 0x3B10, 0x3B11,
+    0x3B12, 0x3B13, 0x3B14, 0x3B15, 0x3B16, 0x3B17, 0x3B18, 0x3B19, 0x3B1A, 0x3B1B, 0x3B1C, 0x3B1D,
+    0x3B1E, 0x3B1F, 0x3B20, 0x3B21, 0x3B22, 0x3B23, 0x3B24, 0x3B25, 0x3B26, 0x3B27, 0x3B28, 0x3B29,
+    0x3B2A, 0x3B2B, 0x3B2C, 0x3B2D, 0x3B2E, 0x3B2F, 0x3B30, 0x3B31, 0x3B32, 0x3B33, 0x3B34, 0x3B35,
+    0x3B36, 0x3B37, 0x3B38, 0x3B39, 0x3B3A, 0x3B3B, 0x3B3C, 0x3B3D, 0x3B3E, 0x3B3F, 0x3B40, 0x3B41,
+    0x3B42, 0x3B43, 0x3B44, 0x3B45, 0x3B46, 0x3B47, 0x3B48, 0x3B49, 0x3B4A, 0x3B4B, 0x3B4C, 0x3B4D,
+    0x3B4E, 0x3B4F, 0x3B50, 0x3B51, 0x3B52, 0x3B53, 0x3B54, 0x3B55, 0x3B56, 0x3B57, 0x3B58, 0x3B59,
+    0x3B5A, 0x3B5B, 0x3B5C, 0x3B5D, 0x3B5E, 0x3B5F, 0x3B60, 0x3B61, 0x3B64, 0x3B69, 0x3B6D, 0x3B71,
+    0x3B73, 0x3B75, 0x3B79, 0x3B7C, 0x3B7D, 0x3B7F, 0x3B84, 0x3B86, 0x3B88, 0x3B8F, 0x3B90, 0x3B93,
+    0x3B95, 0x3B96, 0x3B99, 0x3BA0, 0x3BA5, 0x3BA6, 0x3BAB, 0x3BB0, 0x3BB3, 0x3BB7, 0x3BBB, 0x3BBE,
+    0x3BBF, 0x3BC0, 0x3BC1, 0x3BC2, 0x3BC3, 0x3BC4, 0x3BC5, 0x3BC6, 0x3BC7, 0x3BC8, 0x3BC9, 0x3BCA,
+    0x3BCB, 0x3BCC, 0x3BCD, 0x3BCE, 0x3BCF, 0x3BD0, 0x3BD1, 0x3BD2, 0x3BD3, 0x3BD4, 0x3BD5, 0x3BD6,
+    0x3BD7, 0x3BD8, 0x3BD9, 0x3BDA, 0x3BDB, 0x3BDC, 0x3BDD, 0x3BDE, 0x3BDF, 0x3BE0, 0x3BE1, 0x3BE2,
+    0x3BE3, 0x3BE4, 0x3BE5, 0x3BE6, 0x3BE7, 0x3BE8, 0x3BE9, 0x3BEA, 0x3BEB, 0x3BEC, 0x3BED, 0x3BEE,
+    0x3BEF, 0x3BF0, 0x3BF1, 0x3BF2, 0x3BF3, 0x3BF4, 0x3BF5, 0x3BF6, 0x3BF7, 0x3BF8, 0x3BF9, 0x3BFA,
+    0x3BFB, 0x3BFC, 0x3BFD, 0x3BFE, 0x3BFF, 0x3C00, 0x3C01, 0x3C02, 0x3C03, 0x3C04, 0x3C05, 0x3C06,
+    0x3C07, 0x3C08, 0x3C09, 0x3C0A, 0x3C0B, 0x3C0C, 0x3C0D, 0x3C0E, 0x3C0F, 0x3C10, 0x3C11, 0x3C12,
+    0x3C13, 0x3C14, 0x3C15, 0x3C16, 0x3C17, 0x3C18, 0x3C19, 0x3C1A, 0x3C1B, 0x3C1C, 0x3C1D, 0x3C1E,
+    0x3C27, 0x3C29, 0x3C42, 0x3C48, 0x3C5C, 0x3C5F, 0x3C60, 0x3C61, 0x3C63, 0x3C7E, 0x3C7F, 0x3C80,
+    0x3C81, 0x3C82, 0x3C83, 0x3C84, 0x3C85, 0x3C86, 0x3C87, 0x3C88, 0x3C89, 0x3C8A, 0x3C8B, 0x3C8C,
+    0x3C8D, 0x3C8E, 0x3C8F, 0x3C90, 0x3C91, 0x3C92, 0x3C93, 0x3C94, 0x3C95, 0x3C96, 0x3C97, 0x3C98,
+    0x3C99, 0x3C9A, 0x3C9B, 0x3C9C, 0x3C9D, 0x3C9E, 0x3C9F, 0x3CA0, 0x3CA1, 0x3CA2, 0x3CA3, 0x3CA4,
+    0x3CA5, 0x3CA6, 0x3CA7, 0x3CA8, 0x3CA9, 0x3CAA, 0x3CAB, 0x3CAC, 0x3CAD, 0x3CAE, 0x3CAF, 0x3CB0,
+    0x3CB1, 0x3CB2, 0x3CB3, 0x3CB4, 0x3CB5, 0x3CB6, 0x3CB7, 0x3CB8, 0x3CB9, 0x3CBA, 0x3CBB, 0x3CBC,
+    0x3CBD, 0x3CBE, 0x3CBF, 0x3CC0, 0x3CC1, 0x3CC2, 0x3CC3, 0x3CC4, 0x3CC5, 0x3CC6, 0x3CC7, 0x3CC8,
+    0x3CC9, 0x3CCA, 0x3CCB, 0x3CCC, 0x3CCD, 0x3CCE, 0x3CCF, 0x3CD0, 0x3CD1, 0x3CD2, 0x3CD3, 0x3CD4,
+    0x3CD5, 0x3CD6, 0x3CD7, 0x3CD8, 0x3CD9, 0x3CDA, 0x3CDB, 0x3CDC, 0x3CFB, 0x3CFC, 0x3CFD, 0x3D00,
+    0x3D03, 0x3D04, 0x3D05, 0x3D09, 0x3D0A, 0x3D0E, 0x3D11, 0x3D14, 0x3D20, 0x3D26, 0x3D3C, 0x3D3D,
+    0x3D3E, 0x3D3F, 0x3D40, 0x3D41, 0x3D42, 0x3D43, 0x3D44, 0x3D46, 0x3D47, 0x3D48, 0x3D49, 0x3D4A,
+    0x3D4B, 0x3D4C, 0x3D4D, 0x3D4E, 0x3D4F, 0x3D50, 0x3D51, 0x3D52, 0x3D53, 0x3D54, 0x3D55, 0x3D56,
+    0x3D57, 0x3D58, 0x3D59, 0x3D5A, 0x3D5B, 0x3D5C, 0x3D5D, 0x3D5E, 0x3D5F, 0x3D60, 0x3D61, 0x3D62,
+    0x3D63, 0x3D64, 0x3D65, 0x3D66, 0x3D67, 0x3D68, 0x3D69, 0x3D6A, 0x3D6B, 0x3D6C, 0x3D6D, 0x3D6E,
+    0x3D6F, 0x3D70, 0x3D71, 0x3D72, 0x3D73, 0x3D74, 0x3D75, 0x3D76, 0x3D77, 0x3D78, 0x3D79, 0x3D7A,
+    0x3D7B, 0x3D7C, 0x3D7D, 0x3D7E, 0x3D7F, 0x3D80, 0x3D81, 0x3D82, 0x3D83, 0x3D84, 0x3D85, 0x3D86,
+    0x3D87, 0x3D88, 0x3D89, 0x3D8A, 0x3D8B, 0x3D8C, 0x3D8D, 0x3D8E, 0x3D8F, 0x3D90, 0x3D91, 0x3D92,
+    0x3D93, 0x3D94, 0x3D95, 0x3D96, 0x3D97, 0x3D98, 0x3D99, 0x3D9A, 0x3DBA, 0x3DC3, 0x3DF3, 0x3DFA,
+    0x3DFB, 0x3DFC, 0x3DFD, 0x3DFE, 0x3DFF, 0x3E00, 0x3E01, 0x3E02, 0x3E03, 0x3E04, 0x3E05, 0x3E06,
+    0x3E07, 0x3E08, 0x3E09, 0x3E0A, 0x3E0B, 0x3E0C, 0x3E0D, 0x3E0E, 0x3E0F, 0x3E10, 0x3E11, 0x3E12,
+    0x3E13, 0x3E14, 0x3E15, 0x3E16, 0x3E17, 0x3E18, 0x3E19, 0x3E1A, 0x3E1B, 0x3E1C, 0x3E1D, 0x3E1E,
+    0x3E1F, 0x3E20, 0x3E21, 0x3E22, 0x3E23, 0x3E24, 0x3E25, 0x3E26, 0x3E27, 0x3E28, 0x3E29, 0x3E2A,
+    0x3E2B, 0x3E2C, 0x3E2D, 0x3E2E, 0x3E2F, 0x3E30, 0x3E31, 0x3E32, 0x3E33, 0x3E34, 0x3E35, 0x3E36,
+    0x3E37, 0x3E38, 0x3E39, 0x3E3A, 0x3E3B, 0x3E3C, 0x3E3D, 0x3E3E, 0x3E3F, 0x3E40, 0x3E41, 0x3E42,
+    0x3E43, 0x3E44, 0x3E45, 0x3E46, 0x3E47, 0x3E48, 0x3E49, 0x3E4A, 0x3E4B, 0x3E4C, 0x3E4D, 0x3E4E,
+    0x3E4F, 0x3E50, 0x3E51, 0x3E52, 0x3E53, 0x3E54, 0x3E55, 0x3E56, 0x3E57, 0x3E58, 0x3E60, 0x3E68,
+    0x3EB8, 0x3EB9, 0x3EBA, 0x3EBB, 0x3EBC, 0x3EBD, 0x3EBE, 0x3EBF, 0x3EC0, 0x3EC1, 0x3EC2, 0x3EC3,
+    0x3EC4, 0x3EC5, 0x3EC6, 0x3EC7, 0x3EC8, 0x3EC9, 0x3ECA, 0x3ECB, 0x3ECC, 0x3ECD, 0x3ECE, 0x3ECF,
+    0x3ED0, 0x3ED1, 0x3ED2, 0x3ED3, 0x3ED4, 0x3ED5, 0x3ED6, 0x3ED7, 0x3ED8, 0x3ED9, 0x3EDA, 0x3EDB,
+    0x3EDC, 0x3EDD, 0x3EDE, 0x3EDF, 0x3EE0, 0x3EE1, 0x3EE2, 0x3EE3, 0x3EE4, 0x3EE5, 0x3EE6, 0x3EE7,
+    0x3EE8, 0x3EE9, 0x3EEA, 0x3EEB, 0x3EEC, 0x3EED, 0x3EEE, 0x3EEF, 0x3EF0, 0x3EF1, 0x3EF2, 0x3EF3,
+    0x3EF4, 0x3EF5, 0x3EF6, 0x3EF7, 0x3EF8, 0x3EF9, 0x3EFA, 0x3EFB, 0x3EFC, 0x3EFD, 0x3EFE, 0x3EFF,
+    0x3F00, 0x3F01, 0x3F02, 0x3F03, 0x3F04, 0x3F05, 0x3F06, 0x3F07, 0x3F08, 0x3F09, 0x3F0A, 0x3F0B,
+    0x3F0C, 0x3F0D, 0x3F0E, 0x3F0F, 0x3F10, 0x3F11, 0x3F12, 0x3F13, 0x3F14, 0x3F15, 0x3F16, 0x3F39,
+    0x3F57, 0x3F76, 0x3F77, 0x3F78, 0x3F79, 0x3F7A, 0x3F7B, 0x3F7C, 0x3F7D, 0x3F7E, 0x3F7F, 0x3F80,
+    0x3F81, 0x3F82, 0x3F83, 0x3F84, 0x3F85, 0x3F86, 0x3F87, 0x3F88, 0x3F89, 0x3F8A, 0x3F8B, 0x3F8C,
+    0x3F8D, 0x3F8E, 0x3F8F, 0x3F90, 0x3F91, 0x3F92, 0x3F93, 0x3F94, 0x3F95, 0x3F96, 0x3F98, 0x3F99,
+    0x3F9A, 0x3F9B, 0x3F9C, 0x3F9D, 0x3F9E, 0x3F9F, 0x3FA0, 0x3FA1, 0x3FA2, 0x3FA3, 0x3FA4, 0x3FA5,
+    0x3FA6, 0x3FA7, 0x3FA8, 0x3FA9, 0x3FAA, 0x3FAB, 0x3FAC, 0x3FAD, 0x3FAE, 0x3FAF, 0x3FB0, 0x3FB1,
+    0x3FB2, 0x3FB3, 0x3FB4, 0x3FB5, 0x3FB6, 0x3FB7, 0x3FB8, 0x3FB9, 0x3FBA, 0x3FBB, 0x3FBC, 0x3FBD,
+    0x3FBE, 0x3FBF, 0x3FC0, 0x3FC1, 0x3FC2, 0x3FC3, 0x3FC4, 0x3FC5, 0x3FC6, 0x3FC7, 0x3FC8, 0x3FC9,
+    0x3FCA, 0x3FCB, 0x3FCC, 0x3FCD, 0x3FCE, 0x3FCF, 0x3FD0, 0x3FD1, 0x3FD2, 0x3FD3, 0x3FD4, 0x3FF4,
+    0x3FF9, 0x402C, 0x402D, 0x402E, 0x402F, 0x4030, 0x4031, 0x4032, 0x4033, 0x4034, 0x4035, 0x4036,
+    0x4037, 0x4038, 0x4039, 0x403A, 0x403B, 0x403C, 0x403D, 0x403E, 0x403F, 0x4040, 0x4041, 0x4042,
+    0x4043, 0x4044, 0x4045, 0x4046, 0x4047, 0x4048, 0x4049, 0x404A, 0x404B, 0x404C, 0x404D, 0x404E,
+    0x404F, 0x4050, 0x4051, 0x4052, 0x4053, 0x4054, 0x4055, 0x4056, 0x4057, 0x4058, 0x4059, 0x405A,
+    0x405B, 0x405C, 0x405D, 0x405E, 0x405F, 0x4060, 0x4061, 0x4062, 0x4063, 0x4064, 0x4065, 0x4066,
+    0x4067, 0x4068, 0x4069, 0x406A, 0x406B, 0x406C, 0x406D, 0x406E, 0x406F, 0x4070, 0x4071, 0x4072,
+    0x4073, 0x4074, 0x4075, 0x4076, 0x4077, 0x4078, 0x4079, 0x407A, 0x407B, 0x407C, 0x407D, 0x407E,
+    0x407F, 0x4080, 0x4081, 0x4082, 0x4083, 0x4084, 0x4085, 0x4086, 0x4087, 0x4088, 0x4089, 0x408A,
+    0x408B, 0x408C, 0x408D, 0x4092, 0x409B, 0x409F, 0x40A0, 0x40A2, 0x40AA, 0x40AE, 0x40B4, 0x40BB,
+    0x40C2, 0x40C6, 0x40CD, 0x40CF, 0x40D2, 0x40D3, 0x40DA, 0x40DD, 0x40F2, 0x40F3, 0x40F4, 0x40F5,
+    0x40F6, 0x40F7, 0x40F8, 0x40F9, 0x40FA, 0x40FB, 0x40FC, 0x40FD, 0x40FE, 0x40FF, 0x4100, 0x4101,
+    0x4102, 0x4103, 0x4104, 0x4105, 0x4106, 0x4107, 0x4108, 0x4109, 0x410A, 0x410B, 0x410C, 0x410D,
+    0x410E, 0x410F, 0x4110, 0x4111, 0x4112, 0x4113, 0x4114, 0x4115, 0x4116, 0x4117, 0x4118, 0x4119,
+    0x411A, 0x411B, 0x411C, 0x411D, 0x411E, 0x411F, 0x4120, 0x4121, 0x4122, 0x4123, 0x4124, 0x4125,
+    0x4126, 0x4127, 0x4128, 0x4129, 0x412A, 0x412B, 0x412C, 0x412E, 0x412F, 0x4130, 0x4131, 0x4132,
+    0x4133, 0x4134, 0x4135, 0x4136, 0x4137, 0x4138, 0x4139, 0x413A, 0x413B, 0x413C, 0x413D, 0x413E,
+    0x413F, 0x4140, 0x4141, 0x4142, 0x4143, 0x4144, 0x4145, 0x4146, 0x4147, 0x4148, 0x4149, 0x414A,
+    0x414B, 0x414C, 0x414D, 0x414E, 0x414F, 0x4150, 0x41B0, 0x41B1, 0x41B2, 0x41B3, 0x41B4, 0x41B5,
+    0x41B6, 0x41B7, 0x41B8, 0x41B9, 0x41BA, 0x41BB, 0x41BC, 0x41BD, 0x41BE, 0x41BF, 0x41C0, 0x41C1,
+    0x41C2, 0x41C3, 0x41C4, 0x41C5, 0x41C6, 0x41C7, 0x41C8, 0x41C9, 0x41CA, 0x41CB, 0x41CC, 0x41CD,
+    0x41CE, 0x41CF, 0x41D0, 0x41D1, 0x41D2, 0x41D3, 0x41D4, 0x41D5, 0x41D6, 0x41D7, 0x41D8, 0x41D9,
+    0x41DA, 0x41DB, 0x41DC, 0x41DD, 0x41DE, 0x41DF, 0x41E0, 0x41E1, 0x41E2, 0x41E3, 0x41E4, 0x41E5,
+    0x41E6, 0x41E7, 0x41E8, 0x41E9, 0x41EA, 0x41EB, 0x41EC, 0x41ED, 0x41EE, 0x41EF, 0x41F0, 0x41F1,
+    0x41F2, 0x41F3, 0x41F4, 0x41F5, 0x41F6, 0x41F7, 0x41F8, 0x41F9, 0x41FA, 0x41FB, 0x41FC, 0x41FD,
+    0x41FE, 0x41FF, 0x4200, 0x4201, 0x4202, 0x4203, 0x4204, 0x4205, 0x4206, 0x4207, 0x4208, 0x4209,
+    0x420A, 0x420B, 0x420C, 0x420D, 0x420E, 0x421D, 0x421E, 0x4220, 0x4221, 0x4222, 0x4223, 0x4226,
+    0x4227, 0x4229, 0x422B, 0x4234, 0x4236, 0x423A, 0x423F, 0x4241, 0x424B, 0x424E, 0x425B, 0x425C,
+    0x425E, 0x425F, 0x4260, 0x4261, 0x4264, 0x4265, 0x4269, 0x426A, 0x426B, 0x426E, 0x426F, 0x4270,
+    0x4271, 0x4272, 0x4273, 0x4275, 0x4276, 0x4278, 0x4279, 0x427B, 0x427C, 0x427D, 0x427E, 0x4281,
+    0x4282, 0x4284, 0x4285, 0x4287, 0x4288, 0x428A, 0x428B, 0x428C, 0x428D, 0x428E, 0x428F, 0x4290,
+    0x4292, 0x4293, 0x4294, 0x4295, 0x4296, 0x4297, 0x4299, 0x429C, 0x429D, 0x429E, 0x429F, 0x42A0,
+    0x42A3, 0x42A4, 0x42A5, 0x42A7, 0x42A9, 0x42AB, 0x42AD, 0x42AE, 0x42AF, 0x42B0, 0x42B1, 0x42B2,
+    0x42B3, 0x42B4, 0x42B5, 0x42B6, 0x42B7, 0x42B8, 0x42B9, 0x42BA, 0x42BB, 0x42BC, 0x42BD, 0x42BE,
+    0x42BF, 0x42C0, 0x42C1, 0x42C2, 0x42C3, 0x42C4, 0x42C5, 0x42C6, 0x42C7, 0x42C8, 0x42C9, 0x42CA,
+    0x42CB, 0x42CC, 0x42CD, 0x42CF, 0x42D0, 0x42D3, 0x42D6, 0x42D7, 0x42D9, 0x42DC, 0x42DD, 0x42DE,
+    0x42DF, 0x42E6, 0x42E9, 0x42EA, 0x42EC, 0x42F0, 0x42F8, 0x42FA, 0x42FF, 0x4300, 0x4301, 0x4302,
+    0x4305, 0x4308, 0x430A, 0x430C, 0x430D, 0x430F, 0x4314, 0x431A, 0x4326, 0x4328, 0x432A, 0x432B,
+    0x432C, 0x432D, 0x432E, 0x432F, 0x4330, 0x4331, 0x4332, 0x4333, 0x4334, 0x4335, 0x4336, 0x4337,
+    0x4338, 0x4339, 0x433A, 0x433B, 0x433C, 0x433D, 0x433E, 0x433F, 0x4340, 0x4341, 0x4342, 0x4343,
+    0x4344, 0x4345, 0x4346, 0x4347, 0x4348, 0x4349, 0x434B, 0x434C, 0x434D, 0x434E, 0x434F, 0x4350,
+    0x4351, 0x4352, 0x4353, 0x4354, 0x4355, 0x4356, 0x4357, 0x4358, 0x4359, 0x435A, 0x435B, 0x435C,
+    0x435D, 0x435E, 0x435F, 0x4360, 0x4361, 0x4362, 0x4363, 0x4364, 0x4365, 0x4366, 0x4367, 0x4369,
+    0x436A, 0x436B, 0x436C, 0x436D, 0x436E, 0x436F, 0x4370, 0x4371, 0x4372, 0x4373, 0x4374, 0x4375,
+    0x4376, 0x4377, 0x4378, 0x4379, 0x437A, 0x437B, 0x437C, 0x437D, 0x437E, 0x437F, 0x4380, 0x4381,
+    0x4382, 0x4383, 0x4384, 0x4385, 0x4386, 0x4387, 0x4388, 0x4389, 0x438A, 0x438B, 0x438C, 0x4391,
+    0x4393, 0x4394, 0x4397, 0x4398, 0x439F, 0x43A2, 0x43A9, 0x43AA, 0x43B6, 0x43B8, 0x43BA, 0x43C1,
+    0x43D4, 0x43EA, 0x43EB, 0x43EC, 0x43ED, 0x43EE, 0x43EF, 0x43F0, 0x43F1, 0x43F2, 0x43F3, 0x43F4,
+    0x43F5, 0x43F6, 0x43F7, 0x43F8, 0x43F9, 0x43FA, 0x43FB, 0x43FC, 0x43FD, 0x43FE, 0x43FF, 0x4400,
+    0x4401, 0x4402, 0x4403, 0x4404, 0x4405, 0x4406, 0x4407, 0x4408, 0x4409, 0x440A, 0x440B, 0x440C,
+    0x440D, 0x440E, 0x440F, 0x4410, 0x4411, 0x4413, 0x4414, 0x4415, 0x4416, 0x4417, 0x4418, 0x4419,
+    0x441A, 0x441B, 0x441C, 0x441D, 0x441E, 0x441F, 0x4420, 0x4421, 0x4422, 0x4423, 0x4424, 0x4425,
+    0x4426, 0x4427, 0x4428, 0x4429, 0x442A, 0x442B, 0x442C, 0x442E, 0x442F, 0x4430, 0x4431, 0x4432,
+    0x4434, 0x4435, 0x4436, 0x4437, 0x4438, 0x4439, 0x443A, 0x443B, 0x443C, 0x443D, 0x443E, 0x443F,
+    0x4440, 0x4441, 0x4442, 0x4443, 0x4444, 0x4445, 0x4446, 0x4447, 0x4448, 0x44A8, 0x44A9, 0x44AA,
+    0x44AB, 0x44AC, 0x44AD, 0x44AF, 0x44B0, 0x44B1, 0x44B3, 0x44B4, 0x44B5, 0x44B6, 0x44B7, 0x44B8,
+    0x44B9, 0x44BA, 0x44BB, 0x44BC, 0x44BD, 0x44BE, 0x44BF, 0x44C0, 0x44C1, 0x44C2, 0x44C3, 0x44C4,
+    0x44C5, 0x44C6, 0x44C7, 0x44C8, 0x44C9, 0x44CA, 0x44CB, 0x44CC, 0x44CD, 0x44CE, 0x44CF, 0x44D0,
+    0x44D1, 0x44D2, 0x44D3, 0x44D4, 0x44D5, 0x44D7, 0x44D8, 0x44D9, 0x44DA, 0x44DB, 0x44DC, 0x44DD,
+    0x44DE, 0x44DF, 0x44E0, 0x44E1, 0x44E2, 0x44E4, 0x44E5, 0x44E6, 0x44E7, 0x44E8, 0x44E9, 0x44EA,
+    0x44EB, 0x44EC, 0x44ED, 0x44EE, 0x44EF, 0x44F0, 0x44F1, 0x44F2, 0x44F3, 0x44F4, 0x44F5, 0x44F6,
+    0x44F7, 0x44F8, 0x44F9, 0x44FA, 0x44FB, 0x44FC, 0x44FD, 0x44FE, 0x44FF, 0x4500, 0x4501, 0x4502,
+    0x4503, 0x4504, 0x4505, 0x4506, 0x4527, 0x4528, 0x4529, 0x452A, 0x452B, 0x452C, 0x452F, 0x4533,
+    0x4534, 0x4538, 0x453C, 0x453E, 0x4541, 0x4543, 0x4544, 0x4545, 0x454A, 0x454B, 0x454C, 0x454E,
+    0x454F, 0x4551, 0x4552, 0x4553, 0x4554, 0x4555, 0x4557, 0x455A, 0x455D, 0x455F, 0x4561, 0x4562,
+    0x4563, 0x4564, 0x4565, 0x4566, 0x4567, 0x4568, 0x4569, 0x456A, 0x456B, 0x456C, 0x456D, 0x456E,
+    0x456F, 0x4570, 0x4571, 0x4572, 0x4573, 0x4574, 0x4575, 0x4576, 0x4577, 0x4578, 0x4579, 0x457A,
+    0x457B, 0x457C, 0x457D, 0x457E, 0x457F, 0x4580, 0x4581, 0x4582, 0x4583, 0x4584, 0x4585, 0x4586,
+    0x4587, 0x4588, 0x4589, 0x458A, 0x458B, 0x458C, 0x458D, 0x458E, 0x458F, 0x4590, 0x4591, 0x4592,
+    0x4593, 0x4594, 0x4595, 0x4596, 0x4597, 0x4598, 0x4599, 0x459A, 0x459B, 0x459C, 0x459D, 0x459E,
+    0x459F, 0x45A0, 0x45A1, 0x45A2, 0x45A3, 0x45A4, 0x45A5, 0x45A6, 0x45A7, 0x45A8, 0x45A9, 0x45AA,
+    0x45AB, 0x45AC, 0x45AD, 0x45AE, 0x45AF, 0x45B0, 0x45B1, 0x45B2, 0x45B3, 0x45B4, 0x45B5, 0x45B6,
+    0x45B7, 0x45B8, 0x45B9, 0x45BA, 0x45BB, 0x45BC, 0x45BD, 0x45BE, 0x45BF, 0x45C0, 0x45C1, 0x45C2,
+    0x45C3, 0x45C4, 0x45C5, 0x45C6, 0x45C9, 0x45CF, 0x45D4, 0x45D5, 0x45D7, 0x45D8, 0x45DA, 0x45DB,
+    0x45DD, 0x45E2, 0x45E3, 0x45E6, 0x45E8, 0x45EE, 0x45EF, 0x45F1, 0x45F5, 0x45FA, 0x4601, 0x4602,
+    0x4603, 0x4607, 0x4609, 0x460E, 0x460F, 0x4610, 0x4611, 0x4612, 0x4613, 0x4614, 0x4617, 0x4619,
+    0x461C, 0x461D, 0x461E, 0x461F, 0x4620, 0x4622, 0x4624, 0x4625, 0x4627, 0x4628, 0x4629, 0x462A,
+    0x462B, 0x462C, 0x462D, 0x462E, 0x462F, 0x4630, 0x4631, 0x4632, 0x4633, 0x4634, 0x4635, 0x4636,
+    0x4637, 0x4638, 0x4639, 0x463A, 0x463B, 0x463C, 0x463D, 0x463E, 0x463F, 0x4642, 0x4643, 0x4644,
+    0x4645, 0x4646, 0x4647, 0x4648, 0x4649, 0x464A, 0x464B, 0x464C, 0x464D, 0x464E, 0x4650, 0x4651,
+    0x4652, 0x4653, 0x4654, 0x4655, 0x4656, 0x4657, 0x4658, 0x4659, 0x465A, 0x465B, 0x465D, 0x465E,
+    0x465F, 0x4660, 0x4661, 0x4662, 0x4663, 0x4665, 0x4666, 0x4668, 0x4669, 0x466A, 0x466B, 0x466C,
+    0x466D, 0x466E, 0x466F, 0x4670, 0x4671, 0x4672, 0x4674, 0x4675, 0x4676, 0x4677, 0x4678, 0x4679,
+    0x467A, 0x467B, 0x467C, 0x467D, 0x467E, 0x467F, 0x4680, 0x4681, 0x4682, 0x4684, 0x4685, 0x4687,
+    0x468A, 0x468B, 0x468E, 0x4691, 0x4694, 0x4697, 0x4699, 0x469A, 0x469B, 0x469F, 0x46A2, 0x46A6,
+    0x46A9, 0x46AA, 0x46BE, 0x46C2, 0x46C5, 0x46D0, 0x46D1, 0x46D8, 0x46E1, 0x46E2, 0x46E3, 0x46E4,
+    0x46E7, 0x46E8, 0x46E9, 0x46EA, 0x46EB, 0x46EC, 0x46ED, 0x46EE, 0x46EF, 0x46F0, 0x46F1, 0x46F2,
+    0x46F3, 0x46F4, 0x46F5, 0x46F6, 0x46F7, 0x46F9, 0x46FA, 0x46FB, 0x46FC, 0x46FD, 0x46FE, 0x46FF,
+    0x4700, 0x4702, 0x4703, 0x4704, 0x4705, 0x4706, 0x4707, 0x4708, 0x470A, 0x470C, 0x470D, 0x470E,
+    0x470F, 0x4710, 0x4711, 0x4712, 0x4713, 0x4714, 0x4715, 0x4716, 0x4717, 0x4718, 0x4719, 0x471A,
+    0x471B, 0x471C, 0x471D, 0x471E, 0x471F, 0x4720, 0x4721, 0x4722, 0x4723, 0x4724, 0x4725, 0x4726,
+    0x4727, 0x4728, 0x472A, 0x472B, 0x472C, 0x472D, 0x472E, 0x472F, 0x4730, 0x4731, 0x4732, 0x4733,
+    0x4734, 0x4735, 0x4736, 0x4737, 0x4738, 0x4739, 0x473A, 0x473B, 0x473C, 0x473D, 0x473E, 0x4740,
+    0x4741, 0x4743, 0x4744, 0x4748, 0x474A, 0x474C, 0x474E, 0x4751, 0x4752, 0x4753, 0x4754, 0x4757,
+    0x475C, 0x475D, 0x475E, 0x475F, 0x4761, 0x4768, 0x476E, 0x4774, 0x4776, 0x477A, 0x477E, 0x478A,
+    0x478B, 0x478C, 0x478D, 0x4797, 0x47A0, 0x47A1, 0x47A2, 0x47A3, 0x47A4, 0x47A5, 0x47A6, 0x47A7,
+    0x47A8, 0x47A9, 0x47AA, 0x47AB, 0x47AC, 0x47AE, 0x47AF, 0x47B0, 0x47B1, 0x47B2, 0x47B3, 0x47B4,
+    0x47B5, 0x47B6, 0x47B7, 0x47B8, 0x47B9, 0x47BA, 0x47BB, 0x47BC, 0x47BD, 0x47BE, 0x47BF, 0x47C1,
+    0x47C2, 0x47C3, 0x47C4, 0x47C5, 0x47C6, 0x47C7, 0x47C8, 0x47C9, 0x47CA, 0x47CB, 0x47CC, 0x47CD,
+    0x47CE, 0x47CF, 0x47D0, 0x47D1, 0x47D2, 0x47D3, 0x47D4, 0x47D5, 0x47D6, 0x47D7, 0x47D8, 0x47D9,
+    0x47DA, 0x47DB, 0x47DC, 0x47DD, 0x47DE, 0x47DF, 0x47E0, 0x47E1, 0x47E2, 0x47E3, 0x47E4, 0x47E5,
+    0x47E6, 0x47E7, 0x47E8, 0x47E9, 0x47EA, 0x47EB, 0x47EC, 0x47ED, 0x47EF, 0x47F0, 0x47F1, 0x47F2,
+    0x47F3, 0x47F4, 0x47F5, 0x47F6, 0x47F7, 0x47F8, 0x47F9, 0x47FA, 0x47FB, 0x47FC, 0x47FD, 0x47FE,
+    0x485E, 0x485F, 0x4860, 0x4861, 0x4862, 0x4863, 0x4864, 0x4865, 0x4866, 0x4867, 0x4868, 0x4869,
+    0x486A, 0x486B, 0x486C, 0x486D, 0x486E, 0x486F, 0x4870, 0x4871, 0x4872, 0x4873, 0x4874, 0x4875,
+    0x4876, 0x4877, 0x4878, 0x4879, 0x487F, 0x4880, 0x4881, 0x4882, 0x4883, 0x4884, 0x4885, 0x4886,
+    0x4887, 0x4889, 0x488C, 0x488D, 0x488E, 0x488F, 0x4890, 0x4891, 0x4892, 0x4893, 0x4894, 0x4895,
+    0x4896, 0x4897, 0x4898, 0x4899, 0x489A, 0x489B, 0x489C, 0x489D, 0x489E, 0x489F, 0x48A0, 0x48A1,
+    0x48A2, 0x48A3, 0x48A4, 0x48A5, 0x48A6, 0x48A7, 0x48A9, 0x48AA, 0x48AB, 0x48AC, 0x48AD, 0x48AE,
+    0x48AF, 0x48B0, 0x48B1, 0x48B2, 0x48B3, 0x48B4, 0x48B5, 0x48B6, 0x48B7, 0x48B9, 0x48BA, 0x48BB,
+    0x48BC, 0x48EA, 0x4903, 0x491C, 0x491D, 0x491E, 0x491F, 0x4920, 0x4921, 0x4922, 0x4923, 0x4924,
+    0x4925, 0x4926, 0x4927, 0x4928, 0x4929, 0x492A, 0x492B, 0x492C, 0x492D, 0x492E, 0x492F, 0x4930,
+    0x4931, 0x4932, 0x4933, 0x4934, 0x4935, 0x4936, 0x4937, 0x4938, 0x4939, 0x493A, 0x493B, 0x493C,
+    0x493D, 0x493E, 0x493F, 0x4940, 0x4941, 0x4942, 0x4943, 0x4944, 0x4946, 0x4947, 0x4948, 0x494A,
+    0x494B, 0x494C, 0x4950, 0x4951, 0x4952, 0x4955, 0x4957, 0x4958, 0x4959, 0x495A, 0x495B, 0x495C,
+    0x495D, 0x495E, 0x495F, 0x4960, 0x4961, 0x4962, 0x4963, 0x4965, 0x4966, 0x4967, 0x4968, 0x4969,
+    0x496A, 0x496B, 0x496C, 0x496E, 0x496F, 0x4970, 0x4971, 0x4972, 0x4973, 0x4974, 0x4975, 0x4976,
+    0x4977, 0x4978, 0x4979, 0x497A, 0x4980, 0x4999, 0x49DA, 0x49DB, 0x49DC, 0x49DD, 0x49DE, 0x49DF,
+    0x49E0, 0x49E1, 0x49E2, 0x49E3, 0x49E4, 0x49E5, 0x49E6, 0x49E7, 0x49E8, 0x49E9, 0x49EA, 0x49EB,
+    0x49EC, 0x49ED, 0x49EE, 0x49EF, 0x49F0, 0x49F1, 0x49F3, 0x49F4, 0x49F5, 0x49F6, 0x49F7, 0x49F8,
+    0x49F9, 0x49FA, 0x49FB, 0x49FC, 0x49FD, 0x49FE, 0x49FF, 0x4A00, 0x4A01, 0x4A02, 0x4A03, 0x4A04,
+    0x4A05, 0x4A06, 0x4A07, 0x4A08, 0x4A09, 0x4A0A, 0x4A0B, 0x4A0C, 0x4A0D, 0x4A0F, 0x4A10, 0x4A11,
+    0x4A12, 0x4A13, 0x4A14, 0x4A15, 0x4A16, 0x4A17, 0x4A18, 0x4A19, 0x4A1A, 0x4A1B, 0x4A1C, 0x4A1D,
+    0x4A1E, 0x4A1F, 0x4A20, 0x4A21, 0x4A22, 0x4A23, 0x4A24, 0x4A26, 0x4A27, 0x4A28, 0x4A29, 0x4A2A,
+    0x4A2B, 0x4A2C, 0x4A2D, 0x4A2E, 0x4A2F, 0x4A30, 0x4A31, 0x4A32, 0x4A33, 0x4A34, 0x4A35, 0x4A36,
+    0x4A37, 0x4A38, 0x4A6C, 0x4A97, 0x4A98, 0x4A99, 0x4A9A, 0x4A9B, 0x4A9C, 0x4A9D, 0x4A9E, 0x4A9F,
+    0x4AA0, 0x4AA1, 0x4AA2, 0x4AA3, 0x4AA4, 0x4AA5, 0x4AA6, 0x4AA7, 0x4AA8, 0x4AA9, 0x4AAA, 0x4AAB,
+    0x4AAC, 0x4AAD, 0x4AAE, 0x4AAF, 0x4AB0, 0x4AB1, 0x4AB2, 0x4AB3, 0x4AB4, 0x4AB5, 0x4AB6, 0x4AB7,
+    0x4AB8, 0x4AB9, 0x4ABA, 0x4ABB, 0x4ABC, 0x4ABD, 0x4ABE, 0x4ABF, 0x4AC0, 0x4AC1, 0x4AC2, 0x4AC3,
+    0x4AC4, 0x4AC5, 0x4AC6, 0x4AC7, 0x4AC8, 0x4AC9, 0x4ACA, 0x4ACC, 0x4ACD, 0x4ACE, 0x4ACF, 0x4AD1,
+    0x4AD2, 0x4AD3, 0x4AD4, 0x4AD5, 0x4AD6, 0x4AD7, 0x4AD8, 0x4AD9, 0x4ADA, 0x4ADB, 0x4ADC, 0x4ADD,
+    0x4ADE, 0x4ADF, 0x4AE0, 0x4AE1, 0x4AE2, 0x4AE3, 0x4AE4, 0x4AE5, 0x4AE6, 0x4AE7, 0x4AE8, 0x4AE9,
+    0x4AEA, 0x4AEB, 0x4AEC, 0x4AED, 0x4AEE, 0x4AEF, 0x4AF0, 0x4AF1, 0x4AF2, 0x4AF3, 0x4AF5, 0x4AF6,
+    0x4B19, 0x4B53, 0x4B56, 0x4B57, 0x4B58, 0x4B59, 0x4B5A, 0x4B5B, 0x4B5C, 0x4B5D, 0x4B5E, 0x4B5F,
+    0x4B60, 0x4B62, 0x4B63, 0x4B64, 0x4B65, 0x4B66, 0x4B67, 0x4B68, 0x4B69, 0x4B6A, 0x4B6B, 0x4B6C,
+    0x4B6D, 0x4B6E, 0x4B6F, 0x4B70, 0x4B71, 0x4B72, 0x4B73, 0x4B74, 0x4B75, 0x4B76, 0x4B77, 0x4B78,
+    0x4B79, 0x4B7A, 0x4B7B, 0x4B7C, 0x4B7D, 0x4B7E, 0x4B7F, 0x4B80, 0x4B81, 0x4B82, 0x4B83, 0x4B84,
+    0x4B85, 0x4B86, 0x4B87, 0x4B88, 0x4B89, 0x4B8A, 0x4B8B, 0x4B8C, 0x4B8D, 0x4B8E, 0x4B8F, 0x4B90,
+    0x4B91, 0x4B92, 0x4B93, 0x4B94, 0x4B95, 0x4B96, 0x4B98, 0x4B99, 0x4B9A, 0x4B9B, 0x4B9D, 0x4B9E,
+    0x4B9F, 0x4BA0, 0x4BA1, 0x4BA3, 0x4BA4, 0x4BA5, 0x4BA8, 0x4BAA, 0x4BAC, 0x4BAD, 0x4BAE, 0x4BAF,
+    0x4BB0, 0x4BB1, 0x4BB2, 0x4BB4, 0x4BBC, 0x4C14, 0x4C15, 0x4C16, 0x4C17, 0x4C1A, 0x4C1B, 0x4C1D,
+    0x4C1E, 0x4C1F, 0x4C20, 0x4C21, 0x4C23, 0x4C24, 0x4C25, 0x4C27, 0x4C29, 0x4C2A, 0x4C2B, 0x4C2D,
+    0x4C2E, 0x4C31, 0x4C33, 0x4C35, 0x4C36, 0x4C38, 0x4C39, 0x4C3A, 0x4C3B, 0x4C3C, 0x4C3D, 0x4C44,
+    0x4C48, 0x4C4D, 0x4C4E, 0x4C4F, 0x4C50, 0x4C51, 0x4C52, 0x4C53, 0x4C54, 0x4C55, 0x4C56, 0x4C57,
+    0x4C58, 0x4C59, 0x4C5A, 0x4C5B, 0x4C5C, 0x4C5D, 0x4C5E, 0x4C5F, 0x4C60, 0x4C61, 0x4C62, 0x4C63,
+    0x4C64, 0x4C65, 0x4C66, 0x4C67, 0x4C68, 0x4C69, 0x4C6A, 0x4C6B, 0x4C6C, 0x4C6D, 0x4C6E, 0x4C6F,
+    0x4C70, 0x4C71, 0x4C72, 0x4C81, 0x4CAD, 0x4CC6, 0x4CC7, 0x4CC8, 0x4CC9, 0x4CCA, 0x4CCB, 0x4CCD,
+    0x4CCE, 0x4CCF, 0x4CD0, 0x4CD1, 0x4CD2, 0x4CD3, 0x4CD4, 0x4CD5, 0x4CD6, 0x4CD7, 0x4CD8, 0x4CD9,
+    0x4CDA, 0x4CDB, 0x4CDC, 0x4CDD, 0x4CDE, 0x4CE0, 0x4CE1, 0x4CE2, 0x4CE3, 0x4CE5, 0x4CE6, 0x4CE7,
+    0x4CE8, 0x4CE9, 0x4CEC, 0x4CED, 0x4CEE, 0x4CEF, 0x4CF0, 0x4CF1, 0x4CF2, 0x4CF3, 0x4CF4, 0x4CF5,
+    0x4CF6, 0x4CF7, 0x4CF8, 0x4CF9, 0x4CFA, 0x4CFB, 0x4CFC, 0x4CFD, 0x4CFE, 0x4CFF, 0x4D00, 0x4D01,
+    0x4D02, 0x4D03, 0x4D04, 0x4D05, 0x4D06, 0x4D07, 0x4D08, 0x4D09, 0x4D0A, 0x4D0B, 0x4D0C, 0x4D0D,
+    0x4D0E, 0x4D0F, 0x4D10, 0x4D11, 0x4D13, 0x4D15, 0x4D16, 0x4D17, 0x4D18, 0x4D19, 0x4D1A, 0x4D1B,
+    0x4D1C, 0x4D1D, 0x4D1E, 0x4D1F, 0x4D20, 0x4D21, 0x4D22, 0x4D23, 0x4D24, 0x4D25, 0x4D26, 0x4D27,
+    0x4D28, 0x4D29, 0x4D2A, 0x4D2B, 0x4D2C, 0x4D2D, 0x4D2F, 0x4D30, 0x4D31, 0x4D32, 0x4D33, 0x4D34,
+    0x4D35, 0x4D36, 0x4D37, 0x4D38, 0x4D39, 0x4D3A, 0x4D3C, 0x4D44, 0x4D90, 0x4D91, 0x4D92, 0x4D93,
+    0x4D94, 0x4D95, 0x4D96, 0x4D97, 0x4D98, 0x4D99, 0x4D9A, 0x4D9B, 0x4D9C, 0x4D9D, 0x4D9E, 0x4D9F,
+    0x4DA0, 0x4DA1, 0x4DA2, 0x4DA3, 0x4DA5, 0x4DA6, 0x4DA7, 0x4DA8, 0x4DA9, 0x4DAA, 0x4DAB, 0x4DAC,
+    0x4DAD, 0x4DAE, 0x4DAF, 0x4DB0, 0x4DB1, 0x4DB2, 0x4DB3, 0x4DB4, 0x4DB5, 0x4DB6, 0x4DB7, 0x4DB8,
+    0x4DB9, 0x4DBA, 0x4DBC, 0x4DBD, 0x4DBE, 0x4DBF, 0x4DC0, 0x4DC1, 0x4DC2, 0x4DC3, 0x4DC4, 0x4DC5,
+    0x4DC6, 0x4DC7, 0x4DC8, 0x4DC9, 0x4DCA, 0x4DCB, 0x4DCC, 0x4DCD, 0x4DCE, 0x4DCF, 0x4DD0, 0x4DD1,
+    0x4DD3, 0x4DD4, 0x4DD5, 0x4DD7, 0x4DD8, 0x4DDA, 0x4DDB, 0x4DDC, 0x4DDD, 0x4DDE, 0x4DE1, 0x4DE3,
+    0x4DE4, 0x4DE5, 0x4DE7, 0x4DE8, 0x4DE9, 0x4DEB, 0x4DEC, 0x4DED, 0x4DEE, 0x4E0A, 0x4E0B, 0x4E0C,
+    0x4E0D, 0x4E0E, 0x4E0F, 0x4E10, 0x4E11, 0x4E12, 0x4E19, 0x4E1C, 0x4E1E, 0x4E22, 0x4E23, 0x4E24,
+    0x4E25, 0x4E26, 0x4E28, 0x4E2B, 0x4E30, 0x4E33, 0x4E34, 0x4E35, 0x4E37, 0x4E38, 0x4E3D, 0x4E3E,
+    0x4E4B, 0x4E4C, 0x4E4E, 0x4E51, 0x4E52, 0x4E53, 0x4E54, 0x4E55, 0x4E56, 0x4E57, 0x4E58, 0x4E59,
+    0x4E5A, 0x4E5B, 0x4E5C, 0x4E5D, 0x4E5E, 0x4E5F, 0x4E60, 0x4E61, 0x4E62, 0x4E63, 0x4E64, 0x4E65,
+    0x4E68, 0x4E69, 0x4E6A, 0x4E6B, 0x4E6C, 0x4E6D, 0x4E6E, 0x4E6F, 0x4E70, 0x4E71, 0x4E72, 0x4E73,
+    0x4E74, 0x4E75, 0x4E76, 0x4E77, 0x4E78, 0x4E79, 0x4E7A, 0x4E7B, 0x4E7C, 0x4E7D, 0x4E7E, 0x4E7F,
+    0x4E80, 0x4E81, 0x4E82, 0x4E83, 0x4E85, 0x4E86, 0x4E87, 0x4E89, 0x4E8A, 0x4E8B, 0x4E8C, 0x4E8D,
+    0x4E8F, 0x4E91, 0x4E92, 0x4E93, 0x4E94, 0x4E95, 0x4E96, 0x4E99, 0x4E9D, 0x4E9E, 0x4E9F, 0x4EA0,
+    0x4EA1, 0x4EA2, 0x4EA4, 0x4EA5, 0x4EA6, 0x4EA7, 0x4EA8, 0x4EA9, 0x4EAA, 0x4EAB, 0x4EAC, 0x4EAD,
+    0x4EAE, 0x4EB1, 0x4EB3, 0x4EB5, 0x4EBF, 0x4EC7, 0x4EC9, 0x4ECB, 0x4ECD, 0x4ECF, 0x4ED0, 0x4ED2,
+    0x4ED3, 0x4ED5, 0x4ED7, 0x4ED9, 0x4EE3, 0x4EEA, 0x4EEE, 0x4EF1, 0x4EF3, 0x4EF4, 0x4EF8, 0x4EF9,
+    0x4EFB, 0x4EFC, 0x4EFF, 0x4F02, 0x4F04, 0x4F09, 0x4F0A, 0x4F0C, 0x4F0D, 0x4F0E, 0x4F0F, 0x4F10,
+    0x4F11, 0x4F12, 0x4F13, 0x4F14, 0x4F15, 0x4F16, 0x4F17, 0x4F18, 0x4F19, 0x4F1C, 0x4F1D, 0x4F1E,
+    0x4F1F, 0x4F20, 0x4F21, 0x4F22, 0x4F23, 0x4F24, 0x4F25, 0x4F26, 0x4F27, 0x4F28, 0x4F29, 0x4F2A,
+    0x4F2B, 0x4F2C, 0x4F2D, 0x4F2E, 0x4F2F, 0x4F30, 0x4F31, 0x4F32, 0x4F33, 0x4F34, 0x4F35, 0x4F39,
+    0x4F3A, 0x4F3B, 0x4F3C, 0x4F3D, 0x4F3E, 0x4F3F, 0x4F40, 0x4F41, 0x4F42, 0x4F43, 0x4F44, 0x4F45,
+    0x4F47, 0x4F48, 0x4F49, 0x4F4A, 0x4F4B, 0x4F4C, 0x4F4D, 0x4F4E, 0x4F4F, 0x4F50, 0x4F51, 0x4F52,
+    0x4F53, 0x4F54, 0x4F55, 0x4F57, 0x4F58, 0x4F59, 0x4F5A, 0x4F5C, 0x4F5D, 0x4F5E, 0x4F5F, 0x4F60,
+    0x4F61, 0x4F62, 0x4F63, 0x4F64, 0x4F65, 0x4F66, 0x4F67, 0x4F68, 0x4F69, 0x4F6A, 0x4F73, 0x4F75,
+    0x4F77, 0x4F78, 0x4F7D, 0x4F81, 0x4F94, 0x4F96, 0x4F98, 0x4F99, 0x4F9B, 0x4F9C, 0x4F9E, 0x4FA1,
+    0x4FAA, 0x4FAB, 0x4FB0, 0x4FB8, 0x4FBD, 0x4FBE, 0x4FC1, 0x4FC2, 0x4FC5, 0x4FCA, 0x4FCB, 0x4FCC,
+    0x4FCD, 0x4FCE, 0x4FCF, 0x4FD0, 0x4FD1, 0x4FD2, 0x4FD5, 0x4FD6, 0x4FD8, 0x4FD9, 0x4FDA, 0x4FDB,
+    0x4FDC, 0x4FDD, 0x4FDE, 0x4FDF, 0x4FE0, 0x4FE1, 0x4FE2, 0x4FE3, 0x4FE4, 0x4FE5, 0x4FE6, 0x4FE7,
+    0x4FE8, 0x4FE9, 0x4FEA, 0x4FEB, 0x4FEC, 0x4FED, 0x4FEE, 0x4FEF, 0x4FF0, 0x4FF1, 0x4FF2, 0x4FF3,
+    0x4FF4, 0x4FF5, 0x4FF6, 0x4FF7, 0x4FF8, 0x4FF9, 0x4FFA, 0x4FFB, 0x4FFC, 0x4FFD, 0x4FFE, 0x4FFF,
+    0x5000, 0x5001, 0x5002, 0x5003, 0x5004, 0x5005, 0x5006, 0x5007, 0x5008, 0x5009, 0x500A, 0x500B,
+    0x500C, 0x500D, 0x500E, 0x500F, 0x5010, 0x5011, 0x5013, 0x5014, 0x5015, 0x5016, 0x5017, 0x5019,
+    0x501A, 0x501B, 0x501C, 0x501D, 0x501E, 0x501F, 0x5020, 0x5021, 0x5022, 0x5023, 0x5024, 0x5025,
+    0x5026, 0x5027, 0x5028, 0x502A, 0x502C, 0x5033, 0x5034, 0x5035, 0x5037, 0x5038, 0x5069, 0x506A,
+    0x506F, 0x5070, 0x5088, 0x5089, 0x508A, 0x508B, 0x508C, 0x508D, 0x508E, 0x508F, 0x5090, 0x5091,
+    0x5092, 0x5093, 0x5094, 0x5095, 0x5096, 0x5097, 0x5098, 0x5099, 0x509A, 0x509B, 0x509C, 0x509D,
+    0x509E, 0x509F, 0x50A0, 0x50A1, 0x50A3, 0x50A4, 0x50A5, 0x50A6, 0x50A8, 0x50AB, 0x50AC, 0x50AD,
+    0x50AE, 0x50AF, 0x50B0, 0x50B1, 0x50B2, 0x50B3, 0x50B5, 0x50B6, 0x50B7, 0x50B8, 0x50B9, 0x50BA,
+    0x50BB, 0x50BC, 0x50BD, 0x50BE, 0x50BF, 0x50C0, 0x50C1, 0x50C2, 0x50C3, 0x50C4, 0x50C5, 0x50C6,
+    0x50C7, 0x50C8, 0x50C9, 0x50CA, 0x50CB, 0x50CC, 0x50CD, 0x50CE, 0x50CF, 0x50D0, 0x50D1, 0x50D3,
+    0x50D4, 0x50D5, 0x50D6, 0x50D7, 0x50D9, 0x50DA, 0x50DB, 0x50DC, 0x50DD, 0x50DE, 0x50DF, 0x50E0,
+    0x50E2, 0x50E3, 0x50E4, 0x50E5, 0x50E6, 0x5146, 0x5147, 0x5148, 0x5149, 0x514A, 0x514B, 0x514C,
+    0x514D, 0x514E, 0x5150, 0x5151, 0x5152, 0x5153, 0x5154, 0x5155, 0x5156, 0x5157, 0x5158, 0x5159,
+    0x515A, 0x515B, 0x515C, 0x515D, 0x515E, 0x515F, 0x5160, 0x5161, 0x5162, 0x5163, 0x5164, 0x5165,
+    0x5166, 0x5167, 0x5168, 0x5169, 0x516C, 0x516D, 0x516E, 0x5170, 0x5172, 0x5173, 0x5174, 0x5175,
+    0x5178, 0x517A, 0x517B, 0x517C, 0x517D, 0x517E, 0x5180, 0x5181, 0x5182, 0x5187, 0x5189, 0x518A,
+    0x518B, 0x5192, 0x5195, 0x5198, 0x5199, 0x519A, 0x519B, 0x519E, 0x519F, 0x51A1, 0x51A3, 0x51A4,
+    0x51AA, 0x51AB, 0x51AC, 0x51AD, 0x51AE, 0x51AF, 0x51B0, 0x51D6, 0x51D8, 0x51D9, 0x51DB, 0x51DE,
+    0x51E5, 0x5204, 0x5205, 0x5206, 0x5208, 0x520B, 0x520C, 0x520D, 0x520F, 0x5210, 0x5211, 0x5212,
+    0x5214, 0x5216, 0x5219, 0x521E, 0x521F, 0x5220, 0x5223, 0x5224, 0x5226, 0x5227, 0x5228, 0x522A,
+    0x522B, 0x522E, 0x522F, 0x5231, 0x5232, 0x5233, 0x5236, 0x5237, 0x5238, 0x523A, 0x523D, 0x5241,
+    0x5242, 0x5244, 0x5245, 0x5247, 0x5250, 0x5251, 0x5254, 0x5255, 0x5256, 0x5257, 0x5258, 0x5259,
+    0x525A, 0x525B, 0x525C, 0x525D, 0x525E, 0x525F, 0x5260, 0x5261, 0x5262, 0x5267, 0x5269, 0x5286,
+    0x52AB, 0x52B8, 0x52B9, 0x52BA, 0x52BB, 0x52BD, 0x52BE, 0x52BF, 0x52C1, 0x52C2, 0x52C3, 0x52C4,
+    0x52C5, 0x52C6, 0x52C7, 0x52C8, 0x52C9, 0x52CA, 0x52CB, 0x52CC, 0x52CD, 0x52CE, 0x52CF, 0x52D0,
+    0x52D1, 0x52D2, 0x52D7, 0x52D8, 0x52D9, 0x52DA, 0x52DB, 0x52DC, 0x52DE, 0x52DF, 0x52E1, 0x52E2,
+    0x52E6, 0x52E8, 0x52E9, 0x52EA, 0x52EB, 0x52EC, 0x52EF, 0x52F0, 0x52F1, 0x52F8, 0x52F9, 0x52FA,
+    0x52FB, 0x52FC, 0x52FD, 0x52FE, 0x52FF, 0x5300, 0x5301, 0x5302, 0x5304, 0x5305, 0x5306, 0x5307,
+    0x5308, 0x5309, 0x530A, 0x530B, 0x530C, 0x530D, 0x530E, 0x530F, 0x5310, 0x5311, 0x5312, 0x5313,
+    0x5314, 0x5315, 0x5316, 0x5317, 0x5318, 0x5319, 0x531A, 0x531B, 0x531C, 0x531D, 0x531E, 0x531F,
+    0x5320, 0x5321, 0x5322, 0x532D, 0x532F, 0x5380, 0x5381, 0x5382, 0x5383, 0x5384, 0x5385, 0x5386,
+    0x5387, 0x5388, 0x5389, 0x538A, 0x538B, 0x538C, 0x538D, 0x538E, 0x538F, 0x5390, 0x5391, 0x5392,
+    0x5393, 0x5394, 0x5395, 0x5397, 0x5398, 0x5399, 0x539A, 0x539B, 0x539C, 0x539D, 0x539E, 0x539F,
+    0x53A0, 0x53A1, 0x53A2, 0x53A3, 0x53A4, 0x53A5, 0x53A6, 0x53A7, 0x53A8, 0x53A9, 0x53AA, 0x53AB,
+    0x53AC, 0x53AD, 0x53AE, 0x53AF, 0x53B0, 0x53B1, 0x53B2, 0x53B3, 0x53B4, 0x53B5, 0x53B6, 0x53B7,
+    0x53B8, 0x53B9, 0x53BA, 0x53BB, 0x53BC, 0x53BD, 0x53BE, 0x53BF, 0x53C0, 0x53C1, 0x53C2, 0x53C3,
+    0x53C4, 0x53C5, 0x53C6, 0x53C7, 0x53C8, 0x53C9, 0x53CA, 0x53CB, 0x53CC, 0x53CF, 0x53D0, 0x53D1,
+    0x53D2, 0x53D3, 0x53D4, 0x53D5, 0x53D6, 0x53D7, 0x53D8, 0x53D9, 0x53DA, 0x53DB, 0x53DC, 0x53DD,
+    0x53DE, 0x543E, 0x543F, 0x5441, 0x5442, 0x5443, 0x5445, 0x5447, 0x5449, 0x544B, 0x544C, 0x544D,
+    0x544E, 0x544F, 0x5450, 0x5451, 0x5452, 0x5453, 0x5454, 0x5455, 0x5456, 0x5457, 0x5458, 0x5459,
+    0x545A, 0x545B, 0x545C, 0x545D, 0x545E, 0x545F, 0x5460, 0x5461, 0x5462, 0x5463, 0x5464, 0x5465,
+    0x5466, 0x5467, 0x5468, 0x5469, 0x546B, 0x546C, 0x546D, 0x546E, 0x546F, 0x5470, 0x5471, 0x5472,
+    0x5473, 0x5474, 0x5475, 0x5476, 0x5477, 0x5478, 0x5479, 0x547A, 0x547B, 0x547C, 0x547D, 0x547E,
+    0x547F, 0x5480, 0x5481, 0x5482, 0x5483, 0x5484, 0x5485, 0x5486, 0x5487, 0x5488, 0x5489, 0x548A,
+    0x548C, 0x548D, 0x548E, 0x548F, 0x5490, 0x5491, 0x5492, 0x5493, 0x5494, 0x5495, 0x5496, 0x5497,
+    0x5498, 0x5499, 0x549A, 0x549B, 0x549C, 0x54AE, 0x54AF, 0x54B0, 0x54B1, 0x54B3, 0x54B5, 0x54B6,
+    0x54B7, 0x54BE, 0x54C2, 0x54C3, 0x54C4, 0x54C7, 0x54C9, 0x54CE, 0x54D2, 0x54D3, 0x54D7, 0x54DD,
+    0x54DE, 0x54E2, 0x54E6, 0x54E7, 0x54E8, 0x54F1, 0x54F2, 0x54F9, 0x54FC, 0x54FD, 0x54FE, 0x54FF,
+    0x5500, 0x5501, 0x5502, 0x5503, 0x5504, 0x5505, 0x5506, 0x5507, 0x5508, 0x5509, 0x550A, 0x550B,
+    0x550C, 0x550D, 0x550E, 0x550F, 0x5510, 0x5511, 0x5512, 0x5513, 0x5514, 0x5515, 0x5516, 0x5517,
+    0x5518, 0x5519, 0x551A, 0x551B, 0x551D, 0x551E, 0x551F, 0x5520, 0x5521, 0x5523, 0x5524, 0x5525,
+    0x5526, 0x5527, 0x5528, 0x5529, 0x552A, 0x552B, 0x552C, 0x552D, 0x552E, 0x552F, 0x5530, 0x5531,
+    0x5532, 0x5533, 0x5534, 0x5535, 0x5536, 0x5537, 0x5538, 0x5539, 0x553A, 0x553B, 0x553C, 0x553D,
+    0x553E, 0x553F, 0x5540, 0x5542, 0x5543, 0x5544, 0x5545, 0x5546, 0x5547, 0x5548, 0x5549, 0x554A,
+    0x554B, 0x554C, 0x554D, 0x554E, 0x554F, 0x5550, 0x5551, 0x5553, 0x5554, 0x5555, 0x5556, 0x5557,
+    0x5558, 0x5559, 0x555A, 0x555B, 0x5561, 0x5564, 0x556F, 0x5572, 0x557D, 0x557F, 0x5587, 0x558B,
+    0x558C, 0x558F, 0x5590, 0x5591, 0x5593, 0x55BA, 0x55BB, 0x55BC, 0x55BD, 0x55BE, 0x55BF, 0x55C0,
+    0x55C1, 0x55C3, 0x55C4, 0x55C5, 0x55C6, 0x55C9, 0x55CA, 0x55CB, 0x55CC, 0x55CD, 0x55CE, 0x55CF,
+    0x55D1, 0x55D2, 0x55D3, 0x55D4, 0x55D5, 0x55D6, 0x55D7, 0x55D8, 0x55D9, 0x55DA, 0x55DB, 0x55DC,
+    0x55DD, 0x55DE, 0x55DF, 0x55E0, 0x55E1, 0x55E2, 0x55E4, 0x55E5, 0x55E6, 0x55E7, 0x55E8, 0x55E9,
+    0x55EA, 0x55EB, 0x55EC, 0x55ED, 0x55EF, 0x55F0, 0x55F1, 0x55F3, 0x55F4, 0x55F5, 0x55F6, 0x55F7,
+    0x55F8, 0x55F9, 0x55FA, 0x55FB, 0x55FC, 0x55FD, 0x55FE, 0x55FF, 0x5600, 0x5601, 0x5602, 0x5603,
+    0x5604, 0x5605, 0x5606, 0x5607, 0x5608, 0x5609, 0x560A, 0x560B, 0x560C, 0x560D, 0x560E, 0x560F,
+    0x5610, 0x5611, 0x5612, 0x5613, 0x5614, 0x5615, 0x5617, 0x5618, 0x5678, 0x5679, 0x567A, 0x567B,
+    0x567C, 0x567D, 0x567F, 0x5680, 0x5681, 0x5682, 0x5683, 0x5684, 0x5685, 0x5686, 0x5687, 0x5689,
+    0x568A, 0x568D, 0x568E, 0x568F, 0x5690, 0x5691, 0x5692, 0x5693, 0x5694, 0x5695, 0x5696, 0x5697,
+    0x5698, 0x5699, 0x569A, 0x569B, 0x569C, 0x569E, 0x569F, 0x56A0, 0x56A2, 0x56A3, 0x56A4, 0x56A5,
+    0x56A7, 0x56A8, 0x56A9, 0x56AA, 0x56AB, 0x56AC, 0x56AD, 0x56AE, 0x56AF, 0x56B0, 0x56B1, 0x56B2,
+    0x56B3, 0x56B4, 0x56B5, 0x56B6, 0x56B7, 0x56B8, 0x56B9, 0x56BA, 0x56BB, 0x56BD, 0x56BF, 0x56C0,
+    0x56C1, 0x56C2, 0x56C3, 0x56C4, 0x56C5, 0x56C6, 0x56C7, 0x56C8, 0x56C9, 0x56CA, 0x56CB, 0x56CC,
+    0x56CD, 0x56CE, 0x56CF, 0x56D0, 0x56D1, 0x56D2, 0x56D3, 0x56D4, 0x56D5, 0x56D6, 0x5736, 0x5737,
+    0x5738, 0x5739, 0x573A, 0x573B, 0x573C, 0x573D, 0x573E, 0x573F, 0x5740, 0x5741, 0x5742, 0x5743,
+    0x5744, 0x5745, 0x5746, 0x5747, 0x5748, 0x5749, 0x574A, 0x5750, 0x5751, 0x5752, 0x5753, 0x5754,
+    0x5755, 0x5757, 0x5758, 0x5759, 0x575A, 0x575B, 0x575C, 0x575D, 0x575E, 0x575F, 0x5760, 0x5761,
+    0x5762, 0x5763, 0x5764, 0x5765, 0x5766, 0x576A, 0x576B, 0x576C, 0x576D, 0x5770, 0x5772, 0x5774,
+    0x577A, 0x577F, 0x5780, 0x5782, 0x578A, 0x578E, 0x5792, 0x5794, 0x57D4, 0x57D6, 0x57D7, 0x57D9,
+    0x57DB, 0x57DC, 0x57DD, 0x57DE, 0x57E2, 0x57E3, 0x57E4, 0x57E5, 0x57E6, 0x57E7, 0x57EC, 0x57EE,
+    0x57F0, 0x57F2, 0x57F4, 0x57F9, 0x57FC, 0x57FF, 0x5801, 0x5802, 0x5803, 0x5804, 0x5805, 0x5807,
+    0x5808, 0x5809, 0x580A, 0x580B, 0x580C, 0x580D, 0x580E, 0x580F, 0x5810, 0x5811, 0x5812, 0x5813,
+    0x5814, 0x5815, 0x5816, 0x5817, 0x5818, 0x581A, 0x581B, 0x581C, 0x581D, 0x581E, 0x581F, 0x5820,
+    0x5821, 0x5822, 0x5823, 0x5824, 0x5825, 0x5826, 0x5827, 0x5828, 0x5829, 0x582A, 0x582B, 0x582C,
+    0x582D, 0x582E, 0x582F, 0x5830, 0x5831, 0x5832, 0x5833, 0x5834, 0x5836, 0x5837, 0x5838, 0x583A,
+    0x583B, 0x583C, 0x583D, 0x583E, 0x583F, 0x5842, 0x5843, 0x5844, 0x5845, 0x5846, 0x5847, 0x5848,
+    0x5849, 0x584A, 0x584B, 0x584C, 0x584D, 0x584E, 0x584F, 0x5851, 0x5852, 0x58B2, 0x5910, 0x5970,
+    0x59CE, 0x5A2E, 0x5A8C, 0x5AA8, 0x5AA9, 0x5AAA, 0x5AAB, 0x5AAC, 0x5AAD, 0x5AAF, 0x5AB0, 0x5AB1,
+    0x5AB2, 0x5AB5, 0x5AB6, 0x5AB7, 0x5AB8, 0x5ABC, 0x5ABD, 0x5ABF, 0x5AC0, 0x5ACA, 0x5ACC, 0x5AD0,
+    0x5AD2, 0x5AD8, 0x5AE1, 0x5AE2, 0x5AE8, 0x5AEC, 0x5B4A, 0x5B53, 0x5B56, 0x5B58, 0x5B59, 0x5B5E,
+    0x5B62, 0x5B65, 0x5B66, 0x5B68, 0x5B6B, 0x5B6D, 0x5B6E, 0x5B6F, 0x5B71, 0x5B72, 0x5B73, 0x5B77,
+    0x5B80, 0x5B81, 0x5B82, 0x5B8E, 0x5B8F, 0x5B90, 0x5B92, 0x5B95, 0x5B96, 0x5B9C, 0x5B9D, 0x5BA6,
+    0x5BA8, 0x5BAA, 0x5C08, 0x5C0D, 0x5C0E, 0x5C0F, 0x5C10, 0x5C15, 0x5C20, 0x5C4D, 0x5C4F, 0x5C5B,
+    0x5C5E, 0x5C63, 0x5C64, 0x5C65, 0x5C66, 0x5C67, 0x5C68, 0x5CC6, 0x5CCA, 0x5CCB, 0x5CCD, 0x5CCE,
+    0x5CD1, 0x5CD3, 0x5CD6, 0x5CD7, 0x5CDA, 0x5CDB, 0x5CDC, 0x5CDD, 0x5CDE, 0x5CDF, 0x5CE0, 0x5CE1,
+    0x5CE2, 0x5CE3, 0x5CE4, 0x5CE5, 0x5CE6, 0x5CE7, 0x5CE8, 0x5CE9, 0x5CEA, 0x5CEB, 0x5CEC, 0x5CEE,
+    0x5CEF, 0x5CF0, 0x5CF1, 0x5CF2, 0x5CF4, 0x5CF5, 0x5CF6, 0x5CF7, 0x5CF8, 0x5CF9, 0x5CFA, 0x5CFC,
+    0x5CFD, 0x5CFE, 0x5CFF, 0x5D00, 0x5D01, 0x5D02, 0x5D03, 0x5D04, 0x5D05, 0x5D06, 0x5D07, 0x5D08,
+    0x5D09, 0x5D0A, 0x5D0B, 0x5D0C, 0x5D0D, 0x5D0F, 0x5D11, 0x5D12, 0x5D13, 0x5D14, 0x5D15, 0x5D17,
+    0x5D18, 0x5D1B, 0x5D1C, 0x5D1D, 0x5D24, 0x5D25, 0x5D26,
+];
+
+/// codepoint of the first two byte code of each run
+static TWO_BYTE_RUN_CODE: [u16; 10351] = [
+    0x4E02, 0x4E04, 0x4E0F, 0x4E12, 0x4E17, 0x4E1F, 0x4E23, 0x4E26, 0x4E29, 0x4E2E, 0x4E31, 0x4E33,
+    0x4E35, 0x4E37, 0x4E3C, 0x4E40, 0x4E44, 0x4E46, 0x4E4A, 0x4E51, 0x4E55, 0x4E57, 0x4E5A, 0x4E62,
+    0x4E67, 0x4E6A, 0x4E72, 0x4E74, 0x4E7F, 0x4E87, 0x4E8A, 0x4E90, 0x4E96, 0x4E99, 0x4E9C, 0x4EA3,
+    0x4EAA, 0x4EAF, 0x4EB4, 0x4EB6, 0x4EBC, 0x4EC8, 0x4ECC, 0x4ECF, 0x4ED2, 0x4EDA, 0x4EE0, 0x4EE2,
+    0x4EE6, 0x4EE9, 0x4EED, 0x4EF1, 0x4EF4, 0x4EF8, 0x4EFC, 0x4EFE, 0x4F00, 0x4F02, 0x4F0B, 0x4F12,
+    0x4F1C, 0x4F21, 0x4F23, 0x4F28, 0x4F2C, 0x4F31, 0x4F33, 0x4F35, 0x4F37, 0x4F39, 0x4F3B, 0x4F3E,
+    0x4F44, 0x4F47, 0x4F52, 0x4F54, 0x4F56, 0x4F61, 0x4F66, 0x4F68, 0x4F6A, 0x4F6D, 0x4F71, 0x4F75,
+    0x4F77, 0x4F7D, 0x4F80, 0x4F85, 0x4F8A, 0x4F8C, 0x4F8E, 0x4F90, 0x4F92, 0x4F95, 0x4F98, 0x4F9C,
+    0x4F9E, 0x4FA1, 0x4FA4, 0x4FAB, 0x4FAD, 0x4FB0, 0x4FB6, 0x4FC0, 0x4FC6, 0x4FCB, 0x4FD2, 0x4FD9,
+    0x4FDB, 0x4FE0, 0x4FE2, 0x4FE4, 0x4FE7, 0x4FEB, 0x4FF0, 0x4FF2, 0x4FF4, 0x4FF9, 0x4FFB, 0x4FFF,
+    0x500E, 0x5010, 0x5013, 0x5015, 0x501B, 0x501D, 0x5020, 0x5022, 0x5027, 0x502B, 0x502F, 0x503B,
+    0x503D, 0x503F, 0x5044, 0x5049, 0x504D, 0x5050, 0x5056, 0x505B, 0x505D, 0x5066, 0x506D, 0x5078,
+    0x507C, 0x5081, 0x5086, 0x5089, 0x508E, 0x50A4, 0x50A6, 0x50AA, 0x50AD, 0x50B3, 0x50BC, 0x50D0,
+    0x50D7, 0x50DB, 0x50E8, 0x50EF, 0x50F4, 0x50F6, 0x50FC, 0x5108, 0x510C, 0x5113, 0x5122, 0x5142,
+    0x5147, 0x514A, 0x514C, 0x514E, 0x5152, 0x5157, 0x515B, 0x515D, 0x5163, 0x5166, 0x5169, 0x516F,
+    0x5172, 0x517A, 0x517E, 0x5183, 0x5186, 0x518A, 0x518E, 0x5193, 0x5198, 0x519A, 0x519D, 0x51A1,
+    0x51A3, 0x51A6, 0x51AD, 0x51B4, 0x51B8, 0x51BE, 0x51C1, 0x51C5, 0x51C8, 0x51CA, 0x51CD, 0x51D0,
+    0x51D2, 0x51DC, 0x51DE, 0x51E2, 0x51E5, 0x51EC, 0x51EE, 0x51F1, 0x51F4, 0x51F7, 0x51FE, 0x5204,
+    0x5209, 0x520B, 0x520F, 0x5213, 0x521C, 0x521E, 0x5221, 0x5225, 0x522A, 0x522C, 0x522F, 0x5231,
+    0x5234, 0x523C, 0x523E, 0x5244, 0x524B, 0x524E, 0x5252, 0x5255, 0x5257, 0x525D, 0x525F, 0x5262,
+    0x5266, 0x5268, 0x526B, 0x5270, 0x5273, 0x527E, 0x5280, 0x5283, 0x5289, 0x5291, 0x5294, 0x529C,
+    0x52A4, 0x52AE, 0x52B4, 0x52C0, 0x52C4, 0x52C8, 0x52CA, 0x52CC, 0x52D1, 0x52D3, 0x52D7, 0x52D9,
+    0x52E0, 0x52E5, 0x52F1, 0x52FB, 0x5301, 0x5307, 0x5309, 0x530E, 0x5311, 0x5318, 0x531B, 0x531E,
+    0x5322, 0x5324, 0x5327, 0x532B, 0x532F, 0x533C, 0x5340, 0x5342, 0x5344, 0x5346, 0x534B, 0x5350,
+    0x5354, 0x5358, 0x535B, 0x535D, 0x5365, 0x5368, 0x536A, 0x536C, 0x5372, 0x5376, 0x5379, 0x537B,
+    0x5380, 0x5383, 0x5387, 0x538A, 0x538E, 0x5396, 0x5399, 0x539B, 0x539E, 0x53A0, 0x53A4, 0x53A7,
+    0x53AA, 0x53AF, 0x53B7, 0x53BC, 0x53C0, 0x53C3, 0x53CE, 0x53D2, 0x53D5, 0x53DA, 0x53DC, 0x53E1,
+    0x53E7, 0x53F4, 0x53FA, 0x53FE, 0x5402, 0x5405, 0x5407, 0x540B, 0x5414, 0x5418, 0x541C, 0x5422,
+    0x5424, 0x542A, 0x5430, 0x5433, 0x5436, 0x543A, 0x543D, 0x543F, 0x5441, 0x5444, 0x5447, 0x5449,
+    0x544C, 0x5451, 0x545A, 0x545D, 0x5463, 0x5465, 0x5467, 0x5469, 0x5474, 0x5479, 0x547E, 0x5481,
+    0x5483, 0x5485, 0x5487, 0x548D, 0x5491, 0x5493, 0x5497, 0x549C, 0x549E, 0x54A5, 0x54AE, 0x54B0,
+    0x54B2, 0x54B5, 0x54B9, 0x54BC, 0x54BE, 0x54C3, 0x54C5, 0x54CA, 0x54D6, 0x54D8, 0x54DB, 0x54E0,
+    0x54EB, 0x54EF, 0x54F4, 0x54FB, 0x54FE, 0x5500, 0x5502, 0x5508, 0x550A, 0x5512, 0x5515, 0x551C,
+    0x5521, 0x5525, 0x5528, 0x552B, 0x552D, 0x5532, 0x5534, 0x5538, 0x553D, 0x5540, 0x5542, 0x5545,
+    0x5547, 0x554B, 0x5551, 0x5557, 0x555D, 0x5562, 0x5568, 0x556B, 0x556F, 0x5579, 0x557D, 0x557F,
+    0x5585, 0x558C, 0x5590, 0x5592, 0x5595, 0x559A, 0x559E, 0x55A0, 0x55A8, 0x55B2, 0x55B4, 0x55B6,
+    0x55B8, 0x55BA, 0x55BC, 0x55BF, 0x55C6, 0x55CA, 0x55CE, 0x55D5, 0x55D7, 0x55DE, 0x55E0, 0x55E2,
+    0x55E7, 0x55E9, 0x55ED, 0x55F0, 0x55F4, 0x55F6, 0x55F8, 0x55FF, 0x5602, 0x560A, 0x560D, 0x5610,
+    0x5619, 0x561C, 0x5620, 0x5625, 0x5628, 0x562E, 0x5633, 0x5635, 0x5637, 0x563A, 0x563C, 0x5640,
+    0x564F, 0x5655, 0x565A, 0x565D, 0x5663, 0x5665, 0x566D, 0x5672, 0x5677, 0x567D, 0x5687, 0x5690,
+    0x5694, 0x56A4, 0x56B0, 0x56B8, 0x56BD, 0x56CB, 0x56D5, 0x56D8, 0x56DC, 0x56E3, 0x56E5, 0x56EC,
+    0x56EE, 0x56F2, 0x56F6, 0x56FB, 0x5700, 0x5705, 0x5707, 0x570B, 0x571D, 0x5720, 0x5724, 0x572B,
+    0x5731, 0x5734, 0x573C, 0x573F, 0x5741, 0x5743, 0x5748, 0x574B, 0x5752, 0x5758, 0x5762, 0x5765,
+    0x5767, 0x576C, 0x576E, 0x5770, 0x5774, 0x5778, 0x577D, 0x5787, 0x578D, 0x5794, 0x579C, 0x57A5,
+    0x57A8, 0x57AA, 0x57AC, 0x57AF, 0x57B3, 0x57B5, 0x57B9, 0x57C4, 0x57CC, 0x57D0, 0x57D3, 0x57D6,
+    0x57DB, 0x57DE, 0x57E1, 0x57E5, 0x57EE, 0x57F0, 0x57F5, 0x57FB, 0x57FE, 0x5801, 0x5803, 0x5808,
+    0x580C, 0x580E, 0x5812, 0x5816, 0x581A, 0x581F, 0x5822, 0x5825, 0x582B, 0x5831, 0x5836, 0x5845,
+    0x584E, 0x5852, 0x5855, 0x5859, 0x585F, 0x5866, 0x586D, 0x587F, 0x5882, 0x5884, 0x5886, 0x588A,
+    0x5894, 0x589B, 0x58A0, 0x58AA, 0x58BD, 0x58C2, 0x58C6, 0x58D2, 0x58D6, 0x58E5, 0x58ED, 0x58EF,
+    0x58F1, 0x58F4, 0x58F7, 0x58FA, 0x5903, 0x5905, 0x5908, 0x590E, 0x5910, 0x5917, 0x591B, 0x591D,
+    0x5920, 0x5926, 0x5928, 0x592C, 0x5930, 0x5932, 0x5935, 0x593B, 0x593D, 0x5943, 0x5945, 0x594A,
+    0x594C, 0x5950, 0x5952, 0x5959, 0x595B, 0x5961, 0x5963, 0x5966, 0x5975, 0x5977, 0x597A, 0x597E,
+    0x5985, 0x5989, 0x598B, 0x598E, 0x5994, 0x5998, 0x599A, 0x599F, 0x59A6, 0x59AC, 0x59B0, 0x59B3,
+    0x59BA, 0x59BC, 0x59BF, 0x59C7, 0x59CC, 0x59D5, 0x59D9, 0x59DB, 0x59DE, 0x59E4, 0x59E6, 0x59E9,
+    0x59ED, 0x59FA, 0x59FC, 0x5A00, 0x5A02, 0x5A0A, 0x5A0D, 0x5A12, 0x5A14, 0x5A19, 0x5A1D, 0x5A21,
+    0x5A24, 0x5A26, 0x5A2A, 0x5A33, 0x5A35, 0x5A37, 0x5A3D, 0x5A41, 0x5A47, 0x5A4B, 0x5A56, 0x5A5B,
+    0x5A63, 0x5A68, 0x5A6B, 0x5A78, 0x5A7B, 0x5A80, 0x5A93, 0x5A9C, 0x5AAB, 0x5AB4, 0x5AB6, 0x5AB9,
+    0x5ABF, 0x5AC3, 0x5ACA, 0x5ACD, 0x5AD3, 0x5AD5, 0x5AD7, 0x5AD9, 0x5ADD, 0x5AE2, 0x5AE4, 0x5AE7,
+    0x5AEA, 0x5AEC, 0x5AF2, 0x5B0A, 0x5B18, 0x5B33, 0x5B35, 0x5B38, 0x5B41, 0x5B52, 0x5B56, 0x5B5E,
+    0x5B60, 0x5B67, 0x5B6B, 0x5B6D, 0x5B72, 0x5B74, 0x5B76, 0x5B7B, 0x5B7E, 0x5B82, 0x5B86, 0x5B8A,
+    0x5B8D, 0x5B90, 0x5B94, 0x5B96, 0x5B9F, 0x5BA7, 0x5BAC, 0x5BB1, 0x5BB7, 0x5BBA, 0x5BC0, 0x5BC3,
+    0x5BC8, 0x5BCD, 0x5BD1, 0x5BD4, 0x5BE0, 0x5BE2, 0x5BE6, 0x5BE9, 0x5BEF, 0x5BF1, 0x5BFD, 0x5C00,
+    0x5C02, 0x5C05, 0x5C07, 0x5C0B, 0x5C10, 0x5C12, 0x5C17, 0x5C19, 0x5C1B, 0x5C1E, 0x5C23, 0x5C26,
+    0x5C28, 0x5C2D, 0x5C32, 0x5C35, 0x5C43, 0x5C46, 0x5C4C, 0x5C52, 0x5C56, 0x5C5A, 0x5C5F, 0x5C62,
+    0x5C64, 0x5C67, 0x5C70, 0x5C72, 0x5C7B, 0x5C80, 0x5C83, 0x5C89, 0x5C8E, 0x5C92, 0x5C95, 0x5C9D,
+    0x5CA4, 0x5CAA, 0x5CAE, 0x5CB2, 0x5CB4, 0x5CB6, 0x5CB9, 0x5CBE, 0x5CC0, 0x5CC2, 0x5CC5, 0x5CCC,
+    0x5CD3, 0x5CDA, 0x5CE2, 0x5CE7, 0x5CE9, 0x5CEB, 0x5CEE, 0x5CF1, 0x5CFC, 0x5D04, 0x5D08, 0x5D0F,
+    0x5D15, 0x5D17, 0x5D1C, 0x5D1F, 0x5D25, 0x5D28, 0x5D2A, 0x5D2F, 0x5D35, 0x5D3F, 0x5D48, 0x5D4D,
+    0x5D59, 0x5D5C, 0x5D5E, 0x5D6A, 0x5D6D, 0x5D70, 0x5D75, 0x5D83, 0x5D9A, 0x5D9E, 0x5DB8, 0x5DC6,
+    0x5DCE, 0x5DDC, 0x5DDF, 0x5DE3, 0x5DEA, 0x5DEC, 0x5DF0, 0x5DF5, 0x5DF8, 0x5DFF, 0x5E04, 0x5E07,
+    0x5E09, 0x5E0D, 0x5E12, 0x5E17, 0x5E1E, 0x5E28, 0x5E2F, 0x5E32, 0x5E39, 0x5E3E, 0x5E43, 0x5E46,
+    0x5E4D, 0x5E56, 0x5E5C, 0x5E5F, 0x5E63, 0x5E75, 0x5E77, 0x5E79, 0x5E7E, 0x5E81, 0x5E85, 0x5E88,
+    0x5E8C, 0x5E92, 0x5E98, 0x5E9B, 0x5E9D, 0x5EA1, 0x5EA8, 0x5EAE, 0x5EB4, 0x5EBA, 0x5EBF, 0x5ECB,
+    0x5ED4, 0x5ED7, 0x5EDC, 0x5EE9, 0x5EEB, 0x5EF5, 0x5EF8, 0x5EFB, 0x5F05, 0x5F09, 0x5F0C, 0x5F10,
+    0x5F12, 0x5F14, 0x5F16, 0x5F19, 0x5F1C, 0x5F21, 0x5F28, 0x5F2B, 0x5F2E, 0x5F30, 0x5F32, 0x5F3B,
+    0x5F3D, 0x5F41, 0x5F51, 0x5F54, 0x5F59, 0x5F5E, 0x5F63, 0x5F65, 0x5F67, 0x5F6B, 0x5F6E, 0x5F72,
+    0x5F74, 0x5F78, 0x5F7A, 0x5F7D, 0x5F83, 0x5F86, 0x5F8D, 0x5F91, 0x5F93, 0x5F96, 0x5F9A, 0x5F9D,
+    0x5FA2, 0x5FA9, 0x5FAB, 0x5FAF, 0x5FB6, 0x5FB8, 0x5FBE, 0x5FC7, 0x5FCA, 0x5FCE, 0x5FD3, 0x5FDA,
+    0x5FDE, 0x5FE2, 0x5FE5, 0x5FE8, 0x5FEC, 0x5FEF, 0x5FF2, 0x5FF6, 0x5FF9, 0x5FFC, 0x6007, 0x600B,
+    0x6010, 0x6013, 0x6017, 0x601A, 0x601E, 0x6022, 0x602C, 0x6030, 0x6036, 0x603D, 0x6040, 0x6044,
+    0x604C, 0x604E, 0x6051, 0x6053, 0x6056, 0x605B, 0x605E, 0x6065, 0x606E, 0x6071, 0x6074, 0x6077,
+    0x607E, 0x6080, 0x6085, 0x608A, 0x608E, 0x6093, 0x6095, 0x6097, 0x609C, 0x609E, 0x60A1, 0x60A4,
+    0x60A7, 0x60A9, 0x60AE, 0x60B0, 0x60B3, 0x60B5, 0x60B9, 0x60BD, 0x60C7, 0x60CC, 0x60D2, 0x60D6,
+    0x60D9, 0x60DB, 0x60DE, 0x60E1, 0x60EA, 0x60F1, 0x60F5, 0x60F7, 0x60FB, 0x6102, 0x6107, 0x610A,
+    0x6110, 0x6116, 0x611B, 0x6121, 0x6125, 0x6128, 0x612C, 0x6140, 0x6149, 0x614B, 0x614D, 0x614F,
+    0x6152, 0x6156, 0x615E, 0x6163, 0x6169, 0x6171, 0x6176, 0x6178, 0x618C, 0x618F, 0x6195, 0x619E,
+    0x61AA, 0x61AD, 0x61B8, 0x61BF, 0x61C3, 0x61C9, 0x61CC, 0x61D3, 0x61D5, 0x61E7, 0x61F6, 0x6200,
+    0x6207, 0x6209, 0x6213, 0x6219, 0x621C, 0x6220, 0x6223, 0x6226, 0x622B, 0x622D, 0x622F, 0x6235,
+    0x6238, 0x6242, 0x6244, 0x624A, 0x624F, 0x6255, 0x6259, 0x625C, 0x6264, 0x6268, 0x6271, 0x6274,
+    0x6277, 0x627A, 0x627D, 0x6281, 0x6285, 0x628B, 0x6294, 0x6299, 0x629C, 0x62A3, 0x62A6, 0x62A9,
+    0x62AD, 0x62B2, 0x62B6, 0x62BA, 0x62BE, 0x62C0, 0x62C3, 0x62CB, 0x62CF, 0x62D1, 0x62D5, 0x62DD,
+    0x62E0, 0x62E4, 0x62EA, 0x62F0, 0x62F2, 0x62F5, 0x62F8, 0x6300, 0x6303, 0x630A, 0x630F, 0x6312,
+    0x6317, 0x631C, 0x6326, 0x6329, 0x632C, 0x6330, 0x6333, 0x633B, 0x633E, 0x6344, 0x6347, 0x634A,
+    0x6351, 0x6356, 0x6360, 0x6364, 0x6368, 0x636A, 0x636F, 0x6372, 0x6378, 0x637C, 0x6381, 0x6383,
+    0x638B, 0x638D, 0x6391, 0x6393, 0x6397, 0x6399, 0x63A1, 0x63A4, 0x63A6, 0x63AB, 0x63AF, 0x63B1,
+    0x63B5, 0x63B9, 0x63BB, 0x63BD, 0x63BF, 0x63C5, 0x63C7, 0x63CA, 0x63D1, 0x63D3, 0x63D7, 0x63DF,
+    0x63E2, 0x63E4, 0x63EB, 0x63EE, 0x63F3, 0x63F5, 0x63F7, 0x63F9, 0x63FE, 0x6403, 0x6406, 0x640D,
+    0x6411, 0x6415, 0x641D, 0x641F, 0x6422, 0x6427, 0x642B, 0x642E, 0x6435, 0x643B, 0x643E, 0x6440,
+    0x6442, 0x6449, 0x644B, 0x6453, 0x6455, 0x6459, 0x645F, 0x6468, 0x646A, 0x646E, 0x647B, 0x6483,
+    0x6486, 0x6488, 0x6493, 0x6497, 0x649A, 0x649F, 0x64A5, 0x64AA, 0x64AF, 0x64B1, 0x64B6, 0x64B9,
+    0x64BB, 0x64BD, 0x64C1, 0x64C3, 0x64C6, 0x64CF, 0x64D1, 0x64D3, 0x64D9, 0x64DF, 0x64E3, 0x64E5,
+    0x64E7, 0x6501, 0x650A, 0x6513, 0x6519, 0x6526, 0x652C, 0x6530, 0x6537, 0x653A, 0x653C, 0x6540,
+    0x6546, 0x654A, 0x654D, 0x6550, 0x6552, 0x6557, 0x655A, 0x655C, 0x655F, 0x6564, 0x6567, 0x656D,
+    0x6571, 0x6573, 0x6575, 0x6578, 0x6588, 0x658D, 0x6592, 0x6594, 0x6598, 0x659A, 0x659D, 0x65A0,
+    0x65A2, 0x65A6, 0x65A8, 0x65AA, 0x65AC, 0x65AE, 0x65B1, 0x65BA, 0x65BE, 0x65C2, 0x65C7, 0x65CD,
+    0x65D0, 0x65D3, 0x65D8, 0x65E1, 0x65E3, 0x65EA, 0x65F2, 0x65F8, 0x65FB, 0x6601, 0x6604, 0x6607,
+    0x660B, 0x660D, 0x6610, 0x6616, 0x661A, 0x661E, 0x6621, 0x6626, 0x6629, 0x662E, 0x6630, 0x6632,
+    0x6637, 0x663D, 0x663F, 0x6642, 0x6644, 0x664D, 0x6650, 0x6658, 0x665B, 0x6660, 0x6662, 0x6665,
+    0x6667, 0x6669, 0x6671, 0x6675, 0x6678, 0x667B, 0x667F, 0x6683, 0x6685, 0x6688, 0x668D, 0x6692,
+    0x6698, 0x669E, 0x66A9, 0x66AF, 0x66B5, 0x66BA, 0x66BF, 0x66DA, 0x66DE, 0x66E7, 0x66EA, 0x66F1,
+    0x66F5, 0x66F8, 0x66FA, 0x66FD, 0x6701, 0x670C, 0x670E, 0x6711, 0x6716, 0x6718, 0x671C, 0x671E,
+    0x6720, 0x6727, 0x6729, 0x672E, 0x6730, 0x6732, 0x6736, 0x673B, 0x673E, 0x6741, 0x6744, 0x6747,
+    0x674A, 0x674D, 0x6752, 0x6754, 0x6757, 0x675D, 0x6762, 0x6766, 0x676B, 0x676E, 0x6771, 0x6774,
+    0x6776, 0x6778, 0x677D, 0x6780, 0x6782, 0x6785, 0x6788, 0x678A, 0x678C, 0x6791, 0x6796, 0x6799,
+    0x679B, 0x679F, 0x67A4, 0x67A6, 0x67A9, 0x67AC, 0x67AE, 0x67B1, 0x67B4, 0x67B9, 0x67C2, 0x67C5,
+    0x67D5, 0x67DB, 0x67DF, 0x67E1, 0x67E3, 0x67E6, 0x67EA, 0x67ED, 0x67F2, 0x67F5, 0x67FE, 0x6801,
+    0x6806, 0x680D, 0x6810, 0x6812, 0x6814, 0x6818, 0x681E, 0x6822, 0x682B, 0x6834, 0x683A, 0x683F,
+    0x6847, 0x684B, 0x684D, 0x684F, 0x6852, 0x6856, 0x686A, 0x686C, 0x6875, 0x6878, 0x6882, 0x6884,
+    0x6887, 0x6890, 0x6894, 0x6898, 0x68A3, 0x68A9, 0x68AE, 0x68B1, 0x68B4, 0x68B6, 0x68C1, 0x68C3,
+    0x68CA, 0x68CC, 0x68CE, 0x68D3, 0x68D6, 0x68D9, 0x68DB, 0x68E1, 0x68E4, 0x68EF, 0x68F2, 0x68F6,
+    0x68FB, 0x68FD, 0x6902, 0x6906, 0x690C, 0x690F, 0x6911, 0x6913, 0x6921, 0x6925, 0x692E, 0x6931,
+    0x6935, 0x693A, 0x693E, 0x6940, 0x6943, 0x6955, 0x6958, 0x695B, 0x695F, 0x6961, 0x6964, 0x6967,
+    0x696C, 0x696F, 0x6972, 0x697A, 0x697D, 0x6981, 0x6983, 0x6985, 0x698A, 0x698E, 0x6996, 0x6999,
+    0x699D, 0x69A9, 0x69AC, 0x69AE, 0x69B2, 0x69B5, 0x69B8, 0x69BC, 0x69C2, 0x69CB, 0x69CD, 0x69CF,
+    0x69D1, 0x69D5, 0x69DC, 0x69E1, 0x69EE, 0x69F3, 0x69FE, 0x6A00, 0x6A0B, 0x6A19, 0x6A20, 0x6A22,
+    0x6A29, 0x6A2B, 0x6A30, 0x6A32, 0x6A36, 0x6A3F, 0x6A45, 0x6A48, 0x6A51, 0x6A5A, 0x6A5C, 0x6A62,
+    0x6A66, 0x6A72, 0x6A7A, 0x6A7D, 0x6A81, 0x6A85, 0x6A8F, 0x6A92, 0x6A98, 0x6AA1, 0x6AAA, 0x6AAD,
+    0x6B25, 0x6B28, 0x6B33, 0x6B38, 0x6B3B, 0x6B3F, 0x6B44, 0x6B48, 0x6B4A, 0x6B4D, 0x6B5A, 0x6B68,
+    0x6B6B, 0x6B7A, 0x6B7D, 0x6B85, 0x6B88, 0x6B8C, 0x6B8E, 0x6B94, 0x6B97, 0x6B9C, 0x6BA2, 0x6BAB,
+    0x6BB6, 0x6BB8, 0x6BC0, 0x6BC3, 0x6BC6, 0x6BCC, 0x6BCE, 0x6BD0, 0x6BD8, 0x6BDA, 0x6BDC, 0x6BE2,
+    0x6BEC, 0x6BF0, 0x6BF4, 0x6BF6, 0x6BFA, 0x6BFE, 0x6C08, 0x6C0E, 0x6C12, 0x6C17, 0x6C1C, 0x6C20,
+    0x6C23, 0x6C25, 0x6C2B, 0x6C31, 0x6C33, 0x6C36, 0x6C39, 0x6C3E, 0x6C43, 0x6C48, 0x6C4B, 0x6C51,
+    0x6C56, 0x6C58, 0x6C62, 0x6C65, 0x6C6B, 0x6C71, 0x6C73, 0x6C75, 0x6C77, 0x6C7A, 0x6C7F, 0x6C84,
+    0x6C87, 0x6C8A, 0x6C8D, 0x6C91, 0x6C95, 0x6C9A, 0x6C9C, 0x6CA0, 0x6CA2, 0x6CA8, 0x6CAC, 0x6CAF,
+    0x6CB4, 0x6CBA, 0x6CC0, 0x6CC6, 0x6CCB, 0x6CCD, 0x6CD1, 0x6CD8, 0x6CDC, 0x6CDF, 0x6CE4, 0x6CE6,
+    0x6CE9, 0x6CEC, 0x6CF2, 0x6CF4, 0x6CF9, 0x6CFF, 0x6D02, 0x6D05, 0x6D08, 0x6D0D, 0x6D0F, 0x6D13,
+    0x6D18, 0x6D1C, 0x6D1F, 0x6D26, 0x6D28, 0x6D2C, 0x6D2F, 0x6D34, 0x6D36, 0x6D3A, 0x6D3F, 0x6D42,
+    0x6D44, 0x6D49, 0x6D4C, 0x6D50, 0x6D55, 0x6D5B, 0x6D5D, 0x6D5F, 0x6D61, 0x6D64, 0x6D67, 0x6D6B,
+    0x6D70, 0x6D75, 0x6D79, 0x6D7D, 0x6D83, 0x6D86, 0x6D8A, 0x6D8D, 0x6D8F, 0x6D92, 0x6D96, 0x6D9C,
+    0x6DA2, 0x6DA5, 0x6DAC, 0x6DB0, 0x6DB3, 0x6DB6, 0x6DB9, 0x6DC1, 0x6DC8, 0x6DCD, 0x6DD2, 0x6DD7,
+    0x6DDA, 0x6DDF, 0x6DE2, 0x6DE5, 0x6DE7, 0x6DED, 0x6DEF, 0x6DF2, 0x6DF4, 0x6DF8, 0x6DFA, 0x6DFD,
+    0x6E06, 0x6E0B, 0x6E0F, 0x6E12, 0x6E15, 0x6E18, 0x6E1B, 0x6E1E, 0x6E22, 0x6E26, 0x6E2A, 0x6E2C,
+    0x6E2E, 0x6E30, 0x6E33, 0x6E35, 0x6E39, 0x6E3B, 0x6E45, 0x6E4F, 0x6E55, 0x6E57, 0x6E59, 0x6E5C,
+    0x6E60, 0x6E6C, 0x6E6F, 0x6E80, 0x6E84, 0x6E87, 0x6E8A, 0x6E91, 0x6E99, 0x6E9D, 0x6EA0, 0x6EA3,
+    0x6EA6, 0x6EA8, 0x6EAB, 0x6EB0, 0x6EB3, 0x6EB5, 0x6EB8, 0x6EBC, 0x6EBE, 0x6EC3, 0x6EC8, 0x6ECC,
+    0x6ED0, 0x6ED2, 0x6ED6, 0x6ED8, 0x6EDB, 0x6EE3, 0x6EE7, 0x6EEA, 0x6EF5, 0x6EFA, 0x6F03, 0x6F07,
+    0x6F0A, 0x6F10, 0x6F16, 0x6F21, 0x6F25, 0x6F2C, 0x6F2E, 0x6F30, 0x6F32, 0x6F34, 0x6F37, 0x6F3F,
+    0x6F48, 0x6F4C, 0x6F4E, 0x6F59, 0x6F5D, 0x6F5F, 0x6F63, 0x6F67, 0x6F6F, 0x6F73, 0x6F75, 0x6F79,
+    0x6F7B, 0x6F7D, 0x6F85, 0x6F8A, 0x6F8F, 0x6F9D, 0x6FA2, 0x6FA8, 0x6FB4, 0x6FB7, 0x6FBA, 0x6FC1,
+    0x6FC3, 0x6FCA, 0x6FD3, 0x6FDF, 0x6FE2, 0x6FF0, 0x7012, 0x701C, 0x7024, 0x7036, 0x703A, 0x704D,
+    0x7050, 0x705F, 0x706E, 0x7071, 0x7077, 0x7079, 0x707D, 0x7081, 0x7086, 0x708B, 0x708F, 0x7093,
+    0x7097, 0x709A, 0x709E, 0x70B0, 0x70B2, 0x70B4, 0x70BA, 0x70BE, 0x70C4, 0x70C9, 0x70CB, 0x70DA,
+    0x70DC, 0x70E0, 0x70E5, 0x70EA, 0x70EE, 0x70F0, 0x70F8, 0x70FA, 0x70FE, 0x710B, 0x7111, 0x7114,
+    0x7117, 0x711B, 0x7127, 0x7132, 0x7137, 0x7146, 0x714B, 0x714D, 0x714F, 0x715D, 0x715F, 0x7165,
+    0x7169, 0x716F, 0x7174, 0x7179, 0x717B, 0x717E, 0x7185, 0x718B, 0x7190, 0x7195, 0x719A, 0x71A1,
+    0x71A9, 0x71AD, 0x71B4, 0x71B6, 0x71BA, 0x71C4, 0x71CF, 0x71D6, 0x71E1, 0x71E6, 0x71E8, 0x71EF,
+    0x71FA, 0x7207, 0x721E, 0x7229, 0x722B, 0x722D, 0x7232, 0x723A, 0x723C, 0x723E, 0x7240, 0x7249,
+    0x724E, 0x7253, 0x7257, 0x725A, 0x725C, 0x725E, 0x7260, 0x7263, 0x7268, 0x726A, 0x7270, 0x7273,
+    0x7276, 0x727B, 0x7282, 0x7285, 0x728C, 0x728E, 0x7290, 0x7293, 0x72A0, 0x72AE, 0x72B1, 0x72B5,
+    0x72BA, 0x72C5, 0x72C9, 0x72CF, 0x72D1, 0x72D3, 0x72D8, 0x72DA, 0xE4C6, 0x3000, 0x00B7, 0x02C9,
+    0x02C7, 0x00A8, 0x3003, 0x3005, 0x2014, 0xFF5E, 0x2016, 0x2026, 0x2018, 0x201C, 0x3014, 0x3008,
+    0x3016, 0x3010, 0x00B1, 0x00D7, 0x00F7, 0x2236, 0x2227, 0x2211, 0x220F, 0x222A, 0x2229, 0x2208,
+    0x2237, 0x221A, 0x22A5, 0x2225, 0x2220, 0x2312, 0x2299, 0x222B, 0x222E, 0x2261, 0x224C, 0x2248,
+    0x223D, 0x221D, 0x2260, 0x226E, 0x2264, 0x221E, 0x2235, 0x2234, 0x2642, 0x2640, 0x00B0, 0x2032,
+    0x2103, 0xFF04, 0x00A4, 0xFFE0, 0x2030, 0x00A7, 0x2116, 0x2606, 0x2605, 0x25CB, 0x25CF, 0x25CE,
+    0x25C7, 0x25C6, 0x25A1, 0x25A0, 0x25B3, 0x25B2, 0x203B, 0x2192, 0x2190, 0x2193, 0x3013, 0xE526,
+    0x2170, 0xE766, 0x2488, 0x2474, 0x2460, 0x20AC, 0xE76D, 0x3220, 0xE76E, 0x2160, 0xE770, 0xE586,
+    0xFF01, 0xFFE5, 0xFF05, 0xFFE3, 0xE5E6, 0x3041, 0xE772, 0xE646, 0x30A1, 0xE77D, 0xE6A6, 0x0391,
+    0x03A3, 0xE785, 0x03B1, 0x03C3, 0xE78D, 0xFE35, 0xFE39, 0xFE3F, 0xFE3D, 0xFE41, 0xE794, 0xFE3B,
+    0xFE37, 0xFE31, 0xE796, 0xFE33, 0xE797, 0xE706, 0x0410, 0x0401, 0x0416, 0xE7A0, 0x0430, 0x0451,
+    0x0436, 0xE7AF, 0x02CA, 0x02D9, 0x2013, 0x2015, 0x2025, 0x2035, 0x2105, 0x2109, 0x2196, 0x2215,
+    0x221F, 0x2223, 0x2252, 0x2266, 0x22BF, 0x2550, 0x2581, 0x2593, 0x25BC, 0x25E2, 0x2609, 0x2295,
+    0x3012, 0x301D, 0xE7BC, 0x0101, 0x00E1, 0x01CE, 0x00E0, 0x0113, 0x00E9, 0x011B, 0x00E8, 0x012B,
+    0x00ED, 0x01D0, 0x00EC, 0x014D, 0x00F3, 0x01D2, 0x00F2, 0x016B, 0x00FA, 0x01D4, 0x00F9, 0x01D6,
+    0x01D8, 0x01DA, 0x01DC, 0x00FC, 0x00EA, 0x0251, 0x1E3F, 0x0144, 0x0148, 0x01F9, 0x0261, 0xE7C9,
+    0x3105, 0xE7CD, 0x3021, 0x32A3, 0x338E, 0x339C, 0x33A1, 0x33C4, 0x33CE, 0x33D1, 0x33D5, 0xFE30,
+    0xFFE2, 0xFFE4, 0xE7E2, 0x2121, 0x3231, 0xE7E3, 0x2010, 0xE7E4, 0x30FC, 0x309B, 0x30FD, 0x3006,
+    0x309D, 0xFE49, 0xFE54, 0xFE59, 0xFE68, 0x303E, 0x2FF0, 0x3007, 0xE7F4, 0x2500, 0xE801, 0x72DC,
+    0x72DF, 0x72E2, 0x72EA, 0x72F5, 0x72F9, 0x72FD, 0x7302, 0x7304, 0x730B, 0x730F, 0x7314, 0x7318,
+    0x731F, 0x7323, 0x7326, 0x732D, 0x732F, 0x7332, 0x7335, 0x733A, 0x7340, 0x734E, 0x7351, 0x7353,
+    0x7358, 0x7361, 0x736E, 0x7370, 0xE000, 0x7372, 0x737F, 0x7385, 0x7388, 0x738A, 0x738C, 0x738F,
+    0x7392, 0x7397, 0x739C, 0x73A0, 0x73A3, 0x73AA, 0x73AC, 0x73B1, 0x73B4, 0x73B8, 0x73BC, 0x73C1,
+    0x73C3, 0x73CB, 0x73CE, 0x73D2, 0x73DA, 0x73DF, 0x73E1, 0x73E6, 0x73E8, 0x73EA, 0x73EE, 0x73F3,
+    0xE05E, 0x73F8, 0x7404, 0x7407, 0x740B, 0x7411, 0x741C, 0x7423, 0x7427, 0x7429, 0x742B, 0x742D,
+    0x742F, 0x7431, 0x7437, 0x743D, 0x7442, 0x7456, 0x7458, 0x745D, 0x7460, 0x746E, 0x7471, 0x7478,
+    0xE0BC, 0x747B, 0x747F, 0x7482, 0x7484, 0x7488, 0x748C, 0x748F, 0x7491, 0x749D, 0x749F, 0x74AA,
+    0x74BB, 0x74D3, 0x74DD, 0x74DF, 0x74E1, 0x74E5, 0x74E7, 0x74F0, 0xE11A, 0x74F3, 0x74F5, 0x74F8,
+    0x7500, 0x7505, 0x750E, 0x7510, 0x7512, 0x7514, 0x751B, 0x751D, 0x7520, 0x7526, 0x752A, 0x752E,
+    0x7534, 0x7536, 0x7539, 0x753C, 0x753F, 0x7541, 0x7546, 0x7549, 0x754D, 0x7550, 0x7555, 0x755D,
+    0x7567, 0x756B, 0x7573, 0x7575, 0x757A, 0x7580, 0x7584, 0x7587, 0xE178, 0x7588, 0x758C, 0x7590,
+    0x7593, 0x7595, 0x7598, 0x759B, 0x759E, 0x75A2, 0x75A6, 0x75AD, 0x75B6, 0x75BA, 0x75BF, 0x75C6,
+    0x75CB, 0x75CE, 0x75D3, 0x75D7, 0x75D9, 0x75DC, 0x75DF, 0x75E5, 0x75E9, 0x75EC, 0x75F2, 0x75F5,
+    0x75FA, 0x75FD, 0x7602, 0x7604, 0x7606, 0x760B, 0x760D, 0x7611, 0x7616, 0x761A, 0x761C, 0x7621,
+    0x7623, 0x7627, 0x762C, 0x762E, 0x7631, 0x7636, 0x7639, 0x763D, 0x7641, 0x7644, 0xE1D6, 0x7645,
+    0x764E, 0x7655, 0x7657, 0x765D, 0x765F, 0x7664, 0x766C, 0x7670, 0x7679, 0x767C, 0x767F, 0x7683,
+    0x7685, 0x7689, 0x768C, 0x768F, 0x7692, 0x7694, 0x7697, 0x769A, 0x76A5, 0x76AF, 0x76B3, 0x76B5,
+    0x76C0, 0x76C3, 0x554A, 0x963F, 0x57C3, 0x6328, 0x54CE, 0x5509, 0x54C0, 0x7691, 0x764C, 0x853C,
+    0x77EE, 0x827E, 0x788D, 0x7231, 0x9698, 0x978D, 0x6C28, 0x5B89, 0x4FFA, 0x6309, 0x6697, 0x5CB8,
+    0x80FA, 0x6848, 0x80AE, 0x6602, 0x76CE, 0x51F9, 0x6556, 0x71AC, 0x7FF1, 0x8884, 0x50B2, 0x5965,
+    0x61CA, 0x6FB3, 0x82AD, 0x634C, 0x6252, 0x53ED, 0x5427, 0x7B06, 0x516B, 0x75A4, 0x5DF4, 0x62D4,
+    0x8DCB, 0x9776, 0x628A, 0x8019, 0x575D, 0x9738, 0x7F62, 0x7238, 0x767D, 0x67CF, 0x767E, 0x6446,
+    0x4F70, 0x8D25, 0x62DC, 0x7A17, 0x6591, 0x73ED, 0x642C, 0x6273, 0x822C, 0x9881, 0x677F, 0x7248,
+    0x626E, 0x62CC, 0x4F34, 0x74E3, 0x534A, 0x529E, 0x7ECA, 0x90A6, 0x5E2E, 0x6886, 0x699C, 0x8180,
+    0x7ED1, 0x68D2, 0x78C5, 0x868C, 0x9551, 0x508D, 0x8C24, 0x82DE, 0x80DE, 0x5305, 0x8912, 0x5265,
+    0x76C4, 0x76C7, 0x76C9, 0x76CB, 0x76D3, 0x76D5, 0x76D9, 0x76DC, 0x76E0, 0x76E6, 0x76F0, 0x76F3,
+    0x76F5, 0x76FA, 0x76FD, 0x76FF, 0x7702, 0x7705, 0x770A, 0x770C, 0x770E, 0x771B, 0x7721, 0x7723,
+    0x7727, 0x772A, 0x772E, 0x7730, 0x7739, 0x773B, 0x773D, 0x7742, 0x7744, 0x7748, 0x7752, 0x775C,
+    0x8584, 0x96F9, 0x4FDD, 0x5821, 0x9971, 0x5B9D, 0x62B1, 0x62A5, 0x66B4, 0x8C79, 0x9C8D, 0x7206,
+    0x676F, 0x7891, 0x60B2, 0x5351, 0x5317, 0x8F88, 0x80CC, 0x8D1D, 0x94A1, 0x500D, 0x72C8, 0x5907,
+    0x60EB, 0x7119, 0x88AB, 0x5954, 0x82EF, 0x672C, 0x7B28, 0x5D29, 0x7EF7, 0x752D, 0x6CF5, 0x8E66,
+    0x8FF8, 0x903C, 0x9F3B, 0x6BD4, 0x9119, 0x7B14, 0x5F7C, 0x78A7, 0x84D6, 0x853D, 0x6BD5, 0x6BD9,
+    0x6BD6, 0x5E01, 0x5E87, 0x75F9, 0x95ED, 0x655D, 0x5F0A, 0x5FC5, 0x8F9F, 0x58C1, 0x81C2, 0x907F,
+    0x965B, 0x97AD, 0x8FB9, 0x7F16, 0x8D2C, 0x6241, 0x4FBF, 0x53D8, 0x535E, 0x8FA8, 0x8FAB, 0x904D,
+    0x6807, 0x5F6A, 0x8198, 0x8868, 0x9CD6, 0x618B, 0x522B, 0x762A, 0x5F6C, 0x658C, 0x6FD2, 0x6EE8,
+    0x5BBE, 0x6448, 0x5175, 0x51B0, 0x67C4, 0x4E19, 0x79C9, 0x997C, 0x70B3, 0x775D, 0x7764, 0x7767,
+    0x7769, 0x776D, 0x777A, 0x7781, 0x7786, 0x778F, 0x7793, 0x77A1, 0x77A3, 0x77A6, 0x77A8, 0x77AB,
+    0x77AD, 0x77B1, 0x77B4, 0x77B6, 0x77BC, 0x77BE, 0x77C0, 0x77CE, 0x77D8, 0x77DD, 0x77E4, 0x75C5,
+    0x5E76, 0x73BB, 0x83E0, 0x64AD, 0x62E8, 0x94B5, 0x6CE2, 0x535A, 0x52C3, 0x640F, 0x94C2, 0x7B94,
+    0x4F2F, 0x5E1B, 0x8236, 0x8116, 0x818A, 0x6E24, 0x6CCA, 0x9A73, 0x6355, 0x535C, 0x54FA, 0x8865,
+    0x57E0, 0x4E0D, 0x5E03, 0x6B65, 0x7C3F, 0x90E8, 0x6016, 0x64E6, 0x731C, 0x88C1, 0x6750, 0x624D,
+    0x8D22, 0x776C, 0x8E29, 0x91C7, 0x5F69, 0x83DC, 0x8521, 0x9910, 0x53C2, 0x8695, 0x6B8B, 0x60ED,
+    0x60E8, 0x707F, 0x82CD, 0x8231, 0x4ED3, 0x6CA7, 0x85CF, 0x64CD, 0x7CD9, 0x69FD, 0x66F9, 0x8349,
+    0x5395, 0x7B56, 0x4FA7, 0x518C, 0x6D4B, 0x5C42, 0x8E6D, 0x63D2, 0x53C9, 0x832C, 0x8336, 0x67E5,
+    0x78B4, 0x643D, 0x5BDF, 0x5C94, 0x5DEE, 0x8BE7, 0x62C6, 0x67F4, 0x8C7A, 0x6400, 0x63BA, 0x8749,
+    0x998B, 0x8C17, 0x7F20, 0x94F2, 0x4EA7, 0x9610, 0x98A4, 0x660C, 0x7316, 0x77E6, 0x77E8, 0x77EA,
+    0x77EF, 0x77F4, 0x77F7, 0x77F9, 0x7803, 0x780A, 0x780E, 0x7813, 0x7815, 0x7819, 0x781B, 0x781E,
+    0x7820, 0x7824, 0x7828, 0x782A, 0x782E, 0x7831, 0x7835, 0x783D, 0x783F, 0x7841, 0x7846, 0x7848,
+    0x784D, 0x784F, 0x7851, 0x7853, 0x7858, 0x785E, 0x786F, 0x7878, 0x787D, 0x573A, 0x5C1D, 0x5E38,
+    0x957F, 0x507F, 0x80A0, 0x5382, 0x655E, 0x7545, 0x5531, 0x5021, 0x8D85, 0x6284, 0x949E, 0x671D,
+    0x5632, 0x6F6E, 0x5DE2, 0x5435, 0x7092, 0x8F66, 0x626F, 0x64A4, 0x63A3, 0x5F7B, 0x6F88, 0x90F4,
+    0x81E3, 0x8FB0, 0x5C18, 0x6668, 0x5FF1, 0x6C89, 0x9648, 0x8D81, 0x886C, 0x6491, 0x79F0, 0x57CE,
+    0x6A59, 0x6210, 0x5448, 0x4E58, 0x7A0B, 0x60E9, 0x6F84, 0x8BDA, 0x627F, 0x901E, 0x9A8B, 0x79E4,
+    0x5403, 0x75F4, 0x6301, 0x5319, 0x6C60, 0x8FDF, 0x5F1B, 0x9A70, 0x803B, 0x9F7F, 0x4F88, 0x5C3A,
+    0x8D64, 0x7FC5, 0x65A5, 0x70BD, 0x5145, 0x51B2, 0x866B, 0x5D07, 0x5BA0, 0x62BD, 0x916C, 0x7574,
+    0x8E0C, 0x7A20, 0x6101, 0x7B79, 0x4EC7, 0x7EF8, 0x7785, 0x4E11, 0x81ED, 0x521D, 0x51FA, 0x6A71,
+    0x53A8, 0x8E87, 0x9504, 0x96CF, 0x6EC1, 0x9664, 0x695A, 0x7884, 0x7888, 0x788A, 0x788F, 0x7892,
+    0x7894, 0x7899, 0x789D, 0x78A0, 0x78A2, 0x78A4, 0x78A6, 0x78A8, 0x78B5, 0x78BA, 0x78BF, 0x78C2,
+    0x78C6, 0x78CC, 0x78D1, 0x78D6, 0x78DA, 0x78E9, 0x78ED, 0x78F3, 0x78F5, 0x78F8, 0x78FB, 0x7902,
+    0x7906, 0x7840, 0x50A8, 0x77D7, 0x6410, 0x89E6, 0x5904, 0x63E3, 0x5DDD, 0x7A7F, 0x693D, 0x4F20,
+    0x8239, 0x5598, 0x4E32, 0x75AE, 0x7A97, 0x5E62, 0x5E8A, 0x95EF, 0x521B, 0x5439, 0x708A, 0x6376,
+    0x9524, 0x5782, 0x6625, 0x693F, 0x9187, 0x5507, 0x6DF3, 0x7EAF, 0x8822, 0x6233, 0x7EF0, 0x75B5,
+    0x8328, 0x78C1, 0x96CC, 0x8F9E, 0x6148, 0x74F7, 0x8BCD, 0x6B64, 0x523A, 0x8D50, 0x6B21, 0x806A,
+    0x8471, 0x56F1, 0x5306, 0x4ECE, 0x4E1B, 0x51D1, 0x7C97, 0x918B, 0x7C07, 0x4FC3, 0x8E7F, 0x7BE1,
+    0x7A9C, 0x6467, 0x5D14, 0x50AC, 0x8106, 0x7601, 0x7CB9, 0x6DEC, 0x7FE0, 0x6751, 0x5B58, 0x5BF8,
+    0x78CB, 0x64AE, 0x6413, 0x63AA, 0x632B, 0x9519, 0x642D, 0x8FBE, 0x7B54, 0x7629, 0x6253, 0x5927,
+    0x5446, 0x6B79, 0x50A3, 0x6234, 0x5E26, 0x6B86, 0x4EE3, 0x8D37, 0x888B, 0x5F85, 0x902E, 0x790D,
+    0x7914, 0x791F, 0x7925, 0x7935, 0x793D, 0x793F, 0x7942, 0x7947, 0x794A, 0x7954, 0x7958, 0x7961,
+    0x7963, 0x7966, 0x7969, 0x796E, 0x7970, 0x7979, 0x797B, 0x7982, 0x7986, 0x798B, 0x7990, 0x6020,
+    0x803D, 0x62C5, 0x4E39, 0x5355, 0x90F8, 0x63B8, 0x80C6, 0x65E6, 0x6C2E, 0x4F46, 0x60EE, 0x6DE1,
+    0x8BDE, 0x5F39, 0x86CB, 0x5F53, 0x6321, 0x515A, 0x8361, 0x6863, 0x5200, 0x6363, 0x8E48, 0x5012,
+    0x5C9B, 0x7977, 0x5BFC, 0x5230, 0x7A3B, 0x60BC, 0x9053, 0x76D7, 0x5FB7, 0x5F97, 0x7684, 0x8E6C,
+    0x706F, 0x767B, 0x7B49, 0x77AA, 0x51F3, 0x9093, 0x5824, 0x4F4E, 0x6EF4, 0x8FEA, 0x654C, 0x7B1B,
+    0x72C4, 0x6DA4, 0x7FDF, 0x5AE1, 0x62B5, 0x5E95, 0x5730, 0x8482, 0x7B2C, 0x5E1D, 0x5F1F, 0x9012,
+    0x7F14, 0x98A0, 0x6382, 0x6EC7, 0x7898, 0x70B9, 0x5178, 0x975B, 0x57AB, 0x7535, 0x4F43, 0x7538,
+    0x5E97, 0x60E6, 0x5960, 0x6DC0, 0x6BBF, 0x7889, 0x53FC, 0x96D5, 0x51CB, 0x5201, 0x6389, 0x540A,
+    0x9493, 0x8C03, 0x8DCC, 0x7239, 0x789F, 0x8776, 0x8FED, 0x8C0D, 0x53E0, 0x7993, 0x799B, 0x79A8,
+    0x79B4, 0x79BC, 0x79BF, 0x79C2, 0x79C4, 0x79C7, 0x79CA, 0x79CC, 0x79CE, 0x79D3, 0x79D6, 0x79D9,
+    0x79E0, 0x79E5, 0x79E8, 0x79EA, 0x79EC, 0x79EE, 0x79F1, 0x79F9, 0x79FC, 0x79FE, 0x7A01, 0x7A04,
+    0x7A07, 0x7A0C, 0x7A0F, 0x7A15, 0x7A18, 0x7A1B, 0x4E01, 0x76EF, 0x53EE, 0x9489, 0x9876, 0x9F0E,
+    0x952D, 0x5B9A, 0x8BA2, 0x4E22, 0x4E1C, 0x51AC, 0x8463, 0x61C2, 0x52A8, 0x680B, 0x4F97, 0x606B,
+    0x51BB, 0x6D1E, 0x515C, 0x6296, 0x6597, 0x9661, 0x8C46, 0x9017, 0x75D8, 0x90FD, 0x7763, 0x6BD2,
+    0x728A, 0x72EC, 0x8BFB, 0x5835, 0x7779, 0x8D4C, 0x675C, 0x9540, 0x809A, 0x5EA6, 0x6E21, 0x5992,
+    0x7AEF, 0x77ED, 0x953B, 0x6BB5, 0x65AD, 0x7F0E, 0x5806, 0x5151, 0x961F, 0x5BF9, 0x58A9, 0x5428,
+    0x8E72, 0x6566, 0x987F, 0x56E4, 0x949D, 0x76FE, 0x9041, 0x6387, 0x54C6, 0x591A, 0x593A, 0x579B,
+    0x8EB2, 0x6735, 0x8DFA, 0x8235, 0x5241, 0x60F0, 0x5815, 0x86FE, 0x5CE8, 0x9E45, 0x4FC4, 0x989D,
+    0x8BB9, 0x5A25, 0x6076, 0x5384, 0x627C, 0x904F, 0x9102, 0x997F, 0x6069, 0x800C, 0x513F, 0x8033,
+    0x5C14, 0x9975, 0x6D31, 0x4E8C, 0x7A1D, 0x7A1F, 0x7A21, 0x7A24, 0x7A34, 0x7A38, 0x7A3A, 0x7A3E,
+    0x7A40, 0x7A47, 0x7A52, 0x7A58, 0x7A71, 0x7A75, 0x7A7B, 0x7A82, 0x7A85, 0x7A87, 0x7A89, 0x7A8E,
+    0x7A93, 0x7A99, 0x7A9E, 0x7AA1, 0x8D30, 0x53D1, 0x7F5A, 0x7B4F, 0x4F10, 0x4E4F, 0x9600, 0x6CD5,
+    0x73D0, 0x85E9, 0x5E06, 0x756A, 0x7FFB, 0x6A0A, 0x77FE, 0x9492, 0x7E41, 0x51E1, 0x70E6, 0x53CD,
+    0x8FD4, 0x8303, 0x8D29, 0x72AF, 0x996D, 0x6CDB, 0x574A, 0x82B3, 0x65B9, 0x80AA, 0x623F, 0x9632,
+    0x59A8, 0x4EFF, 0x8BBF, 0x7EBA, 0x653E, 0x83F2, 0x975E, 0x5561, 0x98DE, 0x80A5, 0x532A, 0x8BFD,
+    0x5420, 0x80BA, 0x5E9F, 0x6CB8, 0x8D39, 0x82AC, 0x915A, 0x5429, 0x6C1B, 0x5206, 0x7EB7, 0x575F,
+    0x711A, 0x6C7E, 0x7C89, 0x594B, 0x4EFD, 0x5FFF, 0x6124, 0x7CAA, 0x4E30, 0x5C01, 0x67AB, 0x8702,
+    0x5CF0, 0x950B, 0x98CE, 0x75AF, 0x70FD, 0x9022, 0x51AF, 0x7F1D, 0x8BBD, 0x5949, 0x51E4, 0x4F5B,
+    0x5426, 0x592B, 0x6577, 0x80A4, 0x5B75, 0x6276, 0x62C2, 0x8F90, 0x5E45, 0x6C1F, 0x7B26, 0x4F0F,
+    0x4FD8, 0x670D, 0x7AA3, 0x7AA7, 0x7AA9, 0x7AAE, 0x7AB4, 0x7AC0, 0x7ACC, 0x7AD7, 0x7ADA, 0x7AE1,
+    0x7AE4, 0x7AE7, 0x7AEE, 0x7AF0, 0x7AFB, 0x7AFE, 0x7B00, 0x7B05, 0x7B07, 0x7B09, 0x7B0C, 0x7B10,
+    0x7B12, 0x7B16, 0x7B1A, 0x7B1C, 0x7B1F, 0x7B21, 0x7B27, 0x7B29, 0x7B2D, 0x6D6E, 0x6DAA, 0x798F,
+    0x88B1, 0x5F17, 0x752B, 0x629A, 0x8F85, 0x4FEF, 0x91DC, 0x65A7, 0x812F, 0x8151, 0x5E9C, 0x8150,
+    0x8D74, 0x526F, 0x8986, 0x8D4B, 0x590D, 0x5085, 0x4ED8, 0x961C, 0x7236, 0x8179, 0x8D1F, 0x5BCC,
+    0x8BA3, 0x9644, 0x5987, 0x7F1A, 0x5490, 0x5676, 0x560E, 0x8BE5, 0x6539, 0x6982, 0x9499, 0x76D6,
+    0x6E89, 0x5E72, 0x7518, 0x6746, 0x67D1, 0x7AFF, 0x809D, 0x8D76, 0x611F, 0x79C6, 0x6562, 0x8D63,
+    0x5188, 0x521A, 0x94A2, 0x7F38, 0x809B, 0x7EB2, 0x5C97, 0x6E2F, 0x6760, 0x7BD9, 0x768B, 0x9AD8,
+    0x818F, 0x7F94, 0x7CD5, 0x641E, 0x9550, 0x7A3F, 0x544A, 0x54E5, 0x6B4C, 0x6401, 0x6208, 0x9E3D,
+    0x80F3, 0x7599, 0x5272, 0x9769, 0x845B, 0x683C, 0x86E4, 0x9601, 0x9694, 0x94EC, 0x4E2A, 0x5404,
+    0x7ED9, 0x6839, 0x8DDF, 0x8015, 0x66F4, 0x5E9A, 0x7FB9, 0x7B2F, 0x7B32, 0x7B34, 0x7B39, 0x7B3B,
+    0x7B3D, 0x7B3F, 0x7B46, 0x7B48, 0x7B4A, 0x7B4D, 0x7B53, 0x7B55, 0x7B57, 0x7B59, 0x7B5C, 0x7B5E,
+    0x7B61, 0x7B63, 0x7B6F, 0x7B73, 0x7B76, 0x7B78, 0x7B7A, 0x7B7C, 0x7B7F, 0x7B81, 0x7B86, 0x7B8E,
+    0x7B91, 0x7B96, 0x7B98, 0x7B9E, 0x7BA3, 0x7BAE, 0x7BB2, 0x7BB5, 0x7BB9, 0x7BC2, 0x57C2, 0x803F,
+    0x6897, 0x5DE5, 0x653B, 0x529F, 0x606D, 0x9F9A, 0x4F9B, 0x8EAC, 0x516C, 0x5BAB, 0x5F13, 0x5DE9,
+    0x6C5E, 0x62F1, 0x8D21, 0x5171, 0x94A9, 0x52FE, 0x6C9F, 0x82DF, 0x72D7, 0x57A2, 0x6784, 0x8D2D,
+    0x591F, 0x8F9C, 0x83C7, 0x5495, 0x7B8D, 0x4F30, 0x6CBD, 0x5B64, 0x59D1, 0x9F13, 0x53E4, 0x86CA,
+    0x9AA8, 0x8C37, 0x80A1, 0x6545, 0x987E, 0x56FA, 0x96C7, 0x522E, 0x74DC, 0x5250, 0x5BE1, 0x6302,
+    0x8902, 0x4E56, 0x62D0, 0x602A, 0x68FA, 0x5173, 0x5B98, 0x51A0, 0x89C2, 0x7BA1, 0x9986, 0x7F50,
+    0x60EF, 0x704C, 0x8D2F, 0x5149, 0x5E7F, 0x901B, 0x7470, 0x89C4, 0x572D, 0x7845, 0x5F52, 0x9F9F,
+    0x95FA, 0x8F68, 0x9B3C, 0x8BE1, 0x7678, 0x6842, 0x67DC, 0x8DEA, 0x8D35, 0x523D, 0x8F8A, 0x6EDA,
+    0x68CD, 0x9505, 0x90ED, 0x56FD, 0x679C, 0x88F9, 0x8FC7, 0x54C8, 0x7BC5, 0x7BC8, 0x7BCD, 0x7BD2,
+    0x7BD4, 0x7BDB, 0x7BDE, 0x7BE2, 0x7BE7, 0x7BEB, 0x7BEF, 0x7BF2, 0x7BF8, 0x7BFD, 0x7BFF, 0x7C08,
+    0x7C0D, 0x7C10, 0x7C17, 0x7C20, 0x7C28, 0x7C2B, 0x7C39, 0x7C42, 0x9AB8, 0x5B69, 0x6D77, 0x6C26,
+    0x4EA5, 0x5BB3, 0x9A87, 0x9163, 0x61A8, 0x90AF, 0x97E9, 0x542B, 0x6DB5, 0x5BD2, 0x51FD, 0x558A,
+    0x7F55, 0x7FF0, 0x64BC, 0x634D, 0x65F1, 0x61BE, 0x608D, 0x710A, 0x6C57, 0x6C49, 0x592F, 0x676D,
+    0x822A, 0x58D5, 0x568E, 0x8C6A, 0x6BEB, 0x90DD, 0x597D, 0x8017, 0x53F7, 0x6D69, 0x5475, 0x559D,
+    0x8377, 0x83CF, 0x6838, 0x79BE, 0x548C, 0x4F55, 0x5408, 0x76D2, 0x8C89, 0x9602, 0x6CB3, 0x6DB8,
+    0x8D6B, 0x8910, 0x9E64, 0x8D3A, 0x563F, 0x9ED1, 0x75D5, 0x5F88, 0x72E0, 0x6068, 0x54FC, 0x4EA8,
+    0x6A2A, 0x8861, 0x6052, 0x8F70, 0x54C4, 0x70D8, 0x8679, 0x9E3F, 0x6D2A, 0x5B8F, 0x5F18, 0x7EA2,
+    0x5589, 0x4FAF, 0x7334, 0x543C, 0x539A, 0x5019, 0x540E, 0x547C, 0x4E4E, 0x5FFD, 0x745A, 0x58F6,
+    0x846B, 0x80E1, 0x8774, 0x72D0, 0x7CCA, 0x6E56, 0x7C43, 0x7C4E, 0x7C75, 0x7C7E, 0x7C8A, 0x7C93,
+    0x7C96, 0x7C99, 0x7CA0, 0x7CA3, 0x7CA6, 0x7CAB, 0x7CAF, 0x7CB4, 0x7CBA, 0x5F27, 0x864E, 0x552C,
+    0x62A4, 0x4E92, 0x6CAA, 0x6237, 0x82B1, 0x54D7, 0x534E, 0x733E, 0x6ED1, 0x753B, 0x5212, 0x5316,
+    0x8BDD, 0x69D0, 0x5F8A, 0x6000, 0x6DEE, 0x574F, 0x6B22, 0x73AF, 0x6853, 0x8FD8, 0x7F13, 0x6362,
+    0x60A3, 0x5524, 0x75EA, 0x8C62, 0x7115, 0x6DA3, 0x5BA6, 0x5E7B, 0x8352, 0x614C, 0x9EC4, 0x78FA,
+    0x8757, 0x7C27, 0x7687, 0x51F0, 0x60F6, 0x714C, 0x6643, 0x5E4C, 0x604D, 0x8C0E, 0x7070, 0x6325,
+    0x8F89, 0x5FBD, 0x6062, 0x86D4, 0x56DE, 0x6BC1, 0x6094, 0x6167, 0x5349, 0x60E0, 0x6666, 0x8D3F,
+    0x79FD, 0x4F1A, 0x70E9, 0x6C47, 0x8BB3, 0x8BF2, 0x7ED8, 0x8364, 0x660F, 0x5A5A, 0x9B42, 0x6D51,
+    0x6DF7, 0x8C41, 0x6D3B, 0x4F19, 0x706B, 0x83B7, 0x6216, 0x60D1, 0x970D, 0x8D27, 0x7978, 0x51FB,
+    0x573E, 0x57FA, 0x673A, 0x7578, 0x7A3D, 0x79EF, 0x7B95, 0x7CBF, 0x7CC2, 0x7CC6, 0x7CC9, 0x7CCB,
+    0x7CCE, 0x7CD8, 0x7CDA, 0x7CDD, 0x7CE1, 0x7CE9, 0x7CF0, 0x7CF9, 0x7CFC, 0x7D0B, 0x7D21, 0x7D23,
+    0x7D28, 0x7D2C, 0x7D30, 0x808C, 0x9965, 0x8FF9, 0x6FC0, 0x8BA5, 0x9E21, 0x59EC, 0x7EE9, 0x7F09,
+    0x5409, 0x6781, 0x68D8, 0x8F91, 0x7C4D, 0x96C6, 0x53CA, 0x6025, 0x75BE, 0x6C72, 0x5373, 0x5AC9,
+    0x7EA7, 0x6324, 0x51E0, 0x810A, 0x5DF1, 0x84DF, 0x6280, 0x5180, 0x5B63, 0x4F0E, 0x796D, 0x5242,
+    0x60B8, 0x6D4E, 0x5BC4, 0x5BC2, 0x8BA1, 0x8BB0, 0x65E2, 0x5FCC, 0x9645, 0x5993, 0x7EE7, 0x7EAA,
+    0x5609, 0x67B7, 0x5939, 0x4F73, 0x5BB6, 0x52A0, 0x835A, 0x988A, 0x8D3E, 0x7532, 0x94BE, 0x5047,
+    0x7A3C, 0x4EF7, 0x67B6, 0x9A7E, 0x5AC1, 0x6B7C, 0x76D1, 0x575A, 0x5C16, 0x7B3A, 0x95F4, 0x714E,
+    0x517C, 0x80A9, 0x8270, 0x5978, 0x7F04, 0x8327, 0x68C0, 0x67EC, 0x78B1, 0x7877, 0x62E3, 0x6361,
+    0x7B80, 0x4FED, 0x526A, 0x51CF, 0x8350, 0x69DB, 0x9274, 0x8DF5, 0x8D31, 0x89C1, 0x952E, 0x7BAD,
+    0x4EF6, 0x7D37, 0x7D6F, 0x7D78, 0x5065, 0x8230, 0x5251, 0x996F, 0x6E10, 0x6E85, 0x6DA7, 0x5EFA,
+    0x50F5, 0x59DC, 0x5C06, 0x6D46, 0x6C5F, 0x7586, 0x848B, 0x6868, 0x5956, 0x8BB2, 0x5320, 0x9171,
+    0x964D, 0x8549, 0x6912, 0x7901, 0x7126, 0x80F6, 0x4EA4, 0x90CA, 0x6D47, 0x9A84, 0x5A07, 0x56BC,
+    0x6405, 0x94F0, 0x77EB, 0x4FA5, 0x811A, 0x72E1, 0x89D2, 0x997A, 0x7F34, 0x7EDE, 0x527F, 0x6559,
+    0x9175, 0x8F7F, 0x8F83, 0x53EB, 0x7A96, 0x63ED, 0x63A5, 0x7686, 0x79F8, 0x8857, 0x9636, 0x622A,
+    0x52AB, 0x8282, 0x6854, 0x6770, 0x6377, 0x776B, 0x7AED, 0x6D01, 0x7ED3, 0x89E3, 0x59D0, 0x6212,
+    0x85C9, 0x82A5, 0x754C, 0x501F, 0x4ECB, 0x75A5, 0x8BEB, 0x5C4A, 0x5DFE, 0x7B4B, 0x65A4, 0x91D1,
+    0x4ECA, 0x6D25, 0x895F, 0x7D27, 0x9526, 0x4EC5, 0x8C28, 0x8FDB, 0x9773, 0x664B, 0x7981, 0x8FD1,
+    0x70EC, 0x6D78, 0x7D99, 0x7DA7, 0x7DAF, 0x5C3D, 0x52B2, 0x8346, 0x5162, 0x830E, 0x775B, 0x6676,
+    0x9CB8, 0x4EAC, 0x60CA, 0x7CBE, 0x7CB3, 0x7ECF, 0x4E95, 0x8B66, 0x666F, 0x9888, 0x9759, 0x5883,
+    0x656C, 0x955C, 0x5F84, 0x75C9, 0x9756, 0x7ADF, 0x7ADE, 0x51C0, 0x70AF, 0x7A98, 0x63EA, 0x7A76,
+    0x7EA0, 0x7396, 0x97ED, 0x4E45, 0x7078, 0x4E5D, 0x9152, 0x53A9, 0x6551, 0x65E7, 0x81FC, 0x8205,
+    0x548E, 0x5C31, 0x759A, 0x97A0, 0x62D8, 0x72D9, 0x75BD, 0x5C45, 0x9A79, 0x83CA, 0x5C40, 0x5480,
+    0x77E9, 0x4E3E, 0x6CAE, 0x805A, 0x62D2, 0x636E, 0x5DE8, 0x5177, 0x8DDD, 0x8E1E, 0x952F, 0x4FF1,
+    0x53E5, 0x60E7, 0x70AC, 0x5267, 0x6350, 0x9E43, 0x5A1F, 0x5026, 0x7737, 0x5377, 0x7EE2, 0x6485,
+    0x652B, 0x6289, 0x6398, 0x5014, 0x7235, 0x89C9, 0x51B3, 0x8BC0, 0x7EDD, 0x5747, 0x83CC, 0x94A7,
+    0x519B, 0x541B, 0x5CFB, 0x7DFB, 0x7E3C, 0x7E42, 0x7E48, 0x4FCA, 0x7AE3, 0x6D5A, 0x90E1, 0x9A8F,
+    0x5580, 0x5496, 0x5361, 0x54AF, 0x5F00, 0x63E9, 0x6977, 0x51EF, 0x6168, 0x520A, 0x582A, 0x52D8,
+    0x574E, 0x780D, 0x770B, 0x5EB7, 0x6177, 0x7CE0, 0x625B, 0x6297, 0x4EA2, 0x7095, 0x8003, 0x62F7,
+    0x70E4, 0x9760, 0x5777, 0x82DB, 0x67EF, 0x68F5, 0x78D5, 0x9897, 0x79D1, 0x58F3, 0x54B3, 0x53EF,
+    0x6E34, 0x514B, 0x523B, 0x5BA2, 0x8BFE, 0x80AF, 0x5543, 0x57A6, 0x6073, 0x5751, 0x542D, 0x7A7A,
+    0x6050, 0x5B54, 0x63A7, 0x62A0, 0x53E3, 0x6263, 0x5BC7, 0x67AF, 0x54ED, 0x7A9F, 0x82E6, 0x9177,
+    0x5E93, 0x88E4, 0x5938, 0x57AE, 0x630E, 0x8DE8, 0x80EF, 0x5757, 0x7B77, 0x4FA9, 0x5FEB, 0x5BBD,
+    0x6B3E, 0x5321, 0x7B50, 0x72C2, 0x6846, 0x77FF, 0x7736, 0x65F7, 0x51B5, 0x4E8F, 0x76D4, 0x5CBF,
+    0x7AA5, 0x8475, 0x594E, 0x9B41, 0x5080, 0x7E5E, 0x7E83, 0x7E9C, 0x7EAE, 0x7EB4, 0x7EBB, 0x7ED6,
+    0x7EE4, 0x7EEC, 0x7EF9, 0x7F0A, 0x7F10, 0x7F1E, 0x7F37, 0x7F39, 0x7F3B, 0x7F43, 0x7F46, 0x7F52,
+    0x9988, 0x6127, 0x6E83, 0x5764, 0x6606, 0x6346, 0x56F0, 0x62EC, 0x6269, 0x5ED3, 0x9614, 0x5783,
+    0x62C9, 0x5587, 0x8721, 0x814A, 0x8FA3, 0x5566, 0x83B1, 0x6765, 0x8D56, 0x84DD, 0x5A6A, 0x680F,
+    0x62E6, 0x7BEE, 0x9611, 0x5170, 0x6F9C, 0x8C30, 0x63FD, 0x89C8, 0x61D2, 0x7F06, 0x70C2, 0x6EE5,
+    0x7405, 0x6994, 0x72FC, 0x5ECA, 0x90CE, 0x6717, 0x6D6A, 0x635E, 0x52B3, 0x7262, 0x8001, 0x4F6C,
+    0x59E5, 0x916A, 0x70D9, 0x6D9D, 0x52D2, 0x4E50, 0x96F7, 0x956D, 0x857E, 0x78CA, 0x7D2F, 0x5121,
+    0x5792, 0x64C2, 0x808B, 0x7C7B, 0x6CEA, 0x68F1, 0x695E, 0x51B7, 0x5398, 0x68A8, 0x7281, 0x9ECE,
+    0x7BF1, 0x72F8, 0x79BB, 0x6F13, 0x7406, 0x674E, 0x91CC, 0x9CA4, 0x793C, 0x8389, 0x8354, 0x540F,
+    0x6817, 0x4E3D, 0x5389, 0x52B1, 0x783E, 0x5386, 0x5229, 0x5088, 0x4F8B, 0x4FD0, 0x7F56, 0x7F59,
+    0x7F5B, 0x7F60, 0x7F63, 0x7F6B, 0x7F6F, 0x7F73, 0x7F75, 0x7F7A, 0x7F7F, 0x7F82, 0x7F8B, 0x7F8D,
+    0x7F8F, 0x7F95, 0x7F9B, 0x7FA0, 0x7FA2, 0x7FA5, 0x7FA8, 0x7FB1, 0x7FB3, 0x7FBA, 0x7FBE, 0x7FC0,
+    0x7FC2, 0x7FC6, 0x7FCB, 0x7FCD, 0x7FCF, 0x7FD6, 0x7FD9, 0x7FE2, 0x75E2, 0x7ACB, 0x7C92, 0x6CA5,
+    0x96B6, 0x529B, 0x7483, 0x54E9, 0x4FE9, 0x8054, 0x83B2, 0x8FDE, 0x9570, 0x5EC9, 0x601C, 0x6D9F,
+    0x5E18, 0x655B, 0x8138, 0x94FE, 0x604B, 0x70BC, 0x7EC3, 0x7CAE, 0x51C9, 0x6881, 0x7CB1, 0x826F,
+    0x4E24, 0x8F86, 0x91CF, 0x667E, 0x4EAE, 0x8C05, 0x64A9, 0x804A, 0x50DA, 0x7597, 0x71CE, 0x5BE5,
+    0x8FBD, 0x6F66, 0x4E86, 0x6482, 0x9563, 0x5ED6, 0x6599, 0x5217, 0x88C2, 0x70C8, 0x52A3, 0x730E,
+    0x7433, 0x6797, 0x78F7, 0x9716, 0x4E34, 0x90BB, 0x9CDE, 0x6DCB, 0x51DB, 0x8D41, 0x541D, 0x62CE,
+    0x73B2, 0x83F1, 0x96F6, 0x9F84, 0x94C3, 0x4F36, 0x7F9A, 0x51CC, 0x7075, 0x9675, 0x5CAD, 0x9886,
+    0x53E6, 0x4EE4, 0x6E9C, 0x7409, 0x69B4, 0x786B, 0x998F, 0x7559, 0x5218, 0x7624, 0x6D41, 0x67F3,
+    0x516D, 0x9F99, 0x804B, 0x5499, 0x7B3C, 0x7ABF, 0x7FE4, 0x7FE7, 0x7FEA, 0x7FEF, 0x7FF2, 0x7FF4,
+    0x7FFD, 0x8002, 0x8007, 0x800E, 0x8011, 0x8013, 0x801A, 0x801D, 0x8021, 0x8023, 0x802B, 0x8032,
+    0x8034, 0x8039, 0x803C, 0x803E, 0x8040, 0x8044, 0x8047, 0x804E, 0x8053, 0x8055, 0x8059, 0x805B,
+    0x806B, 0x8072, 0x9686, 0x5784, 0x62E2, 0x9647, 0x697C, 0x5A04, 0x6402, 0x7BD3, 0x6F0F, 0x964B,
+    0x82A6, 0x5362, 0x9885, 0x5E90, 0x7089, 0x63B3, 0x5364, 0x864F, 0x9C81, 0x9E93, 0x788C, 0x9732,
+    0x8DEF, 0x8D42, 0x9E7F, 0x6F5E, 0x7984, 0x5F55, 0x9646, 0x622E, 0x9A74, 0x5415, 0x94DD, 0x4FA3,
+    0x65C5, 0x5C65, 0x5C61, 0x7F15, 0x8651, 0x6C2F, 0x5F8B, 0x7387, 0x6EE4, 0x7EFF, 0x5CE6, 0x631B,
+    0x5B6A, 0x6EE6, 0x5375, 0x4E71, 0x63A0, 0x7565, 0x62A1, 0x8F6E, 0x4F26, 0x4ED1, 0x6CA6, 0x7EB6,
+    0x8BBA, 0x841D, 0x87BA, 0x7F57, 0x903B, 0x9523, 0x7BA9, 0x9AA1, 0x88F8, 0x843D, 0x6D1B, 0x9A86,
+    0x7EDC, 0x5988, 0x9EBB, 0x739B, 0x7801, 0x8682, 0x9A6C, 0x9A82, 0x561B, 0x5417, 0x57CB, 0x4E70,
+    0x9EA6, 0x5356, 0x8FC8, 0x8109, 0x7792, 0x9992, 0x86EE, 0x6EE1, 0x8513, 0x66FC, 0x6162, 0x6F2B,
+    0x807E, 0x8081, 0x8085, 0x8088, 0x808A, 0x808D, 0x8094, 0x8097, 0x8099, 0x809E, 0x80A3, 0x80A6,
+    0x80AC, 0x80B0, 0x80B3, 0x80B5, 0x80B8, 0x80BB, 0x80C5, 0x80C7, 0x80CF, 0x80D8, 0x80DF, 0x80E2,
+    0x80E6, 0x80EE, 0x80F5, 0x80F7, 0x80F9, 0x80FB, 0x80FE, 0x8103, 0x8107, 0x810B, 0x8115, 0x8117,
+    0x8119, 0x811B, 0x811F, 0x812D, 0x8130, 0x8133, 0x8137, 0x8139, 0x813F, 0x8C29, 0x8292, 0x832B,
+    0x76F2, 0x6C13, 0x5FD9, 0x83BD, 0x732B, 0x8305, 0x951A, 0x6BDB, 0x77DB, 0x94C6, 0x536F, 0x8302,
+    0x5192, 0x5E3D, 0x8C8C, 0x8D38, 0x4E48, 0x73AB, 0x679A, 0x6885, 0x9176, 0x9709, 0x7164, 0x6CA1,
+    0x7709, 0x5A92, 0x9541, 0x6BCF, 0x7F8E, 0x6627, 0x5BD0, 0x59B9, 0x5A9A, 0x95E8, 0x95F7, 0x4EEC,
+    0x840C, 0x8499, 0x6AAC, 0x76DF, 0x9530, 0x731B, 0x68A6, 0x5B5F, 0x772F, 0x919A, 0x9761, 0x7CDC,
+    0x8FF7, 0x8C1C, 0x5F25, 0x7C73, 0x79D8, 0x89C5, 0x6CCC, 0x871C, 0x5BC6, 0x5E42, 0x68C9, 0x7720,
+    0x7EF5, 0x5195, 0x514D, 0x52C9, 0x5A29, 0x7F05, 0x9762, 0x82D7, 0x63CF, 0x7784, 0x85D0, 0x79D2,
+    0x6E3A, 0x5E99, 0x5999, 0x8511, 0x706D, 0x6C11, 0x62BF, 0x76BF, 0x654F, 0x60AF, 0x95FD, 0x660E,
+    0x879F, 0x9E23, 0x94ED, 0x540D, 0x547D, 0x8C2C, 0x6478, 0x8140, 0x8147, 0x8149, 0x814D, 0x8152,
+    0x8156, 0x815B, 0x8161, 0x8166, 0x8168, 0x816A, 0x816F, 0x8172, 0x8175, 0x8181, 0x8183, 0x8189,
+    0x818B, 0x8190, 0x8192, 0x8199, 0x819E, 0x81A4, 0x81A7, 0x81A9, 0x81AB, 0x81B4, 0x81BC, 0x81C4,
+    0x81C7, 0x81CB, 0x81CD, 0x6479, 0x8611, 0x6A21, 0x819C, 0x78E8, 0x6469, 0x9B54, 0x62B9, 0x672B,
+    0x83AB, 0x58A8, 0x9ED8, 0x6CAB, 0x6F20, 0x5BDE, 0x964C, 0x8C0B, 0x725F, 0x67D0, 0x62C7, 0x7261,
+    0x4EA9, 0x59C6, 0x6BCD, 0x5893, 0x66AE, 0x5E55, 0x52DF, 0x6155, 0x6728, 0x76EE, 0x7766, 0x7267,
+    0x7A46, 0x62FF, 0x54EA, 0x5450, 0x94A0, 0x90A3, 0x5A1C, 0x7EB3, 0x6C16, 0x4E43, 0x5976, 0x8010,
+    0x5948, 0x5357, 0x7537, 0x96BE, 0x56CA, 0x6320, 0x8111, 0x607C, 0x95F9, 0x6DD6, 0x5462, 0x9981,
+    0x5185, 0x5AE9, 0x80FD, 0x59AE, 0x9713, 0x502A, 0x6CE5, 0x5C3C, 0x62DF, 0x4F60, 0x533F, 0x817B,
+    0x9006, 0x6EBA, 0x852B, 0x62C8, 0x5E74, 0x78BE, 0x64B5, 0x637B, 0x5FF5, 0x5A18, 0x917F, 0x9E1F,
+    0x5C3F, 0x634F, 0x8042, 0x5B7D, 0x556E, 0x954A, 0x954D, 0x6D85, 0x60A8, 0x67E0, 0x72DE, 0x51DD,
+    0x5B81, 0x81D4, 0x81E4, 0x81E8, 0x81EB, 0x81EE, 0x81F5, 0x81FD, 0x81FF, 0x8203, 0x8207, 0x820E,
+    0x8211, 0x8213, 0x8215, 0x821D, 0x8220, 0x8224, 0x8229, 0x822E, 0x8232, 0x823A, 0x823C, 0x823F,
+    0x8245, 0x8248, 0x824A, 0x824C, 0x8250, 0x8259, 0x825B, 0x8260, 0x8269, 0x62E7, 0x6CDE, 0x725B,
+    0x626D, 0x94AE, 0x7EBD, 0x8113, 0x6D53, 0x519C, 0x5F04, 0x5974, 0x52AA, 0x6012, 0x5973, 0x6696,
+    0x8650, 0x759F, 0x632A, 0x61E6, 0x7CEF, 0x8BFA, 0x54E6, 0x6B27, 0x9E25, 0x6BB4, 0x85D5, 0x5455,
+    0x5076, 0x6CA4, 0x556A, 0x8DB4, 0x722C, 0x5E15, 0x6015, 0x7436, 0x62CD, 0x6392, 0x724C, 0x5F98,
+    0x6E43, 0x6D3E, 0x6500, 0x6F58, 0x76D8, 0x78D0, 0x76FC, 0x7554, 0x5224, 0x53DB, 0x4E53, 0x5E9E,
+    0x65C1, 0x802A, 0x80D6, 0x629B, 0x5486, 0x5228, 0x70AE, 0x888D, 0x8DD1, 0x6CE1, 0x5478, 0x80DA,
+    0x57F9, 0x88F4, 0x8D54, 0x966A, 0x914D, 0x4F69, 0x6C9B, 0x55B7, 0x76C6, 0x7830, 0x62A8, 0x70F9,
+    0x6F8E, 0x5F6D, 0x84EC, 0x68DA, 0x787C, 0x7BF7, 0x81A8, 0x670B, 0x9E4F, 0x6367, 0x78B0, 0x576F,
+    0x7812, 0x9739, 0x6279, 0x62AB, 0x5288, 0x7435, 0x6BD7, 0x826A, 0x8271, 0x8275, 0x827B, 0x8280,
+    0x8283, 0x8285, 0x8289, 0x828C, 0x8290, 0x8293, 0x829A, 0x829E, 0x82A0, 0x82A2, 0x82A7, 0x82B2,
+    0x82B5, 0x82BA, 0x82BF, 0x82C2, 0x82C5, 0x82C9, 0x82D0, 0x82D6, 0x82D9, 0x82DD, 0x82E2, 0x82E7,
+    0x82EC, 0x82F0, 0x82F2, 0x82F5, 0x82F8, 0x82FA, 0x82FC, 0x830A, 0x830D, 0x8310, 0x8312, 0x8316,
+    0x8318, 0x831D, 0x8329, 0x832E, 0x8330, 0x8332, 0x8337, 0x833B, 0x833D, 0x5564, 0x813E, 0x75B2,
+    0x76AE, 0x5339, 0x75DE, 0x50FB, 0x5C41, 0x8B6C, 0x7BC7, 0x504F, 0x7247, 0x9A97, 0x98D8, 0x6F02,
+    0x74E2, 0x7968, 0x6487, 0x77A5, 0x62FC, 0x9891, 0x8D2B, 0x54C1, 0x8058, 0x4E52, 0x576A, 0x82F9,
+    0x840D, 0x5E73, 0x51ED, 0x74F6, 0x8BC4, 0x5C4F, 0x5761, 0x6CFC, 0x9887, 0x5A46, 0x7834, 0x9B44,
+    0x8FEB, 0x7C95, 0x5256, 0x6251, 0x94FA, 0x4EC6, 0x8386, 0x8461, 0x83E9, 0x84B2, 0x57D4, 0x6734,
+    0x5703, 0x666E, 0x6D66, 0x8C31, 0x66DD, 0x7011, 0x671F, 0x6B3A, 0x6816, 0x621A, 0x59BB, 0x4E03,
+    0x51C4, 0x6F06, 0x67D2, 0x6C8F, 0x5176, 0x68CB, 0x5947, 0x6B67, 0x7566, 0x5D0E, 0x8110, 0x9F50,
+    0x65D7, 0x7948, 0x7941, 0x9A91, 0x8D77, 0x5C82, 0x4E5E, 0x4F01, 0x542F, 0x5951, 0x780C, 0x5668,
+    0x6C14, 0x8FC4, 0x5F03, 0x6C7D, 0x6CE3, 0x8BAB, 0x6390, 0x833E, 0x8341, 0x8344, 0x8348, 0x834A,
+    0x8353, 0x8355, 0x835D, 0x8362, 0x8370, 0x8379, 0x837E, 0x8387, 0x838A, 0x838F, 0x8394, 0x8399,
+    0x839D, 0x839F, 0x83A1, 0x83AC, 0x83B5, 0x83BB, 0x83BE, 0x83C2, 0x83C6, 0x83C8, 0x83CB, 0x83CD,
+    0x83D0, 0x83D5, 0x83D7, 0x83D9, 0x83DE, 0x83E2, 0x83E6, 0x83EB, 0x6070, 0x6D3D, 0x7275, 0x6266,
+    0x948E, 0x94C5, 0x5343, 0x8FC1, 0x7B7E, 0x4EDF, 0x8C26, 0x4E7E, 0x9ED4, 0x94B1, 0x94B3, 0x524D,
+    0x6F5C, 0x9063, 0x6D45, 0x8C34, 0x5811, 0x5D4C, 0x6B20, 0x6B49, 0x67AA, 0x545B, 0x8154, 0x7F8C,
+    0x5899, 0x8537, 0x5F3A, 0x62A2, 0x6A47, 0x9539, 0x6572, 0x6084, 0x6865, 0x77A7, 0x4E54, 0x4FA8,
+    0x5DE7, 0x9798, 0x64AC, 0x7FD8, 0x5CED, 0x4FCF, 0x7A8D, 0x5207, 0x8304, 0x4E14, 0x602F, 0x7A83,
+    0x94A6, 0x4FB5, 0x4EB2, 0x79E6, 0x7434, 0x52E4, 0x82B9, 0x64D2, 0x79BD, 0x5BDD, 0x6C81, 0x9752,
+    0x8F7B, 0x6C22, 0x503E, 0x537F, 0x6E05, 0x64CE, 0x6674, 0x6C30, 0x60C5, 0x9877, 0x8BF7, 0x5E86,
+    0x743C, 0x7A77, 0x79CB, 0x4E18, 0x90B1, 0x7403, 0x6C42, 0x56DA, 0x914B, 0x6CC5, 0x8D8B, 0x533A,
+    0x86C6, 0x66F2, 0x8EAF, 0x5C48, 0x9A71, 0x6E20, 0x83EE, 0x83F3, 0x83FA, 0x83FE, 0x8402, 0x8405,
+    0x8407, 0x8410, 0x8412, 0x8419, 0x841E, 0x8429, 0x8432, 0x8439, 0x843E, 0x8447, 0x8452, 0x8458,
+    0x845D, 0x8462, 0x8464, 0x846A, 0x846E, 0x8472, 0x8474, 0x8477, 0x8479, 0x847B, 0x53D6, 0x5A36,
+    0x9F8B, 0x8DA3, 0x53BB, 0x5708, 0x98A7, 0x6743, 0x919B, 0x6CC9, 0x5168, 0x75CA, 0x62F3, 0x72AC,
+    0x5238, 0x529D, 0x7F3A, 0x7094, 0x7638, 0x5374, 0x9E4A, 0x69B7, 0x786E, 0x96C0, 0x88D9, 0x7FA4,
+    0x7136, 0x71C3, 0x5189, 0x67D3, 0x74E4, 0x58E4, 0x6518, 0x56B7, 0x8BA9, 0x9976, 0x6270, 0x7ED5,
+    0x60F9, 0x70ED, 0x58EC, 0x4EC1, 0x4EBA, 0x5FCD, 0x97E7, 0x4EFB, 0x8BA4, 0x5203, 0x598A, 0x7EAB,
+    0x6254, 0x4ECD, 0x65E5, 0x620E, 0x8338, 0x84C9, 0x8363, 0x878D, 0x7194, 0x6EB6, 0x5BB9, 0x7ED2,
+    0x5197, 0x63C9, 0x67D4, 0x8089, 0x8339, 0x8815, 0x5112, 0x5B7A, 0x5982, 0x8FB1, 0x4E73, 0x6C5D,
+    0x5165, 0x8925, 0x8F6F, 0x962E, 0x854A, 0x745E, 0x9510, 0x95F0, 0x6DA6, 0x82E5, 0x5F31, 0x6492,
+    0x6D12, 0x8428, 0x816E, 0x9CC3, 0x585E, 0x8D5B, 0x4E09, 0x53C1, 0x847D, 0x8483, 0x848A, 0x848D,
+    0x848F, 0x8498, 0x849A, 0x849D, 0x84A2, 0x84B0, 0x84B3, 0x84B5, 0x84BB, 0x84BE, 0x84C0, 0x84C2,
+    0x84C5, 0x84CB, 0x84CE, 0x84D2, 0x84D4, 0x84D7, 0x84DE, 0x84E1, 0x84E4, 0x84E7, 0x84ED, 0x84F1,
+    0x84FD, 0x8500, 0x4F1E, 0x6563, 0x6851, 0x55D3, 0x4E27, 0x6414, 0x9A9A, 0x626B, 0x5AC2, 0x745F,
+    0x8272, 0x6DA9, 0x68EE, 0x50E7, 0x838E, 0x7802, 0x6740, 0x5239, 0x6C99, 0x7EB1, 0x50BB, 0x5565,
+    0x715E, 0x7B5B, 0x6652, 0x73CA, 0x82EB, 0x6749, 0x5C71, 0x5220, 0x717D, 0x886B, 0x95EA, 0x9655,
+    0x64C5, 0x8D61, 0x81B3, 0x5584, 0x6C55, 0x6247, 0x7F2E, 0x5892, 0x4F24, 0x5546, 0x8D4F, 0x664C,
+    0x4E0A, 0x5C1A, 0x88F3, 0x68A2, 0x634E, 0x7A0D, 0x70E7, 0x828D, 0x52FA, 0x97F6, 0x5C11, 0x54E8,
+    0x90B5, 0x7ECD, 0x5962, 0x8D4A, 0x86C7, 0x820C, 0x8D66, 0x6444, 0x5C04, 0x6151, 0x6D89, 0x793E,
+    0x8BBE, 0x7837, 0x7533, 0x547B, 0x4F38, 0x8EAB, 0x6DF1, 0x5A20, 0x7EC5, 0x795E, 0x6C88, 0x5BA1,
+    0x5A76, 0x751A, 0x80BE, 0x614E, 0x6E17, 0x58F0, 0x751F, 0x7525, 0x7272, 0x5347, 0x7EF3, 0x8503,
+    0x850D, 0x8512, 0x8514, 0x8518, 0x851B, 0x8520, 0x8522, 0x852D, 0x853E, 0x8544, 0x854B, 0x8557,
+    0x855A, 0x855F, 0x8565, 0x8569, 0x8573, 0x8575, 0x857C, 0x857F, 0x7701, 0x76DB, 0x5269, 0x80DC,
+    0x5723, 0x5E08, 0x5931, 0x72EE, 0x65BD, 0x6E7F, 0x8BD7, 0x5C38, 0x8671, 0x5341, 0x77F3, 0x62FE,
+    0x65F6, 0x4EC0, 0x98DF, 0x8680, 0x5B9E, 0x8BC6, 0x53F2, 0x77E2, 0x4F7F, 0x5C4E, 0x9A76, 0x59CB,
+    0x5F0F, 0x793A, 0x58EB, 0x4E16, 0x67FF, 0x4E8B, 0x62ED, 0x8A93, 0x901D, 0x52BF, 0x662F, 0x55DC,
+    0x566C, 0x9002, 0x4ED5, 0x4F8D, 0x91CA, 0x9970, 0x6C0F, 0x5E02, 0x6043, 0x5BA4, 0x89C6, 0x8BD5,
+    0x6536, 0x624B, 0x9996, 0x5B88, 0x5BFF, 0x6388, 0x552E, 0x53D7, 0x7626, 0x517D, 0x852C, 0x67A2,
+    0x68B3, 0x6B8A, 0x6292, 0x8F93, 0x53D4, 0x8212, 0x6DD1, 0x758F, 0x4E66, 0x8D4E, 0x5B70, 0x719F,
+    0x85AF, 0x6691, 0x66D9, 0x7F72, 0x8700, 0x9ECD, 0x9F20, 0x5C5E, 0x672F, 0x8FF0, 0x6811, 0x675F,
+    0x620D, 0x7AD6, 0x5885, 0x5EB6, 0x6570, 0x6F31, 0x8582, 0x8586, 0x8588, 0x8590, 0x859D, 0x85A5,
+    0x85A9, 0x85AB, 0x85B1, 0x85B8, 0x85BA, 0x85C2, 0x85CA, 0x85D1, 0x85D4, 0x85D6, 0x85DD, 0x85E5,
+    0x85EA, 0x6055, 0x5237, 0x800D, 0x6454, 0x8870, 0x7529, 0x5E05, 0x6813, 0x62F4, 0x971C, 0x53CC,
+    0x723D, 0x8C01, 0x6C34, 0x7761, 0x7A0E, 0x542E, 0x77AC, 0x987A, 0x821C, 0x8BF4, 0x7855, 0x6714,
+    0x70C1, 0x65AF, 0x6495, 0x5636, 0x601D, 0x79C1, 0x53F8, 0x4E1D, 0x6B7B, 0x8086, 0x5BFA, 0x55E3,
+    0x56DB, 0x4F3A, 0x4F3C, 0x9972, 0x5DF3, 0x677E, 0x8038, 0x6002, 0x9882, 0x9001, 0x5B8B, 0x8BBC,
+    0x8BF5, 0x641C, 0x8258, 0x64DE, 0x55FD, 0x82CF, 0x9165, 0x4FD7, 0x7D20, 0x901F, 0x7C9F, 0x50F3,
+    0x5851, 0x6EAF, 0x5BBF, 0x8BC9, 0x8083, 0x9178, 0x849C, 0x7B97, 0x867D, 0x968B, 0x968F, 0x7EE5,
+    0x9AD3, 0x788E, 0x5C81, 0x7A57, 0x9042, 0x96A7, 0x795F, 0x5B59, 0x635F, 0x7B0B, 0x84D1, 0x68AD,
+    0x5506, 0x7F29, 0x7410, 0x7D22, 0x9501, 0x6240, 0x584C, 0x4ED6, 0x5B83, 0x5979, 0x5854, 0x85F9,
+    0x85FC, 0x8600, 0x8606, 0x8612, 0x8617, 0x8628, 0x862A, 0x8639, 0x863D, 0x8652, 0x8655, 0x865B,
+    0x865F, 0x8663, 0x736D, 0x631E, 0x8E4B, 0x8E0F, 0x80CE, 0x82D4, 0x62AC, 0x53F0, 0x6CF0, 0x915E,
+    0x592A, 0x6001, 0x6C70, 0x574D, 0x644A, 0x8D2A, 0x762B, 0x6EE9, 0x575B, 0x6A80, 0x75F0, 0x6F6D,
+    0x8C2D, 0x8C08, 0x5766, 0x6BEF, 0x8892, 0x78B3, 0x63A2, 0x53F9, 0x70AD, 0x6C64, 0x5858, 0x642A,
+    0x5802, 0x68E0, 0x819B, 0x5510, 0x7CD6, 0x5018, 0x8EBA, 0x6DCC, 0x8D9F, 0x70EB, 0x638F, 0x6D9B,
+    0x6ED4, 0x7EE6, 0x8404, 0x6843, 0x9003, 0x6DD8, 0x9676, 0x8BA8, 0x5957, 0x7279, 0x85E4, 0x817E,
+    0x75BC, 0x8A8A, 0x68AF, 0x5254, 0x8E22, 0x9511, 0x63D0, 0x9898, 0x8E44, 0x557C, 0x4F53, 0x66FF,
+    0x568F, 0x60D5, 0x6D95, 0x5243, 0x5C49, 0x5929, 0x6DFB, 0x586B, 0x7530, 0x751C, 0x606C, 0x8214,
+    0x8146, 0x6311, 0x6761, 0x8FE2, 0x773A, 0x8DF3, 0x8D34, 0x94C1, 0x5E16, 0x5385, 0x542C, 0x70C3,
+    0x866D, 0x866F, 0x8672, 0x8683, 0x868E, 0x8694, 0x8696, 0x869E, 0x86A5, 0x86AB, 0x86AD, 0x86B2,
+    0x86B7, 0x86BB, 0x86C1, 0x86C5, 0x86C8, 0x86CC, 0x86D2, 0x86D5, 0x86DA, 0x86DC, 0x86E0, 0x86E5,
+    0x86EA, 0x86EF, 0x86F5, 0x86FA, 0x86FF, 0x8701, 0x8704, 0x870B, 0x870E, 0x8714, 0x8716, 0x6C40,
+    0x5EF7, 0x505C, 0x4EAD, 0x5EAD, 0x633A, 0x8247, 0x901A, 0x6850, 0x916E, 0x77B3, 0x540C, 0x94DC,
+    0x5F64, 0x7AE5, 0x6876, 0x6345, 0x7B52, 0x7EDF, 0x75DB, 0x5077, 0x6295, 0x5934, 0x900F, 0x51F8,
+    0x79C3, 0x7A81, 0x56FE, 0x5F92, 0x9014, 0x6D82, 0x5C60, 0x571F, 0x5410, 0x5154, 0x6E4D, 0x56E2,
+    0x63A8, 0x9893, 0x817F, 0x8715, 0x892A, 0x9000, 0x541E, 0x5C6F, 0x81C0, 0x62D6, 0x6258, 0x8131,
+    0x9E35, 0x9640, 0x9A6E, 0x9A7C, 0x692D, 0x59A5, 0x62D3, 0x553E, 0x6316, 0x54C7, 0x86D9, 0x6D3C,
+    0x5A03, 0x74E6, 0x889C, 0x6B6A, 0x5916, 0x8C4C, 0x5F2F, 0x6E7E, 0x73A9, 0x987D, 0x4E38, 0x70F7,
+    0x5B8C, 0x7897, 0x633D, 0x665A, 0x7696, 0x60CB, 0x5B9B, 0x5A49, 0x4E07, 0x8155, 0x6C6A, 0x738B,
+    0x4EA1, 0x6789, 0x7F51, 0x5F80, 0x65FA, 0x671B, 0x5FD8, 0x5984, 0x5A01, 0x8719, 0x871B, 0x871D,
+    0x871F, 0x8724, 0x8726, 0x872A, 0x872F, 0x8732, 0x8735, 0x8738, 0x873C, 0x8740, 0x874A, 0x874D,
+    0x874F, 0x8754, 0x8758, 0x875A, 0x8761, 0x8766, 0x876F, 0x8771, 0x8775, 0x8777, 0x877F, 0x8784,
+    0x8786, 0x8789, 0x878C, 0x878E, 0x8794, 0x8798, 0x87A0, 0x5DCD, 0x5FAE, 0x5371, 0x97E6, 0x8FDD,
+    0x6845, 0x56F4, 0x552F, 0x60DF, 0x4E3A, 0x6F4D, 0x7EF4, 0x82C7, 0x840E, 0x59D4, 0x4F1F, 0x4F2A,
+    0x5C3E, 0x7EAC, 0x672A, 0x851A, 0x5473, 0x754F, 0x80C3, 0x5582, 0x9B4F, 0x4F4D, 0x6E2D, 0x8C13,
+    0x5C09, 0x6170, 0x536B, 0x761F, 0x6E29, 0x868A, 0x6587, 0x95FB, 0x7EB9, 0x543B, 0x7A33, 0x7D0A,
+    0x95EE, 0x55E1, 0x7FC1, 0x74EE, 0x631D, 0x8717, 0x6DA1, 0x7A9D, 0x6211, 0x65A1, 0x5367, 0x63E1,
+    0x6C83, 0x5DEB, 0x545C, 0x94A8, 0x4E4C, 0x6C61, 0x8BEC, 0x5C4B, 0x65E0, 0x829C, 0x68A7, 0x543E,
+    0x5434, 0x6BCB, 0x6B66, 0x4E94, 0x6342, 0x5348, 0x821E, 0x4F0D, 0x4FAE, 0x575E, 0x620A, 0x96FE,
+    0x6664, 0x7269, 0x52FF, 0x52A1, 0x609F, 0x8BEF, 0x6614, 0x7199, 0x6790, 0x897F, 0x7852, 0x77FD,
+    0x6670, 0x563B, 0x5438, 0x9521, 0x727A, 0x87A5, 0x87A9, 0x87AE, 0x87B0, 0x87B4, 0x87B6, 0x87BB,
+    0x87BE, 0x87C1, 0x87C7, 0x87CC, 0x87D4, 0x87DC, 0x87E1, 0x87E6, 0x87EB, 0x87EF, 0x87FA, 0x87FF,
+    0x8804, 0x880B, 0x8814, 0x8817, 0x881C, 0x8823, 0x7A00, 0x606F, 0x5E0C, 0x6089, 0x819D, 0x5915,
+    0x60DC, 0x7184, 0x70EF, 0x6EAA, 0x6C50, 0x7280, 0x6A84, 0x88AD, 0x5E2D, 0x4E60, 0x5AB3, 0x559C,
+    0x94E3, 0x6D17, 0x7CFB, 0x9699, 0x620F, 0x7EC6, 0x778E, 0x867E, 0x5323, 0x971E, 0x8F96, 0x6687,
+    0x5CE1, 0x4FA0, 0x72ED, 0x4E0B, 0x53A6, 0x590F, 0x5413, 0x6380, 0x9528, 0x5148, 0x4ED9, 0x9C9C,
+    0x7EA4, 0x54B8, 0x8D24, 0x8854, 0x8237, 0x95F2, 0x6D8E, 0x5F26, 0x5ACC, 0x663E, 0x9669, 0x73B0,
+    0x732E, 0x53BF, 0x817A, 0x9985, 0x7FA1, 0x5BAA, 0x9677, 0x9650, 0x7EBF, 0x76F8, 0x53A2, 0x9576,
+    0x9999, 0x7BB1, 0x8944, 0x6E58, 0x4E61, 0x7FD4, 0x7965, 0x8BE6, 0x60F3, 0x54CD, 0x4EAB, 0x9879,
+    0x5DF7, 0x6A61, 0x50CF, 0x5411, 0x8C61, 0x8427, 0x785D, 0x9704, 0x524A, 0x54EE, 0x56A3, 0x9500,
+    0x6D88, 0x5BB5, 0x6DC6, 0x6653, 0x8824, 0x8833, 0x883A, 0x883D, 0x8841, 0x8846, 0x884E, 0x8855,
+    0x8858, 0x885A, 0x8866, 0x886A, 0x886D, 0x886F, 0x8871, 0x8873, 0x8878, 0x8880, 0x8883, 0x8886,
+    0x8889, 0x888C, 0x888E, 0x8893, 0x8897, 0x889D, 0x88A3, 0x88A5, 0x5C0F, 0x5B5D, 0x6821, 0x8096,
+    0x5578, 0x7B11, 0x6548, 0x6954, 0x4E9B, 0x6B47, 0x874E, 0x978B, 0x534F, 0x631F, 0x643A, 0x90AA,
+    0x659C, 0x80C1, 0x8C10, 0x5199, 0x68B0, 0x5378, 0x87F9, 0x61C8, 0x6CC4, 0x6CFB, 0x8C22, 0x5C51,
+    0x85AA, 0x82AF, 0x950C, 0x6B23, 0x8F9B, 0x65B0, 0x5FFB, 0x5FC3, 0x4FE1, 0x8845, 0x661F, 0x8165,
+    0x7329, 0x60FA, 0x5174, 0x5211, 0x578B, 0x5F62, 0x90A2, 0x884C, 0x9192, 0x5E78, 0x674F, 0x6027,
+    0x59D3, 0x5144, 0x51F6, 0x80F8, 0x5308, 0x6C79, 0x96C4, 0x718A, 0x4F11, 0x4FEE, 0x7F9E, 0x673D,
+    0x55C5, 0x9508, 0x79C0, 0x8896, 0x7EE3, 0x589F, 0x620C, 0x9700, 0x865A, 0x5618, 0x987B, 0x5F90,
+    0x8BB8, 0x84C4, 0x9157, 0x53D9, 0x65ED, 0x5E8F, 0x755C, 0x6064, 0x7D6E, 0x5A7F, 0x7EEA, 0x7EED,
+    0x8F69, 0x55A7, 0x5BA3, 0x60AC, 0x65CB, 0x7384, 0x88AC, 0x88AE, 0x88B2, 0x88B8, 0x88BD, 0x88C3,
+    0x88C7, 0x88CA, 0x88CF, 0x88D3, 0x88D6, 0x88DA, 0x88E0, 0x88E6, 0x88E9, 0x88F2, 0x88F5, 0x88FA,
+    0x88FD, 0x88FF, 0x8903, 0x890B, 0x8911, 0x8914, 0x891C, 0x8922, 0x8926, 0x892C, 0x8931, 0x8935,
+    0x8937, 0x9009, 0x7663, 0x7729, 0x7EDA, 0x9774, 0x859B, 0x5B66, 0x7A74, 0x96EA, 0x8840, 0x52CB,
+    0x718F, 0x5FAA, 0x65EC, 0x8BE2, 0x5BFB, 0x9A6F, 0x5DE1, 0x6B89, 0x6C5B, 0x8BAD, 0x8BAF, 0x900A,
+    0x8FC5, 0x538B, 0x62BC, 0x9E26, 0x9E2D, 0x5440, 0x4E2B, 0x82BD, 0x7259, 0x869C, 0x5D16, 0x8859,
+    0x6DAF, 0x96C5, 0x54D1, 0x4E9A, 0x8BB6, 0x7109, 0x54BD, 0x9609, 0x70DF, 0x6DF9, 0x76D0, 0x4E25,
+    0x7814, 0x8712, 0x5CA9, 0x5EF6, 0x8A00, 0x989C, 0x960E, 0x708E, 0x6CBF, 0x5944, 0x63A9, 0x773C,
+    0x884D, 0x6F14, 0x8273, 0x5830, 0x71D5, 0x538C, 0x781A, 0x96C1, 0x5501, 0x5F66, 0x7130, 0x5BB4,
+    0x8C1A, 0x9A8C, 0x6B83, 0x592E, 0x9E2F, 0x79E7, 0x6768, 0x626C, 0x4F6F, 0x75A1, 0x7F8A, 0x6D0B,
+    0x9633, 0x6C27, 0x4EF0, 0x75D2, 0x517B, 0x6837, 0x6F3E, 0x9080, 0x8170, 0x5996, 0x7476, 0x8938,
+    0x8942, 0x8945, 0x8960, 0x8967, 0x897C, 0x8980, 0x8982, 0x8984, 0x8987, 0x6447, 0x5C27, 0x9065,
+    0x7A91, 0x8C23, 0x59DA, 0x54AC, 0x8200, 0x836F, 0x8981, 0x8000, 0x6930, 0x564E, 0x8036, 0x7237,
+    0x91CE, 0x51B6, 0x4E5F, 0x9875, 0x6396, 0x4E1A, 0x53F6, 0x66F3, 0x814B, 0x591C, 0x6DB2, 0x4E00,
+    0x58F9, 0x533B, 0x63D6, 0x94F1, 0x4F9D, 0x4F0A, 0x8863, 0x9890, 0x5937, 0x9057, 0x79FB, 0x4EEA,
+    0x80F0, 0x7591, 0x6C82, 0x5B9C, 0x59E8, 0x5F5D, 0x6905, 0x8681, 0x501A, 0x5DF2, 0x4E59, 0x77E3,
+    0x4EE5, 0x827A, 0x6291, 0x6613, 0x9091, 0x5C79, 0x4EBF, 0x5F79, 0x81C6, 0x9038, 0x8084, 0x75AB,
+    0x4EA6, 0x88D4, 0x610F, 0x6BC5, 0x5FC6, 0x4E49, 0x76CA, 0x6EA2, 0x8BE3, 0x8BAE, 0x8C0A, 0x8BD1,
+    0x5F02, 0x7FFC, 0x7FCC, 0x7ECE, 0x8335, 0x836B, 0x56E0, 0x6BB7, 0x97F3, 0x9634, 0x59FB, 0x541F,
+    0x94F6, 0x6DEB, 0x5BC5, 0x996E, 0x5C39, 0x5F15, 0x9690, 0x89A2, 0x89C3, 0x89CD, 0x89D3, 0x89D7,
+    0x89DB, 0x89DD, 0x89DF, 0x89E4, 0x89E7, 0x89EC, 0x89F0, 0x89F4, 0x8A01, 0x8A08, 0x5370, 0x82F1,
+    0x6A31, 0x5A74, 0x9E70, 0x5E94, 0x7F28, 0x83B9, 0x8424, 0x8367, 0x8747, 0x8FCE, 0x8D62, 0x76C8,
+    0x5F71, 0x9896, 0x786C, 0x6620, 0x54DF, 0x62E5, 0x4F63, 0x81C3, 0x75C8, 0x5EB8, 0x96CD, 0x8E0A,
+    0x86F9, 0x548F, 0x6CF3, 0x6D8C, 0x6C38, 0x607F, 0x52C7, 0x7528, 0x5E7D, 0x4F18, 0x60A0, 0x5FE7,
+    0x5C24, 0x7531, 0x90AE, 0x94C0, 0x72B9, 0x6CB9, 0x6E38, 0x9149, 0x6709, 0x53CB, 0x53F3, 0x4F51,
+    0x91C9, 0x8BF1, 0x53C8, 0x5E7C, 0x8FC2, 0x6DE4, 0x4E8E, 0x76C2, 0x6986, 0x865E, 0x611A, 0x8206,
+    0x4F59, 0x4FDE, 0x903E, 0x9C7C, 0x6109, 0x6E1D, 0x6E14, 0x9685, 0x4E88, 0x5A31, 0x96E8, 0x4E0E,
+    0x5C7F, 0x79B9, 0x5B87, 0x8BED, 0x7FBD, 0x7389, 0x57DF, 0x828B, 0x90C1, 0x5401, 0x9047, 0x55BB,
+    0x5CEA, 0x5FA1, 0x6108, 0x6B32, 0x72F1, 0x80B2, 0x8A89, 0x8A1E, 0x8A3F, 0x8A49, 0x8A7A, 0x6D74,
+    0x5BD3, 0x88D5, 0x9884, 0x8C6B, 0x9A6D, 0x9E33, 0x6E0A, 0x51A4, 0x5143, 0x57A3, 0x8881, 0x539F,
+    0x63F4, 0x8F95, 0x56ED, 0x5458, 0x5706, 0x733F, 0x6E90, 0x7F18, 0x8FDC, 0x82D1, 0x613F, 0x6028,
+    0x9662, 0x66F0, 0x7EA6, 0x8D8A, 0x8DC3, 0x94A5, 0x5CB3, 0x7CA4, 0x6708, 0x60A6, 0x9605, 0x8018,
+    0x4E91, 0x90E7, 0x5300, 0x9668, 0x5141, 0x8FD0, 0x8574, 0x915D, 0x6655, 0x97F5, 0x5B55, 0x531D,
+    0x7838, 0x6742, 0x683D, 0x54C9, 0x707E, 0x5BB0, 0x8F7D, 0x518D, 0x5728, 0x54B1, 0x6512, 0x6682,
+    0x8D5E, 0x8D43, 0x810F, 0x846C, 0x906D, 0x7CDF, 0x51FF, 0x85FB, 0x67A3, 0x65E9, 0x6FA1, 0x86A4,
+    0x8E81, 0x566A, 0x9020, 0x7682, 0x7076, 0x71E5, 0x8D23, 0x62E9, 0x5219, 0x6CFD, 0x8D3C, 0x600E,
+    0x589E, 0x618E, 0x66FE, 0x8D60, 0x624E, 0x55B3, 0x6E23, 0x672D, 0x8F67, 0x8A81, 0x8A8B, 0x8A94,
+    0x94E1, 0x95F8, 0x7728, 0x6805, 0x69A8, 0x548B, 0x4E4D, 0x70B8, 0x8BC8, 0x6458, 0x658B, 0x5B85,
+    0x7A84, 0x503A, 0x5BE8, 0x77BB, 0x6BE1, 0x8A79, 0x7C98, 0x6CBE, 0x76CF, 0x65A9, 0x8F97, 0x5D2D,
+    0x5C55, 0x8638, 0x6808, 0x5360, 0x6218, 0x7AD9, 0x6E5B, 0x7EFD, 0x6A1F, 0x7AE0, 0x5F70, 0x6F33,
+    0x5F20, 0x638C, 0x6DA8, 0x6756, 0x4E08, 0x5E10, 0x8D26, 0x4ED7, 0x80C0, 0x7634, 0x969C, 0x62DB,
+    0x662D, 0x627E, 0x6CBC, 0x8D75, 0x7167, 0x7F69, 0x5146, 0x8087, 0x53EC, 0x906E, 0x6298, 0x54F2,
+    0x86F0, 0x8F99, 0x8005, 0x9517, 0x8517, 0x8FD9, 0x6D59, 0x73CD, 0x659F, 0x771F, 0x7504, 0x7827,
+    0x81FB, 0x8D1E, 0x9488, 0x4FA6, 0x6795, 0x75B9, 0x8BCA, 0x9707, 0x632F, 0x9547, 0x9635, 0x84B8,
+    0x6323, 0x7741, 0x5F81, 0x72F0, 0x4E89, 0x6014, 0x6574, 0x62EF, 0x6B63, 0x653F, 0x8AE4, 0x8B08,
+    0x8B27, 0x5E27, 0x75C7, 0x90D1, 0x8BC1, 0x829D, 0x679D, 0x652F, 0x5431, 0x8718, 0x77E5, 0x80A2,
+    0x8102, 0x6C41, 0x4E4B, 0x7EC7, 0x804C, 0x76F4, 0x690D, 0x6B96, 0x6267, 0x503C, 0x4F84, 0x5740,
+    0x6307, 0x6B62, 0x8DBE, 0x53EA, 0x65E8, 0x7EB8, 0x5FD7, 0x631A, 0x63B7, 0x81F3, 0x7F6E, 0x5E1C,
+    0x5CD9, 0x5236, 0x667A, 0x79E9, 0x7A1A, 0x8D28, 0x7099, 0x75D4, 0x6EDE, 0x6CBB, 0x7A92, 0x4E2D,
+    0x76C5, 0x5FE0, 0x949F, 0x8877, 0x7EC8, 0x79CD, 0x80BF, 0x91CD, 0x4EF2, 0x4F17, 0x821F, 0x5468,
+    0x5DDE, 0x6D32, 0x8BCC, 0x7CA5, 0x8F74, 0x8098, 0x5E1A, 0x5492, 0x76B1, 0x5B99, 0x663C, 0x9AA4,
+    0x73E0, 0x682A, 0x86DB, 0x6731, 0x732A, 0x8BF8, 0x8BDB, 0x9010, 0x7AF9, 0x70DB, 0x716E, 0x62C4,
+    0x77A9, 0x5631, 0x4E3B, 0x8457, 0x67F1, 0x52A9, 0x86C0, 0x8D2E, 0x94F8, 0x7B51, 0x8B46, 0x8B67,
+    0x8B6D, 0x8BAC, 0x8BB1, 0x8BBB, 0x8BC7, 0x8BD0, 0x8BEA, 0x8C09, 0x8C1E, 0x4F4F, 0x6CE8, 0x795D,
+    0x9A7B, 0x6293, 0x722A, 0x62FD, 0x4E13, 0x7816, 0x8F6C, 0x64B0, 0x8D5A, 0x7BC6, 0x6869, 0x5E84,
+    0x88C5, 0x5986, 0x649E, 0x58EE, 0x72B6, 0x690E, 0x9525, 0x8FFD, 0x8D58, 0x5760, 0x7F00, 0x8C06,
+    0x51C6, 0x6349, 0x62D9, 0x5353, 0x684C, 0x7422, 0x8301, 0x914C, 0x5544, 0x7740, 0x707C, 0x6D4A,
+    0x5179, 0x54A8, 0x8D44, 0x59FF, 0x6ECB, 0x6DC4, 0x5B5C, 0x7D2B, 0x4ED4, 0x7C7D, 0x6ED3, 0x5B50,
+    0x81EA, 0x6E0D, 0x5B57, 0x9B03, 0x68D5, 0x8E2A, 0x5B97, 0x7EFC, 0x603B, 0x7EB5, 0x90B9, 0x8D70,
+    0x594F, 0x63CD, 0x79DF, 0x8DB3, 0x5352, 0x65CF, 0x7956, 0x8BC5, 0x963B, 0x7EC4, 0x94BB, 0x7E82,
+    0x5634, 0x9189, 0x6700, 0x7F6A, 0x5C0A, 0x9075, 0x6628, 0x5DE6, 0x4F50, 0x67DE, 0x505A, 0x4F5C,
+    0x5750, 0x5EA7, 0xE810, 0x8C38, 0x8C42, 0x8C48, 0x8C4A, 0x8C4D, 0x8C56, 0x8C5B, 0x8C63, 0x8C6C,
+    0x8C74, 0x8C7B, 0x8C83, 0x8C86, 0x8C8B, 0x8C8D, 0x8C95, 0x8C99, 0x4E8D, 0x4E0C, 0x5140, 0x4E10,
+    0x5EFF, 0x5345, 0x4E15, 0x4E98, 0x4E1E, 0x9B32, 0x5B6C, 0x5669, 0x4E28, 0x79BA, 0x4E3F, 0x5315,
+    0x4E47, 0x592D, 0x723B, 0x536E, 0x6C10, 0x56DF, 0x80E4, 0x9997, 0x6BD3, 0x777E, 0x9F17, 0x4E36,
+    0x4E9F, 0x9F10, 0x4E5C, 0x4E69, 0x4E93, 0x8288, 0x5B5B, 0x556C, 0x560F, 0x4EC4, 0x538D, 0x539D,
+    0x53A3, 0x53A5, 0x53AE, 0x9765, 0x8D5D, 0x531A, 0x53F5, 0x5326, 0x532E, 0x533E, 0x8D5C, 0x5366,
+    0x5363, 0x5202, 0x5208, 0x520E, 0x522D, 0x5233, 0x523F, 0x524C, 0x525E, 0x5261, 0x525C, 0x84AF,
+    0x527D, 0x5282, 0x5281, 0x5290, 0x5293, 0x5182, 0x7F54, 0x4EBB, 0x4EC3, 0x4EC9, 0x4EC2, 0x4EE8,
+    0x4EE1, 0x4EEB, 0x4EDE, 0x4F1B, 0x4EF3, 0x4F22, 0x4F64, 0x4EF5, 0x4F25, 0x4F27, 0x4F09, 0x4F2B,
+    0x4F5E, 0x4F67, 0x6538, 0x4F5A, 0x4F5D, 0x8CAE, 0x4F5F, 0x4F57, 0x4F32, 0x4F3D, 0x4F76, 0x4F74,
+    0x4F91, 0x4F89, 0x4F83, 0x4F8F, 0x4F7E, 0x4F7B, 0x4FAA, 0x4F7C, 0x4FAC, 0x4F94, 0x4FE6, 0x4FE8,
+    0x4FEA, 0x4FC5, 0x4FDA, 0x4FE3, 0x4FDC, 0x4FD1, 0x4FDF, 0x4FF8, 0x5029, 0x504C, 0x4FF3, 0x502C,
+    0x500F, 0x502E, 0x502D, 0x4FFE, 0x501C, 0x500C, 0x5025, 0x5028, 0x507E, 0x5043, 0x5055, 0x5048,
+    0x504E, 0x506C, 0x507B, 0x50A5, 0x50A7, 0x50A9, 0x50BA, 0x50D6, 0x5106, 0x50ED, 0x50EC, 0x50E6,
+    0x50EE, 0x5107, 0x510B, 0x4EDD, 0x6C3D, 0x4F58, 0x4F65, 0x4FCE, 0x9FA0, 0x6C46, 0x7C74, 0x516E,
+    0x5DFD, 0x9EC9, 0x9998, 0x5181, 0x5914, 0x52F9, 0x530D, 0x8A07, 0x5310, 0x51EB, 0x5919, 0x5155,
+    0x4EA0, 0x5156, 0x4EB3, 0x886E, 0x88A4, 0x4EB5, 0x8114, 0x88D2, 0x7980, 0x5B34, 0x8803, 0x7FB8,
+    0x51AB, 0x51B1, 0x51BD, 0x51BC, 0x8D0E, 0x8D20, 0x8D51, 0x8D57, 0x8D5F, 0x8D65, 0x8D68, 0x8D6C,
+    0x8D6E, 0x8D71, 0x8D78, 0x8D82, 0x8D86, 0x8D8C, 0x8D92, 0x8D95, 0x8DA0, 0x8DA4, 0x8DB2, 0x8DB6,
+    0x8DB9, 0x8DBB, 0x8DBD, 0x8DC0, 0x8DC5, 0x8DC7, 0x8DCD, 0x8DD0, 0x8DD2, 0x51C7, 0x5196, 0x51A2,
+    0x51A5, 0x8BA0, 0x8BA6, 0x8BAA, 0x8BB4, 0x8BB7, 0x8BC2, 0x8BCB, 0x8BCF, 0x8BCE, 0x8BD2, 0x8BD6,
+    0x8BD8, 0x8BDC, 0x8BDF, 0x8BE4, 0x8BE8, 0x8BEE, 0x8BF0, 0x8BF3, 0x8BF6, 0x8BF9, 0x8BFC, 0x8BFF,
+    0x8C02, 0x8C04, 0x8C07, 0x8C0C, 0x8C0F, 0x8C11, 0x8C14, 0x8C19, 0x8C1B, 0x8C18, 0x8C1D, 0x8C1F,
+    0x8C25, 0x8C27, 0x8C2A, 0x8C2E, 0x8C32, 0x8C35, 0x5369, 0x537A, 0x961D, 0x9622, 0x9621, 0x9631,
+    0x962A, 0x963D, 0x963C, 0x9642, 0x9649, 0x9654, 0x965F, 0x9667, 0x966C, 0x9672, 0x9674, 0x9688,
+    0x968D, 0x9697, 0x96B0, 0x9097, 0x909B, 0x909D, 0x9099, 0x90AC, 0x90A1, 0x90B4, 0x90B3, 0x90B6,
+    0x90BA, 0x8DD5, 0x8DD8, 0x8DDC, 0x8DE0, 0x8DE5, 0x8DE9, 0x8DED, 0x8DF0, 0x8DF4, 0x8DF6, 0x8DFC,
+    0x8DFE, 0x8E06, 0x8E0B, 0x8E0D, 0x8E10, 0x8E15, 0x8E20, 0x8E24, 0x8E2B, 0x8E2D, 0x8E30, 0x8E32,
+    0x8E36, 0x8E3B, 0x8E3E, 0x8E43, 0x8E45, 0x8E4C, 0x8E53, 0x8E5A, 0x8E67, 0x8E6A, 0x8E6E, 0x8E71,
+    0x90B8, 0x90B0, 0x90CF, 0x90C5, 0x90BE, 0x90D0, 0x90C4, 0x90C7, 0x90D3, 0x90E6, 0x90E2, 0x90DC,
+    0x90D7, 0x90DB, 0x90EB, 0x90EF, 0x90FE, 0x9104, 0x9122, 0x911E, 0x9123, 0x9131, 0x912F, 0x9139,
+    0x9143, 0x9146, 0x520D, 0x5942, 0x52A2, 0x52AC, 0x52BE, 0x54FF, 0x52D0, 0x52D6, 0x52F0, 0x53DF,
+    0x71EE, 0x77CD, 0x5EF4, 0x51F5, 0x51FC, 0x9B2F, 0x53B6, 0x5F01, 0x755A, 0x5DEF, 0x574C, 0x57A9,
+    0x57A1, 0x587E, 0x58BC, 0x58C5, 0x58D1, 0x5729, 0x572C, 0x572A, 0x5733, 0x5739, 0x572E, 0x575C,
+    0x573B, 0x5742, 0x5769, 0x5785, 0x576B, 0x5786, 0x577C, 0x577B, 0x5768, 0x576D, 0x5776, 0x5773,
+    0x57AD, 0x57A4, 0x578C, 0x57B2, 0x57CF, 0x57A7, 0x57B4, 0x5793, 0x57A0, 0x57D5, 0x57D8, 0x57DA,
+    0x57D9, 0x57D2, 0x57B8, 0x57F4, 0x57EF, 0x57F8, 0x57E4, 0x57DD, 0x8E73, 0x8E75, 0x8E77, 0x8E7D,
+    0x8E80, 0x8E82, 0x8E86, 0x8E88, 0x8E91, 0x8E95, 0x8E9D, 0x8E9F, 0x8EAD, 0x8EB0, 0x8EB3, 0x8EBB,
+    0x8ECF, 0x580B, 0x580D, 0x57FD, 0x57ED, 0x5800, 0x581E, 0x5819, 0x5844, 0x5820, 0x5865, 0x586C,
+    0x5881, 0x5889, 0x589A, 0x5880, 0x99A8, 0x9F19, 0x61FF, 0x8279, 0x827D, 0x827F, 0x828F, 0x828A,
+    0x82A8, 0x8284, 0x828E, 0x8291, 0x8297, 0x8299, 0x82AB, 0x82B8, 0x82BE, 0x82B0, 0x82C8, 0x82CA,
+    0x82E3, 0x8298, 0x82B7, 0x82AE, 0x82CB, 0x82C1, 0x82A9, 0x82B4, 0x82A1, 0x82AA, 0x829F, 0x82C4,
+    0x82CE, 0x82A4, 0x82E1, 0x8309, 0x82F7, 0x82E4, 0x830F, 0x8307, 0x82DC, 0x82F4, 0x82D2, 0x82D8,
+    0x830C, 0x82FB, 0x82D3, 0x8311, 0x831A, 0x8306, 0x8314, 0x82E0, 0x82D5, 0x831C, 0x8351, 0x835B,
+    0x8308, 0x8392, 0x833C, 0x8334, 0x8331, 0x839B, 0x835E, 0x832F, 0x834F, 0x8347, 0x8343, 0x835F,
+    0x8340, 0x8317, 0x8360, 0x832D, 0x833A, 0x8333, 0x8366, 0x8365, 0x8EE5, 0x8368, 0x831B, 0x8369,
+    0x836C, 0x836A, 0x836D, 0x83B0, 0x8378, 0x83B3, 0x83A0, 0x83AA, 0x8393, 0x839C, 0x8385, 0x837C,
+    0x83B6, 0x83A9, 0x837D, 0x83B8, 0x837B, 0x8398, 0x839E, 0x83A8, 0x83BA, 0x83BC, 0x83C1, 0x8401,
+    0x83E5, 0x83D8, 0x5807, 0x8418, 0x840B, 0x83DD, 0x83FD, 0x83D6, 0x841C, 0x8438, 0x8411, 0x8406,
+    0x83D4, 0x83DF, 0x840F, 0x8403, 0x83F8, 0x83EA, 0x83C5, 0x83C0, 0x8426, 0x83F0, 0x83E1, 0x845C,
+    0x8451, 0x845A, 0x8459, 0x8473, 0x8487, 0x847A, 0x8489, 0x8478, 0x843C, 0x8446, 0x8469, 0x8476,
+    0x848C, 0x848E, 0x8431, 0x846D, 0x84C1, 0x84CD, 0x84D0, 0x84E6, 0x84BD, 0x84D3, 0x84CA, 0x84BF,
+    0x84BA, 0x84E0, 0x84A1, 0x84B9, 0x84B4, 0x8497, 0x84E5, 0x84E3, 0x850C, 0x750D, 0x8538, 0x84F0,
+    0x8539, 0x851F, 0x853A, 0x8F45, 0x8F6A, 0x8F80, 0x8F8C, 0x8F92, 0x8F9D, 0x8FA0, 0x8FA4, 0x8FAA,
+    0x8FAC, 0x8FB2, 0x8FB7, 0x8FBA, 0x8FBF, 0x8FC3, 0x8FC6, 0x8FC9, 0x8FCF, 0x8FD2, 0x8FD6, 0x8FDA,
+    0x8FE0, 0x8FE3, 0x8FE7, 0x8FEC, 0x8FEF, 0x8FF1, 0x8FF4, 0x8FFA, 0x8FFE, 0x9007, 0x900C, 0x900E,
+    0x9013, 0x9015, 0x9018, 0x8556, 0x853B, 0x84FF, 0x84FC, 0x8559, 0x8548, 0x8568, 0x8564, 0x855E,
+    0x857A, 0x77A2, 0x8543, 0x8572, 0x857B, 0x85A4, 0x85A8, 0x8587, 0x858F, 0x8579, 0x85AE, 0x859C,
+    0x8585, 0x85B9, 0x85B7, 0x85B0, 0x85D3, 0x85C1, 0x85DC, 0x85FF, 0x8627, 0x8605, 0x8629, 0x8616,
+    0x863C, 0x5EFE, 0x5F08, 0x593C, 0x5941, 0x8037, 0x5955, 0x595A, 0x5958, 0x530F, 0x5C22, 0x5C25,
+    0x5C2C, 0x5C34, 0x624C, 0x626A, 0x629F, 0x62BB, 0x62CA, 0x62DA, 0x62D7, 0x62EE, 0x6322, 0x62F6,
+    0x6339, 0x634B, 0x6343, 0x63AD, 0x63F6, 0x6371, 0x637A, 0x638E, 0x63B4, 0x636D, 0x63AC, 0x638A,
+    0x6369, 0x63AE, 0x63BC, 0x63F2, 0x63F8, 0x63E0, 0x63FF, 0x63C4, 0x63DE, 0x63CE, 0x6452, 0x63C6,
+    0x63BE, 0x6445, 0x6441, 0x640B, 0x641B, 0x6420, 0x640C, 0x6426, 0x6421, 0x645E, 0x6484, 0x646D,
+    0x6496, 0x9019, 0x901C, 0x9023, 0x9027, 0x9030, 0x9037, 0x9039, 0x903D, 0x903F, 0x9043, 0x9045,
+    0x9048, 0x904E, 0x9054, 0x9059, 0x905C, 0x9064, 0x9066, 0x9069, 0x906F, 0x9076, 0x907E, 0x9081,
+    0x9084, 0x9089, 0x908C, 0x9092, 0x9094, 0x9096, 0x9098, 0x909A, 0x909C, 0x909E, 0x90A4, 0x90A7,
+    0x90AB, 0x90AD, 0x90B2, 0x90B7, 0x90BC, 0x90BF, 0x647A, 0x64B7, 0x6499, 0x64BA, 0x64C0, 0x64D0,
+    0x64D7, 0x64E4, 0x64E2, 0x6509, 0x6525, 0x652E, 0x5F0B, 0x5FD2, 0x7519, 0x5F11, 0x535F, 0x53F1,
+    0x53FD, 0x53E9, 0x53E8, 0x53FB, 0x5412, 0x5416, 0x5406, 0x544B, 0x5452, 0x5456, 0x5443, 0x5421,
+    0x5457, 0x5459, 0x5423, 0x5432, 0x5482, 0x5494, 0x5477, 0x5471, 0x5464, 0x549A, 0x5484, 0x5476,
+    0x5466, 0x549D, 0x54D0, 0x54AD, 0x54C2, 0x54B4, 0x54D2, 0x54A7, 0x54A6, 0x54D3, 0x5472, 0x54A3,
+    0x54D5, 0x54BB, 0x54BF, 0x54CC, 0x54D9, 0x54DC, 0x54A9, 0x54A4, 0x54DD, 0x54CF, 0x54DE, 0x551B,
+    0x54E7, 0x5520, 0x54FD, 0x5514, 0x54F3, 0x5522, 0x550F, 0x5511, 0x5527, 0x552A, 0x5567, 0x558F,
+    0x55B5, 0x5549, 0x556D, 0x5541, 0x5555, 0x553F, 0x5550, 0x553C, 0x90C2, 0x90C6, 0x90C8, 0x90CB,
+    0x90D2, 0x90D4, 0x90D8, 0x90DE, 0x90E3, 0x90E9, 0x90EC, 0x90EE, 0x90F0, 0x90F5, 0x90F9, 0x90FF,
+    0x9103, 0x9105, 0x911A, 0x911F, 0x9124, 0x9130, 0x9132, 0x913A, 0x9144, 0x5537, 0x5556, 0x5575,
+    0x5533, 0x5530, 0x555C, 0x558B, 0x55D2, 0x5583, 0x55B1, 0x55B9, 0x5588, 0x5581, 0x559F, 0x557E,
+    0x55D6, 0x5591, 0x557B, 0x55DF, 0x55BD, 0x5594, 0x5599, 0x55EA, 0x55F7, 0x55C9, 0x561F, 0x55D1,
+    0x55EB, 0x55D4, 0x55E6, 0x55DD, 0x55C4, 0x55EF, 0x55E5, 0x55F2, 0x55CC, 0x55E8, 0x55F5, 0x55E4,
+    0x8F94, 0x561E, 0x5608, 0x560C, 0x5601, 0x5624, 0x5623, 0x55FE, 0x5600, 0x5627, 0x562D, 0x5658,
+    0x5639, 0x5657, 0x562C, 0x564D, 0x5662, 0x5659, 0x565C, 0x564C, 0x5654, 0x5686, 0x5664, 0x5671,
+    0x566B, 0x567B, 0x5685, 0x5693, 0x56AF, 0x56D4, 0x56D7, 0x56DD, 0x56E1, 0x56F5, 0x56EB, 0x56F9,
+    0x56FF, 0x5704, 0x570A, 0x5709, 0x571C, 0x5E0F, 0x5E19, 0x5E14, 0x5E11, 0x5E31, 0x5E3B, 0x9145,
+    0x9147, 0x9151, 0x9153, 0x9158, 0x915B, 0x915F, 0x9166, 0x916B, 0x916D, 0x9173, 0x917A, 0x9180,
+    0x9186, 0x9188, 0x918A, 0x918E, 0x9193, 0x919C, 0x91A4, 0x91AB, 0x91B0, 0x91B6, 0x91BB, 0x91C8,
+    0x91CB, 0x91D0, 0x91D2, 0x91DD, 0x5E37, 0x5E44, 0x5E54, 0x5E5B, 0x5E5E, 0x5E61, 0x5C8C, 0x5C7A,
+    0x5C8D, 0x5C90, 0x5C96, 0x5C88, 0x5C98, 0x5C91, 0x5C9A, 0x5C9C, 0x5CB5, 0x5CA2, 0x5CBD, 0x5CAC,
+    0x5CAB, 0x5CB1, 0x5CA3, 0x5CC1, 0x5CB7, 0x5CC4, 0x5CD2, 0x5CE4, 0x5CCB, 0x5CE5, 0x5D02, 0x5D27,
+    0x5D26, 0x5D2E, 0x5D24, 0x5D1E, 0x5D06, 0x5D1B, 0x5D58, 0x5D3E, 0x5D34, 0x5D3D, 0x5D6C, 0x5D5B,
+    0x5D6F, 0x5D5D, 0x5D6B, 0x5D4B, 0x5D4A, 0x5D69, 0x5D74, 0x5D82, 0x5D99, 0x5D9D, 0x8C73, 0x5DB7,
+    0x5DC5, 0x5F73, 0x5F77, 0x5F82, 0x5F87, 0x5F89, 0x5F8C, 0x5F95, 0x5F99, 0x5F9C, 0x5FA8, 0x5FAD,
+    0x5FB5, 0x5FBC, 0x8862, 0x5F61, 0x72AD, 0x72B0, 0x72B4, 0x72B7, 0x72C3, 0x72C1, 0x72CE, 0x72CD,
+    0x72D2, 0x72E8, 0x72EF, 0x72E9, 0x72F2, 0x72F4, 0x72F7, 0x7301, 0x72F3, 0x7303, 0x72FA, 0x91E6,
+    0x72FB, 0x7317, 0x7313, 0x7321, 0x730A, 0x731E, 0x731D, 0x7315, 0x7322, 0x7339, 0x7325, 0x732C,
+    0x7338, 0x7331, 0x7350, 0x734D, 0x7357, 0x7360, 0x736C, 0x736F, 0x737E, 0x821B, 0x5925, 0x98E7,
+    0x5924, 0x5902, 0x9963, 0x9967, 0x9974, 0x9977, 0x997D, 0x9980, 0x9984, 0x9987, 0x998A, 0x998D,
+    0x9990, 0x9993, 0x5E80, 0x5E91, 0x5E8B, 0x5E96, 0x5EA5, 0x5EA0, 0x5EB9, 0x5EB5, 0x5EBE, 0x5EB3,
+    0x8D53, 0x5ED2, 0x5ED1, 0x5EDB, 0x5EE8, 0x5EEA, 0x81BA, 0x5FC4, 0x5FC9, 0x5FD6, 0x5FCF, 0x6003,
+    0x5FEE, 0x6004, 0x5FE1, 0x5FE4, 0x5FFE, 0x6005, 0x5FEA, 0x5FED, 0x5FF8, 0x6019, 0x6035, 0x6026,
+    0x601B, 0x600F, 0x600D, 0x6029, 0x602B, 0x600A, 0x603F, 0x6021, 0x6078, 0x607B, 0x607A, 0x6042,
+    0x9246, 0x9275, 0x928F, 0x606A, 0x607D, 0x6096, 0x609A, 0x60AD, 0x609D, 0x6083, 0x6092, 0x608C,
+    0x609B, 0x60EC, 0x60BB, 0x60B1, 0x60DD, 0x60D8, 0x60C6, 0x60DA, 0x60B4, 0x6120, 0x6126, 0x6115,
+    0x6123, 0x60F4, 0x6100, 0x610E, 0x612B, 0x614A, 0x6175, 0x61AC, 0x6194, 0x61A7, 0x61B7, 0x61D4,
+    0x61F5, 0x5FDD, 0x96B3, 0x95E9, 0x95EB, 0x95F1, 0x95F3, 0x95F5, 0x95FC, 0x95FE, 0x9603, 0x9606,
+    0x9608, 0x960A, 0x960F, 0x9612, 0x9615, 0x9619, 0x4E2C, 0x723F, 0x6215, 0x6C35, 0x6C54, 0x6C5C,
+    0x6C4A, 0x6CA3, 0x6C85, 0x6C90, 0x6C94, 0x6C8C, 0x6C68, 0x6C74, 0x6C76, 0x6C86, 0x6CA9, 0x6CD0,
+    0x6CD4, 0x6CAD, 0x6CF7, 0x6CF1, 0x6CD7, 0x6CB2, 0x6CE0, 0x6CD6, 0x6CFA, 0x6CEB, 0x6CEE, 0x6CB1,
+    0x6CD3, 0x6CEF, 0x6CFE, 0x92A8, 0x92AF, 0x92C9, 0x6D39, 0x6D27, 0x6D0C, 0x6D43, 0x6D48, 0x6D07,
+    0x6D04, 0x6D19, 0x6D0E, 0x6D2B, 0x6D4D, 0x6D2E, 0x6D35, 0x6D1A, 0x6D4F, 0x6D52, 0x6D54, 0x6D33,
+    0x6D91, 0x6D6F, 0x6D9E, 0x6DA0, 0x6D5E, 0x6D93, 0x6D5C, 0x6D60, 0x6D7C, 0x6D63, 0x6E1A, 0x6DC7,
+    0x6DC5, 0x6DDE, 0x6E0E, 0x6DBF, 0x6DE0, 0x6E11, 0x6DE6, 0x6DDD, 0x6DD9, 0x6E16, 0x6DAB, 0x6E0C,
+    0x6DAE, 0x6E2B, 0x6E6E, 0x6E4E, 0x6E6B, 0x6EB2, 0x6E5F, 0x6E86, 0x6E53, 0x6E32, 0x6E25, 0x6E44,
+    0x6EDF, 0x6EB1, 0x6E98, 0x6EE0, 0x6F2D, 0x6EE2, 0x6EA5, 0x6EA7, 0x6EBD, 0x6EBB, 0x6EB7, 0x6ED7,
+    0x6EB4, 0x6ECF, 0x6E8F, 0x6EC2, 0x6E9F, 0x6F62, 0x6F46, 0x6F24, 0x6F15, 0x6EF9, 0x6F2F, 0x6F36,
+    0x6F4B, 0x6F74, 0x6F2A, 0x6F09, 0x6F29, 0x6F89, 0x6F8D, 0x6F8C, 0x6F78, 0x6F72, 0x6F7C, 0x6F7A,
+    0x6FD1, 0x930A, 0x933F, 0x936B, 0x6FC9, 0x6FA7, 0x6FB9, 0x6FB6, 0x6FC2, 0x6FE1, 0x6FEE, 0x6FDE,
+    0x6FE0, 0x6FEF, 0x701A, 0x7023, 0x701B, 0x7039, 0x7035, 0x704F, 0x705E, 0x5B80, 0x5B84, 0x5B95,
+    0x5B93, 0x5BA5, 0x5BB8, 0x752F, 0x9A9E, 0x6434, 0x5BE4, 0x5BEE, 0x8930, 0x5BF0, 0x8E47, 0x8B07,
+    0x8FB6, 0x8FD3, 0x8FD5, 0x8FE5, 0x8FEE, 0x8FE4, 0x8FE9, 0x8FE6, 0x8FF3, 0x8FE8, 0x9005, 0x9004,
+    0x900B, 0x9026, 0x9011, 0x900D, 0x9016, 0x9021, 0x9035, 0x902D, 0x902F, 0x9044, 0x9051, 0x9050,
+    0x9068, 0x9058, 0x9062, 0x905B, 0x66B9, 0x9074, 0x907D, 0x9082, 0x9088, 0x9083, 0x908B, 0x5F50,
+    0x5F57, 0x5F56, 0x5F58, 0x5C3B, 0x54AB, 0x5C50, 0x5C59, 0x5B71, 0x5C63, 0x5C66, 0x7FBC, 0x5F2A,
+    0x5F29, 0x5F2D, 0x8274, 0x5F3C, 0x9B3B, 0x5C6E, 0x5981, 0x5983, 0x598D, 0x59A9, 0x59A3, 0x936C,
+    0x9390, 0x93CB, 0x5997, 0x59CA, 0x59AB, 0x599E, 0x59A4, 0x59D2, 0x59B2, 0x59AF, 0x59D7, 0x59BE,
+    0x5A05, 0x59DD, 0x5A08, 0x59E3, 0x59D8, 0x59F9, 0x5A0C, 0x5A09, 0x5A32, 0x5A34, 0x5A11, 0x5A23,
+    0x5A13, 0x5A40, 0x5A67, 0x5A4A, 0x5A55, 0x5A3C, 0x5A62, 0x5A75, 0x80EC, 0x5AAA, 0x5A9B, 0x5A77,
+    0x5A7A, 0x5ABE, 0x5AEB, 0x5AB2, 0x5AD2, 0x5AD4, 0x5AB8, 0x5AE0, 0x5AE3, 0x5AF1, 0x5AD6, 0x5AE6,
+    0x5AD8, 0x5ADC, 0x5B09, 0x5B17, 0x5B16, 0x5B32, 0x5B37, 0x5B40, 0x5C15, 0x5C1C, 0x5B5A, 0x5B65,
+    0x5B73, 0x5B51, 0x5B53, 0x5B62, 0x9A75, 0x9A77, 0x9A7A, 0x9A7F, 0x9A7D, 0x9A80, 0x9A85, 0x9A88,
+    0x9A8A, 0x9A90, 0x9A92, 0x9A96, 0x9A98, 0x9A9B, 0x9A9F, 0x9AA2, 0x9AA5, 0x9AA7, 0x7E9F, 0x7EA1,
+    0x7EA3, 0x7EA5, 0x7EA8, 0x93CE, 0x93D7, 0x7EAD, 0x7EB0, 0x7EBE, 0x7EC0, 0x7EC9, 0x7ECB, 0x7ED0,
+    0x7ED4, 0x7ED7, 0x7EDB, 0x7EE0, 0x7EE8, 0x7EEB, 0x7EEE, 0x7EF1, 0x7F0D, 0x7EF6, 0x7EFA, 0x7EFE,
+    0x7F01, 0x7F07, 0x7F0B, 0x7F0F, 0x7F11, 0x7F17, 0x7F19, 0x7F1C, 0x7F1B, 0x7F1F, 0x7F21, 0x7F2A,
+    0x7F2F, 0x7F35, 0x5E7A, 0x757F, 0x5DDB, 0x753E, 0x9095, 0x738E, 0x7391, 0x73AE, 0x73A2, 0x739F,
+    0x73CF, 0x73C2, 0x73D1, 0x73B7, 0x73B3, 0x73C0, 0x73C9, 0x73C8, 0x73E5, 0x73D9, 0x987C, 0x740A,
+    0x73E9, 0x73E7, 0x73DE, 0x73BA, 0x73F2, 0x740F, 0x742A, 0x745B, 0x7426, 0x7425, 0x7428, 0x7430,
+    0x742E, 0x742C, 0x942F, 0x943F, 0x946C, 0x9491, 0x9496, 0x9498, 0x94C7, 0x94CF, 0x94D3, 0x94DA,
+    0x94E6, 0x94FB, 0x951C, 0x9520, 0x741B, 0x741A, 0x7441, 0x745C, 0x7457, 0x7455, 0x7459, 0x7477,
+    0x746D, 0x747E, 0x749C, 0x748E, 0x7480, 0x7487, 0x748B, 0x749E, 0x74A8, 0x7490, 0x74A7, 0x74D2,
+    0x74BA, 0x97EA, 0x674C, 0x6753, 0x675E, 0x6748, 0x6769, 0x67A5, 0x6787, 0x676A, 0x6773, 0x6798,
+    0x67A7, 0x6775, 0x67A8, 0x679E, 0x67AD, 0x678B, 0x6777, 0x677C, 0x67F0, 0x6809, 0x67D8, 0x680A,
+    0x67E9, 0x67B0, 0x680C, 0x67D9, 0x67B5, 0x67DA, 0x67B3, 0x67DD, 0x6800, 0x67C3, 0x67B8, 0x67E2,
+    0x680E, 0x67C1, 0x67FD, 0x6832, 0x6860, 0x684E, 0x6862, 0x6844, 0x6864, 0x6883, 0x681D, 0x6855,
+    0x6866, 0x6841, 0x6867, 0x6840, 0x683E, 0x684A, 0x6849, 0x6829, 0x68B5, 0x688F, 0x6874, 0x6877,
+    0x6893, 0x686B, 0x68C2, 0x696E, 0x68FC, 0x691F, 0x68F9, 0x9527, 0x9533, 0x953D, 0x9543, 0x9548,
+    0x954B, 0x9555, 0x955A, 0x9560, 0x956E, 0x9574, 0x9577, 0x9580, 0x6924, 0x68F0, 0x690B, 0x6901,
+    0x6957, 0x68E3, 0x6910, 0x6971, 0x6939, 0x6960, 0x6942, 0x695D, 0x6984, 0x696B, 0x6980, 0x6998,
+    0x6978, 0x6934, 0x69CC, 0x6987, 0x69CE, 0x6989, 0x6966, 0x6963, 0x6979, 0x699B, 0x69A7, 0x69BB,
+    0x69AB, 0x69AD, 0x69D4, 0x69B1, 0x69C1, 0x69CA, 0x69DF, 0x6995, 0x69E0, 0x698D, 0x69FF, 0x6A2F,
+    0x69ED, 0x6A17, 0x6A65, 0x69F2, 0x6A44, 0x6A3E, 0x6AA0, 0x6A50, 0x6A5B, 0x6A35, 0x6A8E, 0x6A79,
+    0x6A3D, 0x6A28, 0x6A58, 0x6A7C, 0x6A91, 0x6A90, 0x6AA9, 0x6A97, 0x6AAB, 0x7337, 0x7352, 0x6B81,
+    0x6B87, 0x6B84, 0x6B92, 0x6B8D, 0x6B9A, 0x6BA1, 0x6BAA, 0x8F6B, 0x8F6D, 0x8F71, 0x8F75, 0x8F78,
+    0x8F77, 0x8F79, 0x8F7C, 0x8F7E, 0x8F81, 0x8F84, 0x8F87, 0x8F8B, 0x95CC, 0x95EC, 0x95FF, 0x9607,
+    0x9613, 0x9618, 0x961B, 0x961E, 0x9620, 0x9623, 0x962B, 0x962F, 0x9637, 0x963E, 0x9641, 0x9643,
+    0x964A, 0x964E, 0x9651, 0x9656, 0x965C, 0x9660, 0x9663, 0x9665, 0x966B, 0x966D, 0x9673, 0x9678,
+    0x9687, 0x9689, 0x8F8D, 0x8F98, 0x8F9A, 0x8ECE, 0x620B, 0x6217, 0x621B, 0x621F, 0x6222, 0x6221,
+    0x6225, 0x6224, 0x622C, 0x81E7, 0x74EF, 0x74F4, 0x74FF, 0x750F, 0x7511, 0x7513, 0x6534, 0x65EE,
+    0x660A, 0x6619, 0x6772, 0x6603, 0x6615, 0x6600, 0x7085, 0x66F7, 0x661D, 0x6634, 0x6631, 0x6636,
+    0x6635, 0x8006, 0x665F, 0x6654, 0x6641, 0x664F, 0x6656, 0x6661, 0x6657, 0x6677, 0x6684, 0x668C,
+    0x66A7, 0x669D, 0x66BE, 0x66DB, 0x66E6, 0x66E9, 0x8D32, 0x8D36, 0x8D3B, 0x8D3D, 0x8D40, 0x8D45,
+    0x8D48, 0x8D47, 0x8D4D, 0x8D55, 0x8D59, 0x89C7, 0x89CA, 0x89CE, 0x726E, 0x729F, 0x725D, 0x7266,
+    0x726F, 0x727E, 0x7284, 0x728B, 0x728D, 0x728F, 0x7292, 0x6308, 0x6332, 0x63B0, 0x968C, 0x968E,
+    0x9691, 0x9695, 0x969A, 0x969D, 0x96A8, 0x96B1, 0x96B4, 0x96B7, 0x96BA, 0x96BF, 0x96C2, 0x96C8,
+    0x96CA, 0x96D0, 0x96D3, 0x96D6, 0x96E1, 0x96EB, 0x96F0, 0x96F4, 0x96F8, 0x96FA, 0x96FF, 0x9702,
+    0x9705, 0x970A, 0x9710, 0x9714, 0x9717, 0x971D, 0x971F, 0x643F, 0x64D8, 0x8004, 0x6BEA, 0x6BF3,
+    0x6BFD, 0x6BF5, 0x6BF9, 0x6C05, 0x6C07, 0x6C06, 0x6C0D, 0x6C15, 0x6C18, 0x6C21, 0x6C29, 0x6C24,
+    0x6C2A, 0x6C32, 0x6535, 0x6555, 0x656B, 0x724D, 0x7252, 0x7256, 0x7230, 0x8662, 0x5216, 0x809F,
+    0x809C, 0x8093, 0x80BC, 0x670A, 0x80BD, 0x80B1, 0x80AB, 0x80AD, 0x80B4, 0x80B7, 0x80E7, 0x80DB,
+    0x80C2, 0x80C4, 0x80D9, 0x80CD, 0x80D7, 0x6710, 0x80DD, 0x80EB, 0x80F1, 0x80F4, 0x80ED, 0x810D,
+    0x80F2, 0x80FC, 0x6715, 0x8112, 0x8C5A, 0x8136, 0x811E, 0x812C, 0x8118, 0x8132, 0x8148, 0x814C,
+    0x8153, 0x8174, 0x8159, 0x8171, 0x8160, 0x8169, 0x817C, 0x816D, 0x8167, 0x584D, 0x5AB5, 0x8188,
+    0x8182, 0x8191, 0x6ED5, 0x81A3, 0x81AA, 0x81CC, 0x6726, 0x81CA, 0x81BB, 0x9721, 0x972B, 0x972E,
+    0x9731, 0x9733, 0x973A, 0x973F, 0x9754, 0x9757, 0x975A, 0x975C, 0x975F, 0x9763, 0x9766, 0x976A,
+    0x9775, 0x9777, 0x977D, 0x9786, 0x978C, 0x978E, 0x9793, 0x9795, 0x9799, 0x81C1, 0x81A6, 0x6B24,
+    0x6B37, 0x6B39, 0x6B43, 0x6B46, 0x6B59, 0x98D1, 0x98D5, 0x98D9, 0x6BB3, 0x5F40, 0x6BC2, 0x89F3,
+    0x6590, 0x9F51, 0x6593, 0x65BC, 0x65C6, 0x65C4, 0x65C3, 0x65CC, 0x65CE, 0x65D2, 0x65D6, 0x7080,
+    0x709C, 0x7096, 0x709D, 0x70BB, 0x70C0, 0x70B7, 0x70AB, 0x70B1, 0x70E8, 0x70CA, 0x7110, 0x7113,
+    0x7116, 0x712F, 0x7131, 0x7173, 0x715C, 0x7168, 0x7145, 0x7172, 0x714A, 0x7178, 0x717A, 0x7198,
+    0x71B3, 0x71B5, 0x71A8, 0x71A0, 0x71E0, 0x71D4, 0x71E7, 0x71F9, 0x721D, 0x7228, 0x706C, 0x7118,
+    0x7166, 0x71B9, 0x623E, 0x623D, 0x6243, 0x6248, 0x793B, 0x7940, 0x7946, 0x7949, 0x795B, 0x7953,
+    0x795A, 0x7962, 0x7957, 0x7960, 0x796F, 0x7967, 0x797A, 0x7985, 0x798A, 0x799A, 0x79A7, 0x79B3,
+    0x5FD1, 0x5FD0, 0x979E, 0x97A1, 0x97A4, 0x97AC, 0x97AE, 0x97B0, 0x97B3, 0x97B5, 0x97E8, 0x97EE,
+    0x97F4, 0x97F7, 0x603C, 0x605D, 0x605A, 0x6067, 0x6041, 0x6059, 0x6063, 0x60AB, 0x6106, 0x610D,
+    0x615D, 0x61A9, 0x619D, 0x61CB, 0x61D1, 0x6206, 0x8080, 0x807F, 0x6C93, 0x6CF6, 0x6DFC, 0x77F6,
+    0x77F8, 0x7800, 0x7809, 0x7817, 0x7811, 0x65AB, 0x782D, 0x781C, 0x7839, 0x781F, 0x783C, 0x7825,
+    0x782C, 0x7823, 0x7829, 0x784E, 0x786D, 0x7856, 0x7826, 0x7850, 0x7847, 0x784C, 0x786A, 0x789B,
+    0x7893, 0x789A, 0x7887, 0x789C, 0x78A1, 0x78A3, 0x78B2, 0x78B9, 0x78A5, 0x78D4, 0x78D9, 0x78C9,
+    0x78EC, 0x78F2, 0x7905, 0x78F4, 0x7913, 0x7924, 0x791E, 0x7934, 0x9F9B, 0x9EF9, 0x9EFB, 0x76F1,
+    0x7704, 0x770D, 0x76F9, 0x7707, 0x771A, 0x7722, 0x7719, 0x772D, 0x7726, 0x7735, 0x7738, 0x7750,
+    0x7747, 0x7743, 0x775A, 0x7768, 0x980F, 0x7762, 0x7765, 0x777F, 0x778D, 0x777D, 0x7780, 0x778C,
+    0x7791, 0x779F, 0x77B0, 0x77B5, 0x77BD, 0x753A, 0x7540, 0x754E, 0x754B, 0x7548, 0x755B, 0x7572,
+    0x7579, 0x7583, 0x7F58, 0x7F61, 0x7F5F, 0x8A48, 0x7F68, 0x7F74, 0x7F71, 0x7F79, 0x7F81, 0x7F7E,
+    0x76CD, 0x76E5, 0x8832, 0x9485, 0x948B, 0x948A, 0x948C, 0x948F, 0x9494, 0x9497, 0x9495, 0x949A,
+    0x94A3, 0x94AB, 0x94AA, 0x94AD, 0x94AC, 0x94AF, 0x94B2, 0x94B4, 0x94B6, 0x94BC, 0x94BF, 0x94C4,
+    0x94C8, 0x94D0, 0x94D5, 0x94D9, 0x94D8, 0x94DB, 0x94DE, 0x94E2, 0x94E4, 0x94E7, 0x94EA, 0x986F,
+    0x988B, 0x988E, 0x9892, 0x9895, 0x9899, 0x98A3, 0x98A8, 0x98CF, 0x98D4, 0x98D6, 0x98DB, 0x98E0,
+    0x98E9, 0x94E9, 0x94EB, 0x94EE, 0x94F3, 0x94F7, 0x94F9, 0x94FC, 0x94FF, 0x9503, 0x9502, 0x9506,
+    0x9509, 0x950D, 0x9512, 0x9518, 0x951B, 0x951D, 0x9522, 0x952A, 0x9529, 0x952C, 0x9531, 0x9534,
+    0x9536, 0x953C, 0x953E, 0x9542, 0x9535, 0x9544, 0x9549, 0x954C, 0x954E, 0x9552, 0x9556, 0x955B,
+    0x955E, 0x955D, 0x9561, 0x9564, 0x956F, 0x9571, 0x953A, 0x77E7, 0x77EC, 0x96C9, 0x79D5, 0x79ED,
+    0x79E3, 0x79EB, 0x7A06, 0x5D47, 0x7A03, 0x7A02, 0x7A1E, 0x7A14, 0x9908, 0x990E, 0x9911, 0x992F,
+    0x9956, 0x9964, 0x9966, 0x9973, 0x9978, 0x997B, 0x997E, 0x9982, 0x9989, 0x7A39, 0x7A37, 0x7A51,
+    0x9ECF, 0x99A5, 0x7A70, 0x7688, 0x768E, 0x7693, 0x7699, 0x76A4, 0x74DE, 0x74E0, 0x752C, 0x9E20,
+    0x9E22, 0x9E28, 0x9E32, 0x9E31, 0x9E36, 0x9E38, 0x9E37, 0x9E39, 0x9E3E, 0x9E41, 0x9E44, 0x9E46,
+    0x9E4B, 0x9E4E, 0x9E51, 0x9E55, 0x9E57, 0x9E5A, 0x9E5E, 0x9E63, 0x9E66, 0x9E71, 0x9E6D, 0x9E73,
+    0x7592, 0x7594, 0x7596, 0x75A0, 0x759D, 0x75AC, 0x75A3, 0x75B3, 0x75B8, 0x75C4, 0x75B1, 0x75B0,
+    0x75C3, 0x75C2, 0x75D6, 0x75CD, 0x75E3, 0x75E8, 0x75E6, 0x75E4, 0x75EB, 0x75E7, 0x7603, 0x75F1,
+    0x75FC, 0x75FF, 0x7610, 0x7600, 0x7605, 0x760C, 0x7617, 0x760A, 0x7625, 0x7618, 0x7615, 0x7619,
+    0x998C, 0x998E, 0x999A, 0x99A6, 0x99A9, 0x761B, 0x763C, 0x7622, 0x7620, 0x7640, 0x762D, 0x7630,
+    0x763F, 0x7635, 0x7643, 0x763E, 0x7633, 0x764D, 0x765E, 0x7654, 0x765C, 0x7656, 0x766B, 0x766F,
+    0x7FCA, 0x7AE6, 0x7A78, 0x7A80, 0x7A86, 0x7A88, 0x7A95, 0x7AA6, 0x7AA0, 0x7AAC, 0x7AA8, 0x7AAD,
+    0x7AB3, 0x8864, 0x8869, 0x8872, 0x887D, 0x887F, 0x8882, 0x88A2, 0x88C6, 0x88B7, 0x88BC, 0x88C9,
+    0x88E2, 0x88CE, 0x88E3, 0x88E5, 0x88F1, 0x891A, 0x88FC, 0x88E8, 0x88FE, 0x88F0, 0x8921, 0x8919,
+    0x8913, 0x891B, 0x890A, 0x8934, 0x892B, 0x8936, 0x8941, 0x8966, 0x897B, 0x758B, 0x80E5, 0x76B2,
+    0x76B4, 0x77DC, 0x8012, 0x8014, 0x8016, 0x801C, 0x8020, 0x8022, 0x8025, 0x8029, 0x8028, 0x8031,
+    0x800B, 0x8035, 0x8043, 0x8046, 0x804D, 0x8052, 0x8069, 0x8071, 0x8983, 0x9878, 0x9880, 0x9883,
+    0x99FA, 0x9889, 0x988C, 0x988F, 0x9894, 0x989A, 0x989E, 0x98A1, 0x98A5, 0x864D, 0x8654, 0x866C,
+    0x866E, 0x867F, 0x867A, 0x867C, 0x867B, 0x86A8, 0x868D, 0x868B, 0x86AC, 0x869D, 0x86A7, 0x86A3,
+    0x86AA, 0x8693, 0x86A9, 0x86B6, 0x86C4, 0x86B5, 0x86CE, 0x86B0, 0x86BA, 0x86B1, 0x86AF, 0x86C9,
+    0x86CF, 0x86B4, 0x86E9, 0x86F1, 0x86ED, 0x86F3, 0x86D0, 0x8713, 0x86DE, 0x86F4, 0x86DF, 0x86D8,
+    0x86D1, 0x8703, 0x8707, 0x86F8, 0x8708, 0x870A, 0x870D, 0x8709, 0x8723, 0x873B, 0x871E, 0x8725,
+    0x872E, 0x871A, 0x873E, 0x8748, 0x8734, 0x8731, 0x8729, 0x8737, 0x873F, 0x8782, 0x8722, 0x877D,
+    0x877B, 0x8760, 0x8770, 0x874C, 0x876E, 0x878B, 0x8753, 0x8763, 0x877C, 0x8764, 0x8759, 0x8765,
+    0x8793, 0x87AF, 0x87A8, 0x87D2, 0x9A5A, 0x9A72, 0x9A83, 0x9A89, 0x9A8D, 0x9A94, 0x9A99, 0x9AA6,
+    0x9AA9, 0x9AB2, 0x9AB9, 0x9ABB, 0x9ABD, 0x9AC3, 0x9AC6, 0x9ACD, 0x9AD2, 0x9AD4, 0x9AD9, 0x9AE0,
+    0x9AE2, 0x9AE7, 0x9AEC, 0x9AEE, 0x9AF0, 0x9AFA, 0x9AFC, 0x9B04, 0x87C6, 0x8788, 0x8785, 0x87AD,
+    0x8797, 0x8783, 0x87AB, 0x87E5, 0x87AC, 0x87B5, 0x87B3, 0x87CB, 0x87D3, 0x87BD, 0x87D1, 0x87C0,
+    0x87CA, 0x87DB, 0x87EA, 0x87E0, 0x87EE, 0x8816, 0x8813, 0x87FE, 0x880A, 0x881B, 0x8821, 0x8839,
+    0x883C, 0x7F36, 0x7F42, 0x7F44, 0x8210, 0x7AFA, 0x7AFD, 0x7B08, 0x7B03, 0x7B15, 0x7B0A, 0x7B2B,
+    0x7B0F, 0x7B47, 0x7B38, 0x7B2A, 0x7B19, 0x7B2E, 0x7B31, 0x7B20, 0x7B25, 0x7B24, 0x7B33, 0x7B3E,
+    0x7B1E, 0x7B58, 0x7B5A, 0x7B45, 0x7B75, 0x7B4C, 0x7B5D, 0x7B60, 0x7B6E, 0x7B7B, 0x7B62, 0x7B72,
+    0x7B71, 0x7B90, 0x7BA6, 0x7BB8, 0x7BAC, 0x7B9D, 0x7BA8, 0x7B85, 0x7BAA, 0x7B9C, 0x7BA2, 0x7BAB,
+    0x7BB4, 0x7BD1, 0x7BC1, 0x7BCC, 0x7BDD, 0x7BDA, 0x7BE5, 0x7BEA, 0x7C0C, 0x7BFE, 0x7BFC, 0x7C0F,
+    0x7C16, 0x7C0B, 0x9B07, 0x9B09, 0x9B10, 0x9B14, 0x9B20, 0x9B24, 0x9B30, 0x9B33, 0x9B3D, 0x9B46,
+    0x9B4A, 0x9B4E, 0x9B50, 0x9B52, 0x9B55, 0x7C1F, 0x7C2A, 0x7C26, 0x7C38, 0x7C41, 0x7C40, 0x81FE,
+    0x8201, 0x8204, 0x81EC, 0x8844, 0x8221, 0x822D, 0x822F, 0x8228, 0x822B, 0x8238, 0x823B, 0x8233,
+    0x823E, 0x8244, 0x8249, 0x824B, 0x824F, 0x825A, 0x825F, 0x8268, 0x887E, 0x8885, 0x8888, 0x88D8,
+    0x88DF, 0x895E, 0x7F9D, 0x7F9F, 0x7FA7, 0x7FAF, 0x7FB2, 0x7C7C, 0x6549, 0x7C91, 0x7C9D, 0x7C9C,
+    0x7C9E, 0x7CA2, 0x7CB2, 0x7CBC, 0x7CC1, 0x7CC7, 0x7CCC, 0x7CC8, 0x7CC5, 0x7CD7, 0x7CE8, 0x826E,
+    0x66A8, 0x7FBF, 0x7FCE, 0x7FD5, 0x7FE5, 0x7FE1, 0x7FE6, 0x7FE9, 0x7FEE, 0x7FF3, 0x7CF8, 0x7D77,
+    0x7DA6, 0x7DAE, 0x7E47, 0x7E9B, 0x9EB8, 0x9EB4, 0x8D73, 0x8D84, 0x8D94, 0x8D91, 0x8DB1, 0x8D67,
+    0x8D6D, 0x8C47, 0x8C49, 0x914A, 0x9150, 0x914E, 0x9164, 0x9B7C, 0x9162, 0x9161, 0x9170, 0x9169,
+    0x916F, 0x917D, 0x9172, 0x9174, 0x9179, 0x918C, 0x9185, 0x9190, 0x918D, 0x9191, 0x91A2, 0x91AA,
+    0x91AD, 0x91B5, 0x91B4, 0x91BA, 0x8C55, 0x9E7E, 0x8DB8, 0x8DEB, 0x8E05, 0x8E59, 0x8E69, 0x8DB5,
+    0x8DBF, 0x8DBC, 0x8DBA, 0x8DC4, 0x8DD6, 0x8DDA, 0x8DDE, 0x8DCE, 0x8DDB, 0x8DC6, 0x8DEC, 0x8DF7,
+    0x8DE3, 0x8DF9, 0x8DFB, 0x8DE4, 0x8E09, 0x8DFD, 0x8E14, 0x8E1D, 0x8E1F, 0x8E2C, 0x8E2E, 0x8E23,
+    0x8E2F, 0x8E3A, 0x8E40, 0x8E39, 0x8E35, 0x8E3D, 0x8E31, 0x8E49, 0x8E41, 0x8E51, 0x8E4A, 0x8E70,
+    0x8E76, 0x8E7C, 0x8E6F, 0x8E74, 0x8E85, 0x8E8F, 0x8E94, 0x8E90, 0x8E9C, 0x8E9E, 0x8C78, 0x8C82,
+    0x8C8A, 0x8C85, 0x8C98, 0x8C94, 0x659B, 0x89D6, 0x89DE, 0x89DA, 0x89DC, 0x9BDC, 0x89E5, 0x89EB,
+    0x89EF, 0x8A3E, 0x8B26, 0x9753, 0x96E9, 0x96F3, 0x96EF, 0x9706, 0x9701, 0x9708, 0x970F, 0x970E,
+    0x972A, 0x972D, 0x9730, 0x973E, 0x9F80, 0x9F83, 0x9F85, 0x9F8C, 0x9EFE, 0x9F0B, 0x9F0D, 0x96B9,
+    0x96BC, 0x96CE, 0x96D2, 0x77BF, 0x96E0, 0x928E, 0x92AE, 0x92C8, 0x933E, 0x936A, 0x93CA, 0x938F,
+    0x943E, 0x946B, 0x9C7F, 0x9C82, 0x9C85, 0x7A23, 0x9C8B, 0x9C8E, 0x9C90, 0x9C94, 0x9C9A, 0x9C9E,
+    0x9CA5, 0x9CAB, 0x9CAD, 0x9CB0, 0x9CBA, 0x9CC4, 0x9CCA, 0x9C3C, 0x9C7D, 0x9C80, 0x9C83, 0x9C89,
+    0x9C8C, 0x9C8F, 0x9C93, 0x9C96, 0x9C9D, 0x9CAA, 0x9CAC, 0x9CAF, 0x9CB9, 0x9CBE, 0x9CC8, 0x9CD1,
+    0x9CDA, 0x9CE0, 0x9CCC, 0x9CD3, 0x9CD7, 0x9CDC, 0x9CDF, 0x9CE2, 0x977C, 0x9785, 0x9791, 0x9794,
+    0x97AF, 0x97AB, 0x97A3, 0x97B2, 0x97B4, 0x9AB1, 0x9AB0, 0x9AB7, 0x9E58, 0x9AB6, 0x9ABA, 0x9ABC,
+    0x9AC1, 0x9AC0, 0x9AC5, 0x9AC2, 0x9ACB, 0x9AD1, 0x9B45, 0x9B43, 0x9B47, 0x9B49, 0x9B48, 0x9B4D,
+    0x9B51, 0x98E8, 0x990D, 0x992E, 0x9955, 0x9954, 0x9ADF, 0x9AE1, 0x9AE6, 0x9AEF, 0x9AEB, 0x9AFB,
+    0x9AED, 0x9AF9, 0x9B08, 0x9B0F, 0x9B13, 0x9B1F, 0x9B23, 0x9EBD, 0x7E3B, 0x9E82, 0x9E87, 0x9E8B,
+    0x9E92, 0x93D6, 0x9E9D, 0x9E9F, 0x9EDB, 0x9EE0, 0x9EDF, 0x9EE2, 0x9EE9, 0x9EE7, 0x9EE5, 0x9EEA,
+    0x9EEF, 0x9F22, 0x9F2C, 0x9F2F, 0x9F39, 0x9F37, 0x9F3D, 0x9F44, 0x9CE3, 0xE234, 0x9D43, 0xE292,
+    0x9DA3, 0xE2F0, 0x9E03, 0x9E24, 0x9E27, 0x9E2E, 0x9E30, 0x9E34, 0x9E3B, 0x9E40, 0x9E4D, 0x9E50,
+    0x9E52, 0x9E56, 0x9E59, 0x9E5D, 0x9E5F, 0x9E65, 0x9E6E, 0x9E72, 0x9E74, 0x9E80, 0x9E83, 0x9E89,
+    0x9E8C, 0x9E94, 0x9E9E, 0x9EA0, 0x9EA7, 0xE34E, 0x9EAB, 0x9EB5, 0x9EB9, 0x9EBC, 0x9EBF, 0x9EC5,
+    0x9ECA, 0x9ED0, 0x9ED2, 0x9ED5, 0x9ED9, 0x9EDE, 0x9EE1, 0x9EE3, 0x9EE6, 0x9EE8, 0x9EEB, 0x9EF0,
+    0x9EFA, 0x9EFD, 0x9EFF, 0x9F0C, 0x9F0F, 0x9F11, 0x9F14, 0x9F18, 0x9F1A, 0x9F21, 0x9F23, 0x9F2D,
+    0x9F30, 0xE3AC, 0x9F32, 0x9F38, 0x9F3A, 0x9F3C, 0x9F3F, 0x9F45, 0x9F52, 0x9F81, 0x9F8D, 0x9F9C,
+    0x9FA1, 0xF92C, 0xF979, 0xF995, 0xF9E7, 0xF9F1, 0xE40A, 0xFA0C, 0xFA11, 0xFA13, 0xFA18, 0xFA1F,
+    0xFA23, 0xFA27, 0x2E81, 0xE816, 0x2E84, 0x3473, 0x3447, 0x2E88, 0x2E8B, 0xE81E, 0x359E, 0x361A,
+    0x360E, 0x2E8C, 0x2E97, 0x396E, 0x3918, 0xE826, 0x39CF, 0x39DF, 0x3A73, 0x39D0, 0xE82B, 0x3B4E,
+    0x3C6E, 0x3CE0, 0x2EA7, 0xE831, 0x2EAA, 0x4056, 0x415F, 0x2EAE, 0x4337, 0x2EB3, 0x2EB6, 0xE83B,
+    0x43B1, 0x43AC, 0x2EBB, 0x43DD, 0x44D6, 0x4661, 0x464C, 0xE843, 0x4723, 0x4729, 0x477C, 0x478D,
+    0x2ECA, 0x4947, 0x497A, 0x497D, 0x4982, 0x4985, 0x499F, 0x499B, 0x49B7, 0x49B6, 0xE854, 0x4CA3,
+    0x4C9F, 0x4C77, 0x4CA2, 0x4D13, 0x4DAE, 0xE864, 0xE468,
+];
+
+/// linear index of the first four byte code of each BMP run
+const FOUR_BYTE_RUN_START: [u16; 208] = [
+    0x0000, 0x0024, 0x0026, 0x002D, 0x0032, 0x0051, 0x0059, 0x005F, 0x0060, 0x0064, 0x0067, 0x0068,
+    0x0069, 0x006D, 0x007E, 0x0085, 0x0094, 0x00AC, 0x00AF, 0x00B3, 0x00D0, 0x0132, 0x0133, 0x0134,
+    0x0135, 0x0136, 0x0137, 0x0138, 0x0139, 0x0155, 0x01AC, 0x01BB, 0x0220, 0x0221, 0x022E, 0x02E5,
+    0x02E6, 0x02ED, 0x02EE, 0x0325, 0x0333, 0x0334, 0x1D21, 0x1D22, 0x1EF2, 0x1EF4, 0x1EF5, 0x1EF7,
+    0x1EFE, 0x1F07, 0x1F08, 0x1F09, 0x1F0E, 0x1F7E, 0x1FD4, 0x1FD5, 0x1FD8, 0x1FE4, 0x1FEE, 0x202C,
+    0x2030, 0x2046, 0x2048, 0x20B6, 0x20BC, 0x20BD, 0x20C0, 0x20C4, 0x20C6, 0x20C8, 0x20C9, 0x20CA,
+    0x20CC, 0x20D1, 0x20D6, 0x20E0, 0x20E3, 0x20E8, 0x20F5, 0x20F7, 0x20FD, 0x2122, 0x2125, 0x2130,
+    0x2149, 0x219B, 0x22E8, 0x22F2, 0x2356, 0x235A, 0x2367, 0x236A, 0x2374, 0x2384, 0x238C, 0x2394,
+    0x2397, 0x2399, 0x23AB, 0x23CA, 0x23CC, 0x2402, 0x2403, 0x2C41, 0x2C43, 0x2C46, 0x2C48, 0x2C52,
+    0x2C61, 0x2C63, 0x2C66, 0x2C6A, 0x2C6C, 0x2C6F, 0x2C7D, 0x2DA2, 0x2DA6, 0x2DA7, 0x2DAC, 0x2DAE,
+    0x2DC2, 0x2DC4, 0x2DCB, 0x2DCD, 0x2DD2, 0x2DD8, 0x2ECE, 0x2ED5, 0x2F46, 0x3030, 0x303C, 0x303E,
+    0x3060, 0x3069, 0x306B, 0x306D, 0x30DE, 0x3109, 0x3233, 0x32A2, 0x32AD, 0x35AA, 0x35FF, 0x365F,
+    0x366D, 0x3700, 0x37DA, 0x38F9, 0x396A, 0x3CDF, 0x3DE7, 0x3FBE, 0x4032, 0x4036, 0x4061, 0x4159,
+    0x42CE, 0x42E2, 0x43A3, 0x43A8, 0x43FA, 0x440A, 0x45C3, 0x45F5, 0x45F7, 0x45FB, 0x45FC, 0x4610,
+    0x4613, 0x4629, 0x48E8, 0x490F, 0x497E, 0x4A12, 0x4A63, 0x82BD, 0x82BE, 0x82BF, 0x82CC, 0x82CD,
+    0x82D2, 0x82D9, 0x82DD, 0x82E1, 0x82E9, 0x82F0, 0x8300, 0x830E, 0x93D5, 0x9421, 0x943C, 0x948D,
+    0x9496, 0x94B0, 0x94B1, 0x94B2, 0x94B5, 0x94BB, 0x94BC, 0x94BE, 0x98C4, 0x98C5, 0x98C9, 0x98CA,
+    0x98CB, 0x98CC, 0x9961, 0x99E2,
+];
+
+/// codepoint of the first four byte code of each BMP run
+const FOUR_BYTE_RUN_CODE: [u16; 208] = [
+    0x0080, 0x00A5, 0x00A9, 0x00B2, 0x00B8, 0x00D8, 0x00E2, 0x00EB, 0x00EE, 0x00F4, 0x00F8, 0x00FB,
+    0x00FD, 0x0102, 0x0114, 0x011C, 0x012C, 0x0145, 0x0149, 0x014E, 0x016C, 0x01CF, 0x01D1, 0x01D3,
+    0x01D5, 0x01D7, 0x01D9, 0x01DB, 0x01DD, 0x01FA, 0x0252, 0x0262, 0x02C8, 0x02CC, 0x02DA, 0x03A2,
+    0x03AA, 0x03C2, 0x03CA, 0x0402, 0x0450, 0x0452, 0xE7C7, 0x1E40, 0x2011, 0x2017, 0x201A, 0x201E,
+    0x2027, 0x2031, 0x2034, 0x2036, 0x203C, 0x20AD, 0x2104, 0x2106, 0x210A, 0x2117, 0x2122, 0x216C,
+    0x217A, 0x2194, 0x219A, 0x2209, 0x2210, 0x2212, 0x2216, 0x221B, 0x2221, 0x2224, 0x2226, 0x222C,
+    0x222F, 0x2238, 0x223E, 0x2249, 0x224D, 0x2253, 0x2262, 0x2268, 0x2270, 0x2296, 0x229A, 0x22A6,
+    0x22C0, 0x2313, 0x246A, 0x249C, 0x254C, 0x2574, 0x2590, 0x2596, 0x25A2, 0x25B4, 0x25BE, 0x25C8,
+    0x25CC, 0x25D0, 0x25E6, 0x2607, 0x260A, 0x2641, 0x2643, 0x2E82, 0x2E85, 0x2E89, 0x2E8D, 0x2E98,
+    0x2EA8, 0x2EAB, 0x2EAF, 0x2EB4, 0x2EB8, 0x2EBC, 0x2ECB, 0x2FFC, 0x3004, 0x3018, 0x301F, 0x302A,
+    0x303F, 0x3094, 0x309F, 0x30F7, 0x30FF, 0x312A, 0x322A, 0x3232, 0x32A4, 0x3390, 0x339F, 0x33A2,
+    0x33C5, 0x33CF, 0x33D3, 0x33D6, 0x3448, 0x3474, 0x359F, 0x360F, 0x361B, 0x3919, 0x396F, 0x39D1,
+    0x39E0, 0x3A74, 0x3B4F, 0x3C6F, 0x3CE1, 0x4057, 0x4160, 0x4338, 0x43AD, 0x43B2, 0x43DE, 0x44D7,
+    0x464D, 0x4662, 0x4724, 0x472A, 0x477D, 0x478E, 0x4948, 0x497B, 0x497E, 0x4984, 0x4987, 0x499C,
+    0x49A0, 0x49B8, 0x4C78, 0x4CA4, 0x4D1A, 0x4DAF, 0x9FA6, 0xE76C, 0xE7C8, 0xE7E7, 0xE815, 0xE819,
+    0xE81F, 0xE827, 0xE82D, 0xE833, 0xE83C, 0xE844, 0xE856, 0xE865, 0xF92D, 0xF97A, 0xF996, 0xF9E8,
+    0xF9F2, 0xFA10, 0xFA12, 0xFA15, 0xFA19, 0xFA22, 0xFA25, 0xFA2A, 0xFE32, 0xFE45, 0xFE53, 0xFE58,
+    0xFE67, 0xFE6C, 0xFF5F, 0xFFE6,
+];
+
+/// number of two byte codes
+const TWO_BYTE_CODES: u16 = 126 * 190;
+
+/// number of four byte codes below U+10000
+const FOUR_BYTE_BMP_CODES: u32 = 39420;
+
+/// linear index of 90 30 81 30, the four byte code of U+10000
+const FOUR_BYTE_SUPPLEMENTARY_START: u32 = 189000;
+
+/// Map a code to a codepoint with a run table.
+/// # Arguments
+/// * `starts` - the first code of each run, in ascending order
+/// * `codes` - the first codepoint of each run
+/// * `index` - the code to be mapped
+fn run_lookup(starts: &[u16], codes: &[u16], index: u16) -> u32 {
+    let run = starts.partition_point(|v| * v <= index).saturating_sub(1);
+    let start = starts.get(run).copied().unwrap_or(0);
+    let code = codes.get(run).copied().unwrap_or(0);
+    (code as u32) + ((index - start) as u32)
+}
+
+#[inline]
+/// Returns the char of a two byte code, or 'None' if not a two byte
+/// code.
+/// # Arguments
+/// * `lead` - the first byte, from 81 to FE
+/// * `trail` - the second byte, from 40 to 7E or 80 to FE
+pub fn gb18030_two_byte_to_char(lead: u8, trail: u8) -> Option<char> {
+    let trail_pos = match trail {
+        0x40 ..= 0x7E => trail - 0x40,
+        0x80 ..= 0xFE => trail - 0x41,
+        _ => {
+            return Option::None;
+        }
+    };
+    if ! (0x81 ..= 0xFE).contains(& lead) {
+        return Option::None;
+    }
+    let index = ((lead - 0x81) as u16) * 190 + (trail_pos as u16);
+    debug_assert!(index < TWO_BYTE_CODES);
+    char::from_u32(run_lookup(& TWO_BYTE_RUN_START, & TWO_BYTE_RUN_CODE, index))
+}
+
+/// Returns the char of a four byte code from its linear index, or
+/// 'None' if no char is assigned to the code.
+/// # Arguments
+/// * `linear` - linear index of the code from 81 30 81 30
+fn four_byte_linear_to_char(linear: u32) -> Option<char> {
+    if linear < FOUR_BYTE_BMP_CODES {
+        char::from_u32(run_lookup(& FOUR_BYTE_RUN_START, & FOUR_BYTE_RUN_CODE, linear as u16))
+    }
+    else {
+        let offset = linear.checked_sub(FOUR_BYTE_SUPPLEMENTARY_START)?;
+        char::from_u32(0x10000 + offset).filter(|ch| (* ch as u32) >= 0x10000)
+    }
+}
+
+/// Outcome of decoding the sequence at the front of a window
+enum Gb18030Step {
+
+    /// a char and the number of bytes of its sequence
+    Char(char, usize),
+
+    /// an invalid sequence and the number of bytes it takes
+    Invalid(usize),
+
+    /// the window holds the start of a longer sequence
+    Incomplete,
+}
+
+/// Decode the sequence at the front of `window`.
+/// # Arguments
+/// * `window` - up to 4 bytes of input
+fn decode_window(window: &[u8]) -> Gb18030Step {
+    let b0 = match window.first() {
+        Option::Some(v) => * v,
+        Option::None => {
+            return Gb18030Step::Incomplete;
+        }
+    };
+    if b0 < 0x80 {
+        return Gb18030Step::Char(b0 as char, 1);
+    }
+    if (b0 == 0x80) || (b0 == 0xFF) {
+        return Gb18030Step::Invalid(1);
+    }
+    let b1 = match window.get(1) {
+        Option::Some(v) => * v,
+        Option::None => {
+            return Gb18030Step::Incomplete;
+        }
+    };
+    if ! (0x30 ..= 0x39).contains(& b1) {
+        return match gb18030_two_byte_to_char(b0, b1) {
+            Option::Some(ch) => Gb18030Step::Char(ch, 2),
+            // The second byte starts the next sequence.
+            Option::None => Gb18030Step::Invalid(1),
+        };
+    }
+    let b2 = match window.get(2) {
+        Option::Some(v) => * v,
+        Option::None => {
+            return Gb18030Step::Incomplete;
+        }
+    };
+    if ! (0x81 ..= 0xFE).contains(& b2) {
+        return Gb18030Step::Invalid(1);
+    }
+    let b3 = match window.get(3) {
+        Option::Some(v) => * v,
+        Option::None => {
+            return Gb18030Step::Incomplete;
+        }
+    };
+    if ! (0x30 ..= 0x39).contains(& b3) {
+        return Gb18030Step::Invalid(1);
+    }
+    let linear = ((((b0 - 0x81) as u32) * 10 + ((b1 - 0x30) as u32)) * 126
+        + ((b2 - 0x81) as u32)) * 10 + ((b3 - 0x30) as u32);
+    match four_byte_linear_to_char(linear) {
+        Option::Some(ch) => Gb18030Step::Char(ch, 4),
+        Option::None => Gb18030Step::Invalid(4),
+    }
+}
+
+/// Provides conversion functions from GB18030 to char, with the same
+/// streaming interface as FromUtf8.
+///
+/// A sequence split between buffers is kept by the parser until the
+/// next buffer.  Invalid sequences, and an incomplete sequence at the
+/// end of the last buffer, are indicated by Unicode replacement
+/// characters; an invalid lead byte, or a lead byte followed by a byte
+/// that cannot continue it, takes a single replacement character, and
+/// the following byte is decoded again.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut parser = FromGb18030::new();
+/// let mut char_box = ['\0'; 8];
+/// let bytes = [0xD6u8, 0xD0u8, 0xCEu8, 0xC4u8, 0x41u8, 0x94u8, 0x39u8, 0xFCu8, 0x36u8];
+/// let (_rest, num_chars) = parser.gb18030_to_char_slice(& bytes, & mut char_box).unwrap();
+/// assert_eq!(['\u{4E2D}', '\u{6587}', 'A', '\u{1F600}'], char_box[.. num_chars]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FromGb18030 {
+    my_pending: [u8; 4],
+    my_pending_len: u8,
+    my_last_buffer: bool,
+    my_invalid_sequence: bool,
+}
+
+/// Implementation of FromGb18030
+impl FromGb18030 {
+
+    /// Make a new FromGb18030
+    pub fn new() -> FromGb18030 {
+        FromGb18030 {
+            my_pending: [0; 4],
+            my_pending_len: 0,
+            my_last_buffer: true,
+            my_invalid_sequence: false,
+        }
+    }
+
+    #[inline]
+    /// Returns the MoreEnum to report when no char can be produced.
+    fn more_indication(&self) -> MoreEnum {
+        if self.my_last_buffer {
+            // at end of data condition
+            MoreEnum::More(0)
+        }
+        else {
+            // Returning an indication to request a new buffer.
+            MoreEnum::More(4096)
+        }
+    }
+
+    /// A parser takes in byte slice, and returns a Result object with
+    /// either the remaining input and the output char value, or an MoreEnum
+    /// that requests additional data, or an end of data stream condition.
+    ///
+    /// Invalid GB18030 sequences are indicated by Unicode replacement
+    /// characters.  has_invalid_sequence() would return true after this
+    /// event.
+    pub fn gb18030_to_char<'b>(&mut self, input: &'b [u8])
+    -> Result<(&'b [u8], char), MoreEnum> {
+        // The window is the partial sequence kept from the previous
+        // buffer, followed by the front of the input.
+        let pending_len = self.my_pending_len as usize;
+        let mut window = self.my_pending;
+        let take = input.len().min(4 - pending_len);
+        for (slot, b) in window.iter_mut().skip(pending_len).zip(input.iter().take(take)) {
+            * slot = * b;
+        }
+        let avail = pending_len + take;
+        let (ch, len) = match decode_window(window.get(.. avail).unwrap_or(&[])) {
+            Gb18030Step::Char(ch, len) => (ch, len),
+            Gb18030Step::Invalid(len) => {
+                self.signal_invalid_sequence();
+                (char::REPLACEMENT_CHARACTER, len)
+            }
+            Gb18030Step::Incomplete if (avail == 0) || ! self.my_last_buffer => {
+                // Keep the partial sequence; the whole input fits
+                // in the window here.
+                self.my_pending = window;
+                self.my_pending_len = avail as u8;
+                return Result::Err(self.more_indication());
+            }
+            Gb18030Step::Incomplete => {
+                // truncated sequence at end of data
+                self.signal_invalid_sequence();
+                (char::REPLACEMENT_CHARACTER, avail)
+            }
+        };
+        if len < pending_len {
+            // The rest of the kept bytes are decoded again.
+            self.my_pending.copy_within(len .. pending_len, 0);
+            self.my_pending_len = (pending_len - len) as u8;
+            Result::Ok((input, ch))
+        }
+        else {
+            self.my_pending_len = 0;
+            Result::Ok((input.get(len - pending_len ..).unwrap_or(&[]), ch))
+        }
+    }
+
+    /// A bulk parser takes in byte slice, and fills the `output` slice
+    /// with decoded char values.  Returns a Result object with either
+    /// the remaining input and the number of chars written, or an MoreEnum
+    /// that requests additional data, or an end of data stream condition.
+    ///
+    /// The MoreEnum conditions are the same as gb18030_to_char(); they
+    /// are only reported when not a single char could be written.
+    pub fn gb18030_to_char_slice<'b>(&mut self, input: &'b [u8], output: &mut [char])
+    -> Result<(&'b [u8], usize), MoreEnum> {
+        let mut my_cursor = input;
+        let mut written: usize = 0;
+        for slot in output.iter_mut() {
+            match self.gb18030_to_char(my_cursor) {
+                Result::Ok((slice_pos, ch)) => {
+                    * slot = ch;
+                    written += 1;
+                    my_cursor = slice_pos;
+                }
+                Result::Err(en) => {
+                    if written == 0 {
+                        return Result::Err(en);
+                    }
+                    // A partial sequence at the end of the input is
+                    // now kept by the parser.
+                    my_cursor = my_cursor.get(my_cursor.len() ..).unwrap_or(&[]);
+                    break;
+                }
+            }
+        }
+        Result::Ok((my_cursor, written))
+    }
+}
+
+/// Default for FromGb18030 is the same as FromGb18030::new()
+impl Default for FromGb18030 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implementations of common operations for FromGb18030
+impl UtfParserCommon for FromGb18030 {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_last_buffer = b;
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_last_buffer
+    }
+
+    #[inline]
+    /// This function returns true if invalid GB18030 sequence occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_invalid_sequence
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_invalid_sequence = true;
+    }
+
+    #[inline]
+    /// This function resets the invalid sequence state.
+    fn reset_invalid_sequence(&mut self) {
+        self.my_invalid_sequence = false;
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid sequence indication is cleared.
+    /// A kept partial sequence is dropped.
+    fn reset_parser(&mut self) {
+        * self = FromGb18030::new();
+    }
+}
+
+/// Decoder implementation of FromGb18030
+impl Decoder for FromGb18030 {
+    type Unit = u8;
+
+    #[inline]
+    /// Same as gb18030_to_char()
+    fn decode_char<'b>(&mut self, input: &'b [u8])
+    -> Result<(&'b [u8], char), MoreEnum> {
+        self.gb18030_to_char(input)
+    }
+
+    #[inline]
+    /// Same as gb18030_to_char_slice()
+    fn decode_to_slice<'b>(&mut self, input: &'b [u8], output: &mut [char])
+    -> Result<(&'b [u8], usize), MoreEnum> {
+        self.gb18030_to_char_slice(input, output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::vec;
+    use std::vec::Vec;
+
+    /// Decode a complete input split into two buffers at `split`.
+    fn decode_split(bytes: &[u8], split: usize) -> (Vec<char>, bool) {
+        let mut parser = FromGb18030::new();
+        let decoder: & mut dyn Decoder<Unit = u8> = & mut parser;
+        let mut chars = Vec::new();
+        let (head, tail) = bytes.split_at(split);
+        for (indx, buf) in [head, tail].iter().enumerate() {
+            decoder.set_is_last_buffer(indx == 1);
+            let mut cur_slice = * buf;
+            let mut char_box = ['\0'; 3];
+            // The loop stops at MoreEnum::More(_amt);
+            // _amt equals to 0 when end of data
+            while let Result::Ok((slice_pos, num_chars)) = decoder.decode_to_slice(cur_slice, & mut char_box) {
+                cur_slice = slice_pos;
+                chars.extend_from_slice(& char_box[.. num_chars]);
+            }
+        }
+        (chars, decoder.has_invalid_sequence())
+    }
+
+    #[test]
+    // Test that the tables map every code to a distinct char, covering
+    // the Basic Multilingual Plane
+    fn test_gb18030_tables() {
+        let mut seen = vec![false; 0x10000];
+        for b in 0 .. 0x80u8 {
+            seen[b as usize] = true;
+        }
+        let mut mark = |ch: char| {
+            assert!((ch as u32) < 0x10000 && ! seen[ch as usize], "{:?}", ch);
+            seen[ch as usize] = true;
+        };
+        for lead in 0x81 ..= 0xFEu8 {
+            for trail in (0x40 ..= 0x7Eu8).chain(0x80 ..= 0xFEu8) {
+                mark(gb18030_two_byte_to_char(lead, trail).unwrap());
+            }
+        }
+        let mut parser = FromGb18030::new();
+        for linear in 0 .. 39420u32 {
+            let bytes = [
+                0x81 + (linear / 12600) as u8,
+                0x30 + (linear / 1260 % 10) as u8,
+                0x81 + (linear / 10 % 126) as u8,
+                0x30 + (linear % 10) as u8,
+            ];
+            let (rest, ch) = parser.gb18030_to_char(& bytes).unwrap();
+            assert!(rest.is_empty());
+            mark(ch);
+        }
+        assert!(! parser.has_invalid_sequence());
+        let unseen: Vec<usize> = (0 .. 0x10000).filter(|v| ! seen[* v]).collect();
+        assert_eq!((0xD800 .. 0xE000).collect::<Vec<usize>>(), unseen);
+    }
+
+    #[test]
+    // Test known codes, invalid sequences, and decoding over buffer splits
+    fn test_gb18030_decoder() {
+        let cases: [(& [u8], & [char], bool); 13] = [
+            (b"\xB0\xA1\xA1\xA1\xA3\xA0\xFE\xFE", & ['\u{554A}', '\u{3000}', '\u{E5E5}', '\u{E4C5}'], false),
+            (b"\x81\x30\x81\x30\x81\x39\xEF\x30\x84\x31\xA4\x39", & ['\u{80}', '\u{3401}', '\u{FFFF}'], false),
+            (b"\xA8\xBC\x81\x35\xF4\x37", & ['\u{1E3F}', '\u{E7C7}'], false),
+            (b"\xA2\xE3\xA8\xA6\x90\x30\x81\x30\xE3\x32\x9A\x35", & ['\u{20AC}', '\u{E9}', '\u{10000}', '\u{10FFFF}'], false),
+            (b"\x95\x32\x82\x36x", & ['\u{20000}', 'x'], false),
+            (b"\x80\xFFa", & ['\u{FFFD}', '\u{FFFD}', 'a'], true),
+            (b"\x81\x20\x81\x7F", & ['\u{FFFD}', ' ', '\u{FFFD}', '\u{7F}'], true),
+            (b"\x81\x30\x20", & ['\u{FFFD}', '0', ' '], true),
+            (b"\x81\x30\x81\x41", & ['\u{FFFD}', '0', '\u{4E04}'], true),
+            (b"\x84\x31\xA5\x30\xE3\x32\x9A\x36\xFE\x39\xFE\x39", & ['\u{FFFD}', '\u{FFFD}', '\u{FFFD}'], true),
+            (b"ab\x81\x30\x81", & ['a', 'b', '\u{FFFD}'], true),
+            (b"\xB0", & ['\u{FFFD}'], true),
+            (b"", & [], false),
+        ];
+        for (bytes, truth, invalid) in cases.iter().copied() {
+            for split in 0 ..= bytes.len() {
+                let (chars, found_invalid) = decode_split(bytes, split);
+                assert_eq!(truth, & chars[..], "bytes {:02X?} split {}", bytes, split);
+                assert_eq!(invalid, found_invalid);
+            }
+        }
+        let mut parser = FromGb18030::new();
+        parser.set_is_last_buffer(false);
+        assert_eq!(Result::Err(MoreEnum::More(4096)), parser.gb18030_to_char(b"\x81\x30"));
+        assert_eq!(Result::Ok((& b""[..], '\u{80}')), parser.gb18030_to_char(b"\x81\x30"));
+        assert_eq!(Result::Err(MoreEnum::More(4096)), parser.gb18030_to_char(b"\xE3\x32\x9A"));
+        assert_eq!(Option::Some('\u{FFFD}'), parser.finish());
+        assert_eq!(Option::None, parser.finish());
+        parser.reset_parser();
+        assert_eq!(FromGb18030::default(), parser);
+    }
+}
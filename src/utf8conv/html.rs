@@ -0,0 +1,201 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::html
+
+/// codepoint value used once a reference is known to be out of range
+const OUT_OF_RANGE: u32 = 0x110000;
+
+/// char to char iterator struct decoding HTML numeric character references
+pub struct HtmlCharRefStruct<'b, I: ?Sized = dyn Iterator<Item = char> + 'b> {
+
+    /// true after replacing an out of range reference
+    my_invalid_sequence: bool,
+
+    /// chars of an unrecognized reference, passed through as they are
+    my_literal: [char; 2],
+
+    /// number of chars left in my_literal, taken from the back
+    my_literal_len: usize,
+
+    /// a char read past the end of a reference, not yet processed
+    my_peeked: Option<char>,
+
+    /// the source iterator
+    my_borrow_mut_iter: &'b mut I,
+}
+
+/// Implementation of HtmlCharRefStruct
+impl<'b, I: ?Sized> HtmlCharRefStruct<'b, I> {
+
+    #[inline]
+    /// Returns true if an out of range reference was replaced.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_invalid_sequence
+    }
+}
+
+/// Implementation of HtmlCharRefStruct
+impl<'b, I> HtmlCharRefStruct<'b, I>
+where I: Iterator<Item = char> + ?Sized, {
+
+    #[inline]
+    /// Returns the next char of the source, after the peeked char.
+    fn next_input(&mut self) -> Option<char> {
+        match self.my_peeked.take() {
+            Option::Some(ch) => Option::Some(ch),
+            Option::None => self.my_borrow_mut_iter.next(),
+        }
+    }
+
+    /// Decode the rest of a reference after "&#", returning 'None' when
+    /// no digits follow.
+    fn decode_reference(&mut self) -> Option<char> {
+        let mut cur = self.next_input();
+        let hex_mark = cur.filter(|v| * v == 'x' || * v == 'X');
+        let radix:u32 = if hex_mark.is_some() { 16 } else { 10 };
+        if hex_mark.is_some() {
+            cur = self.next_input();
+        }
+        let mut code:u32 = 0;
+        let mut num_digits:usize = 0;
+        while let Option::Some(digit) = cur.and_then(|v| v.to_digit(radix)) {
+            // Saturate, so any number of digits can be consumed.
+            code = (code * radix + digit).min(OUT_OF_RANGE);
+            num_digits += 1;
+            cur = self.next_input();
+        }
+        if num_digits == 0 {
+            // Pass "&#" or "&#x" through; '&' is returned by the caller.
+            self.my_peeked = cur;
+            self.my_literal_len = 0;
+            if let Option::Some(mark) = hex_mark {
+                self.my_literal[0] = mark;
+                self.my_literal_len = 1;
+            }
+            self.my_literal[self.my_literal_len] = '#';
+            self.my_literal_len += 1;
+            return Option::None;
+        }
+        if cur != Option::Some(';') {
+            // The semicolon may be left out, as in HTML.
+            self.my_peeked = cur;
+        }
+        match char::from_u32(code) {
+            Option::Some(ch) if code != 0 => Option::Some(ch),
+            _ => {
+                self.my_invalid_sequence = true;
+                Option::Some(char::REPLACEMENT_CHARACTER)
+            }
+        }
+    }
+}
+
+/// an adapter iterator to decode HTML numeric character references
+impl<'b, I> Iterator for HtmlCharRefStruct<'b, I>
+where I: Iterator<Item = char> + ?Sized, {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.my_literal_len > 0 {
+            self.my_literal_len -= 1;
+            return Option::Some(self.my_literal[self.my_literal_len]);
+        }
+        let ch = self.next_input()?;
+        if ch != '&' {
+            return Option::Some(ch);
+        }
+        match self.next_input() {
+            Option::Some('#') => {
+                Option::Some(self.decode_reference().unwrap_or('&'))
+            }
+            other => {
+                self.my_peeked = other;
+                Option::Some('&')
+            }
+        }
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.my_borrow_mut_iter.size_hint();
+        let more = self.my_peeked.is_some() || (lower > 0);
+        let held = self.my_literal_len + (self.my_peeked.is_some() as usize);
+        // A reference of any length can decode to a single char.
+        (self.my_literal_len + (more as usize), upper.and_then(|v| v.checked_add(held)))
+    }
+}
+
+/// Function html_char_ref_iter() takes a mutable reference to a char
+/// iterator, and returns an iterator in its place that replaces HTML
+/// numeric character references such as "&#123;" and "&#x1F600;" with
+/// the referenced chars.
+///
+/// As in HTML, the closing semicolon may be left out.  References to
+/// zero, surrogates or values above U+10FFFF become replacement
+/// characters.  Named references such as "&amp;", and "&#" without
+/// digits, are passed through unchanged.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut chars = "caf&#233; &#x1F600; &#1114112; &amp;".chars();
+/// let mut iter = html_char_ref_iter(& mut chars);
+/// let text: String = iter.by_ref().collect();
+/// assert_eq!("caf\u{E9} \u{1F600} \u{FFFD} &amp;", text);
+/// assert!(iter.has_invalid_sequence());
+/// ```
+///
+/// # Arguments
+///
+/// * `input` - a mutable reference to a char iterator
+#[inline]
+pub fn html_char_ref_iter<'a, I>(input: &'a mut I) -> HtmlCharRefStruct<'a, I>
+where I: Iterator<Item = char> + ?Sized, {
+    HtmlCharRefStruct {
+        my_invalid_sequence: false,
+        my_literal: ['\0'; 2],
+        my_literal_len: 0,
+        my_peeked: Option::None,
+        my_borrow_mut_iter: input,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::string::String;
+
+    use crate::*;
+
+    #[test]
+    // Test references of every form, and text that only looks like one
+    fn test_html_char_ref_iter() {
+        let cases = [
+            ("&#65;&#x42;&#X43;", "ABC", false),
+            ("&#65&#66;x", "ABx", false),
+            ("&#x20ac &#0000000000000097;", "\u{20AC} a", false),
+            ("&#0;&#xD800;&#x110000;&#99999999999999;", "\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}", true),
+            ("&#; &#x; &#Xg &# &", "&#; &#x; &#Xg &# &", false),
+            ("&&#&#x&#35;", "&&#&#x#", false),
+            ("a&#x", "a&#x", false),
+            ("&lt;&#60;", "&lt;<", false),
+        ];
+        for (input, truth, invalid) in cases {
+            let mut chars = input.chars();
+            let mut iter = html_char_ref_iter(& mut chars);
+            assert_eq!(truth, iter.by_ref().collect::<String>(), "input {:?}", input);
+            assert_eq!(invalid, iter.has_invalid_sequence());
+        }
+        let bytes = b"&#x4E2D;\xE6\x96\x87\xFF";
+        let mut chars = decode_lossy(bytes);
+        let text: String = html_char_ref_iter(& mut chars).collect();
+        assert_eq!("\u{4E2D}\u{6587}\u{FFFD}", text);
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::index
+
+use crate::utf8conv::machine::MachineStatus;
+use crate::utf8conv::machine::Utf8ByteMachine;
+use crate::utf8conv::utf8_len;
+
+/// Builds an index of the byte offset of every Nth char of an UTF8
+/// stream, while the stream is presented one buffer at a time.
+///
+/// Entry k of the offsets buffer is the byte offset of char
+/// `k * stride`, where each invalid sequence counts as one char, the
+/// same as the decoders.  When the offsets buffer is full, chars are
+/// still counted, but no more entries are recorded.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut offsets = [0u64; 4];
+/// let mut builder = CharIndexBuilder::new(2, & mut offsets);
+/// builder.push_bytes("a\u{20AC}".as_bytes());
+/// builder.push_bytes("b\u{10348}c".as_bytes());
+/// assert_eq!((3, 5), builder.finish());
+/// assert_eq!([0, 4, 9, 0], offsets);
+/// // Char 3 is one char after the entry for char 2.
+/// assert_eq!(Some((4, 1)), char_index_seek(& offsets[0 .. 3], 2, 3));
+/// ```
+pub struct CharIndexBuilder<'o> {
+
+    /// decoder of the stream
+    my_machine: Utf8ByteMachine,
+
+    /// output offsets
+    my_offsets: &'o mut [u64],
+
+    /// number of chars between entries
+    my_stride: u64,
+
+    /// number of entries written
+    my_len: usize,
+
+    /// number of chars decoded
+    my_char_count: u64,
+
+    /// byte offset of the next char
+    my_byte_pos: u64,
+
+    /// true when an entry did not fit
+    my_full: bool,
+}
+
+/// Implementation of CharIndexBuilder
+impl<'o> CharIndexBuilder<'o> {
+
+    /// Make a new CharIndexBuilder writing into `offsets`.
+    /// # Arguments
+    /// * `stride` - number of chars between entries; 0 is taken as 1
+    /// * `offsets` - output buffer of byte offsets
+    pub fn new(stride: usize, offsets: &'o mut [u64]) -> CharIndexBuilder<'o> {
+        CharIndexBuilder {
+            my_machine: Utf8ByteMachine::new(),
+            my_offsets: offsets,
+            my_stride: (stride as u64).max(1),
+            my_len: 0,
+            my_char_count: 0,
+            my_byte_pos: 0,
+            my_full: false,
+        }
+    }
+
+    /// Count one decoded char, and record its offset on a stride.
+    fn record(&mut self, status: MachineStatus) {
+        let char_len = match status {
+            MachineStatus::Char(code) => utf8_len(code) as u64,
+            MachineStatus::Error(_kind, len) => len as u64,
+            MachineStatus::NeedMore => {
+                return;
+            }
+        };
+        if self.my_char_count % self.my_stride == 0 {
+            match self.my_offsets.get_mut(self.my_len) {
+                Option::Some(slot) => {
+                    * slot = self.my_byte_pos;
+                    self.my_len += 1;
+                }
+                Option::None => {
+                    self.my_full = true;
+                }
+            }
+        }
+        self.my_char_count += 1;
+        self.my_byte_pos += char_len;
+    }
+
+    /// Decode the next buffer of the stream.
+    /// # Arguments
+    /// * `chunk` - the next bytes of the stream
+    pub fn push_bytes(&mut self, chunk: &[u8]) {
+        for b in chunk {
+            let mut status = self.my_machine.push(* b);
+            while status != MachineStatus::NeedMore {
+                self.record(status);
+                status = self.my_machine.poll();
+            }
+        }
+    }
+
+    /// Signal end of data, counting an incomplete sequence as one char.
+    /// Returns the number of entries written and the number of chars.
+    pub fn finish(&mut self) -> (usize, u64) {
+        loop {
+            let status = self.my_machine.finish();
+            if status == MachineStatus::NeedMore {
+                break;
+            }
+            self.record(status);
+        }
+        (self.my_len, self.my_char_count)
+    }
+
+    #[inline]
+    /// Number of entries written
+    pub fn len(&self) -> usize {
+        self.my_len
+    }
+
+    #[inline]
+    /// Returns true when no entry has been written
+    pub fn is_empty(&self) -> bool {
+        self.my_len == 0
+    }
+
+    #[inline]
+    /// Returns true when an entry did not fit in the offsets buffer.
+    pub fn is_full(&self) -> bool {
+        self.my_full
+    }
+
+    #[inline]
+    /// Number of bytes decoded into chars so far
+    pub fn byte_len(&self) -> u64 {
+        self.my_byte_pos
+    }
+}
+
+/// Find where to start decoding to reach char `char_pos`, using an
+/// index from CharIndexBuilder.  Returns the byte offset of the nearest
+/// indexed char at or before it, and the number of chars to skip from
+/// there, or 'None' when the index is empty.
+/// # Arguments
+/// * `offsets` - the entries written by CharIndexBuilder
+/// * `stride` - the stride the index was built with
+/// * `char_pos` - the char to seek to
+pub fn char_index_seek(offsets: &[u64], stride: usize, char_pos: u64) -> Option<(u64, u64)> {
+    let stride = (stride as u64).max(1);
+    let last = offsets.len().checked_sub(1)? as u64;
+    let entry = (char_pos / stride).min(last);
+    let offset = * offsets.get(entry as usize)?;
+    Option::Some((offset, char_pos - entry * stride))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::vec::Vec;
+
+    #[test]
+    // Test seeks to every char through indexes of several strides
+    fn test_char_index_seek() {
+        let bytes = [
+            0x41u8, 0xE2u8, 0x82u8, 0xACu8, 0xE2u8, 0x41u8, 0xF0u8, 0x90u8,
+            0x8Du8, 0x88u8, 0xEFu8, 0xBFu8, 0xBDu8, 0xDFu8, 0xBFu8, 0xF4u8, 0x8Fu8,
+        ];
+        let truth: Vec<char> = decode_lossy(& bytes).collect();
+        for stride in 0 .. 5 {
+            let mut offsets = [0u64; 16];
+            let mut builder = CharIndexBuilder::new(stride, & mut offsets);
+            // Present the stream in uneven buffers.
+            for chunk in bytes.chunks(3) {
+                builder.push_bytes(chunk);
+            }
+            let (num_entries, num_chars) = builder.finish();
+            assert_eq!(truth.len() as u64, num_chars);
+            assert_eq!(bytes.len() as u64, builder.byte_len());
+            assert!(! builder.is_full());
+            for (char_pos, ch) in truth.iter().enumerate() {
+                let (offset, skip) = char_index_seek(& offsets[0 .. num_entries], stride, char_pos as u64).unwrap();
+                let mut cursor = Utf8Cursor::new(& bytes[offset as usize ..]);
+                assert_eq!(Option::Some(* ch), cursor.nth(skip as usize));
+            }
+        }
+    }
+
+    #[test]
+    // Test an offsets buffer that is too small
+    fn test_char_index_full() {
+        let mut offsets = [u64::MAX; 2];
+        let mut builder = CharIndexBuilder::new(1, & mut offsets);
+        assert!(builder.is_empty());
+        builder.push_bytes(b"abc");
+        assert!(builder.is_full());
+        assert_eq!((2, 3), builder.finish());
+        assert_eq!([0, 1], offsets);
+        assert_eq!(Option::Some((1, 8)), char_index_seek(& offsets, 1, 9));
+        assert_eq!(Option::None, char_index_seek(& [], 1, 0));
+    }
+}
@@ -0,0 +1,1484 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::io
+
+use std::io;
+use std::io::IoSlice;
+use std::io::IoSliceMut;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+
+use crate::utf8conv::bom::SwapDetector;
+use crate::utf8conv::bom::SWAP_HOLD_UNITS;
+use crate::utf8conv::encode;
+use crate::utf8conv::recode::Recode;
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::utf16::FromUtf16;
+use crate::utf8conv::MoreEnum;
+use crate::utf8conv::UtfParserCommon;
+
+/// size of the input buffer of the readers
+const READ_BUF_SIZE: usize = 4096;
+
+/// size of the output buffer of the writers
+const WRITE_BUF_SIZE: usize = 1024;
+
+/// most UTF8 bytes written for one code unit, including held units
+const MAX_UNIT_OUTPUT: usize = 6 * (SWAP_HOLD_UNITS + 1);
+
+/// An io::Read wrapper that decodes UTF8 from an inner reader, and
+/// reads out the text as UTF16 little endian bytes.
+///
+/// Invalid UTF8 sequences become replacement characters.  No byte order
+/// mark is added.
+///
+/// ```rust
+/// use std::io::Read;
+/// use utf8conv::*;
+///
+/// let mut reader = Utf16LeReader::new(& b"a\xE2\x82\xAC\xFF"[..]);
+/// let mut wide = Vec::new();
+/// reader.read_to_end(& mut wide).unwrap();
+/// assert_eq!(vec![0x61u8, 0x00u8, 0xACu8, 0x20u8, 0xFDu8, 0xFFu8], wide);
+/// assert!(reader.has_invalid_sequence());
+/// ```
+pub struct Utf16LeReader<R> {
+
+    /// the inner reader
+    my_inner: R,
+
+    /// the UTF8 parser
+    my_parser: FromUtf8,
+
+    /// bytes read from the inner reader
+    my_in: [u8; READ_BUF_SIZE],
+
+    /// start of the unparsed bytes in my_in
+    my_in_start: usize,
+
+    /// end of the unparsed bytes in my_in
+    my_in_end: usize,
+
+    /// encoded bytes of the current char
+    my_out: [u8; 4],
+
+    /// position of the next byte in my_out
+    my_out_pos: usize,
+
+    /// number of bytes in my_out
+    my_out_len: usize,
+}
+
+/// Implementation of Utf16LeReader
+impl<R: Read> Utf16LeReader<R> {
+
+    /// Make a new Utf16LeReader reading UTF8 from `inner`.
+    pub fn new(inner: R) -> Utf16LeReader<R> {
+        let mut parser = FromUtf8::new();
+        parser.set_is_last_buffer(false);
+        Utf16LeReader {
+            my_inner: inner,
+            my_parser: parser,
+            my_in: [0; READ_BUF_SIZE],
+            my_in_start: 0,
+            my_in_end: 0,
+            my_out: [0; 4],
+            my_out_pos: 0,
+            my_out_len: 0,
+        }
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this stream.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_parser.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// Get a reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        & self.my_inner
+    }
+
+    /// Unwrap this Utf16LeReader, returning the inner reader.
+    /// Bytes buffered but not yet read out are lost.
+    pub fn into_inner(self) -> R {
+        self.my_inner
+    }
+
+    /// Read UTF16 little endian bytes into `buf`, and return the number
+    /// of bytes written.  The inner reader is only read when nothing
+    /// was written and `may_block` is true.
+    /// # Arguments
+    /// * `buf` - the output buffer
+    /// * `may_block` - true to allow reading the inner reader
+    fn read_some(&mut self, buf: &mut [u8], may_block: bool) -> io::Result<usize> {
+        let mut written:usize = 0;
+        loop {
+            // Read out the pending bytes of the current char.
+            while self.my_out_pos < self.my_out_len {
+                match buf.get_mut(written) {
+                    Option::Some(slot) => {
+                        * slot = self.my_out[self.my_out_pos];
+                        self.my_out_pos += 1;
+                        written += 1;
+                    }
+                    Option::None => {
+                        return Result::Ok(written);
+                    }
+                }
+            }
+            if written == buf.len() {
+                return Result::Ok(written);
+            }
+            let input = self.my_in.get(self.my_in_start .. self.my_in_end).unwrap_or(&[]);
+            match self.my_parser.utf8_to_char(input) {
+                Result::Ok((rest, ch)) => {
+                    self.my_in_start = self.my_in_end - rest.len();
+                    let mut units = [0u16; 2];
+                    let mut len:usize = 0;
+                    for unit in ch.encode_utf16(& mut units).iter() {
+                        let bytes = unit.to_le_bytes();
+                        self.my_out[len] = bytes[0];
+                        self.my_out[len + 1] = bytes[1];
+                        len += 2;
+                    }
+                    self.my_out_pos = 0;
+                    self.my_out_len = len;
+                }
+                Result::Err(MoreEnum::More(0)) => {
+                    // end of data
+                    return Result::Ok(written);
+                }
+                Result::Err(MoreEnum::More(_amt)) => {
+                    self.my_in_start = 0;
+                    self.my_in_end = 0;
+                    // Do not wait on the inner reader with output at hand.
+                    if (written > 0) || ! may_block {
+                        return Result::Ok(written);
+                    }
+                    let len = self.my_inner.read(& mut self.my_in)?;
+                    self.my_in_end = len;
+                    if len == 0 {
+                        self.my_parser.set_is_last_buffer(true);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// io::Read producing UTF16 little endian bytes
+impl<R: Read> Read for Utf16LeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.read_some(buf, true)
+    }
+
+    /// Fill the buffers in order; a char may be split across buffers.
+    /// The inner reader is only read while nothing has been written.
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        let mut written:usize = 0;
+        for buf in bufs.iter_mut() {
+            let len = self.read_some(buf, written == 0)?;
+            written += len;
+            if len < buf.len() {
+                break;
+            }
+        }
+        Result::Ok(written)
+    }
+}
+
+/// An io::Write wrapper that takes UTF16 little endian bytes, and
+/// writes the text as UTF8 to an inner writer.
+///
+/// Writes may split a code unit or a surrogate pair anywhere; the
+/// incomplete part is kept until the next write.  Unpaired surrogates
+/// become replacement characters.  Call finish() at the end of the
+/// stream to write out an incomplete code unit or surrogate as a
+/// replacement character.
+///
+/// ```rust
+/// use std::io::Write;
+/// use utf8conv::*;
+///
+/// let mut writer = Utf16LeWriter::new(Vec::new());
+/// writer.write_all(& [0x61u8, 0x00u8, 0x3Du8]).unwrap();
+/// writer.write_all(& [0xD8u8, 0x1Du8, 0xDFu8, 0x00u8, 0xDCu8]).unwrap();
+/// writer.finish().unwrap();
+/// assert_eq!("a\u{1F71D}\u{FFFD}".as_bytes(), & writer.get_ref()[..]);
+/// assert!(writer.has_invalid_sequence());
+/// ```
+///
+/// Streams written with the wrong byte order, such as big endian files
+/// without a byte order mark, can be rescued with the byte swap repair
+/// mode of set_swap_repair().
+pub struct Utf16LeWriter<W: Write> {
+
+    /// the inner writer
+    my_inner: W,
+
+    /// low byte of an incomplete code unit
+    my_low_byte: Option<u8>,
+
+    /// the UTF16 parser, holding a high surrogate waiting for its low
+    /// surrogate
+    my_utf16: FromUtf16,
+
+    /// true after an invalid code unit sequence
+    my_invalid_sequence: bool,
+
+    /// true when the byte order is to be detected
+    my_swap_repair: bool,
+
+    /// the byte order detection of the repair mode
+    my_swap: SwapDetector,
+}
+
+/// Implementation of Utf16LeWriter
+impl<W: Write> Utf16LeWriter<W> {
+
+    /// Make a new Utf16LeWriter writing UTF8 into `inner`.
+    pub fn new(inner: W) -> Utf16LeWriter<W> {
+        let mut utf16 = FromUtf16::new();
+        utf16.set_is_last_buffer(false);
+        Utf16LeWriter {
+            my_inner: inner,
+            my_low_byte: Option::None,
+            my_utf16: utf16,
+            my_invalid_sequence: false,
+            my_swap_repair: false,
+            my_swap: SwapDetector::default(),
+        }
+    }
+
+    /// Turn the byte swap repair mode on or off, before the first write.
+    ///
+    /// In this mode the byte order is detected from the start of the
+    /// stream, and big endian code units are swapped on the fly.  A
+    /// byte order mark decides the order.  Without one, up to 8 code
+    /// units are held, and vote: an ASCII char in the 0x00XX pattern for
+    /// little endian, and one in the 0xXX00 pattern for swapped bytes.
+    /// The majority decides, and a tie is taken as little endian.
+    ///
+    /// ```rust
+    /// use std::io::Write;
+    /// use utf8conv::*;
+    ///
+    /// let mut writer = Utf16LeWriter::new(Vec::new());
+    /// writer.set_swap_repair(true);
+    /// writer.write_all(& [0x4Eu8, 0x2Du8, 0x00u8, 0x41u8]).unwrap();
+    /// writer.finish().unwrap();
+    /// assert_eq!("\u{4E2D}A".as_bytes(), & writer.get_ref()[..]);
+    /// assert!(writer.is_swapped());
+    /// ```
+    /// # Arguments
+    /// * `b` - true to detect the byte order
+    pub fn set_swap_repair(&mut self, b: bool) {
+        self.my_swap_repair = b;
+    }
+
+    #[inline]
+    /// Returns true if the byte swap repair mode found the code units
+    /// to be byte swapped.
+    pub fn is_swapped(&self) -> bool {
+        self.my_swap.is_swapped()
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF16 sequence occurred
+    /// in this stream.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_invalid_sequence || self.my_utf16.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// Get a reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        & self.my_inner
+    }
+
+    /// Unwrap this Utf16LeWriter, returning the inner writer.
+    /// An incomplete code unit or surrogate is dropped; call finish()
+    /// first to keep it as a replacement character.
+    pub fn into_inner(self) -> W {
+        self.my_inner
+    }
+
+    /// Signal end of data: an incomplete code unit or an unpaired high
+    /// surrogate is written as a replacement character, and the inner
+    /// writer is flushed.
+    pub fn finish(&mut self) -> io::Result<()> {
+        let mut out = [0u8; MAX_UNIT_OUTPUT + 8];
+        // The held code units settle the byte order by their votes.
+        self.my_swap.settle();
+        let mut len = self.take_held(& mut out, 0);
+        // An unpaired high surrogate is decoded at end of data.
+        self.my_utf16.set_is_last_buffer(true);
+        len = self.decode_units(& [], & mut out, len);
+        self.my_utf16.set_is_last_buffer(false);
+        if self.my_low_byte.take().is_some() {
+            self.my_invalid_sequence = true;
+            len += char::REPLACEMENT_CHARACTER.encode_utf8(& mut out[len ..]).len();
+        }
+        self.my_inner.write_all(& out[.. len])?;
+        self.my_inner.flush()
+    }
+
+    /// Decode the code units held by the byte order detection into
+    /// `out` at position `len`, once the byte order is settled.  Returns
+    /// the new position.
+    fn take_held(&mut self, out: &mut [u8], len: usize) -> usize {
+        let mut len = len;
+        while let Option::Some(unit) = self.my_swap.take_held() {
+            len = self.decode_unit(unit, out, len);
+        }
+        len
+    }
+
+    /// Take one code unit as read in little endian order, and decode it
+    /// into `out` at position `len` once the byte order is known.
+    /// Returns the new position.
+    fn push_unit(&mut self, unit: u16, out: &mut [u8], len: usize) -> usize {
+        if self.my_swap_repair && ! self.my_swap.is_settled() {
+            self.my_swap.hold(unit);
+            return self.take_held(out, len);
+        }
+        self.decode_unit(self.my_swap.fix_order(unit), out, len)
+    }
+
+    /// Decode one code unit into `out` at position `len`, and return the
+    /// new position.
+    fn decode_unit(&mut self, unit: u16, out: &mut [u8], len: usize) -> usize {
+        self.decode_units(& [unit], out, len)
+    }
+
+    /// Decode `units` with the UTF16 parser into `out` at position
+    /// `len`, and return the new position.
+    fn decode_units(&mut self, units: &[u16], out: &mut [u8], len: usize) -> usize {
+        let mut len = len;
+        let mut cur_slice = units;
+        // The loop stops at MoreEnum::More(_amt);
+        // a high surrogate is kept by the parser
+        while let Result::Ok((slice_pos, ch)) = self.my_utf16.utf16_to_char(cur_slice) {
+            cur_slice = slice_pos;
+            len += ch.encode_utf8(& mut out[len ..]).len();
+        }
+        len
+    }
+
+    /// Decode the bytes of `buf` into `out` at position `len`, writing
+    /// `out` to the inner writer whenever it is nearly full.  Returns
+    /// the new position.
+    fn write_units(&mut self, buf: &[u8], out: &mut [u8], len: usize) -> io::Result<usize> {
+        let mut len = len;
+        for b in buf {
+            match self.my_low_byte.take() {
+                Option::None => {
+                    self.my_low_byte = Option::Some(* b);
+                }
+                Option::Some(low) => {
+                    len = self.push_unit(u16::from_le_bytes([low, * b]), out, len);
+                    if len + MAX_UNIT_OUTPUT > out.len() {
+                        self.my_inner.write_all(& out[.. len])?;
+                        len = 0;
+                    }
+                }
+            }
+        }
+        Result::Ok(len)
+    }
+}
+
+/// io::Write taking UTF16 little endian bytes
+impl<W: Write> Write for Utf16LeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut out = [0u8; WRITE_BUF_SIZE];
+        let len = self.write_units(buf, & mut out, 0)?;
+        self.my_inner.write_all(& out[.. len])?;
+        Result::Ok(buf.len())
+    }
+
+    /// Take all the buffers, as one write of their concatenation; code
+    /// units and surrogate pairs may be split across buffers.
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let mut out = [0u8; WRITE_BUF_SIZE];
+        let mut len:usize = 0;
+        let mut total:usize = 0;
+        for buf in bufs {
+            len = self.write_units(buf, & mut out, len)?;
+            total += buf.len();
+        }
+        self.my_inner.write_all(& out[.. len])?;
+        Result::Ok(total)
+    }
+
+    /// Flush the inner writer.  An incomplete code unit or surrogate is
+    /// kept for the next write.
+    fn flush(&mut self) -> io::Result<()> {
+        self.my_inner.flush()
+    }
+}
+
+/// An io::Read wrapper that converts the bytes of an inner reader with
+/// a Recode converter.
+///
+/// ```rust
+/// use std::io::Read;
+/// use utf8conv::*;
+///
+/// let recode = Recode::from(Encoding::Utf8).normalize_newlines().to(Encoding::Utf16Be);
+/// let mut reader = RecodeReader::new(recode, & b"a\r\n"[..]);
+/// let mut wide = Vec::new();
+/// reader.read_to_end(& mut wide).unwrap();
+/// assert_eq!(vec![0x00u8, 0x61u8, 0x00u8, 0x0Au8], wide);
+/// ```
+pub struct RecodeReader<R> {
+
+    /// the inner reader
+    my_inner: R,
+
+    /// the converter
+    my_recode: Recode,
+
+    /// bytes read from the inner reader
+    my_in: [u8; READ_BUF_SIZE],
+
+    /// start of the unconverted bytes in my_in
+    my_in_start: usize,
+
+    /// end of the unconverted bytes in my_in
+    my_in_end: usize,
+}
+
+/// Implementation of RecodeReader
+impl<R: Read> RecodeReader<R> {
+
+    /// Make a new RecodeReader converting the bytes of `inner` with
+    /// `recode`.
+    /// # Arguments
+    /// * `recode` - the converter
+    /// * `inner` - the input
+    pub fn new(recode: Recode, inner: R) -> RecodeReader<R> {
+        let mut recode = recode;
+        recode.set_is_last_buffer(false);
+        RecodeReader {
+            my_inner: inner,
+            my_recode: recode,
+            my_in: [0; READ_BUF_SIZE],
+            my_in_start: 0,
+            my_in_end: 0,
+        }
+    }
+
+    #[inline]
+    /// This function returns true if invalid sequence occurred
+    /// in this stream.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_recode.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// Get a reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        & self.my_inner
+    }
+
+    /// Unwrap this RecodeReader, returning the inner reader.
+    /// Bytes buffered but not yet read out are lost.
+    pub fn into_inner(self) -> R {
+        self.my_inner
+    }
+}
+
+/// io::Read producing converted bytes
+impl<R: Read> Read for RecodeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let input = self.my_in.get(self.my_in_start .. self.my_in_end).unwrap_or(&[]);
+            match self.my_recode.recode_to_slice(input, buf) {
+                Result::Ok((rest, len)) => {
+                    self.my_in_start = self.my_in_end - rest.len();
+                    return Result::Ok(len);
+                }
+                Result::Err(MoreEnum::More(0)) => {
+                    // end of data
+                    return Result::Ok(0);
+                }
+                Result::Err(MoreEnum::More(_amt)) => {
+                    self.my_in_start = 0;
+                    self.my_in_end = 0;
+                    let len = self.my_inner.read(& mut self.my_in)?;
+                    self.my_in_end = len;
+                    if len == 0 {
+                        self.my_recode.set_is_last_buffer(true);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An io::Write wrapper that converts the bytes written with a Recode
+/// converter, and writes the result to an inner writer.
+///
+/// Writes may split a char anywhere; the incomplete part is kept until
+/// the next write.  Call finish() at the end of the stream to write out
+/// an incomplete char as a replacement character.
+///
+/// ```rust
+/// use std::io::Write;
+/// use utf8conv::*;
+///
+/// let mut writer = RecodeWriter::new(Recode::from(Encoding::Utf32Le), Vec::new());
+/// writer.write_all(& [0x48u8, 0x03u8, 0x01u8]).unwrap();
+/// writer.write_all(& [0x00u8, 0x61u8]).unwrap();
+/// writer.finish().unwrap();
+/// assert_eq!("\u{10348}\u{FFFD}".as_bytes(), & writer.get_ref()[..]);
+/// assert!(writer.has_invalid_sequence());
+/// ```
+pub struct RecodeWriter<W: Write> {
+
+    /// the inner writer
+    my_inner: W,
+
+    /// the converter
+    my_recode: Recode,
+}
+
+/// Implementation of RecodeWriter
+impl<W: Write> RecodeWriter<W> {
+
+    /// Make a new RecodeWriter writing the bytes converted with `recode`
+    /// into `inner`.
+    /// # Arguments
+    /// * `recode` - the converter
+    /// * `inner` - the output
+    pub fn new(recode: Recode, inner: W) -> RecodeWriter<W> {
+        let mut recode = recode;
+        recode.set_is_last_buffer(false);
+        RecodeWriter {
+            my_inner: inner,
+            my_recode: recode,
+        }
+    }
+
+    #[inline]
+    /// This function returns true if invalid sequence occurred
+    /// in this stream.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_recode.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// Get a reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        & self.my_inner
+    }
+
+    /// Unwrap this RecodeWriter, returning the inner writer.
+    /// An incomplete char is dropped; call finish() first to keep it
+    /// as a replacement character.
+    pub fn into_inner(self) -> W {
+        self.my_inner
+    }
+
+    /// Convert `input` and write the result to the inner writer.
+    fn write_converted(&mut self, input: &[u8]) -> io::Result<()> {
+        let mut out = [0u8; WRITE_BUF_SIZE];
+        let mut cur_slice = input;
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((rest, len)) = self.my_recode.recode_to_slice(cur_slice, & mut out) {
+            self.my_inner.write_all(& out[.. len])?;
+            cur_slice = rest;
+        }
+        Result::Ok(())
+    }
+
+    /// Signal end of data: an incomplete char is written as a
+    /// replacement character, and the inner writer is flushed.
+    pub fn finish(&mut self) -> io::Result<()> {
+        self.my_recode.set_is_last_buffer(true);
+        self.write_converted(&[])?;
+        self.my_inner.flush()
+    }
+}
+
+/// io::Write taking bytes to be converted
+impl<W: Write> Write for RecodeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_converted(buf)?;
+        Result::Ok(buf.len())
+    }
+
+    /// Flush the inner writer.  An incomplete char is kept for the next
+    /// write.
+    fn flush(&mut self) -> io::Result<()> {
+        self.my_inner.flush()
+    }
+}
+
+/// A char iterator decoding UTF8 from a reader, with invalid sequences
+/// substituted with replacement characters.
+///
+/// A read error ends the iteration; it is kept for take_error().
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut chars = Utf8ReadChars::new(& b"a\xE2\x82\xAC\xFF"[..]);
+/// let text: String = chars.by_ref().collect();
+/// assert_eq!("a\u{20AC}\u{FFFD}", text);
+/// assert!(chars.take_error().is_none());
+/// ```
+pub struct Utf8ReadChars<R> {
+
+    /// the inner reader
+    my_inner: R,
+
+    /// the UTF8 parser
+    my_parser: FromUtf8,
+
+    /// bytes read from the inner reader
+    my_in: [u8; READ_BUF_SIZE],
+
+    /// start of the unparsed bytes in my_in
+    my_in_start: usize,
+
+    /// end of the unparsed bytes in my_in
+    my_in_end: usize,
+
+    /// the read error that ended the iteration
+    my_error: Option<io::Error>,
+}
+
+/// Implementation of Utf8ReadChars
+impl<R: Read> Utf8ReadChars<R> {
+
+    /// Make a new Utf8ReadChars reading UTF8 from `inner`.
+    pub fn new(inner: R) -> Utf8ReadChars<R> {
+        let mut parser = FromUtf8::new();
+        parser.set_is_last_buffer(false);
+        Utf8ReadChars {
+            my_inner: inner,
+            my_parser: parser,
+            my_in: [0; READ_BUF_SIZE],
+            my_in_start: 0,
+            my_in_end: 0,
+            my_error: Option::None,
+        }
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this stream.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_parser.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// Take the read error that ended the iteration, if any.
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.my_error.take()
+    }
+
+    #[inline]
+    /// Get a reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        & self.my_inner
+    }
+
+    /// Unwrap this Utf8ReadChars, returning the inner reader.
+    /// Bytes buffered but not yet decoded are lost.
+    pub fn into_inner(self) -> R {
+        self.my_inner
+    }
+}
+
+/// Implementation of Utf8ReadChars for seekable readers
+impl<R: Read + Seek> Utf8ReadChars<R> {
+
+    /// Seek `inner` to byte `offset`, skip to the next char boundary,
+    /// and make a new Utf8ReadChars reading from there.  Returns the
+    /// char iterator and the byte offset of its first char.
+    ///
+    /// Continuation bytes at `offset` belong to a char that started
+    /// earlier, and are skipped.  At most 3 are skipped, the most a
+    /// valid sequence can have; further ones decode as replacement
+    /// characters.  This is the way to tail or page through large logs.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use utf8conv::*;
+    ///
+    /// let log = Cursor::new("\u{4E2D}\u{6587} log".as_bytes());
+    /// let (chars, start) = Utf8ReadChars::seek_from(log, 4).unwrap();
+    /// assert_eq!(6, start);
+    /// assert_eq!(" log", chars.collect::<String>());
+    /// ```
+    /// # Arguments
+    /// * `inner` - the seekable reader
+    /// * `offset` - the byte offset to start from
+    pub fn seek_from(inner: R, offset: u64) -> io::Result<(Utf8ReadChars<R>, u64)> {
+        let mut chars = Utf8ReadChars::new(inner);
+        chars.my_inner.seek(SeekFrom::Start(offset))?;
+        let mut skipped:u64 = 0;
+        while skipped < 3 {
+            if chars.my_in_start == chars.my_in_end {
+                let len = match chars.my_inner.read(& mut chars.my_in) {
+                    Result::Ok(len) => len,
+                    Result::Err(er) if er.kind() == io::ErrorKind::Interrupted => {
+                        continue;
+                    }
+                    Result::Err(er) => {
+                        return Result::Err(er);
+                    }
+                };
+                if len == 0 {
+                    break;
+                }
+                chars.my_in_start = 0;
+                chars.my_in_end = len;
+            }
+            if (chars.my_in[chars.my_in_start] & 0xC0) != 0x80 {
+                // a lead byte or an ASCII byte
+                break;
+            }
+            chars.my_in_start += 1;
+            skipped += 1;
+        }
+        Result::Ok((chars, offset + skipped))
+    }
+}
+
+/// Iterator for Utf8ReadChars
+impl<R: Read> Iterator for Utf8ReadChars<R> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let input = self.my_in.get(self.my_in_start .. self.my_in_end).unwrap_or(&[]);
+            match self.my_parser.utf8_to_char(input) {
+                Result::Ok((rest, ch)) => {
+                    self.my_in_start = self.my_in_end - rest.len();
+                    return Option::Some(ch);
+                }
+                Result::Err(MoreEnum::More(0)) => {
+                    // end of data
+                    return Option::None;
+                }
+                Result::Err(MoreEnum::More(_amt)) => {
+                    self.my_in_start = 0;
+                    self.my_in_end = 0;
+                    match self.my_inner.read(& mut self.my_in) {
+                        Result::Ok(0) => {
+                            self.my_parser.set_is_last_buffer(true);
+                        }
+                        Result::Ok(len) => {
+                            self.my_in_end = len;
+                        }
+                        Result::Err(er) if er.kind() == io::ErrorKind::Interrupted => {}
+                        Result::Err(er) => {
+                            // An incomplete sequence before the error
+                            // still comes out as a replacement character.
+                            self.my_error = Option::Some(er);
+                            self.my_parser.set_is_last_buffer(true);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A char iterator decoding UTF8 from a reader like Utf8ReadChars, with
+/// read errors returned in line: the items are io::Result<char>.
+///
+/// Invalid sequences become replacement characters.  A read error ends
+/// the stream; an incomplete sequence before it comes out first as a
+/// replacement character, then the error, then the iteration ends.
+/// Interrupted reads are retried.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let reader = CharReader::new(& b"a\xE2\x82\xAC\xFF"[..]);
+/// let text = reader.collect::<std::io::Result<String>>().unwrap();
+/// assert_eq!("a\u{20AC}\u{FFFD}", text);
+/// ```
+pub struct CharReader<R> {
+
+    /// the char iterator
+    my_chars: Utf8ReadChars<R>,
+}
+
+/// From a Utf8ReadChars, such as one made by seek_from()
+impl<R: Read> From<Utf8ReadChars<R>> for CharReader<R> {
+    fn from(chars: Utf8ReadChars<R>) -> CharReader<R> {
+        CharReader { my_chars: chars }
+    }
+}
+
+/// Implementation of CharReader
+impl<R: Read> CharReader<R> {
+
+    /// Make a new CharReader reading UTF8 from `inner`.
+    pub fn new(inner: R) -> CharReader<R> {
+        CharReader::from(Utf8ReadChars::new(inner))
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this stream.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_chars.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// Get a reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        self.my_chars.get_ref()
+    }
+
+    /// Unwrap this CharReader, returning the inner reader.
+    /// Bytes buffered but not yet decoded are lost.
+    pub fn into_inner(self) -> R {
+        self.my_chars.into_inner()
+    }
+}
+
+/// Iterator for CharReader
+impl<R: Read> Iterator for CharReader<R> {
+    type Item = io::Result<char>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.my_chars.next() {
+            Option::Some(ch) => Option::Some(Result::Ok(ch)),
+            Option::None => self.my_chars.take_error().map(Result::Err),
+        }
+    }
+}
+
+/// A writer of chars and strings, encoding them as UTF8 into an inner
+/// writer.
+///
+/// Each call writes whole chars with write_all(), so short writes of
+/// the inner writer are retried, and a char is never split between
+/// calls.  The bytes are written in blocks; nothing is buffered between
+/// calls.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut writer = Utf8Writer::new(Vec::new());
+/// writer.write_str("caf").unwrap();
+/// writer.write_char('\u{E9}').unwrap();
+/// writer.write_chars("!".chars()).unwrap();
+/// assert_eq!("caf\u{E9}!".as_bytes(), & writer.into_inner()[..]);
+/// ```
+pub struct Utf8Writer<W: Write> {
+
+    /// the inner writer
+    my_inner: W,
+}
+
+/// Implementation of Utf8Writer
+impl<W: Write> Utf8Writer<W> {
+
+    /// Make a new Utf8Writer writing UTF8 into `inner`.
+    pub fn new(inner: W) -> Utf8Writer<W> {
+        Utf8Writer { my_inner: inner }
+    }
+
+    /// Write the UTF8 encoding of `ch`.
+    /// # Arguments
+    /// * `ch` - the char to be written
+    pub fn write_char(&mut self, ch: char) -> io::Result<()> {
+        let mut out = [0u8; 4];
+        self.my_inner.write_all(ch.encode_utf8(& mut out).as_bytes())
+    }
+
+    /// Write the UTF8 encoding of `text`.
+    /// # Arguments
+    /// * `text` - the text to be written
+    pub fn write_str(&mut self, text: &str) -> io::Result<()> {
+        self.my_inner.write_all(text.as_bytes())
+    }
+
+    /// Write the UTF8 encoding of the chars of `input`.
+    /// # Arguments
+    /// * `input` - the chars to be written
+    pub fn write_chars<I>(&mut self, input: I) -> io::Result<()>
+    where I: IntoIterator<Item = char>, {
+        write_chars(& mut self.my_inner, input)
+    }
+
+    #[inline]
+    /// Flush the inner writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.my_inner.flush()
+    }
+
+    #[inline]
+    /// Get a reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        & self.my_inner
+    }
+
+    /// Unwrap this Utf8Writer, returning the inner writer.
+    pub fn into_inner(self) -> W {
+        self.my_inner
+    }
+}
+
+/// A writer of chars and strings, encoding them as UTF16 into an inner
+/// writer, in little endian byte order unless set_big_endian() is
+/// called.
+///
+/// Each call writes whole chars with write_all(), so short writes of
+/// the inner writer are retried, and a surrogate pair is never split
+/// between calls.  No byte order mark is added.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut writer = Utf16Writer::new(Vec::new());
+/// writer.write_str("a\u{1F71D}").unwrap();
+/// assert_eq!(vec![0x61u8, 0x00u8, 0x3Du8, 0xD8u8, 0x1Du8, 0xDFu8], writer.into_inner());
+///
+/// let mut writer = Utf16Writer::new(Vec::new());
+/// writer.set_big_endian(true);
+/// writer.write_char('a').unwrap();
+/// assert_eq!(vec![0x00u8, 0x61u8], writer.into_inner());
+/// ```
+pub struct Utf16Writer<W: Write> {
+
+    /// the inner writer
+    my_inner: W,
+
+    /// true to write the code units in big endian byte order
+    my_big_endian: bool,
+}
+
+/// Implementation of Utf16Writer
+impl<W: Write> Utf16Writer<W> {
+
+    /// Make a new Utf16Writer writing UTF16 little endian into `inner`.
+    pub fn new(inner: W) -> Utf16Writer<W> {
+        Utf16Writer {
+            my_inner: inner,
+            my_big_endian: false,
+        }
+    }
+
+    /// Choose the byte order of the code units written from now on.
+    /// # Arguments
+    /// * `b` - true for big endian, false for little endian
+    pub fn set_big_endian(&mut self, b: bool) {
+        self.my_big_endian = b;
+    }
+
+    #[inline]
+    /// Returns true if the code units are written in big endian byte
+    /// order.
+    pub fn is_big_endian(&self) -> bool {
+        self.my_big_endian
+    }
+
+    /// Write the UTF16 encoding of `ch`.
+    /// # Arguments
+    /// * `ch` - the char to be written
+    pub fn write_char(&mut self, ch: char) -> io::Result<()> {
+        self.write_chars(core::iter::once(ch))
+    }
+
+    /// Write the UTF16 encoding of `text`.
+    /// # Arguments
+    /// * `text` - the text to be written
+    pub fn write_str(&mut self, text: &str) -> io::Result<()> {
+        self.write_chars(text.chars())
+    }
+
+    /// Write the UTF16 encoding of the chars of `input`.  The bytes are
+    /// written in blocks.
+    /// # Arguments
+    /// * `input` - the chars to be written
+    pub fn write_chars<I>(&mut self, input: I) -> io::Result<()>
+    where I: IntoIterator<Item = char>, {
+        let mut out = [0u8; WRITE_BUF_SIZE];
+        let mut len:usize = 0;
+        for ch in input {
+            // Surrogate pairs are not split across writes.
+            if len + 4 > WRITE_BUF_SIZE {
+                self.my_inner.write_all(& out[.. len])?;
+                len = 0;
+            }
+            let mut units = [0u16; 2];
+            for unit in ch.encode_utf16(& mut units).iter() {
+                let bytes = if self.my_big_endian {
+                    unit.to_be_bytes()
+                }
+                else {
+                    unit.to_le_bytes()
+                };
+                out[len] = bytes[0];
+                out[len + 1] = bytes[1];
+                len += 2;
+            }
+        }
+        self.my_inner.write_all(& out[.. len])
+    }
+
+    #[inline]
+    /// Flush the inner writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.my_inner.flush()
+    }
+
+    #[inline]
+    /// Get a reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        & self.my_inner
+    }
+
+    /// Unwrap this Utf16Writer, returning the inner writer.
+    pub fn into_inner(self) -> W {
+        self.my_inner
+    }
+}
+
+/// Returns a char iterator over standard input, with invalid UTF8
+/// sequences substituted with replacement characters.
+///
+/// Standard input stays locked while the iterator is alive.
+///
+/// ```rust,no_run
+/// use utf8conv::*;
+///
+/// let lines = stdin_chars().filter(|ch| * ch == '\n').count();
+/// println!("{} lines", lines);
+/// ```
+pub fn stdin_chars() -> Utf8ReadChars<io::StdinLock<'static>> {
+    Utf8ReadChars::new(io::stdin().lock())
+}
+
+/// Encode chars as UTF8 into `writer`.
+///
+/// The bytes are written in blocks, so unbuffered writers need no
+/// io::BufWriter.  The writer is not flushed.
+/// # Arguments
+/// * `writer` - the output
+/// * `input` - the chars to be written
+pub fn write_chars<W, I>(writer: &mut W, input: I) -> io::Result<()>
+where W: Write + ?Sized, I: IntoIterator<Item = char>, {
+    let mut out = [0u8; WRITE_BUF_SIZE];
+    let mut len:usize = 0;
+    for b in encode(input) {
+        out[len] = b;
+        len += 1;
+        if len == WRITE_BUF_SIZE {
+            writer.write_all(& out)?;
+            len = 0;
+        }
+    }
+    writer.write_all(& out[.. len])
+}
+
+/// Encode chars as UTF8 onto standard output, and flush it.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// print_chars("hello\n".chars()).unwrap();
+/// ```
+/// # Arguments
+/// * `input` - the chars to be printed
+pub fn print_chars<I>(input: I) -> io::Result<()>
+where I: IntoIterator<Item = char>, {
+    let mut stdout = io::stdout().lock();
+    write_chars(& mut stdout, input)?;
+    stdout.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::io::Read;
+    use std::io::Write;
+    use std::vec::Vec;
+
+    // A reader returning at most `self.1` bytes per read
+    struct SlowReader<'a>(&'a [u8], usize);
+
+    impl std::io::Read for SlowReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let len = self.0.len().min(self.1).min(buf.len());
+            buf[.. len].copy_from_slice(& self.0[.. len]);
+            self.0 = & self.0[len ..];
+            Result::Ok(len)
+        }
+    }
+
+    #[test]
+    // Test UTF16LE output against the standard library with slow readers
+    fn test_utf16le_reader() {
+        let bytes = [
+            0x41u8, 0xE2u8, 0x82u8, 0xACu8, 0xF0u8, 0x90u8, 0x8Du8, 0x88u8,
+            0xEDu8, 0xA0u8, 0x80u8, 0x0Au8, 0xF0u8, 0x9Fu8,
+        ];
+        let mut truth = Vec::new();
+        for unit in std::string::String::from_utf8_lossy(& bytes).encode_utf16() {
+            truth.extend_from_slice(& unit.to_le_bytes());
+        }
+        for in_step in 1 .. 5 {
+            for out_step in 1 .. 6 {
+                let mut reader = Utf16LeReader::new(SlowReader(& bytes, in_step));
+                let mut output = Vec::new();
+                let mut buf = [0u8; 5];
+                loop {
+                    let len = reader.read(& mut buf[.. out_step]).unwrap();
+                    if len == 0 {
+                        break;
+                    }
+                    output.extend_from_slice(& buf[.. len]);
+                }
+                assert_eq!(truth, output);
+                assert!(reader.has_invalid_sequence());
+                assert!(reader.get_ref().0.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    // Test UTF16LE input split at every position against the standard library
+    fn test_utf16le_writer() {
+        let units = [0x41u16, 0xD801u16, 0xDC37u16, 0xDC00u16, 0x20ACu16, 0xD800u16, 0x42u16, 0xD83Du16];
+        let mut bytes = Vec::new();
+        for unit in units.iter() {
+            bytes.extend_from_slice(& unit.to_le_bytes());
+        }
+        let truth: std::string::String = std::char::decode_utf16(units.iter().copied())
+            .map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER)).collect();
+        for split1 in 0 ..= bytes.len() {
+            for split2 in split1 ..= bytes.len() {
+                let mut writer = Utf16LeWriter::new(Vec::new());
+                writer.write_all(& bytes[.. split1]).unwrap();
+                writer.write_all(& bytes[split1 .. split2]).unwrap();
+                writer.write_all(& bytes[split2 ..]).unwrap();
+                writer.flush().unwrap();
+                writer.finish().unwrap();
+                assert!(writer.has_invalid_sequence());
+                assert_eq!(truth.as_bytes(), & writer.into_inner()[..]);
+            }
+        }
+    }
+
+    #[test]
+    // Test byte order detection of the byte swap repair mode
+    fn test_utf16le_writer_swap_repair() {
+        let text = "\u{4E2D}\u{6587}\u{1F600}\u{4E2D}x\u{6587}\u{4E2D}\u{6587}\u{4E2D}";
+        for bom in [false, true].iter() {
+            let mut little = Vec::new();
+            let mut big = Vec::new();
+            if * bom {
+                little.extend_from_slice(& [0xFFu8, 0xFEu8]);
+                big.extend_from_slice(& [0xFEu8, 0xFFu8]);
+            }
+            for unit in text.encode_utf16() {
+                little.extend_from_slice(& unit.to_le_bytes());
+                big.extend_from_slice(& unit.to_be_bytes());
+            }
+            for (bytes, swapped) in [(& little, false), (& big, true)].iter() {
+                for step in 1 .. 4 {
+                    let mut writer = Utf16LeWriter::new(Vec::new());
+                    writer.set_swap_repair(true);
+                    for chunk in bytes.chunks(step) {
+                        writer.write_all(chunk).unwrap();
+                    }
+                    writer.finish().unwrap();
+                    assert_eq!(* swapped, writer.is_swapped());
+                    let output = writer.into_inner();
+                    let skip = if * bom { 3 } else { 0 };
+                    assert_eq!(text.as_bytes(), & output[skip ..]);
+                }
+            }
+        }
+        // Without evidence in the first 8 units, the stream is little endian.
+        let mut writer = Utf16LeWriter::new(Vec::new());
+        writer.set_swap_repair(true);
+        writer.write_all(& [0x2Du8, 0x4Eu8, 0x2Du8, 0x4Eu8]).unwrap();
+        assert!(writer.get_ref().is_empty());
+        writer.finish().unwrap();
+        assert_eq!("\u{4E2D}\u{4E2D}".as_bytes(), & writer.get_ref()[..]);
+        assert!(! writer.is_swapped());
+        // Little endian text starting with a char in the 0xXX00 pattern
+        // is not swapped by that char alone.
+        for text in ["\u{3000}A", "\u{4E00} is one", "\u{0100}bc", "\u{0400}bc"].iter() {
+            let mut little = Vec::new();
+            for unit in text.encode_utf16() {
+                little.extend_from_slice(& unit.to_le_bytes());
+            }
+            for split in 0 ..= little.len() {
+                let mut writer = Utf16LeWriter::new(Vec::new());
+                writer.set_swap_repair(true);
+                writer.write_all(& little[.. split]).unwrap();
+                writer.write_all(& little[split ..]).unwrap();
+                writer.finish().unwrap();
+                assert!(! writer.is_swapped(), "{:?}", text);
+                assert_eq!(text.as_bytes(), & writer.get_ref()[..]);
+            }
+        }
+    }
+
+    #[test]
+    // Test vectored reads and writes with buffers split at every position
+    fn test_utf16le_vectored() {
+        use std::io::IoSlice;
+        use std::io::IoSliceMut;
+
+        let text = "a\u{20AC}\u{10348}\u{7FF}z";
+        let mut wide = Vec::new();
+        for unit in text.encode_utf16() {
+            wide.extend_from_slice(& unit.to_le_bytes());
+        }
+        for split1 in 0 ..= wide.len() {
+            for split2 in split1 ..= wide.len() {
+                let mut writer = Utf16LeWriter::new(Vec::new());
+                let bufs = [IoSlice::new(& wide[.. split1]), IoSlice::new(& wide[split1 .. split2]),
+                    IoSlice::new(& wide[split2 ..])];
+                assert_eq!(wide.len(), writer.write_vectored(& bufs).unwrap());
+                writer.finish().unwrap();
+                assert_eq!(text.as_bytes(), & writer.get_ref()[..]);
+
+                let mut reader = Utf16LeReader::new(SlowReader(text.as_bytes(), 3));
+                let mut output = std::vec![0u8; wide.len() + 1];
+                let (head, tail) = output.split_at_mut(split2);
+                let (first, second) = head.split_at_mut(split1);
+                let mut bufs = [IoSliceMut::new(first), IoSliceMut::new(second), IoSliceMut::new(tail)];
+                let mut rest = & mut bufs[..];
+                let mut total:usize = 0;
+                loop {
+                    let len = reader.read_vectored(rest).unwrap();
+                    if len == 0 {
+                        break;
+                    }
+                    total += len;
+                    IoSliceMut::advance_slices(& mut rest, len);
+                }
+                assert_eq!(wide.len(), total);
+                assert_eq!(wide, output[.. total]);
+            }
+        }
+    }
+
+    #[test]
+    // Test a long write, and an odd byte count at the end of data
+    fn test_utf16le_writer_long() {
+        let text: std::string::String = "\u{10348}\u{20AC}x".repeat(500);
+        let mut bytes = Vec::new();
+        for unit in text.encode_utf16() {
+            bytes.extend_from_slice(& unit.to_le_bytes());
+        }
+        let mut writer = Utf16LeWriter::new(Vec::new());
+        writer.write_all(& bytes).unwrap();
+        writer.finish().unwrap();
+        assert!(! writer.has_invalid_sequence());
+        assert_eq!(text.as_bytes(), & writer.get_ref()[..]);
+        let mut writer = Utf16LeWriter::new(Vec::new());
+        writer.write_all(& [0x41u8, 0x00u8, 0x42u8]).unwrap();
+        assert_eq!(b"A", & writer.get_ref()[..]);
+        writer.finish().unwrap();
+        assert_eq!("A\u{FFFD}".as_bytes(), & writer.into_inner()[..]);
+        // An encoded replacement character counts as invalid.
+        let mut writer = Utf16LeWriter::new(Vec::new());
+        writer.write_all(& [0xFDu8, 0xFFu8]).unwrap();
+        writer.finish().unwrap();
+        assert!(writer.has_invalid_sequence());
+        assert_eq!("\u{FFFD}".as_bytes(), & writer.get_ref()[..]);
+    }
+
+    // A reader failing after its bytes are read
+    struct FailingReader<'a>(&'a [u8]);
+
+    impl std::io::Read for FailingReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.0.is_empty() {
+                return Result::Err(std::io::Error::other("broken pipe"));
+            }
+            let len = self.0.len().min(buf.len()).min(2);
+            buf[.. len].copy_from_slice(& self.0[.. len]);
+            self.0 = & self.0[len ..];
+            Result::Ok(len)
+        }
+    }
+
+    #[test]
+    // Test char reading round trips through write_chars(), and read errors
+    fn test_read_and_write_chars() {
+        let text: std::string::String = "x\u{10348}\u{20AC}\n".repeat(400);
+        let mut chars = Utf8ReadChars::new(SlowReader(text.as_bytes(), 7));
+        let mut output = Vec::new();
+        write_chars(& mut output, chars.by_ref()).unwrap();
+        assert_eq!(text.as_bytes(), & output[..]);
+        assert!(! chars.has_invalid_sequence());
+        assert!(chars.take_error().is_none());
+        let mut chars = Utf8ReadChars::new(FailingReader(b"ab\xF0\x90"));
+        assert_eq!("ab\u{FFFD}", chars.by_ref().collect::<std::string::String>());
+        assert!(chars.has_invalid_sequence());
+        assert_eq!("broken pipe", std::format!("{}", chars.take_error().unwrap()));
+        assert_eq!(Option::None, chars.next());
+    }
+
+    #[test]
+    // Test read errors returned in line, after the chars before them
+    fn test_char_reader() {
+        let text: std::string::String = "x\u{10348}\u{20AC}\n".repeat(400);
+        let reader = CharReader::new(SlowReader(text.as_bytes(), 5));
+        assert_eq!(text, reader.collect::<std::io::Result<std::string::String>>().unwrap());
+        let mut reader = CharReader::new(FailingReader(b"ab\xF0\x90"));
+        assert_eq!(Option::Some('a'), reader.next().and_then(Result::ok));
+        assert_eq!(Option::Some('b'), reader.next().and_then(Result::ok));
+        assert_eq!(Option::Some('\u{FFFD}'), reader.next().and_then(Result::ok));
+        assert!(reader.has_invalid_sequence());
+        assert_eq!("broken pipe", std::format!("{}", reader.next().unwrap().unwrap_err()));
+        assert!(reader.next().is_none());
+        let cursor = std::io::Cursor::new("\u{4E2D}z".as_bytes());
+        let (chars, _start) = Utf8ReadChars::seek_from(cursor, 1).unwrap();
+        let mut reader = CharReader::from(chars);
+        assert_eq!("z", reader.by_ref().collect::<std::io::Result<std::string::String>>().unwrap());
+        assert_eq!(4, reader.into_inner().position());
+    }
+
+    // A writer taking at most `self.1` bytes per write
+    struct SlowWriter(Vec<u8>, usize);
+
+    impl std::io::Write for SlowWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let len = self.1.min(buf.len());
+            self.0.extend_from_slice(& buf[.. len]);
+            Result::Ok(len)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Result::Ok(())
+        }
+    }
+
+    #[test]
+    // Test the char writers against the standard library with short writes
+    fn test_char_writers() {
+        let text: std::string::String = "x\u{10348}\u{20AC}\n".repeat(300);
+        let mut writer = Utf8Writer::new(SlowWriter(Vec::new(), 3));
+        writer.write_str(& text).unwrap();
+        writer.write_chars(text.chars()).unwrap();
+        for ch in text.chars() {
+            writer.write_char(ch).unwrap();
+        }
+        writer.flush().unwrap();
+        assert_eq!(text.repeat(3).as_bytes(), & writer.get_ref().0[..]);
+        for big_endian in [false, true] {
+            let mut writer = Utf16Writer::new(SlowWriter(Vec::new(), 3));
+            writer.set_big_endian(big_endian);
+            assert_eq!(big_endian, writer.is_big_endian());
+            writer.write_str(& text).unwrap();
+            for ch in text.chars() {
+                writer.write_char(ch).unwrap();
+            }
+            let expected: Vec<u8> = text.repeat(2).encode_utf16()
+                .flat_map(|unit| if big_endian { unit.to_be_bytes() } else { unit.to_le_bytes() })
+                .collect();
+            assert_eq!(expected, writer.into_inner().0);
+        }
+        let mut byte_box = [0u8; 3];
+        let mut writer = Utf16Writer::new(& mut byte_box[..]);
+        assert_eq!(std::io::ErrorKind::WriteZero, writer.write_str("ab").unwrap_err().kind());
+    }
+
+    #[test]
+    // Test resuming at every byte offset, and past the end
+    fn test_seek_from() {
+        let text = "a\u{10348}\u{20AC}\u{7FF}z\u{4E2D}";
+        for offset in 0 ..= text.len() as u64 + 2 {
+            let cursor = std::io::Cursor::new(text.as_bytes());
+            let (chars, start) = Utf8ReadChars::seek_from(cursor, offset).unwrap();
+            let expected = (offset as usize ..= text.len()).find(|v| text.is_char_boundary(* v))
+                .unwrap_or(offset as usize);
+            assert_eq!(expected as u64, start);
+            assert_eq!(text.get(expected ..).unwrap_or(""), chars.collect::<std::string::String>());
+        }
+        // A run of continuation bytes longer than a sequence
+        let bytes = b"\x80\x80\x80\x80\x80x";
+        let cursor = std::io::Cursor::new(& bytes[..]);
+        let (mut chars, start) = Utf8ReadChars::seek_from(cursor, 0).unwrap();
+        assert_eq!(3, start);
+        assert_eq!("\u{FFFD}\u{FFFD}x", chars.by_ref().collect::<std::string::String>());
+        assert!(chars.has_invalid_sequence());
+    }
+
+    #[test]
+    // Test the Recode reader and writer with slow readers and split writes
+    fn test_recode_reader_writer() {
+        let text = "\u{FEFF}x\r\n\u{10348}\r\u{20AC}";
+        let mut wide = Vec::new();
+        for unit in text.encode_utf16() {
+            wide.extend_from_slice(& unit.to_be_bytes());
+        }
+        let truth = "x\n\u{10348}\n\u{20AC}".as_bytes();
+        let recode = Recode::from(Encoding::Utf16Be).strip_bom().normalize_newlines();
+        for step in 1 .. 6 {
+            let mut reader = RecodeReader::new(recode, SlowReader(& wide, step));
+            let mut output = Vec::new();
+            let mut buf = [0u8; 5];
+            loop {
+                let len = reader.read(& mut buf[.. step]).unwrap();
+                if len == 0 {
+                    break;
+                }
+                output.extend_from_slice(& buf[.. len]);
+            }
+            assert_eq!(truth, & output[..]);
+            assert!(! reader.has_invalid_sequence());
+
+            let mut writer = RecodeWriter::new(recode, Vec::new());
+            for chunk in wide.chunks(step) {
+                writer.write_all(chunk).unwrap();
+            }
+            writer.flush().unwrap();
+            writer.finish().unwrap();
+            assert_eq!(truth, & writer.into_inner()[..]);
+        }
+        let mut writer = RecodeWriter::new(recode, Vec::new());
+        writer.write_all(& [0xD8u8, 0x00u8, 0x00u8]).unwrap();
+        assert!(writer.get_ref().is_empty());
+        writer.finish().unwrap();
+        assert_eq!("\u{FFFD}\u{FFFD}".as_bytes(), & writer.get_ref()[..]);
+        assert!(writer.has_invalid_sequence());
+    }
+
+    #[test]
+    // Test an empty stream and reads after end of data
+    fn test_utf16le_reader_empty() {
+        let mut reader = Utf16LeReader::new(std::io::empty());
+        let mut buf = [0u8; 4];
+        assert_eq!(0, reader.read(& mut buf).unwrap());
+        assert_eq!(0, reader.read(& mut buf).unwrap());
+        assert!(! reader.has_invalid_sequence());
+        let mut reader = Utf16LeReader::new(& b"ok"[..]);
+        assert_eq!(0, reader.read(& mut []).unwrap());
+        assert_eq!(4, reader.read(& mut buf).unwrap());
+        assert_eq!(0, reader.read(& mut buf).unwrap());
+    }
+}
@@ -0,0 +1,455 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::json
+
+use crate::utf8conv::encode_char_to_buf;
+
+/// lower case hex digits
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// longest escape: a surrogate pair written as two \uXXXX escapes
+const MAX_ESCAPE_LEN: usize = 12;
+
+/// char to JSON escaped UTF8 iterator struct
+pub struct JsonEscapeStruct<'b, I: ?Sized = dyn Iterator<Item = char> + 'b> {
+
+    /// remaining output bytes of the current char
+    my_out: [u8; MAX_ESCAPE_LEN],
+
+    /// position of the next output byte in my_out
+    my_out_pos: usize,
+
+    /// number of output bytes in my_out
+    my_out_len: usize,
+
+    /// true to escape all non-ASCII chars
+    my_ascii_only: bool,
+
+    /// the source iterator
+    my_borrow_mut_iter: &'b mut I,
+}
+
+/// Implementation of JsonEscapeStruct
+impl<'b, I: ?Sized> JsonEscapeStruct<'b, I> {
+
+    /// Append a \uXXXX escape of an UTF16 code unit to my_out.
+    fn push_u_escape(&mut self, unit: u32) {
+        let pos = self.my_out_len;
+        self.my_out[pos] = b'\\';
+        self.my_out[pos + 1] = b'u';
+        for indx in 0 .. 4 {
+            let shift = 12 - 4 * indx;
+            self.my_out[pos + 2 + indx] = HEX_DIGITS[((unit >> shift) & 0xF) as usize];
+        }
+        self.my_out_len = pos + 6;
+    }
+
+    /// Fill my_out with the escaped form of `ch`.
+    fn escape_char(&mut self, ch: char) {
+        self.my_out_pos = 0;
+        self.my_out_len = 0;
+        let short_escape = match ch {
+            '"' => b'"',
+            '\\' => b'\\',
+            '\u{8}' => b'b',
+            '\u{C}' => b'f',
+            '\n' => b'n',
+            '\r' => b'r',
+            '\t' => b't',
+            _ => 0,
+        };
+        if short_escape != 0 {
+            self.my_out[0] = b'\\';
+            self.my_out[1] = short_escape;
+            self.my_out_len = 2;
+        }
+        else if (ch as u32) < 0x20 {
+            // Other control characters
+            self.push_u_escape(ch as u32);
+        }
+        else if self.my_ascii_only && (ch as u32) >= 0x80 {
+            let mut units = [0u16; 2];
+            for unit in ch.encode_utf16(& mut units).iter() {
+                self.push_u_escape(* unit as u32);
+            }
+        }
+        else {
+            let mut encode_buf = [0u8; 4];
+            let encoded = encode_char_to_buf(ch, & mut encode_buf).as_bytes();
+            self.my_out[.. encoded.len()].copy_from_slice(encoded);
+            self.my_out_len = encoded.len();
+        }
+    }
+}
+
+/// an adapter iterator to convert chars to the UTF8 body of a JSON string
+impl<'b, I> Iterator for JsonEscapeStruct<'b, I>
+where I: Iterator<Item = char> + ?Sized, {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.my_out_pos >= self.my_out_len {
+            let ch = self.my_borrow_mut_iter.next()?;
+            self.escape_char(ch);
+        }
+        let v = self.my_out.get(self.my_out_pos).copied();
+        self.my_out_pos += 1;
+        v
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.my_out_len.saturating_sub(self.my_out_pos);
+        let (lower, upper) = self.my_borrow_mut_iter.size_hint();
+        (lower.saturating_add(pending),
+        upper.and_then(|v| v.checked_mul(MAX_ESCAPE_LEN)).and_then(|v| v.checked_add(pending)))
+    }
+}
+
+/// Function json_escape_iter() takes a mutable reference to a char
+/// iterator, and returns an iterator of UTF8 bytes of a JSON string body
+/// (without the surrounding quotes) in its place.
+///
+/// Quotes, backslashes, and control characters are escaped.  When
+/// `ascii_only` is true, non-ASCII chars are also escaped as \uXXXX,
+/// with a surrogate pair for chars above U+FFFF, so the output is ASCII.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut chars = "say \"\u{20AC}\"\n".chars();
+/// let body: Vec<u8> = json_escape_iter(& mut chars, false).collect();
+/// assert_eq!("say \\\"\u{20AC}\\\"\\n".as_bytes(), & body[..]);
+/// let mut chars = "\u{1F600}".chars();
+/// let body: Vec<u8> = json_escape_iter(& mut chars, true).collect();
+/// assert_eq!(b"\\ud83d\\ude00", & body[..]);
+/// ```
+///
+/// # Arguments
+///
+/// * `input` - a mutable reference to a char iterator
+/// * `ascii_only` - true to escape all non-ASCII chars
+#[inline]
+pub fn json_escape_iter<'a, I>(input: &'a mut I, ascii_only: bool)
+-> JsonEscapeStruct<'a, I>
+where I: Iterator<Item = char> + ?Sized, {
+    JsonEscapeStruct {
+        my_out: [0u8; MAX_ESCAPE_LEN],
+        my_out_pos: 0,
+        my_out_len: 0,
+        my_ascii_only: ascii_only,
+        my_borrow_mut_iter: input,
+    }
+}
+
+/// JSON escaped char to char iterator struct
+pub struct JsonUnescapeStruct<'b, I: ?Sized = dyn Iterator<Item = char> + 'b> {
+
+    /// input chars to be read again, last in first out
+    my_back: [char; 2],
+
+    /// number of chars in my_back
+    my_back_len: usize,
+
+    /// a decoded \uXXXX code not yet processed
+    my_code: Option<u32>,
+
+    /// an output char not yet returned
+    my_ready: Option<char>,
+
+    /// true after a malformed escape
+    my_invalid_sequence: bool,
+
+    /// the source iterator
+    my_borrow_mut_iter: &'b mut I,
+}
+
+/// Implementation of JsonUnescapeStruct
+impl<'b, I> JsonUnescapeStruct<'b, I>
+where I: Iterator<Item = char> + ?Sized, {
+
+    #[inline]
+    /// Returns true if a malformed escape occurred.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_invalid_sequence
+    }
+
+    #[inline]
+    /// Take the next input char.
+    fn take(&mut self) -> Option<char> {
+        if self.my_back_len > 0 {
+            self.my_back_len -= 1;
+            return self.my_back.get(self.my_back_len).copied();
+        }
+        self.my_borrow_mut_iter.next()
+    }
+
+    #[inline]
+    /// Return an input char to be read again.
+    fn push_back(&mut self, ch: char) {
+        if let Option::Some(slot) = self.my_back.get_mut(self.my_back_len) {
+            * slot = ch;
+            self.my_back_len += 1;
+        }
+    }
+
+    #[inline]
+    /// Take the next input char if it is `expected`.
+    fn expect(&mut self, expected: char) -> bool {
+        match self.take() {
+            Option::Some(ch) if ch == expected => true,
+            Option::Some(ch) => {
+                self.push_back(ch);
+                false
+            }
+            Option::None => false,
+        }
+    }
+
+    #[inline]
+    /// Signal a malformed escape, and return its replacement character.
+    fn malformed(&mut self) -> char {
+        self.my_invalid_sequence = true;
+        char::REPLACEMENT_CHARACTER
+    }
+
+    /// Read the 4 hex digits of a \uXXXX escape.  A char that is not a
+    /// hex digit is left to be read again.
+    fn read_hex4(&mut self) -> Option<u32> {
+        let mut code:u32 = 0;
+        for _indx in 0 .. 4 {
+            let ch = self.take()?;
+            match ch.to_digit(16) {
+                Option::Some(v) => {
+                    code = (code << 4) | v;
+                }
+                Option::None => {
+                    self.push_back(ch);
+                    return Option::None;
+                }
+            }
+        }
+        Option::Some(code)
+    }
+
+    /// Turn a \uXXXX code into a char, reading the low surrogate
+    /// escape that must follow a high surrogate.
+    fn finish_code(&mut self, code: u32) -> char {
+        if (0xDC00 ..= 0xDFFF).contains(& code) {
+            // Low surrogate without a high surrogate
+            return self.malformed();
+        }
+        if ! (0xD800 ..= 0xDBFF).contains(& code) {
+            return char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER);
+        }
+        if ! self.expect('\\') {
+            return self.malformed();
+        }
+        if ! self.expect('u') {
+            // The backslash starts another escape.
+            self.push_back('\\');
+            return self.malformed();
+        }
+        match self.read_hex4() {
+            Option::Some(low) if (0xDC00 ..= 0xDFFF).contains(& low) => {
+                let high_bits = (code - 0xD800) << 10;
+                char::from_u32(0x10000 + high_bits + (low - 0xDC00))
+                .unwrap_or(char::REPLACEMENT_CHARACTER)
+            }
+            Option::Some(other) => {
+                // Lone high surrogate; the second escape is processed next.
+                self.my_code = Option::Some(other);
+                self.malformed()
+            }
+            Option::None => {
+                // Lone high surrogate followed by a malformed escape
+                self.my_ready = Option::Some(char::REPLACEMENT_CHARACTER);
+                self.malformed()
+            }
+        }
+    }
+}
+
+/// an adapter iterator to convert a JSON string body to chars
+impl<'b, I> Iterator for JsonUnescapeStruct<'b, I>
+where I: Iterator<Item = char> + ?Sized, {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Option::Some(ch) = self.my_ready.take() {
+            return Option::Some(ch);
+        }
+        let code = match self.my_code.take() {
+            Option::Some(code) => code,
+            Option::None => {
+                let ch = self.take()?;
+                if ch != '\\' {
+                    return Option::Some(ch);
+                }
+                let unescaped = match self.take() {
+                    Option::Some('"') => '"',
+                    Option::Some('\\') => '\\',
+                    Option::Some('/') => '/',
+                    Option::Some('b') => '\u{8}',
+                    Option::Some('f') => '\u{C}',
+                    Option::Some('n') => '\n',
+                    Option::Some('r') => '\r',
+                    Option::Some('t') => '\t',
+                    Option::Some('u') => {
+                        match self.read_hex4() {
+                            Option::Some(code) => {
+                                return Option::Some(self.finish_code(code));
+                            }
+                            Option::None => self.malformed(),
+                        }
+                    }
+                    Option::Some(other) => {
+                        // Unknown escape; the char is read again.
+                        self.push_back(other);
+                        self.malformed()
+                    }
+                    Option::None => self.malformed(),
+                };
+                return Option::Some(unescaped);
+            }
+        };
+        Option::Some(self.finish_code(code))
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.my_back_len
+            + self.my_code.is_some() as usize + self.my_ready.is_some() as usize;
+        let (lower, upper) = self.my_borrow_mut_iter.size_hint();
+        // A surrogate pair escape of 12 chars yields one char.
+        (lower.div_ceil(MAX_ESCAPE_LEN).saturating_add(pending.min(1)),
+        upper.and_then(|v| v.checked_add(pending)))
+    }
+}
+
+/// Function json_unescape_iter() takes a mutable reference to a char
+/// iterator over a JSON string body (without the surrounding quotes),
+/// and returns an iterator of the unescaped chars in its place.
+///
+/// \uXXXX surrogate pair escapes are combined.  A malformed escape,
+/// including a lone surrogate, is substituted with a replacement
+/// character, and has_invalid_sequence() would return true after this
+/// event.  A char that ends a malformed escape early is read again as
+/// the start of the next char.  For UTF8 input, decode it first, for
+/// example with decode_lossy().
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut chars = decode_lossy(b"a\\n\\ud83d\\ude00\\x");
+/// let mut iter = json_unescape_iter(& mut chars);
+/// let text: String = iter.by_ref().collect();
+/// assert_eq!("a\n\u{1F600}\u{FFFD}x", text);
+/// assert!(iter.has_invalid_sequence());
+/// ```
+///
+/// # Arguments
+///
+/// * `input` - a mutable reference to a char iterator
+#[inline]
+pub fn json_unescape_iter<'a, I>(input: &'a mut I)
+-> JsonUnescapeStruct<'a, I>
+where I: Iterator<Item = char> + ?Sized, {
+    JsonUnescapeStruct {
+        my_back: ['\0'; 2],
+        my_back_len: 0,
+        my_code: Option::None,
+        my_ready: Option::None,
+        my_invalid_sequence: false,
+        my_borrow_mut_iter: input,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::string::String;
+    use std::vec::Vec;
+
+    // Escape a string into a JSON string body.
+    fn escape(text: &str, ascii_only: bool) -> String {
+        let mut chars = text.chars();
+        let body: Vec<u8> = json_escape_iter(& mut chars, ascii_only).collect();
+        String::from_utf8(body).unwrap()
+    }
+
+    #[test]
+    // Test escapes of quotes, backslashes and control characters
+    fn test_json_escape_controls() {
+        assert_eq!("a\\\"b\\\\c/", escape("a\"b\\c/", false));
+        assert_eq!("\\b\\f\\n\\r\\t", escape("\u{8}\u{C}\n\r\t", false));
+        assert_eq!("\\u0000\\u001f\u{7F} ", escape("\u{0}\u{1F}\u{7F} ", false));
+        assert_eq!("", escape("", true));
+    }
+
+    #[test]
+    // Test ASCII-only output against char::encode_utf16
+    fn test_json_escape_ascii_only() {
+        let text = "A\u{E9}\u{7FF}\u{FFFF}\u{10000}\u{10FFFF}";
+        assert_eq!(text, escape(text, false));
+        let mut truth = String::from("A");
+        for unit in text.encode_utf16().skip(1) {
+            truth.push_str(& std::format!("\\u{:04x}", unit));
+        }
+        assert_eq!(truth, escape(text, true));
+        let mut chars = text.chars();
+        let iter = json_escape_iter(& mut chars, true);
+        let (lower, upper) = iter.size_hint();
+        assert!(lower <= truth.len() && Option::Some(truth.len()) <= upper);
+        assert_eq!(truth.len(), iter.count());
+    }
+
+    // Unescape a JSON string body, returning the invalid escape flag.
+    fn unescape(body: &str) -> (String, bool) {
+        let mut chars = body.chars();
+        let mut iter = json_unescape_iter(& mut chars);
+        let text: String = iter.by_ref().collect();
+        (text, iter.has_invalid_sequence())
+    }
+
+    #[test]
+    // Test unescaping against the escaping adapter
+    fn test_json_unescape_round_trip() {
+        let text = "q\"b\\s/\u{8}\u{C}\n\r\t\u{0}\u{1F}\u{E9}\u{FFFF}\u{10000}\u{10FFFF}";
+        for ascii_only in [false, true].iter() {
+            assert_eq!((String::from(text), false), unescape(& escape(text, * ascii_only)));
+        }
+        assert_eq!((String::from("/\u{ABCD}"), false), unescape("\\/\\uABcd"));
+    }
+
+    #[test]
+    // Test replacement of malformed escapes
+    fn test_json_unescape_malformed() {
+        let cases = [
+            ("\\", "\u{FFFD}"),
+            ("\\x", "\u{FFFD}x"),
+            ("\\u12", "\u{FFFD}"),
+            ("\\u12G4", "\u{FFFD}G4"),
+            ("\\udc00a", "\u{FFFD}a"),
+            ("\\ud800a", "\u{FFFD}a"),
+            ("\\ud800\\n", "\u{FFFD}\n"),
+            ("\\ud800\\u0041", "\u{FFFD}A"),
+            ("\\ud800\\ud800\\udc00", "\u{FFFD}\u{10000}"),
+            ("\\ud800\\uzz", "\u{FFFD}\u{FFFD}zz"),
+            ("\\ud800", "\u{FFFD}"),
+        ];
+        for (body, truth) in cases.iter() {
+            assert_eq!((String::from(* truth), true), unescape(body), "body {:?}", body);
+        }
+    }
+}
@@ -0,0 +1,360 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::latin1
+
+use core::fmt;
+
+use crate::utf8conv::format_into;
+use crate::utf8conv::Encoder;
+use crate::utf8conv::MoreEnum;
+use crate::utf8conv::UtfParserCommon;
+
+/// longest numeric escape: "&#1114111;"
+const MAX_ESCAPE_LEN: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// Single byte character sets of Latin1Encoder
+pub enum SingleByteCharset {
+
+    /// US-ASCII, codepoints 0 to 0x7F
+    Ascii,
+
+    /// ISO 8859-1, codepoints 0 to 0xFF
+    Latin1,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// What Latin1Encoder does with a char outside its character set
+pub enum UnencodablePolicy {
+
+    /// output this byte instead, such as b'?'
+    Replace(u8),
+
+    /// stop before the char, and report an UnencodableError
+    Error,
+
+    /// output a decimal character reference, such as "&#8364;"
+    NumericEscape,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// A char that Latin1Encoder could not encode with UnencodablePolicy::Error
+pub struct UnencodableError {
+
+    /// the char
+    pub ch: char,
+
+    /// number of chars before it in the stream
+    pub index: u64,
+}
+
+/// Display for UnencodableError
+impl fmt::Display for UnencodableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unencodable char U+{:04X} at char {}", self.ch as u32, self.index)
+    }
+}
+
+/// Error for UnencodableError
+impl core::error::Error for UnencodableError {}
+
+/// Implementation of UnencodableError
+impl UnencodableError {
+
+    /// Write the Display message into `buf` without allocation, and
+    /// return it.  A message too long for the buffer is cut short.
+    /// # Arguments
+    /// * `buf` - the output buffer
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        format_into(self, buf)
+    }
+}
+
+/// Encoder from chars to single byte ASCII or Latin1 output, for legacy
+/// devices.
+///
+/// Chars outside the character set are handled by an UnencodablePolicy;
+/// has_invalid_sequence() returns true after any of them.  With
+/// UnencodablePolicy::Error, the encoder stops before the char, as at end
+/// of data, and error() returns it.  The char is left at the front of the
+/// remaining input; reset_invalid_sequence() clears the error.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let text = ['c', 'a', 'f', '\u{E9}', ' ', '\u{20AC}'];
+/// let mut encoder = Latin1Encoder::new(SingleByteCharset::Latin1, UnencodablePolicy::NumericEscape);
+/// let mut byte_box = [0u8; 16];
+/// let (_rest, len) = encoder.encode_to_slice(& text, & mut byte_box).unwrap();
+/// assert_eq!(b"caf\xE9 &#8364;", & byte_box[.. len]);
+///
+/// let mut encoder = Latin1Encoder::new(SingleByteCharset::Ascii, UnencodablePolicy::Error);
+/// let (rest, len) = encoder.encode_to_slice(& text, & mut byte_box).unwrap();
+/// assert_eq!(b"caf", & byte_box[.. len]);
+/// assert_eq!(& text[3 ..], rest);
+/// assert_eq!(Some(UnencodableError { ch: '\u{E9}', index: 3 }), encoder.error());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Latin1Encoder {
+
+    /// the output character set
+    my_charset: SingleByteCharset,
+
+    /// handling of chars outside the character set
+    my_policy: UnencodablePolicy,
+
+    /// pending bytes of a numeric escape
+    my_escape: [u8; MAX_ESCAPE_LEN],
+
+    /// position of the next pending byte in my_escape
+    my_escape_pos: usize,
+
+    /// number of bytes in my_escape
+    my_escape_len: usize,
+
+    /// number of chars taken from the input
+    my_chars_in: u64,
+
+    /// the char that stopped the encoder
+    my_error: Option<UnencodableError>,
+
+    my_last_buffer: bool,
+    my_invalid_sequence: bool,
+}
+
+/// Implementation of Latin1Encoder
+impl Latin1Encoder {
+
+    /// Make a new Latin1Encoder
+    /// # Arguments
+    /// * `charset` - the output character set
+    /// * `policy` - handling of chars outside the character set
+    pub fn new(charset: SingleByteCharset, policy: UnencodablePolicy) -> Latin1Encoder {
+        Latin1Encoder {
+            my_charset: charset,
+            my_policy: policy,
+            my_escape: [0u8; MAX_ESCAPE_LEN],
+            my_escape_pos: 0,
+            my_escape_len: 0,
+            my_chars_in: 0,
+            my_error: Option::None,
+            my_last_buffer: true,
+            my_invalid_sequence: false,
+        }
+    }
+
+    #[inline]
+    /// Returns the char that stopped the encoder with
+    /// UnencodablePolicy::Error.
+    pub fn error(&self) -> Option<UnencodableError> {
+        self.my_error
+    }
+
+    /// Fill my_escape with the decimal character reference of `ch`.
+    fn set_escape(&mut self, ch: char) {
+        let mut digits = [0u8; 7];
+        let mut num_digits: usize = 0;
+        let mut code = ch as u32;
+        loop {
+            digits[num_digits] = b'0' + (code % 10) as u8;
+            num_digits += 1;
+            code /= 10;
+            if code == 0 {
+                break;
+            }
+        }
+        self.my_escape[0] = b'&';
+        self.my_escape[1] = b'#';
+        for (slot, digit) in self.my_escape[2 ..].iter_mut().zip(digits[.. num_digits].iter().rev()) {
+            * slot = * digit;
+        }
+        self.my_escape[2 + num_digits] = b';';
+        self.my_escape_pos = 0;
+        self.my_escape_len = num_digits + 3;
+    }
+
+    /// An encoder takes in char slice, and returns a Result object with
+    /// either the remaining input and the output byte value, or an
+    /// MoreEnum that requests additional data, or an end of data stream
+    /// condition.
+    ///
+    /// Chars outside the character set are handled by the
+    /// UnencodablePolicy.
+    pub fn char_to_byte<'b>(&mut self, input: &'b [char])
+    -> Result<(&'b [char], u8), MoreEnum> {
+        if self.my_escape_pos < self.my_escape_len {
+            let v = self.my_escape[self.my_escape_pos];
+            self.my_escape_pos += 1;
+            return Result::Ok((input, v));
+        }
+        if self.my_error.is_some() {
+            return Result::Err(MoreEnum::More(0));
+        }
+        let (ch, rest) = match input.split_first() {
+            Option::Some((ch, rest)) => (* ch, rest),
+            Option::None => {
+                if self.my_last_buffer {
+                    // at end of data condition
+                    return Result::Err(MoreEnum::More(0));
+                }
+                // Returning an indication to request a new buffer.
+                return Result::Err(MoreEnum::More(1024));
+            }
+        };
+        let limit: u32 = match self.my_charset {
+            SingleByteCharset::Ascii => 0x7F,
+            SingleByteCharset::Latin1 => 0xFF,
+        };
+        if (ch as u32) <= limit {
+            self.my_chars_in += 1;
+            return Result::Ok((rest, ch as u8));
+        }
+        self.signal_invalid_sequence();
+        match self.my_policy {
+            UnencodablePolicy::Replace(v) => {
+                self.my_chars_in += 1;
+                Result::Ok((rest, v))
+            }
+            UnencodablePolicy::NumericEscape => {
+                self.my_chars_in += 1;
+                self.set_escape(ch);
+                self.my_escape_pos = 1;
+                Result::Ok((rest, self.my_escape[0]))
+            }
+            UnencodablePolicy::Error => {
+                self.my_error = Option::Some(UnencodableError {
+                    ch,
+                    index: self.my_chars_in,
+                });
+                Result::Err(MoreEnum::More(0))
+            }
+        }
+    }
+}
+
+/// Implementations of common operations for Latin1Encoder
+impl UtfParserCommon for Latin1Encoder {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_last_buffer = b;
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_last_buffer
+    }
+
+    #[inline]
+    /// This function returns true if an unencodable char occurred in this
+    /// stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_invalid_sequence
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an unencodable char.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_invalid_sequence = true;
+    }
+
+    #[inline]
+    /// This function resets the invalid sequence state, and clears the
+    /// error.
+    fn reset_invalid_sequence(&mut self) {
+        self.my_invalid_sequence = false;
+        self.my_error = Option::None;
+    }
+
+    #[inline]
+    /// Reset all encoder states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid sequence indication is cleared.
+    /// The character set and the policy are kept.
+    fn reset_parser(&mut self) {
+        * self = Latin1Encoder::new(self.my_charset, self.my_policy);
+    }
+}
+
+/// Encoder implementation of Latin1Encoder, producing single bytes
+impl Encoder for Latin1Encoder {
+    type Unit = u8;
+
+    #[inline]
+    /// Same as char_to_byte()
+    fn encode_char<'b>(&mut self, input: &'b [char])
+    -> Result<(&'b [char], u8), MoreEnum> {
+        self.char_to_byte(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::vec::Vec;
+
+    /// Encode `text` in two buffers split at `split`.
+    fn encode_split(encoder: &mut Latin1Encoder, text: &[char], split: usize) -> Vec<u8> {
+        let mut output = Vec::new();
+        let (head, tail) = text.split_at(split);
+        for (indx, buf) in [head, tail].iter().enumerate() {
+            encoder.set_is_last_buffer(indx == 1);
+            let mut cur_slice = * buf;
+            let mut byte_box = [0u8; 3];
+            // The loop stops at MoreEnum::More(_amt);
+            // _amt equals to 0 when end of data
+            while let Result::Ok((slice_pos, len)) = encoder.encode_to_slice(cur_slice, & mut byte_box) {
+                cur_slice = slice_pos;
+                output.extend_from_slice(& byte_box[.. len]);
+            }
+        }
+        output
+    }
+
+    #[test]
+    // Test each policy and character set over buffer splits
+    fn test_latin1_encoder_policies() {
+        let text: Vec<char> = "A\u{7F}\u{80}\u{FF}\u{100}\u{10FFFF}z".chars().collect();
+        let cases = [
+            (SingleByteCharset::Latin1, UnencodablePolicy::Replace(b'?'), &b"A\x7F\x80\xFF??z"[..]),
+            (SingleByteCharset::Ascii, UnencodablePolicy::Replace(b'?'), &b"A\x7F????z"[..]),
+            (SingleByteCharset::Latin1, UnencodablePolicy::NumericEscape, &b"A\x7F\x80\xFF&#256;&#1114111;z"[..]),
+            (SingleByteCharset::Ascii, UnencodablePolicy::Error, &b"A\x7F"[..]),
+        ];
+        for (charset, policy, truth) in cases.iter().copied() {
+            for split in 0 ..= text.len() {
+                let mut encoder = Latin1Encoder::new(charset, policy);
+                assert_eq!(truth, & encode_split(& mut encoder, & text, split)[..]);
+                assert!(encoder.has_invalid_sequence());
+            }
+        }
+        let mut encoder = Latin1Encoder::new(SingleByteCharset::Ascii, UnencodablePolicy::Error);
+        assert_eq!(Result::Ok((& text[1 ..], b'A')), encoder.char_to_byte(& text));
+        assert_eq!(Result::Ok((& text[2 ..], 0x7Fu8)), encoder.char_to_byte(& text[1 ..]));
+        assert_eq!(Result::Err(MoreEnum::More(0)), encoder.char_to_byte(& text[2 ..]));
+        let error = encoder.error().unwrap();
+        assert_eq!(UnencodableError { ch: '\u{80}', index: 2 }, error);
+        let mut buf = [0u8; 40];
+        assert_eq!("unencodable char U+0080 at char 2", error.format_into(& mut buf));
+        // Clearing the error resumes with the input after the char.
+        encoder.reset_invalid_sequence();
+        assert_eq!(Option::None, encoder.error());
+        assert_eq!(Result::Ok((& text[7 ..], b'z')), encoder.char_to_byte(& text[6 ..]));
+        assert!(! encoder.has_invalid_sequence());
+    }
+}
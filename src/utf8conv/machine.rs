@@ -0,0 +1,230 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::machine
+
+use crate::utf8conv::buf::EightBytes;
+use crate::utf8conv::bad_decode_kind;
+use crate::utf8conv::utf8_decode;
+use crate::utf8conv::Utf8EndEnum;
+use crate::utf8conv::Utf8ErrorKind;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// Result of presenting one byte to an Utf8ByteMachine.
+pub enum MachineStatus {
+
+    /// no result available; the machine needs another byte
+    NeedMore,
+
+    /// a decoded codepoint
+    Char(u32),
+
+    /// an invalid sequence of the given kind and length in bytes
+    Error(Utf8ErrorKind, u32),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+/// A byte at a time UTF8 decoder, for event driven environments such as
+/// interrupt handlers, without any iterator or slice plumbing.
+///
+/// An invalid sequence is reported as soon as the byte that breaks it
+/// is pushed.  That byte may start a new sequence; it is kept in the
+/// machine, and poll() returns any further result without new input.
+/// Call poll() after every status other than MachineStatus::NeedMore.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut machine = Utf8ByteMachine::new();
+/// let mut codes = Vec::new();
+/// for b in [0xE2u8, 0x41u8, 0xE2u8, 0x82u8, 0xACu8] {
+///     let mut status = machine.push(b);
+///     while status != MachineStatus::NeedMore {
+///         codes.push(status);
+///         status = machine.poll();
+///     }
+/// }
+/// assert_eq!(MachineStatus::NeedMore, machine.finish());
+/// assert_eq!(codes, [
+///     MachineStatus::Error(Utf8ErrorKind::InvalidContinuation, 1),
+///     MachineStatus::Char(0x41),
+///     MachineStatus::Char(0x20AC),
+/// ]);
+/// ```
+pub struct Utf8ByteMachine {
+
+    /// bytes of the sequence being decoded
+    my_buf: EightBytes,
+}
+
+/// Implementation of Utf8ByteMachine
+impl Utf8ByteMachine {
+
+    /// Make a new Utf8ByteMachine
+    pub fn new() -> Utf8ByteMachine {
+        Utf8ByteMachine {
+            my_buf: EightBytes::new(),
+        }
+    }
+
+    #[inline]
+    /// Present the next byte, and return the first result it completes.
+    /// # Arguments
+    /// * `b` - the next byte of the UTF8 stream
+    pub fn push(&mut self, b: u8) -> MachineStatus {
+        self.my_buf.push_back(b);
+        self.poll()
+    }
+
+    /// Return the next result from bytes already held in the machine,
+    /// or MachineStatus::NeedMore when they are a valid but incomplete
+    /// sequence.
+    pub fn poll(&mut self) -> MachineStatus {
+        let lead = match self.my_buf.front() {
+            Option::Some(v) => v,
+            Option::None => {
+                return MachineStatus::NeedMore;
+            }
+        };
+        // Decode a copy as if no more data would follow.  A valid prefix
+        // is reported as TypeUnknown, and is kept for the next byte.
+        let mut trial = self.my_buf;
+        match utf8_decode(& mut trial, true) {
+            Utf8EndEnum::Finish(code) => {
+                self.my_buf = trial;
+                MachineStatus::Char(code)
+            }
+            Utf8EndEnum::BadDecode(len) => {
+                self.my_buf = trial;
+                MachineStatus::Error(bad_decode_kind(lead, len), len)
+            }
+            Utf8EndEnum::TypeUnknown => {
+                MachineStatus::NeedMore
+            }
+        }
+    }
+
+    /// Signal end of data, and return the next remaining result.
+    /// An incomplete sequence is reported as Utf8ErrorKind::Truncated.
+    ///
+    /// Returns MachineStatus::NeedMore once the machine is empty; the
+    /// machine is then ready for a new stream.
+    pub fn finish(&mut self) -> MachineStatus {
+        match self.poll() {
+            MachineStatus::NeedMore => {
+                let len = self.my_buf.len();
+                if len == 0 {
+                    MachineStatus::NeedMore
+                }
+                else {
+                    self.my_buf.clear();
+                    MachineStatus::Error(Utf8ErrorKind::Truncated, len)
+                }
+            }
+            status => status,
+        }
+    }
+
+    #[inline]
+    /// Discard any incomplete sequence.
+    pub fn reset(&mut self) {
+        self.my_buf.clear();
+    }
+
+    #[inline]
+    /// Number of bytes held in the machine.
+    pub fn pending_len(&self) -> u32 {
+        self.my_buf.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::string::String;
+    use std::vec::Vec;
+
+    // Decode with the byte machine, replacing each error with a
+    // replacement character.
+    fn machine_decode(bytes: & [u8]) -> (String, Vec<MachineStatus>) {
+        let mut machine = Utf8ByteMachine::new();
+        let mut output = String::new();
+        let mut statuses = Vec::new();
+        let mut record = |status: MachineStatus| {
+            match status {
+                MachineStatus::Char(code) => {
+                    output.push(char::from_u32(code).unwrap());
+                }
+                MachineStatus::Error(_kind, _len) => {
+                    output.push(char::REPLACEMENT_CHARACTER);
+                }
+                MachineStatus::NeedMore => {}
+            }
+            statuses.push(status);
+        };
+        for b in bytes {
+            let mut status = machine.push(* b);
+            while status != MachineStatus::NeedMore {
+                record(status);
+                status = machine.poll();
+            }
+        }
+        loop {
+            let status = machine.finish();
+            if status == MachineStatus::NeedMore {
+                break;
+            }
+            record(status);
+        }
+        assert_eq!(0, machine.pending_len());
+        (output, statuses)
+    }
+
+    #[test]
+    // Test error kinds and lengths
+    fn test_machine_error_kinds() {
+        let (_, statuses) = machine_decode(& [0x80u8, 0xC0u8, 0xF5u8]);
+        assert_eq!(statuses, [MachineStatus::Error(Utf8ErrorKind::InvalidLead, 1); 3]);
+        let (_, statuses) = machine_decode(& [0xF0u8, 0x90u8, 0x80u8, 0x41u8]);
+        assert_eq!(statuses, [
+            MachineStatus::Error(Utf8ErrorKind::InvalidContinuation, 3),
+            MachineStatus::Char(0x41),
+        ]);
+        let (_, statuses) = machine_decode(& [0xEDu8, 0xA0u8, 0x80u8]);
+        assert_eq!(statuses[0], MachineStatus::Error(Utf8ErrorKind::InvalidContinuation, 1));
+        let (_, statuses) = machine_decode(& [0xEFu8, 0xBFu8, 0xBDu8]);
+        assert_eq!(statuses, [MachineStatus::Error(Utf8ErrorKind::ReplacementCharacter, 3)]);
+        let (_, statuses) = machine_decode(& [0x41u8, 0xF4u8, 0x8Fu8]);
+        assert_eq!(statuses, [
+            MachineStatus::Char(0x41),
+            MachineStatus::Error(Utf8ErrorKind::Truncated, 2),
+        ]);
+        assert_eq!("truncated UTF8 sequence", std::format!("{}", Utf8ErrorKind::Truncated));
+    }
+
+    #[test]
+    // Test the machine against the lossy conversion of the standard library
+    fn test_machine_matches_lossy() {
+        let samples: [& [u8]; 6] = [
+            "Hello \u{20AC} \u{10348} \u{7FF}".as_bytes(),
+            & [0xC2u8, 0x41u8, 0xE0u8, 0x80u8, 0xAFu8, 0xF0u8, 0x80u8, 0x80u8],
+            & [0xE1u8, 0x80u8, 0xE2u8, 0xF0u8, 0x91u8, 0x92u8, 0xF1u8, 0xBFu8, 0x41u8],
+            & [0xF4u8, 0x90u8, 0x80u8, 0x80u8, 0xFFu8, 0xFEu8],
+            & [0xE0u8, 0xA0u8, 0xBFu8, 0xC2u8],
+            & [],
+        ];
+        for bytes in samples.iter() {
+            let (output, _) = machine_decode(bytes);
+            assert_eq!(String::from_utf8_lossy(bytes), output);
+        }
+    }
+}
@@ -0,0 +1,332 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::offsets
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::utf8conv::encode_char_to_buf;
+use crate::utf8conv::format_into;
+use crate::utf8conv::valid::ValidUtf8;
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::UtfParserCommon;
+
+/// number of chars decoded at a time
+const CHAR_BOX_SIZE:usize = 32;
+
+/// An offset type of a string column: i32 for Arrow Utf8, or i64 for
+/// Arrow LargeUtf8.
+pub trait ArrowOffset: Copy {
+
+    /// Convert a byte offset, returning 'None' when it does not fit.
+    fn from_usize(v: usize) -> Option<Self>;
+}
+
+/// ArrowOffset for i32 offsets
+impl ArrowOffset for i32 {
+    #[inline]
+    fn from_usize(v: usize) -> Option<Self> {
+        i32::try_from(v).ok()
+    }
+}
+
+/// ArrowOffset for i64 offsets
+impl ArrowOffset for i64 {
+    #[inline]
+    fn from_usize(v: usize) -> Option<Self> {
+        i64::try_from(v).ok()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// Reason an ArrowOffsetsBuilder could not take a record
+pub enum OffsetsError {
+
+    /// the values slice is too small for the record
+    ValuesFull,
+
+    /// the offsets slice has no room for another offset
+    OffsetsFull,
+
+    /// the end offset of the record does not fit the offset type
+    OffsetOverflow,
+}
+
+/// Display for OffsetsError
+impl fmt::Display for OffsetsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            OffsetsError::ValuesFull => "values buffer is full",
+            OffsetsError::OffsetsFull => "offsets buffer is full",
+            OffsetsError::OffsetOverflow => "offset does not fit the offset type",
+        };
+        f.write_str(msg)
+    }
+}
+
+/// Error for OffsetsError
+impl core::error::Error for OffsetsError {}
+
+/// Implementation of OffsetsError
+impl OffsetsError {
+
+    /// Write the Display message into `buf` without allocation, and
+    /// return it.  A message too long for the buffer is cut short.
+    /// # Arguments
+    /// * `buf` - the output buffer
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        format_into(self, buf)
+    }
+}
+
+/// Builds the values and offsets buffers of an Arrow style string
+/// column in one pass.
+///
+/// Each record is decoded as a complete UTF8 stream; invalid sequences
+/// are replaced with replacement characters, so the values buffer is
+/// always valid UTF8.  The offsets buffer receives 0, then the end
+/// offset of every record, so record n spans
+/// `offsets[n] .. offsets[n + 1]`.
+///
+/// A record that does not fit is not written, and the builder can
+/// continue with other records.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut values = [0u8; 16];
+/// let mut offsets = [0i32; 4];
+/// let mut builder = ArrowOffsetsBuilder::new(& mut values, & mut offsets);
+/// builder.push_record(b"ab").unwrap();
+/// builder.push_record(& [0xE2u8, 0x82u8, 0xACu8, 0xFFu8]).unwrap();
+/// builder.push_record(b"").unwrap();
+/// assert!(builder.has_invalid_sequence());
+/// assert_eq!((8, 4), builder.finish().unwrap());
+/// assert_eq!(& values[0 .. 8], "ab\u{20AC}\u{FFFD}".as_bytes());
+/// assert_eq!(offsets, [0, 2, 8, 8]);
+/// ```
+pub struct ArrowOffsetsBuilder<'v, O: ArrowOffset> {
+
+    /// output UTF8 bytes
+    my_values: &'v mut [u8],
+
+    /// output offsets
+    my_offsets: &'v mut [O],
+
+    /// number of value bytes written
+    my_values_len: usize,
+
+    /// number of records written
+    my_count: usize,
+
+    /// true after an invalid sequence in any record
+    my_invalid_sequence: bool,
+}
+
+/// Implementation of ArrowOffsetsBuilder
+impl<'v, O: ArrowOffset> ArrowOffsetsBuilder<'v, O> {
+
+    /// Make a new ArrowOffsetsBuilder writing into `values` and `offsets`.
+    /// # Arguments
+    /// * `values` - output buffer of UTF8 bytes
+    /// * `offsets` - output buffer of offsets; one more than the
+    ///   number of records is needed
+    pub fn new(values: &'v mut [u8], offsets: &'v mut [O]) -> ArrowOffsetsBuilder<'v, O> {
+        ArrowOffsetsBuilder {
+            my_values: values,
+            my_offsets: offsets,
+            my_values_len: 0,
+            my_count: 0,
+            my_invalid_sequence: false,
+        }
+    }
+
+    /// Decode one record, append it to the values buffer, and record
+    /// its end offset.
+    /// # Arguments
+    /// * `record` - the UTF8 bytes of the record
+    pub fn push_record(&mut self, record: &[u8]) -> Result<(), OffsetsError> {
+        if self.my_offsets.len() < self.my_count + 2 {
+            return Result::Err(OffsetsError::OffsetsFull);
+        }
+        let mut parser = FromUtf8::new();
+        let mut char_box = ['\0'; CHAR_BOX_SIZE];
+        let mut encode_buf = [0u8; 4];
+        let mut pos = self.my_values_len;
+        let mut cur_slice = record;
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, num_chars)) = parser.utf8_to_char_slice(cur_slice, & mut char_box) {
+            cur_slice = slice_pos;
+            for ch in char_box.iter().take(num_chars) {
+                let encoded = encode_char_to_buf(* ch, & mut encode_buf).as_bytes();
+                let end = pos + encoded.len();
+                match self.my_values.get_mut(pos .. end) {
+                    Option::Some(dest) => {
+                        dest.copy_from_slice(encoded);
+                    }
+                    Option::None => {
+                        return Result::Err(OffsetsError::ValuesFull);
+                    }
+                }
+                pos = end;
+            }
+        }
+        let end_offset = O::from_usize(pos).ok_or(OffsetsError::OffsetOverflow)?;
+        let zero = O::from_usize(0).ok_or(OffsetsError::OffsetOverflow)?;
+        // Offsets are only written once the record is known to fit.
+        if let Option::Some(slots) = self.my_offsets.get_mut(self.my_count .. self.my_count + 2) {
+            if self.my_count == 0 {
+                slots[0] = zero;
+            }
+            slots[1] = end_offset;
+        }
+        self.my_values_len = pos;
+        self.my_count += 1;
+        self.my_invalid_sequence |= parser.has_invalid_sequence();
+        Result::Ok(())
+    }
+
+    /// Append a record that is already validated to the values buffer
+    /// without decoding it, and record its end offset.
+    /// # Arguments
+    /// * `record` - the UTF8 bytes of the record
+    pub fn push_valid_record(&mut self, record: ValidUtf8<'_>) -> Result<(), OffsetsError> {
+        if self.my_offsets.len() < self.my_count + 2 {
+            return Result::Err(OffsetsError::OffsetsFull);
+        }
+        let pos = self.my_values_len;
+        let end = pos + record.len();
+        let end_offset = O::from_usize(end).ok_or(OffsetsError::OffsetOverflow)?;
+        let zero = O::from_usize(0).ok_or(OffsetsError::OffsetOverflow)?;
+        match self.my_values.get_mut(pos .. end) {
+            Option::Some(dest) => {
+                dest.copy_from_slice(record.as_bytes());
+            }
+            Option::None => {
+                return Result::Err(OffsetsError::ValuesFull);
+            }
+        }
+        if let Option::Some(slots) = self.my_offsets.get_mut(self.my_count .. self.my_count + 2) {
+            if self.my_count == 0 {
+                slots[0] = zero;
+            }
+            slots[1] = end_offset;
+        }
+        self.my_values_len = end;
+        self.my_count += 1;
+        Result::Ok(())
+    }
+
+    #[inline]
+    /// Number of records written
+    pub fn len(&self) -> usize {
+        self.my_count
+    }
+
+    #[inline]
+    /// Returns true when no record has been written
+    pub fn is_empty(&self) -> bool {
+        self.my_count == 0
+    }
+
+    #[inline]
+    /// Returns true if an invalid sequence occurred in any record written.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_invalid_sequence
+    }
+
+    /// Complete the column, and return the number of value bytes and
+    /// the number of offsets written.
+    pub fn finish(self) -> Result<(usize, usize), OffsetsError> {
+        if self.my_count == 0 {
+            // An empty column still has its starting offset.
+            let zero = O::from_usize(0).ok_or(OffsetsError::OffsetOverflow)?;
+            match self.my_offsets.first_mut() {
+                Option::Some(slot) => {
+                    * slot = zero;
+                }
+                Option::None => {
+                    return Result::Err(OffsetsError::OffsetsFull);
+                }
+            }
+        }
+        Result::Ok((self.my_values_len, self.my_count + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    #[test]
+    // Test records that do not fit
+    fn test_offsets_builder_full() {
+        let mut values = [0u8; 5];
+        let mut offsets = [-1i64; 3];
+        let mut builder = ArrowOffsetsBuilder::new(& mut values, & mut offsets);
+        assert_eq!(Result::Ok(()), builder.push_record(b"abc"));
+        // A replacement character needs 3 bytes.
+        assert_eq!(Result::Err(OffsetsError::ValuesFull), builder.push_record(& [0x80u8]));
+        assert!(! builder.has_invalid_sequence());
+        assert_eq!(Result::Ok(()), builder.push_record(b"de"));
+        assert_eq!(Result::Err(OffsetsError::OffsetsFull), builder.push_record(b""));
+        assert_eq!(2, builder.len());
+        assert_eq!(Result::Ok((5, 3)), builder.finish());
+        assert_eq!(b"abcde", & values);
+        assert_eq!([0i64, 3, 5], offsets);
+
+        let mut offsets: [i32; 0] = [];
+        let builder = ArrowOffsetsBuilder::new(& mut values, & mut offsets);
+        assert!(builder.is_empty());
+        assert_eq!(Result::Err(OffsetsError::OffsetsFull), builder.finish());
+        let mut offsets = [0i32; 3];
+        let mut builder = ArrowOffsetsBuilder::new(& mut values, & mut offsets);
+        let record = ValidUtf8::new("\u{20AC}".as_bytes()).unwrap();
+        assert_eq!(Result::Ok(()), builder.push_valid_record(record));
+        assert_eq!(Result::Err(OffsetsError::ValuesFull), builder.push_valid_record(record));
+        assert_eq!(Result::Ok((3, 2)), builder.finish());
+        assert_eq!([0i32, 3, 0], offsets);
+        let mut offsets = [7i32; 1];
+        let builder = ArrowOffsetsBuilder::new(& mut values, & mut offsets);
+        assert_eq!(Result::Ok((0, 1)), builder.finish());
+        assert_eq!([0i32], offsets);
+        let mut buf = [0u8; 16];
+        assert_eq!("offsets buffer i", OffsetsError::OffsetsFull.format_into(& mut buf));
+    }
+
+    #[test]
+    // Test sanitized records against the lossy conversion of the standard library
+    fn test_offsets_builder_matches_lossy() {
+        let records: [& [u8]; 5] = [
+            "plain".as_bytes(),
+            & [0xF0u8, 0x90u8, 0x8Du8, 0x88u8, 0xC2u8],
+            & [],
+            & [0xEDu8, 0xA0u8, 0x80u8, 0x41u8, 0xE2u8, 0x82u8],
+            "\u{7FF}\u{FFFF}".as_bytes(),
+        ];
+        let mut values = [0u8; 64];
+        let mut offsets = [0i32; 6];
+        let mut builder = ArrowOffsetsBuilder::new(& mut values, & mut offsets);
+        for record in records.iter() {
+            builder.push_record(record).unwrap();
+        }
+        let (values_len, offsets_len) = builder.finish().unwrap();
+        assert_eq!(6, offsets_len);
+        let column = core::str::from_utf8(& values[0 .. values_len]).unwrap();
+        for (indx, record) in records.iter().enumerate() {
+            let start = offsets[indx] as usize;
+            let end = offsets[indx + 1] as usize;
+            assert_eq!(std::string::String::from_utf8_lossy(record), & column[start .. end]);
+        }
+    }
+}
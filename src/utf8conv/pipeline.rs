@@ -0,0 +1,204 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::pipeline
+
+use std::io;
+use std::io::Read;
+use std::sync::mpsc::sync_channel;
+use std::sync::mpsc::Receiver;
+use std::thread;
+use std::thread::JoinHandle;
+use std::vec::Vec;
+
+use crate::utf8conv::encode_char_to_buf;
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::UtfParserCommon;
+
+/// number of bytes read at a time by the read stage
+const PIPE_CHUNK_SIZE: usize = 4096;
+
+/// number of chars decoded at a time by the decode stages
+const PIPE_CHAR_BOX_SIZE: usize = 1024;
+
+/// Spawn a thread reading `reader` into a bounded channel of byte
+/// chunks.  The channel is closed at end of data or on a read error.
+///
+/// The thread returns the number of bytes read, or the read error.
+/// It also stops when the receiver is dropped.
+/// # Arguments
+/// * `reader` - the input
+/// * `capacity` - the number of chunks the channel holds
+pub fn spawn_read_stage<R>(reader: R, capacity: usize)
+-> (Receiver<Vec<u8>>, JoinHandle<io::Result<u64>>)
+where R: Read + Send + 'static, {
+    let (sender, receiver) = sync_channel(capacity);
+    let handle = thread::spawn(move || {
+        let mut reader = reader;
+        let mut total:u64 = 0;
+        loop {
+            let mut chunk = vec![0u8; PIPE_CHUNK_SIZE];
+            let len = match reader.read(& mut chunk) {
+                Result::Ok(0) => {
+                    return Result::Ok(total);
+                }
+                Result::Ok(len) => len,
+                Result::Err(er) if er.kind() == io::ErrorKind::Interrupted => {
+                    continue;
+                }
+                Result::Err(er) => {
+                    return Result::Err(er);
+                }
+            };
+            chunk.truncate(len);
+            total += len as u64;
+            if sender.send(chunk).is_err() {
+                return Result::Ok(total);
+            }
+        }
+    });
+    (receiver, handle)
+}
+
+/// Decode UTF8 chunks from `input` until it closes, handing each decoded
+/// chunk of chars to `emit`.  Returns true if an invalid sequence
+/// occurred, or 'None' if `emit` asked to stop.
+fn decode_chunks<F>(input: Receiver<Vec<u8>>, mut emit: F) -> Option<bool>
+where F: FnMut(& [char]) -> bool, {
+    let mut parser = FromUtf8::new();
+    let mut char_box = ['\0'; PIPE_CHAR_BOX_SIZE];
+    loop {
+        let chunk = input.recv();
+        // Indicate last buffer when the sender is gone.
+        parser.set_is_last_buffer(chunk.is_err());
+        let bytes = chunk.as_deref().unwrap_or(&[]);
+        let mut cur_slice = bytes;
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, num_chars)) = parser.utf8_to_char_slice(cur_slice, & mut char_box) {
+            cur_slice = slice_pos;
+            if ! emit(& char_box[0 .. num_chars]) {
+                return Option::None;
+            }
+        }
+        if parser.is_last_buffer() {
+            return Option::Some(parser.has_invalid_sequence());
+        }
+    }
+}
+
+/// Spawn a thread decoding the UTF8 chunks of `input` into a bounded
+/// channel of char chunks.  Invalid sequences become replacement
+/// characters.  The output channel is closed after `input` closes and
+/// the last chars are sent.
+///
+/// The thread returns true if an invalid sequence occurred.  It also
+/// stops when the receiver is dropped.
+///
+/// ```rust
+/// use std::sync::mpsc::sync_channel;
+/// use utf8conv::*;
+///
+/// let (sender, receiver) = sync_channel(4);
+/// let (chars, handle) = spawn_decode_stage(receiver, 4);
+/// sender.send(vec![0x61u8, 0xE2u8, 0x82u8]).unwrap();
+/// sender.send(vec![0xACu8, 0xFFu8]).unwrap();
+/// drop(sender);
+/// let text: String = chars.iter().flatten().collect();
+/// assert_eq!("a\u{20AC}\u{FFFD}", text);
+/// assert!(handle.join().unwrap());
+/// ```
+/// # Arguments
+/// * `input` - the channel of UTF8 chunks
+/// * `capacity` - the number of chunks the output channel holds
+pub fn spawn_decode_stage(input: Receiver<Vec<u8>>, capacity: usize)
+-> (Receiver<Vec<char>>, JoinHandle<bool>) {
+    let (sender, receiver) = sync_channel(capacity);
+    let handle = thread::spawn(move || {
+        decode_chunks(input, |chars| sender.send(chars.to_vec()).is_ok()).unwrap_or(false)
+    });
+    (receiver, handle)
+}
+
+/// Spawn a thread sanitizing the UTF8 chunks of `input` into a bounded
+/// channel of valid UTF8 chunks.  Invalid sequences become the bytes of
+/// replacement characters.  The output channel is closed after `input`
+/// closes and the last bytes are sent.
+///
+/// The thread returns true if an invalid sequence occurred.  It also
+/// stops when the receiver is dropped.
+/// # Arguments
+/// * `input` - the channel of UTF8 chunks
+/// * `capacity` - the number of chunks the output channel holds
+pub fn spawn_sanitize_stage(input: Receiver<Vec<u8>>, capacity: usize)
+-> (Receiver<Vec<u8>>, JoinHandle<bool>) {
+    let (sender, receiver) = sync_channel(capacity);
+    let handle = thread::spawn(move || {
+        decode_chunks(input, |chars| {
+            let mut bytes = Vec::with_capacity(chars.len());
+            let mut encode_buf = [0u8; 4];
+            for ch in chars {
+                bytes.extend_from_slice(encode_char_to_buf(* ch, & mut encode_buf).as_bytes());
+            }
+            sender.send(bytes).is_ok()
+        }).unwrap_or(false)
+    });
+    (receiver, handle)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::vec::Vec;
+
+    #[test]
+    // Test a read stage feeding a decode stage against the standard library
+    fn test_read_and_decode_stages() {
+        let mut bytes = Vec::new();
+        for indx in 0 .. 3000u32 {
+            bytes.extend_from_slice("x\u{10348}\u{20AC}".as_bytes());
+            if indx % 700 == 0 {
+                bytes.push(0xF0u8);
+            }
+        }
+        let truth = std::string::String::from_utf8_lossy(& bytes).into_owned();
+        let len = bytes.len() as u64;
+        let (chunks, reader) = spawn_read_stage(std::io::Cursor::new(bytes), 2);
+        let (chars, decoder) = spawn_decode_stage(chunks, 2);
+        let text: std::string::String = chars.iter().flatten().collect();
+        assert_eq!(truth, text);
+        assert_eq!(len, reader.join().unwrap().unwrap());
+        assert!(decoder.join().unwrap());
+    }
+
+    #[test]
+    // Test the sanitize stage, and an input ending in an incomplete sequence
+    fn test_sanitize_stage() {
+        let (sender, receiver) = std::sync::mpsc::sync_channel(1);
+        let (output, handle) = spawn_sanitize_stage(receiver, 1);
+        let feeder = std::thread::spawn(move || {
+            for chunk in [& b"ok\xE2"[..], & b"\x82\xAC"[..], & b"\xC3"[..]].iter() {
+                sender.send(chunk.to_vec()).unwrap();
+            }
+        });
+        let bytes: Vec<u8> = output.iter().flatten().collect();
+        assert_eq!("ok\u{20AC}\u{FFFD}".as_bytes(), & bytes[..]);
+        feeder.join().unwrap();
+        assert!(handle.join().unwrap());
+        // An output receiver that is gone stops the stage.
+        let (sender, receiver) = std::sync::mpsc::sync_channel(1);
+        let (output, handle) = spawn_sanitize_stage(receiver, 1);
+        drop(output);
+        sender.send(b"abc".to_vec()).unwrap();
+        drop(sender);
+        assert!(! handle.join().unwrap());
+    }
+}
@@ -0,0 +1,692 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::recode
+
+use crate::utf8conv::bom::Encoding;
+use crate::utf8conv::bom::SwapDetector;
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::utf16::FromUtf16;
+use crate::utf8conv::MoreEnum;
+use crate::utf8conv::UtfParserCommon;
+use crate::utf8conv::BOM;
+use crate::utf8conv::CR;
+use crate::utf8conv::NL;
+
+/// most output bytes of one char: a UTF32 code unit
+const MAX_CHAR_OUTPUT: usize = 4;
+
+#[derive(Debug, Clone, Copy)]
+/// A converter between Unicode encoding forms, set up with a builder
+/// style API, with the clean ups of convert_text() on the way.
+///
+/// `Recode::from()` selects the input encoding, and to() the output
+/// encoding, UTF8 unless chosen.  strip_bom() and normalize_newlines()
+/// turn on the clean ups.  The converter has a slice front-end,
+/// recode_to_slice(), an iterator front-end, recode_iter(), and with
+/// the "std" feature, RecodeReader and RecodeWriter.
+///
+/// Invalid sequences become replacement characters, and
+/// has_invalid_sequence() returns true after this event.  No byte
+/// order mark is added to the output.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let input = b"\xFF\xFEa\x00\r\x00\n\x00\xAC\x20";
+/// let recode = Recode::from(Encoding::Utf16Le).strip_bom().normalize_newlines();
+/// let bytes: Vec<u8> = recode.recode_iter(input.iter().copied()).collect();
+/// assert_eq!("a\n\u{20AC}".as_bytes(), & bytes[..]);
+/// ```
+pub struct Recode {
+
+    /// the input encoding
+    my_from: Encoding,
+
+    /// the output encoding
+    my_to: Encoding,
+
+    /// true to remove a byte order mark at the start of the text
+    my_strip_bom: bool,
+
+    /// true to replace CR and CR LF line endings with LF
+    my_normalize_newlines: bool,
+
+    /// true to detect UTF16 input in the wrong byte order
+    my_swap_repair: bool,
+
+    /// the byte order detection of the repair mode
+    my_swap: SwapDetector,
+
+    /// the UTF8 parser, for UTF8 input
+    my_utf8: FromUtf8,
+
+    /// bytes of an incomplete UTF16 or UTF32 code unit
+    my_unit_bytes: [u8; 4],
+
+    /// number of bytes in my_unit_bytes
+    my_unit_len: usize,
+
+    /// the UTF16 parser, for UTF16 input
+    my_utf16: FromUtf16,
+
+    /// true before the first char
+    my_start_stream: bool,
+
+    /// true after a carriage return was replaced with a newline
+    my_prev_cr: bool,
+
+    /// encoded bytes of the current char
+    my_out: [u8; MAX_CHAR_OUTPUT],
+
+    /// position of the next byte in my_out
+    my_out_pos: usize,
+
+    /// number of bytes in my_out
+    my_out_len: usize,
+
+    /// true when the input presented will be the last input
+    my_last_buffer: bool,
+
+    /// true after an invalid UTF16 or UTF32 sequence
+    my_invalid_sequence: bool,
+}
+
+/// From an encoding, the first step of the builder
+impl From<Encoding> for Recode {
+
+    /// Make a Recode converting from `encoding` to UTF8, with no clean
+    /// ups turned on.
+    fn from(encoding: Encoding) -> Recode {
+        Recode {
+            my_from: encoding,
+            my_to: Encoding::Utf8,
+            my_strip_bom: false,
+            my_normalize_newlines: false,
+            my_swap_repair: false,
+            my_swap: SwapDetector::default(),
+            my_utf8: FromUtf8::new(),
+            my_unit_bytes: [0; 4],
+            my_unit_len: 0,
+            my_utf16: FromUtf16::new(),
+            my_start_stream: true,
+            my_prev_cr: false,
+            my_out: [0; MAX_CHAR_OUTPUT],
+            my_out_pos: 0,
+            my_out_len: 0,
+            my_last_buffer: true,
+            my_invalid_sequence: false,
+        }
+    }
+}
+
+/// Implementation of Recode
+impl Recode {
+
+    /// Set the output encoding.
+    /// # Arguments
+    /// * `encoding` - the output encoding
+    pub fn to(self, encoding: Encoding) -> Recode {
+        Recode { my_to: encoding, .. self }
+    }
+
+    /// Remove a byte order mark at the start of the text.
+    pub fn strip_bom(self) -> Recode {
+        Recode { my_strip_bom: true, .. self }
+    }
+
+    /// Replace CR and CR LF line endings with LF, with the same rules
+    /// as filter_bom_and_cr_iter().
+    pub fn normalize_newlines(self) -> Recode {
+        Recode { my_normalize_newlines: true, .. self }
+    }
+
+    /// Detect UTF16 input written in the wrong byte order, such as a
+    /// big endian file without a byte order mark read as little endian,
+    /// and swap the bytes of its code units on the fly.
+    ///
+    /// A byte order mark decides the order.  Without one, up to 8 code
+    /// units are held, and vote: an ASCII char in the expected order for
+    /// it, and one with its bytes swapped against it.  The majority
+    /// decides, and a tie keeps the input encoding.  Other input
+    /// encodings are not affected.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let input = [0x4Eu8, 0x2Du8, 0x00u8, 0x41u8];
+    /// let recode = Recode::from(Encoding::Utf16Le).repair_byte_swap();
+    /// let bytes: Vec<u8> = recode.recode_iter(input.iter().copied()).collect();
+    /// assert_eq!("\u{4E2D}A".as_bytes(), & bytes[..]);
+    /// ```
+    pub fn repair_byte_swap(self) -> Recode {
+        Recode { my_swap_repair: true, .. self }
+    }
+
+    #[inline]
+    /// Returns true if the byte swap repair found the UTF16 input to be
+    /// in the wrong byte order.
+    pub fn is_swapped(&self) -> bool {
+        self.my_swap.is_swapped()
+    }
+
+    #[inline]
+    /// Returns the input encoding.
+    pub fn input_encoding(&self) -> Encoding {
+        self.my_from
+    }
+
+    #[inline]
+    /// Returns the output encoding.
+    pub fn output_encoding(&self) -> Encoding {
+        self.my_to
+    }
+
+    /// Take the next code unit of `unit_size` bytes from `input`.
+    /// Returns 'None' when the input runs out first; the bytes taken are
+    /// kept for the next input.
+    fn take_unit(&mut self, input: &mut &[u8], unit_size: usize) -> Option<u32> {
+        while self.my_unit_len < unit_size {
+            let (first, rest) = input.split_first()?;
+            self.my_unit_bytes[self.my_unit_len] = * first;
+            self.my_unit_len += 1;
+            * input = rest;
+        }
+        self.my_unit_len = 0;
+        let b = self.my_unit_bytes;
+        Option::Some(match self.my_from {
+            Encoding::Utf16Le => u16::from_le_bytes([b[0], b[1]]) as u32,
+            Encoding::Utf16Be => u16::from_be_bytes([b[0], b[1]]) as u32,
+            Encoding::Utf32Le => u32::from_le_bytes(b),
+            _ => u32::from_be_bytes(b),
+        })
+    }
+
+    /// Take the next UTF16 code unit from `input`, in the byte order
+    /// settled by the byte swap repair.  Returns 'None' when the input
+    /// runs out first.
+    fn take_utf16_unit(&mut self, input: &mut &[u8]) -> Option<u16> {
+        if ! self.my_swap_repair {
+            return self.take_unit(input, 2).map(|unit| unit as u16);
+        }
+        loop {
+            if let Option::Some(unit) = self.my_swap.take_held() {
+                return Option::Some(unit);
+            }
+            if self.my_swap.is_settled() {
+                let unit = self.take_unit(input, 2)? as u16;
+                return Option::Some(self.my_swap.fix_order(unit));
+            }
+            match self.take_unit(input, 2) {
+                Option::Some(unit) => {
+                    self.my_swap.hold(unit as u16);
+                }
+                Option::None if self.my_last_buffer => {
+                    // The held code units settle the byte order by
+                    // their votes.
+                    self.my_swap.settle();
+                }
+                Option::None => {
+                    return Option::None;
+                }
+            }
+        }
+    }
+
+    /// Returns a replacement character for an incomplete code unit at
+    /// the end of the last input, or 'None'.
+    fn finish_unit(&mut self) -> Option<char> {
+        if self.my_last_buffer && (self.my_unit_len > 0) {
+            self.my_unit_len = 0;
+            self.my_invalid_sequence = true;
+            return Option::Some(char::REPLACEMENT_CHARACTER);
+        }
+        Option::None
+    }
+
+    /// Returns `code` as a char, or a replacement character for an
+    /// invalid codepoint.
+    fn code_to_char(&mut self, code: u32) -> char {
+        match char::from_u32(code) {
+            Option::Some(ch) => ch,
+            Option::None => {
+                self.my_invalid_sequence = true;
+                char::REPLACEMENT_CHARACTER
+            }
+        }
+    }
+
+    /// Decode a UTF16 char from `input`.
+    fn decode_utf16(&mut self, input: &mut &[u8]) -> Option<char> {
+        // The parser is taken out while its closure borrows self.
+        let mut utf16 = self.my_utf16;
+        utf16.set_is_last_buffer(self.my_last_buffer);
+        let result = utf16.decode_from(|| self.take_utf16_unit(input));
+        self.my_utf16 = utf16;
+        // An incomplete code unit is reported after the last char.
+        result.or_else(|| self.finish_unit())
+    }
+
+    /// Decode a char from `input`, or return 'None' when more input is
+    /// needed, or at end of data.
+    fn decode_char(&mut self, input: &mut &[u8]) -> Option<char> {
+        match self.my_from {
+            Encoding::Utf8 => {
+                match self.my_utf8.utf8_to_char(input) {
+                    Result::Ok((rest, ch)) => {
+                        * input = rest;
+                        Option::Some(ch)
+                    }
+                    Result::Err(_) => {
+                        // The parser holds the rest of the input.
+                        * input = &[];
+                        Option::None
+                    }
+                }
+            }
+            Encoding::Utf16Le | Encoding::Utf16Be => self.decode_utf16(input),
+            Encoding::Utf32Le | Encoding::Utf32Be => {
+                match self.take_unit(input, 4) {
+                    Option::Some(code) => Option::Some(self.code_to_char(code)),
+                    Option::None => self.finish_unit(),
+                }
+            }
+        }
+    }
+
+    /// Decode the next char from `input` that survives the clean ups.
+    fn next_char(&mut self, input: &mut &[u8]) -> Option<char> {
+        loop {
+            let ch = self.decode_char(input)?;
+            if self.my_start_stream {
+                self.my_start_stream = false;
+                if self.my_strip_bom && (ch == BOM) {
+                    continue;
+                }
+            }
+            if ! self.my_normalize_newlines {
+                return Option::Some(ch);
+            }
+            let after_cr = self.my_prev_cr;
+            self.my_prev_cr = ch == CR;
+            if after_cr && (ch == NL) {
+                // CR - NL pair found; the CR became a newline already.
+                continue;
+            }
+            return Option::Some(if ch == CR { NL } else { ch });
+        }
+    }
+
+    /// Encode `ch` into my_out.
+    fn encode_char(&mut self, ch: char) {
+        let mut len:usize = 0;
+        match self.my_to {
+            Encoding::Utf8 => {
+                len = ch.encode_utf8(& mut self.my_out).len();
+            }
+            Encoding::Utf16Le | Encoding::Utf16Be => {
+                let mut units = [0u16; 2];
+                for unit in ch.encode_utf16(& mut units).iter() {
+                    let bytes = if self.my_to == Encoding::Utf16Le {
+                        unit.to_le_bytes()
+                    }
+                    else {
+                        unit.to_be_bytes()
+                    };
+                    self.my_out[len] = bytes[0];
+                    self.my_out[len + 1] = bytes[1];
+                    len += 2;
+                }
+            }
+            Encoding::Utf32Le => {
+                self.my_out = (ch as u32).to_le_bytes();
+                len = 4;
+            }
+            Encoding::Utf32Be => {
+                self.my_out = (ch as u32).to_be_bytes();
+                len = 4;
+            }
+        }
+        self.my_out_pos = 0;
+        self.my_out_len = len;
+    }
+
+    /// A bulk converter takes in byte slice, and fills the `output`
+    /// slice with converted bytes.  Returns a Result object with either
+    /// the remaining input and the number of bytes written, or an
+    /// MoreEnum that requests additional data, or an end of data stream
+    /// condition.
+    ///
+    /// The MoreEnum conditions are only reported when not a single byte
+    /// could be written.  A char may be split across output slices.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut recode = Recode::from(Encoding::Utf8).to(Encoding::Utf32Be);
+    /// let mut byte_box = [0u8; 16];
+    /// let (rest, len) = recode.recode_to_slice("a\u{10348}".as_bytes(), & mut byte_box).unwrap();
+    /// assert_eq!((0, 8), (rest.len(), len));
+    /// assert_eq!([0, 0, 0, 0x61, 0, 1, 3, 0x48], byte_box[.. len]);
+    /// assert_eq!(Err(MoreEnum::More(0)), recode.recode_to_slice(& [], & mut byte_box));
+    /// ```
+    /// # Arguments
+    /// * `input` - the input bytes
+    /// * `output` - the output buffer
+    pub fn recode_to_slice<'b>(&mut self, input: &'b [u8], output: &mut [u8])
+    -> Result<(&'b [u8], usize), MoreEnum> {
+        let mut my_cursor = input;
+        let mut written:usize = 0;
+        loop {
+            // Read out the pending bytes of the current char.
+            while self.my_out_pos < self.my_out_len {
+                match output.get_mut(written) {
+                    Option::Some(slot) => {
+                        * slot = self.my_out[self.my_out_pos];
+                        self.my_out_pos += 1;
+                        written += 1;
+                    }
+                    Option::None => {
+                        return Result::Ok((my_cursor, written));
+                    }
+                }
+            }
+            if written == output.len() {
+                return Result::Ok((my_cursor, written));
+            }
+            match self.next_char(& mut my_cursor) {
+                Option::Some(ch) => {
+                    self.encode_char(ch);
+                }
+                Option::None if written > 0 => {
+                    return Result::Ok((my_cursor, written));
+                }
+                Option::None if self.my_last_buffer => {
+                    // at end of data condition
+                    return Result::Err(MoreEnum::More(0));
+                }
+                Option::None => {
+                    // Returning an indication to request a new buffer.
+                    return Result::Err(MoreEnum::More(4096));
+                }
+            }
+        }
+    }
+
+    /// Convert the bytes of `input` with an iterator adapter that takes
+    /// ownership of a copy of this converter and of the byte iterator.
+    /// The end of the byte iterator is the end of data.
+    /// # Arguments
+    /// * `input` - the input bytes
+    pub fn recode_iter<I>(self, input: I) -> RecodeIter<I::IntoIter>
+    where I: IntoIterator<Item = u8>, {
+        let mut recode = self;
+        // The last buffer flag is set when the source runs out.
+        recode.set_is_last_buffer(false);
+        RecodeIter {
+            my_iter: input.into_iter(),
+            my_held: Option::None,
+            my_recode: recode,
+        }
+    }
+}
+
+/// Implementations of common operations for Recode
+impl UtfParserCommon for Recode {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_last_buffer = b;
+        self.my_utf8.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_last_buffer
+    }
+
+    #[inline]
+    /// This function returns true if invalid sequence occurred
+    /// in this conversion stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_invalid_sequence || self.my_utf8.has_invalid_sequence()
+        || self.my_utf16.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_invalid_sequence = true;
+    }
+
+    #[inline]
+    /// This function resets the invalid decodes state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_invalid_sequence = false;
+        self.my_utf8.reset_invalid_sequence();
+        self.my_utf16.reset_invalid_sequence();
+    }
+
+    /// Reset all conversion states to the initial value, keeping the
+    /// encodings and the clean ups.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    fn reset_parser(&mut self) {
+        let fresh = Recode {
+            my_to: self.my_to,
+            my_strip_bom: self.my_strip_bom,
+            my_normalize_newlines: self.my_normalize_newlines,
+            my_swap_repair: self.my_swap_repair,
+            .. Recode::from(self.my_from)
+        };
+        * self = fresh;
+    }
+}
+
+/// adapter iterator converting bytes from one Unicode encoding form to
+/// another
+/// (This iterator owns its Recode converter and its source iterator.)
+#[derive(Debug, Clone)]
+pub struct RecodeIter<I> {
+
+    /// the source iterator
+    my_iter: I,
+
+    /// a source byte not taken by the last char
+    my_held: Option<u8>,
+
+    /// the converter
+    my_recode: Recode,
+}
+
+/// Implementation of RecodeIter
+impl<I> RecodeIter<I> {
+
+    #[inline]
+    /// This function returns true if invalid sequence occurred
+    /// in this conversion stream.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_recode.has_invalid_sequence()
+    }
+}
+
+/// Iterator for RecodeIter
+impl<I: Iterator<Item = u8>> Iterator for RecodeIter<I> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let recode = & mut self.my_recode;
+        loop {
+            if recode.my_out_pos < recode.my_out_len {
+                recode.my_out_pos += 1;
+                return Option::Some(recode.my_out[recode.my_out_pos - 1]);
+            }
+            let byte = match self.my_held.take() {
+                Option::Some(b) => Option::Some(b),
+                Option::None if ! recode.my_last_buffer => self.my_iter.next(),
+                Option::None => Option::None,
+            };
+            if byte.is_none() {
+                recode.set_is_last_buffer(true);
+            }
+            let byte_box = [byte.unwrap_or(0)];
+            let mut input = byte_box.get(.. byte.is_some() as usize).unwrap_or(&[]);
+            match recode.next_char(& mut input) {
+                Option::Some(ch) => {
+                    if ! input.is_empty() {
+                        // A char read ahead came out first.
+                        self.my_held = byte;
+                    }
+                    recode.encode_char(ch);
+                }
+                Option::None if recode.my_last_buffer => {
+                    return Option::None;
+                }
+                Option::None => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+    use crate::utf8conv::test_fixtures::encode_text;
+
+    use std::vec::Vec;
+
+    #[test]
+    // Test every pair of encodings, with input split at every position
+    fn test_recode_pairs() {
+        let encodings = [
+            Encoding::Utf8,
+            Encoding::Utf16Le,
+            Encoding::Utf16Be,
+            Encoding::Utf32Le,
+            Encoding::Utf32Be,
+        ];
+        let text = "\u{FEFF}a\r\n\u{10348}\r\r\u{20AC}\n";
+        let clean = "a\n\u{10348}\n\n\u{20AC}\n";
+        for from in encodings {
+            for to in encodings {
+                let input = encode_text(text, from);
+                let recode = Recode::from(from).to(to);
+                assert_eq!(encode_text(text, to), recode.recode_iter(input.iter().copied()).collect::<Vec<u8>>());
+                let recode = recode.strip_bom().normalize_newlines();
+                let truth = encode_text(clean, to);
+                for split in 0 ..= input.len() {
+                    let mut recode = recode;
+                    recode.set_is_last_buffer(false);
+                    let mut output = Vec::new();
+                    let mut byte_box = [0u8; 3];
+                    for part in [& input[.. split], & input[split ..]] {
+                        let mut cur_slice = part;
+                        while let Result::Ok((rest, len)) = recode.recode_to_slice(cur_slice, & mut byte_box) {
+                            output.extend_from_slice(& byte_box[.. len]);
+                            cur_slice = rest;
+                        }
+                    }
+                    recode.set_is_last_buffer(true);
+                    while let Result::Ok((_rest, len)) = recode.recode_to_slice(&[], & mut byte_box) {
+                        output.extend_from_slice(& byte_box[.. len]);
+                    }
+                    assert_eq!(truth, output);
+                    assert!(! recode.has_invalid_sequence());
+                }
+            }
+        }
+    }
+
+    #[test]
+    // Test byte order detection of the byte swap repair, with input
+    // split at every position
+    fn test_recode_swap_repair() {
+        let cases: [(&str, bool); 6] = [
+            ("\u{3000}A", false),
+            ("\u{4E00} is one", true),
+            ("\u{0100}bc", true),
+            ("\u{0400}bc", true),
+            ("\u{FEFF}\u{3000}\u{10348}", true),
+            ("\u{4E2D}\u{6587}", false),
+        ];
+        for (text, detectable) in cases {
+            for from in [Encoding::Utf16Le, Encoding::Utf16Be] {
+                let other = if from == Encoding::Utf16Le { Encoding::Utf16Be } else { Encoding::Utf16Le };
+                let recode = Recode::from(from).repair_byte_swap();
+                // The text only comes out whole when its byte order is right.
+                for (input, swapped) in [(encode_text(text, from), false), (encode_text(text, other), detectable)] {
+                    let whole = swapped || (input == encode_text(text, from));
+                    for split in 0 ..= input.len() {
+                        let mut recode = recode;
+                        recode.set_is_last_buffer(false);
+                        let mut output = Vec::new();
+                        let mut byte_box = [0u8; 3];
+                        for part in [& input[.. split], & input[split ..]] {
+                            let mut cur_slice = part;
+                            while let Result::Ok((rest, len)) = recode.recode_to_slice(cur_slice, & mut byte_box) {
+                                output.extend_from_slice(& byte_box[.. len]);
+                                cur_slice = rest;
+                            }
+                        }
+                        recode.set_is_last_buffer(true);
+                        while let Result::Ok((_rest, len)) = recode.recode_to_slice(&[], & mut byte_box) {
+                            output.extend_from_slice(& byte_box[.. len]);
+                        }
+                        assert_eq!(swapped, recode.is_swapped(), "{:?} {:?}", text, from);
+                        if whole {
+                            assert_eq!(text.as_bytes(), & output[..], "{:?} {:?}", text, from);
+                        }
+                    }
+                    let iter_output: Vec<u8> = recode.recode_iter(input.iter().copied()).collect();
+                    if whole {
+                        assert_eq!(text.as_bytes(), & iter_output[..]);
+                    }
+                }
+            }
+        }
+        // Without the repair, byte swapped input is decoded as it is.
+        let input = encode_text("\u{4E00} is one", Encoding::Utf16Be);
+        let bytes: Vec<u8> = Recode::from(Encoding::Utf16Le).recode_iter(input.iter().copied()).collect();
+        assert_ne!("\u{4E00} is one".as_bytes(), & bytes[..]);
+    }
+
+    #[test]
+    // Test invalid and incomplete input of each encoding
+    fn test_recode_invalid() {
+        let cases: [(Encoding, & [u8], &str); 7] = [
+            (Encoding::Utf8, b"a\xE2\x82", "a\u{FFFD}"),
+            (Encoding::Utf16Le, b"\x00\xD8a\x00\x00\xDC\x00", "\u{FFFD}a\u{FFFD}\u{FFFD}"),
+            (Encoding::Utf16Le, b"a\x00\xFD\xFF", "a\u{FFFD}"),
+            (Encoding::Utf16Be, b"\xD8\x00", "\u{FFFD}"),
+            (Encoding::Utf16Be, b"\xD8\x00\x00", "\u{FFFD}\u{FFFD}"),
+            (Encoding::Utf32Le, b"\x00\x00\x11\x00\x00\xD8\x00\x00a", "\u{FFFD}\u{FFFD}\u{FFFD}"),
+            (Encoding::Utf32Be, b"\x00\x00\x00", "\u{FFFD}"),
+        ];
+        for (from, input, truth) in cases {
+            let mut iter = Recode::from(from).recode_iter(input.iter().copied());
+            assert_eq!(truth.as_bytes(), & iter.by_ref().collect::<Vec<u8>>()[..], "{:?}", from);
+            assert!(iter.has_invalid_sequence());
+            assert_eq!(Option::None, iter.next());
+        }
+        let mut recode = Recode::from(Encoding::Utf16Le).strip_bom();
+        let mut byte_box = [0u8; 4];
+        assert_eq!(Result::Ok((&b"a\x00"[..], 0)), recode.recode_to_slice(b"a\x00", & mut []));
+        recode.set_is_last_buffer(false);
+        assert_eq!(Result::Err(MoreEnum::More(4096)), recode.recode_to_slice(b"\xFF", & mut byte_box));
+        assert_eq!(Result::Ok((&b"b\x00"[..], 1)), recode.recode_to_slice(b"\xFEa\x00b\x00", & mut byte_box[.. 1]));
+        recode.reset_parser();
+        assert_eq!(Encoding::Utf16Le, recode.input_encoding());
+        assert_eq!(Encoding::Utf8, recode.output_encoding());
+        assert_eq!(Result::Err(MoreEnum::More(0)), recode.recode_to_slice(b"\xFF\xFE", & mut byte_box));
+    }
+}
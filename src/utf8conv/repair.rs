@@ -0,0 +1,202 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::repair
+
+use core::ops::Range;
+
+use crate::utf8conv::bad_decode_kind;
+use crate::utf8conv::buf::EightBytes;
+use crate::utf8conv::find_non_ascii;
+use crate::utf8conv::utf8_decode_with_len;
+use crate::utf8conv::Utf8EndEnum;
+use crate::utf8conv::Utf8ErrorKind;
+use crate::utf8conv::REPLACE_PART1;
+use crate::utf8conv::REPLACE_PART2;
+use crate::utf8conv::REPLACE_PART3;
+
+/// bytes of the replacement character
+const REPLACEMENT_BYTES: [u8; 3] = [REPLACE_PART1, REPLACE_PART2, REPLACE_PART3];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// One edit of a repair plan: the bytes `start .. start + len` are to be
+/// replaced with the replacement character bytes EF BF BD.
+pub struct RepairEdit {
+
+    /// byte offset of the invalid sequence
+    pub start: usize,
+
+    /// length of the invalid sequence in bytes
+    pub len: usize,
+
+    /// the kind of the invalid sequence
+    pub kind: Utf8ErrorKind,
+}
+
+/// Implementation of RepairEdit
+impl RepairEdit {
+
+    #[inline]
+    /// The byte range to be replaced
+    pub fn range(&self) -> Range<usize> {
+        self.start .. self.start + self.len
+    }
+
+    #[inline]
+    /// The bytes to put in place of the range
+    pub fn replacement(&self) -> &'static [u8] {
+        & REPLACEMENT_BYTES
+    }
+}
+
+/// Default for RepairEdit, an empty edit to fill edit buffers with
+impl Default for RepairEdit {
+    fn default() -> RepairEdit {
+        RepairEdit {
+            start: 0,
+            len: 0,
+            kind: Utf8ErrorKind::InvalidLead,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// Totals of a repair plan
+pub struct RepairSummary {
+
+    /// number of edits needed
+    pub num_edits: usize,
+
+    /// number of edits written into the edit buffer
+    pub num_written: usize,
+
+    /// length of the data after all edits are applied
+    pub repaired_len: usize,
+}
+
+/// Scan a complete UTF8 buffer, and write the edits that a lossy
+/// conversion would make into `edits`, without changing the data.
+///
+/// Each invalid sequence becomes one edit, in the same places as the
+/// decoders put replacement characters.  Encoded replacement characters
+/// are left out, because replacing them changes nothing.  When `edits`
+/// is too small, the remaining edits are still counted in the summary.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let data = [0x61u8, 0xE2u8, 0x82u8, 0x62u8, 0xFFu8];
+/// let mut edits = [RepairEdit::default(); 4];
+/// let summary = plan_repairs(& data, & mut edits);
+/// assert_eq!(2, summary.num_edits);
+/// assert_eq!(8, summary.repaired_len);
+/// assert_eq!(1 .. 3, edits[0].range());
+/// assert_eq!(Utf8ErrorKind::InvalidLead, edits[1].kind);
+/// ```
+/// # Arguments
+/// * `input` - the complete UTF8 input
+/// * `edits` - output buffer of edits
+pub fn plan_repairs(input: &[u8], edits: &mut [RepairEdit]) -> RepairSummary {
+    let mut summary = RepairSummary::default();
+    let mut pos:usize = 0;
+    let mut mybuf = EightBytes::new();
+    loop {
+        // Skip ASCII runs quickly.
+        let rest = input.get(pos ..).unwrap_or(&[]);
+        match find_non_ascii(rest) {
+            Option::Some(skip) => {
+                pos += skip;
+            }
+            Option::None => {
+                break;
+            }
+        }
+        let rest = input.get(pos ..).unwrap_or(&[]);
+        mybuf.clear();
+        mybuf.fill_from_slice(rest);
+        let lead = rest.first().copied().unwrap_or(0);
+        let (kind, len) = match utf8_decode_with_len(& mut mybuf, true) {
+            (Utf8EndEnum::Finish(_code), len) => {
+                pos += len as usize;
+                continue;
+            }
+            (Utf8EndEnum::BadDecode(_), len) => (bad_decode_kind(lead, len), len),
+            (Utf8EndEnum::TypeUnknown, len) => (Utf8ErrorKind::Truncated, len),
+        };
+        // A decode always consumes at least one byte of a non-empty input.
+        let len = (len as usize).max(1);
+        if kind != Utf8ErrorKind::ReplacementCharacter {
+            if let Option::Some(slot) = edits.get_mut(summary.num_written) {
+                * slot = RepairEdit { start: pos, len, kind };
+                summary.num_written += 1;
+            }
+            summary.num_edits += 1;
+            summary.repaired_len += REPLACEMENT_BYTES.len();
+            summary.repaired_len -= len;
+        }
+        pos += len;
+    }
+    summary.repaired_len += input.len();
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::vec::Vec;
+
+    // Apply a repair plan to a copy of the input.
+    fn apply(input: & [u8], edits: & [RepairEdit]) -> Vec<u8> {
+        let mut output = Vec::new();
+        let mut pos = 0;
+        for edit in edits {
+            output.extend_from_slice(& input[pos .. edit.start]);
+            output.extend_from_slice(edit.replacement());
+            pos = edit.range().end;
+        }
+        output.extend_from_slice(& input[pos ..]);
+        output
+    }
+
+    #[test]
+    // Test applied plans against the lossy conversion of the standard library
+    fn test_plan_repairs_matches_lossy() {
+        let samples: [& [u8]; 5] = [
+            "valid \u{20AC}\u{10348} text".as_bytes(),
+            & [0xC2u8, 0x41u8, 0xE0u8, 0x80u8, 0xAFu8, 0xEDu8, 0xA0u8, 0x80u8],
+            & [0xEFu8, 0xBFu8, 0xBDu8, 0xF4u8, 0x90u8, 0xFFu8, 0xF0u8, 0x9Fu8, 0x98u8],
+            & [0x41u8, 0x42u8, 0x43u8, 0x44u8, 0x45u8, 0x46u8, 0x47u8, 0x48u8, 0x80u8, 0x49u8],
+            & [],
+        ];
+        for bytes in samples.iter() {
+            let mut edits = [RepairEdit::default(); 16];
+            let summary = plan_repairs(bytes, & mut edits);
+            assert_eq!(summary.num_edits, summary.num_written);
+            let output = apply(bytes, & edits[0 .. summary.num_written]);
+            assert_eq!(std::string::String::from_utf8_lossy(bytes).as_bytes(), & output[..]);
+            assert_eq!(output.len(), summary.repaired_len);
+        }
+    }
+
+    #[test]
+    // Test edit kinds and a small edit buffer
+    fn test_plan_repairs_kinds() {
+        let bytes = [0x80u8, 0xE2u8, 0x41u8, 0xEFu8, 0xBFu8, 0xBDu8, 0xF0u8, 0x90u8];
+        let mut edits = [RepairEdit::default(); 2];
+        let summary = plan_repairs(& bytes, & mut edits);
+        assert_eq!(RepairSummary { num_edits: 3, num_written: 2, repaired_len: 13 }, summary);
+        assert_eq!(RepairEdit { start: 0, len: 1, kind: Utf8ErrorKind::InvalidLead }, edits[0]);
+        assert_eq!(RepairEdit { start: 1, len: 1, kind: Utf8ErrorKind::InvalidContinuation }, edits[1]);
+        let summary = plan_repairs(& bytes[6 ..], & mut edits);
+        assert_eq!(1, summary.num_edits);
+        assert_eq!(RepairEdit { start: 0, len: 2, kind: Utf8ErrorKind::Truncated }, edits[0]);
+    }
+}
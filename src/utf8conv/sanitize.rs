@@ -0,0 +1,237 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::sanitize
+
+use core::fmt;
+
+use crate::utf8conv::buf::EightBytes;
+use crate::utf8conv::encode_char_to_buf;
+use crate::utf8conv::fmt_parser_adapter;
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::UtfParserCommon;
+
+/// adapter iterator converting from an UTF8 iterator to a valid UTF8
+/// iterator
+/// (This iterator contains a mutable borrow to the launching
+/// FromUtf8 object while this iterator is alive.)
+///
+/// The output bytes are the same as the input bytes, except that each
+/// invalid sequence is replaced with the replacement character bytes
+/// EF BF BD.  An incomplete sequence at the end of a buffer is kept in
+/// the parser for the next buffer, unless the last buffer flag is set.
+///
+/// The bytes of one char are produced together; run the iterator until
+/// it returns 'None' before presenting the next buffer.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut parser = FromUtf8::new();
+/// parser.set_is_last_buffer(false);
+/// let mut output = Vec::new();
+/// let mut iter = [0x41u8, 0xE2u8, 0x82u8].iter().copied();
+/// output.extend(parser.utf8_sanitize_with_iter(& mut iter));
+/// assert_eq!(b"A", & output[..]);
+/// parser.set_is_last_buffer(true);
+/// let mut iter = [0xACu8, 0xFFu8].iter().copied();
+/// output.extend(parser.utf8_sanitize_with_iter(& mut iter));
+/// assert_eq!("A\u{20AC}\u{FFFD}".as_bytes(), & output[..]);
+/// assert!(parser.has_invalid_sequence());
+/// ```
+pub struct Utf8SanitizeIter<'p, I: ?Sized = dyn Iterator<Item = u8> + 'p> {
+
+    /// remaining output bytes of the current char
+    my_out: EightBytes,
+
+    /// the source iterator
+    my_borrow_mut_iter: &'p mut I,
+
+    /// mutable reference to FromUtf8 object
+    my_info: &'p mut FromUtf8,
+}
+
+/// Implementation of FromUtf8 for sanitizing
+impl FromUtf8 {
+
+    /// Convert from UTF8 to valid UTF8 with a mutable reference
+    /// to the source UTF8 iterator.
+    pub fn utf8_sanitize_with_iter<'d, I>(&'d mut self, iter: &'d mut I)
+    -> Utf8SanitizeIter<'d, I>
+    where I: Iterator<Item = u8> + ?Sized, {
+        Utf8SanitizeIter {
+            my_out: EightBytes::new(),
+            my_borrow_mut_iter: iter,
+            my_info: self,
+        }
+    }
+}
+
+/// Implementation of Utf8SanitizeIter
+impl<'g, I: ?Sized> Utf8SanitizeIter<'g, I> {
+
+    #[inline]
+    /// Point this adapter at the iterator of the next buffer, and return
+    /// the previous source iterator.
+    ///
+    /// The parser state and the pending output bytes, including a
+    /// partial sequence from the end of the previous buffer, are kept,
+    /// so one adapter can serve every
+    /// buffer of a stream.  Set the last buffer flag before presenting
+    /// the last buffer.
+    /// # Arguments
+    /// * `iter` - the source iterator of the next buffer
+    pub fn set_source(&mut self, iter: &'g mut I) -> &'g mut I {
+        core::mem::replace(& mut self.my_borrow_mut_iter, iter)
+    }
+}
+
+/// Implementations of common operations for Utf8SanitizeIter
+impl<'g, I: ?Sized> UtfParserCommon for Utf8SanitizeIter<'g, I> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_info.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_info.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if invalid UTF8 sequence occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_info.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid UTF8 sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_info.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid decodes state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_info.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid decodes indication is cleared.
+    fn reset_parser(&mut self) {
+        self.my_out.clear();
+        self.my_info.reset_parser();
+    }
+}
+
+/// Iterator for Utf8SanitizeIter
+impl<'g, I> Iterator for Utf8SanitizeIter<'g, I>
+where I: Iterator<Item = u8> + ?Sized, {
+    type Item = u8;
+
+    /// A parser takes in an iterator of UTF8 byte stream, and returns
+    /// an iterator of valid UTF8 bytes.
+    ///
+    /// An invalid Unicode decode in the stream are substituted with
+    /// the bytes of an Unicode replacement character.
+    ///
+    /// has_invalid_sequence() would return true after observing
+    /// invalid decodes, or observing a replacement character.
+    fn next(&mut self) -> Option<Self::Item> {
+        // Check if we can pull an u8 from our output buffer
+        if let Option::Some(v1) = self.my_out.pop_front() {
+            return Option::Some(v1);
+        }
+        let ch = self.my_info.decode_from_iter(self.my_borrow_mut_iter)?;
+        let mut encode_buf = [0u8; 4];
+        let (first, rest) = encode_char_to_buf(ch, & mut encode_buf).as_bytes().split_first()?;
+        for v in rest {
+            self.my_out.push_back(* v);
+        }
+        Option::Some(* first)
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.my_out.len() as usize;
+        // Each input byte yields at most one replacement character.
+        let upper = self.my_borrow_mut_iter.size_hint().1
+        .and_then(|v| v.checked_add(self.my_info.my_buf.len() as usize))
+        .and_then(|v| v.checked_mul(3))
+        .and_then(|v| v.checked_add(pending));
+        (pending, upper)
+    }
+}
+
+impl<'g, I: ?Sized> fmt::Debug for Utf8SanitizeIter<'g, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_parser_adapter(f, "Utf8SanitizeIter", & self.my_info.my_buf,
+            self.my_info.my_last_buffer, self.my_info.my_invalid_sequence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::string::String;
+    use std::vec::Vec;
+
+    // Sanitize a sequence of buffers, signaling the last one.
+    fn sanitize_buffers(parser: & mut FromUtf8, buffers: & [& [u8]]) -> Vec<u8> {
+        let mut output = Vec::new();
+        for (indx, buf) in buffers.iter().enumerate() {
+            // Signal no more buffer after the last one.
+            parser.set_is_last_buffer(indx + 1 == buffers.len());
+            let mut byte_iter = buf.iter().copied();
+            output.extend(parser.utf8_sanitize_with_iter(& mut byte_iter));
+        }
+        output
+    }
+
+    #[test]
+    // Test every split of a stream into three buffers
+    fn test_sanitize_splits() {
+        let bytes = [0x41u8, 0xF0u8, 0x90u8, 0x8Du8, 0x88u8, 0xE2u8, 0x41u8, 0xEDu8, 0xA0u8, 0x80u8, 0xDFu8, 0xBFu8, 0xC2u8];
+        let truth = String::from_utf8_lossy(& bytes);
+        let mut parser = FromUtf8::new();
+        for pos1 in 0 ..= bytes.len() {
+            for pos2 in pos1 ..= bytes.len() {
+                let buffers = [& bytes[.. pos1], & bytes[pos1 .. pos2], & bytes[pos2 ..]];
+                let output = sanitize_buffers(& mut parser, & buffers);
+                assert_eq!(truth.as_bytes(), & output[..]);
+                assert!(parser.has_invalid_sequence());
+                parser.reset_parser();
+            }
+        }
+    }
+
+    #[test]
+    // Test that valid input passes through unchanged
+    fn test_sanitize_valid() {
+        let text = "Hello \u{7FF} \u{FFFF} \u{10FFFF}";
+        let mut parser = FromUtf8::new();
+        let output = sanitize_buffers(& mut parser, & [text.as_bytes()]);
+        assert_eq!(text.as_bytes(), & output[..]);
+        assert!(! parser.has_invalid_sequence());
+        let mut byte_iter = [0x80u8; 4].iter().copied();
+        let iter = parser.utf8_sanitize_with_iter(& mut byte_iter);
+        assert_eq!((0, Option::Some(12)), iter.size_hint());
+        assert_eq!(12, iter.count());
+        assert!(parser.has_invalid_sequence());
+    }
+}
@@ -0,0 +1,431 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::sink
+
+use core::fmt;
+
+use crate::utf8conv::CharSource;
+use crate::utf8conv::FromUnicode;
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::MoreEnum;
+
+/// A destination of chars for the decoders.
+///
+/// Implemented for char and UTF32 slice cursors, closures wrapped in
+/// FnSink, and fmt::Write targets wrapped in FmtSink.  A slice cursor
+/// is advanced past the chars written, the same as io::Write for byte
+/// slices.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut parser = FromUtf8::new();
+/// let mut text = String::new();
+/// let mut sink = FmtSink(& mut text);
+/// let (rest, num_chars) = parser.utf8_to_sink("a\u{20AC}".as_bytes(), & mut sink).unwrap();
+/// assert_eq!((0, 2), (rest.len(), num_chars));
+/// assert_eq!("a\u{20AC}", text);
+/// ```
+pub trait CharSink {
+
+    /// Write one char.  Returns false if the char was not taken.
+    fn put_char(&mut self, ch: char) -> bool;
+
+    #[inline]
+    /// Returns true when no more chars can be taken.
+    fn is_full(&self) -> bool {
+        false
+    }
+}
+
+/// A destination of UTF8 bytes for the encoders.
+///
+/// Implemented for byte slice cursors, closures wrapped in FnSink,
+/// and io::Write targets wrapped in IoSink under the "std" feature.
+pub trait ByteSink {
+
+    /// Write one byte.  Returns false if the byte was not taken.
+    fn put_byte(&mut self, b: u8) -> bool;
+
+    #[inline]
+    /// Returns true when no more bytes can be taken.
+    fn is_full(&self) -> bool {
+        false
+    }
+}
+
+/// Sink wrapper for a closure taking chars or bytes
+#[derive(Debug, Clone)]
+pub struct FnSink<F>(pub F);
+
+/// Sink wrapper for a fmt::Write target
+#[derive(Debug, Clone)]
+pub struct FmtSink<W>(pub W);
+
+#[cfg(feature = "std")]
+/// Sink wrapper for an io::Write target
+///
+/// Every byte is a separate write; wrap unbuffered targets in an
+/// io::BufWriter.
+#[derive(Debug, Clone)]
+pub struct IoSink<W>(pub W);
+
+/// CharSink for char slice cursors
+impl CharSink for &mut [char] {
+    #[inline]
+    fn put_char(&mut self, ch: char) -> bool {
+        match core::mem::take(self).split_first_mut() {
+            Option::Some((slot, rest)) => {
+                * slot = ch;
+                * self = rest;
+                true
+            }
+            Option::None => false,
+        }
+    }
+
+    #[inline]
+    fn is_full(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// CharSink for UTF32 slice cursors
+impl CharSink for &mut [u32] {
+    #[inline]
+    fn put_char(&mut self, ch: char) -> bool {
+        match core::mem::take(self).split_first_mut() {
+            Option::Some((slot, rest)) => {
+                * slot = ch as u32;
+                * self = rest;
+                true
+            }
+            Option::None => false,
+        }
+    }
+
+    #[inline]
+    fn is_full(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// ByteSink for byte slice cursors
+impl ByteSink for &mut [u8] {
+    #[inline]
+    fn put_byte(&mut self, b: u8) -> bool {
+        match core::mem::take(self).split_first_mut() {
+            Option::Some((slot, rest)) => {
+                * slot = b;
+                * self = rest;
+                true
+            }
+            Option::None => false,
+        }
+    }
+
+    #[inline]
+    fn is_full(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// CharSink for closures taking chars
+impl<F: FnMut(char)> CharSink for FnSink<F> {
+    #[inline]
+    fn put_char(&mut self, ch: char) -> bool {
+        (self.0)(ch);
+        true
+    }
+}
+
+/// ByteSink for closures taking bytes
+impl<F: FnMut(u8)> ByteSink for FnSink<F> {
+    #[inline]
+    fn put_byte(&mut self, b: u8) -> bool {
+        (self.0)(b);
+        true
+    }
+}
+
+/// CharSink for fmt::Write targets
+impl<W: fmt::Write> CharSink for FmtSink<W> {
+    #[inline]
+    fn put_char(&mut self, ch: char) -> bool {
+        self.0.write_char(ch).is_ok()
+    }
+}
+
+#[cfg(feature = "std")]
+/// ByteSink for io::Write targets
+impl<W: std::io::Write> ByteSink for IoSink<W> {
+    #[inline]
+    fn put_byte(&mut self, b: u8) -> bool {
+        self.0.write_all(& [b]).is_ok()
+    }
+}
+
+/// Implementation of FromUtf8 for sinks
+impl FromUtf8 {
+
+    /// A bulk parser takes in UTF8 slice, and writes chars into `sink`
+    /// until the input runs out or the sink is full.  Returns a Result
+    /// object with either the remaining input and the number of chars
+    /// written, or an MoreEnum that requests additional data, or an end
+    /// of data stream condition.
+    ///
+    /// The MoreEnum conditions are the same as utf8_to_char(); they are
+    /// only reported when not a single char could be written.  A sink
+    /// that rejects a char without reporting is_full() stops the
+    /// conversion, and that char is lost; this is meant for write errors.
+    /// # Arguments
+    /// * `input` - UTF8 input slice
+    /// * `sink` - destination of the chars
+    pub fn utf8_to_sink<'b, S: CharSink + ?Sized>(&mut self, input: &'b [u8], sink: &mut S)
+    -> Result<(&'b [u8], usize), MoreEnum> {
+        let mut my_cursor: &[u8] = input;
+        let mut written: usize = 0;
+        while ! sink.is_full() {
+            match self.utf8_to_char(my_cursor) {
+                Result::Ok((slice_pos, ch)) => {
+                    my_cursor = slice_pos;
+                    if ! sink.put_char(ch) {
+                        break;
+                    }
+                    written += 1;
+                }
+                Result::Err(en) => {
+                    if written == 0 {
+                        return Result::Err(en);
+                    }
+                    break;
+                }
+            }
+        }
+        Result::Ok((my_cursor, written))
+    }
+}
+
+/// Implementation of FromUnicode for sinks
+impl FromUnicode {
+
+    /// A bulk parser takes in a CharSource, and writes UTF8 bytes into
+    /// `sink` until the source runs out or the sink is full.  Returns a
+    /// Result object with either the number of bytes written, or an
+    /// MoreEnum that requests additional data, or an end of data stream
+    /// condition.
+    ///
+    /// The MoreEnum conditions are the same as source_to_utf8(); they are
+    /// only reported when not a single byte could be written.  A sink
+    /// that rejects a byte without reporting is_full() stops the
+    /// conversion, and that byte is lost; this is meant for write errors.
+    /// # Arguments
+    /// * `src` - source of the chars
+    /// * `sink` - destination of the UTF8 bytes
+    pub fn source_to_sink<S, K>(&mut self, src: &mut S, sink: &mut K)
+    -> Result<usize, MoreEnum>
+    where S: CharSource + ?Sized, K: ByteSink + ?Sized, {
+        let mut written: usize = 0;
+        while ! sink.is_full() {
+            match self.source_to_utf8(src) {
+                Result::Ok(b) => {
+                    if ! sink.put_byte(b) {
+                        break;
+                    }
+                    written += 1;
+                }
+                Result::Err(en) => {
+                    if written == 0 {
+                        return Result::Err(en);
+                    }
+                    break;
+                }
+            }
+        }
+        Result::Ok(written)
+    }
+
+    /// Make a fmt::Write adapter encoding the formatted text into
+    /// `sink` with this encoder, so write!() needs no allocation.
+    ///
+    /// ```rust
+    /// use core::fmt::Write;
+    /// use utf8conv::*;
+    ///
+    /// let mut encoder = FromUnicode::new();
+    /// let mut byte_box = [0u8; 16];
+    /// let mut writer = encoder.fmt_writer(& mut byte_box[..]);
+    /// write!(writer, "{}\u{B0}C", 21).unwrap();
+    /// let len = writer.num_written();
+    /// assert_eq!("21\u{B0}C".as_bytes(), & byte_box[.. len]);
+    /// ```
+    /// # Arguments
+    /// * `sink` - destination of the UTF8 bytes
+    pub fn fmt_writer<K: ByteSink>(&mut self, sink: K) -> EncodeWriter<'_, K> {
+        EncodeWriter {
+            my_encoder: self,
+            my_sink: sink,
+            my_written: 0,
+        }
+    }
+}
+
+/// fmt::Write adapter encoding text into a ByteSink with a FromUnicode
+/// encoder, made by fmt_writer().
+///
+/// A byte rejected by the sink stops the formatting with fmt::Error,
+/// and the rest of its char is dropped.  The bytes written before it
+/// stay in the sink, so the output may end inside a char.
+#[derive(Debug)]
+pub struct EncodeWriter<'e, K> {
+
+    /// the encoder
+    my_encoder: &'e mut FromUnicode,
+
+    /// destination of the UTF8 bytes
+    my_sink: K,
+
+    /// number of bytes written into the sink
+    my_written: usize,
+}
+
+/// Implementation of EncodeWriter
+impl<K> EncodeWriter<'_, K> {
+
+    #[inline]
+    /// Returns the number of bytes written into the sink.
+    pub fn num_written(&self) -> usize {
+        self.my_written
+    }
+
+    #[inline]
+    /// Get a reference to the sink
+    pub fn sink(&self) -> &K {
+        & self.my_sink
+    }
+
+    /// Unwrap this EncodeWriter, returning the sink.
+    pub fn into_sink(self) -> K {
+        self.my_sink
+    }
+}
+
+/// fmt::Write for EncodeWriter
+impl<K: ByteSink> fmt::Write for EncodeWriter<'_, K> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut src = s;
+        // The loop stops at MoreEnum::More(_amt), when the source
+        // and the bytes of its last char are used up.
+        while let Result::Ok(b) = self.my_encoder.source_to_utf8(& mut src) {
+            if ! self.my_sink.put_byte(b) {
+                // Drop the rest of the char, so the next text starts
+                // cleanly.
+                while self.my_encoder.source_to_utf8(& mut "").is_ok() {}
+                return Result::Err(fmt::Error);
+            }
+            self.my_written += 1;
+        }
+        Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[test]
+    // Test decoding into slice cursors, closures and fmt::Write targets
+    fn test_char_sinks() {
+        let bytes = [0x41u8, 0xE2u8, 0x82u8, 0xACu8, 0xC2u8, 0xF0u8, 0x90u8, 0x8Du8, 0x88u8];
+        let truth = String::from_utf8_lossy(& bytes);
+        let mut parser = FromUtf8::new();
+
+        // A slice cursor takes chars until it is full.
+        let mut char_box = ['\0'; 3];
+        let mut cursor: & mut [char] = & mut char_box;
+        let (rest, num_chars) = parser.utf8_to_sink(& bytes, & mut cursor).unwrap();
+        assert_eq!((3, 0), (num_chars, cursor.len()));
+        assert_eq!(Result::Ok((rest, 0)), parser.utf8_to_sink(rest, & mut cursor));
+        let mut code_box = [0u32; 4];
+        let mut cursor: & mut [u32] = & mut code_box;
+        assert_eq!(Result::Ok((& [][..], 1)), parser.utf8_to_sink(rest, & mut cursor));
+        assert_eq!(Result::Err(MoreEnum::More(0)), parser.utf8_to_sink(& [], & mut cursor));
+        assert_eq!(['A', '\u{20AC}', '\u{FFFD}'], char_box);
+        assert_eq!([0x10348u32, 0, 0, 0], code_box);
+        parser.reset_parser();
+
+        let mut output = Vec::new();
+        parser.utf8_to_sink(& bytes, & mut FnSink(|ch| output.push(ch))).unwrap();
+        assert_eq!(truth, output.into_iter().collect::<String>());
+        let mut text = String::new();
+        parser.utf8_to_sink(& bytes, & mut FmtSink(& mut text)).unwrap();
+        assert_eq!(truth, text);
+        assert!(parser.has_invalid_sequence());
+    }
+
+    #[test]
+    // Test encoding into slice cursors, closures and io::Write targets
+    fn test_byte_sinks() {
+        let text = "a\u{7FF}\u{20AC}\u{10348}";
+        let mut encoder = FromUnicode::new();
+
+        // A slice cursor may stop in the middle of a sequence.
+        let mut byte_box = [0u8; 6];
+        let mut src = text;
+        let mut cursor: & mut [u8] = & mut byte_box;
+        assert_eq!(Result::Ok(6), encoder.source_to_sink(& mut src, & mut cursor));
+        assert_eq!("\u{10348}", src);
+        let mut output = byte_box.to_vec();
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok(_num_bytes) = encoder.source_to_sink(& mut src, & mut FnSink(|b| output.push(b))) {
+        }
+        assert_eq!(text.as_bytes(), & output[..]);
+
+        #[cfg(feature = "std")]
+        {
+            let mut output = Vec::new();
+            let mut src = text;
+            encoder.source_to_sink(& mut src, & mut IoSink(& mut output)).unwrap();
+            assert_eq!(text.as_bytes(), & output[..]);
+        }
+    }
+
+    #[test]
+    // Test formatting into byte sinks, and sinks running out of room
+    fn test_fmt_writer() {
+        use core::fmt::Write;
+
+        let mut encoder = FromUnicode::new();
+        let mut output = Vec::new();
+        let (ch, num) = ('\u{10348}', 7);
+        let mut writer = encoder.fmt_writer(FnSink(|b| output.push(b)));
+        write!(writer, "{}:{:>3}|\u{20AC}", ch, num).unwrap();
+        writer.write_char('\u{7FF}').unwrap();
+        assert_eq!(14, writer.num_written());
+        assert_eq!("\u{10348}:  7|\u{20AC}\u{7FF}".as_bytes(), & output[..]);
+
+        // The encoder is ready for the next text after a full sink.
+        let mut byte_box = [0u8; 4];
+        let mut writer = encoder.fmt_writer(& mut byte_box[..]);
+        assert!(writer.write_fmt(format_args!("ab{}", ch)).is_err());
+        assert_eq!((4, 0), (writer.num_written(), writer.sink().len()));
+        assert_eq!(0, writer.into_sink().len());
+        assert_eq!(b"ab\xF0\x90", & byte_box[..]);
+        let mut writer = encoder.fmt_writer(& mut byte_box[..]);
+        writer.write_str("xyz").unwrap();
+        assert_eq!(b"xyz", & byte_box[.. 3]);
+        assert!(! encoder.has_invalid_sequence());
+    }
+}
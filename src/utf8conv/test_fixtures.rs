@@ -0,0 +1,27 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::test_fixtures
+
+// Shared fixtures of the unit tests; not part of the public API.
+
+extern crate std;
+
+use crate::utf8conv::bom::Encoding;
+
+/// Encode `text` in `encoding` with the standard library, as the
+/// expected output of the unit tests of the transcoders.
+pub(crate) fn encode_text(text: &str, encoding: Encoding) -> std::vec::Vec<u8> {
+    match encoding {
+        Encoding::Utf8 => text.as_bytes().to_vec(),
+        Encoding::Utf16Le => text.encode_utf16().flat_map(u16::to_le_bytes).collect(),
+        Encoding::Utf16Be => text.encode_utf16().flat_map(u16::to_be_bytes).collect(),
+        Encoding::Utf32Le => text.chars().flat_map(|ch| (ch as u32).to_le_bytes()).collect(),
+        Encoding::Utf32Be => text.chars().flat_map(|ch| (ch as u32).to_be_bytes()).collect(),
+    }
+}
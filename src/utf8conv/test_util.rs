@@ -0,0 +1,200 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::test_util
+
+//! Testing utilities, enabled by the "test-util" feature.
+//!
+//! Each verify function decodes a sequence of byte buffers with one
+//! of the FromUtf8 APIs, treating the last buffer as the end of data,
+//! and panics with a description of the first difference when the
+//! output does not match a truth string.  Applications can use them to
+//! check their own buffer splitting logic.
+//!
+//! The `verify_buffers_*` functions take any number of buffers; the
+//! other functions take exactly four.
+//!
+//! The parser is reset before decoding.  Its invalid sequence
+//! indication is left as decoded, so callers may check it afterwards.
+//!
+//! ```rust
+//! use utf8conv::*;
+//! use utf8conv::test_util::verify_buffers_with_string;
+//!
+//! let mut parser = FromUtf8::new();
+//! verify_buffers_with_string(& mut parser, & [& [0xE2u8, 0x82u8], & [0xACu8]], "\u{20AC}");
+//! assert!(! parser.has_invalid_sequence());
+//! ```
+
+use core::str::CharIndices;
+
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::UtfParserCommon;
+use crate::utf8conv::utf8_ref_iter_to_utf8_iter;
+
+/// Compare one decoded char against the next char of the truth string.
+fn check_next_char(truth_iter: & mut CharIndices, truth: &str, test_char_len: usize, test_ch: char) {
+    match truth_iter.next() {
+        Option::Some((_pos, truth_ch)) => {
+            if test_ch != truth_ch {
+                panic!(
+"\nThe truth string is different than the test vectors.\nTruth string:{}\nTest vector at index {} has code value {:#08x} vs truth {:#08x}\n"
+                , truth, test_char_len, (test_ch as u32), (truth_ch as u32));
+            }
+        }
+        Option::None => {
+            panic!(
+"\nThe truth string is shorter than the combined test vectors.\nTruth string:{}\nTest vector at index {} has code value {:#08x}\n"
+            , truth, test_char_len, (test_ch as u32));
+        }
+    }
+}
+
+/// Check that the truth string has no chars left at end of data.
+fn check_end(truth_iter: & mut CharIndices, truth: &str, test_char_len: usize) {
+    if let Option::Some((_pos, truth_ch)) = truth_iter.next() {
+        panic!(
+"\nThe truth string is longer than the test vectors (length {}).\nTruth string:{}\nTruth at index {} has code value {:#08x}\n"
+        , test_char_len, truth, test_char_len+1, (truth_ch as u32));
+    }
+}
+
+/// Verify utf8_to_char() decoding of `buffers` against `truth`.
+/// # Arguments
+/// * `par` - the parser under test
+/// * `buffers` - byte buffers in stream order
+/// * `truth` - the expected output
+pub fn verify_buffers_with_string(par: &mut FromUtf8, buffers: & [& [u8]], truth: &str) {
+    let mut test_char_len:usize = 0;
+    let mut truth_iter = truth.char_indices();
+    par.reset_parser();
+    for (indx, buf) in buffers.iter().enumerate() {
+        // Signal no more buffer after the last one.
+        par.set_is_last_buffer(indx + 1 == buffers.len());
+        let mut the_slice: &[u8] = buf;
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, test_ch)) = par.utf8_to_char(the_slice) {
+            the_slice = slice_pos;
+            check_next_char(& mut truth_iter, truth, test_char_len, test_ch);
+            test_char_len += 1;
+        }
+    }
+    check_end(& mut truth_iter, truth, test_char_len);
+}
+
+/// Verify utf8_ref_to_char_with_iter() decoding of `buffers`
+/// against `truth`.
+/// # Arguments
+/// * `par` - the parser under test
+/// * `buffers` - byte buffers in stream order
+/// * `truth` - the expected output
+pub fn verify_buffers_style2(par: &mut FromUtf8, buffers: & [& [u8]], truth: &str) {
+    let mut test_char_len:usize = 0;
+    let mut truth_iter = truth.char_indices();
+    par.reset_parser();
+    for (indx, buf) in buffers.iter().enumerate() {
+        // Signal no more buffer after the last one.
+        par.set_is_last_buffer(indx + 1 == buffers.len());
+        let mut byte_ref_iter = buf.iter();
+        for test_ch in par.utf8_ref_to_char_with_iter(& mut byte_ref_iter) {
+            check_next_char(& mut truth_iter, truth, test_char_len, test_ch);
+            test_char_len += 1;
+        }
+    }
+    check_end(& mut truth_iter, truth, test_char_len);
+}
+
+/// Verify utf8_to_char_with_iter() decoding of `buffers`
+/// against `truth`.
+/// # Arguments
+/// * `par` - the parser under test
+/// * `buffers` - byte buffers in stream order
+/// * `truth` - the expected output
+pub fn verify_buffers_style3(par: &mut FromUtf8, buffers: & [& [u8]], truth: &str) {
+    let mut test_char_len:usize = 0;
+    let mut truth_iter = truth.char_indices();
+    par.reset_parser();
+    for (indx, buf) in buffers.iter().enumerate() {
+        // Signal no more buffer after the last one.
+        par.set_is_last_buffer(indx + 1 == buffers.len());
+        let mut byte_ref_iter = buf.iter();
+        let mut glue_iter = utf8_ref_iter_to_utf8_iter(& mut byte_ref_iter);
+        for test_ch in par.utf8_to_char_with_iter(& mut glue_iter) {
+            check_next_char(& mut truth_iter, truth, test_char_len, test_ch);
+            test_char_len += 1;
+        }
+    }
+    check_end(& mut truth_iter, truth, test_char_len);
+}
+
+/// Verify utf8_to_char_slice() decoding of `buffers` against `truth`.
+///
+/// A small output slice is used, so the bulk parser stops in the
+/// middle of the input frequently.
+/// # Arguments
+/// * `par` - the parser under test
+/// * `buffers` - byte buffers in stream order
+/// * `truth` - the expected output
+pub fn verify_buffers_style4(par: &mut FromUtf8, buffers: & [& [u8]], truth: &str) {
+    let mut test_char_len:usize = 0;
+    let mut truth_iter = truth.char_indices();
+    let mut char_box = [char::MAX; 7];
+    par.reset_parser();
+    for (indx, buf) in buffers.iter().enumerate() {
+        // Signal no more buffer after the last one.
+        par.set_is_last_buffer(indx + 1 == buffers.len());
+        let mut the_slice: &[u8] = buf;
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, num_chars)) = par.utf8_to_char_slice(the_slice, & mut char_box) {
+            the_slice = slice_pos;
+            for test_ch in char_box.iter().take(num_chars) {
+                check_next_char(& mut truth_iter, truth, test_char_len, * test_ch);
+                test_char_len += 1;
+            }
+        }
+    }
+    check_end(& mut truth_iter, truth, test_char_len);
+}
+
+/// Verify utf8_to_char() decoding of four buffers against `truth`.
+/// # Arguments
+/// * `par` - the parser under test
+/// * `b1` .. `b4` - byte buffers in stream order
+/// * `truth` - the expected output
+pub fn verify_with_string(par: &mut FromUtf8, b1:& [u8], b2:& [u8], b3:& [u8], b4:& [u8], truth: &str) {
+    verify_buffers_with_string(par, & [b1, b2, b3, b4], truth);
+}
+
+/// Verify utf8_ref_to_char_with_iter() decoding of four buffers against `truth`.
+/// # Arguments
+/// * `par` - the parser under test
+/// * `b1` .. `b4` - byte buffers in stream order
+/// * `truth` - the expected output
+pub fn verify_style2(par: &mut FromUtf8, b1:& [u8], b2:& [u8], b3:& [u8], b4:& [u8], truth: &str) {
+    verify_buffers_style2(par, & [b1, b2, b3, b4], truth);
+}
+
+/// Verify utf8_to_char_with_iter() decoding of four buffers against `truth`.
+/// # Arguments
+/// * `par` - the parser under test
+/// * `b1` .. `b4` - byte buffers in stream order
+/// * `truth` - the expected output
+pub fn verify_style3(par: &mut FromUtf8, b1:& [u8], b2:& [u8], b3:& [u8], b4:& [u8], truth: &str) {
+    verify_buffers_style3(par, & [b1, b2, b3, b4], truth);
+}
+
+/// Verify utf8_to_char_slice() decoding of four buffers against `truth`.
+/// # Arguments
+/// * `par` - the parser under test
+/// * `b1` .. `b4` - byte buffers in stream order
+/// * `truth` - the expected output
+pub fn verify_style4(par: &mut FromUtf8, b1:& [u8], b2:& [u8], b3:& [u8], b4:& [u8], truth: &str) {
+    verify_buffers_style4(par, & [b1, b2, b3, b4], truth);
+}
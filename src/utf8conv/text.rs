@@ -0,0 +1,97 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::text
+
+use alloc::string::String;
+
+use crate::utf8conv::decode_lossy;
+use crate::utf8conv::BomAndCarriageReturnFilterStruct;
+use crate::utf8conv::BOM;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Options of convert_text()
+pub struct TextOptions {
+
+    /// remove a byte order mark at the start of the text
+    pub strip_bom: bool,
+
+    /// replace CR and CR LF line endings with LF
+    pub normalize_newlines: bool,
+}
+
+/// Default for TextOptions, with all clean ups turned on
+impl Default for TextOptions {
+    fn default() -> TextOptions {
+        TextOptions {
+            strip_bom: true,
+            normalize_newlines: true,
+        }
+    }
+}
+
+/// Decode a complete UTF8 input into a String, with the clean ups
+/// chosen in `options`.
+///
+/// Invalid sequences are substituted with replacement characters.
+/// Newlines are normalized with the same rules as
+/// filter_bom_and_cr_iter().
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let bytes = b"\xEF\xBB\xBFone\r\ntwo\rthree\xFF";
+/// assert_eq!("one\ntwo\nthree\u{FFFD}", convert_text(bytes, TextOptions::default()));
+/// ```
+/// # Arguments
+/// * `input` - the complete UTF8 input
+/// * `options` - the clean ups to apply
+pub fn convert_text(input: &[u8], options: TextOptions) -> String {
+    let mut chars = decode_lossy(input);
+    let mut text = String::with_capacity(input.len());
+    if options.normalize_newlines {
+        text.extend(BomAndCarriageReturnFilterStruct {
+            my_borrow_mut_iter: & mut chars,
+            my_start_stream: options.strip_bom,
+            my_prev_cr: false,
+        });
+    }
+    else {
+        let mut chars = chars.peekable();
+        if options.strip_bom {
+            chars.next_if_eq(& BOM);
+        }
+        text.extend(chars);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    #[test]
+    // Test every combination of options
+    fn test_convert_text_options() {
+        let bytes = "\u{FEFF}a\r\nb\r\rc\u{FEFF}".as_bytes();
+        let cases = [
+            (true, true, "a\nb\n\nc\u{FEFF}"),
+            (true, false, "a\r\nb\r\rc\u{FEFF}"),
+            (false, true, "\u{FEFF}a\nb\n\nc\u{FEFF}"),
+            (false, false, "\u{FEFF}a\r\nb\r\rc\u{FEFF}"),
+        ];
+        for (strip_bom, normalize_newlines, truth) in cases.iter().copied() {
+            let options = TextOptions { strip_bom, normalize_newlines };
+            assert_eq!(truth, convert_text(bytes, options));
+        }
+        assert_eq!("\u{FFFD}\n", convert_text(b"\xE2\x82\r", TextOptions::default()));
+        assert_eq!("", convert_text(b"\xEF\xBB\xBF", TextOptions::default()));
+    }
+}
@@ -0,0 +1,357 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::transcoder
+
+use crate::utf8conv::bom::Encoding;
+use crate::utf8conv::recode::Recode;
+use crate::utf8conv::FromUtf8;
+use crate::utf8conv::MoreEnum;
+use crate::utf8conv::UtfParserCommon;
+
+#[derive(Debug, Clone)]
+/// A UTF8 decoder owning a staging buffer of N chars, for callers that
+/// want chars from a stream of byte buffers without handling MoreEnum,
+/// the last buffer flag or the output buffers.
+///
+/// Each buffer is given to push(), and the chars are taken from the
+/// returned iterator; finish() ends the stream and returns the chars
+/// of an incomplete sequence at the end.  The next push() starts a new
+/// stream.  The bytes are decoded N chars at a time with the bulk
+/// parser; N should be at least 1.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut transcoder = BufferedTranscoder::<64>::new();
+/// let mut text = String::new();
+/// for buf in [& b"caf\xC3"[..], b"\xA9 \xE2\x82"] {
+///     text.extend(transcoder.push(buf));
+/// }
+/// text.extend(transcoder.finish());
+/// assert_eq!("caf\u{E9} \u{FFFD}", text);
+/// assert!(transcoder.has_invalid_sequence());
+/// ```
+pub struct BufferedTranscoder<const N: usize> {
+
+    /// the UTF8 parser
+    my_parser: FromUtf8,
+
+    /// chars decoded and not yet returned
+    my_stage: [char; N],
+}
+
+/// Implementation of BufferedTranscoder
+impl<const N: usize> BufferedTranscoder<N> {
+
+    /// Make a new BufferedTranscoder.
+    pub fn new() -> BufferedTranscoder<N> {
+        BufferedTranscoder {
+            my_parser: FromUtf8::new(),
+            my_stage: ['\0'; N],
+        }
+    }
+
+    /// Decode the next buffer of the stream.  Returns an iterator of the
+    /// decoded chars; an incomplete sequence at the end of `input` is
+    /// kept for the next push() or finish().
+    ///
+    /// Run the iterator to the end; chars it did not return are lost.
+    /// # Arguments
+    /// * `input` - the next UTF8 buffer
+    pub fn push<'t, 'b>(&'t mut self, input: &'b [u8]) -> BufferedChars<'t, 'b, N> {
+        if self.my_parser.is_last_buffer() {
+            // The last stream was finished; start a new one.
+            self.my_parser.reset_parser();
+            self.my_parser.set_is_last_buffer(false);
+        }
+        BufferedChars {
+            my_transcoder: self,
+            my_input: input,
+            my_pos: 0,
+            my_len: 0,
+        }
+    }
+
+    /// End the stream.  Returns an iterator of the chars left: a
+    /// replacement character for an incomplete sequence at the end.
+    pub fn finish(&mut self) -> BufferedChars<'_, 'static, N> {
+        self.my_parser.set_is_last_buffer(true);
+        BufferedChars {
+            my_transcoder: self,
+            my_input: &[],
+            my_pos: 0,
+            my_len: 0,
+        }
+    }
+
+    #[inline]
+    /// Returns true if an invalid sequence was found in the current
+    /// stream, or in the last one after finish().
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_parser.has_invalid_sequence()
+    }
+}
+
+/// Default for BufferedTranscoder
+impl<const N: usize> Default for BufferedTranscoder<N> {
+    fn default() -> BufferedTranscoder<N> {
+        BufferedTranscoder::new()
+    }
+}
+
+#[derive(Debug)]
+/// Iterator of the chars decoded from one buffer by BufferedTranscoder
+pub struct BufferedChars<'t, 'b, const N: usize> {
+
+    /// the transcoder with its staging buffer
+    my_transcoder: &'t mut BufferedTranscoder<N>,
+
+    /// input not yet decoded
+    my_input: &'b [u8],
+
+    /// position of the next char in the staging buffer
+    my_pos: usize,
+
+    /// number of chars in the staging buffer
+    my_len: usize,
+}
+
+/// Iterator for BufferedChars
+impl<'t, 'b, const N: usize> Iterator for BufferedChars<'t, 'b, N> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.my_pos >= self.my_len {
+            // Refill the staging buffer.
+            let transcoder = & mut * self.my_transcoder;
+            match transcoder.my_parser.utf8_to_char_slice(self.my_input, & mut transcoder.my_stage) {
+                Result::Ok((rest, num_chars)) => {
+                    self.my_input = rest;
+                    self.my_pos = 0;
+                    self.my_len = num_chars;
+                }
+                Result::Err(_) => {
+                    return Option::None;
+                }
+            }
+        }
+        let ch = self.my_transcoder.my_stage.get(self.my_pos).copied()?;
+        self.my_pos += 1;
+        Option::Some(ch)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// A converter of byte buffers from one Unicode encoding form to
+/// another, for callers that want bytes in and bytes out without
+/// handling MoreEnum or the last buffer flag.
+///
+/// Each buffer is given to transcode(), with `last` set on the final
+/// one; a stream may be converted in as many calls as the output
+/// buffer needs.  The next call after the final one starts a new
+/// stream.  The conversion is done by Recode, and `Transcoder::from()`
+/// a Recode turns on its clean ups.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut transcoder = Transcoder::new(Encoding::Utf16Be, Encoding::Utf8);
+/// let mut byte_box = [0u8; 16];
+/// assert_eq!((3, 1), transcoder.transcode(b"\x00a\x20", & mut byte_box, false));
+/// assert_eq!(b"a", & byte_box[.. 1]);
+/// assert_eq!((1, 3), transcoder.transcode(b"\xAC", & mut byte_box, true));
+/// assert_eq!("\u{20AC}".as_bytes(), & byte_box[.. 3]);
+/// ```
+pub struct Transcoder {
+
+    /// the converter
+    my_recode: Recode,
+
+    /// true after the final buffer was converted completely
+    my_finished: bool,
+}
+
+/// From a Recode, keeping its encodings and clean ups
+impl From<Recode> for Transcoder {
+    fn from(recode: Recode) -> Transcoder {
+        let mut recode = recode;
+        recode.reset_parser();
+        Transcoder {
+            my_recode: recode,
+            my_finished: false,
+        }
+    }
+}
+
+/// Implementation of Transcoder
+impl Transcoder {
+
+    /// Make a new Transcoder with no clean ups turned on.
+    /// # Arguments
+    /// * `from` - the input encoding
+    /// * `to` - the output encoding
+    pub fn new(from: Encoding, to: Encoding) -> Transcoder {
+        Transcoder::from(Recode::from(from).to(to))
+    }
+
+    #[inline]
+    /// Returns the input encoding.
+    pub fn input_encoding(&self) -> Encoding {
+        self.my_recode.input_encoding()
+    }
+
+    #[inline]
+    /// Returns the output encoding.
+    pub fn output_encoding(&self) -> Encoding {
+        self.my_recode.output_encoding()
+    }
+
+    /// Convert `input` into `output`, and return the number of input
+    /// bytes read and the number of output bytes written.
+    ///
+    /// The conversion stops when the input is used up, or when the
+    /// output is full.  Input bytes not read should be given again to
+    /// the next call.  With `last` set, the end of the stream is
+    /// reached when all of `input` was read and `output` is not full.
+    /// # Arguments
+    /// * `input` - the input bytes
+    /// * `output` - the output buffer
+    /// * `last` - true when `input` ends the stream
+    pub fn transcode(&mut self, input: &[u8], output: &mut [u8], last: bool) -> (usize, usize) {
+        if self.my_finished {
+            // The last stream was finished; start a new one.
+            self.my_recode.reset_parser();
+            self.my_finished = false;
+        }
+        self.my_recode.set_is_last_buffer(last);
+        let mut cur_slice = input;
+        let mut written:usize = 0;
+        while written < output.len() {
+            match self.my_recode.recode_to_slice(cur_slice, & mut output[written ..]) {
+                Result::Ok((rest, len)) => {
+                    cur_slice = rest;
+                    written += len;
+                }
+                Result::Err(more) => {
+                    // The converter holds the rest of the input.
+                    cur_slice = &[];
+                    self.my_finished = more == MoreEnum::More(0);
+                    break;
+                }
+            }
+        }
+        (input.len() - cur_slice.len(), written)
+    }
+
+    #[inline]
+    /// Returns true if an invalid sequence was found in the current
+    /// stream, or in the last one after it was finished.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_recode.has_invalid_sequence()
+    }
+
+    /// Drop the current stream; the next call starts a new one.
+    pub fn reset(&mut self) {
+        self.my_recode.reset_parser();
+        self.my_finished = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+    use crate::utf8conv::test_fixtures::encode_text;
+
+    use std::string::String;
+    use std::vec::Vec;
+
+    // decode `bytes` split at `split` with a staging buffer of N chars
+    fn transcode<const N: usize>(transcoder: & mut BufferedTranscoder<N>, bytes: &[u8], split: usize) -> String {
+        let mut text = String::new();
+        text.extend(transcoder.push(& bytes[.. split]));
+        text.extend(transcoder.push(& bytes[split ..]));
+        text.extend(transcoder.finish());
+        text
+    }
+
+    #[test]
+    // Test every split of an input against from_utf8_lossy()
+    fn test_buffered_transcoder() {
+        let bytes = b"ASCII \xC3\xA9\xE2\x82\xAC\xF0\x9F\x98\x80 \xFF\xC0\xAF\xED\xA0\x80 end\xF0\x9F";
+        let expected = String::from_utf8_lossy(bytes);
+        let mut small = BufferedTranscoder::<1>::new();
+        let mut medium = BufferedTranscoder::<3>::default();
+        let mut large = BufferedTranscoder::<64>::new();
+        for split in 0 .. bytes.len() + 1 {
+            assert_eq!(expected, transcode(& mut small, bytes, split));
+            assert_eq!(expected, transcode(& mut medium, bytes, split));
+            assert_eq!(expected, transcode(& mut large, bytes, split));
+            assert!(large.has_invalid_sequence());
+        }
+        // A new stream after finish() is decoded from a clean state.
+        assert_eq!("ok", transcode(& mut large, b"ok", 1));
+        assert!(! large.has_invalid_sequence());
+        // An empty staging buffer decodes nothing.
+        let mut empty = BufferedTranscoder::<0>::new();
+        assert_eq!("", transcode(& mut empty, b"abc", 1));
+    }
+
+    #[test]
+    // Test every pair of encodings, with input split at every position
+    fn test_transcoder() {
+        let encodings = [
+            Encoding::Utf8,
+            Encoding::Utf16Le,
+            Encoding::Utf16Be,
+            Encoding::Utf32Le,
+            Encoding::Utf32Be,
+        ];
+        let text = "a\u{E9}\u{10348}\u{20AC}z";
+        for from in encodings {
+            for to in encodings {
+                let input = encode_text(text, from);
+                let truth = encode_text(text, to);
+                let mut transcoder = Transcoder::new(from, to);
+                assert_eq!((from, to), (transcoder.input_encoding(), transcoder.output_encoding()));
+                for split in 0 ..= input.len() {
+                    let mut output = Vec::new();
+                    let mut byte_box = [0u8; 3];
+                    let parts = [& input[.. split], & input[split ..]];
+                    for (num, part) in parts.iter().enumerate() {
+                        let last = num == 1;
+                        let mut cur_slice = * part;
+                        loop {
+                            let (read, len) = transcoder.transcode(cur_slice, & mut byte_box, last);
+                            output.extend_from_slice(& byte_box[.. len]);
+                            cur_slice = & cur_slice[read ..];
+                            if cur_slice.is_empty() && (len < byte_box.len()) {
+                                break;
+                            }
+                        }
+                    }
+                    assert_eq!(truth, output);
+                    assert!(! transcoder.has_invalid_sequence());
+                }
+            }
+        }
+        // Clean ups of a Recode, and a new stream after an invalid one
+        let mut transcoder = Transcoder::from(Recode::from(Encoding::Utf8).strip_bom().normalize_newlines());
+        let mut byte_box = [0u8; 16];
+        assert_eq!((7, 5), transcoder.transcode(b"\xEF\xBB\xBFa\r\n\xE2", & mut byte_box, true));
+        assert_eq!(b"a\n\xEF\xBF\xBD"[..], byte_box[.. 5]);
+        assert!(transcoder.has_invalid_sequence());
+        assert_eq!((4, 1), transcoder.transcode(b"\xEF\xBB\xBFb", & mut byte_box, false));
+        assert!(! transcoder.has_invalid_sequence());
+        transcoder.reset();
+        assert_eq!((1, 0), transcoder.transcode(b"\xE2", & mut byte_box, false));
+        assert_eq!((0, 0), transcoder.transcode(b"c", & mut [], false));
+    }
+}
@@ -0,0 +1,731 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::utf16
+
+use core::fmt;
+
+use crate::utf8conv::Decoder;
+use crate::utf8conv::MoreEnum;
+use crate::utf8conv::UtfParserCommon;
+
+#[inline]
+/// Returns true if `unit` is a high (leading) surrogate.
+fn is_high_surrogate(unit: u16) -> bool {
+    (0xD800 ..= 0xDBFF).contains(& unit)
+}
+
+#[inline]
+/// Returns true if `unit` is a low (trailing) surrogate.
+fn is_low_surrogate(unit: u16) -> bool {
+    (0xDC00 ..= 0xDFFF).contains(& unit)
+}
+
+/// Provides conversion functions from UTF16 code units to char, with
+/// the same streaming interface as FromUtf8.
+///
+/// A surrogate pair split between buffers is kept by the parser until
+/// the next buffer.  An unpaired surrogate, including a high surrogate
+/// at the end of the last buffer, is indicated by a Unicode replacement
+/// character; the code unit following an unpaired high surrogate is
+/// decoded again.  As with FromUtf8, a replacement character in the
+/// input is decoded, and counted as an invalid sequence.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut parser = FromUtf16::new();
+/// let mut char_box = ['\0'; 8];
+/// let units = [0x41u16, 0xD83Du16, 0xDE00u16, 0xDC00u16, 0x42u16];
+/// let (_rest, num_chars) = parser.utf16_to_char_slice(& units, & mut char_box).unwrap();
+/// assert_eq!(['A', '\u{1F600}', '\u{FFFD}', 'B'], char_box[.. num_chars]);
+/// assert!(parser.has_invalid_sequence());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FromUtf16 {
+    my_pending: Option<u16>,
+    my_last_buffer: bool,
+    my_invalid_sequence: bool,
+}
+
+/// adapter iterator converting from an UTF16 iterator to a char iterator
+/// (This iterator contains a mutable borrow to the launching
+/// FromUtf16 object while this iterator is alive.)
+pub struct Utf16IterToCharIter<'p, I: ?Sized = dyn Iterator<Item = u16> + 'p> {
+
+    /// the source iterator
+    my_borrow_mut_iter: &'p mut I,
+
+    /// mutable reference to FromUtf16 object
+    my_info: &'p mut FromUtf16,
+}
+
+/// adapter iterator converting from an UTF16 reference iterator to a
+/// char iterator
+/// (This iterator contains a mutable borrow to the launching
+/// FromUtf16 object while this iterator is alive.)
+pub struct Utf16RefIterToCharIter<'r, I: ?Sized = dyn Iterator<Item = &'r u16> + 'r> {
+
+    /// the source iterator
+    my_borrow_mut_iter: &'r mut I,
+
+    /// mutable reference to FromUtf16 object
+    my_info: &'r mut FromUtf16,
+}
+
+/// adapter iterator converting from an UTF16 iterator to an UTF8 iterator
+/// (This iterator contains a mutable borrow to the launching
+/// FromUtf16 object while this iterator is alive.)
+pub struct Utf16IterToUtf8Iter<'q, I: ?Sized = dyn Iterator<Item = u16> + 'q> {
+
+    /// the UTF8 bytes of the current char
+    my_bytes: [u8; 4],
+
+    /// position of the next byte in my_bytes
+    my_pos: u8,
+
+    /// number of bytes in my_bytes
+    my_len: u8,
+
+    /// the source iterator
+    my_borrow_mut_iter: &'q mut I,
+
+    /// mutable reference to FromUtf16 object
+    my_info: &'q mut FromUtf16,
+}
+
+/// Implementation of FromUtf16
+impl FromUtf16 {
+
+    /// Make a new FromUtf16
+    pub fn new() -> FromUtf16 {
+        FromUtf16 {
+            my_pending: Option::None,
+            my_last_buffer: true,
+            my_invalid_sequence: false,
+        }
+    }
+
+    #[inline]
+    /// Returns the MoreEnum to report when no char can be produced.
+    fn more_indication(&self) -> MoreEnum {
+        if self.my_last_buffer {
+            // at end of data condition
+            MoreEnum::More(0)
+        }
+        else if self.my_pending.is_some() {
+            // The low surrogate of a pair is expected.
+            MoreEnum::More(1)
+        }
+        else {
+            // Returning an indication to request a new buffer.
+            MoreEnum::More(4096)
+        }
+    }
+
+    /// Decode one char from the code units of `next`.
+    ///
+    /// 'None' is returned when no char can be produced: `next` is
+    /// drained, and the parser holds nothing or a high surrogate while
+    /// more buffers are expected.
+    /// # Arguments
+    /// * `next` - returns the next code unit of the current buffer
+    pub(crate) fn decode_from<F: FnMut() -> Option<u16>>(&mut self, mut next: F) -> Option<char> {
+        let first = match self.my_pending.take() {
+            Option::Some(unit) => unit,
+            Option::None => next()?,
+        };
+        if is_high_surrogate(first) {
+            match next() {
+                Option::Some(second) if is_low_surrogate(second) => {
+                    let code = 0x10000 + ((((first & 0x3FF) as u32) << 10) | ((second & 0x3FF) as u32));
+                    return char::from_u32(code);
+                }
+                Option::Some(second) => {
+                    // The unit after an unpaired high surrogate is
+                    // decoded again.
+                    self.my_pending = Option::Some(second);
+                }
+                Option::None if ! self.my_last_buffer => {
+                    // Keep the high surrogate for the next buffer.
+                    self.my_pending = Option::Some(first);
+                    return Option::None;
+                }
+                Option::None => {
+                    // truncated surrogate pair at end of data
+                }
+            }
+            self.signal_invalid_sequence();
+            Option::Some(char::REPLACEMENT_CHARACTER)
+        }
+        else if is_low_surrogate(first) {
+            self.signal_invalid_sequence();
+            Option::Some(char::REPLACEMENT_CHARACTER)
+        }
+        else {
+            if first == 0xFFFD {
+                // Encountering a replacement character is considered the
+                // same as having an invalid decode.
+                self.signal_invalid_sequence();
+            }
+            char::from_u32(first as u32)
+        }
+    }
+
+    /// A parser takes in an UTF16 slice, and returns a Result object
+    /// with either the remaining input and the output char value, or an
+    /// MoreEnum that requests additional data, or an end of data stream
+    /// condition.
+    ///
+    /// Unpaired surrogates are indicated by Unicode replacement
+    /// characters.  has_invalid_sequence() would return true after this
+    /// event.
+    pub fn utf16_to_char<'b>(&mut self, input: &'b [u16])
+    -> Result<(&'b [u16], char), MoreEnum> {
+        let mut my_cursor = input;
+        let next = || {
+            let (first, rest) = my_cursor.split_first()?;
+            my_cursor = rest;
+            Option::Some(* first)
+        };
+        match self.decode_from(next) {
+            Option::Some(ch) => Result::Ok((my_cursor, ch)),
+            Option::None => Result::Err(self.more_indication()),
+        }
+    }
+
+    /// A bulk parser takes in an UTF16 slice, and fills the `output`
+    /// slice with decoded char values.  Returns a Result object with
+    /// either the remaining input and the number of chars written, or an
+    /// MoreEnum that requests additional data, or an end of data stream
+    /// condition.
+    ///
+    /// The MoreEnum conditions are the same as utf16_to_char(); they are
+    /// only reported when not a single char could be written.
+    pub fn utf16_to_char_slice<'b>(&mut self, input: &'b [u16], output: &mut [char])
+    -> Result<(&'b [u16], usize), MoreEnum> {
+        let mut my_cursor = input;
+        let mut written: usize = 0;
+        for slot in output.iter_mut() {
+            match self.utf16_to_char(my_cursor) {
+                Result::Ok((slice_pos, ch)) => {
+                    * slot = ch;
+                    written += 1;
+                    my_cursor = slice_pos;
+                }
+                Result::Err(en) => {
+                    if written == 0 {
+                        return Result::Err(en);
+                    }
+                    // A high surrogate at the end of the input is now
+                    // kept by the parser.
+                    my_cursor = my_cursor.get(my_cursor.len() ..).unwrap_or(&[]);
+                    break;
+                }
+            }
+        }
+        Result::Ok((my_cursor, written))
+    }
+
+    /// Convert from UTF16 to char with a mutable reference
+    /// to the source UTF16 iterator.
+    pub fn utf16_to_char_with_iter<'d, I>(&'d mut self, iter: &'d mut I)
+    -> Utf16IterToCharIter<'d, I>
+    where I: Iterator<Item = u16> + ?Sized, {
+        Utf16IterToCharIter {
+            my_info : self,
+            my_borrow_mut_iter: iter,
+        }
+    }
+
+    /// Convert from UTF16 to UTF8 with a mutable reference to the source
+    /// UTF16 iterator, in a single pass.  Unpaired surrogates become
+    /// replacement characters.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut parser = FromUtf16::new();
+    /// let mut iter = [0x61u16, 0xD83Du16, 0xDE00u16, 0xDC00u16].iter().copied();
+    /// let bytes: Vec<u8> = parser.utf16_to_utf8_with_iter(& mut iter).collect();
+    /// assert_eq!(b"a\xF0\x9F\x98\x80\xEF\xBF\xBD", & bytes[..]);
+    /// assert!(parser.has_invalid_sequence());
+    /// ```
+    pub fn utf16_to_utf8_with_iter<'d, I>(&'d mut self, iter: &'d mut I)
+    -> Utf16IterToUtf8Iter<'d, I>
+    where I: Iterator<Item = u16> + ?Sized, {
+        Utf16IterToUtf8Iter {
+            my_bytes: [0; 4],
+            my_pos: 0,
+            my_len: 0,
+            my_borrow_mut_iter: iter,
+            my_info : self,
+        }
+    }
+
+    /// Convert from UTF16 reference to char with a mutable reference
+    /// to the source UTF16 iterator.
+    pub fn utf16_ref_to_char_with_iter<'d, 'c, I>(&'d mut self, iter: &'d mut I)
+    -> Utf16RefIterToCharIter<'d, I>
+    where I: Iterator<Item = &'c u16> + ?Sized, {
+        Utf16RefIterToCharIter {
+            my_info : self,
+            my_borrow_mut_iter: iter,
+        }
+    }
+}
+
+/// Default for FromUtf16 is the same as FromUtf16::new()
+impl Default for FromUtf16 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implementations of common operations for FromUtf16
+impl UtfParserCommon for FromUtf16 {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_last_buffer = b;
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_last_buffer
+    }
+
+    #[inline]
+    /// This function returns true if an unpaired surrogate occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_invalid_sequence
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_invalid_sequence = true;
+    }
+
+    #[inline]
+    /// This function resets the invalid sequence state.
+    fn reset_invalid_sequence(&mut self) {
+        self.my_invalid_sequence = false;
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid sequence indication is cleared.
+    /// A kept high surrogate is dropped.
+    fn reset_parser(&mut self) {
+        * self = FromUtf16::new();
+    }
+}
+
+/// Decoder implementation of FromUtf16
+impl Decoder for FromUtf16 {
+    type Unit = u16;
+
+    #[inline]
+    /// Same as utf16_to_char()
+    fn decode_char<'b>(&mut self, input: &'b [u16])
+    -> Result<(&'b [u16], char), MoreEnum> {
+        self.utf16_to_char(input)
+    }
+
+    #[inline]
+    /// Same as utf16_to_char_slice()
+    fn decode_to_slice<'b>(&mut self, input: &'b [u16], output: &mut [char])
+    -> Result<(&'b [u16], usize), MoreEnum> {
+        self.utf16_to_char_slice(input, output)
+    }
+}
+
+/// Implementation of Utf16IterToCharIter
+impl<'g, I: ?Sized> Utf16IterToCharIter<'g, I> {
+
+    #[inline]
+    /// Point this adapter at the iterator of the next buffer, and return
+    /// the previous source iterator.
+    ///
+    /// The parser state, including a high surrogate from the end of the
+    /// previous buffer, is kept, so one adapter can serve every buffer
+    /// of a stream.  Set the last buffer flag before presenting the last
+    /// buffer.
+    /// # Arguments
+    /// * `iter` - the source iterator of the next buffer
+    pub fn set_source(&mut self, iter: &'g mut I) -> &'g mut I {
+        core::mem::replace(& mut self.my_borrow_mut_iter, iter)
+    }
+}
+
+/// Implementations of common operations for Utf16IterToCharIter
+impl<'g, I: ?Sized> UtfParserCommon for Utf16IterToCharIter<'g, I> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_info.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_info.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if an unpaired surrogate occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_info.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_info.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid sequence state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_info.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid sequence indication is cleared.
+    fn reset_parser(&mut self) {
+        self.my_info.reset_parser();
+    }
+}
+
+/// Iterator for Utf16IterToCharIter
+impl<'g, I> Iterator for Utf16IterToCharIter<'g, I>
+where I: Iterator<Item = u16> + ?Sized, {
+    type Item = char;
+
+    /// A parser takes in an iterator of UTF16 code units, and returns
+    /// an iterator of char values.
+    ///
+    /// An unpaired surrogate in the stream is substituted with an
+    /// Unicode replacement character.
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter = & mut * self.my_borrow_mut_iter;
+        self.my_info.decode_from(|| iter.next())
+    }
+}
+
+/// Implementation of Utf16RefIterToCharIter
+impl<'g, I: ?Sized> Utf16RefIterToCharIter<'g, I> {
+
+    #[inline]
+    /// Point this adapter at the iterator of the next buffer, and return
+    /// the previous source iterator.
+    ///
+    /// The parser state, including a high surrogate from the end of the
+    /// previous buffer, is kept, so one adapter can serve every buffer
+    /// of a stream.  Set the last buffer flag before presenting the last
+    /// buffer.
+    /// # Arguments
+    /// * `iter` - the source iterator of the next buffer
+    pub fn set_source(&mut self, iter: &'g mut I) -> &'g mut I {
+        core::mem::replace(& mut self.my_borrow_mut_iter, iter)
+    }
+}
+
+/// Implementations of common operations for Utf16RefIterToCharIter
+impl<'g, I: ?Sized> UtfParserCommon for Utf16RefIterToCharIter<'g, I> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_info.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_info.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if an unpaired surrogate occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_info.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_info.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid sequence state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_info.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid sequence indication is cleared.
+    fn reset_parser(&mut self) {
+        self.my_info.reset_parser();
+    }
+}
+
+/// Iterator for Utf16RefIterToCharIter
+impl<'g, 'c, I> Iterator for Utf16RefIterToCharIter<'g, I>
+where I: Iterator<Item = &'c u16> + ?Sized, {
+    type Item = char;
+
+    /// A parser takes in an iterator of UTF16 code unit references, and
+    /// returns an iterator of char values.
+    ///
+    /// An unpaired surrogate in the stream is substituted with an
+    /// Unicode replacement character.
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter = & mut * self.my_borrow_mut_iter;
+        self.my_info.decode_from(|| iter.next().copied())
+    }
+}
+
+/// Implementation of Utf16IterToUtf8Iter
+impl<'g, I: ?Sized> Utf16IterToUtf8Iter<'g, I> {
+
+    #[inline]
+    /// Point this adapter at the iterator of the next buffer, and return
+    /// the previous source iterator.
+    ///
+    /// The parser state, including a high surrogate from the end of the
+    /// previous buffer, is kept, so one adapter can serve every buffer
+    /// of a stream.  Set the last buffer flag before presenting the last
+    /// buffer.
+    /// # Arguments
+    /// * `iter` - the source iterator of the next buffer
+    pub fn set_source(&mut self, iter: &'g mut I) -> &'g mut I {
+        core::mem::replace(& mut self.my_borrow_mut_iter, iter)
+    }
+}
+
+/// Implementations of common operations for Utf16IterToUtf8Iter
+impl<'g, I: ?Sized> UtfParserCommon for Utf16IterToUtf8Iter<'g, I> {
+
+    #[inline]
+    /// If argument `b` is true, then any input buffer to be presented will
+    /// be the last buffer.
+    fn set_is_last_buffer(&mut self, b: bool) {
+        self.my_info.set_is_last_buffer(b);
+    }
+
+    #[inline]
+    /// Returns the last input buffer flag.
+    fn is_last_buffer(&self) -> bool {
+        self.my_info.is_last_buffer()
+    }
+
+    #[inline]
+    /// This function returns true if an unpaired surrogate occurred
+    /// in this parsing stream.
+    fn has_invalid_sequence(&self) -> bool {
+        self.my_info.has_invalid_sequence()
+    }
+
+    #[inline]
+    /// This function signals the occurrence of an invalid sequence.
+    fn signal_invalid_sequence(&mut self) {
+        self.my_info.signal_invalid_sequence();
+    }
+
+    #[inline]
+    /// This function resets the invalid sequence state.
+    fn reset_invalid_sequence(& mut self) {
+        self.my_info.reset_invalid_sequence();
+    }
+
+    #[inline]
+    /// Reset all parser states to the initial value.
+    /// Last buffer indication is set to true.
+    /// Invalid sequence indication is cleared.
+    /// Bytes of the current char not yet returned are dropped.
+    fn reset_parser(&mut self) {
+        self.my_pos = 0;
+        self.my_len = 0;
+        self.my_info.reset_parser();
+    }
+}
+
+/// Iterator for Utf16IterToUtf8Iter
+impl<'g, I> Iterator for Utf16IterToUtf8Iter<'g, I>
+where I: Iterator<Item = u16> + ?Sized, {
+    type Item = u8;
+
+    /// A parser takes in an iterator of UTF16 code units, and returns
+    /// an iterator of UTF8 bytes.
+    ///
+    /// An unpaired surrogate in the stream is substituted with an
+    /// Unicode replacement character.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.my_pos >= self.my_len {
+            let iter = & mut * self.my_borrow_mut_iter;
+            let ch = self.my_info.decode_from(|| iter.next())?;
+            self.my_len = ch.encode_utf8(& mut self.my_bytes).len() as u8;
+            self.my_pos = 0;
+        }
+        let v = self.my_bytes.get(self.my_pos as usize).copied();
+        self.my_pos += 1;
+        v
+    }
+}
+
+impl<'g, I: ?Sized> fmt::Debug for Utf16IterToUtf8Iter<'g, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf16IterToUtf8Iter")
+        .field("parser", & self.my_info)
+        .field("pending_len", & (self.my_len - self.my_pos.min(self.my_len)))
+        .finish_non_exhaustive()
+    }
+}
+
+impl<'g, I: ?Sized> fmt::Debug for Utf16IterToCharIter<'g, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf16IterToCharIter")
+        .field("parser", & self.my_info)
+        .finish_non_exhaustive()
+    }
+}
+
+impl<'g, I: ?Sized> fmt::Debug for Utf16RefIterToCharIter<'g, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf16RefIterToCharIter")
+        .field("parser", & self.my_info)
+        .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::vec::Vec;
+
+    /// Decode a complete input split into two buffers at `split`, with
+    /// the slice parser and with the iterator adapter.
+    fn decode_split(units: &[u16], split: usize) -> (Vec<char>, Vec<char>, bool) {
+        let (head, tail) = units.split_at(split);
+        let mut parser = FromUtf16::new();
+        let mut chars = Vec::new();
+        for (indx, buf) in [head, tail].iter().enumerate() {
+            parser.set_is_last_buffer(indx == 1);
+            let mut cur_slice = * buf;
+            let mut char_box = ['\0'; 3];
+            // The loop stops at MoreEnum::More(_amt);
+            // _amt equals to 0 when end of data
+            while let Result::Ok((slice_pos, num_chars)) = parser.utf16_to_char_slice(cur_slice, & mut char_box) {
+                cur_slice = slice_pos;
+                chars.extend_from_slice(& char_box[.. num_chars]);
+            }
+        }
+        let mut iter_parser = FromUtf16::new();
+        iter_parser.set_is_last_buffer(false);
+        let mut head_iter = head.iter();
+        let mut tail_iter = tail.iter();
+        let mut adapter = iter_parser.utf16_ref_to_char_with_iter(& mut head_iter);
+        let mut iter_chars: Vec<char> = adapter.by_ref().collect();
+        adapter.set_source(& mut tail_iter);
+        adapter.set_is_last_buffer(true);
+        iter_chars.extend(adapter.by_ref());
+        assert_eq!(parser.has_invalid_sequence(), adapter.has_invalid_sequence());
+        (chars, iter_chars, parser.has_invalid_sequence())
+    }
+
+    #[test]
+    // Test against char::decode_utf16() over every split of the input,
+    // including surrogate pairs split between buffers
+    fn test_utf16_differential() {
+        let cases: [&[u16]; 10] = [
+            & [0x41, 0x20AC, 0xD83D, 0xDE00, 0xFFFF, 0x42],
+            & [0xD800, 0xDC00, 0xDBFF, 0xDFFF],
+            & [0xD800],
+            & [0xDC00, 0x41],
+            & [0xD800, 0x41, 0xDC00],
+            & [0xD800, 0xD800, 0xDC00],
+            & [0xDBFF, 0xDBFF],
+            & [0x41, 0xDFFF, 0xD83D],
+            & [0xFFFD, 0x0],
+            & [],
+        ];
+        for units in cases.iter() {
+            let truth: Vec<char> = char::decode_utf16(units.iter().copied())
+                .map(|v| v.unwrap_or(char::REPLACEMENT_CHARACTER)).collect();
+            // A replacement character in the input also counts as invalid.
+            let invalid = truth.contains(& char::REPLACEMENT_CHARACTER);
+            for split in 0 ..= units.len() {
+                let (chars, iter_chars, found_invalid) = decode_split(units, split);
+                assert_eq!(truth, chars, "units {:04X?} split {}", units, split);
+                assert_eq!(truth, iter_chars, "units {:04X?} split {}", units, split);
+                assert_eq!(invalid, found_invalid);
+            }
+        }
+    }
+
+    #[test]
+    // Test UTF16 to UTF8 transcoding against String::from_utf16_lossy(),
+    // over buffer splits
+    fn test_utf16_to_utf8_iter() {
+        let units = [0x41u16, 0xE9, 0x20AC, 0xD83D, 0xDE00, 0xDC00, 0xD800, 0x42, 0xDBFF, 0xDFFF, 0xD83D];
+        let truth = std::string::String::from_utf16_lossy(& units);
+        for split in 0 ..= units.len() {
+            let mut parser = FromUtf16::new();
+            parser.set_is_last_buffer(false);
+            let mut head = units[.. split].iter().copied();
+            let mut tail = units[split ..].iter().copied();
+            let mut iter = parser.utf16_to_utf8_with_iter(& mut head);
+            let mut bytes: Vec<u8> = iter.by_ref().collect();
+            iter.set_source(& mut tail);
+            iter.set_is_last_buffer(true);
+            bytes.extend(iter.by_ref());
+            assert_eq!(truth.as_bytes(), & bytes[..], "split {}", split);
+            assert!(iter.has_invalid_sequence());
+        }
+    }
+
+    #[test]
+    // Test the MoreEnum conditions, the owned iterator adapter and finish()
+    fn test_utf16_more() {
+        let mut parser = FromUtf16::new();
+        parser.set_is_last_buffer(false);
+        assert_eq!(Result::Err(MoreEnum::More(4096)), parser.utf16_to_char(& []));
+        assert_eq!(Result::Err(MoreEnum::More(1)), parser.utf16_to_char(& [0xD83D]));
+        assert_eq!(Result::Ok((& [0x41u16][..], '\u{1F600}')), parser.utf16_to_char(& [0xDE00, 0x41]));
+        let units = [0x61u16, 0xD83D];
+        let mut iter = units.iter().copied();
+        let chars: Vec<char> = parser.utf16_to_char_with_iter(& mut iter).collect();
+        assert_eq!(std::vec!['a'], chars);
+        assert_eq!(Option::Some('\u{FFFD}'), parser.finish());
+        assert_eq!(Option::None, parser.finish());
+        assert_eq!(Result::Err(MoreEnum::More(0)), parser.utf16_to_char(& []));
+        parser.reset_parser();
+        assert_eq!(FromUtf16::default(), parser);
+    }
+}
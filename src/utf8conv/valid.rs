@@ -0,0 +1,344 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::valid
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::utf8conv::diag::FromUtf8WithDiagnostics;
+use crate::utf8conv::diag::Utf8Diagnostic;
+use crate::utf8conv::find_non_ascii;
+use crate::utf8conv::format_into;
+use crate::utf8conv::Utf8ErrorKind;
+
+/// number of chars decoded at a time by the validator
+const VALIDATE_BOX_SIZE: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// The first invalid sequence found by ValidUtf8::new()
+pub struct Utf8ValidationError {
+
+    /// number of bytes before the invalid sequence
+    pub valid_up_to: usize,
+
+    /// length of the invalid sequence in bytes
+    pub len: usize,
+
+    /// the kind of the invalid sequence
+    pub kind: Utf8ErrorKind,
+}
+
+/// Display for Utf8ValidationError
+impl fmt::Display for Utf8ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.valid_up_to)
+    }
+}
+
+/// Error for Utf8ValidationError
+impl core::error::Error for Utf8ValidationError {}
+
+/// Implementation of Utf8ValidationError
+impl Utf8ValidationError {
+
+    /// Write the Display message into `buf` without allocation, and
+    /// return it.  A message too long for the buffer is cut short.
+    /// # Arguments
+    /// * `buf` - the output buffer
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        format_into(self, buf)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A byte slice proven to be valid UTF8 by the validator of this crate.
+///
+/// A ValidUtf8 can only be made by ValidUtf8::new() or TryFrom, so APIs
+/// taking one can skip validation.  The crate policy applies: an encoded
+/// replacement character counts as an invalid sequence, so decoding a
+/// ValidUtf8 never produces a replacement character.
+///
+/// ```rust
+/// use core::convert::TryFrom;
+/// use utf8conv::*;
+///
+/// let valid = ValidUtf8::try_from("a\u{20AC}".as_bytes()).unwrap();
+/// assert_eq!("a\u{20AC}", valid.as_str());
+/// let error = ValidUtf8::new(b"ab\xE2\x82").unwrap_err();
+/// assert_eq!((2, 2, Utf8ErrorKind::Truncated), (error.valid_up_to, error.len, error.kind));
+/// ```
+pub struct ValidUtf8<'a>(&'a [u8]);
+
+/// Implementation of ValidUtf8
+impl<'a> ValidUtf8<'a> {
+
+    /// Validate `input`, returning the first invalid sequence on failure.
+    /// # Arguments
+    /// * `input` - the complete UTF8 input
+    pub fn new(input: &'a [u8]) -> Result<ValidUtf8<'a>, Utf8ValidationError> {
+        match find_invalid(input) {
+            Option::None => Result::Ok(ValidUtf8(input)),
+            Option::Some(error) => Result::Err(error),
+        }
+    }
+
+    #[inline]
+    /// Returns the bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    #[inline]
+    /// Returns the bytes as a string slice, without validating again.
+    pub fn as_str(&self) -> &'a str {
+        #[cfg(not(feature = "safe"))]
+        {
+            // Unsafe is justified because the bytes were validated when
+            // this ValidUtf8 was made.
+            unsafe { core::str::from_utf8_unchecked(self.0) }
+        }
+        #[cfg(feature = "safe")]
+        {
+            core::str::from_utf8(self.0).unwrap_or("")
+        }
+    }
+
+    #[inline]
+    /// Returns an iterator of the chars, without validating again.
+    pub fn chars(&self) -> core::str::Chars<'a> {
+        self.as_str().chars()
+    }
+
+    #[inline]
+    /// Length in bytes
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    /// Returns true when there are no bytes
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Find the first invalid sequence of `input`.
+///
+/// Runs of ASCII bytes are skipped with find_non_ascii(); only the
+/// bytes between them go through the parser.
+fn find_invalid(input: &[u8]) -> Option<Utf8ValidationError> {
+    let mut start: usize = 0;
+    loop {
+        start += find_non_ascii(input.get(start ..).unwrap_or(&[]))?;
+        let rest = input.get(start ..).unwrap_or(&[]);
+        // No sequence goes past an ASCII byte.  The ASCII byte is passed
+        // to the parser too, so a sequence it cuts short is classified
+        // the same as in the whole input.
+        let run_len = rest.iter().position(|v| * v < 0x80).unwrap_or(rest.len());
+        let run = rest.get(.. run_len + 1).unwrap_or(rest);
+        if let Option::Some(diag) = first_diagnostic(run) {
+            return Option::Some(Utf8ValidationError {
+                valid_up_to: start + diag.offset as usize,
+                len: diag.len as usize,
+                kind: diag.kind,
+            });
+        }
+        start += run_len;
+    }
+}
+
+/// Decode `input` as a complete stream, up to its first invalid sequence.
+fn first_diagnostic(input: &[u8]) -> Option<Utf8Diagnostic> {
+    let mut entries = [Utf8Diagnostic::default(); 1];
+    let mut parser = FromUtf8WithDiagnostics::new(& mut entries[..]);
+    let mut char_box = ['\0'; VALIDATE_BOX_SIZE];
+    let mut cur_slice = input;
+    // The loop stops at MoreEnum::More(_amt);
+    // _amt equals to 0 when end of data
+    while let Result::Ok((slice_pos, _num_chars)) = parser.utf8_to_char_slice(cur_slice, & mut char_box) {
+        cur_slice = slice_pos;
+        if parser.num_reported() > 0 {
+            break;
+        }
+    }
+    if parser.num_reported() == 0 {
+        return Option::None;
+    }
+    Option::Some(entries[0])
+}
+
+/// TryFrom byte slices for ValidUtf8, the same as ValidUtf8::new()
+impl<'a> TryFrom<&'a [u8]> for ValidUtf8<'a> {
+    type Error = Utf8ValidationError;
+
+    #[inline]
+    fn try_from(input: &'a [u8]) -> Result<ValidUtf8<'a>, Utf8ValidationError> {
+        ValidUtf8::new(input)
+    }
+}
+
+/// AsRef for ValidUtf8
+impl AsRef<[u8]> for ValidUtf8<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A run of bytes given by Utf8Runs
+pub enum Utf8Run<'a> {
+
+    /// a run of valid UTF8, borrowed from the input
+    Valid(&'a str),
+
+    /// one invalid sequence, and its kind
+    Invalid(&'a [u8], Utf8ErrorKind),
+}
+
+#[derive(Debug, Clone)]
+/// An iterator splitting a byte slice into runs of valid UTF8, returned
+/// as string slices without copying, and the invalid sequences between
+/// them.
+///
+/// The crate policy applies: an encoded replacement character is an
+/// invalid sequence.  Runs of ASCII bytes are skipped with
+/// find_non_ascii().
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut runs = utf8_runs(b"caf\xC3\xA9 \xE2\x82!");
+/// assert_eq!(Some(Utf8Run::Valid("caf\u{E9} ")), runs.next());
+/// assert_eq!(Some(Utf8Run::Invalid(b"\xE2\x82", Utf8ErrorKind::InvalidContinuation)), runs.next());
+/// assert_eq!(Some(Utf8Run::Valid("!")), runs.next());
+/// assert_eq!(None, runs.next());
+/// ```
+pub struct Utf8Runs<'a> {
+
+    /// the bytes not yet returned
+    my_rest: &'a [u8],
+}
+
+/// Split `input` into runs of valid UTF8 and invalid sequences.
+/// # Arguments
+/// * `input` - the complete UTF8 input
+pub fn utf8_runs(input: &[u8]) -> Utf8Runs<'_> {
+    Utf8Runs {
+        my_rest: input,
+    }
+}
+
+/// Iterator for Utf8Runs
+impl<'a> Iterator for Utf8Runs<'a> {
+    type Item = Utf8Run<'a>;
+
+    fn next(&mut self) -> Option<Utf8Run<'a>> {
+        if self.my_rest.is_empty() {
+            return Option::None;
+        }
+        let (valid_len, error) = match find_invalid(self.my_rest) {
+            Option::Some(error) => (error.valid_up_to, Option::Some(error)),
+            Option::None => (self.my_rest.len(), Option::None),
+        };
+        if valid_len > 0 {
+            let (valid, rest) = self.my_rest.split_at(valid_len);
+            self.my_rest = rest;
+            return Option::Some(Utf8Run::Valid(ValidUtf8(valid).as_str()));
+        }
+        let error = error?;
+        let (invalid, rest) = self.my_rest.split_at(error.len.clamp(1, self.my_rest.len()));
+        self.my_rest = rest;
+        Option::Some(Utf8Run::Invalid(invalid, error.kind))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    #[test]
+    // Test validation against the standard library, and the crate policy
+    fn test_valid_utf8() {
+        let inputs: [& [u8]; 6] = [
+            "plain \u{7FF}\u{FFFF}\u{10FFFF} text".as_bytes(),
+            b"",
+            b"abc\xF0\x90\x8D",
+            b"\xC0\xAFxyz",
+            b"abcdefghijklmnopqrstuvwxyz0123456789abcdefghij\xED\xA0\x80",
+            "ok\u{FFFD}".as_bytes(),
+        ];
+        let errors = [
+            Option::None,
+            Option::None,
+            Option::Some((3, 3, Utf8ErrorKind::Truncated)),
+            Option::Some((0, 1, Utf8ErrorKind::InvalidLead)),
+            Option::Some((46, 1, Utf8ErrorKind::InvalidContinuation)),
+            Option::Some((2, 3, Utf8ErrorKind::ReplacementCharacter)),
+        ];
+        for (input, error) in inputs.iter().zip(errors.iter()) {
+            match ValidUtf8::new(input) {
+                Result::Ok(valid) => {
+                    assert_eq!(* error, Option::None);
+                    assert_eq!(core::str::from_utf8(input).unwrap(), valid.as_str());
+                    assert_eq!(input.len(), valid.len());
+                    assert!(valid.chars().eq(decode_lossy(input)));
+                }
+                Result::Err(found) => {
+                    assert_eq!(* error, Option::Some((found.valid_up_to, found.len, found.kind)));
+                    if found.kind != Utf8ErrorKind::ReplacementCharacter {
+                        assert_eq!(found.valid_up_to, core::str::from_utf8(input).unwrap_err().valid_up_to());
+                    }
+                }
+            }
+        }
+        let long_ascii = [b'a'; 100];
+        let error = ValidUtf8::new(& [& long_ascii[..], b"\xE2\x82a"].concat()).unwrap_err();
+        assert_eq!((100, 2, Utf8ErrorKind::InvalidContinuation), (error.valid_up_to, error.len, error.kind));
+        let error = ValidUtf8::new(& [& long_ascii[..], b"\xE2\x82"].concat()).unwrap_err();
+        assert_eq!((100, 2, Utf8ErrorKind::Truncated), (error.valid_up_to, error.len, error.kind));
+        let mut buf = [0u8; 64];
+        let error = ValidUtf8::new(b"\x80").unwrap_err();
+        assert_eq!("invalid UTF8 lead byte at byte 0", error.format_into(& mut buf));
+    }
+
+    #[test]
+    // Test the runs against the Utf8Chunks of the standard library
+    fn test_utf8_runs() {
+        let inputs: [& [u8]; 6] = [
+            "plain \u{7FF}\u{FFFF}\u{10FFFF} text".as_bytes(),
+            b"",
+            b"\xC0\xAFxyz\x80\x80",
+            b"abc\xF0\x90\x8D",
+            b"\xE2\x82a\xE2\x82\xACb\xED\xA0\x80\xF4\x90\x80\x80c",
+            b"\xFF\xFE\xFD",
+        ];
+        for input in inputs.iter() {
+            let mut truth = std::vec::Vec::new();
+            for chunk in input.utf8_chunks() {
+                if ! chunk.valid().is_empty() {
+                    truth.push(Utf8Run::Valid(chunk.valid()));
+                }
+                if ! chunk.invalid().is_empty() {
+                    // The kind depends on the bytes after the sequence.
+                    let offset = chunk.invalid().as_ptr() as usize - input.as_ptr() as usize;
+                    let kind = ValidUtf8::new(& input[offset ..]).unwrap_err().kind;
+                    truth.push(Utf8Run::Invalid(chunk.invalid(), kind));
+                }
+            }
+            assert_eq!(truth, utf8_runs(input).collect::<std::vec::Vec<_>>());
+        }
+        let runs: std::vec::Vec<_> = utf8_runs("ok\u{FFFD}!".as_bytes()).collect();
+        assert_eq!(runs, [Utf8Run::Valid("ok"), Utf8Run::Invalid("\u{FFFD}".as_bytes(), Utf8ErrorKind::ReplacementCharacter),
+            Utf8Run::Valid("!")]);
+    }
+}
@@ -0,0 +1,281 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::whatwg
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::utf8conv::diag::DiagnosticSink;
+use crate::utf8conv::diag::FromUtf8WithDiagnostics;
+use crate::utf8conv::diag::Utf8Diagnostic;
+use crate::utf8conv::format_into;
+use crate::utf8conv::sink::CharSink;
+#[cfg(feature = "alloc")]
+use crate::utf8conv::sink::FmtSink;
+use crate::utf8conv::Utf8ErrorKind;
+use crate::utf8conv::UtfParserCommon;
+use crate::utf8conv::BOM;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// Options of TextDecoder, named after the options of the web
+/// TextDecoder constructor.  The default has both turned off.
+pub struct TextDecoderOptions {
+
+    /// stop with an error at the first invalid sequence, instead of
+    /// replacing it with a replacement character
+    pub fatal: bool,
+
+    /// keep a byte order mark at the start of the stream in the output
+    pub ignore_bom: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// The invalid sequence that stopped a fatal TextDecoder
+pub struct TextDecodeError {
+
+    /// byte offset of the sequence from the start of the stream
+    pub offset: u64,
+
+    /// length of the sequence in bytes
+    pub len: u32,
+
+    /// the kind of the sequence
+    pub kind: Utf8ErrorKind,
+}
+
+/// Display for TextDecodeError
+impl fmt::Display for TextDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset)
+    }
+}
+
+/// Error for TextDecodeError
+impl core::error::Error for TextDecodeError {}
+
+/// Implementation of TextDecodeError
+impl TextDecodeError {
+
+    /// Write the Display message into `buf` without allocation, and
+    /// return it.  A message too long for the buffer is cut short.
+    /// # Arguments
+    /// * `buf` - the output buffer
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        format_into(self, buf)
+    }
+}
+
+/// diagnostics sink keeping the latest diagnostic
+#[derive(Debug, Clone, Copy, Default)]
+struct LatestDiagnostic(Option<Utf8Diagnostic>);
+
+/// DiagnosticSink for LatestDiagnostic
+impl DiagnosticSink for LatestDiagnostic {
+    #[inline]
+    fn put_diagnostic(&mut self, diag: Utf8Diagnostic) -> bool {
+        self.0 = Option::Some(diag);
+        true
+    }
+}
+
+/// A UTF8 decoder with the semantics of the web TextDecoder, for code
+/// ported from JavaScript or wasm environments.
+///
+/// - With `fatal`, decoding stops with an error at the first invalid
+///   sequence.  Otherwise invalid sequences become replacement
+///   characters, one for each maximal subpart as in the WHATWG Encoding
+///   standard.  Unlike the rest of the crate, an encoded replacement
+///   character is valid text, as on the web.
+/// - Without `ignore_bom`, a byte order mark at the start of the stream
+///   is removed.
+/// - A decode with `stream` set keeps an incomplete sequence at the end
+///   of the input for the next decode.  A decode without it ends the
+///   stream: an incomplete sequence becomes a replacement character (or
+///   an error), and the next decode starts a new stream.
+#[derive(Debug)]
+pub struct TextDecoder {
+
+    /// the options
+    my_options: TextDecoderOptions,
+
+    /// the UTF8 parser, keeping the latest invalid sequence
+    my_parser: FromUtf8WithDiagnostics<LatestDiagnostic>,
+
+    /// true once the first char of the stream was decoded
+    my_bom_seen: bool,
+
+    /// true when the last decode had `stream` set
+    my_do_not_flush: bool,
+}
+
+/// Implementation of TextDecoder
+impl TextDecoder {
+
+    /// Make a new TextDecoder with `options`.
+    /// # Arguments
+    /// * `options` - the options
+    pub fn new(options: TextDecoderOptions) -> TextDecoder {
+        TextDecoder {
+            my_options: options,
+            my_parser: FromUtf8WithDiagnostics::new(LatestDiagnostic::default()),
+            my_bom_seen: false,
+            my_do_not_flush: false,
+        }
+    }
+
+    #[inline]
+    /// Returns true if invalid sequences are errors.
+    pub fn fatal(&self) -> bool {
+        self.my_options.fatal
+    }
+
+    #[inline]
+    /// Returns true if a byte order mark is kept in the output.
+    pub fn ignore_bom(&self) -> bool {
+        self.my_options.ignore_bom
+    }
+
+    /// Decode `input` into `sink`, and return the number of chars
+    /// written, or the first invalid sequence in fatal mode.
+    ///
+    /// The sink should take every char; chars it rejects are lost.
+    /// After an error, the rest of `input` is dropped.
+    /// # Arguments
+    /// * `input` - the UTF8 input
+    /// * `stream` - true when more input follows
+    /// * `sink` - destination of the chars
+    pub fn decode_to_sink<S: CharSink + ?Sized>(&mut self, input: &[u8], stream: bool, sink: &mut S)
+    -> Result<usize, TextDecodeError> {
+        if ! self.my_do_not_flush {
+            // The last decode ended the stream; start a new one.
+            self.my_parser = FromUtf8WithDiagnostics::new(LatestDiagnostic::default());
+            self.my_bom_seen = false;
+        }
+        self.my_do_not_flush = stream;
+        self.my_parser.set_is_last_buffer(! stream);
+        let mut cur_slice = input;
+        let mut written:usize = 0;
+        loop {
+            let reported = self.my_parser.num_reported();
+            let ch = match self.my_parser.utf8_to_char(cur_slice) {
+                Result::Ok((rest, ch)) => {
+                    cur_slice = rest;
+                    ch
+                }
+                Result::Err(_) => {
+                    return Result::Ok(written);
+                }
+            };
+            if self.my_options.fatal && (self.my_parser.num_reported() != reported) {
+                if let Option::Some(diag) = self.my_parser.sink().0 {
+                    if diag.kind != Utf8ErrorKind::ReplacementCharacter {
+                        return Result::Err(TextDecodeError {
+                            offset: diag.offset,
+                            len: diag.len,
+                            kind: diag.kind,
+                        });
+                    }
+                }
+            }
+            if ! self.my_bom_seen {
+                self.my_bom_seen = true;
+                if (ch == BOM) && ! self.my_options.ignore_bom {
+                    continue;
+                }
+            }
+            if sink.put_char(ch) {
+                written += 1;
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Decode `input` into a String, or return the first invalid
+    /// sequence in fatal mode.
+    ///
+    /// ```rust
+    /// use utf8conv::*;
+    ///
+    /// let mut decoder = TextDecoder::new(TextDecoderOptions::default());
+    /// assert_eq!(Ok(String::from("a")), decoder.decode(b"\xEF\xBB\xBFa\xE2\x82", true));
+    /// assert_eq!(Ok(String::from("\u{20AC}\u{FFFD}")), decoder.decode(b"\xAC\xF0", false));
+    ///
+    /// let options = TextDecoderOptions { fatal: true, ignore_bom: false };
+    /// let mut decoder = TextDecoder::new(options);
+    /// let error = decoder.decode(b"ok\xFF", false).unwrap_err();
+    /// assert_eq!((2, Utf8ErrorKind::InvalidLead), (error.offset, error.kind));
+    /// ```
+    /// # Arguments
+    /// * `input` - the UTF8 input
+    /// * `stream` - true when more input follows
+    pub fn decode(&mut self, input: &[u8], stream: bool) -> Result<String, TextDecodeError> {
+        let mut text = String::with_capacity(input.len());
+        self.decode_to_sink(input, stream, & mut FmtSink(& mut text))?;
+        Result::Ok(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    use std::string::String;
+
+    // decode into a String without the alloc feature
+    fn decode(decoder: & mut TextDecoder, input: &[u8], stream: bool) -> Result<String, TextDecodeError> {
+        let mut text = String::new();
+        decoder.decode_to_sink(input, stream, & mut FmtSink(& mut text))?;
+        Result::Ok(text)
+    }
+
+    #[test]
+    // Test byte order marks, streaming, and encoded replacement characters
+    fn test_text_decoder_lossy() {
+        let mut decoder = TextDecoder::new(TextDecoderOptions::default());
+        let mut text = String::new();
+        for part in [& b"\xEF"[..], b"\xBB", b"\xBF\xEF\xBB\xBF", b"\xEF\xBF\xBDx\xF0\x9F", b"\x98"] {
+            text.push_str(& decode(& mut decoder, part, true).unwrap());
+        }
+        text.push_str(& decode(& mut decoder, b"", false).unwrap());
+        assert_eq!("\u{FEFF}\u{FFFD}x\u{FFFD}", text);
+        // A new stream strips its byte order mark again.
+        assert_eq!(Result::Ok(String::from("b")), decode(& mut decoder, b"\xEF\xBB\xBFb", false));
+        assert_eq!(Result::Ok(String::from("\u{FFFD}\u{FFFD}\u{FFFD}")), decode(& mut decoder, b"\xED\xA0\x80", false));
+
+        let options = TextDecoderOptions { fatal: false, ignore_bom: true };
+        let mut decoder = TextDecoder::new(options);
+        assert!(decoder.ignore_bom() && ! decoder.fatal());
+        assert_eq!(Result::Ok(String::from("\u{FEFF}c")), decode(& mut decoder, b"\xEF\xBB\xBFc", false));
+        let mut char_box = ['\0'; 4];
+        let mut sink = & mut char_box[..];
+        assert_eq!(Result::Ok(2), decoder.decode_to_sink(b"\xC3\xA9\xE2", false, & mut sink));
+        assert_eq!(['\u{E9}', '\u{FFFD}'], char_box[.. 2]);
+    }
+
+    #[test]
+    // Test errors of the fatal mode, and decoding after an error
+    fn test_text_decoder_fatal() {
+        let options = TextDecoderOptions { fatal: true, ignore_bom: false };
+        let mut decoder = TextDecoder::new(options);
+        assert_eq!(Result::Ok(String::from("\u{FFFD}a")), decode(& mut decoder, "\u{FEFF}\u{FFFD}a".as_bytes(), false));
+        assert_eq!(Result::Ok(String::from("a")), decode(& mut decoder, b"a\xF0\x9F", true));
+        let error = decode(& mut decoder, b"\x98", false).unwrap_err();
+        assert_eq!(TextDecodeError { offset: 1, len: 3, kind: Utf8ErrorKind::Truncated }, error);
+        let mut buf = [0u8; 64];
+        assert_eq!("truncated UTF8 sequence at byte 1", error.format_into(& mut buf));
+        let error = decode(& mut decoder, b"xy\xC0\xAF", false).unwrap_err();
+        assert_eq!((2, Utf8ErrorKind::InvalidLead), (error.offset, error.kind));
+        assert_eq!(Result::Ok(String::from("ok")), decode(& mut decoder, b"ok", false));
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Module is crate::utf8conv::xml
+
+/// Returns true if `ch` is allowed in an XML 1.0 document.
+///
+/// Allowed are tab, newline, carriage return, and all chars from U+0020
+/// except U+FFFE and U+FFFF.  Surrogates cannot occur in a char; the
+/// decoders already replace them.
+#[inline]
+pub const fn is_xml_char(ch: char) -> bool {
+    let code = ch as u32;
+    if code < 0x20 {
+        code == 0x9 || code == 0xA || code == 0xD
+    }
+    else {
+        code != 0xFFFE && code != 0xFFFF
+    }
+}
+
+/// char to XML safe char iterator struct
+pub struct XmlSafeStruct<'b, I: ?Sized = dyn Iterator<Item = char> + 'b> {
+
+    /// true after replacing a char
+    my_invalid_sequence: bool,
+
+    /// the source iterator
+    my_borrow_mut_iter: &'b mut I,
+}
+
+/// Implementation of XmlSafeStruct
+impl<'b, I: ?Sized> XmlSafeStruct<'b, I> {
+
+    #[inline]
+    /// Returns true if a char not allowed in XML was replaced.
+    pub fn has_invalid_sequence(&self) -> bool {
+        self.my_invalid_sequence
+    }
+}
+
+/// an adapter iterator to replace chars not allowed in XML 1.0
+impl<'b, I> Iterator for XmlSafeStruct<'b, I>
+where I: Iterator<Item = char> + ?Sized, {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ch = self.my_borrow_mut_iter.next()?;
+        if is_xml_char(ch) {
+            Option::Some(ch)
+        }
+        else {
+            self.my_invalid_sequence = true;
+            Option::Some(char::REPLACEMENT_CHARACTER)
+        }
+    }
+
+    /// sizing hint for iterator, with a lower bound and optional upperbound
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.my_borrow_mut_iter.size_hint()
+    }
+}
+
+/// Function xml_safe_iter() takes a mutable reference to a char
+/// iterator, and returns an iterator in its place that substitutes
+/// chars not allowed in XML 1.0 with replacement characters.
+///
+/// Place it between a decoder and an encoder, so the output can be
+/// written into an XML document.  Markup characters such as '<' are
+/// allowed chars, and are not escaped.
+///
+/// ```rust
+/// use utf8conv::*;
+///
+/// let mut chars = "a\u{0}\tb\u{FFFF}".chars();
+/// let mut iter = xml_safe_iter(& mut chars);
+/// let text: String = iter.by_ref().collect();
+/// assert_eq!("a\u{FFFD}\tb\u{FFFD}", text);
+/// assert!(iter.has_invalid_sequence());
+/// ```
+///
+/// # Arguments
+///
+/// * `input` - a mutable reference to a char iterator
+#[inline]
+pub fn xml_safe_iter<'a, I>(input: &'a mut I) -> XmlSafeStruct<'a, I>
+where I: Iterator<Item = char> + ?Sized, {
+    XmlSafeStruct {
+        my_invalid_sequence: false,
+        my_borrow_mut_iter: input,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+
+    #[test]
+    // Test the XML char ranges exhaustively
+    fn test_is_xml_char() {
+        let mut allowed:u32 = 0;
+        for ch in (0 ..= 0x10FFFFu32).filter_map(char::from_u32) {
+            if is_xml_char(ch) {
+                allowed += 1;
+            }
+        }
+        // #x9 | #xA | #xD | [#x20-#xD7FF] | [#xE000-#xFFFD] | [#x10000-#x10FFFF]
+        assert_eq!(3 + (0xD800 - 0x20) + (0xFFFE - 0xE000) + 0x100000, allowed);
+        assert!(! is_xml_char('\u{1F}'));
+        assert!(is_xml_char('\u{FFFD}'));
+        assert!(is_xml_char('\u{10000}'));
+    }
+
+    #[test]
+    // Test the XML safe adapter between a decoder and an encoder
+    fn test_xml_safe_conversion() {
+        let bytes = [0x3Cu8, 0x61u8, 0x08u8, 0x0Du8, 0xEFu8, 0xBFu8, 0xBEu8, 0xEDu8, 0xA0u8, 0x80u8];
+        let mut chars = decode_lossy(& bytes);
+        let mut iter = xml_safe_iter(& mut chars);
+        let output: std::vec::Vec<u8> = encode(iter.by_ref()).collect();
+        assert_eq!("<a\u{FFFD}\r\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}".as_bytes(), & output[..]);
+        assert!(iter.has_invalid_sequence());
+        let mut chars = "ok\n".chars();
+        let mut iter = xml_safe_iter(& mut chars);
+        assert_eq!("ok\n", iter.by_ref().collect::<std::string::String>());
+        assert!(! iter.has_invalid_sequence());
+    }
+}
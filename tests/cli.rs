@@ -0,0 +1,112 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// End to end tests of the utf8conv command line tool.
+
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::Command;
+use std::process::Output;
+use std::process::Stdio;
+
+// Run the tool with `args`, feeding `input` on stdin.
+fn run_cli(args: &[&str], input: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_utf8conv"))
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .unwrap();
+    child.stdin.take().unwrap().write_all(input).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+// Test a round trip through UTF32 with BOM and newline options
+fn test_cli_round_trip() {
+    let text = "\u{FEFF}a\r\nb\rc\u{10348}\n";
+    let output = run_cli(& ["--to", "utf32be", "--bom=strip"], text.as_bytes());
+    assert!(output.status.success());
+    let codes: Vec<u32> = "a\r\nb\rc\u{10348}\n".chars().map(|v| v as u32).collect();
+    let truth: Vec<u8> = codes.iter().flat_map(|v| v.to_be_bytes()).collect();
+    assert_eq!(truth, output.stdout);
+
+    let output = run_cli(& ["--from", "UTF32BE", "--newline", "lf", "--bom", "add"], & output.stdout);
+    assert!(output.status.success());
+    assert_eq!("\u{FEFF}a\nb\nc\u{10348}\n".as_bytes(), & output.stdout[..]);
+
+    let output = run_cli(& ["--newline=lf"], text.as_bytes());
+    assert_eq!("\u{FEFF}a\nb\nc\u{10348}\n".as_bytes(), & output.stdout[..]);
+}
+
+#[test]
+// Test UTF16 in both byte orders, with a BOM and a surrogate pair
+fn test_cli_utf16() {
+    let text = "\u{FEFF}a\u{20AC}\u{1F600}\r\n";
+    let truth: Vec<u8> = text.encode_utf16().flat_map(|v| v.to_le_bytes()).collect();
+    let output = run_cli(& ["--to", "utf16le"], text.as_bytes());
+    assert!(output.status.success());
+    assert_eq!(truth, output.stdout);
+
+    let output = run_cli(& ["--from", "utf-16le", "--to", "UTF16BE", "--bom", "strip"], & output.stdout);
+    assert!(output.status.success());
+    let truth: Vec<u8> = text[3 ..].encode_utf16().flat_map(|v| v.to_be_bytes()).collect();
+    assert_eq!(truth, output.stdout);
+
+    let output = run_cli(& ["--from", "utf16be", "--newline", "lf", "--bom", "add"], & output.stdout);
+    assert!(output.status.success());
+    assert_eq!("\u{FEFF}a\u{20AC}\u{1F600}\n".as_bytes(), & output.stdout[..]);
+
+    // An unpaired surrogate is invalid input.
+    let output = run_cli(& ["--from", "utf16le", "--strict"], & [0x41u8, 0, 0x00u8, 0xD8u8, 0x42u8, 0]);
+    assert_eq!(Some(1), output.status.code());
+    assert_eq!(b"A", & output.stdout[..]);
+    assert!(String::from_utf8_lossy(& output.stderr).contains("invalid utf16le input at char 1"));
+    let output = run_cli(& ["--from", "utf16le"], & [0x41u8, 0, 0x00u8, 0xD8u8, 0x42u8, 0]);
+    assert!(output.status.success());
+    assert_eq!("A\u{FFFD}B".as_bytes(), & output.stdout[..]);
+}
+
+#[test]
+// Test input longer than the buffers of the tool
+fn test_cli_long_input() {
+    let text: String = "ascii text \u{E9}\u{4E2D}\u{1F600}\r\n".repeat(2000);
+    let output = run_cli(& ["--to", "utf16be"], text.as_bytes());
+    assert!(output.status.success());
+    let output = run_cli(& ["--from", "utf16be", "--to", "utf32le"], & output.stdout);
+    assert!(output.status.success());
+    let output = run_cli(& ["--from", "utf32le", "--strict"], & output.stdout);
+    assert!(output.status.success());
+    assert_eq!(text.as_bytes(), & output.stdout[..]);
+}
+
+#[test]
+// Test lossy and strict handling of invalid input, and usage errors
+fn test_cli_invalid_input() {
+    let bytes = [0x41u8, 0xE2u8, 0x42u8, 0xF0u8, 0x90u8];
+    let output = run_cli(& [], & bytes);
+    assert!(output.status.success());
+    assert_eq!("A\u{FFFD}B\u{FFFD}".as_bytes(), & output.stdout[..]);
+
+    let output = run_cli(& ["--strict"], & bytes);
+    assert_eq!(Some(1), output.status.code());
+    assert_eq!(b"A", & output.stdout[..]);
+    assert!(String::from_utf8_lossy(& output.stderr).contains("invalid utf8 input at char 1"));
+
+    let output = run_cli(& ["--from", "utf32le", "--strict"], & [0x41u8, 0, 0, 0, 0x42u8]);
+    assert_eq!(Some(1), output.status.code());
+    assert_eq!(b"A", & output.stdout[..]);
+
+    let output = run_cli(& ["--to", "latin1"], b"");
+    assert_eq!(Some(2), output.status.code());
+    let output = run_cli(& ["--help"], b"");
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(& output.stdout).starts_with("usage:"));
+}
@@ -0,0 +1,109 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Tests that every error type of the crate composes with `?` and boxed
+// core::error::Error values, the way anyhow and eyre take them.
+
+extern crate std;
+
+use std::boxed::Box;
+use std::string::String;
+use std::string::ToString;
+
+use utf8conv::*;
+
+type BoxError = Box<dyn core::error::Error + Send + Sync + 'static>;
+
+// Takes any error as a trait object, as a no_std caller would.
+fn message(error: &dyn core::error::Error) -> String {
+    assert!(error.source().is_none());
+    error.to_string()
+}
+
+// Run `f`, which must fail, and return the message of its error.
+fn failure<F: FnOnce() -> Result<(), BoxError>>(f: F) -> String {
+    message(&* f().unwrap_err())
+}
+
+#[test]
+// Test the validation and decoding errors through `?`
+fn test_decode_errors() {
+    assert_eq!("out of range value in UTF32 input at index 1", failure(|| {
+        validate_utf32_slice(& [0x41, 0x110000])?;
+        Result::Ok(())
+    }));
+    assert_eq!("truncated input: 2 byte UTF8 sequence at byte 2", failure(|| {
+        decode_utf8_exact(b"ab\xF0\x9F")?.for_each(drop);
+        Result::Ok(())
+    }));
+    assert_eq!("invalid UTF8 lead byte at byte 1", failure(|| {
+        ValidUtf8::new(b"a\xFF")?;
+        Result::Ok(())
+    }));
+    assert_eq!("invalid UTF8 lead byte, length 1", failure(|| {
+        let mut parser = FromUtf8::new();
+        let (rest, _ch) = parser.utf8_to_char_strict(b"a\xC0")?;
+        parser.utf8_to_char_strict(rest)?;
+        Result::Ok(())
+    }));
+    assert_eq!("invalid UTF8 lead byte at byte 1", failure(|| {
+        let mut decoder = TextDecoder::new(TextDecoderOptions { fatal: true, ignore_bom: false });
+        decoder.decode_to_sink(b"a\xFF", false, & mut FnSink(|_ch| {}))?;
+        Result::Ok(())
+    }));
+    assert_eq!("invalid UTF8 sequence after \"a\" at byte 1", failure(|| {
+        let mut parser = FromUtf8WithContext::<4>::new();
+        let mut cur_slice = & b"a\xFF"[..];
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, _ch)) = parser.utf8_to_char(cur_slice) {
+            cur_slice = slice_pos;
+        }
+        Result::Err(parser.last_error().unwrap())?
+    }));
+}
+
+#[test]
+// Test the encoding errors through `?`
+fn test_encode_errors() {
+    assert_eq!("surrogate in UTF32 input at index 1, value 0xD800", failure(|| {
+        let mut parser = FromUnicode::new();
+        let (rest, _b) = parser.utf32_to_utf8_strict(& [0x41, 0xD800])?;
+        parser.utf32_to_utf8_strict(rest)?;
+        Result::Ok(())
+    }));
+    assert_eq!("unencodable char U+00E9 at char 3", failure(|| {
+        let mut encoder = Latin1Encoder::new(SingleByteCharset::Ascii, UnencodablePolicy::Error);
+        let mut byte_box = [0u8; 8];
+        encoder.encode_to_slice(& ['c', 'a', 'f', '\u{E9}'], & mut byte_box).unwrap();
+        Result::Err(encoder.error().unwrap())?
+    }));
+    assert_eq!("offsets buffer is full", failure(|| {
+        let mut values = [0u8; 8];
+        let mut offsets = [0i32; 1];
+        let mut builder = ArrowOffsetsBuilder::new(& mut values, & mut offsets);
+        builder.push_record(b"abc")?;
+        Result::Ok(())
+    }));
+}
+
+#[test]
+// Test that the status enums are errors with the same messages as
+// format_into(), which needs no allocation
+fn test_error_kinds() {
+    let mut buf = [0u8; 64];
+    let kinds: [&dyn core::error::Error; 3] = [
+        & Utf8ErrorKind::Overlong,
+        & Utf32ErrorKind::Surrogate,
+        & EncodeError::More(MoreEnum::More(0)),
+    ];
+    assert_eq!("overlong UTF8 sequence", message(kinds[0]));
+    assert_eq!(Utf8ErrorKind::Overlong.format_into(& mut buf), message(kinds[0]));
+    assert_eq!("surrogate in UTF32 input", message(kinds[1]));
+    assert_eq!("end of data", message(kinds[2]));
+}
@@ -0,0 +1,140 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Exhaustive conformance harness: every byte sequence of length 1 to 4
+// is decoded with utf8_decode(), and the output is compared against
+// core::str::from_utf8() with replacement of invalid sequences.
+//
+// There are over four billion sequences, so the test is ignored by
+// default.  Run it with:
+//
+//     cargo test --release --test exhaustive_conformance -- --ignored
+
+extern crate std;
+
+use utf8conv::*;
+
+use core::str;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::thread;
+
+// Decode with utf8_decode(); returns the number of chars and whether
+// any invalid sequence was seen.
+fn fsm_decode(bytes: & [u8], out: &mut [char; 4]) -> (usize, bool) {
+    let mut mybuf = EightBytes::new();
+    for v in bytes {
+        mybuf.push_back(* v);
+    }
+    let mut num_chars:usize = 0;
+    let mut invalid = false;
+    while ! mybuf.is_empty() {
+        match utf8_decode(& mut mybuf, true) {
+            Utf8EndEnum::Finish(code) => {
+                out[num_chars] = char::from_u32(code).unwrap();
+            }
+            Utf8EndEnum::BadDecode(_) => {
+                out[num_chars] = char::REPLACEMENT_CHARACTER;
+                invalid = true;
+            }
+            Utf8EndEnum::TypeUnknown => {
+                // A sequence truncated by the end of the last buffer;
+                // FromUtf8 reports it as one replacement character.
+                assert!(mybuf.is_empty());
+                out[num_chars] = char::REPLACEMENT_CHARACTER;
+                invalid = true;
+            }
+        }
+        num_chars += 1;
+    }
+    (num_chars, invalid)
+}
+
+// Decode with str::from_utf8(), replacing each invalid sequence
+// the same way as String::from_utf8_lossy().
+fn truth_decode(bytes: & [u8], out: &mut [char; 4]) -> (usize, bool) {
+    let mut cur_slice = bytes;
+    let mut num_chars:usize = 0;
+    let mut invalid = false;
+    while ! cur_slice.is_empty() {
+        let (valid_part, error_len) = match str::from_utf8(cur_slice) {
+            Ok(str_ref) => { (str_ref, Option::None) }
+            Err(en) => {
+                let valid_part = str::from_utf8(& cur_slice[.. en.valid_up_to()]).unwrap();
+                // A truncated sequence extends to the end of data.
+                let error_len = en.error_len().unwrap_or(cur_slice.len() - en.valid_up_to());
+                (valid_part, Option::Some(error_len))
+            }
+        };
+        for ch in valid_part.chars() {
+            out[num_chars] = ch;
+            num_chars += 1;
+        }
+        cur_slice = & cur_slice[valid_part.len() ..];
+        if let Option::Some(error_len) = error_len {
+            out[num_chars] = char::REPLACEMENT_CHARACTER;
+            num_chars += 1;
+            invalid = true;
+            cur_slice = & cur_slice[error_len ..];
+        }
+    }
+    (num_chars, invalid)
+}
+
+// Compare both decoders on one sequence.
+fn check_sequence(bytes: & [u8]) {
+    let mut fsm_box = [char::MAX; 4];
+    let mut truth_box = [char::MAX; 4];
+    let (fsm_len, fsm_invalid) = fsm_decode(bytes, & mut fsm_box);
+    let (truth_len, truth_invalid) = truth_decode(bytes, & mut truth_box);
+    if fsm_box[0 .. fsm_len] != truth_box[0 .. truth_len] {
+        panic!("\nSequence {:02x?} decodes to {:?} vs truth {:?}\n",
+            bytes, & fsm_box[0 .. fsm_len], & truth_box[0 .. truth_len]);
+    }
+    // Encountering a replacement character is considered the same as
+    // having an invalid decode.
+    let truth_invalid = truth_invalid || truth_box[0 .. truth_len].contains(& char::REPLACEMENT_CHARACTER);
+    if fsm_invalid != truth_invalid {
+        panic!("\nSequence {:02x?} invalid indication {} vs truth {}\n",
+            bytes, fsm_invalid, truth_invalid);
+    }
+}
+
+// Check every sequence of `seq_len` bytes starting with `lead`.
+fn check_lead_byte(lead: u8, seq_len: u32) {
+    let tail_count:u64 = 1u64 << (8 * (seq_len - 1));
+    let mut seq_box = [lead; 4];
+    for tail in 0 .. tail_count {
+        for (pos, slot) in seq_box.iter_mut().enumerate().take(seq_len as usize).skip(1) {
+            * slot = (tail >> (8 * (seq_len as usize - 1 - pos))) as u8;
+        }
+        check_sequence(& seq_box[0 .. seq_len as usize]);
+    }
+}
+
+#[test]
+#[ignore]
+fn test_exhaustive_up_to_four_bytes() {
+    let num_threads = thread::available_parallelism().map(|v| v.get()).unwrap_or(4);
+    for seq_len in 1u32 ..= 4 {
+        // Work is handed out one lead byte at a time.
+        let next_lead = AtomicU32::new(0);
+        thread::scope(|s| {
+            for _indx in 0 .. num_threads {
+                s.spawn(|| {
+                    loop {
+                        let lead = next_lead.fetch_add(1, Ordering::Relaxed);
+                        if lead > 0xFF {
+                            break;
+                        }
+                        check_lead_byte(lead as u8, seq_len);
+                    }
+                });
+            }
+        });
+    }
+}
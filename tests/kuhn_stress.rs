@@ -0,0 +1,137 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Markus Kuhn's UTF-8 decoder capability and stress test.
+//
+// Every line of tests/data/UTF-8-test.txt is split into two buffers at
+// every offset, and decoded with the slice, bulk and iterator APIs.
+// The output must match String::from_utf8_lossy() of the whole line,
+// which replaces each maximal invalid subpart with one replacement
+// character.
+
+extern crate std;
+
+use utf8conv::*;
+
+use std::string::String;
+use std::vec::Vec;
+
+const STRESS_FILE: &[u8] = include_bytes!("data/UTF-8-test.txt");
+
+/// Expected replacement characters on each numbered test line that
+/// has any.  Lines not listed here decode without replacement.
+const EXPECTED_REPLACEMENTS: [(&str, usize); 58] = [
+    ("2.1.5", 5), ("2.1.6", 6), ("2.2.4", 4), ("2.2.5", 5), ("2.2.6", 6),
+    ("2.3.3", 1), ("2.3.5", 4),
+    ("3.1.1", 1), ("3.1.2", 1), ("3.1.3", 2), ("3.1.4", 3), ("3.1.5", 4),
+    ("3.1.6", 5), ("3.1.7", 6), ("3.1.8", 7),
+    ("3.3.1", 1), ("3.3.2", 2), ("3.3.3", 3), ("3.3.4", 4), ("3.3.5", 5),
+    ("3.3.6", 1), ("3.3.7", 1), ("3.3.8", 3), ("3.3.9", 4), ("3.3.10", 5),
+    ("3.5.1", 1), ("3.5.2", 1), ("3.5.3", 4),
+    ("4.1.1", 2), ("4.1.2", 3), ("4.1.3", 4), ("4.1.4", 5), ("4.1.5", 6),
+    ("4.2.1", 2), ("4.2.2", 3), ("4.2.3", 4), ("4.2.4", 5), ("4.2.5", 6),
+    ("4.3.1", 2), ("4.3.2", 3), ("4.3.3", 4), ("4.3.4", 5), ("4.3.5", 6),
+    ("5.1.1", 3), ("5.1.2", 3), ("5.1.3", 3), ("5.1.4", 3), ("5.1.5", 3),
+    ("5.1.6", 3), ("5.1.7", 3),
+    ("5.2.1", 6), ("5.2.2", 6), ("5.2.3", 6), ("5.2.4", 6), ("5.2.5", 6),
+    ("5.2.6", 6), ("5.2.7", 6), ("5.2.8", 6),
+];
+
+/// Replacement characters in the whole file.
+const TOTAL_REPLACEMENTS: usize = 379;
+
+// Decode two buffers with utf8_to_char().  Returns the chars and the
+// invalid sequence indication.
+fn decode_with_slice(par: &mut FromUtf8, b1: & [u8], b2: & [u8]) -> (Vec<char>, bool) {
+    let mut output = Vec::new();
+    par.reset_parser();
+    par.set_is_last_buffer(false);
+    for (stage, buf) in [b1, b2].iter().enumerate() {
+        par.set_is_last_buffer(stage == 1);
+        let mut cur_slice: &[u8] = buf;
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, ch)) = par.utf8_to_char(cur_slice) {
+            cur_slice = slice_pos;
+            output.push(ch);
+        }
+    }
+    (output, par.has_invalid_sequence())
+}
+
+// Decode two buffers with utf8_to_char_slice() into a small output slice.
+fn decode_with_bulk(par: &mut FromUtf8, b1: & [u8], b2: & [u8]) -> (Vec<char>, bool) {
+    let mut output = Vec::new();
+    let mut char_box = [char::MAX; 5];
+    par.reset_parser();
+    par.set_is_last_buffer(false);
+    for (stage, buf) in [b1, b2].iter().enumerate() {
+        par.set_is_last_buffer(stage == 1);
+        let mut cur_slice: &[u8] = buf;
+        while let Result::Ok((slice_pos, num_chars)) = par.utf8_to_char_slice(cur_slice, & mut char_box) {
+            cur_slice = slice_pos;
+            output.extend_from_slice(& char_box[0 .. num_chars]);
+        }
+    }
+    (output, par.has_invalid_sequence())
+}
+
+// Decode two buffers with the UTF8 reference iterator adapter.
+fn decode_with_iter(par: &mut FromUtf8, b1: & [u8], b2: & [u8]) -> (Vec<char>, bool) {
+    let mut output = Vec::new();
+    par.reset_parser();
+    par.set_is_last_buffer(false);
+    for (stage, buf) in [b1, b2].iter().enumerate() {
+        par.set_is_last_buffer(stage == 1);
+        let mut byte_ref_iter = buf.iter();
+        output.extend(par.utf8_ref_to_char_with_iter(& mut byte_ref_iter));
+    }
+    (output, par.has_invalid_sequence())
+}
+
+#[test]
+fn test_kuhn_stress_every_split() {
+    let mut par = FromUtf8::new();
+    let mut total_replacements: usize = 0;
+    for (line_num, line) in STRESS_FILE.split(|v| * v == b'\n').enumerate() {
+        let truth: Vec<char> = String::from_utf8_lossy(line).chars().collect();
+        let replacements = truth.iter().filter(|v| ** v == char::REPLACEMENT_CHARACTER).count();
+        total_replacements += replacements;
+        for split_pos in 0 ..= line.len() {
+            let (b1, b2) = line.split_at(split_pos);
+            for (api, (test, invalid)) in [
+                ("utf8_to_char", decode_with_slice(&mut par, b1, b2)),
+                ("utf8_to_char_slice", decode_with_bulk(&mut par, b1, b2)),
+                ("utf8_ref_to_char_with_iter", decode_with_iter(&mut par, b1, b2)),
+            ] {
+                if test != truth {
+                    panic!("\n{} differs on line {} split at {}\n", api, line_num + 1, split_pos);
+                }
+                // Encountering a replacement character is considered the
+                // same as having an invalid decode.
+                assert_eq!(replacements > 0, invalid);
+            }
+        }
+    }
+    assert_eq!(TOTAL_REPLACEMENTS, total_replacements);
+}
+
+#[test]
+fn test_kuhn_stress_replacement_pattern() {
+    let mut par = FromUtf8::new();
+    for (section, expected) in EXPECTED_REPLACEMENTS.iter() {
+        let mut prefix = Vec::from(section.as_bytes());
+        prefix.push(b' ');
+        let line = STRESS_FILE.split(|v| * v == b'\n')
+            .find(|v| v.starts_with(& prefix))
+            .unwrap();
+        let (output, _invalid) = decode_with_slice(&mut par, line, & []);
+        let replacements = output.iter().filter(|v| ** v == char::REPLACEMENT_CHARACTER).count();
+        assert_eq!(* expected, replacements, "section {}", section);
+    }
+}
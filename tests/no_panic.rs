@@ -0,0 +1,214 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// No-panic harness: adversarial input is driven through every slice
+// and iterator API, with arbitrary buffer splits and output sizes.
+// Any panic is caught and reported together with the failing round.
+// Tests are built with overflow checks on, so arithmetic overflow
+// would also be caught here.
+
+extern crate std;
+
+use utf8conv::*;
+
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use rand::RngCore;
+
+use std::panic;
+
+/// Bytes that sit on the edges of the UTF8 decoding table.
+const EDGE_BYTES: [u8; 16] = [
+    0x00, 0x7F, 0x80, 0x8F, 0x90, 0x9F, 0xA0, 0xBF,
+    0xC0, 0xC2, 0xE0, 0xED, 0xEF, 0xF0, 0xF4, 0xFF,
+];
+
+/// Codepoints that sit on the edges of the Unicode ranges.
+const EDGE_CODES: [u32; 12] = [
+    0, 0x7F, 0x80, 0x7FF, 0x800, 0xD7FF, 0xD800, 0xDFFF,
+    0xFFFD, 0x10FFFF, 0x110000, u32::MAX,
+];
+
+// Run `f` and turn a panic into a test failure naming the round.
+fn no_panic<F: FnOnce() + panic::UnwindSafe>(api: &str, round: usize, f: F) {
+    if panic::catch_unwind(f).is_err() {
+        panic!("\n{} panicked in round {}\n", api, round);
+    }
+}
+
+// Fill a byte slice mostly with table edge bytes.
+fn make_hostile_bytes(byte_slice: &mut [u8], rng: &mut SmallRng) {
+    for slot in byte_slice.iter_mut() {
+        let dice = rng.next_u32();
+        if (dice & 3) == 0 {
+            * slot = dice as u8;
+        }
+        else {
+            * slot = EDGE_BYTES[((dice >> 8) as usize) % EDGE_BYTES.len()];
+        }
+    }
+}
+
+// Fill a codepoint slice mostly with range edge values.
+fn make_hostile_codes(code_slice: &mut [u32], rng: &mut SmallRng) {
+    for slot in code_slice.iter_mut() {
+        let dice = rng.next_u32();
+        if (dice & 3) == 0 {
+            * slot = rng.next_u32();
+        }
+        else {
+            * slot = EDGE_CODES[((dice >> 8) as usize) % EDGE_CODES.len()];
+        }
+    }
+}
+
+// Split a slice at a random position.
+fn random_split<'a, T>(input: &'a [T], rng: &mut SmallRng) -> (&'a [T], &'a [T]) {
+    let pos = (rng.next_u32() as usize) % (input.len() + 1);
+    input.split_at(pos)
+}
+
+fn drive_utf8_slice_api(input: & [u8], seed: u64) {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut parser = FromUtf8::new();
+    let (b1, b2) = random_split(input, &mut rng);
+    // Buffers may be presented in any state, including a last buffer
+    // followed by more data.
+    for (stage, buf) in [b1, b2, b1, &[][..]].iter().enumerate() {
+        parser.set_is_last_buffer((rng.next_u32() & 1) == 0 || stage == 3);
+        let mut cur_slice: &[u8] = buf;
+        while let Result::Ok((slice_pos, _ch)) = parser.utf8_to_char(cur_slice) {
+            cur_slice = slice_pos;
+        }
+        let _ = parser.utf8_to_utf32(cur_slice);
+    }
+}
+
+fn drive_utf8_bulk_api(input: & [u8], seed: u64) {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut parser = FromUtf8::new();
+    let mut char_box = [char::MAX; 9];
+    let (b1, b2) = random_split(input, &mut rng);
+    for (stage, buf) in [b1, b2, &[][..]].iter().enumerate() {
+        parser.set_is_last_buffer((rng.next_u32() & 1) == 0 || stage == 2);
+        let mut cur_slice: &[u8] = buf;
+        loop {
+            // Output sizes include zero.
+            let out_len = (rng.next_u32() as usize) % (char_box.len() + 1);
+            match parser.utf8_to_char_slice(cur_slice, & mut char_box[0 .. out_len]) {
+                Result::Ok((slice_pos, num_chars)) => {
+                    assert!(num_chars <= out_len);
+                    if (out_len == 0) && (slice_pos.len() == cur_slice.len()) {
+                        break;
+                    }
+                    cur_slice = slice_pos;
+                }
+                Result::Err(MoreEnum::More(_amt)) => {
+                    break;
+                }
+            }
+        }
+    }
+    let _ = find_non_ascii(input);
+}
+
+fn drive_utf8_iter_api(input: & [u8], seed: u64) {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut parser = FromUtf8::new();
+    let (b1, b2) = random_split(input, &mut rng);
+    for (stage, buf) in [b1, b2, &[][..]].iter().enumerate() {
+        parser.set_is_last_buffer((rng.next_u32() & 1) == 0 || stage == 2);
+        let mut byte_ref_iter = buf.iter();
+        if (rng.next_u32() & 1) == 0 {
+            let iter = parser.utf8_ref_to_char_with_iter(& mut byte_ref_iter);
+            let _ = iter.count();
+        }
+        else {
+            let mut glue_iter = utf8_ref_iter_to_utf8_iter(& mut byte_ref_iter);
+            let mut char_iter = parser.utf8_to_char_with_iter(& mut glue_iter);
+            let mut filter_iter = filter_bom_and_cr_iter(& mut char_iter);
+            let _ = filter_iter.by_ref().count();
+        }
+    }
+}
+
+fn drive_utf32_apis(input: & [u32], seed: u64) {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut parser = FromUnicode::new();
+    let (c1, c2) = random_split(input, &mut rng);
+    for (stage, buf) in [c1, c2, &[][..]].iter().enumerate() {
+        parser.set_is_last_buffer((rng.next_u32() & 1) == 0 || stage == 2);
+        let mut cur_slice: &[u32] = buf;
+        while let Result::Ok((slice_pos, _b)) = parser.utf32_to_utf8(cur_slice) {
+            cur_slice = slice_pos;
+        }
+        let mut code_iter = buf.iter();
+        let mut glue_iter = utf32_ref_iter_to_utf32_iter(& mut code_iter);
+        let _ = parser.utf32_to_utf8_with_iter(& mut glue_iter).count();
+        for code in buf.iter() {
+            let _ = classify_utf32(* code);
+        }
+    }
+    let char_box: [char; 4] = ['\0', '\u{FFFD}', char::MAX, '\u{7FF}'];
+    let mut cur_slice = & char_box[..];
+    while let Result::Ok((slice_pos, _b)) = parser.char_to_utf8(cur_slice) {
+        cur_slice = slice_pos;
+    }
+    let mut char_ref_iter = char_box.iter();
+    let _ = parser.char_ref_to_utf8_with_iter(& mut char_ref_iter).count();
+}
+
+#[test]
+fn test_no_panic_hostile_input() {
+    let mut rng = SmallRng::seed_from_u64(0x4c2f81d0a9e3b657u64);
+    let mut byte_buf = [0u8; 64];
+    let mut code_buf = [0u32; 24];
+    for round in 0 .. 20000 {
+        let byte_len = (rng.next_u32() as usize) % (byte_buf.len() + 1);
+        let code_len = (rng.next_u32() as usize) % (code_buf.len() + 1);
+        make_hostile_bytes(& mut byte_buf[0 .. byte_len], &mut rng);
+        make_hostile_codes(& mut code_buf[0 .. code_len], &mut rng);
+        let bytes = & byte_buf[0 .. byte_len];
+        let codes = & code_buf[0 .. code_len];
+        let seed = rng.next_u64();
+        no_panic("utf8_to_char", round, || drive_utf8_slice_api(bytes, seed));
+        no_panic("utf8_to_char_slice", round, || drive_utf8_bulk_api(bytes, seed));
+        no_panic("utf8 iterators", round, || drive_utf8_iter_api(bytes, seed));
+        no_panic("utf32 encoders", round, || drive_utf32_apis(codes, seed));
+    }
+}
+
+#[test]
+fn test_no_panic_eightbytes() {
+    let mut rng = SmallRng::seed_from_u64(0x93b1e07d5f2a6c48u64);
+    let mut byte_buf = [0u8; 12];
+    for round in 0 .. 20000 {
+        let seed = rng.next_u64();
+        make_hostile_bytes(& mut byte_buf, &mut rng);
+        let bytes = & byte_buf;
+        no_panic("EightBytes", round, || {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            let mut b1 = EightBytes::new();
+            for _step in 0 .. 24 {
+                let dice = rng.next_u32();
+                let v = dice as u8;
+                match (dice >> 8) % 8 {
+                    0 => { b1.push_back(v); }
+                    1 => { b1.push_front(v); }
+                    2 => { let _ = b1.pop_front(); }
+                    3 => { let _ = b1.pop_back(); }
+                    4 => { let _ = b1.peek_at(v as usize); }
+                    5 => { let _ = b1.fill_from_slice(& bytes[0 .. (v as usize) % 13]); }
+                    6 => { let _ = (b1.front(), b1.back()); }
+                    _ => { b1.clear(); }
+                }
+                assert!(b1.len() <= b1.capacity());
+            }
+        });
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Property based differential tests.  Arbitrary inputs are cut into
+// buffers at arbitrary split points, and the converters are compared
+// against the standard library.
+
+extern crate std;
+
+use utf8conv::*;
+
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+use std::string::String;
+use std::vec::Vec;
+
+// Cut `input` into buffers at the given split points.
+fn split_buffers<'a, T>(input: &'a [T], splits: & [Index]) -> Vec<&'a [T]> {
+    let mut positions: Vec<usize> = splits.iter().map(|v| v.index(input.len() + 1)).collect();
+    positions.sort_unstable();
+    let mut buffers = Vec::new();
+    let mut start:usize = 0;
+    for pos in positions {
+        buffers.push(& input[start .. pos]);
+        start = pos;
+    }
+    buffers.push(& input[start ..]);
+    buffers
+}
+
+// Decode buffers with utf8_to_char().
+fn decode_with_slice(buffers: & [&[u8]]) -> (String, bool) {
+    let mut par = FromUtf8::new();
+    let mut output = String::new();
+    for (indx, buf) in buffers.iter().enumerate() {
+        par.set_is_last_buffer(indx == buffers.len() - 1);
+        let mut cur_slice: &[u8] = buf;
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, ch)) = par.utf8_to_char(cur_slice) {
+            cur_slice = slice_pos;
+            output.push(ch);
+        }
+    }
+    (output, par.has_invalid_sequence())
+}
+
+// Decode buffers with utf8_to_char_slice().
+fn decode_with_bulk(buffers: & [&[u8]], out_len: usize) -> (String, bool) {
+    let mut par = FromUtf8::new();
+    let mut output = String::new();
+    let mut char_box = [char::MAX; 16];
+    for (indx, buf) in buffers.iter().enumerate() {
+        par.set_is_last_buffer(indx == buffers.len() - 1);
+        let mut cur_slice: &[u8] = buf;
+        while let Result::Ok((slice_pos, num_chars)) =
+        par.utf8_to_char_slice(cur_slice, & mut char_box[0 .. out_len]) {
+            cur_slice = slice_pos;
+            output.extend(char_box[0 .. num_chars].iter());
+        }
+    }
+    (output, par.has_invalid_sequence())
+}
+
+// Decode buffers with the UTF8 iterator adapter.
+fn decode_with_iter(buffers: & [&[u8]]) -> (String, bool) {
+    let mut par = FromUtf8::new();
+    let mut output = String::new();
+    for (indx, buf) in buffers.iter().enumerate() {
+        par.set_is_last_buffer(indx == buffers.len() - 1);
+        let mut byte_ref_iter = buf.iter();
+        let mut glue_iter = utf8_ref_iter_to_utf8_iter(& mut byte_ref_iter);
+        output.extend(par.utf8_to_char_with_iter(& mut glue_iter));
+    }
+    (output, par.has_invalid_sequence())
+}
+
+// Encode char buffers with char_to_utf8().
+fn encode_chars(buffers: & [&[char]]) -> Vec<u8> {
+    let mut par = FromUnicode::new();
+    let mut output = Vec::new();
+    for (indx, buf) in buffers.iter().enumerate() {
+        par.set_is_last_buffer(indx == buffers.len() - 1);
+        let mut cur_slice: &[char] = buf;
+        while let Result::Ok((slice_pos, b)) = par.char_to_utf8(cur_slice) {
+            cur_slice = slice_pos;
+            output.push(b);
+        }
+    }
+    output
+}
+
+proptest! {
+    #[test]
+    fn prop_decode_matches_lossy(
+        bytes in prop::collection::vec(any::<u8>(), 0 .. 200),
+        splits in prop::collection::vec(any::<Index>(), 0 .. 4),
+        out_len in 1usize ..= 16,
+    ) {
+        let truth = String::from_utf8_lossy(& bytes);
+        // Encountering a replacement character is considered the same
+        // as having an invalid decode.
+        let truth_invalid = truth.contains(char::REPLACEMENT_CHARACTER);
+        let buffers = split_buffers(& bytes, & splits);
+        prop_assert_eq!((truth.to_string(), truth_invalid), decode_with_slice(& buffers));
+        prop_assert_eq!((truth.to_string(), truth_invalid), decode_with_bulk(& buffers, out_len));
+        prop_assert_eq!((truth.to_string(), truth_invalid), decode_with_iter(& buffers));
+    }
+
+    #[test]
+    fn prop_valid_round_trip(
+        text in any::<String>(),
+        splits in prop::collection::vec(any::<Index>(), 0 .. 4),
+    ) {
+        let chars: Vec<char> = text.chars().collect();
+        let utf8 = encode_chars(& split_buffers(& chars, & splits));
+        prop_assert_eq!(text.as_bytes(), & utf8[..]);
+        let (decoded, _invalid) = decode_with_slice(& split_buffers(& utf8, & splits));
+        prop_assert_eq!(& text, & decoded);
+    }
+
+    #[test]
+    fn prop_encode_utf32_is_valid(
+        codes in prop::collection::vec(prop_oneof![any::<u32>(), 0u32 .. 0x11000], 0 .. 64),
+        splits in prop::collection::vec(any::<Index>(), 0 .. 4),
+    ) {
+        let mut par = FromUnicode::new();
+        let mut output = Vec::new();
+        let buffers = split_buffers(& codes, & splits);
+        for (indx, buf) in buffers.iter().enumerate() {
+            par.set_is_last_buffer(indx == buffers.len() - 1);
+            let mut cur_slice: &[u32] = buf;
+            while let Result::Ok((slice_pos, b)) = par.utf32_to_utf8(cur_slice) {
+                cur_slice = slice_pos;
+                output.push(b);
+            }
+        }
+        let truth: String = codes.iter()
+            .map(|v| char::from_u32(* v).unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect();
+        prop_assert_eq!(truth.as_bytes(), & output[..]);
+        prop_assert_eq!(truth.contains(char::REPLACEMENT_CHARACTER), par.has_invalid_sequence());
+    }
+}
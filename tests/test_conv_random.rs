@@ -150,16 +150,16 @@ fn verify_with_string(par: &mut FromUtf8, b1:& [u8], b2:& [u8], b3:& [u8], b4:&
         let mut the_slice: &[u8];
         match stage {
             0 => {
-                the_slice = &b1;
+                the_slice = b1;
             }
             1 => {
-                the_slice = &b2;
+                the_slice = b2;
             }
             2 => {
-                the_slice = &b3;
+                the_slice = b3;
             }
             _ => {
-                the_slice = &b4;
+                the_slice = b4;
                 // Signal no more buffer after the current one.
                 par.set_is_last_buffer(true);
             }
@@ -220,130 +220,130 @@ fn test_utf8parsing_aaa() {
     let mut par:FromUtf8 = FromUtf8::new();
     println!("case 1: all empty");
     let t1 = "";
-    verify_with_string(&mut par, "".as_bytes(), "".as_bytes(), "".as_bytes(), "".as_bytes(), &t1);
+    verify_with_string(&mut par, "".as_bytes(), "".as_bytes(), "".as_bytes(), "".as_bytes(), t1);
     assert!(!par.has_invalid_sequence());
 
     println!("case 2, different length ASCII");
     let t1 = "abcdef\x7f\t\r\n";
-    verify_with_string(&mut par, "a".as_bytes(), "bc".as_bytes(), "def".as_bytes(), "\x7f\t\r\n".as_bytes(), &t1);
+    verify_with_string(&mut par, "a".as_bytes(), "bc".as_bytes(), "def".as_bytes(), "\x7f\t\r\n".as_bytes(), t1);
     assert!(!par.has_invalid_sequence());
 
     println!("case 3: multi-language");
     let t1 = "寒い,감기,frío,студен";
-    verify_with_string(&mut par, "寒い,".as_bytes(), "감기,".as_bytes(), "frío,".as_bytes(), "студен".as_bytes(), &t1);
+    verify_with_string(&mut par, "寒い,".as_bytes(), "감기,".as_bytes(), "frío,".as_bytes(), "студен".as_bytes(), t1);
     assert!(!par.has_invalid_sequence());
 
     println!("case 4: emoji and symbols");
     let t1 = "😀🐔🐣🇧🇷🇨🇦元∰⇲";
-    verify_with_string(&mut par, "😀".as_bytes(), "🐔🐣".as_bytes(), "🇧🇷🇨🇦".as_bytes(), "元∰⇲".as_bytes(), &t1);
+    verify_with_string(&mut par, "😀".as_bytes(), "🐔🐣".as_bytes(), "🇧🇷🇨🇦".as_bytes(), "元∰⇲".as_bytes(), t1);
     assert!(!par.has_invalid_sequence());
 
     println!("case 5: long text");
     // long text
     let t1 = "The red fox jumped over the white fence in a stormy morning with seven chasing servants";
     verify_with_string(&mut par, "The red fox jumped over the white fence in a stormy morning with seven chasing servants".as_bytes(),
-    "".as_bytes(), "".as_bytes(), "".as_bytes(), &t1);
+    "".as_bytes(), "".as_bytes(), "".as_bytes(), t1);
     assert!(!par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 6: decode across buffer boundaries: ED/9F-bf, C2 / 80");
     let t1 = "\u{D7FF}\u{80}";
-    verify_with_string(&mut par, & [0xEDu8], & [0x9Fu8, 0xbfu8], & [0xC2u8], & [0x80u8], &t1);
+    verify_with_string(&mut par, & [0xEDu8], & [0x9Fu8, 0xbfu8], & [0xC2u8], & [0x80u8], t1);
     assert!(!par.has_invalid_sequence());
 
     println!("case 7: long decode error followed by 2 byte decode");
     let t1 = "\u{FFFD}\u{FFFD}\u{7FF}";
-    verify_with_string(&mut par, &[0xF0u8], "".as_bytes(), & [0x85u8], &[0xDFu8, 0xBFu8], &t1);
+    verify_with_string(&mut par, &[0xF0u8], "".as_bytes(), & [0x85u8], &[0xDFu8, 0xBFu8], t1);
     assert!(par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 8: decode error in last byte, then an ASCII");
     let t1 = "\u{FFFD}\u{7f}?";
-    verify_with_string(&mut par, & [0xF4u8], & [0x8Fu8], & [0x80u8, 0x7fu8], & [0x3fu8], &t1);
+    verify_with_string(&mut par, & [0xF4u8], & [0x8Fu8], & [0x80u8, 0x7fu8], & [0x3fu8], t1);
     assert!(par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 9: overlong encoding of the euro sign");
     let t1 = "\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}";
-    verify_with_string(&mut par, & [0xF0u8], & [0x82u8], & [0x82u8], & [0xACu8], &t1);
+    verify_with_string(&mut par, & [0xF0u8], & [0x82u8], & [0x82u8], & [0xACu8], t1);
     assert!(par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 10: invalid bytes from F5 to FF");
     let t1 = "\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}";
-    verify_with_string(&mut par, & [245u8,246u8,247u8,248u8,249u8,250u8,251u8,252u8,253u8,254u8,255u8], & [], & [], & [], &t1);
+    verify_with_string(&mut par, & [245u8,246u8,247u8,248u8,249u8,250u8,251u8,252u8,253u8,254u8,255u8], & [], & [], & [], t1);
     assert!(par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 11: accept some non-characters");
     let t1 = "\u{FFFE}\u{FFFF}\u{1FFFF}\u{2FFFE}\u{2FFFF}";
-    verify_with_string(&mut par, "\u{FFFE}\u{FFFF}\u{1FFFF}\u{2FFFE}\u{2FFFF}".as_bytes(), & [], & [], & [], &t1);
+    verify_with_string(&mut par, "\u{FFFE}\u{FFFF}\u{1FFFF}\u{2FFFE}\u{2FFFF}".as_bytes(), & [], & [], & [], t1);
     assert!(! par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 12: unicode 0, 16, 32, 48 ...");
     let t1 = "\u{0}\u{16}\u{32}\u{48}\u{64}\u{80}\u{96}\u{112}\u{128}\u{144}\u{160}";
-    verify_with_string(&mut par, "\u{0}\u{16}\u{32}\u{48}\u{64}\u{80}\u{96}\u{112}\u{128}\u{144}\u{160}".as_bytes(), & [], & [], & [], &t1);
+    verify_with_string(&mut par, "\u{0}\u{16}\u{32}\u{48}\u{64}\u{80}\u{96}\u{112}\u{128}\u{144}\u{160}".as_bytes(), & [], & [], & [], t1);
     assert!(! par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 13: < D0 D0 >");
     let t1 = "<\u{FFFD}\u{FFFD}>";
-    verify_with_string(&mut par, "<".as_bytes(), & [0xD0u8], & [0xD0u8], ">".as_bytes(), &t1);
+    verify_with_string(&mut par, "<".as_bytes(), & [0xD0u8], & [0xD0u8], ">".as_bytes(), t1);
     assert!(par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 14: E1 A0 C0");
     let t1 = "\u{FFFD}\u{FFFD}\\";
-    verify_with_string(&mut par, & [0xE1u8], & [0xA0u8], & [], & [0xC0, 0x5c], &t1);
+    verify_with_string(&mut par, & [0xE1u8], & [0xA0u8], & [], & [0xC0, 0x5c], t1);
     assert!(par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 15: over long null characters");
     let t1 = "\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}";
-    verify_with_string(&mut par, & [0xE0u8,128u8,128u8], & [0xF0,128u8,128u8,128u8], & [0xC0u8,128u8], & [], &t1);
+    verify_with_string(&mut par, & [0xE0u8,128u8,128u8], & [0xF0,128u8,128u8,128u8], & [0xC0u8,128u8], & [], t1);
     assert!(par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 16: +U10000");
     let t1 = "\u{10000}";
-    verify_with_string(&mut par, & [0b1111_0000u8], & [0b1001_0000u8], & [0b1000_0000u8], & [0b1000_0000u8], &t1);
+    verify_with_string(&mut par, & [0b1111_0000u8], & [0b1001_0000u8], & [0b1000_0000u8], & [0b1000_0000u8], t1);
     assert!(! par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 17: double quote, F0, double quote, NL");
     let t1 = "\"\u{FFFD}\"\n";
-    verify_with_string(&mut par, & [34u8], & [0xF0u8], & [34u8], & [10u8], &t1);
+    verify_with_string(&mut par, & [34u8], & [0xF0u8], & [34u8], & [10u8], t1);
     assert!(par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 18: +UD800");
     let t1 = "\u{FFFD}\u{FFFD}\u{FFFD}\n";
-    verify_with_string(&mut par, & [0xEDu8], & [0xA0u8], & [0x80u8], & [10u8], &t1);
+    verify_with_string(&mut par, & [0xEDu8], & [0xA0u8], & [0x80u8], & [10u8], t1);
     assert!(par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 19: +UDFFF");
     let t1 = "\u{FFFD}\u{FFFD}\u{FFFD}\r";
-    verify_with_string(&mut par, & [0xEDu8], & [0xbfu8], & [0xbfu8], & [13u8], &t1);
+    verify_with_string(&mut par, & [0xEDu8], & [0xbfu8], & [0xbfu8], & [13u8], t1);
     assert!(par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 20: 0x80");
     let t1 = "G\u{FFFD}R\r";
-    verify_with_string(&mut par, & [71u8], & [0x80u8], & [82u8], & [13u8], &t1);
+    verify_with_string(&mut par, & [71u8], & [0x80u8], & [82u8], & [13u8], t1);
     assert!(par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 21: 0xC0, 0xC1");
     let t1 = "G\u{FFFD}\u{FFFD}\n";
-    verify_with_string(&mut par, & [71u8], & [0xC1u8], & [0xC0u8], & [10u8], &t1);
+    verify_with_string(&mut par, & [71u8], & [0xC1u8], & [0xC0u8], & [10u8], t1);
     assert!(par.has_invalid_sequence());
 
     par.reset_parser();
     println!("case 22: U+110000");
     let t1 = "\u{FFFD}\u{FFFD}\u{FFFD}\u{FFFD}\n";
-    verify_with_string(&mut par, & [0xF5u8], & [0x80u8, 0x80u8], & [0x80u8], & [10u8], &t1);
+    verify_with_string(&mut par, & [0xF5u8], & [0x80u8, 0x80u8], & [0x80u8], & [10u8], t1);
     assert!(par.has_invalid_sequence());
 }
 
@@ -377,8 +377,8 @@ fn spread_noise(byte_slice: &mut [u8], rng: &mut SmallRng) {
 // Data in char is alredy valid Unicode codepoint.
 fn char_slice_to_u8_slice<'a>(char_slice: & [char], u8_slice: &'a mut [u8]) -> &'a mut [u8] {
     let mut cur_u8_len:usize = 0;
-    for char_indx in 0usize .. char_slice.len() {
-        let target = char_slice[char_indx].encode_utf8(&mut u8_slice[cur_u8_len .. ]);
+    for ch in char_slice {
+        let target = ch.encode_utf8(&mut u8_slice[cur_u8_len .. ]);
         cur_u8_len += target.len();
     }
     &mut u8_slice[0 .. cur_u8_len]
@@ -388,9 +388,7 @@ fn char_slice_to_u8_slice<'a>(char_slice: & [char], u8_slice: &'a mut [u8]) -> &
 fn copy_u8_slice_to_u8_slice(from_slice: & [u8], to_slice: & mut [u8]) {
     // assert!(to_slice.len() >= from_slice.len());
     let len = from_slice.len();
-    for indx in 0 .. len {
-        to_slice[indx] = from_slice[indx];
-    }
+    to_slice[0 .. len].copy_from_slice(from_slice);
 }
 
 // Copy a replacement character to a target u8 slice.
@@ -405,7 +403,7 @@ fn copy_replacement_to_u8_slice(to_slice: & mut [u8]) {
 fn validify_u8_buffer<'a>(u8_slice: & [u8], dest_slice: &'a mut [u8]) -> &'a mut [u8] {
     let mut cur_slice = u8_slice;
     let mut output_len:usize = 0;
-    while cur_slice.len() > 0 {
+    while !cur_slice.is_empty() {
         match str::from_utf8(cur_slice) {
             Ok(str_ref) => {
                 let ref_len = str_ref.len();
@@ -438,15 +436,15 @@ fn validify_u8_buffer<'a>(u8_slice: & [u8], dest_slice: &'a mut [u8]) -> &'a mut
 
 // Populate a char slice with random codepoints.
 fn make_random_string(char_slice: &mut [char], rng: &mut SmallRng) {
-    for indx in 0usize .. char_slice.len() {
+    for slot in char_slice.iter_mut() {
         let val:u32 = rng.next_u32() % 0x111000u32;
         match char::from_u32(val) {
             Option::Some(ch) => {
-                char_slice[indx] = ch;
+                * slot = ch;
             }
             Option::None => {
                 assert!(!((val > 0xffffu32) && (val <= 0x10ffffu32)));
-                char_slice[indx] = char::REPLACEMENT_CHARACTER;
+                * slot = char::REPLACEMENT_CHARACTER;
             }
         }
     }
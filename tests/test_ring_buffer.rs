@@ -14,7 +14,7 @@ use core::cmp::Ordering;
 fn test_ringbuffer_misc() {
     let mut b1:EightBytes = EightBytes::new();
     let mut b2:EightBytes = EightBytes::new();
-    assert_eq!(true, b1.eq(&b2));
+    assert!(b1.eq(&b2));
     assert_eq!(Option::Some(Ordering::Equal), b1.partial_cmp(&b2));
     b1.push_back(12u8);
     assert_eq!(Ordering::Greater, b1.cmp(&b2));
@@ -0,0 +1,156 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Randomization based testing on the UTF16 parser, checked against
+// char::decode_utf16()
+
+extern crate std;
+
+use utf8conv::*;
+
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use rand::RngCore;
+
+// Fill the buffer with UTF16 code units where about half of the units
+// are surrogates, paired or not.
+fn make_random_utf16(unit_buf: &mut [u16], rng: &mut SmallRng) {
+    let mut indx = 0usize;
+    while indx < unit_buf.len() {
+        let unit = match rng.next_u32() % 8 {
+            0 => { 0xD800u16 + (rng.next_u32() % 0x400) as u16 }
+            1 => { 0xDC00u16 + (rng.next_u32() % 0x400) as u16 }
+            2 | 3 => {
+                // A valid surrogate pair, if there is room for it
+                if indx + 1 < unit_buf.len() {
+                    unit_buf[indx] = 0xD800u16 + (rng.next_u32() % 0x400) as u16;
+                    indx += 1;
+                }
+                0xDC00u16 + (rng.next_u32() % 0x400) as u16
+            }
+            4 => { 0x20u16 + (rng.next_u32() % 0x60) as u16 }
+            5 => { 0xFFFDu16 }
+            _ => { (rng.next_u32() % 0x10000) as u16 }
+        };
+        unit_buf[indx] = unit;
+        indx += 1;
+    }
+}
+
+// Chop up one u16 slice into 4 slices at random points.
+fn four_random_split<'a>(units: &'a [u16], rng: &mut SmallRng) -> [&'a [u16]; 4] {
+    let mut cuts = [0usize; 5];
+    for cut in cuts[1 .. 4].iter_mut() {
+        * cut = (rng.next_u32() as usize) % (units.len() + 1);
+    }
+    cuts[4] = units.len();
+    cuts.sort_unstable();
+    [& units[cuts[0] .. cuts[1]], & units[cuts[1] .. cuts[2]],
+    & units[cuts[2] .. cuts[3]], & units[cuts[3] .. cuts[4]]]
+}
+
+// Result of char::decode_utf16() with replacement characters.
+fn reference_decode(units: &[u16]) -> std::vec::Vec<char> {
+    char::decode_utf16(units.iter().copied())
+    .map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER))
+    .collect()
+}
+
+// Decode the buffers with utf16_to_char().
+fn decode_single(bufs: &[&[u16]; 4]) -> (std::vec::Vec<char>, bool) {
+    let mut parser = FromUtf16::new();
+    let mut chars = std::vec::Vec::new();
+    for (indx, buf) in bufs.iter().enumerate() {
+        parser.set_is_last_buffer(indx == bufs.len() - 1);
+        let mut cur_slice = * buf;
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, ch)) = parser.utf16_to_char(cur_slice) {
+            cur_slice = slice_pos;
+            chars.push(ch);
+        }
+    }
+    (chars, parser.has_invalid_sequence())
+}
+
+// Decode the buffers with utf16_to_char_slice().
+fn decode_bulk(bufs: &[&[u16]; 4]) -> (std::vec::Vec<char>, bool) {
+    let mut parser = FromUtf16::new();
+    let mut chars = std::vec::Vec::new();
+    let mut char_box = ['\0'; 5];
+    for (indx, buf) in bufs.iter().enumerate() {
+        parser.set_is_last_buffer(indx == bufs.len() - 1);
+        let mut cur_slice = * buf;
+        // The loop stops at MoreEnum::More(_amt);
+        // _amt equals to 0 when end of data
+        while let Result::Ok((slice_pos, num_chars)) = parser.utf16_to_char_slice(cur_slice, & mut char_box) {
+            cur_slice = slice_pos;
+            chars.extend_from_slice(& char_box[.. num_chars]);
+        }
+    }
+    (chars, parser.has_invalid_sequence())
+}
+
+// Decode the buffers with the iterator adapter, switching the source
+// iterator at every buffer boundary.
+fn decode_iter(bufs: &[&[u16]; 4]) -> (std::vec::Vec<char>, bool) {
+    let mut parser = FromUtf16::new();
+    parser.set_is_last_buffer(false);
+    let mut iters = [bufs[0].iter(), bufs[1].iter(), bufs[2].iter(), bufs[3].iter()];
+    let (first, rest) = iters.split_first_mut().unwrap();
+    let mut adapter = parser.utf16_ref_to_char_with_iter(first);
+    let mut chars: std::vec::Vec<char> = adapter.by_ref().collect();
+    let num_rest = rest.len();
+    for (indx, iter) in rest.iter_mut().enumerate() {
+        adapter.set_source(iter);
+        adapter.set_is_last_buffer(indx == num_rest - 1);
+        chars.extend(adapter.by_ref());
+    }
+    (chars, adapter.has_invalid_sequence())
+}
+
+// Decode the buffers into UTF8 with the iterator adapter.
+fn decode_utf8_iter(bufs: &[&[u16]; 4]) -> (std::vec::Vec<u8>, bool) {
+    let mut parser = FromUtf16::new();
+    let mut bytes = std::vec::Vec::new();
+    for (indx, buf) in bufs.iter().enumerate() {
+        parser.set_is_last_buffer(indx == bufs.len() - 1);
+        let mut iter = buf.iter().copied();
+        bytes.extend(parser.utf16_to_utf8_with_iter(& mut iter));
+    }
+    (bytes, parser.has_invalid_sequence())
+}
+
+fn run_monkey(seed: u64, unit_len: usize, rounds: usize) {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut unit_buf = std::vec![0u16; unit_len];
+    for _indx in 0 .. rounds {
+        make_random_utf16(& mut unit_buf, & mut rng);
+        let bufs = four_random_split(& unit_buf, & mut rng);
+        let truth = reference_decode(& unit_buf);
+        let invalid = truth.contains(& char::REPLACEMENT_CHARACTER);
+        assert_eq!((truth.clone(), invalid), decode_single(& bufs), "units {:X?}", bufs);
+        assert_eq!((truth.clone(), invalid), decode_bulk(& bufs), "units {:X?}", bufs);
+        assert_eq!((truth.clone(), invalid), decode_iter(& bufs), "units {:X?}", bufs);
+        let truth_utf8: std::string::String = truth.iter().collect();
+        assert_eq!((truth_utf8.into_bytes(), invalid), decode_utf8_iter(& bufs), "units {:X?}", bufs);
+    }
+}
+
+#[test]
+// Test short surrogate heavy streams, where most splits fall next to
+// a surrogate
+fn test_utf16_monkey1() {
+    run_monkey(0x2c9e41b7d05f63a8u64, 6, 40000);
+}
+
+#[test]
+// Test longer surrogate heavy streams split into four buffers
+fn test_utf16_monkey2() {
+    run_monkey(0x91d3a6f04e7b25c1u64, 120, 10000);
+}
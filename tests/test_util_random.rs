@@ -0,0 +1,92 @@
+// Copyright 2022 Thomas Wang and utf8conv contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Randomization based testing on the verify functions of the test_util
+// module, with truth strings from String::from_utf8_lossy()
+
+extern crate std;
+
+use utf8conv::*;
+use utf8conv::test_util::*;
+
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use rand::RngCore;
+
+// Fill the buffer with UTF8 text of random chars, with random bytes
+// mixed in as noise.
+fn make_noisy_utf8(rng: &mut SmallRng) -> std::vec::Vec<u8> {
+    let mut bytes = std::vec::Vec::new();
+    let mut char_bytes = [0u8; 4];
+    for _indx in 0 .. 120 {
+        if rng.next_u32() % 8 == 0 {
+            bytes.push(rng.next_u32() as u8);
+        }
+        else if let Option::Some(ch) = char::from_u32(rng.next_u32() % 0x110000u32) {
+            bytes.extend_from_slice(ch.encode_utf8(& mut char_bytes).as_bytes());
+        }
+    }
+    bytes
+}
+
+// Chop up one u8 slice into 1 to 8 slices at random points.
+fn random_split<'a>(bytes: &'a [u8], rng: &mut SmallRng) -> std::vec::Vec<&'a [u8]> {
+    let num_cuts = (rng.next_u32() % 8) as usize;
+    let mut cuts: std::vec::Vec<usize> = (0 .. num_cuts)
+    .map(|_| (rng.next_u32() as usize) % (bytes.len() + 1))
+    .collect();
+    cuts.push(0);
+    cuts.push(bytes.len());
+    cuts.sort_unstable();
+    cuts.windows(2).map(|w| & bytes[w[0] .. w[1]]).collect()
+}
+
+#[test]
+// Test the verify functions over any number of buffers
+fn test_verify_buffers_monkey() {
+    let mut par:FromUtf8 = FromUtf8::new();
+    let mut rng = SmallRng::seed_from_u64(0x5be2c41f0d9e3b71u64);
+    for _indx in 0 .. 10000 {
+        let bytes = make_noisy_utf8(& mut rng);
+        let truth = std::string::String::from_utf8_lossy(& bytes);
+        let buffers = random_split(& bytes, & mut rng);
+        verify_buffers_with_string(&mut par, & buffers, & truth);
+        verify_buffers_style2(&mut par, & buffers, & truth);
+        verify_buffers_style3(&mut par, & buffers, & truth);
+        verify_buffers_style4(&mut par, & buffers, & truth);
+    }
+}
+
+#[test]
+// Test the four buffer verify functions
+fn test_verify_four_monkey() {
+    let mut par:FromUtf8 = FromUtf8::new();
+    let mut rng = SmallRng::seed_from_u64(0x2f6a91c4e07d35b8u64);
+    for _indx in 0 .. 10000 {
+        let bytes = make_noisy_utf8(& mut rng);
+        let truth = std::string::String::from_utf8_lossy(& bytes);
+        let mut buffers = random_split(& bytes, & mut rng);
+        // Pad to four buffers, and join the buffers past the third one.
+        while buffers.len() < 4 {
+            buffers.push(& []);
+        }
+        let rest = buffers.split_off(3).concat();
+        verify_with_string(&mut par, buffers[0], buffers[1], buffers[2], & rest, & truth);
+        verify_style2(&mut par, buffers[0], buffers[1], buffers[2], & rest, & truth);
+        verify_style3(&mut par, buffers[0], buffers[1], buffers[2], & rest, & truth);
+        verify_style4(&mut par, buffers[0], buffers[1], buffers[2], & rest, & truth);
+    }
+}
+
+#[test]
+#[should_panic]
+// Test that a wrong truth string is caught
+fn test_verify_mismatch() {
+    let mut par:FromUtf8 = FromUtf8::new();
+    verify_buffers_with_string(&mut par, & [b"ab", b"\xC3", b"\xA9"], "ab\u{FFFD}\u{FFFD}");
+}